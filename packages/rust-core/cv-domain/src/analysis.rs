@@ -3,8 +3,12 @@
 //! This module contains functions for analyzing CV structure, detecting layout types,
 //! estimating page counts, and evaluating font complexity.
 
-use tsx_parser::{JSXElement, TsxDocument};
+use tsx_parser::{
+    extract_class_name, extract_element_name, extract_inline_style, extract_text_content, JSXChild,
+    JSXElement, TsxDocument,
+};
 
+use crate::extractors::experience::{extract_experience_roles, ExperienceEntry};
 use crate::metadata::{FontComplexity, LayoutType};
 
 // Page estimation constants (characters per page by layout type)
@@ -24,6 +28,9 @@ const CHARS_PER_PAGE_PORTFOLIO: usize = 3500;
 /// Default characters per page for custom/unknown layouts
 const CHARS_PER_PAGE_CUSTOM: usize = 3000;
 
+/// Average adult silent reading speed for prose, in words per minute
+const READING_WORDS_PER_MINUTE: usize = 238;
+
 // Section detection thresholds
 
 /// Minimum number of h2/h3 headings to indicate clear sections
@@ -35,6 +42,17 @@ const MIN_SECTIONS_FOR_TWO_COL: usize = 2;
 /// Minimum number of div elements to suggest custom/complex layout
 const MIN_DIVS_FOR_CUSTOM: usize = 10;
 
+/// Minimum number of dated experience entries needed to judge their order
+const MIN_DATED_ENTRIES: usize = 2;
+
+// Sidebar detection thresholds
+
+/// Widest fixed-width column (in px/pt) still treated as a sidebar
+const MAX_SIDEBAR_WIDTH: f64 = 250.0;
+
+/// Widest percentage column still treated as a sidebar
+const MAX_SIDEBAR_PERCENT: f64 = 40.0;
+
 /// Detect layout type from component structure.
 ///
 /// # Algorithm
@@ -96,6 +114,25 @@ pub(crate) fn estimate_page_count(content_length: usize, layout: LayoutType) ->
     (content_length / chars_per_page).max(1)
 }
 
+/// Count whitespace-separated words that contain a letter or digit
+///
+/// Standalone punctuation such as `|` or `-` separators is not a word.
+pub(crate) fn count_words(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|token| token.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Count the characters of a text, excluding whitespace
+pub(crate) fn count_chars(text: &str) -> usize {
+    text.chars().filter(|c| !c.is_whitespace()).count()
+}
+
+/// Estimate how long reading `word_count` words takes, rounded up to a second
+pub(crate) fn estimate_reading_time_seconds(word_count: usize) -> usize {
+    (word_count * 60).div_ceil(READING_WORDS_PER_MINUTE)
+}
+
 /// Detect if CV has clear section headings
 pub(crate) fn detect_clear_sections(elements: &[&JSXElement]) -> bool {
     // Count heading elements (h2, h3) that likely indicate sections
@@ -111,6 +148,97 @@ pub(crate) fn detect_clear_sections(elements: &[&JSXElement]) -> bool {
     heading_count >= MIN_SECTION_HEADINGS
 }
 
+/// Find section headings used more than once.
+///
+/// Section headings are the `<h2>` elements, or the `<h3>` elements when the
+/// document has no `<h2>` (role titles under `<h2>` sections often repeat
+/// legitimately). Headings are compared case-insensitively, ignoring
+/// surrounding whitespace and trailing punctuation, so "Education" and
+/// "EDUCATION:" are duplicates.
+///
+/// # Returns
+///
+/// Each duplicated heading once, as first written, in document order.
+pub(crate) fn detect_duplicate_sections(elements: &[&JSXElement]) -> Vec<String> {
+    let has_h2 = elements.iter().any(|e| extract_element_name(e) == "h2");
+    let section_tag = if has_h2 { "h2" } else { "h3" };
+
+    let mut seen: Vec<(String, String)> = Vec::new();
+    let mut duplicates: Vec<String> = Vec::new();
+    for element in elements {
+        if extract_element_name(element) != section_tag {
+            continue;
+        }
+        let text = extract_text_content(element).join(" ");
+        let heading = text.trim();
+        let key = normalize_heading(heading);
+        if key.is_empty() {
+            continue;
+        }
+        match seen.iter().find(|(seen_key, _)| *seen_key == key) {
+            Some((_, first)) if !duplicates.contains(first) => duplicates.push(first.clone()),
+            Some(_) => {}
+            None => seen.push((key, heading.to_string())),
+        }
+    }
+    duplicates
+}
+
+/// Heading text reduced for comparison: lowercase, single-spaced, no trailing punctuation
+fn normalize_heading(heading: &str) -> String {
+    heading
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(|c: char| c.is_ascii_punctuation() || c.is_whitespace())
+        .to_lowercase()
+}
+
+/// Check whether the experience section lists roles most recent first.
+///
+/// Takes each experience entry's start year (see
+/// [`ExperienceEntry::start_year`]) and checks that the years never increase
+/// down the list. Entries without a date are skipped; roles starting in the
+/// same year count as in order.
+///
+/// # Returns
+///
+/// `None` when fewer than two entries have a start year, otherwise whether the
+/// dated entries are in reverse-chronological order.
+///
+/// # Example
+///
+/// ```
+/// use cv_domain::is_reverse_chronological;
+/// use tsx_parser::parse_tsx;
+///
+/// let tsx = r#"<div>
+///     <h2>Experience</h2>
+///     <h3>Intern | Acme</h3>
+///     <p>2016 - 2017</p>
+///     <h3>Engineer | Globex</h3>
+///     <p>2019 - Present</p>
+/// </div>"#;
+/// let doc = parse_tsx(tsx)?;
+/// assert_eq!(is_reverse_chronological(&doc), Some(false));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn is_reverse_chronological(document: &TsxDocument) -> Option<bool> {
+    reverse_chronological_order(&extract_experience_roles(document))
+}
+
+/// Order check of [`is_reverse_chronological`] on already extracted entries
+pub(crate) fn reverse_chronological_order(entries: &[ExperienceEntry]) -> Option<bool> {
+    let years: Vec<u16> = entries
+        .iter()
+        .filter_map(ExperienceEntry::start_year)
+        .collect();
+    if years.len() < MIN_DATED_ENTRIES {
+        return None;
+    }
+    Some(years.windows(2).all(|pair| pair[0] >= pair[1]))
+}
+
 /// Detect font complexity from inline styles in document.
 ///
 /// # How It Works
@@ -162,3 +290,102 @@ pub(crate) fn detect_font_complexity(document: &TsxDocument) -> FontComplexity {
         _ => FontComplexity::Complex,
     }
 }
+
+/// Detect whether contact details sit in a sidebar column.
+///
+/// # How It Works
+///
+/// Looks for row containers (flex rows or multi-column grids) and checks each
+/// narrow column for any of the `contact` strings. A column counts as narrow
+/// when it is an `<aside>`, has a `sidebar` class, or declares a width of at
+/// most 250px/pt, 40%, or a Tailwind fraction below one half (`w-1/3`).
+///
+/// Many ATS parsers read multi-column layouts line by line across columns or
+/// skip side columns entirely, so contact details there are often lost.
+pub(crate) fn detect_contact_in_sidebar(elements: &[&JSXElement], contact: &[&str]) -> bool {
+    if contact.is_empty() {
+        return false;
+    }
+
+    elements
+        .iter()
+        .filter(|element| is_row_container(element))
+        .flat_map(|row| row.children.iter())
+        .filter_map(|child| match child {
+            JSXChild::Element(column) => Some(column),
+            _ => None,
+        })
+        .filter(|column| is_sidebar_column(column))
+        .any(|column| {
+            let text = extract_text_content(column).join(" ");
+            contact.iter().any(|value| text.contains(value))
+        })
+}
+
+/// Check if an element lays its children out side by side
+fn is_row_container(element: &JSXElement) -> bool {
+    let style = extract_inline_style(element)
+        .unwrap_or_default()
+        .to_lowercase();
+    let declares = |property: &str, value: &str| {
+        style
+            .split(';')
+            .filter_map(|decl| decl.split_once(':'))
+            .any(|(p, v)| p.trim() == property && v.trim().starts_with(value))
+    };
+    if declares("display", "flex") {
+        return !declares("flex-direction", "column");
+    }
+    if declares("display", "grid") {
+        return declares("grid-template-columns", "");
+    }
+
+    let class_name = extract_class_name(element).unwrap_or_default();
+    let classes: Vec<&str> = class_name.split_whitespace().collect();
+    (classes.contains(&"flex") && !classes.contains(&"flex-col"))
+        || (classes.contains(&"grid")
+            && classes
+                .iter()
+                .any(|c| c.strip_prefix("grid-cols-").is_some_and(|n| n != "1")))
+}
+
+/// Check if a column of a row container is a narrow side column
+fn is_sidebar_column(element: &JSXElement) -> bool {
+    if extract_element_name(element) == "aside" {
+        return true;
+    }
+
+    let class_name = extract_class_name(element).unwrap_or_default();
+    if class_name.split_whitespace().any(|class| {
+        class.contains("sidebar")
+            || class
+                .strip_prefix("w-")
+                .and_then(|fraction| fraction.split_once('/'))
+                .and_then(|(n, d)| Some((n.parse::<f64>().ok()?, d.parse::<f64>().ok()?)))
+                .is_some_and(|(n, d)| n / d < 0.5)
+    }) {
+        return true;
+    }
+
+    let style = extract_inline_style(element).unwrap_or_default();
+    style
+        .split(';')
+        .filter_map(|decl| decl.split_once(':'))
+        .filter(|(property, _)| property.trim().eq_ignore_ascii_case("width"))
+        .any(|(_, value)| {
+            let value = value.trim().to_lowercase();
+            if let Some(percent) = value.strip_suffix('%') {
+                percent
+                    .trim()
+                    .parse::<f64>()
+                    .is_ok_and(|p| p <= MAX_SIDEBAR_PERCENT)
+            } else {
+                value
+                    .trim_end_matches("px")
+                    .trim_end_matches("pt")
+                    .trim()
+                    .parse::<f64>()
+                    .is_ok_and(|w| w <= MAX_SIDEBAR_WIDTH)
+            }
+        })
+}
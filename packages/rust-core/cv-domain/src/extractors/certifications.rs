@@ -0,0 +1,118 @@
+//! Certification extraction from CV elements
+//!
+//! Responsible for listing the entries of a certifications section together
+//! with the year each one was issued.
+
+use serde::{Deserialize, Serialize};
+use tsx_parser::{
+    extract_element_name, extract_jsx_elements, extract_text_content, JSXChild, JSXElement,
+    TsxDocument,
+};
+
+/// A single certification or license listed in the CV
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Certification {
+    /// Certification name as written (e.g. "Certified Kubernetes Administrator (CKA)")
+    pub name: String,
+    /// Year the certification was issued, when the entry states one
+    pub year: Option<u16>,
+}
+
+/// Extract the certifications listed under the certifications section of a document.
+///
+/// See [`extract_certifications_from_elements`] for the heuristics used.
+///
+/// # Example
+///
+/// ```
+/// use cv_domain::extract_certifications;
+/// use tsx_parser::parse_tsx;
+///
+/// let tsx = r#"<div>
+///     <h2>Certifications</h2>
+///     <p>Certified Scrum Master (2020)</p>
+///     <p>PMP</p>
+/// </div>"#;
+/// let doc = parse_tsx(tsx)?;
+/// let certs = extract_certifications(&doc);
+/// assert_eq!(certs[0].name, "Certified Scrum Master");
+/// assert_eq!(certs[0].year, Some(2020));
+/// assert_eq!(certs[1].year, None);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn extract_certifications(document: &TsxDocument) -> Vec<Certification> {
+    extract_certifications_from_elements(&extract_jsx_elements(document))
+}
+
+/// Extract certification entries from elements in document order.
+///
+/// Within a section whose `<h1>`/`<h2>` heading names certifications or
+/// licenses, every element with its own text is one entry. A trailing year
+/// ("(2023)", ", 2023", "- 2023") is split off into `year`; entries without
+/// one keep `year: None`.
+///
+/// # Returns
+///
+/// Entries in document order. Empty if no certifications section was found.
+pub fn extract_certifications_from_elements(elements: &[&JSXElement]) -> Vec<Certification> {
+    let mut entries = Vec::new();
+    let mut in_certifications_section = false;
+
+    for element in elements {
+        let tag = extract_element_name(element);
+        let text = extract_text_content(element).join(" ");
+        let text = text.trim();
+
+        match tag.as_str() {
+            "h1" | "h2" => in_certifications_section = is_certifications_heading(text),
+            _ if in_certifications_section && has_direct_text(element) && !text.is_empty() => {
+                entries.extend(parse_certification(text));
+            }
+            _ => {}
+        }
+    }
+
+    entries
+}
+
+/// Check if heading text names a certifications section
+fn is_certifications_heading(text: &str) -> bool {
+    let lower = text.trim().to_lowercase();
+    lower.contains("certif") || lower.contains("licens")
+}
+
+/// Check if an element has its own text (not only text of nested elements)
+fn has_direct_text(element: &JSXElement) -> bool {
+    element.children.iter().any(|child| match child {
+        JSXChild::Text(text) => !text.value.trim().is_empty(),
+        JSXChild::ExpressionContainer(_) => true,
+        _ => false,
+    })
+}
+
+/// Split a certification line into its name and trailing issue year
+fn parse_certification(text: &str) -> Option<Certification> {
+    let caps = regex_utils::CERTIFICATION_ENTRY.captures(text)?;
+    Some(Certification {
+        name: caps["name"].trim().to_string(),
+        year: caps
+            .name("year")
+            .and_then(|year| year.as_str().parse().ok()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_certification_with_and_without_year() {
+        let cert = parse_certification("Certified Kubernetes Administrator (CKA) (2022)").unwrap();
+        assert_eq!(cert.name, "Certified Kubernetes Administrator (CKA)");
+        assert_eq!(cert.year, Some(2022));
+
+        let cert = parse_certification("Google Professional Data Engineer").unwrap();
+        assert_eq!(cert.name, "Google Professional Data Engineer");
+        assert_eq!(cert.year, None);
+    }
+}
@@ -0,0 +1,240 @@
+//! Experience entry extraction from CV elements
+//!
+//! Responsible for pairing role headings in the experience section with
+//! their organization and date range.
+
+use serde::{Deserialize, Serialize};
+use tsx_parser::{
+    extract_element_name, extract_jsx_elements, extract_text_content, JSXChild, JSXElement,
+    TsxDocument,
+};
+
+/// Number of text lines after a role heading searched for its organization and dates
+const MAX_DETAIL_LINES: usize = 3;
+
+/// Maximum word count for an organization line
+const MAX_ORGANIZATION_WORDS: usize = 8;
+
+/// Separators between role title and organization in a single heading
+/// (e.g. "Software Engineer | Acme", "Designer at Acme")
+const TITLE_SEPARATORS: [&str; 6] = [" | ", " — ", " – ", " - ", " at ", " @ "];
+
+/// A single role listed in the experience section
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExperienceEntry {
+    /// Role title (e.g. "Senior Software Engineer")
+    pub title: String,
+    /// Company or organization, when one could be detected
+    pub organization: Option<String>,
+    /// Date range as written in the CV (e.g. "2021 - Present")
+    pub date_range: Option<String>,
+}
+
+impl ExperienceEntry {
+    /// Year the role started: the first four-digit year of its date range
+    ///
+    /// ```
+    /// use cv_domain::ExperienceEntry;
+    ///
+    /// let entry = ExperienceEntry {
+    ///     title: "Data Analyst".to_string(),
+    ///     organization: None,
+    ///     date_range: Some("Jan 2019 - Dec 2021".to_string()),
+    /// };
+    /// assert_eq!(entry.start_year(), Some(2019));
+    /// ```
+    pub fn start_year(&self) -> Option<u16> {
+        let date_range = self.date_range.as_deref()?;
+        date_range
+            .split(|c: char| !c.is_ascii_digit())
+            .find(|digits| digits.len() == 4)
+            .and_then(|year| year.parse().ok())
+    }
+}
+
+/// Extract the roles listed under the experience section of a document.
+///
+/// See [`extract_experience_from_elements`] for the heuristics used.
+///
+/// # Example
+///
+/// ```
+/// use cv_domain::extract_experience_roles;
+/// use tsx_parser::parse_tsx;
+///
+/// let tsx = r#"<div>
+///     <h2>Experience</h2>
+///     <h3>Data Analyst</h3>
+///     <p>Acme Inc.</p>
+///     <p>Jan 2019 - Dec 2021</p>
+/// </div>"#;
+/// let doc = parse_tsx(tsx)?;
+/// let roles = extract_experience_roles(&doc);
+/// assert_eq!(roles[0].title, "Data Analyst");
+/// assert_eq!(roles[0].organization.as_deref(), Some("Acme Inc."));
+/// assert_eq!(roles[0].date_range.as_deref(), Some("Jan 2019 - Dec 2021"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn extract_experience_roles(document: &TsxDocument) -> Vec<ExperienceEntry> {
+    extract_experience_from_elements(&extract_jsx_elements(document))
+}
+
+/// Extract experience entries from elements in document order.
+///
+/// Within a section whose `<h1>`/`<h2>` heading names work experience, each
+/// `<h3>` starts a new entry. A heading such as "Engineer | Acme" or
+/// "Engineer at Acme" is split into title and organization. The next few
+/// text lines (up to the next heading or bullet list) supply the date range
+/// and, if the heading had none, the organization. Entries without a
+/// detectable date keep `date_range: None`.
+///
+/// # Returns
+///
+/// Entries in document order. Empty if no experience section was found.
+pub fn extract_experience_from_elements(elements: &[&JSXElement]) -> Vec<ExperienceEntry> {
+    let mut entries: Vec<ExperienceEntry> = Vec::new();
+    let mut in_experience_section = false;
+    // Detail lines still to inspect for the most recent entry
+    let mut detail_lines = 0;
+
+    for element in elements {
+        let tag = extract_element_name(element);
+        let text = extract_text_content(element).join(" ");
+        let text = text.trim();
+
+        match tag.as_str() {
+            "h1" | "h2" => {
+                in_experience_section = is_experience_heading(text);
+                detail_lines = 0;
+            }
+            "h3" if in_experience_section && !text.is_empty() => {
+                entries.push(parse_role_heading(text));
+                detail_lines = MAX_DETAIL_LINES;
+            }
+            "h3" | "ul" | "ol" | "li" => detail_lines = 0,
+            _ if detail_lines > 0 && has_direct_text(element) => {
+                detail_lines -= 1;
+                if let Some(entry) = entries.last_mut() {
+                    apply_detail_line(entry, text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    entries
+}
+
+/// Check if heading text names a work experience section
+fn is_experience_heading(text: &str) -> bool {
+    let lower = text.trim().to_lowercase();
+    lower.contains("experience")
+        || lower.contains("employment")
+        || lower.contains("work history")
+        || lower.contains("career")
+}
+
+/// Check if an element has its own text (not only text of nested elements)
+fn has_direct_text(element: &JSXElement) -> bool {
+    element.children.iter().any(|child| match child {
+        JSXChild::Text(text) => !text.value.trim().is_empty(),
+        JSXChild::ExpressionContainer(_) => true,
+        _ => false,
+    })
+}
+
+/// Split a role heading into title, organization and an inline date range
+fn parse_role_heading(text: &str) -> ExperienceEntry {
+    let date_range = find_date_range(text);
+    let remainder = match &date_range {
+        Some(date) => strip_segment(text, date),
+        None => text.to_string(),
+    };
+
+    let split = TITLE_SEPARATORS
+        .iter()
+        .filter_map(|sep| remainder.split_once(sep))
+        .min_by_key(|(title, _)| title.len());
+
+    let (title, organization) = match split {
+        Some((title, organization)) => (title.trim(), first_segment(organization)),
+        None => (remainder.trim(), None),
+    };
+
+    ExperienceEntry {
+        title: title.to_string(),
+        organization,
+        date_range,
+    }
+}
+
+/// Fill in the date range and organization of an entry from a line below its heading
+fn apply_detail_line(entry: &mut ExperienceEntry, text: &str) {
+    let date = find_date_range(text);
+    let remainder = match &date {
+        Some(date) => strip_segment(text, date),
+        None => text.to_string(),
+    };
+
+    if entry.date_range.is_none() {
+        entry.date_range = date;
+    }
+    if entry.organization.is_none() {
+        entry.organization = first_segment(&remainder)
+            .filter(|org| org.split_whitespace().count() <= MAX_ORGANIZATION_WORDS);
+    }
+}
+
+/// Find the first date range in text (month names, numeric, ISO or plain years)
+fn find_date_range(text: &str) -> Option<String> {
+    [
+        &regex_utils::DATE_MONTH_NAME,
+        &regex_utils::DATE_NUMERIC,
+        &regex_utils::DATE_ISO,
+        &regex_utils::DATE_YEAR_RANGE,
+    ]
+    .into_iter()
+    .find_map(|pattern| pattern.find(text))
+    .map(|m| m.as_str().to_string())
+}
+
+/// Remove a matched segment and the separator punctuation left around it
+fn strip_segment(text: &str, segment: &str) -> String {
+    text.replacen(segment, "", 1)
+        .trim_matches(|c: char| c.is_whitespace() || matches!(c, '|' | '•' | '·' | ',' | '(' | ')'))
+        .to_string()
+}
+
+/// First non-empty segment of a line split on pipes and bullets
+fn first_segment(text: &str) -> Option<String> {
+    text.split(['|', '•', '·'])
+        .map(str::trim)
+        .find(|segment| !segment.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_role_heading_splits_organization() {
+        let entry = parse_role_heading("Senior Software Engineer | Tech Corp");
+        assert_eq!(entry.title, "Senior Software Engineer");
+        assert_eq!(entry.organization.as_deref(), Some("Tech Corp"));
+        assert_eq!(entry.date_range, None);
+
+        let entry = parse_role_heading("Product Designer at Studio One (2018 - 2020)");
+        assert_eq!(entry.title, "Product Designer");
+        assert_eq!(entry.organization.as_deref(), Some("Studio One"));
+        assert_eq!(entry.date_range.as_deref(), Some("2018 - 2020"));
+    }
+
+    #[test]
+    fn test_apply_detail_line_keeps_heading_organization() {
+        let mut entry = parse_role_heading("Engineer | Acme");
+        apply_detail_line(&mut entry, "Berlin | May 2020 – Present");
+        assert_eq!(entry.organization.as_deref(), Some("Acme"));
+        assert_eq!(entry.date_range.as_deref(), Some("May 2020 – Present"));
+    }
+}
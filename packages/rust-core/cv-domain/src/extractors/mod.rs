@@ -3,19 +3,25 @@
 //! This module contains specialized extractors for different types of CV fields.
 //! Each extractor focuses on a single responsibility (SRP refactoring Phase 3).
 
+pub mod certifications;
 pub mod email;
+pub mod experience;
 pub mod location;
 pub mod name;
 pub mod phone;
+pub mod skills;
 pub mod text;
 pub mod title;
 pub mod url;
 
 // Re-export public functions for backward compatibility
+pub use certifications::extract_certifications_from_elements;
 pub use email::extract_email_from_text;
+pub use experience::extract_experience_from_elements;
 pub use location::extract_location_from_text;
 pub use name::extract_name_from_elements;
-pub use phone::extract_phone_from_text;
-pub use text::collect_all_text;
+pub use phone::{extract_phone_from_text, normalize_phone_e164};
+pub use skills::extract_skills_from_elements;
+pub use text::{collect_all_text, top_level_elements};
 pub use title::extract_title_from_elements;
 pub use url::extract_website_from_text;
@@ -12,6 +12,8 @@
 /// ## North America
 /// - US/Canada: `(555) 123-4567`, `555-123-4567`, `555.123.4567`
 /// - With country code: `+1-555-123-4567`, `+1 (555) 123-4567`
+/// - With extension: `555-123-4567 ext. 42`, `(555) 123-4567 x42` (returned
+///   as `555-123-4567 ext. 42`)
 ///
 /// ## Europe
 /// - UK: `+44 20 7123 4567`, `+44 7700 900123`
@@ -29,9 +31,11 @@
 /// # Limitations
 ///
 /// - **No country validation** - Does not verify number belongs to country
-/// - **No format normalization** - Returns number as found in text
+/// - **No format normalization** - Returns number as found in text (see
+///   [`normalize_phone_e164`] for the canonical form)
 /// - **Regex-based detection** - May miss unusual formats or extensions
-/// - **No extension support** - Ext. or x123 suffixes are not captured
+/// - **US/Canada extensions only** - Ext. or x123 suffixes are only recognized
+///   for US/Canada numbers
 /// - **Estimated coverage** - 80% of international formats (vs. 99% with libphonenumber)
 ///
 /// For comprehensive validation (+400-600KB), consider the `phonenumber` crate.
@@ -47,16 +51,64 @@
 pub fn extract_phone_from_text(text: &str) -> Option<String> {
     // Try each pattern in order (country-specific first, then generic fallbacks)
     for pattern in regex_utils::PHONE_PATTERNS.iter() {
-        if let Some(m) = pattern.find(text) {
+        if let Some(caps) = pattern.captures(text) {
+            // Patterns with an extension capture the number on its own
+            let m = caps.name("number").or_else(|| caps.get(0))?;
             let phone = m.as_str().to_string();
             if validate_phone(&phone) {
-                return Some(phone);
+                return Some(match caps.name("ext") {
+                    Some(ext) => format!("{} ext. {}", phone, ext.as_str()),
+                    None => phone,
+                });
             }
         }
     }
     None
 }
 
+/// Normalize a phone number to E.164 (`+15551234567`) when its country is clear.
+///
+/// The country is taken from a leading `+` country code; numbers without one
+/// are read as US/Canada only when they have exactly 10 digits (or 11 with a
+/// leading `1`) and a valid area code. A `(0)` trunk prefix after the country
+/// code (`+44 (0) 20 ...`) is dropped, and so is an extension (`ext. 42`),
+/// which E.164 cannot express.
+///
+/// # Returns
+///
+/// The E.164 string, or `None` when the number is ambiguous (e.g. a local
+/// number without a country code) or cannot be a valid E.164 number.
+pub fn normalize_phone_e164(phone: &str) -> Option<String> {
+    // An extension starts at the first letter ("ext. 42", "x42")
+    let phone = phone
+        .split(|c: char| c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default()
+        .trim();
+    if let Some(rest) = phone.strip_prefix('+') {
+        let digits: String = rest
+            .replace("(0)", "")
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect();
+        // E.164 allows at most 15 digits and country codes never start with 0
+        let valid = (8..=15).contains(&digits.len()) && !digits.starts_with('0');
+        return valid.then(|| format!("+{}", digits));
+    }
+
+    let digits: String = phone.chars().filter(|c| c.is_ascii_digit()).collect();
+    let national = match digits.len() {
+        10 => digits.as_str(),
+        11 if digits.starts_with('1') => &digits[1..],
+        _ => return None,
+    };
+    // NANP area codes start with 2-9
+    if national.starts_with(['0', '1']) {
+        return None;
+    }
+    Some(format!("+1{}", national))
+}
+
 /// Validate that an extracted phone number has a reasonable digit count.
 ///
 /// Phone numbers should have between 10-15 digits (covers most international formats).
@@ -112,4 +164,69 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_extract_phone_keeps_extension() {
+        assert_eq!(
+            extract_phone_from_text("(Office) (555) 123-4567 ext. 42"),
+            Some("(555) 123-4567 ext. 42".to_string())
+        );
+        assert_eq!(
+            extract_phone_from_text("+1 555 123 4567 x89"),
+            Some("+1 555 123 4567 ext. 89".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_drops_extension() {
+        assert_eq!(
+            normalize_phone_e164("+1 555 123 4567 ext. 89"),
+            Some("+15551234567".to_string())
+        );
+        assert_eq!(
+            normalize_phone_e164("(555) 123-4567 x42"),
+            Some("+15551234567".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_us_number_defaults_to_plus_one() {
+        assert_eq!(
+            normalize_phone_e164("(555) 123-4567"),
+            Some("+15551234567".to_string())
+        );
+        assert_eq!(
+            normalize_phone_e164("1-555-123-4567"),
+            Some("+15551234567".to_string())
+        );
+        assert_eq!(
+            normalize_phone_e164("+1 (555) 123-4567"),
+            Some("+15551234567".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_uk_number_keeps_country_code() {
+        assert_eq!(
+            normalize_phone_e164("+44 20 7123 4567"),
+            Some("+442071234567".to_string())
+        );
+        assert_eq!(
+            normalize_phone_e164("+44 (0) 20 7123 4567"),
+            Some("+442071234567".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_ambiguous_bare_number_is_none() {
+        // 11 digits with a national trunk prefix: country unknown
+        assert_eq!(normalize_phone_e164("020 7123 4567"), None);
+        // 10 digits but not a valid NANP area code
+        assert_eq!(normalize_phone_e164("030 123 4567"), None);
+        assert_eq!(normalize_phone_e164("(123) 456-7890"), None);
+        // Also with the NANP country code in front
+        assert_eq!(normalize_phone_e164("1 030 123 4567"), None);
+        // Country code cannot start with 0
+        assert_eq!(normalize_phone_e164("+0 555 123 4567"), None);
+    }
 }
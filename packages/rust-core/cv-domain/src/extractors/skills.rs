@@ -0,0 +1,106 @@
+//! Skills extraction from CV elements
+//!
+//! Responsible for collecting individual skill tokens listed under a skills heading.
+
+use tsx_parser::{extract_element_name, extract_text_content, JSXElement};
+
+/// Maximum length for a single skill token in characters
+const MAX_SKILL_LENGTH: usize = 40;
+
+/// Maximum word count for a single skill token
+const MAX_SKILL_WORDS: usize = 4;
+
+/// Maximum number of skills collected from one CV
+const MAX_SKILLS: usize = 50;
+
+/// Extract skill tokens from the elements following a skills heading.
+///
+/// Looks for an `<h1>`-`<h6>` whose text names a skills section (e.g. "Skills",
+/// "Technical Skills", "Core Competencies") and collects the text of the
+/// `<p>`, `<li>`, `<span>`, `<td>` and `<dd>` elements after it, up to the next
+/// heading. Text is split on commas, pipes, bullets and semicolons, and a
+/// leading "Label:" prefix (e.g. "Languages: Rust, Go") is dropped.
+///
+/// # Returns
+///
+/// Skill tokens in document order, de-duplicated case-insensitively.
+/// Empty if no skills section was found.
+pub fn extract_skills_from_elements(elements: &[&JSXElement]) -> Vec<String> {
+    let mut skills: Vec<String> = Vec::new();
+    let mut in_skills_section = false;
+
+    for element in elements {
+        let tag = extract_element_name(element);
+        let text = extract_text_content(element).join(" ");
+
+        if is_heading_tag(&tag) {
+            in_skills_section = is_skills_heading(&text);
+            continue;
+        }
+        if !in_skills_section || !matches!(tag.as_str(), "p" | "li" | "span" | "td" | "dd") {
+            continue;
+        }
+
+        // Drop a "Label:" prefix such as "Languages:" or "Frameworks:"
+        let list = text.split_once(':').map_or(text.as_str(), |(_, rest)| rest);
+
+        for token in list.split([',', '|', '•', '·', ';', '\n']) {
+            let token = token.trim();
+            if !looks_like_skill(token) {
+                continue;
+            }
+            if !skills.iter().any(|s| s.eq_ignore_ascii_case(token)) {
+                skills.push(token.to_string());
+                if skills.len() == MAX_SKILLS {
+                    return skills;
+                }
+            }
+        }
+    }
+
+    skills
+}
+
+fn is_heading_tag(tag: &str) -> bool {
+    matches!(tag, "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+}
+
+/// Check if heading text names a skills section
+fn is_skills_heading(text: &str) -> bool {
+    let lower = text.trim().to_lowercase();
+    lower.contains("skill")
+        || lower.contains("competenc")
+        || matches!(lower.as_str(), "technologies" | "tech stack" | "tools")
+}
+
+/// Check if a token is short enough to be a skill rather than a sentence
+fn looks_like_skill(token: &str) -> bool {
+    !token.is_empty()
+        && token.chars().count() <= MAX_SKILL_LENGTH
+        && token.split_whitespace().count() <= MAX_SKILL_WORDS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_skills_heading() {
+        assert!(is_skills_heading("Skills"));
+        assert!(is_skills_heading("Technical Skills"));
+        assert!(is_skills_heading("Core Competencies"));
+        assert!(is_skills_heading("Tech Stack"));
+        assert!(!is_skills_heading("Experience"));
+        assert!(!is_skills_heading("Education"));
+    }
+
+    #[test]
+    fn test_looks_like_skill() {
+        assert!(looks_like_skill("Rust"));
+        assert!(looks_like_skill("Amazon Web Services"));
+        assert!(!looks_like_skill(""));
+        assert!(!looks_like_skill(
+            "Led a team of five engineers to deliver the platform"
+        ));
+    }
+}
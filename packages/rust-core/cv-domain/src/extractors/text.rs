@@ -2,7 +2,8 @@
 //!
 //! Responsible for collecting all text content from JSX elements.
 
-use tsx_parser::{extract_text_content, JSXElement};
+use std::collections::HashSet;
+use tsx_parser::{extract_text_content, JSXChild, JSXElement};
 
 /// Collect all text content from elements.
 ///
@@ -22,6 +23,28 @@ pub fn collect_all_text(elements: &[&JSXElement]) -> String {
     all_text
 }
 
+/// Elements that are not a direct child of another element in the list.
+///
+/// `extract_jsx_elements` lists every nested element as well, so collecting
+/// text from all of them repeats each text once per ancestor. Collecting from
+/// the top-level elements yields every text exactly once.
+pub fn top_level_elements<'a>(elements: &[&'a JSXElement<'a>]) -> Vec<&'a JSXElement<'a>> {
+    let nested: HashSet<*const JSXElement> = elements
+        .iter()
+        .flat_map(|element| element.children.iter())
+        .filter_map(|child| match child {
+            JSXChild::Element(child) => Some(&**child as *const JSXElement),
+            _ => None,
+        })
+        .collect();
+
+    elements
+        .iter()
+        .copied()
+        .filter(|element| !nested.contains(&(*element as *const JSXElement)))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     // No tests currently - collect_all_text is tested indirectly through other extractors
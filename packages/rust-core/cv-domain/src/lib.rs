@@ -6,7 +6,8 @@
 //! # Module Organization
 //!
 //! - `metadata` - CVMetadata struct and extraction logic
-//! - `extractors` - Specialized extractors (name, email, phone, location, url)
+//! - `extractors` - Specialized extractors (name, email, phone, location, url, experience,
+//!   certifications)
 //! - `analysis` - Layout detection and ATS analysis
 
 mod analysis;
@@ -15,10 +16,15 @@ mod metadata;
 mod tsx_layout;
 
 // Re-export public API
+pub use analysis::is_reverse_chronological;
+pub use extractors::certifications::{extract_certifications, Certification};
+pub use extractors::experience::{extract_experience_roles, ExperienceEntry};
+pub use extractors::phone::normalize_phone_e164;
 pub use metadata::{extract_metadata, CVMetadata, ExtractionError, FontComplexity, LayoutType};
 
 pub use tsx_layout::{
-    extract_tsx_layout_config_from_document, parse_class_names, Spacing, TSXLayoutConfig,
+    detect_text_direction, extract_tsx_layout_config_from_document, parse_class_names, Spacing,
+    TSXLayoutConfig, TextDirection,
 };
 
 #[cfg(test)]
@@ -94,6 +100,7 @@ mod tests {
         let document = parse_tsx(tsx).unwrap();
         let metadata = extract_metadata(&document).unwrap();
         assert!(metadata.phone.is_some());
+        assert_eq!(metadata.phone_e164, Some("+15551234567".to_string()));
     }
 
     #[test]
@@ -190,6 +197,15 @@ mod tests {
         assert_eq!(metadata.name, Some("Mary-Jane Watson".to_string()));
     }
 
+    #[test]
+    fn test_extract_name_from_string_literal_expression() {
+        let tsx = r#"<div><h1>{"John Doe"}</h1><p>{`Software Engineer`}</p></div>"#;
+        let document = parse_tsx(tsx).unwrap();
+        let metadata = extract_metadata(&document).unwrap();
+        assert_eq!(metadata.name, Some("John Doe".to_string()));
+        assert_eq!(metadata.title, Some("Software Engineer".to_string()));
+    }
+
     #[test]
     fn test_extract_name_with_unicode() {
         let tsx = r#"<h1>José García</h1>"#;
@@ -279,6 +295,21 @@ mod tests {
         let metadata = result.unwrap();
         assert_eq!(metadata.name, None);
         assert_eq!(metadata.email, None);
+        assert_eq!(metadata.word_count, 0);
+        assert_eq!(metadata.char_count, 0);
+        assert_eq!(metadata.reading_time_seconds, 0);
+    }
+
+    #[test]
+    fn test_word_count_counts_nested_text_once() {
+        let tsx =
+            r#"<div><h1>Jane Doe</h1><p>Boston, MA | jane@example.com - <b>Python</b></p></div>"#;
+        let document = parse_tsx(tsx).unwrap();
+        let metadata = extract_metadata(&document).unwrap();
+        // Jane, Doe, Boston, MA, jane@example.com, Python; `|` and `-` are not words
+        assert_eq!(metadata.word_count, 6);
+        assert_eq!(metadata.char_count, 40);
+        assert_eq!(metadata.reading_time_seconds, 2);
     }
 
     #[test]
@@ -325,6 +356,142 @@ mod tests {
         assert!(metadata.is_ats_friendly(), "Should be ATS friendly");
     }
 
+    #[test]
+    fn test_contact_in_tailwind_sidebar() {
+        let tsx = r#"
+            <div className="flex">
+                <aside className="w-1/3">
+                    <p>jane.doe@example.com</p>
+                </aside>
+                <main className="w-2/3">
+                    <h1>Jane Doe</h1>
+                    <h2>Experience</h2>
+                </main>
+            </div>
+        "#;
+        let document = parse_tsx(tsx).unwrap();
+        let metadata = extract_metadata(&document).unwrap();
+        assert_eq!(metadata.email, Some("jane.doe@example.com".to_string()));
+        assert!(metadata.contact_in_sidebar);
+    }
+
+    #[test]
+    fn test_contact_in_header_not_flagged() {
+        let tsx = r#"
+            <div>
+                <div style="display: flex; flex-direction: column">
+                    <h1>Jane Doe</h1>
+                    <p>jane.doe@example.com</p>
+                </div>
+                <div className="flex">
+                    <div className="w-1/3"><h2>Skills</h2></div>
+                    <div className="w-2/3"><h2>Experience</h2></div>
+                </div>
+            </div>
+        "#;
+        let document = parse_tsx(tsx).unwrap();
+        let metadata = extract_metadata(&document).unwrap();
+        assert!(metadata.has_contact_info);
+        assert!(!metadata.contact_in_sidebar);
+    }
+
+    #[test]
+    fn test_experience_role_without_date() {
+        let tsx = r#"
+            <div>
+                <h1>Jane Doe</h1>
+                <h2>Work Experience</h2>
+                <h3>Freelance Illustrator</h3>
+                <p>Self-employed</p>
+                <ul><li>Designed covers for 2019 - 2020 releases</li></ul>
+                <h3>Art Director at Inkwell (Mar 2015 - Jan 2019)</h3>
+                <h2>Education</h2>
+                <h3>BA Fine Arts</h3>
+            </div>
+        "#;
+        let document = parse_tsx(tsx).unwrap();
+        let roles = extract_experience_roles(&document);
+        assert_eq!(roles.len(), 2);
+        assert_eq!(roles[0].title, "Freelance Illustrator");
+        assert_eq!(roles[0].organization.as_deref(), Some("Self-employed"));
+        assert_eq!(roles[0].date_range, None);
+        assert_eq!(roles[1].title, "Art Director");
+        assert_eq!(roles[1].organization.as_deref(), Some("Inkwell"));
+        assert_eq!(roles[1].date_range.as_deref(), Some("Mar 2015 - Jan 2019"));
+    }
+
+    #[test]
+    fn test_out_of_order_experience_is_flagged() {
+        let tsx = r#"
+            <div>
+                <h1>Jane Doe</h1>
+                <p>jane@example.com</p>
+                <h2>Experience</h2>
+                <h3>Staff Engineer | Globex</h3>
+                <p>01/2021 - Present</p>
+                <h3>Junior Developer | Initech</h3>
+                <p>2014 - 2016</p>
+                <h3>Senior Engineer | Acme</h3>
+                <p>Jun 2017 - Dec 2020</p>
+                <h2>Education</h2>
+            </div>
+        "#;
+        let document = parse_tsx(tsx).unwrap();
+        assert_eq!(is_reverse_chronological(&document), Some(false));
+
+        let metadata = extract_metadata(&document).unwrap();
+        assert_eq!(metadata.chronological_order, Some(false));
+        assert!(metadata
+            .ats_suggestions()
+            .iter()
+            .any(|s| s.contains("reverse-chronological")));
+
+        let ordered = parse_tsx(&tsx.replace("2014 - 2016", "2018 - 2020")).unwrap();
+        assert_eq!(is_reverse_chronological(&ordered), Some(true));
+    }
+
+    #[test]
+    fn test_duplicate_education_heading_is_flagged() {
+        let tsx = r#"
+            <div>
+                <h1>Jane Doe</h1>
+                <h2>Education</h2>
+                <p>BS Computer Science</p>
+                <h2>Experience</h2>
+                <h3>Engineer | Acme</h3>
+                <h3>Engineer | Globex</h3>
+                <h2>EDUCATION:</h2>
+                <p>MS Computer Science</p>
+            </div>
+        "#;
+        let document = parse_tsx(tsx).unwrap();
+        let metadata = extract_metadata(&document).unwrap();
+
+        assert_eq!(metadata.duplicate_sections, vec!["Education".to_string()]);
+        assert!(metadata
+            .ats_suggestions()
+            .contains(&"Duplicate section heading 'Education' detected.".to_string()));
+    }
+
+    #[test]
+    fn test_chronological_order_needs_two_dated_entries() {
+        let tsx = r#"
+            <div>
+                <h2>Experience</h2>
+                <h3>Engineer | Acme</h3>
+                <p>2019 - Present</p>
+                <h3>Freelance Illustrator</h3>
+                <p>Self-employed</p>
+            </div>
+        "#;
+        let document = parse_tsx(tsx).unwrap();
+        assert_eq!(is_reverse_chronological(&document), None);
+        assert_eq!(
+            extract_metadata(&document).unwrap().chronological_order,
+            None
+        );
+    }
+
     #[test]
     fn test_ats_suggestions() {
         let tsx = r#"<div><p>Some content</p></div>"#;
@@ -7,12 +7,17 @@ use thiserror::Error;
 use tsx_parser::{extract_jsx_elements, TsxDocument};
 
 use crate::analysis::{
-    detect_clear_sections, detect_font_complexity, detect_layout_type, estimate_page_count,
+    count_chars, count_words, detect_clear_sections, detect_contact_in_sidebar,
+    detect_duplicate_sections, detect_font_complexity, detect_layout_type, estimate_page_count,
+    estimate_reading_time_seconds, reverse_chronological_order,
 };
+use crate::extractors::certifications::Certification;
+use crate::extractors::experience::ExperienceEntry;
 use crate::extractors::{
-    collect_all_text, extract_email_from_text, extract_location_from_text,
-    extract_name_from_elements, extract_phone_from_text, extract_title_from_elements,
-    extract_website_from_text,
+    collect_all_text, extract_certifications_from_elements, extract_email_from_text,
+    extract_experience_from_elements, extract_location_from_text, extract_name_from_elements,
+    extract_phone_from_text, extract_skills_from_elements, extract_title_from_elements,
+    extract_website_from_text, normalize_phone_e164, top_level_elements,
 };
 
 /// Metadata extracted from a CV/resume TSX document.
@@ -51,6 +56,11 @@ pub struct CVMetadata {
     /// Returns `None` if no phone pattern is detected.
     pub phone: Option<String>,
 
+    /// `phone` in canonical E.164 form (e.g. `+15551234567`).
+    /// `None` when the country cannot be inferred confidently.
+    #[serde(default)]
+    pub phone_e164: Option<String>,
+
     /// Location in "City, State" or "City, Country" format.
     /// Returns `None` if no location pattern is detected.
     pub location: Option<String>,
@@ -59,6 +69,21 @@ pub struct CVMetadata {
     /// Returns `None` if no URL pattern is detected.
     pub website: Option<String>,
 
+    /// Skill tokens listed under a "Skills" heading, in document order.
+    /// Empty if no skills section is detected.
+    #[serde(default)]
+    pub skills: Vec<String>,
+
+    /// Roles listed under the experience section, in document order.
+    /// Empty if no experience section is detected.
+    #[serde(default)]
+    pub experience: Vec<ExperienceEntry>,
+
+    /// Certifications and licenses with their issue year, in document order.
+    /// Empty if no certifications section is detected.
+    #[serde(default)]
+    pub certifications: Vec<Certification>,
+
     // Document Characteristics
     /// Detected layout structure of the CV.
     /// Based on heuristics analyzing component count and structure.
@@ -76,6 +101,34 @@ pub struct CVMetadata {
     /// Critical for ATS (Applicant Tracking System) compatibility.
     pub has_contact_info: bool,
 
+    /// Whether the detected email or phone sits in a narrow sidebar column.
+    /// Many ATS parsers drop or scramble side columns, so this is a risk.
+    #[serde(default)]
+    pub contact_in_sidebar: bool,
+
+    /// Whether dated experience entries run most recent first.
+    /// `None` when fewer than two entries have a start year.
+    #[serde(default)]
+    pub chronological_order: Option<bool>,
+
+    /// Section headings that appear more than once, as first written.
+    /// ATS parsers may attribute content to the wrong copy.
+    #[serde(default)]
+    pub duplicate_sections: Vec<String>,
+
+    // Length
+    /// Number of words in the CV text, ignoring standalone punctuation.
+    #[serde(default)]
+    pub word_count: usize,
+
+    /// Number of characters in the CV text, excluding whitespace.
+    #[serde(default)]
+    pub char_count: usize,
+
+    /// Estimated time to read the CV text at an average reading speed.
+    #[serde(default)]
+    pub reading_time_seconds: usize,
+
     /// Whether the CV has clear section headings (h2/h3 tags).
     /// Important for ATS parsing and document structure.
     pub has_clear_sections: bool,
@@ -229,6 +282,23 @@ impl CVMetadata {
             suggestions.push("Add contact information (email or phone number)".to_string());
         }
 
+        if self.contact_in_sidebar {
+            suggestions.push(
+                "Move contact information out of the sidebar into the main header".to_string(),
+            );
+        }
+
+        for heading in &self.duplicate_sections {
+            suggestions.push(format!("Duplicate section heading '{}' detected.", heading));
+        }
+
+        if self.chronological_order == Some(false) {
+            suggestions.push(
+                "List experience in reverse-chronological order (most recent role first)"
+                    .to_string(),
+            );
+        }
+
         if !self.has_clear_sections {
             suggestions.push("Use clear section headings (h2 or h3 tags)".to_string());
         }
@@ -330,12 +400,22 @@ pub fn extract_metadata(document: &TsxDocument) -> Result<CVMetadata, Extraction
         title: None,
         email: None,
         phone: None,
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: elements.len(),
         has_contact_info: false,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: false,
         font_complexity: FontComplexity::Simple,
     };
@@ -350,9 +430,20 @@ pub fn extract_metadata(document: &TsxDocument) -> Result<CVMetadata, Extraction
     let all_text = collect_all_text(&elements);
     metadata.email = extract_email_from_text(&all_text);
     metadata.phone = extract_phone_from_text(&all_text);
+    metadata.phone_e164 = metadata.phone.as_deref().and_then(normalize_phone_e164);
     metadata.location = extract_location_from_text(&all_text);
     metadata.website = extract_website_from_text(&all_text);
 
+    // Extract skill tokens listed under a skills heading
+    metadata.skills = extract_skills_from_elements(&elements);
+
+    // Pair experience role headings with their organization and dates
+    metadata.experience = extract_experience_from_elements(&elements);
+    metadata.chronological_order = reverse_chronological_order(&metadata.experience);
+
+    // List certifications with the year they were issued
+    metadata.certifications = extract_certifications_from_elements(&elements);
+
     // Detect layout type from component structure
     metadata.layout_type = detect_layout_type(&elements);
 
@@ -362,8 +453,20 @@ pub fn extract_metadata(document: &TsxDocument) -> Result<CVMetadata, Extraction
 
     // Set ATS hints
     metadata.has_contact_info = metadata.email.is_some() || metadata.phone.is_some();
+    let contact: Vec<&str> = [metadata.email.as_deref(), metadata.phone.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    metadata.contact_in_sidebar = detect_contact_in_sidebar(&elements, &contact);
     metadata.has_clear_sections = detect_clear_sections(&elements);
+    metadata.duplicate_sections = detect_duplicate_sections(&elements);
     metadata.font_complexity = detect_font_complexity(document);
 
+    // Measure length from each text once, not once per enclosing element
+    let document_text = collect_all_text(&top_level_elements(&elements));
+    metadata.word_count = count_words(&document_text);
+    metadata.char_count = count_chars(&document_text);
+    metadata.reading_time_seconds = estimate_reading_time_seconds(metadata.word_count);
+
     Ok(metadata)
 }
@@ -5,9 +5,12 @@
 
 use serde::{Deserialize, Serialize};
 use tsx_parser::{
-    extract_jsx_elements, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, TsxDocument,
+    extract_jsx_elements, JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElement,
+    TsxDocument,
 };
 
+use crate::extractors::collect_all_text;
+
 /// Layout configuration extracted from TSX root element
 ///
 /// This configuration is extracted from Tailwind CSS classes on the root element
@@ -15,7 +18,7 @@ use tsx_parser::{
 ///
 /// # Example
 /// ```
-/// use cv_domain::{TSXLayoutConfig, Spacing};
+/// use cv_domain::{TSXLayoutConfig, Spacing, TextDirection};
 ///
 /// let config = TSXLayoutConfig {
 ///     max_width: Some(896.0),  // max-w-4xl in points
@@ -26,6 +29,7 @@ use tsx_parser::{
 ///         left: 32.0,
 ///     },
 ///     background_color: Some("#ffffff".to_string()),
+///     direction: TextDirection::Ltr,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -39,6 +43,21 @@ pub struct TSXLayoutConfig {
 
     /// Background color (from bg-* classes)
     pub background_color: Option<String>,
+
+    /// Base text direction (from the root `dir` attribute, else detected from content)
+    #[serde(default)]
+    pub direction: TextDirection,
+}
+
+/// Base direction of the document's text (CSS `direction`)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TextDirection {
+    /// Left-to-right (Latin, Cyrillic, CJK, ...)
+    #[default]
+    Ltr,
+    /// Right-to-left (Hebrew, Arabic)
+    Rtl,
 }
 
 /// Spacing values for padding/margins
@@ -152,21 +171,87 @@ pub fn extract_tsx_layout_config_from_document(document: &TsxDocument) -> TSXLay
     // Extract className from root element
     let root_element = elements[0];
 
-    for attr in &root_element.opening_element.attributes {
-        if let JSXAttributeItem::Attribute(jsx_attr) = attr {
-            // Check if this is the className attribute
-            if let JSXAttributeName::Identifier(ident) = &jsx_attr.name {
-                if ident.name.as_str() == "className" {
-                    // Extract the className value
-                    if let Some(JSXAttributeValue::StringLiteral(class_str)) = &jsx_attr.value {
-                        return parse_class_names(class_str.value.as_ref());
-                    }
-                }
+    let mut config = root_string_attribute(root_element, "className")
+        .map(parse_class_names)
+        .unwrap_or_default();
+
+    // An explicit dir attribute always wins over the content heuristic
+    config.direction = match root_string_attribute(root_element, "dir") {
+        Some(dir) if dir.eq_ignore_ascii_case("rtl") => TextDirection::Rtl,
+        Some(dir) if dir.eq_ignore_ascii_case("ltr") => TextDirection::Ltr,
+        _ => detect_text_direction(&elements),
+    };
+
+    config
+}
+
+/// Value of a string-literal attribute on an element
+fn root_string_attribute<'a>(element: &'a JSXElement, name: &str) -> Option<&'a str> {
+    element
+        .opening_element
+        .attributes
+        .iter()
+        .find_map(|attr| match attr {
+            JSXAttributeItem::Attribute(jsx_attr) => match (&jsx_attr.name, &jsx_attr.value) {
+                (
+                    JSXAttributeName::Identifier(ident),
+                    Some(JSXAttributeValue::StringLiteral(value)),
+                ) if ident.name.as_str() == name => Some(value.value.as_ref()),
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+/// Detect the base direction from the letters in the document
+///
+/// Counts letters in the Hebrew and Arabic Unicode blocks against all other
+/// letters; the document is RTL when RTL letters are the strict majority.
+/// Digits, punctuation and whitespace are neutral and not counted.
+///
+/// # Example
+/// ```
+/// use cv_domain::{detect_text_direction, TextDirection};
+/// use tsx_parser::{extract_jsx_elements, parse_tsx};
+///
+/// let document = parse_tsx("<div><h1>سارة أحمد</h1><p>مهندسة برمجيات</p></div>")?;
+/// let elements = extract_jsx_elements(&document);
+/// assert_eq!(detect_text_direction(&elements), TextDirection::Rtl);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn detect_text_direction(elements: &[&JSXElement]) -> TextDirection {
+    let (rtl, ltr) = collect_all_text(elements)
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .fold((0usize, 0usize), |(rtl, ltr), c| {
+            if is_rtl_char(c) {
+                (rtl + 1, ltr)
+            } else {
+                (rtl, ltr + 1)
             }
-        }
+        });
+
+    if rtl > ltr {
+        TextDirection::Rtl
+    } else {
+        TextDirection::Ltr
     }
+}
 
-    TSXLayoutConfig::default()
+/// Check if a character belongs to a right-to-left script block
+fn is_rtl_char(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0590..=0x05FF // Hebrew
+            | 0x0600..=0x06FF // Arabic
+            | 0x0700..=0x074F // Syriac
+            | 0x0750..=0x077F // Arabic Supplement
+            | 0x0780..=0x07BF // Thaana
+            | 0x08A0..=0x08FF // Arabic Extended-A
+            | 0xFB1D..=0xFB4F // Hebrew presentation forms
+            | 0xFB50..=0xFDFF // Arabic Presentation Forms-A
+            | 0xFE70..=0xFEFF // Arabic Presentation Forms-B
+    )
 }
 
 /// Parse Tailwind CSS class names into layout configuration
@@ -307,6 +392,46 @@ mod tests {
         assert_eq!(config.background_color, Some("white".to_string()));
     }
 
+    fn direction_of(tsx: &str) -> TextDirection {
+        let document = tsx_parser::parse_tsx(tsx).unwrap();
+        extract_tsx_layout_config_from_document(&document).direction
+    }
+
+    #[test]
+    fn test_direction_detected_from_arabic_majority() {
+        let tsx = r#"<div className="p-8">
+            <h1>سارة أحمد</h1>
+            <p>مهندسة برمجيات أولى في شركة التقنية</p>
+            <p>React, Rust</p>
+        </div>"#;
+        assert_eq!(direction_of(tsx), TextDirection::Rtl);
+    }
+
+    #[test]
+    fn test_direction_stays_ltr_for_latin_majority() {
+        let tsx = r#"<div>
+            <h1>Sarah Cohen</h1>
+            <p>Senior software engineer, fluent in Hebrew (עברית) and Arabic (العربية)</p>
+        </div>"#;
+        assert_eq!(direction_of(tsx), TextDirection::Ltr);
+    }
+
+    #[test]
+    fn test_explicit_dir_attribute_wins() {
+        assert_eq!(
+            direction_of(r#"<div dir="ltr"><p>مهندسة برمجيات</p></div>"#),
+            TextDirection::Ltr
+        );
+        assert_eq!(
+            direction_of(r#"<div dir="rtl" className="p-8"><p>Software engineer</p></div>"#),
+            TextDirection::Rtl
+        );
+        assert_eq!(
+            direction_of(r#"<div dir="auto"><p>מהנדסת תוכנה</p></div>"#),
+            TextDirection::Rtl
+        );
+    }
+
     #[test]
     fn test_spacing_helpers() {
         let uniform = Spacing::uniform(10.0);
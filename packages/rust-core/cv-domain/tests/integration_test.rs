@@ -2,7 +2,10 @@
 //!
 //! These tests verify end-to-end functionality of parsing TSX and extracting CV metadata.
 
-use cv_domain::{extract_metadata, LayoutType};
+use cv_domain::{
+    extract_certifications, extract_experience_roles, extract_metadata, Certification,
+    ExperienceEntry, LayoutType,
+};
 use tsx_parser::parse_tsx;
 
 #[test]
@@ -152,6 +155,27 @@ fn test_integration_smoke_fixture_04() {
     println!("  Name: {:?}", metadata.name);
 }
 
+#[test]
+fn test_integration_length_of_simple_cv() {
+    let tsx =
+        include_str!("../../../../test-fixtures/tsx-samples/single-page/03-minimal-simple.tsx");
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let metadata = extract_metadata(&document).expect("Failed to extract metadata");
+
+    assert!(
+        (150..=600).contains(&metadata.word_count),
+        "one-page CV word count: {}",
+        metadata.word_count
+    );
+    assert!(metadata.char_count > metadata.word_count * 3);
+    // Reading a one-page CV takes one to three minutes
+    assert!(
+        (30..=180).contains(&metadata.reading_time_seconds),
+        "reading time: {}s",
+        metadata.reading_time_seconds
+    );
+}
+
 #[test]
 fn test_all_fixtures_parse_successfully() {
     let fixtures = vec![
@@ -188,3 +212,101 @@ fn test_all_fixtures_parse_successfully() {
         println!("✓ Fixture {} parsed and extracted successfully", name);
     }
 }
+
+#[test]
+fn test_integration_contact_in_left_sidebar() {
+    let tsx =
+        include_str!("../../../../test-fixtures/tsx-samples/single-page/02-two-column-modern.tsx");
+
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let metadata = extract_metadata(&document).expect("Failed to extract metadata");
+
+    // Contact details are found even though the sidebar precedes the main column
+    assert_eq!(metadata.email.as_deref(), Some("sarah.chen@email.com"));
+    assert!(metadata.has_contact_info);
+
+    // ...but their sidebar placement is flagged as an ATS risk
+    assert!(metadata.contact_in_sidebar);
+    assert!(metadata
+        .ats_suggestions()
+        .iter()
+        .any(|s| s.contains("sidebar")));
+}
+
+#[test]
+fn test_integration_experience_roles() {
+    let tsx = include_str!("../../../../test-fixtures/tsx-samples/benchmarks/cv_small.tsx");
+
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let metadata = extract_metadata(&document).expect("Failed to extract metadata");
+
+    assert_eq!(
+        metadata.experience,
+        [ExperienceEntry {
+            title: "Software Engineer".to_string(),
+            organization: Some("Tech Corp".to_string()),
+            date_range: Some("2020 - Present".to_string()),
+        }]
+    );
+    assert_eq!(extract_experience_roles(&document), metadata.experience);
+}
+
+#[test]
+fn test_integration_experience_roles_skip_other_headings() {
+    let tsx = include_str!("../../../../test-fixtures/tsx-samples/benchmarks/cv_medium.tsx");
+
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let metadata = extract_metadata(&document).expect("Failed to extract metadata");
+
+    // Education and project headings are not mistaken for roles
+    let titles: Vec<&str> = metadata
+        .experience
+        .iter()
+        .map(|e| e.title.as_str())
+        .collect();
+    assert_eq!(
+        titles,
+        [
+            "Senior Software Engineer",
+            "Software Engineer",
+            "Junior Developer"
+        ]
+    );
+}
+
+#[test]
+fn test_integration_certifications() {
+    let tsx = include_str!("../../../../test-fixtures/tsx-samples/benchmarks/cv_medium.tsx");
+
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let metadata = extract_metadata(&document).expect("Failed to extract metadata");
+
+    assert_eq!(
+        metadata.certifications,
+        [
+            Certification {
+                name: "AWS Certified Solutions Architect - Professional".to_string(),
+                year: Some(2023),
+            },
+            Certification {
+                name: "Certified Kubernetes Administrator (CKA)".to_string(),
+                year: Some(2022),
+            },
+        ]
+    );
+    assert_eq!(extract_certifications(&document), metadata.certifications);
+
+    // Entries without a year are kept; a CV without the section yields none
+    let tsx = r#"<div><h2>Licenses &amp; Certifications</h2><p>PMP</p></div>"#;
+    let certs = extract_certifications(&parse_tsx(tsx).expect("Failed to parse TSX"));
+    assert_eq!(
+        certs,
+        [Certification {
+            name: "PMP".to_string(),
+            year: None,
+        }]
+    );
+
+    let tsx = r#"<div><h2>Experience</h2><p>Certified Scrum Master (2020)</p></div>"#;
+    assert!(extract_certifications(&parse_tsx(tsx).expect("Failed to parse TSX")).is_empty());
+}
@@ -10,7 +10,7 @@ mod to_unicode;
 
 use lopdf::{Document, ObjectId};
 use std::collections::BTreeMap;
-use ttf_parser::Face;
+use ttf_parser::{Face, Permissions};
 
 pub use cid_mapping::CidToGidMap;
 
@@ -28,6 +28,18 @@ pub enum EmbedError {
 
     #[error("Font embedding not supported for this font type")]
     UnsupportedFont,
+
+    #[error("Font '{0}' does not permit embedding (OS/2 fsType: restricted license)")]
+    LicenseRestricted(String),
+}
+
+/// Options controlling font embedding
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmbedOptions {
+    /// Embed fonts whose OS/2 `fsType` marks them as restricted-license
+    ///
+    /// Only enable this when the user holds a license that permits embedding.
+    pub allow_restricted: bool,
 }
 
 /// Embedded font information
@@ -66,7 +78,7 @@ pub struct EmbeddedFont {
 /// # PDF Structure Created
 /// ```text
 /// Type 0 Font (top-level)
-///   |- BaseFont: /FontName-Weight[-Italic]
+///   |- BaseFont: font's PostScript name (or /FontName-Weight[-Italic] if absent)
 ///   |- Encoding: Identity-H
 ///   |- ToUnicode: CMap stream
 ///   +- DescendantFonts: [CIDFont]
@@ -126,11 +138,53 @@ pub fn embed_truetype_font(
     weight: u16,
     is_italic: bool,
     cid_mapping: Option<&BTreeMap<u32, u16>>,
+) -> Result<EmbeddedFont, EmbedError> {
+    embed_truetype_font_with_options(
+        doc,
+        font_bytes,
+        font_name,
+        weight,
+        is_italic,
+        cid_mapping,
+        EmbedOptions::default(),
+    )
+}
+
+/// Whether a font's OS/2 `fsType` restricts embedding
+///
+/// True for "Restricted License embedding" (bit 1 set without the preview &
+/// print or editable exceptions). Unparseable fonts are not restricted; they
+/// fail to embed for other reasons.
+pub fn is_license_restricted(font_bytes: &[u8]) -> bool {
+    Face::parse(font_bytes, 0).is_ok_and(|face| face.permissions() == Some(Permissions::Restricted))
+}
+
+/// Embeds a TrueType font with explicit [`EmbedOptions`]
+///
+/// Same as [`embed_truetype_font`], which uses the default options.
+///
+/// # License Check
+/// Fonts whose OS/2 `fsType` is "Restricted License embedding" (bit 1 set
+/// without the preview & print or editable exceptions) are rejected with
+/// [`EmbedError::LicenseRestricted`] unless `options.allow_restricted` is set.
+pub fn embed_truetype_font_with_options(
+    doc: &mut Document,
+    font_bytes: &[u8],
+    font_name: &str,
+    weight: u16,
+    is_italic: bool,
+    cid_mapping: Option<&BTreeMap<u32, u16>>,
+    options: EmbedOptions,
 ) -> Result<EmbeddedFont, EmbedError> {
     // Parse font to extract metrics
     let face =
         Face::parse(font_bytes, 0).map_err(|e| EmbedError::ParseError(format!("{:?}", e)))?;
 
+    // Refuse to embed restricted-license fonts unless explicitly allowed
+    if !options.allow_restricted && is_license_restricted(font_bytes) {
+        return Err(EmbedError::LicenseRestricted(font_name.to_string()));
+    }
+
     // Determine if this is a subsetted font
     let is_subsetted = cid_mapping.is_some();
 
@@ -146,8 +200,9 @@ pub fn embed_truetype_font(
         }
     };
 
-    // Generate PostScript font name
-    let base_ps_name = pdf_objects::generate_postscript_name(font_name, weight, is_italic);
+    // Prefer the font's own PostScript name; the caller's family may be a CSS alias
+    let base_ps_name = crate::truetype::find_postscript_name(&face)
+        .unwrap_or_else(|| pdf_objects::generate_postscript_name(font_name, weight, is_italic));
 
     // Add subset prefix if font is subsetted (e.g., "ABCDEF+FontName")
     let ps_font_name = if is_subsetted {
@@ -226,6 +281,86 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_ligature_glyph_extracts_as_letters() {
+        let face = Face::parse(ROBOTO_TTF, 0).unwrap();
+        assert!(
+            face.glyph_index('\u{FB01}').is_some(),
+            "Roboto should have an fi ligature"
+        );
+
+        let mut doc = Document::with_version("1.7");
+        let embedded =
+            embed_truetype_font(&mut doc, ROBOTO_TTF, "Roboto", 400, false, None).unwrap();
+
+        let font = doc.get_dictionary(embedded.font_id).unwrap();
+        let to_unicode_id = font.get(b"ToUnicode").unwrap().as_reference().unwrap();
+        let cmap = doc
+            .get_object(to_unicode_id)
+            .and_then(|obj| obj.as_stream())
+            .unwrap()
+            .decompressed_content()
+            .unwrap();
+        let cmap = String::from_utf8(cmap).unwrap();
+        assert!(cmap.contains("<FB01> <00660069>\n"));
+    }
+
+    /// Copy of `font` with the OS/2 fsType field overwritten
+    fn with_fs_type(font: &[u8], fs_type: u16) -> Vec<u8> {
+        let mut bytes = font.to_vec();
+        let num_tables = u16::from_be_bytes([bytes[4], bytes[5]]) as usize;
+        let os2_offset = (0..num_tables)
+            .map(|i| 12 + i * 16)
+            .find(|&record| &bytes[record..record + 4] == b"OS/2")
+            .map(|record| {
+                u32::from_be_bytes(bytes[record + 8..record + 12].try_into().unwrap()) as usize
+            })
+            .expect("font should have an OS/2 table");
+
+        // fsType is at offset 8 in the OS/2 table
+        bytes[os2_offset + 8..os2_offset + 10].copy_from_slice(&fs_type.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn test_embed_restricted_font_rejected() {
+        let restricted = with_fs_type(ROBOTO_TTF, 0x0002);
+        let mut doc = Document::with_version("1.7");
+
+        let result = embed_truetype_font(&mut doc, &restricted, "Restricted", 400, false, None);
+        assert!(
+            matches!(result, Err(EmbedError::LicenseRestricted(ref name)) if name == "Restricted")
+        );
+    }
+
+    #[test]
+    fn test_embed_restricted_font_with_override() {
+        let restricted = with_fs_type(ROBOTO_TTF, 0x0002);
+        let mut doc = Document::with_version("1.7");
+
+        let result = embed_truetype_font_with_options(
+            &mut doc,
+            &restricted,
+            "Restricted",
+            400,
+            false,
+            None,
+            EmbedOptions {
+                allow_restricted: true,
+            },
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_embed_preview_and_print_font_allowed() {
+        let preview = with_fs_type(ROBOTO_TTF, 0x0004);
+        let mut doc = Document::with_version("1.7");
+
+        let result = embed_truetype_font(&mut doc, &preview, "Preview", 400, false, None);
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_embed_invalid_font() {
         let mut doc = Document::with_version("1.7");
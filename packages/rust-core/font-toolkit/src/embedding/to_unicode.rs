@@ -8,10 +8,26 @@ use std::fmt::Write as FmtWrite;
 use super::compression::compress_bytes;
 use super::EmbedError;
 
+/// Latin ligature presentation forms and the letters they stand for
+const LIGATURE_DECOMPOSITIONS: &[(u32, &str)] = &[
+    (0xFB00, "ff"),
+    (0xFB01, "fi"),
+    (0xFB02, "fl"),
+    (0xFB03, "ffi"),
+    (0xFB04, "ffl"),
+    (0xFB05, "\u{17F}t"),
+    (0xFB06, "st"),
+];
+
+/// Private Use Area, where icon and decorative glyphs have no standard meaning
+const PRIVATE_USE_AREA: std::ops::RangeInclusive<u32> = 0xE000..=0xF8FF;
+
 /// Creates ToUnicode CMap stream for text extraction
 ///
 /// For Identity-H encoding, CID = Unicode codepoint, so the mapping is
-/// effectively an identity mapping for all characters in the font.
+/// effectively an identity mapping for all characters in the font. Glyphs
+/// whose codepoint carries no extractable text get a best-effort entry
+/// instead (see [`unicode_text`]), so extracted text has no silent holes.
 ///
 /// # Arguments
 /// * `doc` - PDF document to add stream to
@@ -51,9 +67,13 @@ fn generate_cmap_content(cid_to_gid: &BTreeMap<u32, u16>) -> String {
     if !cids.is_empty() {
         let _ = writeln!(&mut cmap, "{} beginbfchar", cids.len());
 
-        for &cid in &cids {
-            // For Identity-H: CID = Unicode, so mapping is <CID> <CID>
-            let _ = writeln!(&mut cmap, "<{:04X}> <{:04X}>", cid, cid);
+        for &&cid in &cids {
+            // For Identity-H: CID = Unicode, so mapping is <CID> <UTF-16BE text>
+            let _ = write!(&mut cmap, "<{:04X}> <", cid);
+            for unit in unicode_text(cid).encode_utf16() {
+                let _ = write!(&mut cmap, "{:04X}", unit);
+            }
+            cmap.push_str(">\n");
         }
 
         cmap.push_str("endbfchar\n");
@@ -65,6 +85,21 @@ fn generate_cmap_content(cid_to_gid: &BTreeMap<u32, u16>) -> String {
     cmap
 }
 
+/// Text a CID stands for when extracted
+///
+/// Ligature presentation forms decompose to their letters ("ﬁ" → "fi"), and
+/// private-use glyphs and invalid codepoints map to U+FFFD; every other CID
+/// is its own codepoint.
+fn unicode_text(cid: u32) -> String {
+    if let Some((_, letters)) = LIGATURE_DECOMPOSITIONS.iter().find(|(lig, _)| *lig == cid) {
+        return (*letters).to_string();
+    }
+    match char::from_u32(cid) {
+        Some(ch) if !PRIVATE_USE_AREA.contains(&cid) => ch.to_string(),
+        _ => char::REPLACEMENT_CHARACTER.to_string(),
+    }
+}
+
 /// ToUnicode CMap header (PostScript)
 const CMAP_HEADER: &str = "/CIDInit /ProcSet findresource begin\n\
 12 dict begin\n\
@@ -105,6 +140,20 @@ mod tests {
         assert!(content.contains("endcmap"));
     }
 
+    #[test]
+    fn test_unmapped_glyphs_get_fallback_text() {
+        let mut mapping = BTreeMap::new();
+        mapping.insert(0xFB03, 1u16); // ﬃ
+        mapping.insert(0xE001, 2u16); // icon in the Private Use Area
+        mapping.insert(0xD800, 3u16); // lone surrogate
+
+        let content = generate_cmap_content(&mapping);
+
+        assert!(content.contains("<FB03> <006600660069>\n"));
+        assert!(content.contains("<E001> <FFFD>\n"));
+        assert!(content.contains("<D800> <FFFD>\n"));
+    }
+
     #[test]
     fn test_generate_cmap_content_empty_mapping() {
         let mapping = BTreeMap::new();
@@ -22,6 +22,9 @@
 //! Reduces TrueType font file sizes by extracting only the glyphs used in a document.
 //! Achieves 60-90% size reduction while maintaining full font functionality.
 //!
+//! ## `normalize`
+//! Normalizes text to Unicode NFC so composed glyphs are found consistently.
+//!
 //! ## `woff`
 //! Decompresses WOFF (Web Open Font Format) files to TrueType format using zlib.
 //!
@@ -93,6 +96,7 @@ pub enum FontStyle {
 
 pub mod embedding;
 pub mod mapper;
+pub mod normalize;
 pub mod optimizer;
 #[cfg(feature = "advanced-fonts")]
 pub mod subsetter;
@@ -101,13 +105,23 @@ pub mod woff;
 pub mod woff2;
 
 // Re-export commonly used types for convenience
-pub use embedding::{embed_truetype_font, EmbedError, EmbeddedFont};
+pub use embedding::{
+    embed_truetype_font, embed_truetype_font_with_options, is_license_restricted, EmbedError,
+    EmbedOptions, EmbeddedFont,
+};
 pub use mapper::{
-    is_google_font, map_web_safe_font, select_font_from_fallback_chain, select_font_variant,
-    GOOGLE_FONTS,
+    is_google_font, map_web_safe_font, resolve_with_trace, select_font_from_fallback_chain,
+    select_font_variant, GOOGLE_FONTS,
 };
-pub use optimizer::strip_hinting_tables;
+pub use normalize::to_nfc;
+pub use optimizer::{ensure_post_table, strip_hinting_tables, strip_optional_tables};
 #[cfg(feature = "advanced-fonts")]
-pub use subsetter::{subset_font_core, SubsetError, SubsetMetrics};
+pub use subsetter::{
+    collect_composite_components, find_broken_cff_glyph, glyph_components, subset_font_core,
+    subset_fonts, BatchSubsetResult, SubsetError, SubsetMetrics,
+};
+pub use truetype::{
+    read_decoration_metrics, read_names, DecorationMetrics, FontNames, NameTableError,
+};
 pub use woff::{decompress_woff, WoffError};
 pub use woff2::{decompress_woff2, Woff2Error};
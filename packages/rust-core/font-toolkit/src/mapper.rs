@@ -125,7 +125,7 @@ pub fn map_web_safe_font(font_family: &str) -> &'static str {
 ///
 /// # Arguments
 /// * `font_chain` - CSS font-family value (e.g., "CustomFont, Roboto, Arial, sans-serif")
-/// * `available_fonts` - Set of available fonts (for Google Fonts support, currently unused in MVP)
+/// * `available_fonts` - Families whose font data can be embedded (case-insensitive)
 ///
 /// # Returns
 /// PDF font base name selected from the chain
@@ -133,46 +133,112 @@ pub fn map_web_safe_font(font_family: &str) -> &'static str {
 /// # Algorithm
 /// 1. Split font chain by commas
 /// 2. For each font in order:
+///    - Use it as-is if it is in `available_fonts`
+///    - Skip it if it is a Google Font that has not been fetched
 ///    - Check if it maps to a web-safe font
 ///    - Check if it's a generic family (sans-serif, serif, monospace)
 ///    - Skip if unsupported
-/// 3. Fall back to Helvetica if no match found (with console warning)
+/// 3. Fall back to Helvetica if no match found
 pub fn select_font_from_fallback_chain(
     font_chain: &str,
-    _available_fonts: &HashSet<String>, // Reserved for Google Fonts
+    available_fonts: &HashSet<String>,
 ) -> String {
-    let fonts: Vec<&str> = font_chain
+    select_font(font_chain, available_fonts, None)
+}
+
+/// Maps a lowercase CSS generic family to its PDF Standard 14 font
+fn map_generic_family(font_lower: &str) -> Option<&'static str> {
+    match font_lower {
+        "sans-serif" => Some("Helvetica"),
+        "serif" => Some("Times-Roman"),
+        "monospace" => Some("Courier"),
+        _ => None,
+    }
+}
+
+/// Resolves a CSS font-family chain and records why each candidate was used or skipped
+///
+/// Diagnostic counterpart of [`select_font_from_fallback_chain`]: it picks the
+/// same font and also returns the reasoning behind the choice.
+///
+/// # Arguments
+/// * `css_family_chain` - CSS font-family value (e.g., "FooBrand, Roboto, Arial")
+/// * `available` - Families whose font data can be embedded (case-insensitive)
+///
+/// # Returns
+/// The chosen font name and one trace line per candidate considered, in order
+///
+/// # Example
+/// ```
+/// use font_toolkit::mapper::resolve_with_trace;
+/// use std::collections::HashSet;
+///
+/// let (font, trace) = resolve_with_trace("Lato, Georgia", &HashSet::new());
+/// assert_eq!(font, "Times-Roman");
+/// assert_eq!(trace[0], "Lato: skipped (not available)");
+/// assert_eq!(trace[1], "Georgia: mapped to Times-Roman");
+/// ```
+pub fn resolve_with_trace(
+    css_family_chain: &str,
+    available: &HashSet<String>,
+) -> (String, Vec<String>) {
+    let mut trace = Vec::new();
+    let font = select_font(css_family_chain, available, Some(&mut trace));
+    (font, trace)
+}
+
+/// Walks a font-family chain, optionally recording one line per candidate in `trace`
+fn select_font(
+    font_chain: &str,
+    available: &HashSet<String>,
+    mut trace: Option<&mut Vec<String>>,
+) -> String {
+    let mut note = |line: String| {
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.push(line);
+        }
+    };
+
+    for font in font_chain
         .split(',')
         .map(|s| s.trim().trim_matches('"').trim_matches('\''))
-        .collect();
-
-    for font in fonts {
+        .filter(|s| !s.is_empty())
+    {
         let font_lower = font.to_lowercase();
 
-        // Check if it's a Google Font
+        if available.iter().any(|f| f.eq_ignore_ascii_case(font)) {
+            note(format!("{}: chosen (available)", font));
+            return font.to_string();
+        }
+
+        // Google Fonts are only usable once fetched into `available`
         if is_google_font(font) {
-            // TODO: This will trigger font fetching and embedding in production
-            // For now, continue to next font in fallback chain
+            note(format!("{}: skipped (not available)", font));
             continue;
         }
 
         // Check web-safe fonts
         let mapped = map_web_safe_font(font);
-        if mapped != "Helvetica" || font_lower.contains("helvetica") || font_lower.contains("arial")
+        if mapped != "Helvetica" || matches!(font_lower.as_str(), "arial" | "helvetica" | "verdana")
         {
+            note(format!("{}: mapped to {}", font, mapped));
             return mapped.to_string();
         }
 
-        // Check generic font families
-        match font_lower.as_str() {
-            "sans-serif" => return "Helvetica".to_string(),
-            "serif" => return "Times-Roman".to_string(),
-            "monospace" => return "Courier".to_string(),
-            _ => continue, // Try next font in chain
+        // Check generic font families, otherwise try next font in chain
+        if let Some(generic) = map_generic_family(&font_lower) {
+            note(format!("{}: generic family, mapped to {}", font, generic));
+            return generic.to_string();
         }
+
+        note(format!(
+            "{}: skipped (not available, not a Google font)",
+            font
+        ));
     }
 
     // Ultimate fallback (AC9)
+    note("no usable family: falling back to Helvetica".to_string());
     "Helvetica".to_string()
 }
 
@@ -380,6 +446,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_with_trace_skips_unavailable_brand_font() {
+        let available: HashSet<String> = ["Roboto".to_string()].into_iter().collect();
+        let (font, trace) = resolve_with_trace("FooBrand, Roboto, Arial", &available);
+
+        assert_eq!(font, "Roboto");
+        assert_eq!(
+            trace,
+            vec![
+                "FooBrand: skipped (not available, not a Google font)",
+                "Roboto: chosen (available)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_trace_falls_back_to_helvetica() {
+        let (font, trace) = resolve_with_trace("'Open Sans', FooBrand", &HashSet::new());
+
+        assert_eq!(font, "Helvetica");
+        assert_eq!(trace.len(), 3);
+        assert_eq!(trace[0], "Open Sans: skipped (not available)");
+        assert!(trace[2].contains("falling back to Helvetica"));
+    }
+
+    #[test]
+    fn test_select_font_from_fallback_chain_matches_trace_resolution() {
+        let available: HashSet<String> = ["Lato".to_string()].into_iter().collect();
+        for chain in [
+            "Verdana, serif",
+            "Roboto, Lato, Arial",
+            "FooBrand, monospace",
+        ] {
+            assert_eq!(
+                select_font_from_fallback_chain(chain, &available),
+                resolve_with_trace(chain, &available).0
+            );
+        }
+        assert_eq!(
+            select_font_from_fallback_chain("Verdana, serif", &available),
+            "Helvetica"
+        );
+        assert_eq!(
+            select_font_from_fallback_chain("Roboto, Lato, Arial", &available),
+            "Lato"
+        );
+    }
+
     #[test]
     fn test_select_font_variant_helvetica_normal() {
         assert_eq!(
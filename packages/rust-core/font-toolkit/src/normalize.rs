@@ -0,0 +1,47 @@
+//! Unicode normalization for glyph lookup
+//!
+//! Text can reach the PDF pipeline in either composed ("é", U+00E9) or
+//! decomposed ("e" + U+0301) form. Fonts map the composed code point, and
+//! glyphs are looked up one code point at a time, so text is normalized to
+//! NFC before subsetting and encoding.
+
+use std::borrow::Cow;
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+/// Normalizes text to Unicode Normalization Form C (canonical composition)
+///
+/// Returns the input unchanged (borrowed) when it is already in NFC, which is
+/// the common case for ASCII and most Latin text.
+///
+/// # Example
+/// ```
+/// use font_toolkit::normalize::to_nfc;
+///
+/// assert_eq!(to_nfc("Jose\u{0301}"), "Jos\u{00E9}");
+/// assert_eq!(to_nfc("John Doe"), "John Doe");
+/// ```
+pub fn to_nfc(text: &str) -> Cow<'_, str> {
+    if is_nfc_quick(text.chars()) == IsNormalized::Yes {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(text.nfc().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_nfc_composes_combining_marks() {
+        let normalized = to_nfc("Jose\u{0301} Mu\u{0308}ller");
+        assert_eq!(normalized, "Jos\u{00E9} M\u{00FC}ller");
+        assert!(matches!(normalized, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_to_nfc_borrows_normalized_text() {
+        assert!(matches!(to_nfc("Jos\u{00E9}"), Cow::Borrowed(_)));
+        assert!(matches!(to_nfc(""), Cow::Borrowed(_)));
+    }
+}
@@ -1,5 +1,6 @@
 //! ABOUTME: TrueType Font Optimizer
-//! ABOUTME: Strips hinting tables for smaller PDF output
+//! ABOUTME: Strips hinting and optional tables for smaller PDF output, repairs the `post` table
+//! ABOUTME: and extracts single faces from TrueType collections
 
 /// Tables to strip for PDF embedding (hinting not used in PDF)
 const HINTING_TABLES: &[&[u8; 4]] = &[
@@ -32,12 +33,138 @@ pub fn strip_hinting_tables(font_bytes: &[u8]) -> Vec<u8> {
     }
 }
 
-/// Table entry from the TrueType table directory
-struct TableEntry {
-    tag: [u8; 4],
-    checksum: u32,
-    offset: u32,
-    length: u32,
+/// Tables a PDF viewer never reads from an embedded CIDFontType2 font
+///
+/// Glyphs are addressed by glyph ID, so naming, layout (shaping already
+/// happened) and device-metric tables are dead weight. `cmap`, `OS/2` and
+/// `post` are kept for validators that inspect them.
+const OPTIONAL_TABLES: &[&[u8; 4]] = &[
+    b"name", // Naming strings (the PDF carries its own BaseFont)
+    b"kern", // Legacy kerning
+    b"GPOS", // OpenType positioning
+    b"GSUB", // OpenType substitution
+    b"GDEF", // OpenType glyph definitions
+    b"BASE", // Baseline data
+    b"JSTF", // Justification
+    b"DSIG", // Digital signature (invalid after subsetting anyway)
+    b"hdmx", // Device metrics
+    b"LTSH", // Linear threshold
+    b"VDMX", // Vertical device metrics
+    b"PCLT", // PCL 5 data
+    b"meta", // Metadata
+];
+
+/// Strips hinting and every table PDF rendering does not need
+///
+/// A more aggressive variant of [`strip_hinting_tables`] for size-constrained
+/// output. Text still renders and extracts identically; only the font's
+/// internal names and OpenType layout data are lost.
+///
+/// # Arguments
+/// * `font_bytes` - TrueType font data
+///
+/// # Returns
+/// Optimized font bytes, or original if parsing fails
+pub fn strip_optional_tables(font_bytes: &[u8]) -> Vec<u8> {
+    let tables: Vec<&[u8; 4]> = HINTING_TABLES
+        .iter()
+        .chain(OPTIONAL_TABLES)
+        .copied()
+        .collect();
+    strip_tables_internal(font_bytes, &tables).unwrap_or_else(|_| font_bytes.to_vec())
+}
+
+/// Size of a version 3.0 `post` table (header only, no glyph names)
+const POST_V3_LEN: usize = 32;
+
+/// Ensures a font carries a `post` table that parses
+///
+/// Some strict PDF/A validators warn about fonts whose `post` table is missing
+/// or broken, which subsetting can produce. When the table is absent or
+/// unreadable it is replaced by a minimal version 3.0 table (no glyph names,
+/// which PDF embedding never needs). The italic angle and pitch lived in the
+/// lost table and are written as upright and proportional; underline metrics
+/// are derived from the em size.
+///
+/// # Arguments
+/// * `font_bytes` - TrueType font data
+///
+/// # Returns
+/// Font bytes with a valid `post` table, or the original if the font already
+/// has one or cannot be parsed
+pub fn ensure_post_table(font_bytes: &[u8]) -> Vec<u8> {
+    let Ok(face) = ttf_parser::Face::parse(font_bytes, 0) else {
+        return font_bytes.to_vec();
+    };
+
+    let post_is_valid = face
+        .raw_face()
+        .table(ttf_parser::Tag::from_bytes(b"post"))
+        .and_then(ttf_parser::post::Table::parse)
+        .is_some();
+    if post_is_valid {
+        return font_bytes.to_vec();
+    }
+
+    let post = minimal_post_table(&face);
+    rebuild_tables(font_bytes, &[b"post"], Some((*b"post", &post)))
+        .unwrap_or_else(|_| font_bytes.to_vec())
+}
+
+/// Build a version 3.0 `post` table for a font
+fn minimal_post_table(face: &ttf_parser::Face) -> Vec<u8> {
+    // Common underline proportions: 10% of the em below the baseline, 5% thick
+    let units_per_em = face.units_per_em() as i16;
+    let (underline_position, underline_thickness) = (-units_per_em / 10, units_per_em / 20);
+
+    let mut post = Vec::with_capacity(POST_V3_LEN);
+    post.extend_from_slice(&0x0003_0000u32.to_be_bytes());
+    post.extend_from_slice(&0i32.to_be_bytes()); // italicAngle (16.16 fixed)
+    post.extend_from_slice(&underline_position.to_be_bytes());
+    post.extend_from_slice(&underline_thickness.to_be_bytes());
+    post.extend_from_slice(&0u32.to_be_bytes()); // isFixedPitch
+                                                 // minMemType42, maxMemType42, minMemType1, maxMemType1: unknown
+    post.resize(POST_V3_LEN, 0);
+    post
+}
+
+/// Extracts one face of a TrueType collection (`ttcf`) as a standalone font
+///
+/// PDF `FontFile2` streams must hold a single TrueType font, so the face's
+/// tables are copied out of the collection into a new font file. Tables the
+/// collection shares between faces are copied as well.
+///
+/// # Arguments
+/// * `collection_bytes` - TrueType collection data
+/// * `index` - Zero-based index of the face to extract
+///
+/// # Errors
+/// Returns a description of the problem if the data is not a collection, the
+/// face does not exist, or its table directory is truncated.
+pub fn extract_collection_face(
+    collection_bytes: &[u8],
+    index: usize,
+) -> Result<Vec<u8>, &'static str> {
+    if collection_bytes.len() < 12 || &collection_bytes[0..4] != b"ttcf" {
+        return Err("Not a TrueType collection");
+    }
+
+    let num_fonts = read_u32(collection_bytes, 8)? as usize;
+    if index >= num_fonts {
+        return Err("Collection face index out of range");
+    }
+    let face_offset = read_u32(collection_bytes, 12 + index * 4)? as usize;
+
+    // Table offsets inside a collection are relative to the collection start
+    let (sfnt_version, tables) = read_table_directory(collection_bytes, face_offset)?;
+    Ok(write_font(sfnt_version, &tables))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, &'static str> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|b| u32::from_be_bytes(b.try_into().unwrap()))
+        .ok_or("Collection header truncated")
 }
 
 /// Internal implementation that can return errors for testing
@@ -45,71 +172,87 @@ fn strip_tables_internal(
     font_bytes: &[u8],
     tables_to_remove: &[&[u8; 4]],
 ) -> Result<Vec<u8>, &'static str> {
-    if font_bytes.len() < 12 {
-        return Err("Font too small for offset table");
+    rebuild_tables(font_bytes, tables_to_remove, None)
+}
+
+/// Rewrite a font without `tables_to_remove`, optionally adding one table
+///
+/// The table directory stays sorted by tag and the `head` checksum adjustment
+/// is recomputed. Returns the original bytes when nothing changes.
+fn rebuild_tables(
+    font_bytes: &[u8],
+    tables_to_remove: &[&[u8; 4]],
+    added_table: Option<([u8; 4], &[u8])>,
+) -> Result<Vec<u8>, &'static str> {
+    let (sfnt_version, mut tables) = read_table_directory(font_bytes, 0)?;
+    let num_tables = tables.len();
+
+    // Filter out tables we want to remove
+    tables.retain(|(tag, _, _)| !tables_to_remove.contains(&tag));
+
+    if tables.len() == num_tables && added_table.is_none() {
+        // No tables removed, return original
+        return Ok(font_bytes.to_vec());
     }
 
-    // Parse offset table
-    let sfnt_version = &font_bytes[0..4];
-    if sfnt_version != [0x00, 0x01, 0x00, 0x00] && sfnt_version != b"OTTO" {
-        return Err("Not a TrueType or OpenType font");
+    if let Some((tag, data)) = added_table {
+        tables.push((tag, calc_checksum(data), data));
+        tables.sort_by_key(|(tag, _, _)| *tag);
     }
 
-    let num_tables = u16::from_be_bytes([font_bytes[4], font_bytes[5]]) as usize;
+    Ok(write_font(sfnt_version, &tables))
+}
 
-    if font_bytes.len() < 12 + num_tables * 16 {
-        return Err("Font too small for table directory");
-    }
+/// A table directory entry: (tag, checksum, data)
+type TableRecord<'a> = ([u8; 4], u32, &'a [u8]);
 
-    // Parse table directory
-    let mut tables: Vec<TableEntry> = Vec::with_capacity(num_tables);
-
-    for i in 0..num_tables {
-        let entry_offset = 12 + i * 16;
-        let tag: [u8; 4] = font_bytes[entry_offset..entry_offset + 4]
-            .try_into()
-            .unwrap();
-        let checksum = u32::from_be_bytes(
-            font_bytes[entry_offset + 4..entry_offset + 8]
-                .try_into()
-                .unwrap(),
-        );
-        let offset = u32::from_be_bytes(
-            font_bytes[entry_offset + 8..entry_offset + 12]
-                .try_into()
-                .unwrap(),
-        );
-        let length = u32::from_be_bytes(
-            font_bytes[entry_offset + 12..entry_offset + 16]
-                .try_into()
-                .unwrap(),
-        );
+/// Parse the offset table at `dir_offset` and the tables it lists
+///
+/// Table offsets are read relative to the start of `font_bytes`, which is
+/// also how collections store them.
+fn read_table_directory(
+    font_bytes: &[u8],
+    dir_offset: usize,
+) -> Result<(&[u8], Vec<TableRecord<'_>>), &'static str> {
+    let header = font_bytes
+        .get(dir_offset..dir_offset + 12)
+        .ok_or("Font too small for offset table")?;
 
-        tables.push(TableEntry {
-            tag,
-            checksum,
-            offset,
-            length,
-        });
+    // Parse offset table
+    let sfnt_version = &header[0..4];
+    if sfnt_version != [0x00, 0x01, 0x00, 0x00] && sfnt_version != b"OTTO" {
+        return Err("Not a TrueType or OpenType font");
     }
 
-    // Filter out tables we want to remove
-    let kept_tables: Vec<&TableEntry> = tables
-        .iter()
-        .filter(|t| !tables_to_remove.iter().any(|remove| **remove == t.tag))
-        .collect();
-
-    if kept_tables.len() == tables.len() {
-        // No tables removed, return original
-        return Ok(font_bytes.to_vec());
+    let num_tables = u16::from_be_bytes([header[4], header[5]]) as usize;
+    let directory = font_bytes
+        .get(dir_offset + 12..dir_offset + 12 + num_tables * 16)
+        .ok_or("Font too small for table directory")?;
+
+    let mut tables = Vec::with_capacity(num_tables + 1);
+    for entry in directory.chunks_exact(16) {
+        let tag: [u8; 4] = entry[0..4].try_into().unwrap();
+        let checksum = u32::from_be_bytes(entry[4..8].try_into().unwrap());
+        let offset = u32::from_be_bytes(entry[8..12].try_into().unwrap()) as usize;
+        let length = u32::from_be_bytes(entry[12..16].try_into().unwrap()) as usize;
+
+        let data = font_bytes
+            .get(offset..offset + length)
+            .ok_or("Table extends beyond font data")?;
+        tables.push((tag, checksum, data));
     }
 
+    Ok((sfnt_version, tables))
+}
+
+/// Write a font file holding `tables` in the given order
+fn write_font(sfnt_version: &[u8], tables: &[TableRecord<'_>]) -> Vec<u8> {
     // Calculate new table count and search parameters
-    let new_num_tables = kept_tables.len() as u16;
+    let new_num_tables = tables.len() as u16;
     let (search_range, entry_selector, range_shift) = calc_search_params(new_num_tables);
 
     // Calculate new offsets
-    let header_size = 12 + kept_tables.len() * 16;
+    let header_size = 12 + tables.len() * 16;
     let mut current_offset = header_size as u32;
 
     // Align to 4 bytes
@@ -129,39 +272,36 @@ fn strip_tables_internal(
 
     // Calculate offsets for all tables first
     let mut new_offsets: Vec<u32> = Vec::new();
-    for table in &kept_tables {
+    for (_, _, data) in tables {
         new_offsets.push(current_offset);
-        current_offset = align4(current_offset + table.length);
+        current_offset = align4(current_offset + data.len() as u32);
     }
 
     // Write table directory
-    for (i, table) in kept_tables.iter().enumerate() {
-        output.extend_from_slice(&table.tag);
-        output.extend_from_slice(&table.checksum.to_be_bytes());
+    for (i, (tag, checksum, data)) in tables.iter().enumerate() {
+        output.extend_from_slice(tag);
+        output.extend_from_slice(&checksum.to_be_bytes());
         output.extend_from_slice(&new_offsets[i].to_be_bytes());
-        output.extend_from_slice(&table.length.to_be_bytes());
+        output.extend_from_slice(&(data.len() as u32).to_be_bytes());
     }
 
     // Write table data
-    for table in &kept_tables {
-        let start = table.offset as usize;
-        let end = start + table.length as usize;
-
-        if end > font_bytes.len() {
-            return Err("Table extends beyond font data");
-        }
-
-        output.extend_from_slice(&font_bytes[start..end]);
+    for (_, _, data) in tables {
+        output.extend_from_slice(data);
 
         // Pad to 4-byte alignment
-        let padding = (4 - (table.length % 4)) % 4;
-        output.resize(output.len() + padding as usize, 0);
+        let padding = (4 - (data.len() % 4)) % 4;
+        output.resize(output.len() + padding, 0);
     }
 
     // Update head table checksum adjustment
-    update_head_checksum(&mut output, &kept_tables, &new_offsets);
+    let head_offset = tables
+        .iter()
+        .position(|(tag, _, _)| tag == b"head")
+        .map(|idx| new_offsets[idx] as usize);
+    update_head_checksum(&mut output, head_offset);
 
-    Ok(output)
+    output
 }
 
 /// Calculate searchRange, entrySelector, rangeShift for table directory
@@ -208,13 +348,8 @@ fn calc_checksum(data: &[u8]) -> u32 {
 }
 
 /// Update the checkSumAdjustment field in the head table
-fn update_head_checksum(output: &mut [u8], kept_tables: &[&TableEntry], new_offsets: &[u32]) {
-    // Find head table
-    let head_idx = kept_tables.iter().position(|t| &t.tag == b"head");
-
-    if let Some(idx) = head_idx {
-        let head_offset = new_offsets[idx] as usize;
-
+fn update_head_checksum(output: &mut [u8], head_offset: Option<usize>) {
+    if let Some(head_offset) = head_offset {
         // Zero out the checkSumAdjustment field (offset 8 in head table)
         if head_offset + 12 <= output.len() {
             output[head_offset + 8] = 0;
@@ -270,6 +405,20 @@ mod tests {
         println!("Stripped hinting from full font: saved {} bytes", saved);
     }
 
+    #[test]
+    fn test_strip_optional_tables_beyond_hinting() {
+        let hinting_only = strip_hinting_tables(ROBOTO_TTF);
+        let optimized = strip_optional_tables(ROBOTO_TTF);
+        assert!(optimized.len() < hinting_only.len());
+
+        let face = ttf_parser::Face::parse(&optimized, 0).expect("still a valid font");
+        assert!(face.glyph_index('A').is_some(), "cmap is kept");
+        let raw = face.raw_face();
+        assert!(raw.table(ttf_parser::Tag::from_bytes(b"GPOS")).is_none());
+        assert!(raw.table(ttf_parser::Tag::from_bytes(b"name")).is_none());
+        assert!(raw.table(ttf_parser::Tag::from_bytes(b"post")).is_some());
+    }
+
     #[test]
     fn test_strip_from_subset() {
         #[cfg(feature = "advanced-fonts")]
@@ -327,6 +476,88 @@ mod tests {
         assert_eq!(result, invalid);
     }
 
+    #[test]
+    fn test_ensure_post_table_restores_missing_post() {
+        let without_post = strip_tables_internal(ROBOTO_TTF, &[b"post"]).unwrap();
+        let face = ttf_parser::Face::parse(&without_post, 0).unwrap();
+        assert!(face
+            .raw_face()
+            .table(ttf_parser::Tag::from_bytes(b"post"))
+            .is_none());
+
+        let repaired = ensure_post_table(&without_post);
+        let face = ttf_parser::Face::parse(&repaired, 0).expect("Repaired font should parse");
+        let post = face
+            .raw_face()
+            .table(ttf_parser::Tag::from_bytes(b"post"))
+            .expect("post table should be added");
+        assert_eq!(post.len(), POST_V3_LEN);
+        assert_eq!(&post[0..4], &[0x00, 0x03, 0x00, 0x00]);
+        assert!(ttf_parser::post::Table::parse(post).is_some());
+
+        // Roboto has 2048 units per em
+        let underline = face.underline_metrics().unwrap();
+        assert_eq!((underline.position, underline.thickness), (-204, 102));
+        let original = ttf_parser::Face::parse(ROBOTO_TTF, 0).unwrap();
+        assert_eq!(face.number_of_glyphs(), original.number_of_glyphs());
+
+        // Directory stays sorted and the whole-font checksum is balanced
+        let tags: Vec<&[u8]> = (0..u16::from_be_bytes([repaired[4], repaired[5]]) as usize)
+            .map(|i| &repaired[12 + i * 16..16 + i * 16])
+            .collect();
+        assert!(tags.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(calc_checksum(&repaired), 0xB1B0AFBA);
+    }
+
+    #[test]
+    fn test_ensure_post_table_keeps_valid_font() {
+        assert_eq!(ensure_post_table(KARLA_REGULAR), KARLA_REGULAR);
+        assert_eq!(ensure_post_table(b"not a font"), b"not a font");
+    }
+
+    /// Two-face collection whose faces share every table of `font`
+    fn two_face_collection(font: &[u8]) -> Vec<u8> {
+        const HEADER_LEN: u32 = 20;
+        let mut ttc = Vec::new();
+        ttc.extend_from_slice(b"ttcf");
+        ttc.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+        ttc.extend_from_slice(&2u32.to_be_bytes());
+        ttc.extend_from_slice(&HEADER_LEN.to_be_bytes());
+        ttc.extend_from_slice(&HEADER_LEN.to_be_bytes());
+        ttc.extend_from_slice(font);
+
+        // Table offsets become relative to the collection start
+        let num_tables = u16::from_be_bytes([font[4], font[5]]) as usize;
+        for i in 0..num_tables {
+            let at = HEADER_LEN as usize + 12 + i * 16 + 8;
+            let offset = u32::from_be_bytes(ttc[at..at + 4].try_into().unwrap());
+            ttc[at..at + 4].copy_from_slice(&(offset + HEADER_LEN).to_be_bytes());
+        }
+        ttc
+    }
+
+    #[test]
+    fn test_extract_collection_face() {
+        let ttc = two_face_collection(ROBOTO_TTF);
+
+        let face = extract_collection_face(&ttc, 1).expect("face 1 extracted");
+        assert_eq!(&face[0..4], &[0x00, 0x01, 0x00, 0x00]);
+        assert_eq!(calc_checksum(&face), 0xB1B0AFBA);
+
+        let parsed = ttf_parser::Face::parse(&face, 0).expect("standalone font");
+        let original = ttf_parser::Face::parse(ROBOTO_TTF, 0).unwrap();
+        assert_eq!(parsed.number_of_glyphs(), original.number_of_glyphs());
+        assert_eq!(parsed.glyph_index('A'), original.glyph_index('A'));
+    }
+
+    #[test]
+    fn test_extract_collection_face_errors() {
+        let ttc = two_face_collection(ROBOTO_TTF);
+        assert!(extract_collection_face(&ttc, 2).is_err());
+        assert!(extract_collection_face(ROBOTO_TTF, 0).is_err());
+        assert!(extract_collection_face(&ttc[..40], 0).is_err());
+    }
+
     #[test]
     fn test_calc_search_params() {
         let (sr, es, rs) = calc_search_params(11);
@@ -27,6 +27,17 @@
 //!   - Font parsing: `None` = parse internally, `Some(&face)` = reuse parsed font
 //!   - Metrics: `return_metrics: true` = return size/glyph statistics
 //!   - Returns: `(subset_bytes, Option<metrics>)`
+//! - [`subset_fonts`] - Batch subsetting of several fonts (e.g. weights of one
+//!   family) against the same text, with aggregate savings
+//!
+//! # CFF Outlines
+//!
+//! CFF charstrings may call local and global subroutines (subrs). The
+//! subsetter crate desubroutinizes CFF fonts: every subr call of a retained
+//! glyph is inlined into its charstring and the subr INDEXes are written
+//! empty, so a retained glyph can never reference a dropped subr.
+//! [`find_broken_cff_glyph`] re-executes every charstring of the subset to
+//! enforce this before the font is returned.
 
 use std::collections::HashSet;
 use subsetter::{subset, GlyphRemapper};
@@ -71,6 +82,22 @@ use ttf_parser::{Face, GlyphId};
 /// }
 /// ```
 ///
+/// ## NoGlyphsRetained
+/// Returned when none of the text's characters exist in the font (e.g. a Latin
+/// font against CJK text), so the subset would hold nothing beyond `.notdef`.
+/// Callers should fall back to another font rather than embed it.
+///
+/// ```no_run
+/// # use font_toolkit::subsetter::{subset_font_core, SubsetError};
+/// # let font_bytes = b"font data";
+/// match subset_font_core(font_bytes, None, "日本語", false) {
+///     Err(SubsetError::NoGlyphsRetained { missing_chars }) => {
+///         eprintln!("Font covers none of {} characters, using fallback", missing_chars);
+///     }
+///     _ => {}
+/// }
+/// ```
+///
 /// ## ValidationError
 /// Returned when the generated subset font fails validation.
 ///
@@ -117,6 +144,10 @@ pub enum SubsetError {
         reason: String,
     },
 
+    /// None of the text's characters map to a glyph in the font
+    #[error("Font has no glyphs for any of the {missing_chars} characters in the text")]
+    NoGlyphsRetained { missing_chars: usize },
+
     /// Generic invalid font structure error (for backward compatibility)
     #[error("Invalid font structure: {0}")]
     InvalidFont(String),
@@ -124,6 +155,14 @@ pub enum SubsetError {
     /// Font checksum verification failed
     #[error("Checksum validation failed for table {table}")]
     ChecksumError { table: String },
+
+    /// Subsetting one font of a batch failed
+    #[error("Failed to subset font '{font}': {source}")]
+    BatchFontError {
+        font: String,
+        #[source]
+        source: Box<SubsetError>,
+    },
 }
 
 /// Metrics about font subsetting operation
@@ -222,13 +261,14 @@ impl SubsetMetrics {
 /// # Algorithm
 /// 1. Parse font with ttf-parser
 /// 2. Collect all glyphs used in text + .notdef + space
-/// 3. Collect composite glyph dependencies
+/// 3. Collect composite glyph dependencies (components of retained composites)
 /// 4. Extract glyph data (outlines, metrics)
 /// 5. Rebuild tables: glyf, loca, hmtx, cmap, maxp, name, head, hhea, OS/2, post
 /// 6. Calculate checksums
 /// 7. Assemble final font
 /// 8. Validate with ttf-parser
-/// 9. Return subset (or original if validation fails)
+/// 9. Add a minimal version 3.0 `post` table if the subset lacks a valid one
+/// 10. Return subset (or original if validation fails)
 ///
 /// Core subsetting implementation with maximum flexibility
 ///
@@ -238,7 +278,8 @@ impl SubsetMetrics {
 /// # Arguments
 /// * `font_bytes` - Original TrueType font bytes
 /// * `face` - Optional pre-parsed Face for performance optimization (None = will parse internally)
-/// * `text` - CV text that will use this font
+/// * `text` - CV text that will use this font; normalized to NFC first so the
+///   CID mapping matches `encode_as_cidfont_hex`
 /// * `return_metrics` - Whether to compute and return detailed subsetting metrics
 ///
 /// # Returns
@@ -294,6 +335,18 @@ pub fn subset_font_core(
     text: &str,
     return_metrics: bool,
 ) -> Result<(Vec<u8>, Option<SubsetMetrics>), SubsetError> {
+    let (subset_bytes, metrics) = subset_with_metrics(font_bytes, face, text)?;
+    Ok((subset_bytes, return_metrics.then_some(metrics)))
+}
+
+/// Subset a font for `text`, always returning its [`SubsetMetrics`]
+///
+/// Shared implementation of [`subset_font_core`] and [`subset_fonts`].
+fn subset_with_metrics(
+    font_bytes: &[u8],
+    face: Option<&Face>,
+    text: &str,
+) -> Result<(Vec<u8>, SubsetMetrics), SubsetError> {
     use std::collections::BTreeMap;
 
     let original_size = font_bytes.len();
@@ -312,8 +365,28 @@ pub fn subset_font_core(
 
     let original_glyphs = face_ref.number_of_glyphs();
 
+    // Decomposed input ("e" + U+0301) must find the font's precomposed glyph
+    let text = crate::normalize::to_nfc(text);
+    let text = text.as_ref();
+
     // Phase 1: Collect used glyphs AND build char→old_gid mapping
-    let (used_glyphs, char_to_old_gid) = collect_used_glyphs_with_mapping(face_ref, text);
+    let (mut used_glyphs, char_to_old_gid) = collect_used_glyphs_with_mapping(face_ref, text);
+
+    // A subset with only .notdef (and the implicit space) would render nothing
+    let missing_chars = text
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .collect::<HashSet<_>>()
+        .len();
+    let has_text_glyphs = char_to_old_gid
+        .keys()
+        .any(|&cp| char::from_u32(cp).is_some_and(|ch| !ch.is_whitespace()));
+    if missing_chars > 0 && !has_text_glyphs {
+        return Err(SubsetError::NoGlyphsRetained { missing_chars });
+    }
+
+    // Phase 1b: Pull in components of composite glyphs (e.g. "é" = "e" + acute)
+    collect_composite_components(face_ref, &mut used_glyphs);
 
     // Phase 2: Build GlyphRemapper
     let mut remapper = GlyphRemapper::new();
@@ -335,12 +408,25 @@ pub fn subset_font_core(
         })?;
 
     // Phase 4: Validate subset font
-    Face::parse(&subset_bytes, 0).map_err(|e| SubsetError::ValidationError {
+    let validation_error = |reason: String| SubsetError::ValidationError {
         index: 0,
         original_size,
         subset_size: subset_bytes.len(),
-        reason: format!("Subset validation failed: {:?}", e),
-    })?;
+        reason,
+    };
+    let subset_face = Face::parse(&subset_bytes, 0)
+        .map_err(|e| validation_error(format!("Subset validation failed: {:?}", e)))?;
+
+    // A CFF glyph calling a subroutine that was dropped no longer renders
+    if let Some((glyph_id, reason)) = find_broken_cff_glyph(&subset_face) {
+        return Err(validation_error(format!(
+            "CFF glyph {} is broken after subsetting: {}",
+            glyph_id.0, reason
+        )));
+    }
+
+    // Phase 4b: Strict PDF/A validators want a usable `post` table
+    let subset_bytes = crate::optimizer::ensure_post_table(&subset_bytes);
 
     // Phase 5: Build CID→new_gid mapping using remapper
     let mut cid_to_new_gid = BTreeMap::new();
@@ -354,22 +440,104 @@ pub fn subset_font_core(
         }
     }
 
-    // Phase 6: Compute metrics if requested
-    let metrics = if return_metrics {
-        Some(SubsetMetrics::new(
-            original_size,
-            subset_bytes.len(),
-            original_glyphs,
-            subset_glyphs,
-            cid_to_new_gid,
-        ))
-    } else {
-        None
-    };
+    // Phase 6: Compute metrics
+    let metrics = SubsetMetrics::new(
+        original_size,
+        subset_bytes.len(),
+        original_glyphs,
+        subset_glyphs,
+        cid_to_new_gid,
+    );
 
     Ok((subset_bytes, metrics))
 }
 
+/// Result of subsetting several fonts against the same text
+///
+/// Returned by [`subset_fonts`]. Per-font results are in input order.
+#[derive(Debug, Clone)]
+pub struct BatchSubsetResult {
+    /// Subset bytes and metrics for each input font
+    pub fonts: Vec<(Vec<u8>, SubsetMetrics)>,
+
+    /// Combined size of all original fonts in bytes
+    pub total_original_size: usize,
+
+    /// Combined size of all subset fonts in bytes
+    pub total_subset_size: usize,
+}
+
+impl BatchSubsetResult {
+    /// Total bytes saved across all fonts
+    pub fn bytes_saved(&self) -> usize {
+        self.total_original_size
+            .saturating_sub(self.total_subset_size)
+    }
+
+    /// Combined size reduction percentage (0.0 to 100.0)
+    pub fn size_reduction_pct(&self) -> f32 {
+        if self.total_original_size > 0 {
+            (1.0 - (self.total_subset_size as f32 / self.total_original_size as f32)) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Subset several fonts against the same CV text
+///
+/// Intended for CVs that use multiple weights/styles of a family
+/// (e.g. Roboto 400/700/italic). Every font is subsetted for the same set of
+/// characters, so glyph coverage is consistent across weights, and the
+/// combined savings are reported alongside per-font metrics.
+///
+/// # Arguments
+/// * `fonts` - `(font_bytes, name)` pairs; the name is used in error messages
+/// * `cv_text` - CV text shared by all fonts
+///
+/// # Returns
+/// [`BatchSubsetResult`] with per-font results in input order, or
+/// [`SubsetError::BatchFontError`] for the first font that fails
+///
+/// # Example
+/// ```no_run
+/// use font_toolkit::subsetter::subset_fonts;
+///
+/// let regular = std::fs::read("Roboto-Regular.ttf").unwrap();
+/// let bold = std::fs::read("Roboto-Bold.ttf").unwrap();
+///
+/// let batch = subset_fonts(&[(&regular, "Roboto"), (&bold, "Roboto Bold")], "Jane Doe").unwrap();
+/// println!("Saved {} bytes ({:.1}%)", batch.bytes_saved(), batch.size_reduction_pct());
+/// ```
+pub fn subset_fonts(
+    fonts: &[(&[u8], &str)],
+    cv_text: &str,
+) -> Result<BatchSubsetResult, SubsetError> {
+    let mut results = Vec::with_capacity(fonts.len());
+    let mut total_original_size = 0;
+    let mut total_subset_size = 0;
+
+    for (font_bytes, name) in fonts {
+        let (subset_bytes, metrics) =
+            subset_with_metrics(font_bytes, None, cv_text).map_err(|e| {
+                SubsetError::BatchFontError {
+                    font: name.to_string(),
+                    source: Box::new(e),
+                }
+            })?;
+
+        total_original_size += metrics.original_size;
+        total_subset_size += metrics.subset_size;
+        results.push((subset_bytes, metrics));
+    }
+
+    Ok(BatchSubsetResult {
+        fonts: results,
+        total_original_size,
+        total_subset_size,
+    })
+}
+
 // ============================================================================
 // Phase 1: Glyph Collection
 // ============================================================================
@@ -404,8 +572,146 @@ fn collect_used_glyphs_with_mapping(
     (glyphs, char_to_gid)
 }
 
-// Note: Composite glyph dependencies are automatically handled by the subsetter crate
-// No need for manual collection - the subsetter will include referenced component glyphs
+// ============================================================================
+// Phase 1b: Composite Glyph Closure
+// ============================================================================
+
+/// Composite glyph flag: arguments are 16-bit words (otherwise bytes)
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+/// Composite glyph flag: a single F2Dot14 scale follows the arguments
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+/// Composite glyph flag: another component record follows this one
+const MORE_COMPONENTS: u16 = 0x0020;
+/// Composite glyph flag: separate x and y F2Dot14 scales follow
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+/// Composite glyph flag: a 2x2 F2Dot14 transform follows
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+/// Adds every glyph referenced (directly or transitively) by a composite
+/// glyph in `glyphs` to the set
+///
+/// Components are retained so the composite still renders after subsetting.
+/// The subsetter crate rewrites the component indices inside each composite
+/// to match the new glyph IDs assigned by the remapper.
+///
+/// Fonts without a `glyf` table (CFF outlines) are left unchanged.
+pub fn collect_composite_components(face: &Face, glyphs: &mut HashSet<GlyphId>) {
+    let mut pending: Vec<GlyphId> = glyphs.iter().copied().collect();
+
+    while let Some(glyph_id) = pending.pop() {
+        for component in glyph_components(face, glyph_id) {
+            if glyphs.insert(component) {
+                pending.push(component);
+            }
+        }
+    }
+}
+
+/// Returns the component glyphs of a composite glyph
+///
+/// Returns an empty list for simple glyphs, empty glyphs, fonts without
+/// TrueType outlines, and malformed glyph data.
+pub fn glyph_components(face: &Face, glyph_id: GlyphId) -> Vec<GlyphId> {
+    glyph_data(face, glyph_id)
+        .and_then(parse_component_glyphs)
+        .unwrap_or_default()
+}
+
+/// Raw `glyf` entry for a glyph, located through the `loca` table
+fn glyph_data<'a>(face: &Face<'a>, glyph_id: GlyphId) -> Option<&'a [u8]> {
+    use ttf_parser::{loca, Tag};
+
+    let raw = face.raw_face();
+    let glyf = raw.table(Tag::from_bytes(b"glyf"))?;
+    let loca_data = raw.table(Tag::from_bytes(b"loca"))?;
+    let number_of_glyphs = std::num::NonZeroU16::new(face.number_of_glyphs())?;
+    let loca = loca::Table::parse(
+        number_of_glyphs,
+        face.tables().head.index_to_location_format,
+        loca_data,
+    )?;
+
+    glyf.get(loca.glyph_range(glyph_id)?)
+}
+
+/// Walks the component records of a composite glyph description
+///
+/// Returns `Some(vec![])` for simple glyphs and `None` for truncated data.
+fn parse_component_glyphs(data: &[u8]) -> Option<Vec<GlyphId>> {
+    let read_u16 = |offset: usize| -> Option<u16> {
+        data.get(offset..offset + 2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+    };
+
+    // Empty glyphs (e.g. space) have no description at all
+    if data.is_empty() {
+        return Some(Vec::new());
+    }
+
+    // Negative numberOfContours marks a composite glyph
+    let number_of_contours = read_u16(0)? as i16;
+    if number_of_contours >= 0 {
+        return Some(Vec::new());
+    }
+
+    // Skip the 10-byte glyph header (contours + bounding box)
+    let mut offset = 10;
+    let mut components = Vec::new();
+    loop {
+        let flags = read_u16(offset)?;
+        components.push(GlyphId(read_u16(offset + 2)?));
+        offset += 4;
+
+        offset += if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            4
+        } else {
+            2
+        };
+        if flags & WE_HAVE_A_SCALE != 0 {
+            offset += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            offset += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            offset += 8;
+        }
+
+        if flags & MORE_COMPONENTS == 0 {
+            return Some(components);
+        }
+    }
+}
+
+// ============================================================================
+// Phase 4: CFF Charstring Validation
+// ============================================================================
+
+/// Finds the first CFF glyph whose charstring fails to execute
+///
+/// Runs every charstring through ttf-parser's CFF interpreter, which follows
+/// local and global subroutine calls, so a call to a missing subroutine is
+/// reported along with any other malformed charstring. Empty glyphs (such as
+/// space) are fine. Returns `None` for fonts without a `CFF ` table.
+pub fn find_broken_cff_glyph(face: &Face) -> Option<(GlyphId, String)> {
+    struct NullOutline;
+    impl ttf_parser::OutlineBuilder for NullOutline {
+        fn move_to(&mut self, _: f32, _: f32) {}
+        fn line_to(&mut self, _: f32, _: f32) {}
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+        fn close(&mut self) {}
+    }
+
+    let cff = face.tables().cff.as_ref()?;
+    (0..face.number_of_glyphs())
+        .map(GlyphId)
+        .find_map(|glyph_id| {
+            match cff.outline(glyph_id, &mut NullOutline) {
+                // `ZeroBBox` marks an empty glyph
+                Ok(_) | Err(ttf_parser::CFFError::ZeroBBox) => None,
+                Err(error) => Some((glyph_id, format!("{:?}", error))),
+            }
+        })
+}
 
 // Note: All table extraction, rebuilding, and assembly is handled by the subsetter crate
 // The subsetter crate automatically:
@@ -425,12 +731,49 @@ mod tests {
         // This will be tested in integration tests with real fonts
     }
 
+    #[test]
+    fn test_parse_component_glyphs() {
+        // Simple glyph: non-negative contour count
+        assert_eq!(parse_component_glyphs(&[0, 1, 0, 0]), Some(vec![]));
+        // Empty glyph
+        assert_eq!(parse_component_glyphs(&[]), Some(vec![]));
+
+        // Composite: word args + scale, then byte args, last component
+        let mut data = vec![0xFF, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0];
+        data.extend_from_slice(
+            &(ARG_1_AND_2_ARE_WORDS | WE_HAVE_A_SCALE | MORE_COMPONENTS).to_be_bytes(),
+        );
+        data.extend_from_slice(&7u16.to_be_bytes());
+        data.extend_from_slice(&[0; 6]);
+        data.extend_from_slice(&0u16.to_be_bytes());
+        data.extend_from_slice(&42u16.to_be_bytes());
+        data.extend_from_slice(&[0; 2]);
+        assert_eq!(
+            parse_component_glyphs(&data),
+            Some(vec![GlyphId(7), GlyphId(42)])
+        );
+
+        // Truncated component record
+        assert_eq!(parse_component_glyphs(&data[..14]), None);
+    }
+
     #[test]
     fn test_subset_error_display() {
         let error = SubsetError::InvalidFont("test error".to_string());
         assert!(error.to_string().contains("test error"));
     }
 
+    #[test]
+    fn test_subset_fonts_reports_failing_font() {
+        let fonts: [(&[u8], &str); 1] = [(b"not a font", "Broken Sans")];
+        let err = subset_fonts(&fonts, "Jane Doe").unwrap_err();
+
+        assert!(
+            matches!(err, SubsetError::BatchFontError { ref font, .. } if font == "Broken Sans")
+        );
+        assert!(err.to_string().contains("Broken Sans"));
+    }
+
     #[test]
     fn test_subset_metrics_zero_original_size() {
         use std::collections::BTreeMap;
@@ -1,8 +1,118 @@
 //! TrueType Font Table Parsing
 //!
-//! Parses TrueType font tables to extract glyph metrics for PDF embedding.
+//! Parses TrueType font tables to extract glyph metrics and names for PDF embedding.
 
-use ttf_parser::{Face, GlyphId};
+use ttf_parser::{name_id, Face, GlyphId, Language};
+
+/// Errors reading a font's `name` table
+#[derive(Debug, thiserror::Error)]
+pub enum NameTableError {
+    #[error("Failed to parse font: {0}")]
+    ParseError(String),
+
+    #[error("Font has no readable {0} name record")]
+    MissingName(&'static str),
+}
+
+/// Canonical font names from the `name` table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontNames {
+    /// Family name (typographic family, falling back to name ID 1)
+    pub family: String,
+    /// Subfamily/style name (e.g., "Regular", "Bold Italic")
+    pub subfamily: String,
+    /// Full font name (e.g., "Roboto Bold")
+    pub full_name: String,
+    /// PostScript name (e.g., "Roboto-Bold"), suitable for `/BaseFont`
+    pub postscript_name: String,
+}
+
+/// Reads the family, subfamily, full and PostScript names from a font's `name` table
+///
+/// Typographic family/subfamily (name IDs 16/17) take precedence over the
+/// legacy IDs 1/2, so "Roboto Medium" reports family "Roboto". English
+/// (United States) records are preferred over other languages.
+///
+/// # Errors
+/// Returns [`NameTableError::ParseError`] if the font cannot be parsed and
+/// [`NameTableError::MissingName`] if it has no readable family or PostScript name.
+pub fn read_names(font_bytes: &[u8]) -> Result<FontNames, NameTableError> {
+    let face =
+        Face::parse(font_bytes, 0).map_err(|e| NameTableError::ParseError(format!("{:?}", e)))?;
+
+    let family = find_name(&face, name_id::TYPOGRAPHIC_FAMILY)
+        .or_else(|| find_name(&face, name_id::FAMILY))
+        .ok_or(NameTableError::MissingName("family"))?;
+    let subfamily = find_name(&face, name_id::TYPOGRAPHIC_SUBFAMILY)
+        .or_else(|| find_name(&face, name_id::SUBFAMILY))
+        .unwrap_or_else(|| "Regular".to_string());
+    let full_name =
+        find_name(&face, name_id::FULL_NAME).unwrap_or_else(|| format!("{} {}", family, subfamily));
+    let postscript_name =
+        find_postscript_name(&face).ok_or(NameTableError::MissingName("PostScript"))?;
+
+    Ok(FontNames {
+        family,
+        subfamily,
+        full_name,
+        postscript_name,
+    })
+}
+
+/// Underline and strikeout placement from a font's `post` and `OS/2` tables
+///
+/// Positions are offsets from the baseline (negative is below) and all values
+/// are fractions of the em, so they scale directly with the font size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecorationMetrics {
+    /// Underline offset from the baseline (`post.underlinePosition`)
+    pub underline_position: f64,
+    /// Underline stroke thickness (`post.underlineThickness`)
+    pub underline_thickness: f64,
+    /// Strikeout offset from the baseline (`OS/2.yStrikeoutPosition`), if present
+    pub strikeout_position: Option<f64>,
+    /// Strikeout stroke thickness (`OS/2.yStrikeoutSize`), if present
+    pub strikeout_thickness: Option<f64>,
+}
+
+/// Reads underline and strikeout metrics, in em units
+///
+/// Returns `None` if the font cannot be parsed or has no `post` underline
+/// metrics.
+pub fn read_decoration_metrics(font_bytes: &[u8]) -> Option<DecorationMetrics> {
+    let face = Face::parse(font_bytes, 0).ok()?;
+    let units_per_em = f64::from(face.units_per_em());
+    let to_em = |value: i16| f64::from(value) / units_per_em;
+
+    let underline = face.underline_metrics()?;
+    let strikeout = face.strikeout_metrics();
+    Some(DecorationMetrics {
+        underline_position: to_em(underline.position),
+        underline_thickness: to_em(underline.thickness),
+        strikeout_position: strikeout.map(|m| to_em(m.position)),
+        strikeout_thickness: strikeout.map(|m| to_em(m.thickness)),
+    })
+}
+
+/// Reads the PostScript name (name ID 6), restricted to characters valid in a PDF name
+pub fn find_postscript_name(face: &Face) -> Option<String> {
+    let name: String = find_name(face, name_id::POST_SCRIPT_NAME)?
+        .chars()
+        .filter(|c| c.is_ascii_graphic() && !"[](){}<>/%".contains(*c))
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+/// Finds a decodable name record, preferring English (United States)
+fn find_name(face: &Face, id: u16) -> Option<String> {
+    let records = || face.names().into_iter().filter(|n| n.name_id == id);
+    records()
+        .filter(|n| n.language() == Language::English_UnitedStates)
+        .chain(records())
+        .find_map(|n| n.to_string())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
 
 /// Extracts glyph widths scaled to PDF's 1000-unit text space
 ///
@@ -66,6 +176,44 @@ pub fn extract_glyph_widths(face: &Face) -> Vec<u16> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_names_roboto() {
+        let font_data = include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf");
+        let names = read_names(font_data).expect("Roboto should have a name table");
+
+        assert_eq!(names.family, "Roboto");
+        assert_eq!(names.subfamily, "Regular");
+        assert!(!names.postscript_name.is_empty());
+        assert!(!names.postscript_name.contains(' '));
+    }
+
+    #[test]
+    fn test_read_names_invalid_font() {
+        let result = read_names(b"not a font");
+        assert!(matches!(result, Err(NameTableError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_read_decoration_metrics_roboto() {
+        let font_data = include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf");
+        let face = Face::parse(font_data, 0).unwrap();
+        let underline = face.underline_metrics().unwrap();
+
+        let metrics = read_decoration_metrics(font_data).expect("Roboto has a post table");
+        assert_eq!(
+            metrics.underline_position,
+            f64::from(underline.position) / 2048.0
+        );
+        assert!(
+            metrics.underline_position < 0.0,
+            "underline sits below the baseline"
+        );
+        assert!(metrics.underline_thickness > 0.0);
+        assert!(metrics.strikeout_position.is_some_and(|p| p > 0.0));
+
+        assert_eq!(read_decoration_metrics(b"not a font"), None);
+    }
+
     #[test]
     fn test_extract_glyph_widths() {
         // Test with a real font from the test fixtures
@@ -8,6 +8,9 @@
 //! - Brotli decompression
 //! - TrueType reconstruction
 //!
+//! WOFF2 collections decompress to a TrueType collection (`ttcf`); only the
+//! first face is kept, as a standalone font, since PDF embeds single fonts.
+//!
 //! Reference: <https://www.w3.org/TR/WOFF2/>
 
 use thiserror::Error;
@@ -107,6 +110,7 @@ pub enum Woff2Error {
 /// * ✅ glyf/loca table transformations (Google Fonts)
 /// * ✅ Brotli decompression
 /// * ✅ TrueType-flavored fonts
+/// * ✅ Font collections (the first face is extracted as a standalone font)
 ///
 /// # Example
 /// ```no_run
@@ -148,14 +152,23 @@ pub fn decompress_woff2_with_limit(
             ))
         })?;
 
-    // 2. Check size limit if specified
+    // 2. Collections embed as their first face: FontFile2 holds a single font
+    let ttf_bytes = if ttf_bytes.starts_with(b"ttcf") {
+        crate::optimizer::extract_collection_face(&ttf_bytes, 0).map_err(|e| {
+            Woff2Error::InvalidFormat(format!("Font collection face 0 unreadable: {}", e))
+        })?
+    } else {
+        ttf_bytes
+    };
+
+    // 3. Check size limit if specified
     if let Some(limit) = max_size {
         if ttf_bytes.len() > limit {
             return Err(Woff2Error::FontTooLarge(ttf_bytes.len(), limit));
         }
     }
 
-    // 3. Validate the decompressed TrueType font
+    // 4. Validate the decompressed TrueType font
     validate_truetype_font(&ttf_bytes)?;
 
     Ok(ttf_bytes)
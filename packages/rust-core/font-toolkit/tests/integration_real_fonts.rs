@@ -19,7 +19,10 @@
 
 #![cfg(feature = "advanced-fonts")]
 
-use font_toolkit::{decompress_woff, decompress_woff2, embed_truetype_font, subset_font_core};
+use font_toolkit::{
+    decompress_woff, decompress_woff2, embed_truetype_font, glyph_components, subset_font_core,
+    subset_fonts, SubsetError,
+};
 use lopdf::Document;
 use ttf_parser::Face;
 
@@ -32,6 +35,8 @@ const ROBOTO_REGULAR_WOFF2: &[u8] =
     include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.woff2");
 const OPENSANS_BOLD_TTF: &[u8] =
     include_bytes!("../../../../test-fixtures/fonts/OpenSans-Bold.ttf");
+const KARLA_REGULAR_TTF: &[u8] = include_bytes!("../../pdf-generator/fonts/Karla-Regular.ttf");
+const KARLA_BOLD_TTF: &[u8] = include_bytes!("../../pdf-generator/fonts/Karla-Bold.ttf");
 
 // Realistic CV content samples for different testing scenarios
 const MINIMAL_CV: &str = "John Doe\nSoftware Engineer";
@@ -219,6 +224,25 @@ fn test_subset_roboto_minimal_cv() {
     assert_eq!(face.units_per_em(), subset_face.units_per_em());
 }
 
+#[test]
+fn test_subset_output_has_parseable_post_table() {
+    for font in [ROBOTO_REGULAR_TTF, OPENSANS_BOLD_TTF] {
+        let (subset_bytes, _) = subset_font_core(font, None, TYPICAL_CV, false)
+            .expect("Subsetting typical CV should succeed");
+
+        let subset_face = Face::parse(&subset_bytes, 0).expect("Subset should be valid font");
+        let post = subset_face
+            .raw_face()
+            .table(ttf_parser::Tag::from_bytes(b"post"))
+            .expect("Subset should keep a post table");
+        assert!(
+            ttf_parser::post::Table::parse(post).is_some(),
+            "post table should parse"
+        );
+        assert!(subset_face.underline_metrics().is_some());
+    }
+}
+
 #[test]
 fn test_subset_roboto_typical_cv() {
     let face = Face::parse(ROBOTO_REGULAR_TTF, 0).unwrap();
@@ -303,6 +327,24 @@ fn test_subset_unicode_content() {
     assert!(metrics.size_reduction_pct >= 50.0);
 }
 
+#[test]
+fn test_subset_decomposed_text_maps_precomposed_glyph() {
+    let face = Face::parse(ROBOTO_REGULAR_TTF, 0).unwrap();
+
+    // "José" with a combining acute accent (U+0301)
+    let (subset_bytes, metrics) =
+        subset_font_core(ROBOTO_REGULAR_TTF, Some(&face), "Jose\u{301}", true)
+            .expect("Subsetting decomposed text should succeed");
+    let metrics = metrics.unwrap();
+
+    assert!(!metrics.cid_to_new_gid.contains_key(&0x0301));
+    let e_acute = metrics.cid_to_new_gid[&('é' as u32)];
+    assert_ne!(e_acute, 0, "é should map to a real glyph, not .notdef");
+
+    let subset_face = Face::parse(&subset_bytes, 0).expect("Subset should be valid");
+    assert!(e_acute < subset_face.number_of_glyphs());
+}
+
 #[test]
 fn test_subset_special_characters() {
     let face = Face::parse(ROBOTO_REGULAR_TTF, 0).unwrap();
@@ -319,6 +361,105 @@ fn test_subset_special_characters() {
     assert_eq!(face.units_per_em(), subset_face.units_per_em());
 }
 
+#[test]
+fn test_subset_latin_font_against_cjk_text() {
+    let result = subset_font_core(ROBOTO_REGULAR_TTF, None, "山田 太郎 東京", false);
+
+    assert!(
+        matches!(
+            result,
+            Err(SubsetError::NoGlyphsRetained { missing_chars: 6 })
+        ),
+        "Expected NoGlyphsRetained, got {:?}",
+        result.map(|(bytes, _)| bytes.len())
+    );
+}
+
+#[test]
+fn test_subset_fonts_batch_aggregate() {
+    let batch = subset_fonts(
+        &[
+            (KARLA_REGULAR_TTF, "Karla Regular"),
+            (KARLA_BOLD_TTF, "Karla Bold"),
+        ],
+        TYPICAL_CV,
+    )
+    .expect("Batch subsetting should succeed");
+
+    assert_eq!(batch.fonts.len(), 2);
+    for ((subset_bytes, metrics), original) in
+        batch.fonts.iter().zip([KARLA_REGULAR_TTF, KARLA_BOLD_TTF])
+    {
+        assert!(
+            subset_bytes.len() < original.len(),
+            "Each font should shrink: {} < {}",
+            subset_bytes.len(),
+            original.len()
+        );
+        assert_eq!(metrics.subset_size, subset_bytes.len());
+    }
+
+    let per_font_saved: usize = batch
+        .fonts
+        .iter()
+        .map(|(_, m)| m.original_size - m.subset_size)
+        .sum();
+    assert_eq!(batch.bytes_saved(), per_font_saved);
+    assert_eq!(
+        batch.total_original_size,
+        KARLA_REGULAR_TTF.len() + KARLA_BOLD_TTF.len()
+    );
+}
+
+#[test]
+fn test_subset_retains_composite_glyph_components() {
+    let face = Face::parse(ROBOTO_REGULAR_TTF, 0).expect("Should parse Roboto");
+    let e_acute = face.glyph_index('é').expect("Roboto should map é");
+    let components = glyph_components(&face, e_acute);
+    assert!(
+        components.len() >= 2,
+        "é should be a composite of base and accent glyphs"
+    );
+
+    let (subset_bytes, metrics) =
+        subset_font_core(ROBOTO_REGULAR_TTF, None, "é", true).expect("Subsetting should succeed");
+    let metrics = metrics.expect("Metrics requested");
+
+    // .notdef + space + é + its components
+    assert_eq!(metrics.subset_glyphs, 3 + components.len() as u16);
+
+    // The composite renders from the retained (and remapped) components
+    let subset_face = Face::parse(&subset_bytes, 0).expect("Subset should parse");
+    let new_gid = metrics.cid_to_new_gid[&('é' as u32)];
+    let subset_components = glyph_components(&subset_face, ttf_parser::GlyphId(new_gid));
+    assert_eq!(subset_components.len(), components.len());
+    for component in subset_components {
+        assert!(component.0 < subset_face.number_of_glyphs());
+    }
+
+    struct Sink(usize);
+    impl ttf_parser::OutlineBuilder for Sink {
+        fn move_to(&mut self, _: f32, _: f32) {
+            self.0 += 1;
+        }
+        fn line_to(&mut self, _: f32, _: f32) {
+            self.0 += 1;
+        }
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {
+            self.0 += 1;
+        }
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {
+            self.0 += 1;
+        }
+        fn close(&mut self) {}
+    }
+    let mut sink = Sink(0);
+    subset_face
+        .outline_glyph(ttf_parser::GlyphId(new_gid), &mut sink)
+        .expect("Composite glyph should have an outline");
+    assert!(sink.0 > 0);
+}
+
 // ============================================================================
 // Font Embedding Tests
 // ============================================================================
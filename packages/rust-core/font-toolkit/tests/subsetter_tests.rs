@@ -122,6 +122,7 @@ fn test_all_error_variants_display() {
             subset_size: 500,
             reason: "test".to_string(),
         },
+        SubsetError::NoGlyphsRetained { missing_chars: 3 },
         SubsetError::InvalidFont("test".to_string()),
         SubsetError::ChecksumError {
             table: "head".to_string(),
@@ -226,3 +227,226 @@ fn test_subset_with_unicode() {
     let unicode_text = "Résumé 日本語 😀";
     assert!(unicode_text.chars().any(|c| c as u32 > 127));
 }
+
+// ============================================================================
+// CFF subroutines
+// ============================================================================
+
+/// CFF INDEX with 2-byte offsets
+fn cff_index(items: &[&[u8]]) -> Vec<u8> {
+    let mut out = (items.len() as u16).to_be_bytes().to_vec();
+    if items.is_empty() {
+        return out;
+    }
+    out.push(2);
+    let mut offset = 1u16;
+    out.extend_from_slice(&offset.to_be_bytes());
+    for item in items {
+        offset += item.len() as u16;
+        out.extend_from_slice(&offset.to_be_bytes());
+    }
+    for item in items {
+        out.extend_from_slice(item);
+    }
+    out
+}
+
+/// 5-byte DICT integer operand, so offsets can be patched without resizing
+fn dict_int(value: i32) -> Vec<u8> {
+    let mut out = vec![29];
+    out.extend_from_slice(&value.to_be_bytes());
+    out
+}
+
+/// Name-keyed CFF table whose glyphs reach their outlines through subrs
+///
+/// Glyphs: 0 `.notdef`, 1 `A` (local subr 0), 2 `B` (global subr 0),
+/// 3 `C` (local subr 1, which calls global subr 0), 4 `D` (local subr 2).
+/// Subr indices are biased by -107 in charstrings (fewer than 1240 subrs).
+fn cff_table_with_subrs() -> Vec<u8> {
+    // rmoveto 100 100; callsubr/callgsubr; endchar
+    let glyph = |subr_operand: u8, call_op: u8| vec![239, 239, 21, subr_operand, call_op, 14];
+    let char_strings = [
+        vec![14],
+        glyph(32, 10), // callsubr 0
+        glyph(32, 29), // callgsubr 0
+        glyph(33, 10), // callsubr 1
+        glyph(34, 10), // callsubr 2
+    ];
+    let local_subrs: [&[u8]; 3] = [
+        // 200 0 rlineto 0 200 rlineto -200 0 rlineto return
+        &[247, 92, 139, 5, 139, 247, 92, 5, 251, 92, 139, 5, 11],
+        // callgsubr 0 return
+        &[32, 29, 11],
+        // 0 100 rlineto return
+        &[139, 239, 5, 11],
+    ];
+    // 0 150 rlineto 150 0 rlineto return
+    let global_subrs: [&[u8]; 1] = [&[139, 247, 42, 5, 247, 42, 139, 5, 11]];
+
+    let header = [1u8, 0, 4, 2];
+    let name_index = cff_index(&[b"SubrTest"]);
+    let string_index = cff_index(&[]);
+    let global_subr_index = cff_index(&global_subrs);
+    let char_strings: Vec<&[u8]> = char_strings.iter().map(Vec::as_slice).collect();
+    let char_string_index = cff_index(&char_strings);
+    let local_subr_index = cff_index(&local_subrs);
+
+    // Private DICT: Subrs (19) right after the 6-byte dict itself
+    let mut private_dict = dict_int(6);
+    private_dict.push(19);
+
+    // Top DICT: CharStrings (17) and Private (18), with fixed-size operands
+    let top_dict_len = 17;
+    let top_dict_index_len = 2 + 1 + 2 * 2 + top_dict_len;
+    let char_strings_offset = header.len()
+        + name_index.len()
+        + top_dict_index_len
+        + string_index.len()
+        + global_subr_index.len();
+    let private_offset = char_strings_offset + char_string_index.len();
+
+    let mut top_dict = dict_int(char_strings_offset as i32);
+    top_dict.push(17);
+    top_dict.extend(dict_int(private_dict.len() as i32));
+    top_dict.extend(dict_int(private_offset as i32));
+    top_dict.push(18);
+    assert_eq!(top_dict.len(), top_dict_len);
+
+    let mut cff = header.to_vec();
+    cff.extend(name_index);
+    cff.extend(cff_index(&[&top_dict]));
+    cff.extend(string_index);
+    cff.extend(global_subr_index);
+    cff.extend(char_string_index);
+    cff.extend(private_dict);
+    cff.extend(local_subr_index);
+    cff
+}
+
+/// Minimal OpenType font (`OTTO`) around [`cff_table_with_subrs`], mapping A-D
+fn cff_font_with_subrs() -> Vec<u8> {
+    const NUM_GLYPHS: u16 = 5;
+    let be16 = |values: &[u16]| {
+        values
+            .iter()
+            .flat_map(|v| v.to_be_bytes())
+            .collect::<Vec<_>>()
+    };
+
+    let mut head = Vec::new();
+    head.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // version
+    head.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // fontRevision
+    head.extend_from_slice(&0u32.to_be_bytes()); // checksumAdjustment
+    head.extend_from_slice(&0x5F0F_3CF5u32.to_be_bytes()); // magicNumber
+    head.extend(be16(&[0, 1000])); // flags, unitsPerEm
+    head.extend_from_slice(&[0; 16]); // created, modified
+    head.extend(be16(&[0, 0, 400, 400, 0, 8, 2, 0, 0])); // bbox .. glyphDataFormat
+
+    let mut hhea = 0x0001_0000u32.to_be_bytes().to_vec();
+    hhea.extend(be16(&[800, (-200i16) as u16, 0, 500, 0, 0, 400, 1, 0, 0]));
+    hhea.extend(be16(&[0, 0, 0, 0, 0, NUM_GLYPHS]));
+
+    let mut maxp = 0x0000_5000u32.to_be_bytes().to_vec();
+    maxp.extend(be16(&[NUM_GLYPHS]));
+
+    let hmtx = be16(&[500, 0].repeat(NUM_GLYPHS as usize));
+
+    // cmap format 4: 'A'..='D' -> glyphs 1..=4
+    let mut cmap = be16(&[0, 1, 3, 1, 0, 12]);
+    cmap.extend(be16(&[4, 32, 0, 4, 4, 1, 0]));
+    cmap.extend(be16(&[0x44, 0xFFFF, 0, 0x41, 0xFFFF]));
+    cmap.extend(be16(&[(1i16 - 0x41) as u16, 1, 0, 0]));
+
+    let mut post = 0x0003_0000u32.to_be_bytes().to_vec();
+    post.extend_from_slice(&[0; 28]);
+
+    let tables: [(&[u8; 4], Vec<u8>); 7] = [
+        (b"CFF ", cff_table_with_subrs()),
+        (b"cmap", cmap),
+        (b"head", head),
+        (b"hhea", hhea),
+        (b"hmtx", hmtx),
+        (b"maxp", maxp),
+        (b"post", post),
+    ];
+
+    let mut font = b"OTTO".to_vec();
+    font.extend(be16(&[tables.len() as u16, 64, 2, 48]));
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, data) in &tables {
+        font.extend_from_slice(*tag);
+        font.extend_from_slice(&0u32.to_be_bytes());
+        font.extend_from_slice(&(offset as u32).to_be_bytes());
+        font.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in &tables {
+        font.extend_from_slice(data);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+    font
+}
+
+fn glyph_bbox(face: &ttf_parser::Face, glyph_id: ttf_parser::GlyphId) -> ttf_parser::Rect {
+    struct NullOutline;
+    impl ttf_parser::OutlineBuilder for NullOutline {
+        fn move_to(&mut self, _: f32, _: f32) {}
+        fn line_to(&mut self, _: f32, _: f32) {}
+        fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+        fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+        fn close(&mut self) {}
+    }
+    face.outline_glyph(glyph_id, &mut NullOutline)
+        .unwrap_or_else(|| panic!("glyph {} has no outline", glyph_id.0))
+}
+
+#[test]
+fn test_cff_subset_keeps_glyphs_that_call_subroutines() {
+    use font_toolkit::{find_broken_cff_glyph, subset_font_core};
+    use ttf_parser::{Face, GlyphId};
+
+    let font = cff_font_with_subrs();
+    let face = Face::parse(&font, 0).expect("synthetic CFF font parses");
+    assert!(face.tables().cff.is_some());
+    assert!(find_broken_cff_glyph(&face).is_none());
+
+    // 'A' uses a local subr, 'C' a local subr that calls a global one;
+    // 'B' (global subr only) and 'D' (another local subr) are dropped
+    let (subset, metrics) = subset_font_core(&font, None, "AC", true).expect("CFF subset");
+    let metrics = metrics.unwrap();
+    let subset_face = Face::parse(&subset, 0).expect("subset parses");
+    assert_eq!(subset_face.number_of_glyphs(), 3);
+    assert!(find_broken_cff_glyph(&subset_face).is_none());
+
+    for ch in ['A', 'C'] {
+        let old_gid = face.glyph_index(ch).unwrap();
+        let new_gid = GlyphId(metrics.cid_to_new_gid[&(ch as u32)]);
+        assert_eq!(
+            glyph_bbox(&subset_face, new_gid),
+            glyph_bbox(&face, old_gid),
+            "outline of '{}' changed",
+            ch
+        );
+    }
+}
+
+#[test]
+fn test_find_broken_cff_glyph_reports_missing_subroutine() {
+    use font_toolkit::find_broken_cff_glyph;
+    use ttf_parser::Face;
+
+    // Point glyph 'D' at local subr 3, which does not exist
+    let mut font = cff_font_with_subrs();
+    let call = [239, 239, 21, 34, 10, 14];
+    let pos = font
+        .windows(call.len())
+        .rposition(|window| window == call)
+        .unwrap();
+    font[pos + 3] = 35;
+
+    let face = Face::parse(&font, 0).unwrap();
+    let (glyph_id, reason) = find_broken_cff_glyph(&face).expect("broken glyph found");
+    assert_eq!(glyph_id.0, 4);
+    assert!(reason.contains("Subroutine"), "reason: {}", reason);
+}
@@ -115,3 +115,109 @@ fn test_roboto_character_mapping() {
         "Should have glyphs for all ASCII alphanumeric characters"
     );
 }
+
+/// Brotli stream storing `data` in one uncompressed meta-block
+fn brotli_stored(data: &[u8]) -> Vec<u8> {
+    assert!(!data.is_empty() && data.len() <= 1 << 20);
+    // WBITS=16 (0), ISLAST=0, MNIBBLES=5 (01), MLEN-1 in 20 bits, ISUNCOMPRESSED=1
+    let bits: u32 = (1 << 2) | (((data.len() - 1) as u32) << 4);
+    let mut stream = bits.to_le_bytes()[..3].to_vec();
+    stream.push(0x01);
+    stream.extend_from_slice(data);
+    // Empty last meta-block: ISLAST=1, ISLASTEMPTY=1
+    stream.push(0x03);
+    stream
+}
+
+/// UIntBase128 encoding used by the WOFF2 table directory
+fn base128(mut value: u32) -> Vec<u8> {
+    let mut bytes = vec![(value & 0x7F) as u8];
+    value >>= 7;
+    while value > 0 {
+        bytes.insert(0, (value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes
+}
+
+/// WOFF2 collection of `num_faces` faces that all share the tables of `ttf`
+fn woff2_collection(ttf: &[u8], num_faces: u8) -> Vec<u8> {
+    let num_tables = u16::from_be_bytes([ttf[4], ttf[5]]);
+    let entries: Vec<&[u8]> = (0..num_tables as usize)
+        .map(|i| &ttf[12 + i * 16..28 + i * 16])
+        .collect();
+
+    // Collections must list loca directly after glyf
+    let mut ordered: Vec<&[u8]> = Vec::with_capacity(entries.len());
+    for entry in entries.iter().filter(|e| &e[0..4] != b"loca") {
+        ordered.push(entry);
+        if &entry[0..4] == b"glyf" {
+            ordered.extend(entries.iter().filter(|e| &e[0..4] == b"loca"));
+        }
+    }
+
+    let mut directory = Vec::new();
+    let mut table_data = Vec::new();
+    for entry in ordered {
+        let tag = &entry[0..4];
+        let offset = u32::from_be_bytes(entry[8..12].try_into().unwrap()) as usize;
+        let length = u32::from_be_bytes(entry[12..16].try_into().unwrap());
+
+        // Arbitrary tag (63); glyf/loca need transform version 3, the null transform
+        let null_transform = if tag == b"glyf" || tag == b"loca" {
+            0xC0
+        } else {
+            0
+        };
+        directory.push(63 | null_transform);
+        directory.extend_from_slice(tag);
+        directory.extend_from_slice(&base128(length));
+        table_data.extend_from_slice(&ttf[offset..offset + length as usize]);
+    }
+
+    // Collection directory: every face lists every table (255UInt16 values < 253)
+    directory.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    directory.push(num_faces);
+    for _ in 0..num_faces {
+        directory.push(num_tables as u8);
+        directory.extend_from_slice(&ttf[0..4]);
+        directory.extend(0..num_tables as u8);
+    }
+
+    let compressed = brotli_stored(&table_data);
+    let length = 48 + directory.len() + compressed.len();
+    let mut woff2 = Vec::with_capacity(length);
+    woff2.extend_from_slice(b"wOF2");
+    woff2.extend_from_slice(b"ttcf");
+    woff2.extend_from_slice(&(length as u32).to_be_bytes());
+    woff2.extend_from_slice(&num_tables.to_be_bytes());
+    woff2.extend_from_slice(&0u16.to_be_bytes()); // reserved
+    woff2.extend_from_slice(&(ttf.len() as u32).to_be_bytes()); // totalSfntSize
+    woff2.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+    woff2.extend_from_slice(&[0, 1, 0, 0]); // major/minor version
+    woff2.extend_from_slice(&[0; 20]); // no metadata or private data
+    woff2.extend_from_slice(&directory);
+    woff2.extend_from_slice(&compressed);
+    woff2
+}
+
+#[test]
+fn test_woff2_collection_yields_first_face() {
+    const OPEN_SANS_BOLD: &[u8] =
+        include_bytes!("../../../../test-fixtures/fonts/OpenSans-Bold.ttf");
+    let collection = woff2_collection(OPEN_SANS_BOLD, 2);
+    assert_eq!(&collection[4..8], b"ttcf");
+
+    let ttf_bytes = decompress_woff2(&collection).expect("collection decompresses");
+    assert_eq!(
+        &ttf_bytes[0..4],
+        &[0x00, 0x01, 0x00, 0x00],
+        "first face is returned as a standalone TrueType font"
+    );
+
+    let face = ttf_parser::Face::parse(&ttf_bytes, 0).expect("valid TrueType");
+    let original = ttf_parser::Face::parse(OPEN_SANS_BOLD, 0).unwrap();
+    assert_eq!(face.number_of_glyphs(), original.number_of_glyphs());
+    assert_eq!(face.glyph_index('W'), original.glyph_index('W'));
+    assert_eq!(ttf_parser::fonts_in_collection(&ttf_bytes), None);
+}
@@ -7,9 +7,11 @@
 //! Pipeline: TSX → TsxDocument → Taffy Node → LayoutBox → LayoutStructure
 
 // Sub-modules
+pub mod baseline_grid;
 pub mod box_extractor;
 pub mod content_area;
 pub mod element_mapping;
+pub mod fit_to_pages;
 pub mod layout_element;
 pub mod style_conversion;
 pub mod text_measurement;
@@ -18,33 +20,47 @@ pub mod tree_builder;
 use crate::error::LayoutError;
 use crate::pagination::paginate_boxes;
 use cv_domain::CVMetadata;
-use cv_domain::TSXLayoutConfig;
-use layout_types::{BoxContent, LayoutStructure, TextMeasurer};
+use cv_domain::{TSXLayoutConfig, TextDirection};
+use layout_types::{
+    BoxContent, LayoutStructure, Page, Position, StyleDeclaration, TextAlign, TextMeasurer,
+};
 use pdf_generator::config::PDFConfig;
+use pdf_generator::FontSubstitution;
 use std::collections::HashMap;
+use style_resolver::Stylesheet;
 use taffy::prelude::*;
-use tsx_parser::TsxDocument;
+use tsx_parser::{JSXElement, TsxDocument};
 
 // Imports from sub-modules
+use baseline_grid::snap_to_baseline_grid;
 use box_extractor::taffy_to_layout_boxes;
-use content_area::calculate_content_area;
-use tree_builder::jsx_to_taffy;
+use content_area::{calculate_content_area, ContentArea};
+use fit_to_pages::{initial_fit_scale, min_fit_scale, next_fit_scale, scale_tree};
+use tree_builder::{jsx_to_taffy, reparent_absolute_nodes, resolve_missing_fonts};
 
 /// Recursively flatten containers to extract leaf boxes, but preserve flex containers
 ///
 /// This converts a nested LayoutBox tree into a mostly-flat list of boxes.
 /// Regular containers are flattened (children extracted), but flex containers
 /// are preserved so their children maintain horizontal positioning.
+/// Keep-together containers (see [`layout_types::ElementType::keeps_together`]) no taller
+/// than `keep_together_height` are preserved too, so pagination moves them
+/// as a unit, and so are containers with a top, left or right border.
+/// Absolutely positioned containers are preserved as well, so pagination can
+/// place them as one out-of-flow box.
 ///
 /// # Arguments
 ///
 /// * `boxes` - Vector of LayoutBox (may contain nested containers)
+/// * `keep_together_height` - Tallest keep-together container to preserve
+///   (the page content height)
 ///
 /// # Returns
 ///
 /// Vector of LayoutBox with flex containers preserved
 fn flatten_containers_recursively(
     boxes: &[layout_types::LayoutBox],
+    keep_together_height: f64,
 ) -> Vec<layout_types::LayoutBox> {
     use layout_types::Display;
 
@@ -60,20 +76,41 @@ fn flatten_containers_recursively(
                 let is_flex_row =
                     is_flex && box_item.style.flex.flex_direction != Some(FlexDirection::Column);
 
-                if is_flex_row {
+                // Full-bleed containers (edge-to-edge sidebars) must survive flattening
+                // so the renderer can paint their background out to the page edge
+                let is_full_bleed = box_item.style.box_model.full_bleed == Some(true);
+
+                // Entries that fit on a page stay whole (implicit break-inside: avoid)
+                let keeps_together = box_item.element_type.is_some_and(|et| et.keeps_together())
+                    && box_item.height <= keep_together_height;
+
+                // Full-box borders (not just border-bottom) frame the container,
+                // so it must survive flattening for the renderer to draw them
+                let box_model = &box_item.style.box_model;
+                let has_side_border = box_model.border_top.is_some()
+                    || box_model.border_right.is_some()
+                    || box_model.border_left.is_some();
+
+                // Absolute boxes are placed by their insets, not by the flow
+                let is_absolute = box_model.position == Some(Position::Absolute);
+
+                if is_flex_row || is_full_bleed || keeps_together || has_side_border || is_absolute
+                {
                     // Preserve flex-row container as-is (children maintain their x positions)
                     // But recursively process children in case they have nested structures
-                    let processed_children = flatten_containers_recursively(children);
+                    let processed_children =
+                        flatten_containers_recursively(children, keep_together_height);
                     let mut preserved_box = box_item.clone();
                     preserved_box.content = BoxContent::Container(processed_children.clone());
 
                     // Propagate heading element_type from children to container for orphan prevention
                     // If container wraps a heading (e.g., job title in flex row), mark it as heading
                     // Check if container is NOT already a heading type before propagating
+                    // (keep-together containers keep their own type so pagination sees it)
                     let is_already_heading =
                         preserved_box.element_type.is_some_and(|et| et.is_heading());
 
-                    if !is_already_heading {
+                    if !is_already_heading && !keeps_together {
                         for child in &processed_children {
                             if let Some(et) = child.element_type {
                                 if et.is_heading() {
@@ -87,7 +124,8 @@ fn flatten_containers_recursively(
                     flattened.push(preserved_box);
                 } else {
                     // Regular container - flatten by extracting children
-                    let mut child_boxes = flatten_containers_recursively(children);
+                    let mut child_boxes =
+                        flatten_containers_recursively(children, keep_together_height);
 
                     // If container has border-bottom, create a border-only box
                     // and propagate margin-bottom to the border (the last visual element)
@@ -164,6 +202,18 @@ fn flatten_containers_recursively(
     flattened
 }
 
+/// Print rules from every `<style>` element in the document, with media
+/// features evaluated against `page_size`
+fn collect_stylesheet(jsx_elements: &[&JSXElement], page_size: (f64, f64)) -> Stylesheet {
+    let css = jsx_elements
+        .iter()
+        .filter(|element| tsx_parser::extract_element_name(element) == "style")
+        .map(|element| tsx_parser::extract_text_content(element).join("\n"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Stylesheet::parse(&css, page_size)
+}
+
 /// Direct layout calculation entry point
 ///
 /// Converts TSX document directly to LayoutStructure without RenderNode intermediate layer.
@@ -181,11 +231,106 @@ fn flatten_containers_recursively(
 /// Paginated LayoutStructure ready for PDF rendering
 pub fn calculate_layout_direct(
     document: &TsxDocument,
-    _metadata: &CVMetadata,
+    metadata: &CVMetadata,
     layout_config: &TSXLayoutConfig,
     pdf_config: &PDFConfig,
     measurer: &dyn TextMeasurer,
 ) -> Result<LayoutStructure, LayoutError> {
+    calculate_layout_direct_with_progress(
+        document,
+        metadata,
+        layout_config,
+        pdf_config,
+        measurer,
+        None,
+    )
+}
+
+/// Direct layout calculation with pagination progress reporting
+///
+/// Same as [`calculate_layout_direct`], but `progress` receives 0.0-100.0 as
+/// pages are completed during pagination, the longest phase for multi-page CVs.
+pub fn calculate_layout_direct_with_progress(
+    document: &TsxDocument,
+    metadata: &CVMetadata,
+    layout_config: &TSXLayoutConfig,
+    pdf_config: &PDFConfig,
+    measurer: &dyn TextMeasurer,
+    progress: Option<&dyn Fn(f32)>,
+) -> Result<LayoutStructure, LayoutError> {
+    fit_layout_direct(
+        document,
+        metadata,
+        layout_config,
+        pdf_config,
+        measurer,
+        progress,
+    )
+    .map(|fitted| fitted.layout)
+}
+
+/// Layout together with the uniform scale applied to fit it
+#[derive(Debug, Clone)]
+pub struct FittedLayout {
+    /// Paginated layout ready for PDF rendering
+    pub layout: LayoutStructure,
+    /// Factor applied to font sizes and spacing (1.0 = natural size)
+    pub scale: f64,
+    /// Requested fonts replaced according to `missing_font_policy`
+    pub font_substitutions: Vec<FontSubstitution>,
+}
+
+/// Direct layout calculation honoring `PDFConfig::fit_to_pages`
+///
+/// Lays the document out at its natural size first. When that needs more
+/// pages than `fit_to_pages` allows, font sizes, line heights and spacing are
+/// shrunk by a uniform factor, re-estimated from the remaining overflow after
+/// each attempt until the content fits. If fitting would take
+/// text below [`fit_to_pages::MIN_FIT_FONT_SIZE`], the natural layout is
+/// returned with its extra pages and a scale of 1.0.
+///
+/// `progress` only follows the natural layout pass. Every requested font
+/// is assumed to be supplied; use [`fit_layout_direct_with_fonts`] to apply
+/// `missing_font_policy` before text is measured.
+pub fn fit_layout_direct(
+    document: &TsxDocument,
+    metadata: &CVMetadata,
+    layout_config: &TSXLayoutConfig,
+    pdf_config: &PDFConfig,
+    measurer: &dyn TextMeasurer,
+    progress: Option<&dyn Fn(f32)>,
+) -> Result<FittedLayout, LayoutError> {
+    fit_layout_direct_with_fonts(
+        document,
+        metadata,
+        layout_config,
+        pdf_config,
+        measurer,
+        &|_, _, _| true,
+        progress,
+    )
+}
+
+/// Direct layout calculation resolving missing fonts before measurement
+///
+/// Same as [`fit_layout_direct`], but `is_supplied(family, weight, italic)`
+/// reports which font variants have data, and `PDFConfig::missing_font_policy`
+/// decides what replaces the others. Substitutions are reported in
+/// [`FittedLayout::font_substitutions`].
+///
+/// # Errors
+///
+/// Returns `LayoutError::FontError` for a missing font under
+/// `MissingFontPolicy::Error`.
+pub fn fit_layout_direct_with_fonts(
+    document: &TsxDocument,
+    _metadata: &CVMetadata,
+    layout_config: &TSXLayoutConfig,
+    pdf_config: &PDFConfig,
+    measurer: &dyn TextMeasurer,
+    is_supplied: &dyn Fn(&str, u16, bool) -> bool,
+    progress: Option<&dyn Fn(f32)>,
+) -> Result<FittedLayout, LayoutError> {
     // 1. Extract JSX elements from document
     let jsx_elements = tsx_parser::extract_jsx_elements(document);
 
@@ -195,26 +340,127 @@ pub fn calculate_layout_direct(
         ));
     }
 
-    // 2. Build Taffy tree from JSX elements
-    let mut taffy_tree = TaffyTree::new();
-    let mut node_info_map = HashMap::new();
     let root_jsx = jsx_elements
         .first()
         .ok_or_else(|| LayoutError::CalculationFailed("No root element".to_string()))?;
 
+    // RTL documents default to right-aligned text; explicit alignment still wins
+    let rtl_root_style = (layout_config.direction == TextDirection::Rtl).then(|| {
+        let mut style = StyleDeclaration::default();
+        style.text.text_align = Some(TextAlign::Right);
+        style
+    });
+    let (page_width, page_height) = pdf_config.page_size.dimensions();
+    let stylesheet = collect_stylesheet(&jsx_elements, (page_width, page_height));
+
+    // Calculate content area using TSX layout config
+    let content = calculate_content_area(page_width, page_height, pdf_config, layout_config);
+
+    let layout_at = |scale: f64, progress: Option<&dyn Fn(f32)>| {
+        layout_pages(
+            root_jsx,
+            rtl_root_style.as_ref(),
+            &stylesheet,
+            &content,
+            pdf_config,
+            measurer,
+            is_supplied,
+            scale,
+            progress,
+        )
+    };
+
+    let (natural_pages, min_scale, font_substitutions) = layout_at(1.0, progress)?;
+    let mut pages = natural_pages;
+    let mut scale = 1.0;
+
+    if let Some(target) = pdf_config.fit_to_pages.filter(|&target| target > 0) {
+        if pages.len() > target && min_scale < 1.0 {
+            let available = target as f64 * content.height;
+            let needed = needed_content_height(&pages, content.y, content.height);
+            let mut candidate =
+                initial_fit_scale(needed, available).clamp(min_scale, 1.0 - f64::EPSILON);
+            loop {
+                let (scaled_pages, _, _) = layout_at(candidate, None)?;
+                if scaled_pages.len() <= target {
+                    pages = scaled_pages;
+                    scale = candidate;
+                    break;
+                }
+                if candidate <= min_scale {
+                    break;
+                }
+                let needed = needed_content_height(&scaled_pages, content.y, content.height);
+                candidate = next_fit_scale(candidate, needed, available).max(min_scale);
+            }
+        }
+    }
+
+    Ok(FittedLayout {
+        layout: LayoutStructure {
+            page_width,
+            page_height,
+            pages,
+        },
+        scale,
+        font_substitutions,
+    })
+}
+
+/// Content height the pages occupy, counting every page but the last as full
+fn needed_content_height(pages: &[Page], content_y: f64, content_height: f64) -> f64 {
+    let last_bottom = pages
+        .last()
+        .into_iter()
+        .flat_map(|page| &page.boxes)
+        .map(|b| b.y + b.height)
+        .fold(content_y, f64::max);
+    pages.len().saturating_sub(1) as f64 * content_height + (last_bottom - content_y)
+}
+
+/// Build, lay out and paginate the document with every size scaled by `scale`
+///
+/// Also returns the smallest scale the document's text allows and the
+/// fonts substituted for missing ones.
+#[allow(clippy::too_many_arguments)]
+fn layout_pages(
+    root_jsx: &JSXElement,
+    root_style: Option<&StyleDeclaration>,
+    stylesheet: &Stylesheet,
+    content: &ContentArea,
+    pdf_config: &PDFConfig,
+    measurer: &dyn TextMeasurer,
+    is_supplied: &dyn Fn(&str, u16, bool) -> bool,
+    scale: f64,
+    progress: Option<&dyn Fn(f32)>,
+) -> Result<(Vec<Page>, f64, Vec<FontSubstitution>), LayoutError> {
+    // 2. Build Taffy tree from JSX elements
+    let mut taffy_tree = TaffyTree::new();
+    let mut node_info_map = HashMap::new();
+
     let root_id = jsx_to_taffy(
         &mut taffy_tree,
         &mut node_info_map,
         root_jsx,
-        None,
+        root_style,
+        stylesheet,
         measurer,
     )?;
+    reparent_absolute_nodes(&mut taffy_tree, &node_info_map, root_id)?;
+    let font_substitutions = resolve_missing_fonts(
+        &mut taffy_tree,
+        &mut node_info_map,
+        root_id,
+        &pdf_config.missing_font_policy,
+        is_supplied,
+    )?;
 
-    // 3. Compute layout using Taffy
-    let (page_width, page_height) = pdf_config.page_size.dimensions();
+    let min_scale = min_fit_scale(&node_info_map);
+    if scale != 1.0 {
+        scale_tree(&mut taffy_tree, &mut node_info_map, scale)?;
+    }
 
-    // Calculate content area using TSX layout config
-    let content = calculate_content_area(page_width, page_height, pdf_config, layout_config);
+    // 3. Compute layout using Taffy
     let (content_x, content_y, content_width, content_height) =
         (content.x, content.y, content.width, content.height);
 
@@ -248,20 +494,27 @@ pub fn calculate_layout_direct(
 
     // 5. Recursively flatten ALL containers to get leaf boxes for pagination
     // This ensures orphan prevention works on individual headings, not coarse sections
-    let mut boxes_to_paginate = flatten_containers_recursively(&positioned_boxes);
+    let mut boxes_to_paginate = flatten_containers_recursively(&positioned_boxes, content_height);
 
     // Filter out empty boxes (height = 0) that may result from widow/orphan prevention
     // BUT keep zero-height boxes that have borders (they're used to render container borders)
     boxes_to_paginate.retain(|b| b.height > 0.0 || b.style.box_model.border_bottom.is_some());
 
-    // 6. Apply pagination
-    let pages = paginate_boxes(boxes_to_paginate, content_y, content_height)?;
+    // 6. Optionally align text baselines to a shared vertical grid
+    if let Some(step) = pdf_config.baseline_grid {
+        snap_to_baseline_grid(&mut boxes_to_paginate, step, content_y);
+    }
 
-    Ok(LayoutStructure {
-        page_width,
-        page_height,
-        pages,
-    })
+    // 7. Apply pagination
+    let pages = paginate_boxes(
+        boxes_to_paginate,
+        content_y,
+        content_height,
+        pdf_config.baseline_grid,
+        progress,
+    )?;
+
+    Ok((pages, min_scale, font_substitutions))
 }
 
 #[cfg(test)]
@@ -295,9 +548,11 @@ mod tests {
                 content: BoxContent::Text(vec![TextLine::from(text)]),
                 style: layout_types::StyleDeclaration::default(),
                 element_type: Some(layout_types::ElementType::Heading2),
+                list_ordinal: None,
             }]),
             style,
             element_type: Some(layout_types::ElementType::Heading2),
+            list_ordinal: None,
         }
     }
 
@@ -308,7 +563,7 @@ mod tests {
         let boxes = vec![h2_container];
 
         // Flatten
-        let flattened = flatten_containers_recursively(&boxes);
+        let flattened = flatten_containers_recursively(&boxes, f64::MAX);
 
         // Should have 2 boxes: text child and border box
         assert_eq!(flattened.len(), 2, "Should have text child and border box");
@@ -354,6 +609,7 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("Job content here")]),
             style: layout_types::StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         // Create section container (no border, not flex)
@@ -365,10 +621,11 @@ mod tests {
             content: BoxContent::Container(vec![h2_container, div_child]),
             style: layout_types::StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let boxes = vec![section];
-        let flattened = flatten_containers_recursively(&boxes);
+        let flattened = flatten_containers_recursively(&boxes, f64::MAX);
 
         // Should have 3 boxes: h2 text, h2 border, div content
         assert_eq!(
@@ -432,13 +689,15 @@ mod tests {
                 content: BoxContent::Text(vec![TextLine::from("Title")]),
                 style: layout_types::StyleDeclaration::default(),
                 element_type: None,
+                list_ordinal: None,
             }]),
             style,
             element_type: None,
+            list_ordinal: None,
         };
 
         let boxes = vec![flex_container];
-        let flattened = flatten_containers_recursively(&boxes);
+        let flattened = flatten_containers_recursively(&boxes, f64::MAX);
 
         // Flex container should be preserved as a single box
         assert_eq!(
@@ -451,4 +710,59 @@ mod tests {
             "Should still be a container"
         );
     }
+
+    #[test]
+    fn test_flatten_preserves_full_bleed_container() {
+        // Block sidebar (not flex) marked full-bleed should keep its container
+        let mut style = layout_types::StyleDeclaration::default();
+        style.box_model.full_bleed = Some(true);
+        style.box_model.background_color = Some(Color {
+            r: 31,
+            g: 41,
+            b: 55,
+            a: 1.0,
+        });
+
+        let sidebar = layout_types::LayoutBox {
+            x: 0.0,
+            y: 36.0,
+            width: 180.0,
+            height: 200.0,
+            content: BoxContent::Container(vec![layout_types::LayoutBox {
+                x: 12.0,
+                y: 48.0,
+                width: 156.0,
+                height: 20.0,
+                content: BoxContent::Text(vec![TextLine::from("Skills")]),
+                style: layout_types::StyleDeclaration::default(),
+                element_type: None,
+                list_ordinal: None,
+            }]),
+            style,
+            element_type: Some(layout_types::ElementType::Div),
+            list_ordinal: None,
+        };
+
+        let flattened = flatten_containers_recursively(&[sidebar], f64::MAX);
+
+        assert_eq!(flattened.len(), 1, "Full-bleed sidebar should be preserved");
+        assert!(
+            matches!(&flattened[0].content, BoxContent::Container(_)),
+            "Should still be a container"
+        );
+        assert!(flattened[0].style.box_model.background_color.is_some());
+    }
+
+    #[test]
+    fn test_flatten_preserves_container_with_full_box_border() {
+        let mut card = create_container_with_border("Projects", 100.0);
+        card.style.box_model.border_top = card.style.box_model.border_bottom.clone();
+        card.style.box_model.border_left = card.style.box_model.border_bottom.clone();
+        card.style.box_model.border_right = card.style.box_model.border_bottom.clone();
+
+        let flattened = flatten_containers_recursively(&[card], f64::MAX);
+
+        assert_eq!(flattened.len(), 1, "Bordered card should be preserved");
+        assert!(matches!(&flattened[0].content, BoxContent::Container(_)));
+    }
 }
@@ -0,0 +1,184 @@
+//! Baseline grid snapping
+//!
+//! Aligns every text baseline to a fixed vertical grid counted from the top
+//! of the content area, so adjacent columns and sections share baselines.
+
+use layout_types::{BoxContent, LayoutBox, DEFAULT_FONT_SIZE, DEFAULT_LINE_HEIGHT_RATIO};
+use pdf_generator::line_baseline_offset;
+
+/// Tolerance for comparing positions that should coincide exactly
+const EPSILON: f64 = 1e-6;
+
+/// Snap laid-out boxes to a baseline grid of `step` points before pagination
+///
+/// Each text box's line height is rounded to the nearest multiple of `step`
+/// (at least one step), then the box moves down to the first grid line at or
+/// below its first baseline. Whatever a box moves or grows by pushes the
+/// boxes stacked below it down by the same amount, and containers move and
+/// grow with their children so their backgrounds and borders stay around
+/// them. Non-positive or non-finite steps leave the boxes as-is.
+pub fn snap_to_baseline_grid(boxes: &mut [LayoutBox], step: f64, content_top: f64) {
+    if !(step.is_finite() && step > 0.0) {
+        return;
+    }
+    snap_stack(boxes, 0.0, step, content_top);
+}
+
+/// Snap sibling boxes, each starting from the largest push of the siblings
+/// stacked above it (or `inherited`, the shift of their parent)
+///
+/// Returns the largest push among the siblings. Siblings side by side (a flex
+/// row's columns) don't push each other.
+fn snap_stack(boxes: &mut [LayoutBox], inherited: f64, step: f64, content_top: f64) -> f64 {
+    // Original bottom edge and push of every sibling snapped so far
+    let mut snapped: Vec<(f64, f64)> = Vec::with_capacity(boxes.len());
+    for layout_box in boxes.iter_mut() {
+        let original_top = layout_box.y;
+        let original_bottom = layout_box.y + layout_box.height;
+        let shift = snapped
+            .iter()
+            .filter(|(bottom, _)| *bottom <= original_top + EPSILON)
+            .map(|(_, push)| *push)
+            .fold(inherited, f64::max);
+        snap_box(layout_box, shift, step, content_top);
+        snapped.push((
+            original_bottom,
+            layout_box.y + layout_box.height - original_bottom,
+        ));
+    }
+    snapped
+        .iter()
+        .map(|(_, push)| *push)
+        .fold(inherited, f64::max)
+}
+
+/// Move a box down by `shift`, then snap its own text to the grid
+fn snap_box(layout_box: &mut LayoutBox, shift: f64, step: f64, content_top: f64) {
+    layout_box.y += shift;
+    match &mut layout_box.content {
+        BoxContent::Text(lines) => {
+            let Some(first_line) = lines.first() else {
+                return;
+            };
+            let text = &mut layout_box.style.text;
+            let font_size = text.font_size.unwrap_or(DEFAULT_FONT_SIZE);
+            let line_height = text
+                .line_height
+                .unwrap_or(font_size * DEFAULT_LINE_HEIGHT_RATIO);
+            let snapped_line_height = (line_height / step).round().max(1.0) * step;
+            text.line_height = Some(snapped_line_height);
+            layout_box.height += (snapped_line_height - line_height) * lines.len() as f64;
+
+            let baseline =
+                layout_box.y + line_baseline_offset(&layout_box.style, first_line, 0) - content_top;
+            let grid_line = (baseline / step - EPSILON).ceil() * step;
+            layout_box.y += grid_line - baseline;
+        }
+        BoxContent::Container(children) => {
+            let push = snap_stack(children, shift, step, content_top);
+            layout_box.height += push - shift;
+        }
+        BoxContent::Empty => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use layout_types::{StyleDeclaration, TextLine};
+
+    fn text_box(y: f64, line_count: usize) -> LayoutBox {
+        let mut style = StyleDeclaration::default();
+        style.text.font_size = Some(10.0);
+        style.text.line_height = Some(14.0);
+        LayoutBox {
+            x: 36.0,
+            y,
+            width: 200.0,
+            height: 14.0 * line_count as f64,
+            content: BoxContent::Text(vec![TextLine::from("Senior engineer"); line_count]),
+            style,
+            element_type: None,
+            list_ordinal: None,
+        }
+    }
+
+    fn container(children: Vec<LayoutBox>) -> LayoutBox {
+        let top = children.iter().map(|c| c.y).fold(f64::INFINITY, f64::min);
+        let bottom = children.iter().map(|c| c.y + c.height).fold(top, f64::max);
+        LayoutBox {
+            x: 36.0,
+            y: top,
+            width: 400.0,
+            height: bottom - top,
+            content: BoxContent::Container(children),
+            style: StyleDeclaration::default(),
+            element_type: None,
+            list_ordinal: None,
+        }
+    }
+
+    #[test]
+    fn test_snap_rounds_line_height_and_moves_first_baseline_down() {
+        let mut boxes = vec![text_box(40.0, 3)];
+        snap_to_baseline_grid(&mut boxes, 12.0, 36.0);
+
+        let snapped = &boxes[0];
+        assert_eq!(snapped.style.text.line_height, Some(12.0));
+        assert_eq!(snapped.height, 36.0);
+        // Baseline was 4 + 10 = 14 below the top margin; next grid line is 24
+        assert_eq!(snapped.y, 50.0);
+    }
+
+    #[test]
+    fn test_snap_pushes_following_boxes_down() {
+        let mut boxes = vec![text_box(40.0, 1), text_box(54.0, 1)];
+        snap_to_baseline_grid(&mut boxes, 12.0, 36.0);
+
+        let first_bottom = boxes[0].y + boxes[0].height;
+        assert!(
+            boxes[1].y >= first_bottom,
+            "second box at {} overlaps first box ending at {}",
+            boxes[1].y,
+            first_bottom
+        );
+    }
+
+    #[test]
+    fn test_container_moves_and_grows_with_children() {
+        let mut boxes = vec![container(vec![text_box(40.0, 1), text_box(54.0, 1)])];
+        snap_to_baseline_grid(&mut boxes, 12.0, 36.0);
+
+        let parent = &boxes[0];
+        let BoxContent::Container(children) = &parent.content else {
+            panic!("expected container");
+        };
+        let last = children.last().unwrap();
+        assert!(parent.y <= children[0].y);
+        assert!(parent.y + parent.height >= last.y + last.height);
+    }
+
+    #[test]
+    fn test_side_by_side_children_do_not_push_each_other() {
+        let mut left = text_box(40.0, 1);
+        let mut right = text_box(40.0, 1);
+        right.x = 240.0;
+        left.style.text.line_height = Some(20.0);
+        left.height = 20.0;
+        let mut boxes = vec![container(vec![left, right])];
+        snap_to_baseline_grid(&mut boxes, 12.0, 36.0);
+
+        let BoxContent::Container(children) = &boxes[0].content else {
+            panic!("expected container");
+        };
+        assert_eq!(children[1].y, 50.0);
+    }
+
+    #[test]
+    fn test_invalid_step_is_ignored() {
+        let mut boxes = vec![text_box(40.0, 1)];
+        snap_to_baseline_grid(&mut boxes, 0.0, 36.0);
+        assert_eq!(boxes[0].y, 40.0);
+        assert_eq!(boxes[0].style.text.line_height, Some(14.0));
+    }
+}
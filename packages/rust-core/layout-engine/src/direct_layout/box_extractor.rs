@@ -3,12 +3,12 @@
 //! This module converts Taffy's computed layout results into ResumeWright's
 //! LayoutBox tree structure, which is then used for PDF rendering.
 
-use super::text_measurement::TextMeasureContext;
+use super::text_measurement::{column_width, resolve_columns, TextMeasureContext};
 use crate::error::LayoutError;
-use crate::text_layout::TextLayoutConfig;
+use crate::text_layout::{fit_with_ellipsis, TextLayoutConfig, ELLIPSIS};
 use layout_types::{
     BoxContent, ElementType, LayoutBox, Rect, StyleDeclaration, TextLine, TextMeasurer,
-    TextSegment, DEFAULT_FONT_FAMILY, DEFAULT_FONT_SIZE,
+    TextSegment, DEFAULT_FONT_FAMILY, DEFAULT_FONT_SIZE, DEFAULT_LINE_HEIGHT_RATIO,
 };
 use std::collections::HashMap;
 use taffy::prelude::*;
@@ -23,6 +23,8 @@ pub struct JsxElementInfo {
     pub element_type: Option<ElementType>,
     pub style: StyleDeclaration,
     pub content_type: ContentType,
+    /// Position of a list item within its list (see [`LayoutBox::list_ordinal`])
+    pub list_ordinal: Option<usize>,
 }
 
 /// Content type for a layout node
@@ -88,7 +90,12 @@ pub fn taffy_to_layout_boxes(
 
     match &info.content_type {
         ContentType::Text(segments) => {
-            extract_text_box(segments, bounds, &info.style, info.element_type, measurer)
+            let mut boxes =
+                extract_text_box(segments, bounds, &info.style, info.element_type, measurer)?;
+            for text_box in &mut boxes {
+                text_box.list_ordinal = info.list_ordinal;
+            }
+            Ok(boxes)
         }
         ContentType::Container => {
             extract_container_box(tree, node_info_map, node_id, bounds, info, measurer)
@@ -111,6 +118,28 @@ fn extract_text_box(
         .clone()
         .unwrap_or_else(|| DEFAULT_FONT_FAMILY.to_string());
 
+    let (column_count, column_gap) = resolve_columns(style, font_size);
+    if column_count > 1 {
+        let column_width = column_width(bounds.width, column_count, column_gap);
+        let lines = wrap_styled_segments(segments, column_width, font_size, &font_name, measurer)?;
+        let lines = clamp_styled_lines(
+            lines,
+            style.text.line_clamp,
+            column_width,
+            font_size,
+            &font_name,
+            measurer,
+        );
+        return Ok(split_lines_into_columns(
+            lines,
+            bounds,
+            style,
+            element_type,
+            column_count,
+            column_gap,
+        ));
+    }
+
     // Concatenate all segment text to check total width
     let full_text: String = segments.iter().map(|s| s.text.as_str()).collect();
 
@@ -124,7 +153,15 @@ fn extract_text_box(
         vec![TextLine::from_segments(segments.to_vec())]
     } else {
         // Need to wrap - use styled text wrapping
-        wrap_styled_segments(segments, bounds.width, font_size, &font_name, measurer)?
+        let lines = wrap_styled_segments(segments, bounds.width, font_size, &font_name, measurer)?;
+        clamp_styled_lines(
+            lines,
+            style.text.line_clamp,
+            bounds.width,
+            font_size,
+            &font_name,
+            measurer,
+        )
     };
 
     Ok(vec![LayoutBox {
@@ -135,9 +172,50 @@ fn extract_text_box(
         content: BoxContent::Text(lines),
         style: style.clone(),
         element_type,
+        list_ordinal: None,
     }])
 }
 
+/// Distribute wrapped lines across side-by-side column boxes (CSS column-count)
+///
+/// Lines flow top-to-bottom, filling each column with an equal share before
+/// moving on to the next, so column heights are balanced. Always returns
+/// `column_count` boxes; columns left without lines are empty.
+fn split_lines_into_columns(
+    lines: Vec<TextLine>,
+    bounds: Rect,
+    style: &StyleDeclaration,
+    element_type: Option<ElementType>,
+    column_count: usize,
+    column_gap: f64,
+) -> Vec<LayoutBox> {
+    let font_size = style.text.font_size.unwrap_or(DEFAULT_FONT_SIZE);
+    let line_height = style
+        .text
+        .line_height
+        .unwrap_or(font_size * DEFAULT_LINE_HEIGHT_RATIO);
+    let width = column_width(bounds.width, column_count, column_gap);
+    let lines_per_column = lines.len().div_ceil(column_count).max(1);
+
+    lines
+        .chunks(lines_per_column)
+        .map(<[TextLine]>::to_vec)
+        .chain(std::iter::repeat_with(Vec::new))
+        .take(column_count)
+        .enumerate()
+        .map(|(i, column_lines)| LayoutBox {
+            x: bounds.x + i as f64 * (width + column_gap),
+            y: bounds.y,
+            width,
+            height: column_lines.len() as f64 * line_height,
+            content: BoxContent::Text(column_lines),
+            style: style.clone(),
+            element_type,
+            list_ordinal: None,
+        })
+        .collect()
+}
+
 /// Wrap styled segments across multiple lines while preserving styling
 fn wrap_styled_segments(
     segments: &[TextSegment],
@@ -153,14 +231,16 @@ fn wrap_styled_segments(
 
     for segment in segments {
         // Wrap this segment's text, preserving its style
-        let segment_words: Vec<&str> = segment.text.split_whitespace().collect();
+        let segment_words = split_words_keeping_tabs(&segment.text);
 
-        for (i, word) in segment_words.iter().enumerate() {
+        for (i, (word, after_tab)) in segment_words.iter().enumerate() {
+            // A tab is kept so the renderer can place the next column at its stop
+            let separator = if *after_tab { '\t' } else { ' ' };
             let word_with_space = if i == 0 && !current_line_segments.is_empty() {
                 // Add space before word if not at start of line
-                format!(" {}", word)
+                format!("{}{}", separator, word)
             } else if i > 0 {
-                format!(" {}", word)
+                format!("{}{}", separator, word)
             } else {
                 word.to_string()
             };
@@ -182,6 +262,7 @@ fn wrap_styled_segments(
                     font_size: segment.font_size,
                     text_decoration: segment.text_decoration,
                     color: segment.color,
+                    link: segment.link.clone(),
                 });
                 current_line_width = word_only_width;
             } else {
@@ -193,6 +274,7 @@ fn wrap_styled_segments(
                         && last_seg.font_size == segment.font_size
                         && last_seg.text_decoration == segment.text_decoration
                         && last_seg.color == segment.color
+                        && last_seg.link == segment.link
                     {
                         last_seg.text.push_str(&word_with_space);
                         current_line_width += word_width;
@@ -207,6 +289,7 @@ fn wrap_styled_segments(
                     font_size: segment.font_size,
                     text_decoration: segment.text_decoration,
                     color: segment.color,
+                    link: segment.link.clone(),
                 });
                 current_line_width += word_width;
             }
@@ -226,6 +309,76 @@ fn wrap_styled_segments(
     Ok(lines)
 }
 
+/// Whitespace-separated words of `text`, each with whether a tab precedes it
+fn split_words_keeping_tabs(text: &str) -> Vec<(&str, bool)> {
+    let mut words = Vec::new();
+    let mut rest = text;
+    loop {
+        let word_start = rest.trim_start();
+        if word_start.is_empty() {
+            return words;
+        }
+        let after_tab = rest[..rest.len() - word_start.len()].contains('\t');
+        let end = word_start
+            .find(char::is_whitespace)
+            .unwrap_or(word_start.len());
+        words.push((&word_start[..end], after_tab));
+        rest = &word_start[end..];
+    }
+}
+
+/// Apply CSS line-clamp to wrapped lines, preserving segment styles
+///
+/// Keeps at most `line_clamp` lines; when lines were dropped, the last kept
+/// line is shortened so that it fits `max_width` with a trailing ellipsis.
+fn clamp_styled_lines(
+    mut lines: Vec<TextLine>,
+    line_clamp: Option<usize>,
+    max_width: f64,
+    font_size: f64,
+    font_name: &str,
+    measurer: &dyn TextMeasurer,
+) -> Vec<TextLine> {
+    let Some(max_lines) = line_clamp.filter(|&n| n > 0) else {
+        return lines;
+    };
+    if lines.len() <= max_lines {
+        return lines;
+    }
+
+    lines.truncate(max_lines);
+    if let Some(last) = lines.last_mut() {
+        let fitted = fit_with_ellipsis(
+            &last.plain_text(),
+            max_width,
+            font_size,
+            font_name,
+            measurer,
+        );
+        // The fitted text is a prefix of the line plus the ellipsis
+        let mut remaining = fitted.chars().count() - ELLIPSIS.chars().count();
+
+        last.segments.retain_mut(|segment| {
+            if remaining == 0 {
+                return false;
+            }
+            let len = segment.text.chars().count();
+            if len > remaining {
+                segment.text = segment.text.chars().take(remaining).collect();
+            }
+            remaining -= len.min(remaining);
+            true
+        });
+
+        match last.segments.last_mut() {
+            Some(segment) => segment.text.push_str(ELLIPSIS),
+            None => *last = TextLine::simple(ELLIPSIS.to_string()),
+        }
+    }
+
+    lines
+}
+
 /// Extract a container LayoutBox with children
 fn extract_container_box(
     tree: &TaffyTree<TextMeasureContext>,
@@ -262,6 +415,7 @@ fn extract_container_box(
         content: BoxContent::Container(child_boxes),
         style: info.style.clone(),
         element_type: info.element_type,
+        list_ordinal: info.list_ordinal,
     }])
 }
 
@@ -293,6 +447,7 @@ mod tests {
             font_size: None,
             text_decoration: None,
             color: None,
+            link: None,
         }];
 
         let result = extract_text_box(
@@ -323,6 +478,103 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_extract_text_box_with_column_count() {
+        let mut style = StyleDeclaration::default();
+        style.box_model.column_count = Some(2);
+        style.flex.column_gap = Some(20.0);
+        let measurer = MockTextMeasurer;
+        let bounds = Rect::new(0.0, 0.0, 220.0, 100.0);
+
+        let segments = vec![TextSegment {
+            text: "lime pear plum kiwi date pome yuzu sloe bael acai nuts figs".to_string(),
+            font_weight: None,
+            font_style: None,
+            font_size: None,
+            text_decoration: None,
+            color: None,
+            link: None,
+        }];
+        let boxes =
+            extract_text_box(&segments, bounds, &style, None, &measurer).expect("should extract");
+
+        // 100pt columns fit three 4-letter words per line: 4 lines split 2 + 2
+        assert_eq!(boxes.len(), 2);
+        assert_eq!(boxes[0].x, 0.0);
+        assert_eq!(boxes[1].x, 120.0);
+        assert_eq!(boxes[0].width, 100.0);
+        assert_eq!(boxes[0].y, boxes[1].y);
+        for column in &boxes {
+            match &column.content {
+                BoxContent::Text(lines) => assert_eq!(lines.len(), 2),
+                _ => panic!("Expected Text content"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_lines_into_columns_pads_empty_columns() {
+        let mut style = StyleDeclaration::default();
+        style.text.font_size = Some(10.0);
+        style.text.line_height = Some(12.0);
+        let bounds = Rect::new(0.0, 0.0, 320.0, 100.0);
+        let lines = vec![TextLine::from("first"), TextLine::from("second")];
+
+        let boxes = split_lines_into_columns(lines, bounds, &style, None, 3, 10.0);
+
+        assert_eq!(boxes.len(), 3);
+        assert_eq!(boxes[2].x, 220.0);
+        assert_eq!(boxes[2].height, 0.0);
+        let line_counts: Vec<usize> = boxes
+            .iter()
+            .map(|column| match &column.content {
+                BoxContent::Text(lines) => lines.len(),
+                _ => panic!("Expected Text content"),
+            })
+            .collect();
+        assert_eq!(line_counts, vec![1, 1, 0]);
+    }
+
+    #[test]
+    fn test_extract_text_box_with_line_clamp() {
+        use layout_types::FontWeight;
+
+        let mut style = StyleDeclaration::default();
+        style.text.line_clamp = Some(2);
+        let measurer = MockTextMeasurer;
+        let bounds = Rect::new(0.0, 0.0, 100.0, 24.0);
+
+        let segment = |text: &str, font_weight| TextSegment {
+            text: text.to_string(),
+            font_weight,
+            font_style: None,
+            font_size: None,
+            text_decoration: None,
+            color: None,
+            link: None,
+        };
+        let segments = vec![
+            segment("lime pear plum kiwi ", None),
+            segment("date pome yuzu sloe bael acai", Some(FontWeight::Bold)),
+        ];
+        let boxes =
+            extract_text_box(&segments, bounds, &style, None, &measurer).expect("should extract");
+
+        match &boxes[0].content {
+            BoxContent::Text(lines) => {
+                // Four wrapped lines clamped to two; the ellipsis fits in 100pt
+                assert_eq!(lines.len(), 2);
+                let last = &lines[1];
+                assert_eq!(last.plain_text(), "kiwi date pom…");
+                assert!(measurer.measure_text(&last.plain_text(), 10.0, "Helvetica") <= 100.0);
+                // The cut keeps the style of the segment it lands in
+                let tail = last.segments.last().unwrap();
+                assert_eq!(tail.font_weight, Some(FontWeight::Bold));
+            }
+            _ => panic!("Expected Text content"),
+        }
+    }
+
     #[test]
     fn test_extract_text_box_with_custom_font() {
         let mut style = StyleDeclaration::default();
@@ -338,6 +590,7 @@ mod tests {
             font_size: None,
             text_decoration: None,
             color: None,
+            link: None,
         }];
         let result = extract_text_box(&segments, bounds, &style, None, &measurer);
 
@@ -353,6 +606,7 @@ mod tests {
             font_size: None,
             text_decoration: None,
             color: None,
+            link: None,
         }];
         let content = ContentType::Text(segments);
 
@@ -383,11 +637,13 @@ mod tests {
             font_size: None,
             text_decoration: None,
             color: None,
+            link: None,
         }];
         let info = JsxElementInfo {
             element_type: Some(ElementType::Heading1),
             style,
             content_type: ContentType::Text(segments),
+            list_ordinal: None,
         };
 
         assert_eq!(info.element_type, Some(ElementType::Heading1));
@@ -421,4 +677,17 @@ mod tests {
             _ => panic!("Expected Text content"),
         }
     }
+
+    #[test]
+    fn test_wrapped_lines_keep_tabs() {
+        assert_eq!(
+            split_words_keeping_tabs(" Engineer \t 2020  Remote"),
+            vec![("Engineer", false), ("2020", true), ("Remote", false)]
+        );
+
+        let segments = TextLine::from("Led the platform team\t2020").segments;
+        let lines =
+            wrap_styled_segments(&segments, 120.0, 12.0, "Helvetica", &MockTextMeasurer).unwrap();
+        assert_eq!(lines.last().unwrap().plain_text(), "team\t2020");
+    }
 }
@@ -38,6 +38,9 @@ pub struct ContentArea {
 /// * `pdf_config` - PDF configuration (page margins)
 /// * `layout_config` - TSX layout configuration (optional max-width and padding)
 ///
+/// In both cases the configured header and footer band heights are taken off
+/// the top and bottom of the area, so paginated content never overlaps them.
+///
 /// # Returns
 ///
 /// ContentArea with x, y, width, and height for the usable content region.
@@ -47,7 +50,7 @@ pub fn calculate_content_area(
     pdf_config: &PDFConfig,
     layout_config: &TSXLayoutConfig,
 ) -> ContentArea {
-    if let Some(max_container_width) = layout_config.max_width {
+    let area = if let Some(max_container_width) = layout_config.max_width {
         // Scenario 1: TSX layout with max-width constraint
         calculate_content_area_with_max_width(
             page_width,
@@ -59,6 +62,18 @@ pub fn calculate_content_area(
     } else {
         // Scenario 2: Full-width layout (traditional PDF margins only)
         calculate_content_area_full_width(page_width, page_height, pdf_config)
+    };
+    reserve_bands(area, pdf_config)
+}
+
+/// Take the header band off the top and the footer band off the bottom
+fn reserve_bands(area: ContentArea, pdf_config: &PDFConfig) -> ContentArea {
+    let header = pdf_config.header_band_height.max(0.0);
+    let footer = pdf_config.footer_band_height.max(0.0);
+    ContentArea {
+        y: area.y + header,
+        height: (area.height - header - footer).max(0.0),
+        ..area
     }
 }
 
@@ -124,7 +139,7 @@ fn calculate_content_area_full_width(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use cv_domain::Spacing;
+    use cv_domain::{Spacing, TextDirection};
     use pdf_generator::config::{Margin, PageSize};
 
     fn create_test_pdf_config() -> PDFConfig {
@@ -150,6 +165,7 @@ mod tests {
                 left: 0.0,
             },
             background_color: None,
+            direction: TextDirection::Ltr,
         }
     }
 
@@ -163,6 +179,7 @@ mod tests {
                 left: 30.0,
             },
             background_color: None,
+            direction: TextDirection::Ltr,
         }
     }
 
@@ -221,6 +238,31 @@ mod tests {
         assert_eq!(content.width, 552.0);
     }
 
+    #[test]
+    fn test_header_and_footer_bands_shrink_content_height() {
+        let mut pdf_config = create_test_pdf_config();
+        pdf_config.header_band_height = 30.0;
+        pdf_config.footer_band_height = 20.0;
+
+        let content = calculate_content_area(
+            612.0,
+            792.0,
+            &pdf_config,
+            &create_test_tsx_layout_no_max_width(),
+        );
+        assert_eq!(content.y, 102.0); // 72 + 30
+        assert_eq!(content.height, 598.0); // 648 - 30 - 20
+
+        let content = calculate_content_area(
+            612.0,
+            792.0,
+            &pdf_config,
+            &create_test_tsx_layout_with_max_width(),
+        );
+        assert_eq!(content.y, 122.0); // 72 + 20 padding + 30
+        assert_eq!(content.height, 558.0); // 608 - 30 - 20
+    }
+
     #[test]
     fn test_zero_margins() {
         let mut pdf_config = create_test_pdf_config();
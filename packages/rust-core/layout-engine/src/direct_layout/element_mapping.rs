@@ -45,9 +45,15 @@ pub fn tag_to_element_type(tag: &str) -> Option<ElementType> {
         "footer" => Some(ElementType::Footer),
         "main" => Some(ElementType::Main),
 
+        // Semantic elements
+        "address" => Some(ElementType::ContactBlock),
+        "time" => Some(ElementType::DateTime),
+
         // Special elements
         "a" => Some(ElementType::Link),
         "img" => Some(ElementType::Image),
+        "figure" => Some(ElementType::Figure),
+        "figcaption" => Some(ElementType::Caption),
         "br" => Some(ElementType::Break),
 
         // Unknown tags
@@ -55,6 +61,72 @@ pub fn tag_to_element_type(tag: &str) -> Option<ElementType> {
     }
 }
 
+/// Convert an ARIA role to ElementType
+///
+/// `aria_level` selects the heading level for `role="heading"` (clamped to
+/// 1-6, defaulting to 2 as in WAI-ARIA). Roles without a matching element
+/// type (e.g. `presentation`) return `None`.
+pub fn role_to_element_type(role: &str, aria_level: Option<u8>) -> Option<ElementType> {
+    match role {
+        "heading" => Some(heading_element_type(aria_level.unwrap_or(2))),
+        "paragraph" => Some(ElementType::Paragraph),
+        "list" => Some(ElementType::UnorderedList),
+        "listitem" => Some(ElementType::ListItem),
+        "region" => Some(ElementType::Section),
+        "article" => Some(ElementType::Article),
+        "banner" => Some(ElementType::Header),
+        "contentinfo" => Some(ElementType::Footer),
+        "main" => Some(ElementType::Main),
+        "link" => Some(ElementType::Link),
+        "img" | "image" => Some(ElementType::Image),
+        "figure" => Some(ElementType::Figure),
+        "code" => Some(ElementType::Code),
+        "time" => Some(ElementType::DateTime),
+        _ => None,
+    }
+}
+
+/// Determine the ElementType of an element from its tag and ARIA attributes
+///
+/// A recognized `role` wins over the tag, so `<div role="heading"
+/// aria-level="2">` maps to `Heading2`. `aria-level` on a native heading
+/// (`<h3 aria-level="2">`) overrides the tag's level.
+pub fn resolve_element_type(
+    tag: &str,
+    role: Option<&str>,
+    aria_level: Option<u8>,
+) -> Option<ElementType> {
+    if let Some(element_type) = role.and_then(|role| role_to_element_type(role, aria_level)) {
+        return Some(element_type);
+    }
+
+    match tag_to_element_type(tag) {
+        Some(element_type) if element_type.is_heading() => {
+            Some(aria_level.map_or(element_type, heading_element_type))
+        }
+        element_type => element_type,
+    }
+}
+
+/// Whether an element is a list item by ARIA role alone
+///
+/// `role="listitem"` gives an element list semantics (e.g. for pagination)
+/// but not the `display: list-item` of `<li>`, so it gets no marker.
+pub fn is_role_only_list_item(tag: &str, element_type: Option<ElementType>) -> bool {
+    element_type == Some(ElementType::ListItem) && tag != "li"
+}
+
+fn heading_element_type(level: u8) -> ElementType {
+    match level {
+        0 | 1 => ElementType::Heading1,
+        2 => ElementType::Heading2,
+        3 => ElementType::Heading3,
+        4 => ElementType::Heading4,
+        5 => ElementType::Heading5,
+        _ => ElementType::Heading6,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,10 +169,24 @@ mod tests {
         assert_eq!(tag_to_element_type("main"), Some(ElementType::Main));
     }
 
+    #[test]
+    fn test_tag_to_element_type_semantic() {
+        assert_eq!(
+            tag_to_element_type("address"),
+            Some(ElementType::ContactBlock)
+        );
+        assert_eq!(tag_to_element_type("time"), Some(ElementType::DateTime));
+    }
+
     #[test]
     fn test_tag_to_element_type_special() {
         assert_eq!(tag_to_element_type("a"), Some(ElementType::Link));
         assert_eq!(tag_to_element_type("img"), Some(ElementType::Image));
+        assert_eq!(tag_to_element_type("figure"), Some(ElementType::Figure));
+        assert_eq!(
+            tag_to_element_type("figcaption"),
+            Some(ElementType::Caption)
+        );
         assert_eq!(tag_to_element_type("br"), Some(ElementType::Break));
     }
 
@@ -111,6 +197,46 @@ mod tests {
         assert_eq!(tag_to_element_type(""), None);
     }
 
+    #[test]
+    fn test_role_overrides_tag() {
+        assert_eq!(
+            resolve_element_type("div", Some("heading"), Some(2)),
+            Some(ElementType::Heading2)
+        );
+        assert_eq!(
+            resolve_element_type("span", Some("heading"), None),
+            Some(ElementType::Heading2)
+        );
+        assert_eq!(
+            resolve_element_type("div", Some("heading"), Some(9)),
+            Some(ElementType::Heading6)
+        );
+        assert_eq!(
+            resolve_element_type("div", Some("list"), None),
+            Some(ElementType::UnorderedList)
+        );
+        assert_eq!(
+            resolve_element_type("div", Some("presentation"), None),
+            Some(ElementType::Div)
+        );
+    }
+
+    #[test]
+    fn test_aria_level_applies_to_headings_only() {
+        assert_eq!(
+            resolve_element_type("h3", None, Some(2)),
+            Some(ElementType::Heading2)
+        );
+        assert_eq!(
+            resolve_element_type("h3", None, None),
+            Some(ElementType::Heading3)
+        );
+        assert_eq!(
+            resolve_element_type("p", None, Some(2)),
+            Some(ElementType::Paragraph)
+        );
+    }
+
     #[test]
     fn test_tag_lookup_consistency() {
         // Test that repeated lookups return consistent results
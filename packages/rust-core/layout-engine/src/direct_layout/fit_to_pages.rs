@@ -0,0 +1,157 @@
+//! Uniform content scaling for `fit_to_pages`
+//!
+//! Shrinks font sizes, line heights and spacing of a built Taffy tree by one
+//! factor so a document that overflows its page budget can be laid out again
+//! at a smaller size. Widths, heights and borders keep their declared sizes.
+
+use super::box_extractor::{ContentType, JsxElementInfo};
+use super::style_conversion::convert_style_to_taffy;
+use super::text_measurement::TextMeasureContext;
+use crate::error::LayoutError;
+use layout_types::{Spacing, StyleDeclaration, DEFAULT_FONT_SIZE, DEFAULT_LINE_HEIGHT_RATIO};
+use std::collections::HashMap;
+use taffy::prelude::*;
+
+/// Smallest font size (in points) scaling may produce
+pub const MIN_FIT_FONT_SIZE: f64 = 6.0;
+
+/// Largest multiplier applied to the scale after an attempt that still overflows
+pub const FIT_SCALE_STEP: f64 = 0.98;
+
+/// Smallest scale that keeps every text node at or above [`MIN_FIT_FONT_SIZE`]
+///
+/// Returns 1.0 when the smallest text is already at or below the floor.
+pub fn min_fit_scale(node_info_map: &HashMap<NodeId, JsxElementInfo>) -> f64 {
+    let smallest = node_info_map
+        .values()
+        .filter(|info| matches!(info.content_type, ContentType::Text(_)))
+        .map(|info| info.style.text.font_size.unwrap_or(DEFAULT_FONT_SIZE))
+        .fold(f64::INFINITY, f64::min);
+
+    if smallest.is_finite() {
+        (MIN_FIT_FONT_SIZE / smallest).min(1.0)
+    } else {
+        1.0
+    }
+}
+
+/// First scale to try, from how far the natural layout overflows
+///
+/// Wrapped text reflows into the freed width as it shrinks, so the height it
+/// needs falls up to the square of the scale. The first attempt assumes it
+/// does; [`next_fit_scale`] corrects for content that shrinks only linearly.
+pub fn initial_fit_scale(needed_height: f64, available_height: f64) -> f64 {
+    if needed_height <= 0.0 {
+        return 1.0;
+    }
+    (available_height / needed_height).sqrt().min(1.0)
+}
+
+/// Scale to try after `scale` still needed `needed_height`
+///
+/// Shrinks by the remaining overflow ratio, and by at least
+/// [`FIT_SCALE_STEP`] so every attempt makes progress.
+pub fn next_fit_scale(scale: f64, needed_height: f64, available_height: f64) -> f64 {
+    let ratio = if needed_height > 0.0 {
+        available_height / needed_height
+    } else {
+        1.0
+    };
+    scale * ratio.min(FIT_SCALE_STEP)
+}
+
+/// Scale every node of a built tree by `scale`
+///
+/// Updates the stored styles, the Taffy margins, padding and gaps, and the
+/// text measurement contexts so the next layout pass uses the scaled sizes.
+pub fn scale_tree(
+    tree: &mut TaffyTree<TextMeasureContext>,
+    node_info_map: &mut HashMap<NodeId, JsxElementInfo>,
+    scale: f64,
+) -> Result<(), LayoutError> {
+    for (&node_id, info) in node_info_map.iter_mut() {
+        scale_style(&mut info.style, scale);
+        if let ContentType::Text(segments) = &mut info.content_type {
+            for segment in segments {
+                segment.font_size = segment.font_size.map(|size| size * scale);
+            }
+        }
+
+        let converted = convert_style_to_taffy(&info.style, None);
+        let mut taffy_style = tree
+            .style(node_id)
+            .map_err(|e| LayoutError::CalculationFailed(format!("Taffy style error: {}", e)))?
+            .clone();
+        taffy_style.margin = converted.margin;
+        taffy_style.padding = converted.padding;
+        taffy_style.gap = converted.gap;
+        tree.set_style(node_id, taffy_style)
+            .map_err(|e| LayoutError::CalculationFailed(format!("Taffy style error: {}", e)))?;
+
+        if let Some(context) = tree.get_node_context_mut(node_id) {
+            *context = TextMeasureContext::new(std::mem::take(&mut context.content), &info.style);
+        }
+    }
+    Ok(())
+}
+
+/// Scale the size-dependent properties of one style
+fn scale_style(style: &mut StyleDeclaration, scale: f64) {
+    let font_size = style.text.font_size.unwrap_or(DEFAULT_FONT_SIZE);
+    let line_height = style
+        .text
+        .line_height
+        .unwrap_or(font_size * DEFAULT_LINE_HEIGHT_RATIO);
+    style.text.font_size = Some(font_size * scale);
+    style.text.line_height = Some(line_height * scale);
+    style.text.letter_spacing = style.text.letter_spacing.map(|s| s * scale);
+
+    let scale_spacing = |spacing: Spacing| Spacing {
+        top: spacing.top * scale,
+        right: spacing.right * scale,
+        bottom: spacing.bottom * scale,
+        left: spacing.left * scale,
+    };
+    style.box_model.margin = style.box_model.margin.map(scale_spacing);
+    style.box_model.padding = style.box_model.padding.map(scale_spacing);
+
+    style.flex.gap = style.flex.gap.map(|g| g * scale);
+    style.flex.row_gap = style.flex.row_gap.map(|g| g * scale);
+    style.flex.column_gap = style.flex.column_gap.map(|g| g * scale);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_style_shrinks_text_and_spacing() {
+        let mut style = StyleDeclaration::default();
+        style.text.font_size = Some(12.0);
+        style.text.line_height = Some(18.0);
+        style.box_model.margin = Some(Spacing::uniform(10.0));
+        style.flex.gap = Some(8.0);
+
+        scale_style(&mut style, 0.5);
+
+        assert_eq!(style.text.font_size, Some(6.0));
+        assert_eq!(style.text.line_height, Some(9.0));
+        assert_eq!(style.box_model.margin, Some(Spacing::uniform(5.0)));
+        assert_eq!(style.flex.gap, Some(4.0));
+        assert_eq!(style.flex.row_gap, None);
+    }
+
+    #[test]
+    fn test_initial_fit_scale_from_overflow() {
+        assert_eq!(initial_fit_scale(400.0, 400.0), 1.0);
+        assert_eq!(initial_fit_scale(100.0, 400.0), 1.0);
+        assert!((initial_fit_scale(800.0, 400.0) - 0.5_f64.sqrt()).abs() < 1e-9);
+        assert_eq!(initial_fit_scale(0.0, 400.0), 1.0);
+    }
+
+    #[test]
+    fn test_next_fit_scale_always_shrinks() {
+        assert!((next_fit_scale(0.8, 500.0, 400.0) - 0.64).abs() < 1e-9);
+        assert!((next_fit_scale(0.8, 401.0, 400.0) - 0.8 * FIT_SCALE_STEP).abs() < 1e-9);
+    }
+}
@@ -5,8 +5,9 @@
 //! from the specific AST structure, making the code unit testable.
 
 use layout_types::{FontStyle, FontWeight, TextSegment};
-use style_resolver::resolve_tailwind_classes;
-use tsx_parser::{JSXChild, JSXElement, JSXExpression};
+use pdf_generator::sanitize_link_uri;
+use style_resolver::{resolve_tailwind_classes, Stylesheet};
+use tsx_parser::{JSXChild, JSXElement};
 
 /// Intermediate representation of an element for layout computation
 ///
@@ -25,6 +26,15 @@ pub struct LayoutElement {
     /// Inline CSS styles
     pub inline_style: Option<String>,
 
+    /// Sanitized `href` of an `<a>` element (unsafe schemes are dropped)
+    pub href: Option<String>,
+
+    /// ARIA `role`, lowercased (e.g. `heading` on a styled `<div>`)
+    pub role: Option<String>,
+
+    /// ARIA `aria-level`, the heading level for `role="heading"`
+    pub aria_level: Option<u8>,
+
     /// Child elements (can be elements or text nodes)
     pub children: Vec<LayoutElement>,
 }
@@ -39,6 +49,9 @@ impl LayoutElement {
             tag: "#text".to_string(),
             class_name: None,
             inline_style: Some(content.to_string()),
+            href: None,
+            role: None,
+            aria_level: None,
             children: Vec::new(),
         }
     }
@@ -67,6 +80,9 @@ impl LayoutElement {
             tag: tag.to_string(),
             class_name: None,
             inline_style: None,
+            href: None,
+            role: None,
+            aria_level: None,
             children,
         }
     }
@@ -77,6 +93,9 @@ impl LayoutElement {
             tag: tag.to_string(),
             class_name: Some(class_name.to_string()),
             inline_style: None,
+            href: None,
+            role: None,
+            aria_level: None,
             children: Vec::new(),
         }
     }
@@ -134,6 +153,7 @@ impl LayoutElement {
     ///
     /// # Arguments
     /// * `parent_style` - The resolved style of the parent element (for inheritance)
+    /// * `stylesheet` - Print rules from the document's `<style>` elements
     ///
     /// # Returns
     /// Vector of TextSegments with text and style information
@@ -141,12 +161,15 @@ impl LayoutElement {
         &self,
         parent_font_weight: Option<FontWeight>,
         parent_font_style: Option<FontStyle>,
+        stylesheet: &Stylesheet,
     ) -> Vec<TextSegment> {
         let mut segments = Vec::new();
         self.collect_styled_segments_recursive(
             &mut segments,
             parent_font_weight,
             parent_font_style,
+            None,
+            stylesheet,
         );
         segments
     }
@@ -156,6 +179,8 @@ impl LayoutElement {
         segments: &mut Vec<TextSegment>,
         current_font_weight: Option<FontWeight>,
         current_font_style: Option<FontStyle>,
+        current_link: Option<&str>,
+        stylesheet: &Stylesheet,
     ) {
         if self.is_text() {
             // Direct text node - use current styles
@@ -168,26 +193,37 @@ impl LayoutElement {
                         font_size: None, // Inherit from parent
                         text_decoration: None,
                         color: None,
+                        link: current_link.map(str::to_string),
                     });
                 }
             }
         } else {
             // Element node - check if it has styles to apply
             let (child_weight, child_style) =
-                self.resolve_inline_styles(current_font_weight, current_font_style);
+                self.resolve_inline_styles(current_font_weight, current_font_style, stylesheet);
+
+            // Nested anchors are invalid HTML; the innermost href wins
+            let child_link = self.href.as_deref().or(current_link);
 
             // Process children with potentially updated styles
             for child in &self.children {
-                child.collect_styled_segments_recursive(segments, child_weight, child_style);
+                child.collect_styled_segments_recursive(
+                    segments,
+                    child_weight,
+                    child_style,
+                    child_link,
+                    stylesheet,
+                );
             }
         }
     }
 
-    /// Resolve styles from this element's className
+    /// Resolve styles from this element's className and matching stylesheet rules
     fn resolve_inline_styles(
         &self,
         parent_weight: Option<FontWeight>,
         parent_style: Option<FontStyle>,
+        stylesheet: &Stylesheet,
     ) -> (Option<FontWeight>, Option<FontStyle>) {
         // Check if this is a semantic element that implies styling
         let (semantic_weight, semantic_style) = match self.tag.as_str() {
@@ -197,13 +233,27 @@ impl LayoutElement {
         };
 
         // Apply className styles (override semantic)
-        if let Some(class_name) = &self.class_name {
+        let (weight, style) = if let Some(class_name) = &self.class_name {
             let resolved = resolve_tailwind_classes(class_name);
             let weight = resolved.text.font_weight.or(semantic_weight);
             let style = resolved.text.font_style.or(semantic_style);
             (weight, style)
         } else {
             (semantic_weight, semantic_style)
+        };
+
+        // Apply stylesheet rules (override className)
+        let declared = stylesheet
+            .matching_declarations(&self.tag, self.class_name.as_deref())
+            .and_then(|declarations| {
+                pdf_generator::css_parser::parse_declared_styles(&declarations).ok()
+            });
+        match declared {
+            Some(declared) => (
+                declared.text.font_weight.or(weight),
+                declared.text.font_style.or(style),
+            ),
+            None => (weight, style),
         }
     }
 }
@@ -226,6 +276,14 @@ pub fn jsx_to_layout_element(jsx: &JSXElement) -> LayoutElement {
     let element_name = tsx_parser::extract_element_name(jsx);
     let class_name = tsx_parser::extract_class_name(jsx);
     let inline_style = tsx_parser::extract_inline_style(jsx);
+    // Only `href` is read for links; event handlers (`onClick`, ...) are ignored
+    let href = if element_name == "a" {
+        tsx_parser::get_attribute_value(jsx, "href").and_then(|href| sanitize_link_uri(&href))
+    } else {
+        None
+    };
+    let role = tsx_parser::extract_aria_role(jsx);
+    let aria_level = tsx_parser::extract_aria_level(jsx);
 
     // Process children
     let mut children = Vec::new();
@@ -240,16 +298,19 @@ pub fn jsx_to_layout_element(jsx: &JSXElement) -> LayoutElement {
                 }
             }
             JSXChild::ExpressionContainer(expr_container) => {
-                // Extract string literals from expressions
-                // In oxc, Expression variants are inherited into JSXExpression
-                if let JSXExpression::StringLiteral(str_lit) = &expr_container.expression {
-                    let text = str_lit.value.to_string();
+                // Extract string and plain template literals from expressions
+                if let Some(text) = tsx_parser::extract_expression_text(&expr_container.expression)
+                {
                     if !text.trim().is_empty() {
                         children.push(LayoutElement::text(&text));
                     }
                 }
             }
             JSXChild::Element(child_jsx) => {
+                // `<style>` content is CSS, not text to lay out
+                if tsx_parser::extract_element_name(child_jsx) == "style" {
+                    continue;
+                }
                 // Recursively convert child elements
                 children.push(jsx_to_layout_element(child_jsx));
             }
@@ -261,6 +322,9 @@ pub fn jsx_to_layout_element(jsx: &JSXElement) -> LayoutElement {
         tag: element_name,
         class_name,
         inline_style,
+        href,
+        role,
+        aria_level,
         children,
     }
 }
@@ -361,6 +425,28 @@ mod tests {
         assert_eq!(span.extract_all_text(), "Native:");
     }
 
+    #[test]
+    fn test_styled_segments_apply_stylesheet_rules() {
+        let mut label = LayoutElement::with_class("span", "label");
+        label.children = vec![LayoutElement::text("Native:")];
+        let p = LayoutElement::with_children(
+            "p",
+            vec![
+                label,
+                LayoutElement::with_children("em", vec![LayoutElement::text(" Russian")]),
+            ],
+        );
+        let sheet = Stylesheet::parse(
+            ".label { font-weight: bold } em { font-style: normal }",
+            (612.0, 792.0),
+        );
+
+        let segments = p.extract_styled_segments(None, None, &sheet);
+
+        assert_eq!(segments[0].font_weight, Some(FontWeight::Bold));
+        assert_eq!(segments[1].font_style, Some(FontStyle::Normal));
+    }
+
     #[test]
     fn test_extract_text_for_layout() {
         // Test that extract_text_for_layout combines ALL text (nested + direct)
@@ -3,14 +3,68 @@
 //! This module handles the conversion of layout-types::StyleDeclaration to
 //! Taffy's Style type, which is used for CSS layout calculations.
 
-use layout_types::{AlignItems, Display, FlexDirection, JustifyContent, StyleDeclaration};
+use layout_types::{
+    AlignItems, BoxSizing, CssLength, Display, FlexDirection, FlexWrap, Inset, JustifyContent,
+    Position, Spacing, StyleDeclaration, DEFAULT_FONT_SIZE,
+};
 use taffy::prelude::*;
 
 /// Block-level container tags that should fill available width by default
 const BLOCK_CONTAINER_TAGS: &[&str] = &[
-    "div", "section", "article", "header", "footer", "main", "nav", "aside",
+    "div",
+    "section",
+    "article",
+    "header",
+    "footer",
+    "main",
+    "nav",
+    "aside",
+    "address",
+    "figure",
+    "figcaption",
 ];
 
+/// Resolve `em` lengths against the element's own font size
+///
+/// Runs once the cascade has settled the element's font size, so
+/// `font-size: 20px; margin: 1em` gets a 15pt margin rather than one based
+/// on the default font size. Converts `margin_em` and `padding_em` into
+/// `margin` and `padding`, and `em` widths, flex bases and insets to points.
+pub fn resolve_em_lengths(style: &mut StyleDeclaration) {
+    let font_size = style.text.font_size.unwrap_or(DEFAULT_FONT_SIZE);
+    let apply = |points: Option<Spacing>, em: Spacing| {
+        let points = points.unwrap_or_default();
+        let side = |points: f64, em: f64| if em != 0.0 { em * font_size } else { points };
+        Spacing {
+            top: side(points.top, em.top),
+            right: side(points.right, em.right),
+            bottom: side(points.bottom, em.bottom),
+            left: side(points.left, em.left),
+        }
+    };
+
+    let box_model = &mut style.box_model;
+    if let Some(em) = box_model.margin_em.take() {
+        box_model.margin = Some(apply(box_model.margin, em));
+    }
+    if let Some(em) = box_model.padding_em.take() {
+        box_model.padding = Some(apply(box_model.padding, em));
+    }
+
+    let resolve = |length: &mut Option<CssLength>| {
+        *length = length.map(|length| length.resolve_em(font_size));
+    };
+    resolve(&mut box_model.width);
+    resolve(&mut box_model.max_width);
+    resolve(&mut style.flex.flex_basis);
+    if let Some(inset) = &mut box_model.inset {
+        resolve(&mut inset.top);
+        resolve(&mut inset.right);
+        resolve(&mut inset.bottom);
+        resolve(&mut inset.left);
+    }
+}
+
 /// Convert StyleDeclaration to Taffy's Style
 ///
 /// Maps ResumeWright's StyleDeclaration (which combines Tailwind classes and inline styles)
@@ -25,6 +79,8 @@ const BLOCK_CONTAINER_TAGS: &[&str] = &[
 ///
 /// A Taffy Style ready for layout calculations
 pub fn convert_style_to_taffy(style: &StyleDeclaration, tag: Option<&str>) -> Style {
+    let is_absolute = style.box_model.position == Some(Position::Absolute);
+
     Style {
         // Display mode
         display: convert_display_mode(style.flex.display),
@@ -32,10 +88,17 @@ pub fn convert_style_to_taffy(style: &StyleDeclaration, tag: Option<&str>) -> St
         // Flexbox direction
         flex_direction: convert_flex_direction(style.flex.flex_direction),
 
+        // Wrapping onto multiple lines (rows are separated by row-gap)
+        flex_wrap: convert_flex_wrap(style.flex.flex_wrap),
+
         // Flex properties
         flex_grow: style.flex.flex.unwrap_or(0.0) as f32,
         flex_shrink: style.flex.flex_shrink.unwrap_or(1.0) as f32,
-        flex_basis: Dimension::auto(),
+        flex_basis: style
+            .flex
+            .flex_basis
+            .map(convert_length)
+            .unwrap_or(Dimension::auto()),
 
         // Justify content (main axis alignment)
         justify_content: style.flex.justify_content.and_then(convert_justify_content),
@@ -43,9 +106,24 @@ pub fn convert_style_to_taffy(style: &StyleDeclaration, tag: Option<&str>) -> St
         // Align items (cross axis alignment)
         align_items: style.flex.align_items.and_then(convert_align_items),
 
-        // Sizing
+        // Absolute boxes leave the flow and are placed by their insets.
+        // Relative offsets are not applied: relative boxes only anchor
+        // absolute descendants (see `reparent_absolute_nodes`)
+        position: if is_absolute {
+            taffy::Position::Absolute
+        } else {
+            taffy::Position::Relative
+        },
+        inset: style
+            .box_model
+            .inset
+            .filter(|_| is_absolute)
+            .map(convert_inset)
+            .unwrap_or(Rect::auto()),
+
+        // Sizing (absolute blocks shrink to fit rather than fill the width)
         size: Size {
-            width: convert_width(style.box_model.width, tag),
+            width: convert_width(style.box_model.width, tag.filter(|_| !is_absolute)),
             height: style
                 .box_model
                 .height
@@ -53,13 +131,18 @@ pub fn convert_style_to_taffy(style: &StyleDeclaration, tag: Option<&str>) -> St
                 .unwrap_or(Dimension::auto()),
         },
 
+        // A clamped width bounds its percentage with min and max widths
+        min_size: Size {
+            width: match style.box_model.width {
+                Some(CssLength::Clamped { min: Some(min), .. }) => Dimension::length(min as f32),
+                _ => Dimension::auto(),
+            },
+            height: Dimension::auto(),
+        },
+
         // Max sizing constraints
         max_size: Size {
-            width: style
-                .box_model
-                .max_width
-                .map(|w| Dimension::length(w as f32))
-                .unwrap_or(Dimension::auto()),
+            width: convert_max_width(style.box_model.max_width, style.box_model.width),
             height: style
                 .box_model
                 .max_height
@@ -68,16 +151,7 @@ pub fn convert_style_to_taffy(style: &StyleDeclaration, tag: Option<&str>) -> St
         },
 
         // Box model - Margin
-        margin: style
-            .box_model
-            .margin
-            .map(|m| Rect {
-                left: LengthPercentageAuto::length(m.left as f32),
-                right: LengthPercentageAuto::length(m.right as f32),
-                top: LengthPercentageAuto::length(m.top as f32),
-                bottom: LengthPercentageAuto::length(m.bottom as f32),
-            })
-            .unwrap_or(Rect::zero()),
+        margin: convert_margin(style.box_model.margin, style.box_model.margin_percent),
 
         // Box model - Padding
         padding: style
@@ -127,6 +201,12 @@ pub fn convert_style_to_taffy(style: &StyleDeclaration, tag: Option<&str>) -> St
             ),
         },
 
+        // Unset follows Tailwind's preflight (border-box), as Taffy does
+        box_sizing: match style.box_model.box_sizing {
+            Some(BoxSizing::ContentBox) => taffy::BoxSizing::ContentBox,
+            Some(BoxSizing::BorderBox) | None => taffy::BoxSizing::BorderBox,
+        },
+
         // Gap properties for flexbox/grid layouts
         gap: Size {
             width: LengthPercentage::length(
@@ -142,6 +222,11 @@ pub fn convert_style_to_taffy(style: &StyleDeclaration, tag: Option<&str>) -> St
 }
 
 /// Convert display mode from StyleDeclaration to Taffy
+///
+/// Block children are laid out by Taffy's block algorithm, which collapses
+/// adjacent vertical sibling margins to `max(upper.margin_bottom, lower.margin_top)`
+/// as CSS does. Flex items keep both margins, so keep blocks as `Display::Block`
+/// rather than promoting them to flex columns.
 fn convert_display_mode(display: Option<Display>) -> taffy::Display {
     match display {
         Some(Display::Flex) => taffy::Display::Flex,
@@ -151,6 +236,15 @@ fn convert_display_mode(display: Option<Display>) -> taffy::Display {
     }
 }
 
+/// Convert flex wrap from StyleDeclaration to Taffy
+fn convert_flex_wrap(flex_wrap: Option<FlexWrap>) -> taffy::FlexWrap {
+    match flex_wrap {
+        Some(FlexWrap::Wrap) => taffy::FlexWrap::Wrap,
+        Some(FlexWrap::WrapReverse) => taffy::FlexWrap::WrapReverse,
+        Some(FlexWrap::NoWrap) | None => taffy::FlexWrap::NoWrap,
+    }
+}
+
 /// Convert flex direction from StyleDeclaration to Taffy
 fn convert_flex_direction(flex_direction: Option<FlexDirection>) -> taffy::FlexDirection {
     match flex_direction {
@@ -185,28 +279,126 @@ fn convert_align_items(ai: AlignItems) -> Option<taffy::AlignItems> {
     })
 }
 
+/// Convert a CSS length to a Taffy dimension
+///
+/// Percentages are handed to Taffy unresolved so they are computed against
+/// the containing block's content width during layout. `em` lengths are
+/// expected to be resolved by [`resolve_em_lengths`] already. A clamped
+/// length converts to its percentage; only `width` carries the bounds, as
+/// min and max widths.
+fn convert_length(length: CssLength) -> Dimension {
+    match length {
+        CssLength::Points(points) => Dimension::length(points as f32),
+        CssLength::Percent(percent) | CssLength::Clamped { percent, .. } => {
+            Dimension::percent((percent / 100.0) as f32)
+        }
+        CssLength::Em(_) => Dimension::length(length.resolve(0.0) as f32),
+    }
+}
+
+/// Convert insets; unset sides are `auto`
+fn convert_inset(inset: Inset) -> Rect<LengthPercentageAuto> {
+    let side = |length: Option<CssLength>| match length {
+        Some(CssLength::Points(points)) => LengthPercentageAuto::length(points as f32),
+        Some(CssLength::Percent(percent) | CssLength::Clamped { percent, .. }) => {
+            LengthPercentageAuto::percent((percent / 100.0) as f32)
+        }
+        Some(length @ CssLength::Em(_)) => LengthPercentageAuto::length(length.resolve(0.0) as f32),
+        None => LengthPercentageAuto::auto(),
+    };
+    Rect {
+        left: side(inset.left),
+        right: side(inset.right),
+        top: side(inset.top),
+        bottom: side(inset.bottom),
+    }
+}
+
+/// Convert max-width, tightened by the upper bound of a clamped width
+///
+/// An absolute max-width and bound combine to the smaller of the two; a
+/// percentage max-width cannot be compared before layout and wins.
+fn convert_max_width(max_width: Option<CssLength>, width: Option<CssLength>) -> Dimension {
+    let bound = match width {
+        Some(CssLength::Clamped { max, .. }) => max,
+        _ => None,
+    };
+    match (max_width, bound) {
+        (Some(CssLength::Points(points)), Some(bound)) => {
+            Dimension::length(points.min(bound) as f32)
+        }
+        (None, Some(bound)) => Dimension::length(bound as f32),
+        (max_width, _) => max_width.map(convert_length).unwrap_or(Dimension::auto()),
+    }
+}
+
+/// Convert margins, letting non-zero percentage sides take precedence
+fn convert_margin(
+    margin: Option<Spacing>,
+    margin_percent: Option<Spacing>,
+) -> Rect<LengthPercentageAuto> {
+    let margin = margin.unwrap_or_default();
+    let percent = margin_percent.unwrap_or_default();
+
+    let side = |points: f64, pct: f64| {
+        if pct != 0.0 {
+            LengthPercentageAuto::percent((pct / 100.0) as f32)
+        } else {
+            LengthPercentageAuto::length(points as f32)
+        }
+    };
+
+    Rect {
+        left: side(margin.left, percent.left),
+        right: side(margin.right, percent.right),
+        top: side(margin.top, percent.top),
+        bottom: side(margin.bottom, percent.bottom),
+    }
+}
+
 /// Convert width with smart defaults for block containers
 ///
 /// Block-level container elements (div, section, etc.) without an explicit width
 /// will default to 100% (fill available space). Other elements default to auto.
-fn convert_width(width: Option<f64>, tag: Option<&str>) -> Dimension {
-    width
-        .map(|w| Dimension::length(w as f32))
-        .unwrap_or_else(|| {
-            // For block-level container elements without explicit width, use 100% to fill available space
-            if let Some(tag_name) = tag {
-                if BLOCK_CONTAINER_TAGS.contains(&tag_name) {
-                    return Dimension::percent(1.0);
-                }
+fn convert_width(width: Option<CssLength>, tag: Option<&str>) -> Dimension {
+    width.map(convert_length).unwrap_or_else(|| {
+        // For block-level container elements without explicit width, use 100% to fill available space
+        if let Some(tag_name) = tag {
+            if BLOCK_CONTAINER_TAGS.contains(&tag_name) {
+                return Dimension::percent(1.0);
             }
-            Dimension::auto()
-        })
+        }
+        Dimension::auto()
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use layout_types::Spacing;
+
+    #[test]
+    fn test_absolute_position_sets_insets_and_shrinks_to_fit() {
+        let mut style = StyleDeclaration::default();
+        style.box_model.position = Some(Position::Absolute);
+        style.box_model.inset = Some(Inset {
+            top: Some(CssLength::Points(0.0)),
+            right: Some(CssLength::Percent(50.0)),
+            ..Default::default()
+        });
+
+        let taffy_style = convert_style_to_taffy(&style, Some("div"));
+        assert_eq!(taffy_style.position, taffy::Position::Absolute);
+        assert_eq!(taffy_style.inset.top, LengthPercentageAuto::length(0.0));
+        assert_eq!(taffy_style.inset.right, LengthPercentageAuto::percent(0.5));
+        assert_eq!(taffy_style.inset.left, LengthPercentageAuto::auto());
+        assert_eq!(taffy_style.size.width, Dimension::auto());
+
+        // Relative offsets are ignored
+        style.box_model.position = Some(Position::Relative);
+        let taffy_style = convert_style_to_taffy(&style, Some("div"));
+        assert_eq!(taffy_style.position, taffy::Position::Relative);
+        assert_eq!(taffy_style.inset, Rect::auto());
+    }
 
     #[test]
     fn test_convert_display_mode_flex() {
@@ -270,6 +462,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_convert_flex_wrap() {
+        let mut style = StyleDeclaration::default();
+        assert_eq!(
+            convert_style_to_taffy(&style, None).flex_wrap,
+            taffy::FlexWrap::NoWrap
+        );
+
+        style.flex.flex_wrap = Some(FlexWrap::Wrap);
+        assert_eq!(
+            convert_style_to_taffy(&style, None).flex_wrap,
+            taffy::FlexWrap::Wrap
+        );
+    }
+
     #[test]
     fn test_convert_align_items_center() {
         let mut style = StyleDeclaration::default();
@@ -282,13 +489,93 @@ mod tests {
     #[test]
     fn test_explicit_width() {
         let mut style = StyleDeclaration::default();
-        style.box_model.width = Some(200.0);
+        style.box_model.width = Some(CssLength::Points(200.0));
 
         let taffy_style = convert_style_to_taffy(&style, None);
         // Verify width was set (Taffy uses opaque types, so we just check it was set)
         assert_eq!(taffy_style.size.width, Dimension::length(200.0));
     }
 
+    #[test]
+    fn test_resolve_em_lengths_uses_own_font_size() {
+        let mut style = StyleDeclaration::default();
+        style.text.font_size = Some(15.0);
+        style.box_model.margin = Some(Spacing {
+            left: 4.0,
+            ..Spacing::ZERO
+        });
+        style.box_model.margin_em = Some(Spacing {
+            top: 1.0,
+            ..Spacing::ZERO
+        });
+        style.box_model.padding_em = Some(Spacing::uniform(0.5));
+        style.box_model.width = Some(CssLength::Em(10.0));
+
+        resolve_em_lengths(&mut style);
+
+        assert_eq!(
+            style.box_model.margin,
+            Some(Spacing {
+                top: 15.0,
+                left: 4.0,
+                ..Spacing::ZERO
+            })
+        );
+        assert_eq!(style.box_model.padding, Some(Spacing::uniform(7.5)));
+        assert_eq!(style.box_model.width, Some(CssLength::Points(150.0)));
+        assert_eq!(style.box_model.margin_em, None);
+        assert_eq!(style.box_model.padding_em, None);
+    }
+
+    #[test]
+    fn test_percent_width_and_margin() {
+        let mut style = StyleDeclaration::default();
+        style.box_model.width = Some(CssLength::Percent(50.0));
+        style.box_model.max_width = Some(CssLength::Percent(80.0));
+        style.box_model.margin = Some(Spacing::uniform(10.0));
+        style.box_model.margin_percent = Some(Spacing {
+            top: 0.0,
+            right: 0.0,
+            bottom: 0.0,
+            left: 25.0,
+        });
+
+        let taffy_style = convert_style_to_taffy(&style, None);
+
+        assert_eq!(taffy_style.size.width, Dimension::percent(0.5));
+        assert_eq!(taffy_style.max_size.width, Dimension::percent(0.8));
+        assert_eq!(taffy_style.margin.top, LengthPercentageAuto::length(10.0));
+        assert_eq!(taffy_style.margin.left, LengthPercentageAuto::percent(0.25));
+    }
+
+    #[test]
+    fn test_clamped_width_bounds_percentage() {
+        let mut style = StyleDeclaration::default();
+        // min(600px, 90%)
+        style.box_model.width = Some(CssLength::Clamped {
+            min: None,
+            percent: 90.0,
+            max: Some(450.0),
+        });
+
+        let taffy_style = convert_style_to_taffy(&style, Some("div"));
+        assert_eq!(taffy_style.size.width, Dimension::percent(0.9));
+        assert_eq!(taffy_style.min_size.width, Dimension::auto());
+        assert_eq!(taffy_style.max_size.width, Dimension::length(450.0));
+
+        // clamp(100pt, 50%, 300pt) under a tighter max-width
+        style.box_model.width = Some(CssLength::Clamped {
+            min: Some(100.0),
+            percent: 50.0,
+            max: Some(300.0),
+        });
+        style.box_model.max_width = Some(CssLength::Points(200.0));
+
+        let taffy_style = convert_style_to_taffy(&style, Some("div"));
+        assert_eq!(taffy_style.min_size.width, Dimension::length(100.0));
+        assert_eq!(taffy_style.max_size.width, Dimension::length(200.0));
+    }
+
     #[test]
     fn test_block_container_default_width() {
         let style = StyleDeclaration::default();
@@ -348,7 +635,7 @@ mod tests {
     #[test]
     fn test_max_size_constraints() {
         let mut style = StyleDeclaration::default();
-        style.box_model.max_width = Some(500.0);
+        style.box_model.max_width = Some(CssLength::Points(500.0));
         style.box_model.max_height = Some(300.0);
 
         let taffy_style = convert_style_to_taffy(&style, None);
@@ -356,4 +643,26 @@ mod tests {
         assert_eq!(taffy_style.max_size.width, Dimension::length(500.0));
         assert_eq!(taffy_style.max_size.height, Dimension::length(300.0));
     }
+
+    #[test]
+    fn test_box_sizing_controls_whether_padding_adds_to_width() {
+        // (total width, content width) of a 100px-wide box with 10px padding
+        let laid_out = |box_sizing: &str| {
+            let style = pdf_generator::css_parser::parse_inline_styles(&format!(
+                "width: 100px; padding: 10px; box-sizing: {box_sizing}"
+            ))
+            .unwrap();
+            let mut tree: TaffyTree<()> = TaffyTree::new();
+            let node = tree
+                .new_leaf(convert_style_to_taffy(&style, Some("div")))
+                .unwrap();
+            tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+            let layout = tree.layout(node).unwrap();
+            (layout.size.width, layout.content_box_width())
+        };
+
+        // 100px = 75pt, 10px = 7.5pt
+        assert_eq!(laid_out("border-box"), (75.0, 60.0));
+        assert_eq!(laid_out("content-box"), (90.0, 75.0));
+    }
 }
@@ -4,7 +4,9 @@
 //! It wraps text styling information and implements measurement logic for different layout
 //! scenarios (definite width, min-content, max-content).
 
-use crate::text_layout::{calculate_text_width, wrap_text_with_config, TextLayoutConfig};
+use crate::text_layout::{
+    calculate_text_width, line_width, wrap_text_with_config, TextLayoutConfig,
+};
 use layout_types::{
     StyleDeclaration, TextMeasurer, DEFAULT_FONT_FAMILY, DEFAULT_FONT_SIZE,
     DEFAULT_LINE_HEIGHT_RATIO,
@@ -24,6 +26,14 @@ pub struct TextMeasureContext {
     pub font_size: f64,
     pub line_height: f64,
     pub font_name: String,
+    /// Number of columns the text flows into (1 = no multi-column flow)
+    pub column_count: usize,
+    /// Gap between columns in points
+    pub column_gap: f64,
+    /// Maximum number of wrapped lines (CSS line-clamp)
+    pub line_clamp: Option<usize>,
+    /// Right-aligned tab stops in points from the box's left edge
+    pub tab_stops: Vec<f64>,
 }
 
 impl TextMeasureContext {
@@ -50,11 +60,26 @@ impl TextMeasureContext {
             .clone()
             .unwrap_or_else(|| DEFAULT_FONT_FAMILY.to_string());
 
+        let (column_count, column_gap) = resolve_columns(style, font_size);
+
         Self {
             content,
             font_size,
             line_height,
             font_name,
+            column_count,
+            column_gap,
+            line_clamp: style.text.line_clamp,
+            tab_stops: style.text.tab_stops.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Wrapping configuration for this text
+    fn layout_config(&self) -> TextLayoutConfig {
+        TextLayoutConfig {
+            line_clamp: self.line_clamp,
+            tab_stops: self.tab_stops.clone(),
+            ..TextLayoutConfig::default()
         }
     }
 
@@ -91,6 +116,10 @@ impl TextMeasureContext {
 
         // Calculate available width for text wrapping
         let (width, height) = match available_space.width {
+            AvailableSpace::Definite(w) if self.column_count > 1 => {
+                // Multi-column text always fills the available width
+                self.measure_with_columns(w as f64, measurer)
+            }
             AvailableSpace::Definite(w) => {
                 // Check if the definite width is sufficient for max-content (no wrapping needed)
                 let (max_width, max_height) = self.measure_max_content(measurer);
@@ -122,7 +151,7 @@ impl TextMeasureContext {
 
     /// Measure text with wrapping to fit within a definite width
     fn measure_with_wrapping(&self, max_width: f64, measurer: &dyn TextMeasurer) -> (f32, f32) {
-        let config = TextLayoutConfig::default();
+        let config = self.layout_config();
         let lines = wrap_text_with_config(
             &self.content,
             max_width,
@@ -136,7 +165,14 @@ impl TextMeasureContext {
         let width = lines
             .iter()
             .map(|line| {
-                calculate_text_width(line.as_str(), self.font_size, &self.font_name, measurer)
+                line_width(
+                    line,
+                    max_width,
+                    self.font_size,
+                    &self.font_name,
+                    &config,
+                    measurer,
+                )
             })
             .max_by(|a, b| a.partial_cmp(b).unwrap())
             .unwrap_or(0.0) as f32;
@@ -145,6 +181,28 @@ impl TextMeasureContext {
         (width, height)
     }
 
+    /// Measure text flowed into equal-width columns (CSS column-count)
+    ///
+    /// Lines wrap at the column width and are balanced across columns, so the
+    /// height is that of the tallest column.
+    fn measure_with_columns(&self, max_width: f64, measurer: &dyn TextMeasurer) -> (f32, f32) {
+        let width = column_width(max_width, self.column_count, self.column_gap);
+        let config = self.layout_config();
+        let lines = wrap_text_with_config(
+            &self.content,
+            width,
+            self.font_size,
+            &self.font_name,
+            &config,
+            measurer,
+        )
+        .unwrap_or_else(|_| vec![self.content.clone()]);
+
+        let lines_per_column = lines.len().div_ceil(self.column_count);
+        let height = (lines_per_column as f64 * self.line_height) as f32;
+        (max_width as f32, height)
+    }
+
     /// Measure minimum content width (longest word, no wrapping)
     fn measure_min_content(&self, measurer: &dyn TextMeasurer) -> (f32, f32) {
         let words: Vec<&str> = self.content.split_whitespace().collect();
@@ -168,6 +226,22 @@ impl TextMeasureContext {
     }
 }
 
+/// Resolve multi-column settings from a style
+///
+/// Returns `(column_count, column_gap)`. The count defaults to 1 (no columns);
+/// the gap defaults to 1em, matching CSS `column-gap: normal`.
+pub fn resolve_columns(style: &StyleDeclaration, font_size: f64) -> (usize, f64) {
+    let count = style.box_model.column_count.unwrap_or(1).max(1) as usize;
+    let gap = style.flex.column_gap.unwrap_or(font_size);
+    (count, gap)
+}
+
+/// Width of each column when `total_width` is split into `count` columns
+pub fn column_width(total_width: f64, count: usize, gap: f64) -> f64 {
+    let count = count.max(1);
+    ((total_width - gap * (count - 1) as f64) / count as f64).max(0.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,14 +4,19 @@
 //! and creating the necessary data structures for CSS layout computation.
 
 use super::box_extractor::{ContentType, JsxElementInfo};
-use super::element_mapping::tag_to_element_type;
+use super::element_mapping::{is_role_only_list_item, resolve_element_type};
 use super::layout_element::jsx_to_layout_element;
-use super::style_conversion::convert_style_to_taffy;
+use super::style_conversion::{convert_style_to_taffy, resolve_em_lengths};
 use super::text_measurement::TextMeasureContext;
 use crate::error::LayoutError;
-use layout_types::{Display, StyleDeclaration, TextMeasurer, TextSegment};
+use layout_types::{
+    Display, ElementType, ListStyleType, Position, StyleDeclaration, TextMeasurer, TextSegment,
+};
+use pdf_generator::config::MissingFontPolicy;
+use pdf_generator::error::PDFError;
+use pdf_generator::font_substitution::{resolve_missing_font, FontSubstitution};
 use std::collections::HashMap;
-use style_resolver::resolve_element_styles;
+use style_resolver::{resolve_element_styles_with_stylesheet, Stylesheet};
 use taffy::prelude::*;
 use tsx_parser::{JSXChild, JSXElement};
 
@@ -27,6 +32,7 @@ use tsx_parser::{JSXChild, JSXElement};
 /// * `node_info_map` - Map to store semantic information for each Taffy NodeId
 /// * `jsx` - The JSX element to convert
 /// * `parent_style` - Optional parent style for inheritance
+/// * `stylesheet` - Print rules from the document's `<style>` elements
 /// * `measurer` - Text measurement implementation for text nodes
 ///
 /// # Returns
@@ -43,6 +49,7 @@ pub fn jsx_to_taffy(
     node_info_map: &mut HashMap<NodeId, JsxElementInfo>,
     jsx: &JSXElement,
     parent_style: Option<&StyleDeclaration>,
+    stylesheet: &Stylesheet,
     measurer: &dyn TextMeasurer,
 ) -> Result<NodeId, LayoutError> {
     // 1. Convert JSX to LayoutElement (abstraction layer for testability)
@@ -53,11 +60,23 @@ pub fn jsx_to_taffy(
     let class_name = layout_elem.class_name.as_deref();
     let inline_style = layout_elem.inline_style.as_deref();
 
-    // 3. Resolve styles using style-resolver
-    let resolved_style = resolve_element_styles(class_name, inline_style, parent_style);
-
-    // 4. Determine element type
-    let element_type = tag_to_element_type(element_name);
+    // 3. Resolve styles using style-resolver, then `em` lengths against the
+    //    element's now-known font size
+    let mut resolved_style = resolve_element_styles_with_stylesheet(
+        element_name,
+        class_name,
+        inline_style,
+        parent_style,
+        stylesheet,
+    );
+    resolve_em_lengths(&mut resolved_style);
+
+    // 4. Determine element type (ARIA role and level take precedence over the tag)
+    let element_type = resolve_element_type(
+        element_name,
+        layout_elem.role.as_deref(),
+        layout_elem.aria_level,
+    );
 
     // Check if this element is a flex container
     // In flex containers, ALL children (including spans) become flex items
@@ -111,6 +130,7 @@ pub fn jsx_to_taffy(
                 font_size: None,
                 text_decoration: None,
                 color: None,
+                link: layout_elem.href.clone(),
             }]
         }
     } else {
@@ -118,6 +138,7 @@ pub fn jsx_to_taffy(
         let segments = layout_elem.extract_styled_segments(
             resolved_style.text.font_weight,
             resolved_style.text.font_style,
+            stylesheet,
         );
         // Filter out empty segments
         segments
@@ -161,23 +182,58 @@ pub fn jsx_to_taffy(
             &text_segments,
             &resolved_style,
             element_name,
+            stylesheet,
             measurer,
             has_styled_inline_children,
             element_type,
         )?
     };
 
-    // Store info in map for later LayoutBox extraction
+    // Store info in map for later LayoutBox extraction; descendants already
+    // inherited from the unsuppressed style, so only this box loses its marker
+    let mut style = resolved_style;
+    if is_role_only_list_item(element_name, element_type) {
+        style.text.list_style_type = Some(ListStyleType::None);
+    }
     let info = JsxElementInfo {
         element_type,
-        style: resolved_style,
+        style,
         content_type,
+        list_ordinal: None,
     };
     node_info_map.insert(node_id, info);
 
     Ok(node_id)
 }
 
+/// Whether the element is a list whose items get markers (`<ul>`, `<ol>`)
+fn is_list(element_type: Option<ElementType>) -> bool {
+    matches!(
+        element_type,
+        Some(ElementType::UnorderedList | ElementType::OrderedList)
+    )
+}
+
+/// Number a list item and the boxes standing in for it (such as its text child)
+///
+/// Items of nested lists were numbered when their own list was built, so
+/// boxes that already have an ordinal keep it.
+fn assign_list_ordinal(
+    tree: &TaffyTree<TextMeasureContext>,
+    node_info_map: &mut HashMap<NodeId, JsxElementInfo>,
+    node_id: NodeId,
+    ordinal: usize,
+) {
+    if let Some(info) = node_info_map.get_mut(&node_id) {
+        if info.element_type == Some(ElementType::ListItem) && info.list_ordinal.is_none() {
+            info.list_ordinal = Some(ordinal);
+        }
+    }
+    for child in tree.children(node_id).unwrap_or_default() {
+        assign_list_ordinal(tree, node_info_map, child, ordinal);
+    }
+}
+
 /// Create a text leaf node in the Taffy tree with styled segments
 fn create_text_node(
     tree: &mut TaffyTree<TextMeasureContext>,
@@ -206,11 +262,13 @@ fn create_container_node(
     text_segments: &[TextSegment],
     resolved_style: &StyleDeclaration,
     element_name: &str,
+    stylesheet: &Stylesheet,
     measurer: &dyn TextMeasurer,
     has_styled_inline_children: bool,
     element_type: Option<layout_types::ElementType>,
 ) -> Result<(NodeId, ContentType), LayoutError> {
     let mut child_ids = Vec::new();
+    let mut list_ordinal = 0;
 
     // Check if this is a flex container - in flex context, ALL children become flex items
     // Note: has_styled_inline_children already set display:flex on resolved_style
@@ -223,6 +281,10 @@ fn create_container_node(
     for child in &jsx.children {
         if let JSXChild::Element(child_jsx) = child {
             let child_tag = tsx_parser::extract_element_name(child_jsx);
+            // Stylesheets are applied through `stylesheet`, never rendered
+            if child_tag == "style" {
+                continue;
+            }
             let child_class = tsx_parser::extract_class_name(child_jsx);
 
             // Check if this is a styled inline element that needs its own box
@@ -249,8 +311,16 @@ fn create_container_node(
                     node_info_map,
                     child_jsx,
                     Some(resolved_style),
+                    stylesheet,
                     measurer,
                 )?;
+                let is_list_item = node_info_map
+                    .get(&child_id)
+                    .is_some_and(|info| info.element_type == Some(ElementType::ListItem));
+                if is_list(element_type) && is_list_item {
+                    list_ordinal += 1;
+                    assign_list_ordinal(tree, node_info_map, child_id, list_ordinal);
+                }
                 child_ids.push(child_id);
             }
         }
@@ -275,6 +345,12 @@ fn create_container_node(
         text_child_style.box_model.border_right = None;
         text_child_style.box_model.border_bottom = None;
         text_child_style.box_model.border_left = None;
+        // The container is the one placed out of flow, not its text
+        text_child_style.box_model.position = None;
+        text_child_style.box_model.inset = None;
+        if is_role_only_list_item(element_name, element_type) {
+            text_child_style.text.list_style_type = Some(ListStyleType::None);
+        }
 
         let (text_node_id, text_content_type) =
             create_text_node(tree, text_segments, &text_child_style, element_name)?;
@@ -287,6 +363,7 @@ fn create_container_node(
             element_type,
             style: text_child_style,
             content_type: text_content_type,
+            list_ordinal: None,
         };
         node_info_map.insert(text_node_id, text_info);
     }
@@ -299,6 +376,100 @@ fn create_container_node(
     Ok((node_id, ContentType::Container))
 }
 
+/// Apply `policy` to the fonts of every text node before layout
+///
+/// Text is then measured in the font the renderer registers for it: a named
+/// fallback rewrites the node's `font_family`, and each substituted node is
+/// measured with the substitute. Returns one [`FontSubstitution`] per
+/// distinct missing variant, in document order.
+///
+/// # Errors
+///
+/// Returns `LayoutError::FontError` under [`MissingFontPolicy::Error`].
+pub fn resolve_missing_fonts(
+    tree: &mut TaffyTree<TextMeasureContext>,
+    node_info_map: &mut HashMap<NodeId, JsxElementInfo>,
+    root_id: NodeId,
+    policy: &MissingFontPolicy,
+    is_supplied: &dyn Fn(&str, u16, bool) -> bool,
+) -> Result<Vec<FontSubstitution>, LayoutError> {
+    let mut substitutions = Vec::new();
+    let mut pending = vec![root_id];
+    while let Some(node_id) = pending.pop() {
+        let children = tree
+            .children(node_id)
+            .map_err(|e| LayoutError::CalculationFailed(format!("Taffy children error: {}", e)))?;
+        pending.extend(children.into_iter().rev());
+
+        let Some(info) = node_info_map.get_mut(&node_id) else {
+            continue;
+        };
+        if !matches!(info.content_type, ContentType::Text(_)) {
+            continue;
+        }
+        let substitution =
+            resolve_missing_font(&mut info.style, policy, is_supplied).map_err(|e| match e {
+                PDFError::FontError(message) => LayoutError::FontError(message),
+                e => LayoutError::CalculationFailed(e.to_string()),
+            })?;
+        if let Some(substitution) = substitution {
+            if let Some(context) = tree.get_node_context_mut(node_id) {
+                context.font_name = substitution.substitute.clone();
+            }
+            if !substitutions.contains(&substitution) {
+                substitutions.push(substitution);
+            }
+        }
+    }
+    Ok(substitutions)
+}
+
+/// Move absolutely positioned nodes under their containing block
+///
+/// Taffy places an absolute node relative to its parent, while CSS uses the
+/// nearest positioned ancestor, or the page content area (`root_id`) when
+/// there is none. Each absolute node is moved to the end of its containing
+/// block's children, so it is painted over that block's in-flow content.
+pub fn reparent_absolute_nodes(
+    tree: &mut TaffyTree<TextMeasureContext>,
+    node_info_map: &HashMap<NodeId, JsxElementInfo>,
+    root_id: NodeId,
+) -> Result<(), LayoutError> {
+    reparent_children(tree, node_info_map, root_id, root_id)
+}
+
+fn reparent_children(
+    tree: &mut TaffyTree<TextMeasureContext>,
+    node_info_map: &HashMap<NodeId, JsxElementInfo>,
+    node_id: NodeId,
+    containing_block: NodeId,
+) -> Result<(), LayoutError> {
+    let taffy_error = |e: taffy::TaffyError| {
+        LayoutError::CalculationFailed(format!("Taffy reparenting error: {}", e))
+    };
+    let position = |id: NodeId| {
+        node_info_map
+            .get(&id)
+            .and_then(|info| info.style.box_model.position)
+            .unwrap_or_default()
+    };
+
+    let containing_block = if position(node_id).is_positioned() {
+        node_id
+    } else {
+        containing_block
+    };
+    for child_id in tree.children(node_id).map_err(taffy_error)? {
+        if position(child_id) == Position::Absolute && node_id != containing_block {
+            tree.remove_child(node_id, child_id).map_err(taffy_error)?;
+            tree.add_child(containing_block, child_id)
+                .map_err(taffy_error)?;
+        }
+        reparent_children(tree, node_info_map, child_id, containing_block)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     // Note: Full integration tests are in tests/direct_layout_tests.rs
@@ -12,4 +12,8 @@ pub enum LayoutError {
     /// Content exceeds page bounds
     #[error("Content exceeds page bounds: {0}")]
     ContentOverflow(String),
+
+    /// A requested font is missing and `missing_font_policy` forbids substitution
+    #[error("Font error: {0}")]
+    FontError(String),
 }
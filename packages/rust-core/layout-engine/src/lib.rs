@@ -23,10 +23,16 @@ mod direct_layout;
 
 // Re-export public API
 pub use error::LayoutError;
-pub use text_layout::{wrap_text_with_config, TextLayoutConfig};
+pub use text_layout::{
+    position_tab_runs, wrap_text_with_config, wrap_text_with_ranges, PositionedRun,
+    TextLayoutConfig, WrappedLine,
+};
 
 // Direct layout API
-pub use direct_layout::calculate_layout_direct;
+pub use direct_layout::{
+    calculate_layout_direct, calculate_layout_direct_with_progress, fit_layout_direct,
+    fit_layout_direct_with_fonts, FittedLayout,
+};
 
 // Re-export layout types from shared layout-types crate
 pub use layout_types::{
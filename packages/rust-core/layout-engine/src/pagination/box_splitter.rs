@@ -7,7 +7,7 @@
 //!
 //! Implements CSS `box-decoration-break: clone` semantics.
 
-use layout_types::{BoxContent, LayoutBox, StyleDeclaration, TextLine};
+use layout_types::{BoxContent, Display, FlexDirection, LayoutBox, StyleDeclaration, TextLine};
 
 use super::coordinate_adjuster::adjust_box_y_coordinates;
 
@@ -22,14 +22,38 @@ fn get_line_height(style: &StyleDeclaration) -> f64 {
     })
 }
 
+/// Whether a container lays its children out side by side (a flex row)
+fn is_flex_row(style: &StyleDeclaration) -> bool {
+    style.flex.display == Some(Display::Flex)
+        && style.flex.flex_direction != Some(FlexDirection::Column)
+}
+
 /// Restack boxes vertically starting from a given Y position
 ///
 /// Adjusts Y coordinates of each box to stack sequentially without gaps.
-fn restack_boxes_vertically(boxes: &mut [LayoutBox], start_y: f64) {
-    let mut current_y = start_y;
+/// In a flex row (`side_by_side`), boxes that sit side by side (its columns)
+/// are stacked in separate horizontal lanes that each start at `start_y`, so
+/// columns stay top-aligned on a continuation page and keep their X
+/// positions and gap. Any other container stacks every box in one lane.
+fn restack_boxes_vertically(boxes: &mut [LayoutBox], start_y: f64, side_by_side: bool) {
+    // (left, right, next_y) for each lane
+    let mut lanes: Vec<(f64, f64, f64)> = Vec::new();
+
     for b in boxes.iter_mut() {
-        adjust_box_y_coordinates(b, current_y);
-        current_y += b.height;
+        let center = b.x + b.width / 2.0;
+        let lane_idx = lanes
+            .iter()
+            .position(|&(left, right, _)| !side_by_side || (center >= left && center <= right))
+            .unwrap_or_else(|| {
+                lanes.push((b.x, b.x + b.width, start_y));
+                lanes.len() - 1
+            });
+
+        let lane = &mut lanes[lane_idx];
+        adjust_box_y_coordinates(b, lane.2);
+        lane.0 = lane.0.min(b.x);
+        lane.1 = lane.1.max(b.x + b.width);
+        lane.2 += b.height;
     }
 }
 
@@ -55,7 +79,7 @@ pub fn split_box_at_height(
         BoxContent::Container(children) => {
             // Split children across the boundary
             let split_y_coordinate = y_position + split_height;
-            split_container_children(children, split_y_coordinate, 0.0)
+            split_container_children(children, split_y_coordinate, 0.0, is_flex_row(&style))
         }
         BoxContent::Text(lines) => {
             // Split text lines across the boundary
@@ -68,6 +92,17 @@ pub fn split_box_at_height(
     let first_fragment_height = calculate_content_height(&first_content, &style);
     let remainder_height = calculate_content_height(&second_content, &style);
 
+    // A paragraph split mid-way continues on the next page, so the first
+    // fragment's last line follows `text-align`, not `text-align-last`
+    let mut first_style = style.clone();
+    if let (BoxContent::Text(first_lines), BoxContent::Text(second_lines)) =
+        (&first_content, &second_content)
+    {
+        if !first_lines.is_empty() && !second_lines.is_empty() {
+            first_style.text.text_align_last = style.text.text_align;
+        }
+    }
+
     // Create first fragment
     let first_fragment = LayoutBox {
         x: box_to_split.x,
@@ -75,8 +110,9 @@ pub fn split_box_at_height(
         width: box_to_split.width,
         height: first_fragment_height,
         content: first_content,
-        style: style.clone(),
+        style: first_style,
         element_type: box_to_split.element_type,
+        list_ordinal: None,
     };
 
     // Create remainder fragment (will be positioned on next page)
@@ -88,6 +124,7 @@ pub fn split_box_at_height(
         content: second_content,
         style,
         element_type: box_to_split.element_type,
+        list_ordinal: None,
     };
 
     (first_fragment, remainder)
@@ -101,6 +138,7 @@ pub fn split_box_at_height(
 /// * `children` - Container's child boxes
 /// * `split_y_coordinate` - Absolute Y where page break occurs
 /// * `container_top_y` - Absolute Y of container's top edge
+/// * `side_by_side` - Whether the container is a flex row
 ///
 /// # Returns
 /// Tuple of (first_fragment_content, second_fragment_content)
@@ -108,13 +146,11 @@ fn split_container_children(
     children: Vec<LayoutBox>,
     split_y_coordinate: f64,
     container_top_y: f64,
+    side_by_side: bool,
 ) -> (BoxContent, BoxContent) {
     let mut first_fragment_children = Vec::new();
     let mut second_fragment_children = Vec::new();
 
-    // Track the current Y position in the second fragment for sequential stacking
-    let mut second_fragment_current_y = container_top_y;
-
     for child in children {
         let child_top = child.y;
         let child_bottom = child.y + child.height;
@@ -123,15 +159,12 @@ fn split_container_children(
             // Child fits entirely before the break
             first_fragment_children.push(child);
         } else if child_top >= split_y_coordinate {
-            // Child starts after the break - place sequentially in second fragment
-            let mut adjusted_child = child;
-            adjust_box_y_coordinates(&mut adjusted_child, second_fragment_current_y);
-            second_fragment_current_y += adjusted_child.height;
-            second_fragment_children.push(adjusted_child);
+            // Child starts after the break - moves to the second fragment
+            second_fragment_children.push(child);
         } else {
             // Child spans the break - split it recursively
             let split_height_within_child = split_y_coordinate - child_top;
-            let (first_part, mut second_part) =
+            let (first_part, second_part) =
                 split_box_at_height(child, split_height_within_child, child_top);
 
             // Only add fragments if they have content (widow/orphan prevention may create empty fragments)
@@ -139,14 +172,14 @@ fn split_container_children(
                 first_fragment_children.push(first_part);
             }
             if second_part.height > 0.0 {
-                // Place the second part sequentially in second fragment
-                adjust_box_y_coordinates(&mut second_part, second_fragment_current_y);
-                second_fragment_current_y += second_part.height;
                 second_fragment_children.push(second_part);
             }
         }
     }
 
+    // Stack the second fragment from its top (per column for side-by-side children)
+    restack_boxes_vertically(&mut second_fragment_children, container_top_y, side_by_side);
+
     // Orphan prevention: move trailing headings from first fragment to second
     // This prevents headings from being stranded at the bottom of a page
     if !second_fragment_children.is_empty() {
@@ -154,6 +187,7 @@ fn split_container_children(
             &mut first_fragment_children,
             &mut second_fragment_children,
             container_top_y,
+            side_by_side,
         );
     }
 
@@ -163,6 +197,7 @@ fn split_container_children(
         &mut first_fragment_children,
         &mut second_fragment_children,
         container_top_y,
+        side_by_side,
     );
 
     (
@@ -176,6 +211,7 @@ fn move_orphaned_headings_to_second_fragment(
     first: &mut Vec<LayoutBox>,
     second: &mut Vec<LayoutBox>,
     container_top_y: f64,
+    side_by_side: bool,
 ) {
     // Collect trailing elements to move: headings and any empty/border boxes that follow them
     // This handles cases like: [text_box(heading), border_box] where border_box has height=0
@@ -220,7 +256,7 @@ fn move_orphaned_headings_to_second_fragment(
         // Prepend headings to second fragment and restack
         headings_to_move.append(second);
         *second = headings_to_move;
-        restack_boxes_vertically(second, container_top_y);
+        restack_boxes_vertically(second, container_top_y, side_by_side);
     }
 }
 
@@ -233,6 +269,7 @@ fn apply_list_orphan_prevention(
     first: &mut Vec<LayoutBox>,
     second: &mut Vec<LayoutBox>,
     container_top_y: f64,
+    side_by_side: bool,
 ) {
     use layout_types::ElementType;
 
@@ -283,7 +320,7 @@ fn apply_list_orphan_prevention(
             }
         }
 
-        restack_boxes_vertically(second, container_top_y);
+        restack_boxes_vertically(second, container_top_y, side_by_side);
     }
 
     // If first fragment has only 1 list item left (widow at bottom of page), move it to second
@@ -300,7 +337,7 @@ fn apply_list_orphan_prevention(
         {
             let item = first.remove(idx);
             second.insert(0, item);
-            restack_boxes_vertically(second, container_top_y);
+            restack_boxes_vertically(second, container_top_y, side_by_side);
         }
     }
 }
@@ -308,7 +345,8 @@ fn apply_list_orphan_prevention(
 /// Split text lines across a page boundary
 ///
 /// Implements widow/orphan prevention: requires at least 2 lines in each fragment
-/// to avoid splitting a paragraph with only 1 line on a page.
+/// to avoid splitting a paragraph with only 1 line on a page. The block's CSS
+/// `orphans` (first fragment) and `widows` (second fragment) override the default.
 ///
 /// # Arguments
 /// * `lines` - Text lines to split
@@ -328,9 +366,12 @@ pub fn split_text_lines(
     let lines_in_first = (split_height / line_height).floor() as usize;
     let lines_in_first = lines_in_first.min(lines.len());
 
-    // Widow/orphan prevention: require at least 2 lines in each fragment
+    // Widow/orphan prevention: require at least 2 lines in each fragment unless
+    // the block sets its own thresholds.
     // Only applies when we would actually create two non-empty fragments
     const MIN_LINES_PER_FRAGMENT: usize = 2;
+    let min_orphans = style.text.orphans.unwrap_or(MIN_LINES_PER_FRAGMENT);
+    let min_widows = style.text.widows.unwrap_or(MIN_LINES_PER_FRAGMENT);
 
     // If we can't fit anything, return empty first fragment (let caller decide what to do)
     if lines_in_first == 0 {
@@ -344,9 +385,7 @@ pub fn split_text_lines(
 
     // Now we know we're splitting: 0 < lines_in_first < lines.len()
     // Apply widow/orphan prevention
-    if lines_in_first < MIN_LINES_PER_FRAGMENT
-        || (lines.len() - lines_in_first) < MIN_LINES_PER_FRAGMENT
-    {
+    if lines_in_first < min_orphans || (lines.len() - lines_in_first) < min_widows {
         // Can't split without creating orphan/widow - return all in second fragment
         return (BoxContent::Text(Vec::new()), BoxContent::Text(lines));
     }
@@ -500,6 +539,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_text_lines_respects_css_widows() {
+        let lines: Vec<TextLine> = (1..=5)
+            .map(|i| TextLine::from(format!("Line {}", i)))
+            .collect();
+        let mut style = StyleDeclaration {
+            text: TextStyle {
+                line_height: Some(20.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // 3 lines fit, leaving 2 for the next page: fine under the default of 2
+        let (first, _) = split_text_lines(lines.clone(), 65.0, &style);
+        assert!(matches!(first, BoxContent::Text(ref l) if l.len() == 3));
+
+        // widows: 3 refuses to carry only 2 lines over
+        style.text.widows = Some(3);
+        let (first, second) = split_text_lines(lines.clone(), 65.0, &style);
+        if let (BoxContent::Text(first_lines), BoxContent::Text(second_lines)) = (first, second) {
+            assert_eq!(first_lines.len(), 0, "Should not leave a 2-line widow");
+            assert_eq!(second_lines.len(), 5);
+        } else {
+            panic!("Expected Text content");
+        }
+
+        // orphans: 3 with widows: 2 allows a 3/2 split, but not 2/3
+        style.text.widows = Some(2);
+        style.text.orphans = Some(3);
+        let (first, _) = split_text_lines(lines.clone(), 65.0, &style);
+        assert!(matches!(first, BoxContent::Text(ref l) if l.len() == 3));
+        let (first, _) = split_text_lines(lines, 45.0, &style);
+        assert!(matches!(first, BoxContent::Text(ref l) if l.is_empty()));
+    }
+
     #[test]
     fn test_split_text_lines_allows_valid_split() {
         // Test that valid splits (2+ lines each) work
@@ -558,6 +633,7 @@ mod tests {
                 ..Default::default()
             },
             element_type: None,
+            list_ordinal: None,
         };
 
         // Split at 50pt (should fit 2 lines in first fragment, 2 in second)
@@ -586,6 +662,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_justified_text_box_only_ends_on_last_fragment() {
+        let text_box = LayoutBox {
+            x: 0.0,
+            y: 100.0,
+            width: 200.0,
+            height: 80.0,
+            content: BoxContent::Text(vec![
+                TextLine::from("Line 1"),
+                TextLine::from("Line 2"),
+                TextLine::from("Line 3"),
+                TextLine::from("Line 4"),
+            ]),
+            style: StyleDeclaration {
+                text: TextStyle {
+                    line_height: Some(20.0),
+                    text_align: Some(layout_types::TextAlign::Justify),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        let (first, second) = split_box_at_height(text_box, 50.0, 100.0);
+
+        // The first fragment's last line is still mid-paragraph
+        assert_eq!(
+            first.style.text.text_align_last,
+            Some(layout_types::TextAlign::Justify)
+        );
+        assert_eq!(second.style.text.text_align_last, None);
+    }
+
     #[test]
     fn test_split_container_with_text_children() {
         // Test splitting a container with multiple text children
@@ -597,6 +708,7 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("Child 1 text")]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let child2 = LayoutBox {
@@ -607,6 +719,7 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("Child 2 text")]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let child3 = LayoutBox {
@@ -617,6 +730,7 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("Child 3 text")]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let container = LayoutBox {
@@ -627,6 +741,7 @@ mod tests {
             content: BoxContent::Container(vec![child1, child2, child3]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         // Split at y=130 (after child1, in middle of child2)
@@ -710,6 +825,7 @@ mod tests {
                 ..Default::default()
             },
             element_type: None,
+            list_ordinal: None,
         };
 
         // Try to split at 30pt (would create 1-line orphan + 2-line widow)
@@ -759,6 +875,7 @@ mod tests {
                 ..Default::default()
             },
             element_type: Some(layout_types::ElementType::ListItem),
+            list_ordinal: None,
         };
 
         let li2 = LayoutBox {
@@ -776,6 +893,7 @@ mod tests {
                 ..Default::default()
             },
             element_type: Some(layout_types::ElementType::ListItem),
+            list_ordinal: None,
         };
 
         let li3 = LayoutBox {
@@ -793,6 +911,7 @@ mod tests {
                 ..Default::default()
             },
             element_type: Some(layout_types::ElementType::ListItem),
+            list_ordinal: None,
         };
 
         let ul_container = LayoutBox {
@@ -803,6 +922,7 @@ mod tests {
             content: BoxContent::Container(vec![li1, li2, li3]),
             style: StyleDeclaration::default(),
             element_type: Some(layout_types::ElementType::UnorderedList),
+            list_ordinal: None,
         };
 
         // Split at 30pt - would normally put li1, li2 on first page, li3 alone on second (orphan)
@@ -846,6 +966,7 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("EDUCATION")]),
             style: StyleDeclaration::default(),
             element_type: Some(layout_types::ElementType::Heading2),
+            list_ordinal: None,
         };
 
         let content = LayoutBox {
@@ -866,6 +987,7 @@ mod tests {
                 ..Default::default()
             },
             element_type: None,
+            list_ordinal: None,
         };
 
         let section = LayoutBox {
@@ -876,6 +998,7 @@ mod tests {
             content: BoxContent::Container(vec![heading, content]),
             style: StyleDeclaration::default(),
             element_type: Some(layout_types::ElementType::Section),
+            list_ordinal: None,
         };
 
         // Split at y=725 - heading fits (ends at 720), content doesn't (starts at 720)
@@ -934,6 +1057,7 @@ mod tests {
                 ..Default::default()
             },
             element_type: None,
+            list_ordinal: None,
         };
 
         let list_item = LayoutBox {
@@ -944,6 +1068,7 @@ mod tests {
             content: BoxContent::Container(vec![bullet_text]),
             style: StyleDeclaration::default(),
             element_type: Some(layout_types::ElementType::ListItem),
+            list_ordinal: None,
         };
 
         // Split at y=710 (10pt into the list item)
@@ -976,4 +1101,54 @@ mod tests {
             panic!("Second fragment should be Container");
         }
     }
+
+    #[test]
+    fn test_restack_keeps_side_by_side_columns_top_aligned() {
+        let text_box = |x: f64, y: f64, width: f64| LayoutBox {
+            x,
+            y,
+            width,
+            height: 20.0,
+            content: BoxContent::Text(vec![TextLine::from("Column text")]),
+            style: StyleDeclaration::default(),
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        // Left column (x=0) followed by right column (x=150), as flattened from a flex row
+        let mut boxes = vec![
+            text_box(0.0, 300.0, 120.0),
+            text_box(10.0, 320.0, 110.0), // indented, still in the left column
+            text_box(150.0, 310.0, 200.0),
+            text_box(150.0, 330.0, 200.0),
+        ];
+        restack_boxes_vertically(&mut boxes, 0.0, true);
+
+        let positions: Vec<(f64, f64)> = boxes.iter().map(|b| (b.x, b.y)).collect();
+        assert_eq!(
+            positions,
+            vec![(0.0, 0.0), (10.0, 20.0), (150.0, 0.0), (150.0, 20.0)]
+        );
+    }
+
+    #[test]
+    fn test_restack_column_stacks_indented_children_in_one_lane() {
+        let text_box = |x: f64, y: f64, width: f64| LayoutBox {
+            x,
+            y,
+            width,
+            height: 20.0,
+            content: BoxContent::Text(vec![TextLine::from("Entry text")]),
+            style: StyleDeclaration::default(),
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        // A narrow child followed by one indented past its right edge
+        let mut boxes = vec![text_box(0.0, 300.0, 40.0), text_box(60.0, 320.0, 200.0)];
+        restack_boxes_vertically(&mut boxes, 0.0, false);
+
+        let positions: Vec<(f64, f64)> = boxes.iter().map(|b| (b.x, b.y)).collect();
+        assert_eq!(positions, vec![(0.0, 0.0), (60.0, 20.0)]);
+    }
 }
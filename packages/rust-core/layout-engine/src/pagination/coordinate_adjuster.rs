@@ -52,6 +52,7 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("Test")]),
             element_type: None,
             style: StyleDeclaration::default(),
+            list_ordinal: None,
         }
     }
 
@@ -75,6 +76,7 @@ mod tests {
             content: BoxContent::Container(vec![child1, child2]),
             element_type: Some(ElementType::Div),
             style: StyleDeclaration::default(),
+            list_ordinal: None,
         };
 
         adjust_box_y_coordinates(&mut parent, 72.0);
@@ -7,7 +7,7 @@
 //! 4. Finalizing pages with accumulated boxes
 
 use crate::error::LayoutError;
-use layout_types::{BoxContent, LayoutBox, Page};
+use layout_types::{BoxContent, LayoutBox, Page, Position};
 
 use super::box_splitter::split_box_at_height;
 use super::coordinate_adjuster::adjust_box_y_coordinates;
@@ -25,6 +25,10 @@ const MIN_FRAGMENT_HEIGHT: f64 = 50.0;
 /// * `boxes` - Flat list of all layout boxes (with Taffy-computed positions including margins)
 /// * `content_top` - Y position where content starts (TSX padding from top)
 /// * `content_height` - Available height per page (page height minus margins)
+/// * `baseline_grid` - Grid step the boxes were snapped to, if any; page breaks
+///   then shift content by whole steps so baselines stay on the grid
+/// * `progress` - Optional sink receiving 0.0-100.0 as pages are completed,
+///   estimated against the page count implied by the content height
 ///
 /// # Returns
 /// Vector of pages, each containing boxes for that page
@@ -34,15 +38,30 @@ const MIN_FRAGMENT_HEIGHT: f64 = 50.0;
 /// For page 1, we preserve these positions to maintain proper spacing.
 /// For subsequent pages, we apply an offset to shift content appropriately,
 /// using the same `content_top` for consistency.
+///
+/// # Absolute Positioning
+/// Absolutely positioned boxes are out of flow: they never split or break a
+/// page, and land on the page where the flow reaches their top edge, shifted
+/// by that page's offset.
 pub fn paginate_boxes(
     boxes: Vec<LayoutBox>,
     content_top: f64,
     content_height: f64,
+    baseline_grid: Option<f64>,
+    progress: Option<&dyn Fn(f32)>,
 ) -> Result<Vec<Page>, LayoutError> {
+    let boxes = merge_out_of_flow_boxes(boxes);
     let mut pages = Vec::new();
     let mut current_page_boxes = Vec::new();
     let page_bottom = content_top + content_height;
 
+    // Page breaks only push content down, so this undercounts; progress is capped below 100
+    let content_bottom = boxes.iter().map(|b| b.y + b.height).fold(0.0, f64::max);
+    let estimated_pages = ((content_bottom - content_top) / content_height)
+        .ceil()
+        .max(1.0);
+    let mut reported_pages = 0;
+
     // Track where each box ENDS (box.y + box.height) to determine page breaks
     // Y offset is applied when moving to a new page
     let mut page_y_offset: f64 = 0.0;
@@ -52,6 +71,16 @@ pub fn paginate_boxes(
     while i < boxes.len() {
         let current_box = &boxes[i];
 
+        if is_out_of_flow(current_box) {
+            let mut positioned_box = current_box.clone();
+            if page_y_offset > 0.0 {
+                adjust_box_y_coordinates(&mut positioned_box, current_box.y - page_y_offset);
+            }
+            current_page_boxes.push(positioned_box);
+            i += 1;
+            continue;
+        }
+
         // Calculate where this box would be on the current page
         let box_top_on_page = current_box.y - page_y_offset;
         let box_bottom_on_page = box_top_on_page + current_box.height;
@@ -176,10 +205,10 @@ pub fn paginate_boxes(
         if should_move_heading_to_next_page || should_move_h3_with_subtitle {
             // Move heading to next page to keep it with its content
             finalize_and_start_new_page(&mut pages, &mut current_page_boxes);
-            page_y_offset = current_box.y - content_top;
+            page_y_offset = new_page_offset(current_box.y, content_top, baseline_grid);
 
             let mut positioned_box = current_box.clone();
-            adjust_box_y_coordinates(&mut positioned_box, content_top);
+            adjust_box_y_coordinates(&mut positioned_box, current_box.y - page_y_offset);
             current_page_boxes.push(positioned_box);
             i += 1;
 
@@ -264,10 +293,10 @@ pub fn paginate_boxes(
                 // Heading would be orphaned - move entire box to next page (don't split)
                 finalize_and_start_new_page(&mut pages, &mut current_page_boxes);
 
-                page_y_offset = current_box.y - content_top;
+                page_y_offset = new_page_offset(current_box.y, content_top, baseline_grid);
 
                 let mut positioned_box = current_box.clone();
-                adjust_box_y_coordinates(&mut positioned_box, content_top);
+                adjust_box_y_coordinates(&mut positioned_box, current_box.y - page_y_offset);
                 current_page_boxes.push(positioned_box);
                 i += 1;
             }
@@ -275,7 +304,16 @@ pub fn paginate_boxes(
                 // Box doesn't fit - determine split strategy
                 let remaining_space = page_bottom - box_top_on_page;
 
-                if remaining_space >= MIN_FRAGMENT_HEIGHT && box_top_on_page < page_bottom {
+                // Keep-together entries that fit on a page move whole instead of splitting
+                let keeps_together = current_box
+                    .element_type
+                    .is_some_and(|et| et.keeps_together())
+                    && current_box.height <= content_height;
+
+                if !keeps_together
+                    && remaining_space >= MIN_FRAGMENT_HEIGHT
+                    && box_top_on_page < page_bottom
+                {
                     // We have enough space to split - attempt to split the box
                     split_box_across_pages_preserving_positions(
                         current_box.clone(),
@@ -284,21 +322,30 @@ pub fn paginate_boxes(
                         &mut page_y_offset,
                         page_bottom,
                         content_top,
+                        baseline_grid,
                     );
                 } else {
                     // Not enough space left for a meaningful fragment - move entire box to next page
                     finalize_and_start_new_page(&mut pages, &mut current_page_boxes);
 
                     // Calculate new page offset: shift so this box starts at content_top
-                    page_y_offset = current_box.y - content_top;
+                    page_y_offset = new_page_offset(current_box.y, content_top, baseline_grid);
 
                     let mut positioned_box = current_box.clone();
-                    adjust_box_y_coordinates(&mut positioned_box, content_top);
+                    adjust_box_y_coordinates(&mut positioned_box, current_box.y - page_y_offset);
                     current_page_boxes.push(positioned_box);
                 }
                 i += 1;
             }
         }
+
+        if let Some(report) = progress {
+            if pages.len() > reported_pages {
+                reported_pages = pages.len();
+                let completed = (reported_pages as f64 / estimated_pages).min(0.99);
+                report((completed * 100.0) as f32);
+            }
+        }
     }
 
     // Finalize last page
@@ -311,14 +358,41 @@ pub fn paginate_boxes(
         pages.push(Page::new(1, Vec::new()));
     }
 
+    if let Some(report) = progress {
+        report(100.0);
+    }
+
     Ok(pages)
 }
 
-/// Find the next content box (skip empty/border boxes) starting from index
+/// Whether the box is absolutely positioned and so takes no part in the flow
+fn is_out_of_flow(b: &LayoutBox) -> bool {
+    b.style.box_model.position == Some(Position::Absolute)
+}
+
+/// Move out-of-flow boxes to where their top edge falls in the flow
+///
+/// Absolute boxes are extracted after their containing block's in-flow
+/// content, which may span several pages. Placing each one after the last
+/// flow box starting at or above it lets pagination apply the offset of the
+/// page it is drawn on. Flow order is left untouched.
+fn merge_out_of_flow_boxes(boxes: Vec<LayoutBox>) -> Vec<LayoutBox> {
+    let (out_of_flow, mut merged): (Vec<_>, Vec<_>) = boxes.into_iter().partition(is_out_of_flow);
+    for positioned in out_of_flow {
+        let idx = merged
+            .iter()
+            .rposition(|b| !is_out_of_flow(b) && b.y <= positioned.y)
+            .map_or(0, |idx| idx + 1);
+        merged.insert(idx, positioned);
+    }
+    merged
+}
+
+/// Find the next content box (skip empty/border/out-of-flow boxes) starting from index
 fn find_next_content_box(boxes: &[LayoutBox], start_idx: usize) -> Option<usize> {
     for (idx, b) in boxes.iter().enumerate().skip(start_idx) {
         // Skip empty boxes and zero-height boxes (borders)
-        if !matches!(&b.content, BoxContent::Empty) && b.height > 0.0 {
+        if !matches!(&b.content, BoxContent::Empty) && b.height > 0.0 && !is_out_of_flow(b) {
             return Some(idx);
         }
     }
@@ -333,6 +407,7 @@ fn split_box_across_pages_preserving_positions(
     page_y_offset: &mut f64,
     page_bottom: f64,
     content_top: f64,
+    baseline_grid: Option<f64>,
 ) {
     // Track the current Taffy Y coordinate through splits
     // This is needed because after adjusting the remainder, box_to_split.y becomes page-relative
@@ -373,23 +448,37 @@ fn split_box_across_pages_preserving_positions(
             // The remainder will be placed at content_top, so:
             // page_y = taffy_y - page_y_offset = content_top
             // Therefore: page_y_offset = taffy_y - content_top
-            *page_y_offset = taffy_y - content_top;
+            // (rounded down to whole grid steps when a baseline grid is set)
+            *page_y_offset = new_page_offset(taffy_y, content_top, baseline_grid);
 
             // Adjust remainder position for new page
             let mut adjusted_remainder = remainder;
-            adjust_box_y_coordinates(&mut adjusted_remainder, content_top);
+            adjust_box_y_coordinates(&mut adjusted_remainder, taffy_y - *page_y_offset);
             box_to_split = adjusted_remainder;
         } else {
             // Not enough space for meaningful fragment, move to next page
             finalize_and_start_new_page(pages, current_page_boxes);
 
             // Calculate offset for new page
-            *page_y_offset = taffy_y - content_top;
-            adjust_box_y_coordinates(&mut box_to_split, content_top);
+            *page_y_offset = new_page_offset(taffy_y, content_top, baseline_grid);
+            adjust_box_y_coordinates(&mut box_to_split, taffy_y - *page_y_offset);
         }
     }
 }
 
+/// Page offset that moves flow position `y` to the top of a new page
+///
+/// With a baseline grid the offset is rounded down to whole grid steps, so the
+/// box lands up to one step below `content_top` with its baselines still on the
+/// grid.
+fn new_page_offset(y: f64, content_top: f64, baseline_grid: Option<f64>) -> f64 {
+    let offset = y - content_top;
+    match baseline_grid.filter(|step| step.is_finite() && *step > 0.0) {
+        Some(step) => (offset / step + 1e-6).floor() * step,
+        None => offset,
+    }
+}
+
 /// Finalize current page and prepare for a new one
 fn finalize_and_start_new_page(pages: &mut Vec<Page>, current_page_boxes: &mut Vec<LayoutBox>) {
     if !current_page_boxes.is_empty() {
@@ -431,6 +520,7 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("Test")]),
             element_type,
             style: StyleDeclaration::default(),
+            list_ordinal: None,
         }
     }
 
@@ -440,7 +530,7 @@ mod tests {
         // Box at y=72 (content_top) with height 100
         let boxes = vec![create_test_box_at(72.0, 100.0, None)];
 
-        let result = paginate_boxes(boxes, content_top, 648.0).unwrap();
+        let result = paginate_boxes(boxes, content_top, 648.0, None, None).unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].boxes.len(), 1);
@@ -458,9 +548,76 @@ mod tests {
             create_test_box_at(672.0, 100.0, None),
         ];
 
-        let result = paginate_boxes(boxes, content_top, content_height).unwrap();
+        let result = paginate_boxes(boxes, content_top, content_height, None, None).unwrap();
+
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_pagination_reports_progress_per_page() {
+        let content_top = 72.0;
+        let content_height = 648.0;
+        let boxes = (0..3)
+            .map(|page| create_test_box_at(72.0 + page as f64 * 648.0, 600.0, None))
+            .collect();
+
+        let events = std::cell::RefCell::new(Vec::new());
+        let record = |percent: f32| events.borrow_mut().push(percent);
+        let result =
+            paginate_boxes(boxes, content_top, content_height, None, Some(&record)).unwrap();
+
+        assert_eq!(result.len(), 3);
+        let events = events.into_inner();
+        // One event per completed page before the last, then completion
+        assert_eq!(events.len(), 3);
+        assert!(events.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(events.last(), Some(&100.0));
+    }
+
+    #[test]
+    fn test_experience_entry_moves_whole_to_next_page() {
+        let content_top = 72.0;
+        let content_height = 648.0;
+
+        // Entry at y=600 (120pt left on page 1) with 200pt of content: it
+        // would normally split, but fits on a page so it moves as a unit
+        let mut entry = create_test_box_at(600.0, 200.0, Some(ElementType::Article));
+        entry.content = BoxContent::Container(vec![
+            create_test_box_at(600.0, 20.0, Some(ElementType::Heading3)),
+            create_test_box_at(620.0, 180.0, Some(ElementType::UnorderedList)),
+        ]);
+        let boxes = vec![create_test_box_at(72.0, 528.0, None), entry];
+
+        let result = paginate_boxes(boxes, content_top, content_height, None, None).unwrap();
 
         assert_eq!(result.len(), 2);
+        assert_eq!(result[0].boxes.len(), 1);
+        assert_eq!(result[1].boxes.len(), 1);
+        let moved = &result[1].boxes[0];
+        assert_eq!(moved.y, content_top);
+        let BoxContent::Container(children) = &moved.content else {
+            panic!("entry should stay a container");
+        };
+        assert_eq!(children[0].y, content_top);
+        assert_eq!(children[1].y, content_top + 20.0);
+    }
+
+    #[test]
+    fn test_oversized_experience_entry_still_splits() {
+        let content_top = 72.0;
+        let content_height = 648.0;
+
+        let mut entry = create_test_box_at(600.0, 700.0, Some(ElementType::Article));
+        entry.content = BoxContent::Container(vec![
+            create_test_box_at(600.0, 350.0, None),
+            create_test_box_at(950.0, 350.0, None),
+        ]);
+        let boxes = vec![create_test_box_at(72.0, 528.0, None), entry];
+
+        let result = paginate_boxes(boxes, content_top, content_height, None, None).unwrap();
+
+        // Part of the entry stays on page 1
+        assert_eq!(result[0].boxes.len(), 2);
     }
 
     #[test]
@@ -479,7 +636,7 @@ mod tests {
             create_test_box_at(722.0, 100.0, None),
         ];
 
-        let result = paginate_boxes(boxes, content_top, content_height).unwrap();
+        let result = paginate_boxes(boxes, content_top, content_height, None, None).unwrap();
 
         assert_eq!(result.len(), 2, "Heading should move to page 2");
     }
@@ -507,7 +664,7 @@ mod tests {
             create_test_box_at(702.0, 200.0, None), // Bullet points
         ];
 
-        let result = paginate_boxes(boxes, content_top, content_height).unwrap();
+        let result = paginate_boxes(boxes, content_top, content_height, None, None).unwrap();
 
         // The h3 and its content should be on page 2
         assert_eq!(result.len(), 2, "Should create 2 pages");
@@ -549,7 +706,7 @@ mod tests {
             create_test_box_at(707.0, 150.0, None), // First project content
         ];
 
-        let result = paginate_boxes(boxes, content_top, content_height).unwrap();
+        let result = paginate_boxes(boxes, content_top, content_height, None, None).unwrap();
 
         // The H2 and its content should be on page 2
         assert_eq!(result.len(), 2, "Should create 2 pages");
@@ -594,6 +751,7 @@ mod tests {
             content: BoxContent::Empty,
             element_type: None,
             style: StyleDeclaration::default(),
+            list_ordinal: None,
         };
         // Add border styling
         border_box.style.box_model.border_bottom = Some(layout_types::BorderStyle {
@@ -614,7 +772,7 @@ mod tests {
             create_test_box_at(707.0, 150.0, None), // Project content
         ];
 
-        let result = paginate_boxes(boxes, content_top, content_height).unwrap();
+        let result = paginate_boxes(boxes, content_top, content_height, None, None).unwrap();
 
         // The H2, border, and content should be on page 2
         assert_eq!(result.len(), 2, "Should create 2 pages");
@@ -664,7 +822,7 @@ mod tests {
             create_test_box_at(697.0, 100.0, None),                       // Paragraph (doesn't fit)
         ];
 
-        let result = paginate_boxes(boxes, content_top, content_height).unwrap();
+        let result = paginate_boxes(boxes, content_top, content_height, None, None).unwrap();
 
         assert_eq!(result.len(), 2, "Should create 2 pages");
 
@@ -682,4 +840,23 @@ mod tests {
             "Page 2 should have H3 + paragraph"
         );
     }
+
+    #[test]
+    fn test_baseline_grid_moves_pages_by_whole_steps() {
+        let content_top = 72.0;
+        let content_height = 648.0;
+        // Second box starts 5pt past a grid line and doesn't fit on page 1
+        let boxes = vec![
+            create_test_box_at(72.0, 600.0, None),
+            create_test_box_at(701.0, 40.0, None),
+        ];
+
+        let result = paginate_boxes(boxes, content_top, content_height, Some(12.0), None).unwrap();
+
+        assert_eq!(result.len(), 2);
+        let moved = &result[1].boxes[0];
+        let steps = (701.0 - moved.y) / 12.0;
+        assert_eq!(steps, steps.round(), "moved by {} steps", steps);
+        assert!(moved.y >= content_top && moved.y < content_top + 12.0);
+    }
 }
@@ -11,6 +11,13 @@ pub struct TextLayoutConfig {
     pub enable_hyphenation: bool,
     /// Minimum word length to consider for hyphenation (default: 6)
     pub min_word_length: usize,
+    /// Keep at most this many lines, ending the last with "…" (CSS line-clamp)
+    pub line_clamp: Option<usize>,
+    /// Right-aligned tab stops in points from the start of the line
+    ///
+    /// The n-th `\t` in a line right-aligns the text after it at the n-th
+    /// stop. Tabs without a configured stop align to the right edge.
+    pub tab_stops: Vec<f64>,
 }
 
 impl Default for TextLayoutConfig {
@@ -18,10 +25,18 @@ impl Default for TextLayoutConfig {
         Self {
             enable_hyphenation: false, // Disabled by default to prevent word splits in CVs
             min_word_length: 6,
+            line_clamp: None,
+            tab_stops: Vec::new(),
         }
     }
 }
 
+/// Ellipsis appended to text truncated by line-clamp
+pub(crate) const ELLIPSIS: &str = "…";
+
+/// Tab character separating right-aligned runs within a line
+const TAB: char = '\t';
+
 /// Calculate text width using character-specific width estimates
 ///
 /// **Note:** This function uses hardcoded character widths based on typical sans-serif fonts
@@ -126,6 +141,12 @@ fn combine_words_with_punctuation(words: &[&str], index: usize) -> (String, usiz
 }
 
 /// Wrap text with custom configuration
+///
+/// A `\t` ends the current run and right-aligns the following text at the
+/// next tab stop (see [`TextLayoutConfig::tab_stops`]), producing the
+/// "Role ... Date" pattern. Tabbed lines keep their `\t` separators; use
+/// [`position_tab_runs`] to place the runs. Text before the first tab wraps
+/// as usual, and each tabbed run joins its last line when it fits there.
 pub fn wrap_text_with_config(
     text: &str,
     max_width: f64,
@@ -134,6 +155,59 @@ pub fn wrap_text_with_config(
     config: &TextLayoutConfig,
     measurer: &dyn TextMeasurer,
 ) -> Result<Vec<String>, LayoutError> {
+    let mut runs = text.split(TAB);
+    let mut lines = wrap_words(
+        runs.next().unwrap_or_default(),
+        max_width,
+        font_size,
+        font_name,
+        config,
+        measurer,
+    );
+
+    for run in runs {
+        let run = run.split_whitespace().collect::<Vec<_>>().join(" ");
+        if run.is_empty() {
+            continue;
+        }
+
+        let last = lines
+            .last_mut()
+            .expect("wrap_words returns at least one line");
+        let candidate = format!("{}{}{}", last, TAB, run);
+        if tab_runs_fit(
+            &candidate, max_width, font_size, font_name, config, measurer,
+        ) {
+            *last = candidate;
+        } else {
+            lines.push(format!("{}{}", TAB, run));
+        }
+    }
+
+    // Line clamp: drop overflow lines and mark the cut on the last kept line
+    if let Some(max_lines) = config.line_clamp.filter(|&n| n > 0) {
+        if lines.len() > max_lines {
+            lines.truncate(max_lines);
+            if let Some(last) = lines.last_mut() {
+                *last = fit_with_ellipsis(last, max_width, font_size, font_name, measurer);
+            }
+        }
+    }
+
+    Ok(lines)
+}
+
+/// Greedy word wrap of text without tabs
+///
+/// Always returns at least one (possibly empty) line.
+fn wrap_words(
+    text: &str,
+    max_width: f64,
+    font_size: f64,
+    font_name: &str,
+    config: &TextLayoutConfig,
+    measurer: &dyn TextMeasurer,
+) -> Vec<String> {
     let mut lines = Vec::new();
     let words: Vec<&str> = text.split_whitespace().collect();
     let mut current_line = String::new();
@@ -219,7 +293,208 @@ pub fn wrap_text_with_config(
         lines.push(String::new());
     }
 
-    Ok(lines)
+    lines
+}
+
+/// A run of a wrapped line placed at its horizontal offset
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionedRun {
+    /// Run text (no tab characters)
+    pub text: String,
+    /// Offset of the run's left edge from the start of the line, in points
+    pub x: f64,
+    /// Measured run width in points
+    pub width: f64,
+}
+
+/// Place the tab-separated runs of a wrapped line
+///
+/// The first run starts at 0; each later run is right-aligned at its tab
+/// stop, or at `max_width` if no stop is configured. A run that would
+/// overlap the previous one is pushed right to start where it ends.
+///
+/// # Example
+/// ```
+/// use layout_engine::{position_tab_runs, TextLayoutConfig};
+/// use layout_types::TextMeasurer;
+///
+/// struct Fixed;
+/// impl TextMeasurer for Fixed {
+///     fn measure_text(&self, text: &str, _size: f64, _font: &str) -> f64 {
+///         text.chars().count() as f64 * 5.0
+///     }
+/// }
+///
+/// let config = TextLayoutConfig::default();
+/// let runs = position_tab_runs("Engineer\t2020", 200.0, 10.0, "Helvetica", &config, &Fixed);
+/// assert_eq!(runs[0].x, 0.0);
+/// assert_eq!(runs[1].x, 180.0); // 200 - "2020" width
+/// ```
+pub fn position_tab_runs(
+    line: &str,
+    max_width: f64,
+    font_size: f64,
+    font_name: &str,
+    config: &TextLayoutConfig,
+    measurer: &dyn TextMeasurer,
+) -> Vec<PositionedRun> {
+    let mut runs: Vec<PositionedRun> = Vec::new();
+
+    for (index, text) in line.split(TAB).enumerate() {
+        let width = calculate_text_width(text, font_size, font_name, measurer);
+        let previous_end = runs.last().map_or(0.0, |run| run.x + run.width);
+        let x = if index == 0 {
+            0.0
+        } else {
+            let stop = config
+                .tab_stops
+                .get(index - 1)
+                .copied()
+                .unwrap_or(max_width);
+            (stop - width).max(previous_end)
+        };
+        runs.push(PositionedRun {
+            text: text.to_string(),
+            x,
+            width,
+        });
+    }
+
+    runs
+}
+
+/// Width a wrapped line occupies, accounting for tab stops
+pub(crate) fn line_width(
+    line: &str,
+    max_width: f64,
+    font_size: f64,
+    font_name: &str,
+    config: &TextLayoutConfig,
+    measurer: &dyn TextMeasurer,
+) -> f64 {
+    if !line.contains(TAB) {
+        return calculate_text_width(line, font_size, font_name, measurer);
+    }
+    position_tab_runs(line, max_width, font_size, font_name, config, measurer)
+        .last()
+        .map_or(0.0, |run| run.x + run.width)
+}
+
+/// Check that every run of a tabbed line reaches its stop without overlap
+/// and the line stays within `max_width`
+fn tab_runs_fit(
+    line: &str,
+    max_width: f64,
+    font_size: f64,
+    font_name: &str,
+    config: &TextLayoutConfig,
+    measurer: &dyn TextMeasurer,
+) -> bool {
+    let runs = position_tab_runs(line, max_width, font_size, font_name, config, measurer);
+    let no_overlap = runs
+        .windows(2)
+        .all(|pair| pair[1].x > pair[0].x + pair[0].width || pair[0].text.is_empty());
+    let end = runs.last().map_or(0.0, |run| run.x + run.width);
+    no_overlap && end <= max_width
+}
+
+/// A wrapped line together with the span of source text it came from
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrappedLine {
+    /// Line text as rendered (may include an inserted hyphen or ellipsis)
+    pub text: String,
+    /// Byte range of the source text covered by this line
+    ///
+    /// Ranges of consecutive lines are contiguous and together cover the
+    /// whole input: whitespace between two lines belongs to the later line,
+    /// and the last line extends to the end of the input (including any text
+    /// dropped by line-clamp).
+    pub byte_range: std::ops::Range<usize>,
+}
+
+/// Wrap text like [`wrap_text_with_config`], also reporting source ranges
+///
+/// Used by the interactive preview to map a position on a wrapped line back
+/// to the source text that produced it.
+pub fn wrap_text_with_ranges(
+    text: &str,
+    max_width: f64,
+    font_size: f64,
+    font_name: &str,
+    config: &TextLayoutConfig,
+    measurer: &dyn TextMeasurer,
+) -> Result<Vec<WrappedLine>, LayoutError> {
+    let lines = wrap_text_with_config(text, max_width, font_size, font_name, config, measurer)?;
+    let line_count = lines.len();
+
+    let mut start = 0;
+    Ok(lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let end = if i + 1 == line_count {
+                text.len()
+            } else {
+                source_line_end(text, start, &line)
+            };
+            let wrapped = WrappedLine {
+                text: line,
+                byte_range: start..end,
+            };
+            start = end;
+            wrapped
+        })
+        .collect())
+}
+
+/// Byte offset just past the source text matched by a wrapped line
+///
+/// Walks the source from `start`, matching the line's visible characters and
+/// skipping whitespace (which wrapping collapses). A hyphen inserted by
+/// hyphenation has no source counterpart and is skipped; any other mismatch
+/// (e.g. a line-clamp ellipsis) ends the match.
+fn source_line_end(source: &str, start: usize, line: &str) -> usize {
+    let mut source_chars = source[start..].char_indices().peekable();
+    let mut end = start;
+
+    for line_char in line.chars().filter(|c| !c.is_whitespace()) {
+        while source_chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+
+        match source_chars.peek() {
+            Some(&(offset, c)) if c == line_char => {
+                source_chars.next();
+                end = start + offset + c.len_utf8();
+            }
+            _ if line_char == '-' => continue,
+            _ => break,
+        }
+    }
+
+    end
+}
+
+/// Append an ellipsis to a line, trimming trailing characters until it fits
+///
+/// Trailing whitespace is removed before the ellipsis so the line never ends
+/// in " …". If even the bare ellipsis is too wide, it is returned alone.
+pub(crate) fn fit_with_ellipsis(
+    line: &str,
+    max_width: f64,
+    font_size: f64,
+    font_name: &str,
+    measurer: &dyn TextMeasurer,
+) -> String {
+    let mut kept = line.trim_end().to_string();
+    loop {
+        let candidate = format!("{}{}", kept, ELLIPSIS);
+        if kept.is_empty()
+            || calculate_text_width(&candidate, font_size, font_name, measurer) <= max_width
+        {
+            return candidate;
+        }
+        kept.pop();
+        kept.truncate(kept.trim_end().len());
+    }
 }
 
 #[cfg(test)]
@@ -418,6 +693,8 @@ mod tests {
         let config = TextLayoutConfig {
             enable_hyphenation: false,
             min_word_length: 6,
+            line_clamp: None,
+            tab_stops: Vec::new(),
         };
 
         // "programming" = 11 chars * 10 * 0.6 = 66.0, exceeds max_width of 50
@@ -445,6 +722,8 @@ mod tests {
         let config = TextLayoutConfig {
             enable_hyphenation: true,
             min_word_length: 6,
+            line_clamp: None,
+            tab_stops: Vec::new(),
         };
 
         // "short" = 5 chars, below min_word_length of 6, should NOT be hyphenated
@@ -474,6 +753,8 @@ mod tests {
         let config = TextLayoutConfig {
             enable_hyphenation: true,
             min_word_length: 6,
+            line_clamp: None,
+            tab_stops: Vec::new(),
         };
 
         // "longer" = 6 chars, exactly at min_word_length, SHOULD be eligible for hyphenation
@@ -509,6 +790,8 @@ mod tests {
         let config = TextLayoutConfig {
             enable_hyphenation: true,
             min_word_length: 6,
+            line_clamp: None,
+            tab_stops: Vec::new(),
         };
 
         // "internationalization" = 20 chars * 10 * 0.6 = 120.0
@@ -543,6 +826,8 @@ mod tests {
         let config = TextLayoutConfig {
             enable_hyphenation: false,
             min_word_length: 6,
+            line_clamp: None,
+            tab_stops: Vec::new(),
         };
 
         // "internationalization" = 20 chars * 10 * 0.6 = 120.0
@@ -574,6 +859,8 @@ mod tests {
         let config = TextLayoutConfig {
             enable_hyphenation: true,
             min_word_length: 6,
+            line_clamp: None,
+            tab_stops: Vec::new(),
         };
 
         // Use a word that hyphenates to a piece that fits exactly
@@ -592,4 +879,116 @@ mod tests {
             "All content should be preserved after hyphenation"
         );
     }
+
+    #[test]
+    fn test_line_clamp_truncates_with_ellipsis() {
+        let measurer = MockMeasurer;
+        let config = TextLayoutConfig {
+            line_clamp: Some(2),
+            ..Default::default()
+        };
+
+        // 6pt per character at 10pt: 15 characters per 90pt line
+        let text = "Led the migration of legacy billing services to a modern event driven \
+                    platform while mentoring four engineers";
+        let lines = wrap_text_with_config(text, 90.0, 10.0, "Helvetica", &config, &measurer)
+            .expect("wrapping should succeed");
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].ends_with('…'), "got {:?}", lines[1]);
+        for line in &lines {
+            assert!(measurer.measure_text(line, 10.0, "Helvetica") <= 90.0);
+        }
+
+        // Text that already fits is left untouched
+        let lines =
+            wrap_text_with_config("Short text", 90.0, 10.0, "Helvetica", &config, &measurer)
+                .expect("wrapping should succeed");
+        assert_eq!(lines, vec!["Short text".to_string()]);
+    }
+
+    #[test]
+    fn test_tab_right_aligns_date_on_same_line() {
+        let measurer = MockMeasurer;
+        let config = TextLayoutConfig::default();
+
+        let lines = wrap_text_with_config(
+            "Senior Engineer\tJan 2020",
+            300.0,
+            10.0,
+            "Helvetica",
+            &config,
+            &measurer,
+        )
+        .expect("wrapping should succeed");
+        assert_eq!(lines, vec!["Senior Engineer\tJan 2020".to_string()]);
+
+        let runs = position_tab_runs(&lines[0], 300.0, 10.0, "Helvetica", &config, &measurer);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].text, "Senior Engineer");
+        assert_eq!(runs[0].x, 0.0);
+        assert_eq!(runs[1].text, "Jan 2020");
+        // "Jan 2020" = 8 chars * 6pt, flush with the right edge
+        assert_eq!(runs[1].x, 300.0 - 48.0);
+    }
+
+    #[test]
+    fn test_tab_stops_and_overflow() {
+        let measurer = MockMeasurer;
+        let config = TextLayoutConfig {
+            tab_stops: vec![150.0],
+            ..Default::default()
+        };
+
+        // First tab aligns at the configured stop, second at the right edge
+        let runs = position_tab_runs(
+            "Role\tAcme\t2020",
+            300.0,
+            10.0,
+            "Helvetica",
+            &config,
+            &measurer,
+        );
+        assert_eq!(runs[1].x, 150.0 - 24.0);
+        assert_eq!(runs[2].x, 300.0 - 24.0);
+
+        // A date that no longer fits beside the label moves to its own line
+        let lines = wrap_text_with_config(
+            "Principal Engineer\tJan 2020",
+            120.0,
+            10.0,
+            "Helvetica",
+            &TextLayoutConfig::default(),
+            &measurer,
+        )
+        .expect("wrapping should succeed");
+        assert_eq!(lines.last().unwrap(), "\tJan 2020");
+    }
+
+    #[test]
+    fn test_wrap_text_with_ranges_covers_input() {
+        let measurer = MockMeasurer;
+        let config = TextLayoutConfig::default();
+        let text = "Designed  and shipped a résumé parser\nused by three hiring teams ";
+
+        let lines = wrap_text_with_ranges(text, 90.0, 10.0, "Helvetica", &config, &measurer)
+            .expect("wrapping should succeed");
+        assert!(lines.len() > 2, "text should wrap onto several lines");
+
+        // Contiguous, non-overlapping, and covering the whole input
+        assert_eq!(lines.first().unwrap().byte_range.start, 0);
+        assert_eq!(lines.last().unwrap().byte_range.end, text.len());
+        for pair in lines.windows(2) {
+            assert_eq!(pair[0].byte_range.end, pair[1].byte_range.start);
+        }
+
+        // Each range holds exactly that line's words
+        for line in &lines {
+            let source = &text[line.byte_range.clone()];
+            assert_eq!(
+                source.split_whitespace().collect::<Vec<_>>(),
+                line.text.split_whitespace().collect::<Vec<_>>()
+            );
+        }
+    }
 }
@@ -17,6 +17,29 @@ pub fn default_test_config() -> PDFConfig {
         ats_weights: None,
         compress_content_streams: false,
         generate_bookmarks: true,
+        structured_reading_order: true,
+        reading_order: Default::default(),
+        allow_restricted_fonts: false,
+        producer: None,
+        keywords_from_skills: true,
+        trim_box: None,
+        bleed_box: None,
+        embed_source: false,
+        binary_comment: true,
+        viewer_preferences: None,
+        autolink: false,
+        link_style: None,
+        target_max_bytes: None,
+        strip_optional_font_tables: false,
+        embed_base_fonts: false,
+        baseline_grid: None,
+        missing_font_policy: Default::default(),
+        font_quality: Default::default(),
+        fit_to_pages: None,
+        custom_info: Vec::new(),
+        header_band_height: 0.0,
+        footer_band_height: 0.0,
+        watermark: None,
     }
 }
 
@@ -27,12 +50,22 @@ pub fn default_test_metadata() -> CVMetadata {
         title: None,
         email: None,
         phone: None,
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: 1,
         has_contact_info: false,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: false,
         font_complexity: FontComplexity::Simple,
     }
@@ -2,9 +2,9 @@
 //!
 //! These tests validate the direct pipeline: TSX → TsxDocument → Taffy → LayoutBox
 
-use cv_domain::{CVMetadata, FontComplexity, LayoutType, TSXLayoutConfig};
+use cv_domain::{CVMetadata, FontComplexity, LayoutType, TSXLayoutConfig, TextDirection};
 use layout_engine::{calculate_layout_direct, LayoutError};
-use layout_types::{BoxContent, TextMeasurer};
+use layout_types::{BoxContent, ElementType, TextAlign, TextMeasurer};
 use pdf_generator::config::PDFConfig;
 
 /// Mock text measurer for testing
@@ -24,12 +24,22 @@ fn create_test_configs() -> (CVMetadata, TSXLayoutConfig, PDFConfig) {
         title: None,
         email: Some("test@example.com".to_string()),
         phone: None,
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: 5,
         has_contact_info: true,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: true,
         font_complexity: FontComplexity::Simple,
     };
@@ -43,6 +53,7 @@ fn create_test_configs() -> (CVMetadata, TSXLayoutConfig, PDFConfig) {
             left: 0.0,
         },
         background_color: None,
+        direction: TextDirection::Ltr,
     };
 
     let pdf_config = PDFConfig::default();
@@ -230,6 +241,124 @@ fn test_calculate_layout_direct_text_wrapping() {
     }
 }
 
+#[test]
+fn test_calculate_layout_direct_column_count() {
+    let tsx = r#"
+        export default function CV() {
+            return <p style={{ columnCount: 2, columnGap: '16px' }}>Rust TypeScript React Node PostgreSQL Redis Docker Kubernetes Terraform AWS GraphQL Kafka Python Go Elixir Svelte Tailwind Vite Webpack Jest Playwright Cypress Grafana Prometheus Linux Nginx Git CI/CD Figma Agile</p>;
+        }
+    "#;
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let measurer = MockTextMeasurer;
+
+    let layout =
+        calculate_layout_direct(&document, &metadata, &layout_config, &pdf_config, &measurer)
+            .expect("Layout with column-count should succeed");
+
+    let columns: Vec<_> = layout
+        .pages
+        .iter()
+        .flat_map(|p| &p.boxes)
+        .filter_map(|b| match &b.content {
+            BoxContent::Text(lines) => Some((b.x, b.x + b.width, lines.len())),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(columns.len(), 2, "Paragraph should flow into two columns");
+    let (left_start, left_end, left_lines) = columns[0];
+    let (right_start, right_end, right_lines) = columns[1];
+
+    assert!(
+        left_end <= right_start,
+        "Columns should occupy distinct X ranges: {}..{} vs {}..{}",
+        left_start,
+        left_end,
+        right_start,
+        right_end
+    );
+    assert!(left_lines > 1, "Text should wrap within the column");
+    assert!(
+        left_lines.abs_diff(right_lines) <= 1,
+        "Lines should be balanced across columns: {} vs {}",
+        left_lines,
+        right_lines
+    );
+}
+
+#[test]
+fn test_calculate_layout_direct_percent_width_resolves_against_container() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div style={{ width: '400pt' }}>
+                    <p style={{ width: '50%' }}>Half-width summary</p>
+                </div>
+            );
+        }
+    "#;
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let measurer = MockTextMeasurer;
+
+    let layout =
+        calculate_layout_direct(&document, &metadata, &layout_config, &pdf_config, &measurer)
+            .expect("Layout with percentage width should succeed");
+
+    let text_box = layout
+        .pages
+        .iter()
+        .flat_map(|p| &p.boxes)
+        .find(|b| matches!(&b.content, BoxContent::Text(lines) if !lines.is_empty()))
+        .expect("Paragraph should produce a text box");
+
+    assert!(
+        (text_box.width - 200.0).abs() < 0.01,
+        "50% of a 400pt container should be 200pt, got {}",
+        text_box.width
+    );
+}
+
+#[test]
+fn test_calculate_layout_direct_min_width_resolves_against_container() {
+    let layout_in = |container: &str| {
+        let tsx = format!(
+            r#"
+            export default function CV() {{
+                return (
+                    <div style={{{{ width: '{container}' }}}}>
+                        <p style={{{{ width: 'min(600px, 90%)' }}}}>Bounded summary</p>
+                    </div>
+                );
+            }}
+        "#
+        );
+        let document = tsx_parser::parse_tsx(&tsx).expect("Failed to parse TSX");
+        let (metadata, layout_config, pdf_config) = create_test_configs();
+        calculate_layout_direct(
+            &document,
+            &metadata,
+            &layout_config,
+            &pdf_config,
+            &MockTextMeasurer,
+        )
+        .expect("Layout with min() width should succeed")
+    };
+
+    // 90% of 400pt, then capped at 600px = 450pt
+    assert_eq!(
+        find_text_box(&layout_in("400pt"), "Bounded summary").width,
+        360.0
+    );
+    assert_eq!(
+        find_text_box(&layout_in("600pt"), "Bounded summary").width,
+        450.0
+    );
+}
+
 #[test]
 fn test_calculate_layout_direct_empty_document() {
     let tsx = r#"
@@ -313,12 +442,22 @@ fn test_calculate_layout_direct_tsx_padding_config() {
         title: None,
         email: None,
         phone: None,
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: 1,
         has_contact_info: false,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: false,
         font_complexity: FontComplexity::Simple,
     };
@@ -333,6 +472,7 @@ fn test_calculate_layout_direct_tsx_padding_config() {
             left: 30.0,
         },
         background_color: None,
+        direction: TextDirection::Ltr,
     };
 
     let pdf_config = PDFConfig::default();
@@ -811,10 +951,8 @@ fn test_space_y_gap_applied_to_flex_column() {
         let mut positions = Vec::new();
         for layout_box in boxes {
             match &layout_box.content {
-                BoxContent::Text(lines) => {
-                    if !lines.is_empty() {
-                        positions.push((lines[0].plain_text(), layout_box.y));
-                    }
+                BoxContent::Text(lines) if !lines.is_empty() => {
+                    positions.push((lines[0].plain_text(), layout_box.y));
                 }
                 BoxContent::Container(children) => {
                     positions.extend(collect_y_positions(children));
@@ -866,3 +1004,697 @@ fn test_space_y_gap_applied_to_flex_column() {
         panic!("Could not find expected text positions");
     }
 }
+
+/// Finds the text box whose first line reads `text`
+fn find_text_box<'a>(
+    layout: &'a layout_types::LayoutStructure,
+    text: &str,
+) -> &'a layout_types::LayoutBox {
+    fn find<'a>(
+        boxes: &'a [layout_types::LayoutBox],
+        text: &str,
+    ) -> Option<&'a layout_types::LayoutBox> {
+        boxes.iter().find_map(|b| match &b.content {
+            BoxContent::Text(lines) if lines.first().is_some_and(|l| l.plain_text() == text) => {
+                Some(b)
+            }
+            BoxContent::Container(children) => find(children, text),
+            _ => None,
+        })
+    }
+    layout
+        .pages
+        .iter()
+        .find_map(|p| find(&p.boxes, text))
+        .unwrap_or_else(|| panic!("text box '{}' not found", text))
+}
+
+/// Vertical position and height of the text box whose first line reads `text`
+fn text_box_extent(layout: &layout_types::LayoutStructure, text: &str) -> (f64, f64) {
+    let text_box = find_text_box(layout, text);
+    (text_box.y, text_box.height)
+}
+
+#[test]
+fn test_adjacent_block_margins_collapse() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div>
+                    <div style="margin-bottom: 20pt">Upper</div>
+                    <div style="margin-top: 30pt">Lower</div>
+                </div>
+            );
+        }
+    "#;
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    let (upper_y, upper_height) = text_box_extent(&layout, "Upper");
+    let (lower_y, _) = text_box_extent(&layout, "Lower");
+    let gap = lower_y - (upper_y + upper_height);
+
+    // max(20, 30), not 20 + 30
+    assert!(
+        (gap - 30.0).abs() < 0.01,
+        "Adjacent block margins should collapse to 30pt, got {}",
+        gap
+    );
+}
+
+#[test]
+fn test_flex_item_margins_do_not_collapse() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div style="display: flex; flex-direction: column">
+                    <div style="margin-bottom: 20pt">Upper</div>
+                    <div style="margin-top: 30pt">Lower</div>
+                </div>
+            );
+        }
+    "#;
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    let (upper_y, upper_height) = text_box_extent(&layout, "Upper");
+    let (lower_y, _) = text_box_extent(&layout, "Lower");
+    let gap = lower_y - (upper_y + upper_height);
+
+    // Flex items never collapse margins (CSS Flexbox §4.2)
+    assert!(
+        (gap - 50.0).abs() < 0.01,
+        "Flex item margins should add up to 50pt, got {}",
+        gap
+    );
+}
+
+#[test]
+fn test_flex_wrap_moves_items_onto_new_rows() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div style="display: flex; flex-wrap: wrap; width: 200pt; row-gap: 6pt; column-gap: 4pt">
+                    <div style="width: 60pt">Rust</div>
+                    <div style="width: 60pt">Go</div>
+                    <div style="width: 60pt">SQL</div>
+                    <div style="width: 60pt">AWS</div>
+                    <div style="width: 60pt">Docker</div>
+                    <div style="width: 60pt">Linux</div>
+                    <div style="width: 60pt">React</div>
+                </div>
+            );
+        }
+    "#;
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    let badges: Vec<(f64, f64, f64)> = ["Rust", "Go", "SQL", "AWS", "Docker", "Linux", "React"]
+        .iter()
+        .map(|text| {
+            let text_box = find_text_box(&layout, text);
+            (text_box.x, text_box.y, text_box.height)
+        })
+        .collect();
+
+    let mut rows: Vec<f64> = badges.iter().map(|(_, y, _)| *y).collect();
+    rows.dedup_by(|a, b| (*a - *b).abs() < 0.01);
+    // 3 × 60pt + 2 × 4pt gap fits in 200pt, so 7 items wrap onto 3 rows
+    assert_eq!(rows.len(), 3, "Expected 3 rows, got Y values {:?}", rows);
+
+    // Each row restarts at the container's left edge
+    assert!((badges[3].0 - badges[0].0).abs() < 0.01);
+    assert!((badges[6].0 - badges[0].0).abs() < 0.01);
+
+    // Rows are stacked along the cross axis, separated by row-gap
+    let (_, first_y, first_height) = badges[0];
+    assert!(
+        (rows[1] - (first_y + first_height + 6.0)).abs() < 0.01,
+        "Second row should start one row height plus 6pt below the first"
+    );
+}
+
+#[test]
+fn test_rtl_direction_right_aligns_text_by_default() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div>
+                    <h1>سارة أحمد</h1>
+                    <p className="text-left">مهندسة برمجيات</p>
+                </div>
+            );
+        }
+    "#;
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, _, pdf_config) = create_test_configs();
+    let layout_config = cv_domain::extract_tsx_layout_config_from_document(&document);
+    assert_eq!(layout_config.direction, TextDirection::Rtl);
+
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    let alignments: Vec<_> = layout
+        .pages
+        .iter()
+        .flat_map(|page| page.boxes.iter())
+        .filter(|b| matches!(b.content, BoxContent::Text(_)))
+        .map(|b| b.style.text.text_align)
+        .collect();
+
+    // Heading inherits the RTL default, the explicit text-left class still wins
+    assert_eq!(
+        alignments,
+        vec![Some(TextAlign::Right), Some(TextAlign::Left)]
+    );
+}
+
+#[test]
+fn test_figcaption_renders_below_image() {
+    let caption_y = |image: &str| {
+        let tsx = format!(
+            r#"
+            export default function CV() {{
+                return (
+                    <figure>
+                        {image}
+                        <figcaption>Jane at the 2024 summit</figcaption>
+                    </figure>
+                );
+            }}
+        "#
+        );
+        let document = tsx_parser::parse_tsx(&tsx).expect("Failed to parse TSX");
+        let (metadata, layout_config, pdf_config) = create_test_configs();
+        let layout = calculate_layout_direct(
+            &document,
+            &metadata,
+            &layout_config,
+            &pdf_config,
+            &MockTextMeasurer,
+        )
+        .expect("Layout should succeed");
+
+        let caption = layout.pages[0]
+            .boxes
+            .iter()
+            .find(|b| b.element_type == Some(ElementType::Caption))
+            .expect("caption box");
+        caption.y
+    };
+
+    // A 64px (48pt) image pushes its caption down by its full height
+    let with_image =
+        caption_y(r#"<img src="photo.png" alt="Portrait of Jane" className="w-16 h-16" />"#);
+    let without_image = caption_y("");
+    assert_eq!(with_image - without_image, 48.0);
+}
+
+#[test]
+fn test_baseline_grid_aligns_all_text_baselines() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div>
+                    <h1 className="text-2xl">Jane Doe</h1>
+                    <div className="flex gap-4">
+                        <div className="w-1/3">
+                            <h2 className="text-lg">Skills</h2>
+                            <p className="text-sm">Rust, TypeScript, distributed systems and more things</p>
+                        </div>
+                        <div className="w-2/3">
+                            <h2 className="text-base">Experience</h2>
+                            <p className="text-xs leading-relaxed">Led the migration of the billing platform to an event-driven architecture across several teams</p>
+                        </div>
+                    </div>
+                </div>
+            );
+        }
+    "#;
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, mut layout_config, mut pdf_config) = create_test_configs();
+    layout_config.max_width = None;
+    pdf_config.baseline_grid = Some(12.0);
+    let content_top = pdf_config.margin.top;
+
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    fn collect_baselines(layout_box: &layout_types::LayoutBox, baselines: &mut Vec<f64>) {
+        match &layout_box.content {
+            BoxContent::Text(lines) => {
+                for (index, line) in lines.iter().enumerate() {
+                    let offset =
+                        pdf_generator::line_baseline_offset(&layout_box.style, line, index);
+                    baselines.push(layout_box.y + offset);
+                }
+            }
+            BoxContent::Container(children) => {
+                for child in children {
+                    collect_baselines(child, baselines);
+                }
+            }
+            BoxContent::Empty => {}
+        }
+    }
+    let mut baselines = Vec::new();
+    for layout_box in &layout.pages[0].boxes {
+        collect_baselines(layout_box, &mut baselines);
+    }
+
+    assert!(
+        baselines.len() >= 6,
+        "expected several lines: {:?}",
+        baselines
+    );
+    for baseline in baselines {
+        let steps = (baseline - content_top) / 12.0;
+        assert!(
+            (steps - steps.round()).abs() < 1e-6,
+            "baseline {} is not on the 12pt grid from {}",
+            baseline,
+            content_top
+        );
+    }
+}
+
+#[test]
+fn test_media_print_style_block_overrides_screen_color() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div>
+                    <style>{`
+                        .title { color: blue; }
+                        @media screen { .title, .subtitle { color: red; } }
+                        @media print { .title { color: black; } }
+                    `}</style>
+                    <h1 className="title text-2xl">Jane Doe</h1>
+                    <p className="subtitle">Software Engineer</p>
+                </div>
+            );
+        }
+    "#;
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    let black = layout_types::Color {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 1.0,
+    };
+    let title = find_text_box(&layout, "Jane Doe");
+    assert_eq!(title.style.text.color, Some(black));
+    // The print rule only sets color; the Tailwind size still applies
+    assert!(title.style.text.font_size.unwrap() > 14.0);
+
+    let red = layout_types::Color {
+        r: 255,
+        g: 0,
+        b: 0,
+        a: 1.0,
+    };
+    let subtitle = find_text_box(&layout, "Software Engineer");
+    assert_ne!(subtitle.style.text.color, Some(red));
+
+    // The stylesheet itself is not laid out as text
+    let text = pdf_generator::layout_analyzer::extract_all_text_from_layout(&layout);
+    assert!(!text.contains("color"), "CSS rendered as text: {}", text);
+}
+
+#[test]
+fn test_media_width_features_use_configured_page_size() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div>
+                    <style>{`
+                        @media (min-width: 1000px) { .title { color: red; } }
+                    `}</style>
+                    <h1 className="title">Jane Doe</h1>
+                </div>
+            );
+        }
+    "#;
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let title_color = |page_size: pdf_generator::config::PageSize| {
+        let (metadata, layout_config, mut pdf_config) = create_test_configs();
+        pdf_config.page_size = page_size;
+        let layout = calculate_layout_direct(
+            &document,
+            &metadata,
+            &layout_config,
+            &pdf_config,
+            &MockTextMeasurer,
+        )
+        .expect("Layout should succeed");
+        find_text_box(&layout, "Jane Doe").style.text.color
+    };
+
+    let red = Some(layout_types::Color {
+        r: 255,
+        g: 0,
+        b: 0,
+        a: 1.0,
+    });
+    // Letter is 816px wide, Tabloid 1056px
+    assert_ne!(title_color(pdf_generator::config::PageSize::Letter), red);
+    assert_eq!(title_color(pdf_generator::config::PageSize::Tabloid), red);
+}
+
+#[test]
+fn test_aria_heading_role_maps_div_to_heading_element() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div>
+                    <div role="heading" aria-level="2" className="text-lg font-bold">Experience</div>
+                    <div>Senior Engineer at Acme</div>
+                </div>
+            );
+        }
+    "#;
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    assert_eq!(
+        find_text_box(&layout, "Experience").element_type,
+        Some(ElementType::Heading2)
+    );
+    assert_eq!(
+        find_text_box(&layout, "Senior Engineer at Acme").element_type,
+        Some(ElementType::Div)
+    );
+}
+
+#[test]
+fn test_flex_shorthand_basis_fixes_sidebar_width() {
+    let sidebar_width = |main: &str| {
+        let tsx = format!(
+            r#"
+            export default function CV() {{
+                return (
+                    <div style={{{{ display: 'flex' }}}}>
+                        <div style={{{{ flex: '0 0 200pt' }}}}>Sidebar</div>
+                        <div style={{{{ flex: '1' }}}}>{main}</div>
+                    </div>
+                );
+            }}
+        "#
+        );
+        let document = tsx_parser::parse_tsx(&tsx).expect("Failed to parse TSX");
+        let (metadata, layout_config, pdf_config) = create_test_configs();
+        let layout = calculate_layout_direct(
+            &document,
+            &metadata,
+            &layout_config,
+            &pdf_config,
+            &MockTextMeasurer,
+        )
+        .expect("Layout should succeed");
+        find_text_box(&layout, "Sidebar").width
+    };
+
+    let long_main = "Led the migration of a monolithic billing platform to event-driven \
+        services, cutting invoice latency from hours to seconds across four regions";
+    assert_eq!(sidebar_width("Short"), 200.0);
+    assert_eq!(sidebar_width(long_main), 200.0);
+}
+
+#[test]
+fn test_absolute_box_lands_at_containing_block_origin_without_displacing_flow() {
+    let layout_with_badge = |badge: &str| {
+        let tsx = format!(
+            r#"
+            export default function CV() {{
+                return (
+                    <div>
+                        <div>Summary</div>
+                        <div style={{{{ position: 'relative', padding: '20px' }}}}>
+                            <div>
+                                {badge}
+                                <div>Senior Engineer</div>
+                                <div>Acme Corp</div>
+                            </div>
+                        </div>
+                    </div>
+                );
+            }}
+        "#
+        );
+        let document = tsx_parser::parse_tsx(&tsx).expect("Failed to parse TSX");
+        let (metadata, layout_config, pdf_config) = create_test_configs();
+        calculate_layout_direct(
+            &document,
+            &metadata,
+            &layout_config,
+            &pdf_config,
+            &MockTextMeasurer,
+        )
+        .expect("Layout should succeed")
+    };
+
+    let flowed = layout_with_badge("");
+    let positioned = layout_with_badge(
+        r#"<div style={{ position: 'absolute', top: 0, left: 0 }}>Featured</div>"#,
+    );
+
+    // The relative container starts right below "Summary"
+    let summary = find_text_box(&positioned, "Summary");
+    let badge = find_text_box(&positioned, "Featured");
+    assert_eq!(badge.x, summary.x);
+    assert_eq!(badge.y, summary.y + summary.height);
+
+    for text in ["Senior Engineer", "Acme Corp"] {
+        let before = find_text_box(&flowed, text);
+        let after = find_text_box(&positioned, text);
+        assert_eq!((after.x, after.y), (before.x, before.y), "{} moved", text);
+    }
+}
+
+#[test]
+fn test_absolute_box_stays_on_first_page_in_multi_page_layout() {
+    let paragraphs: String = (1..=80)
+        .map(|n| format!("<p>Paragraph {}</p>", n))
+        .collect();
+    let tsx = format!(
+        r#"
+        export default function CV() {{
+            return (
+                <div>
+                    <header>
+                        <div>Jane Doe</div>
+                        <div style={{{{ position: 'absolute', top: 0, right: 0 }}}}>BADGE</div>
+                    </header>
+                    {paragraphs}
+                </div>
+            );
+        }}
+    "#
+    );
+    let document = tsx_parser::parse_tsx(&tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+    assert!(layout.pages.len() > 1, "80 paragraphs should span pages");
+
+    // Without a positioned ancestor the page is the containing block, so the
+    // badge is extracted after all 80 paragraphs but belongs on page 1
+
+    fn contains_text(boxes: &[layout_types::LayoutBox], text: &str) -> bool {
+        boxes.iter().any(|b| match &b.content {
+            BoxContent::Text(lines) => lines.iter().any(|l| l.plain_text() == text),
+            BoxContent::Container(children) => contains_text(children, text),
+            _ => false,
+        })
+    }
+    let page_of = |text: &str| {
+        layout
+            .pages
+            .iter()
+            .position(|p| contains_text(&p.boxes, text))
+            .unwrap_or_else(|| panic!("text box '{}' not found", text))
+    };
+    assert_eq!(page_of("BADGE"), 0);
+
+    // Top-aligned with the header, inside the page
+    let name = find_text_box(&layout, "Jane Doe");
+    let badge = find_text_box(&layout, "BADGE");
+    assert_eq!(badge.y, name.y);
+    assert!(badge.y >= 0.0 && badge.y + badge.height <= layout.page_height);
+}
+
+#[test]
+fn test_em_margin_resolves_against_own_font_size() {
+    let tsx = r#"
+        export default function CV() {
+            return (
+                <div>
+                    <div>Summary</div>
+                    <div style={{ fontSize: '20px', margin: '1em' }}>Senior Engineer</div>
+                </div>
+            );
+        }
+    "#;
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    // 20px is 15pt, so 1em is 15pt rather than the 12pt default font size
+    let summary = find_text_box(&layout, "Summary");
+    let title = find_text_box(&layout, "Senior Engineer");
+    assert_eq!(title.style.text.font_size, Some(15.0));
+    assert_eq!(title.x - summary.x, 15.0);
+    assert_eq!(title.y - (summary.y + summary.height), 15.0);
+}
+
+/// Layout boxes of list items in document order, flattened out of containers
+fn list_item_boxes(tsx: &str) -> Vec<layout_types::LayoutBox> {
+    fn collect(layout_box: &layout_types::LayoutBox, items: &mut Vec<layout_types::LayoutBox>) {
+        if layout_box.element_type == Some(ElementType::ListItem)
+            && matches!(layout_box.content, BoxContent::Text(_))
+        {
+            items.push(layout_box.clone());
+        }
+        if let BoxContent::Container(children) = &layout_box.content {
+            for child in children {
+                collect(child, items);
+            }
+        }
+    }
+
+    let document = tsx_parser::parse_tsx(tsx).expect("Failed to parse TSX");
+    let (metadata, layout_config, pdf_config) = create_test_configs();
+    let layout = calculate_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &pdf_config,
+        &MockTextMeasurer,
+    )
+    .expect("Layout should succeed");
+
+    let mut items = Vec::new();
+    for layout_box in &layout.pages[0].boxes {
+        collect(layout_box, &mut items);
+    }
+    items
+}
+
+#[test]
+fn test_list_items_are_numbered_within_their_own_list() {
+    let items = list_item_boxes(
+        r#"
+        export default function CV() {
+            return (
+                <div>
+                    <ol className="list-decimal"><li>Rust</li><li>Go</li></ol>
+                    <h2>Languages</h2>
+                    <ol className="list-decimal"><li>English</li><li>German</li></ol>
+                </div>
+            );
+        }
+    "#,
+    );
+
+    let ordinals: Vec<_> = items.iter().map(|b| b.list_ordinal).collect();
+    assert_eq!(ordinals, vec![Some(1), Some(2), Some(1), Some(2)]);
+}
+
+#[test]
+fn test_list_none_keeps_author_padding() {
+    let items = list_item_boxes(
+        r#"
+        export default function CV() {
+            return (
+                <div>
+                    <ul className="list-disc pl-5"><li>With marker</li></ul>
+                    <ul className="list-none pl-5"><li>Padded</li></ul>
+                    <ul className="list-none"><li>Unpadded</li></ul>
+                </div>
+            );
+        }
+    "#,
+    );
+
+    assert_eq!(items.len(), 3);
+    // No gutter is reserved for markers, so only author padding indents items
+    assert!(items[0].x > 0.0, "disc list keeps its padding");
+    assert_eq!(items[1].x, items[0].x, "list-none keeps author padding");
+    assert_eq!(
+        items[2].x, 0.0,
+        "unpadded list-none items line up with the text"
+    );
+}
@@ -7,6 +7,8 @@ fn test_hyphenation_long_word() {
     let config = TextLayoutConfig {
         enable_hyphenation: true,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     // "Telecommunications" should be hyphenated in a narrow column
@@ -54,6 +56,8 @@ fn test_hyphenation_disabled() {
     let config = TextLayoutConfig {
         enable_hyphenation: false,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     let text = "Telecommunications is a long word";
@@ -85,6 +89,8 @@ fn test_hyphenation_short_words_not_hyphenated() {
     let config = TextLayoutConfig {
         enable_hyphenation: true,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     let text = "Hello World Test";
@@ -120,6 +126,8 @@ fn test_hyphenation_min_word_length() {
     let config = TextLayoutConfig {
         enable_hyphenation: true,
         min_word_length: 10, // Only hyphenate very long words
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     let text = "Programming languages";
@@ -298,6 +306,8 @@ fn test_hyphenation_narrow_column() {
     let config = TextLayoutConfig {
         enable_hyphenation: true,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     let text = "Antidisestablishmentarianism";
@@ -439,6 +449,8 @@ fn test_hyphenation_vs_no_hyphenation_line_count() {
     let config_with = TextLayoutConfig {
         enable_hyphenation: true,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
     let lines_with = wrap_text_with_config(
         text,
@@ -453,6 +465,8 @@ fn test_hyphenation_vs_no_hyphenation_line_count() {
     let config_without = TextLayoutConfig {
         enable_hyphenation: false,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
     let lines_without = wrap_text_with_config(
         text,
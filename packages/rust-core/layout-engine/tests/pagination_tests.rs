@@ -220,6 +220,29 @@ fn test_ats_optimized_recent_projects_not_orphaned() {
         ats_weights: None,
         compress_content_streams: false,
         generate_bookmarks: false,
+        structured_reading_order: true,
+        reading_order: Default::default(),
+        allow_restricted_fonts: false,
+        producer: None,
+        keywords_from_skills: true,
+        trim_box: None,
+        bleed_box: None,
+        embed_source: false,
+        binary_comment: true,
+        viewer_preferences: None,
+        autolink: false,
+        link_style: None,
+        target_max_bytes: None,
+        strip_optional_font_tables: false,
+        embed_base_fonts: false,
+        baseline_grid: None,
+        missing_font_policy: Default::default(),
+        font_quality: Default::default(),
+        fit_to_pages: None,
+        custom_info: Vec::new(),
+        header_band_height: 0.0,
+        footer_band_height: 0.0,
+        watermark: None,
     };
 
     let layout = calculate_layout_direct(
@@ -258,3 +281,109 @@ fn test_ats_optimized_recent_projects_not_orphaned() {
         rp_page_idx + 1
     );
 }
+
+/// Left edge, right edge and top of each column in a paginated flex-row fragment
+fn column_extents(row: &layout_types::LayoutBox) -> Vec<(f64, f64, f64)> {
+    let layout_types::BoxContent::Container(children) = &row.content else {
+        panic!("two-column row should stay a container");
+    };
+    let mut columns: Vec<(f64, f64, f64)> = Vec::new();
+    for child in children {
+        match columns.iter_mut().find(|c| c.0 == child.x) {
+            Some(column) => column.2 = column.2.min(child.y),
+            None => columns.push((child.x, child.x + child.width, child.y)),
+        }
+    }
+    columns.sort_by(|a, b| a.0.total_cmp(&b.0));
+    columns
+}
+
+/// Test that an unbalanced two-column layout keeps its columns aligned across pages
+///
+/// Both columns must keep their X positions and gap on the continuation page and
+/// restart at the top of it; the shorter column must not add a phantom page.
+#[test]
+fn test_two_column_pagination_preserves_columns() {
+    let tsx = include_str!(
+        "../../../../test-fixtures/tsx-samples/multi-page/04-two-column-unbalanced.tsx"
+    );
+
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let metadata = default_test_metadata();
+    let layout_config = extract_tsx_layout_config_from_document(&document);
+    let config = default_test_config();
+
+    let layout = calculate_layout_direct(
+        &document,
+        &Arc::new(metadata),
+        &layout_config,
+        &config,
+        &layout_types::EstimatedTextMeasurer,
+    )
+    .expect("Failed to calculate layout");
+
+    // Main column needs 3 pages; the sidebar ends on page 2 without adding one
+    assert_eq!(layout.pages.len(), 3, "Unexpected page count");
+    assert!(layout.pages.iter().all(|p| !p.boxes.is_empty()));
+
+    let page1 = column_extents(&layout.pages[0].boxes[0]);
+    let page2 = column_extents(&layout.pages[1].boxes[0]);
+    assert_eq!(page1.len(), 2, "Page 1 should have both columns");
+    assert_eq!(page2.len(), 2, "Page 2 should have both columns");
+
+    // Identical X positions and gap on both pages
+    let gap = |cols: &[(f64, f64, f64)]| cols[1].0 - cols[0].1;
+    assert_eq!(page1[0].0, page2[0].0);
+    assert_eq!(page1[1].0, page2[1].0);
+    assert!(gap(&page1) > 0.0);
+    assert_eq!(gap(&page1), gap(&page2));
+
+    // Both columns start at the top margin of the continuation page
+    let content_top = layout.pages[1].boxes[0].y;
+    assert_eq!(page2[0].2, content_top, "Sidebar should restart at the top");
+    assert_eq!(
+        page2[1].2, content_top,
+        "Main column should restart at the top"
+    );
+}
+
+/// Test that a footer band keeps body text out of the bottom of every page
+#[test]
+fn test_footer_band_is_kept_clear() {
+    let items: String = (1..=60)
+        .map(|i| format!("<p>Responsibility line {i} describing delivered work</p>"))
+        .collect();
+    let tsx = format!("<div>{items}</div>");
+    let document = parse_tsx(&tsx).expect("Failed to parse TSX");
+    let layout_config = extract_tsx_layout_config_from_document(&document);
+
+    let config = PDFConfig {
+        footer_band_height: 20.0,
+        ..default_test_config()
+    };
+    let layout = calculate_layout_direct(
+        &document,
+        &default_test_metadata(),
+        &layout_config,
+        &config,
+        &layout_types::EstimatedTextMeasurer,
+    )
+    .expect("Failed to calculate layout");
+
+    assert!(layout.pages.len() > 1, "Content should span several pages");
+    let footer_top = layout.page_height - config.margin.bottom - 20.0;
+    for page in &layout.pages {
+        let content_bottom = page
+            .boxes
+            .iter()
+            .map(|b| b.y + b.height)
+            .fold(0.0, f64::max);
+        assert!(
+            content_bottom <= footer_top,
+            "Page {} content ends at {}, inside the footer band starting at {}",
+            page.page_number,
+            content_bottom,
+            footer_top
+        );
+    }
+}
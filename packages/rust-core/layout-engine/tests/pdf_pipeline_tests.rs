@@ -184,6 +184,29 @@ fn test_layout_to_pdf_a4_page_size() {
         ats_weights: None,
         compress_content_streams: false,
         generate_bookmarks: true,
+        structured_reading_order: true,
+        reading_order: Default::default(),
+        allow_restricted_fonts: false,
+        producer: None,
+        keywords_from_skills: true,
+        trim_box: None,
+        bleed_box: None,
+        embed_source: false,
+        binary_comment: true,
+        viewer_preferences: None,
+        autolink: false,
+        link_style: None,
+        target_max_bytes: None,
+        strip_optional_font_tables: false,
+        embed_base_fonts: false,
+        baseline_grid: None,
+        missing_font_policy: Default::default(),
+        font_quality: Default::default(),
+        fit_to_pages: None,
+        custom_info: Vec::new(),
+        header_band_height: 0.0,
+        footer_band_height: 0.0,
+        watermark: None,
     };
 
     let layout = calculate_layout_direct(
@@ -327,3 +350,309 @@ fn test_layout_box_positioning_integrity() {
         .expect("Failed to render layout");
     let _pdf_bytes = generator.finalize().expect("Failed to generate PDF");
 }
+
+/// Render TSX to a PDF and return the URIs of its page-1 link annotations
+fn link_annotation_uris(tsx: &str) -> Vec<String> {
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let layout_config = extract_tsx_layout_config_from_document(&document);
+    let config = default_test_config();
+    let layout = calculate_layout_direct(
+        &document,
+        &Arc::new(default_test_metadata()),
+        &layout_config,
+        &config,
+        &layout_types::EstimatedTextMeasurer,
+    )
+    .expect("Failed to calculate layout");
+
+    let mut generator = PDFGenerator::new(config).expect("Failed to create PDF generator");
+    generator
+        .render_layout(&layout)
+        .expect("Failed to render layout");
+    let pdf_bytes = generator.finalize().expect("Failed to generate PDF");
+
+    let doc = lopdf::Document::load_mem(&pdf_bytes).expect("Failed to load PDF");
+    let page_id = doc.get_pages()[&1];
+    let annots = doc
+        .get_dictionary(page_id)
+        .unwrap()
+        .get(b"Annots")
+        .and_then(|obj| obj.as_array())
+        .cloned()
+        .unwrap_or_default();
+    annots
+        .iter()
+        .map(|annot| {
+            let dict = doc.get_dictionary(annot.as_reference().unwrap()).unwrap();
+            let action = dict.get(b"A").unwrap().as_dict().unwrap();
+            String::from_utf8_lossy(action.get(b"URI").unwrap().as_str().unwrap()).to_string()
+        })
+        .collect()
+}
+
+/// Anchor hrefs become link annotations only when their scheme is safe
+#[test]
+fn test_anchor_href_link_annotations_are_sanitized() {
+    let safe = link_annotation_uris(
+        r#"<div><p>Portfolio: <a href="https://jane.dev" onClick="steal()">my site</a></p></div>"#,
+    );
+    assert_eq!(safe, vec!["https://jane.dev".to_string()]);
+
+    let script = link_annotation_uris(
+        r#"<div><p>Portfolio: <a href="javascript:alert(1)">my site</a></p></div>"#,
+    );
+    assert!(script.is_empty(), "javascript: href must not be linked");
+}
+
+/// Test that fit_to_pages shrinks content that spills onto a second page
+#[test]
+fn test_fit_to_pages_scales_two_pages_onto_one() {
+    let items: String = (1..=45)
+        .map(|i| {
+            format!("<p className=\"mb-2\">Delivered project milestone {i} ahead of schedule</p>")
+        })
+        .collect();
+    let tsx = format!("<div><h1 className=\"text-2xl\">Jane Doe</h1>{items}</div>");
+    let document = parse_tsx(&tsx).expect("Failed to parse TSX");
+    let metadata = default_test_metadata();
+    let layout_config = extract_tsx_layout_config_from_document(&document);
+    let measurer = layout_types::EstimatedTextMeasurer;
+
+    let natural = layout_engine::fit_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &default_test_config(),
+        &measurer,
+        None,
+    )
+    .expect("Failed to calculate layout");
+    assert_eq!(natural.layout.pages.len(), 2, "Content should spill");
+    assert_eq!(natural.scale, 1.0);
+
+    let config = PDFConfig {
+        fit_to_pages: Some(1),
+        ..default_test_config()
+    };
+    let fitted = layout_engine::fit_layout_direct(
+        &document,
+        &metadata,
+        &layout_config,
+        &config,
+        &measurer,
+        None,
+    )
+    .expect("Failed to calculate layout");
+    assert_eq!(fitted.layout.pages.len(), 1);
+    assert!(fitted.scale < 1.0, "scale: {}", fitted.scale);
+    let font_size = |layout: &layout_engine::LayoutStructure| {
+        layout.pages[0].boxes[0].style.text.font_size.unwrap()
+    };
+    assert!(font_size(&fitted.layout) < font_size(&natural.layout));
+}
+
+/// Test that a tabbed line renders as two runs with the date at the tab stop
+#[test]
+fn test_tabbed_line_renders_date_at_tab_stop() {
+    let tsx = r#"<div><p>{"Engineer\t2020"}</p></div>"#;
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let layout_config = extract_tsx_layout_config_from_document(&document);
+    let config = default_test_config();
+    let layout = calculate_layout_direct(
+        &document,
+        &Arc::new(default_test_metadata()),
+        &layout_config,
+        &config,
+        &layout_types::EstimatedTextMeasurer,
+    )
+    .expect("Failed to calculate layout");
+
+    fn find_text_box(boxes: &[layout_types::LayoutBox]) -> Option<&layout_types::LayoutBox> {
+        boxes.iter().find_map(|b| match &b.content {
+            layout_types::BoxContent::Text(_) => Some(b),
+            layout_types::BoxContent::Container(children) => find_text_box(children),
+            layout_types::BoxContent::Empty => None,
+        })
+    }
+    let text_box = find_text_box(&layout.pages[0].boxes).expect("text box");
+
+    let mut generator = PDFGenerator::new(config).expect("Failed to create PDF generator");
+    generator
+        .render_layout(&layout)
+        .expect("Failed to render layout");
+    let pdf_bytes = generator.finalize().expect("Failed to generate PDF");
+    let doc = lopdf::Document::load_mem(&pdf_bytes).expect("Failed to load PDF");
+    let page_id = doc.get_pages()[&1];
+    let content = doc.get_page_content(page_id).unwrap();
+    let operations = lopdf::content::Content::decode(&content)
+        .unwrap()
+        .operations;
+
+    let td = operations.iter().find(|op| op.operator == "Td").unwrap();
+    let start_x = td.operands[0].as_float().unwrap() as f64;
+    let tj = operations.iter().find(|op| op.operator == "TJ").unwrap();
+    let elements = tj.operands[0].as_array().unwrap();
+    let strings: Vec<&[u8]> = elements.iter().filter_map(|e| e.as_str().ok()).collect();
+    assert_eq!(strings.len(), 2, "Expected two runs: {:?}", elements);
+    assert!(
+        strings.iter().all(|s| !s.contains(&0x09)),
+        "Tab must not reach the content stream"
+    );
+
+    // The date run ends at the box's right edge
+    let font_size = 12.0;
+    let engineer = pdf_generator::fonts::estimate_text_width("Engineer", font_size, "Helvetica");
+    let date = pdf_generator::fonts::estimate_text_width("2020", font_size, "Helvetica");
+    let adjustment = elements[1].as_float().unwrap() as f64;
+    let date_x = start_x + engineer - adjustment * font_size / 1000.0;
+    let expected = text_box.x + text_box.width - date;
+    assert!(
+        (date_x - expected).abs() < 0.01,
+        "date at {date_x}, expected {expected}"
+    );
+}
+
+/// Test that a missing font is replaced before text is measured
+#[test]
+fn test_missing_font_fallback_is_measured_before_layout() {
+    use pdf_generator::config::MissingFontPolicy;
+    use pdf_generator::fonts::{estimate_text_width, PDFTextMeasurer};
+
+    let tsx = r#"<div style={{ display: 'flex' }}><p style={{ fontFamily: 'Roboto' }}>Senior Platform Engineer</p></div>"#;
+    let document = parse_tsx(tsx).expect("Failed to parse TSX");
+    let layout_config = extract_tsx_layout_config_from_document(&document);
+    let layout_with = |policy: MissingFontPolicy| {
+        let config = PDFConfig {
+            missing_font_policy: policy,
+            ..default_test_config()
+        };
+        layout_engine::fit_layout_direct_with_fonts(
+            &document,
+            &default_test_metadata(),
+            &layout_config,
+            &config,
+            &PDFTextMeasurer,
+            &|_, _, _| false,
+            None,
+        )
+    };
+
+    let fitted = layout_with(MissingFontPolicy::SubstituteNamedFallback(
+        "Georgia".to_string(),
+    ))
+    .expect("Failed to calculate layout");
+    assert_eq!(fitted.font_substitutions.len(), 1);
+    assert_eq!(fitted.font_substitutions[0].substitute, "Times-Roman");
+
+    fn find_text_box(boxes: &[layout_types::LayoutBox]) -> Option<&layout_types::LayoutBox> {
+        boxes.iter().find_map(|b| match &b.content {
+            layout_types::BoxContent::Text(_) => Some(b),
+            layout_types::BoxContent::Container(children) => find_text_box(children),
+            layout_types::BoxContent::Empty => None,
+        })
+    }
+    let text_box = find_text_box(&fitted.layout.pages[0].boxes).expect("text box");
+    assert_eq!(text_box.style.text.font_family.as_deref(), Some("Georgia"));
+    let times_width = estimate_text_width("Senior Platform Engineer", 12.0, "Times-Roman");
+    assert!(
+        (text_box.width - times_width).abs() < 0.5,
+        "width {} should be measured in Times ({})",
+        text_box.width,
+        times_width
+    );
+
+    match layout_with(MissingFontPolicy::Error) {
+        Err(layout_engine::LayoutError::FontError(message)) => {
+            assert!(message.contains("Roboto:400:false"), "{}", message)
+        }
+        other => panic!("expected a font error, got {:?}", other.map(|f| f.scale)),
+    }
+}
+
+/// Test that `role="listitem"` keeps list semantics without drawing a bullet
+#[test]
+fn test_role_listitem_emits_no_bullet() {
+    let curve_count = |tsx: &str| {
+        let document = parse_tsx(tsx).expect("Failed to parse TSX");
+        let layout_config = extract_tsx_layout_config_from_document(&document);
+        let config = default_test_config();
+        let layout = calculate_layout_direct(
+            &document,
+            &Arc::new(default_test_metadata()),
+            &layout_config,
+            &config,
+            &layout_types::EstimatedTextMeasurer,
+        )
+        .expect("Failed to calculate layout");
+
+        let mut generator = PDFGenerator::new(config).expect("Failed to create PDF generator");
+        generator
+            .render_layout(&layout)
+            .expect("Failed to render layout");
+        let pdf_bytes = generator.finalize().expect("Failed to generate PDF");
+        let doc = lopdf::Document::load_mem(&pdf_bytes).expect("Failed to load PDF");
+        let content = doc.get_page_content(doc.get_pages()[&1]).unwrap();
+        lopdf::content::Content::decode(&content)
+            .unwrap()
+            .operations
+            .iter()
+            .filter(|op| op.operator == "c")
+            .count()
+    };
+
+    assert!(curve_count("<div><ul><li>Led the platform team</li></ul></div>") > 0);
+    assert_eq!(
+        curve_count(
+            r#"<div><div role="list"><div role="listitem">Led the platform team</div></div></div>"#
+        ),
+        0
+    );
+}
+
+/// Emoji contact icons in a real CV cost exactly the special characters weight
+#[test]
+fn test_executive_cv_special_characters_are_weighted() {
+    let tsx =
+        include_str!("../../../../test-fixtures/tsx-samples/multi-page/03-six-page-executive.tsx");
+    let with_icons = tsx.replace(
+        "New York, NY • patricia.williams@email.com",
+        "📍 New York, NY • 📧 patricia.williams@email.com",
+    );
+    assert_ne!(tsx, with_icons, "fixture contact line changed");
+
+    let metadata = default_test_metadata();
+    let validate = |tsx: &str, weights: Option<&pdf_generator::ATSWeights>| {
+        let document = parse_tsx(tsx).expect("Failed to parse TSX");
+        let layout_config = extract_tsx_layout_config_from_document(&document);
+        let layout = calculate_layout_direct(
+            &document,
+            &Arc::new(metadata.clone()),
+            &layout_config,
+            &default_test_config(),
+            &layout_types::EstimatedTextMeasurer,
+        )
+        .expect("Failed to calculate layout");
+        pdf_generator::validate_ats_compatibility(&layout, &metadata, true, weights)
+    };
+
+    let plain = validate(tsx, None);
+    assert!(plain.special_characters.is_empty());
+    assert!(plain.rule("text.special_characters").unwrap().passed);
+
+    let report = validate(&with_icons, None);
+    assert_eq!(report.special_characters, vec!['📍', '📧']);
+    let rule = report.rule("text.special_characters").unwrap();
+    assert!(!rule.passed);
+    assert!(rule.message.contains("📍 📧"));
+    assert!(rule.weight > 0.0);
+    assert!((plain.score - report.score - rule.weight).abs() < 1e-9);
+    assert!(report.warnings.iter().any(|w| w.contains('📧')));
+
+    let weights = pdf_generator::ATSWeights {
+        special_characters: 0.0,
+        ..Default::default()
+    };
+    let disabled = validate(&with_icons, Some(&weights));
+    assert!(disabled.rule("text.special_characters").is_none());
+    assert!(!disabled.warnings.iter().any(|w| w.contains('📧')));
+}
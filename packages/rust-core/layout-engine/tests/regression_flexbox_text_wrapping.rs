@@ -60,12 +60,22 @@ function Resume() {
         title: None,
         email: None,
         phone: None,
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: 1,
         has_contact_info: false,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: false,
         font_complexity: FontComplexity::Simple,
     };
@@ -153,12 +163,22 @@ function Test() {
         title: None,
         email: None,
         phone: None,
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: 1,
         has_contact_info: false,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: false,
         font_complexity: FontComplexity::Simple,
     };
@@ -239,12 +259,22 @@ function Resume() {
         title: None,
         email: None,
         phone: None,
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: 1,
         has_contact_info: false,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: false,
         font_complexity: FontComplexity::Simple,
     };
@@ -357,12 +387,22 @@ function Resume() {
         title: None,
         email: None,
         phone: None,
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: 1,
         has_contact_info: false,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: false,
         font_complexity: FontComplexity::Simple,
     };
@@ -13,6 +13,8 @@ fn test_wrap_text_single_line() {
     let config = TextLayoutConfig {
         enable_hyphenation: false,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     let text = "Hello";
@@ -37,6 +39,8 @@ fn test_wrap_text_multiple_lines() {
     let config = TextLayoutConfig {
         enable_hyphenation: false,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     let text = "This is a long line of text that should wrap to multiple lines";
@@ -60,6 +64,8 @@ fn test_wrap_text_empty() {
     let config = TextLayoutConfig {
         enable_hyphenation: false,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     let text = "";
@@ -84,6 +90,8 @@ fn test_wrap_text_with_long_word() {
     let config = TextLayoutConfig {
         enable_hyphenation: false,
         min_word_length: 6,
+        line_clamp: None,
+        tab_stops: Vec::new(),
     };
 
     let text = "Supercalifragilisticexpialidocious";
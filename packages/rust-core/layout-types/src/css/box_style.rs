@@ -2,7 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::primitives::{BorderStyle, Color, Spacing};
+use super::{BoxSizing, Inset, Position};
+use crate::primitives::{BorderStyle, Color, CssLength, Spacing};
 
 /// Box model styling properties
 ///
@@ -11,19 +12,19 @@ use crate::primitives::{BorderStyle, Color, Spacing};
 ///
 /// # Example
 /// ```
-/// use layout_types::{BoxStyle, Spacing};
+/// use layout_types::{BoxStyle, CssLength, Spacing};
 ///
 /// let mut box_style = BoxStyle::default();
 /// box_style.padding = Some(Spacing::uniform(8.0));
-/// box_style.width = Some(200.0);
+/// box_style.width = Some(CssLength::Points(200.0));
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BoxStyle {
     pub margin: Option<Spacing>,
     pub padding: Option<Spacing>,
-    pub width: Option<f64>,
+    pub width: Option<CssLength>,
     pub height: Option<f64>,
-    pub max_width: Option<f64>,
+    pub max_width: Option<CssLength>,
     pub max_height: Option<f64>,
     pub border_top: Option<BorderStyle>,
     pub border_right: Option<BorderStyle>,
@@ -32,6 +33,22 @@ pub struct BoxStyle {
     pub background_color: Option<Color>,
     pub opacity: Option<f64>,       // 0.0-1.0
     pub border_radius: Option<f64>, // In PDF points
+    pub full_bleed: Option<bool>,   // Paint background out to the page edge
+    pub column_count: Option<u32>,  // Multi-column text flow (CSS column-count)
+    /// Margin sides given as a percentage of the containing block's width.
+    /// A non-zero side takes precedence over the same side of `margin`.
+    pub margin_percent: Option<Spacing>,
+    /// Margin sides given in `em`, resolved against the element's font size
+    /// by the layout engine. A non-zero side replaces the same side of `margin`.
+    pub margin_em: Option<Spacing>,
+    /// Padding sides given in `em`, resolved like [`margin_em`](Self::margin_em)
+    pub padding_em: Option<Spacing>,
+    /// Whether width/height include padding and border (CSS `box-sizing`)
+    pub box_sizing: Option<BoxSizing>,
+    /// Positioning scheme (CSS `position`, default static)
+    pub position: Option<Position>,
+    /// Offsets applied to absolutely positioned boxes
+    pub inset: Option<Inset>,
 }
 
 impl BoxStyle {
@@ -51,6 +68,14 @@ impl BoxStyle {
             background_color: None,
             opacity: None,
             border_radius: None,
+            full_bleed: None,
+            column_count: None,
+            margin_percent: None,
+            margin_em: None,
+            padding_em: None,
+            box_sizing: None,
+            position: None,
+            inset: None,
         }
     }
 
@@ -127,6 +152,14 @@ impl Default for BoxStyle {
             background_color: None,
             opacity: None,
             border_radius: None,
+            full_bleed: None,
+            column_count: None,
+            margin_percent: None,
+            margin_em: None,
+            padding_em: None,
+            box_sizing: None,
+            position: None,
+            inset: None,
         }
     }
 }
@@ -2,7 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::{AlignItems, Display, FlexDirection, JustifyContent};
+use super::{AlignItems, Display, FlexDirection, FlexWrap, JustifyContent};
+use crate::CssLength;
 
 /// Flexbox layout styling properties
 ///
@@ -21,9 +22,11 @@ use super::{AlignItems, Display, FlexDirection, JustifyContent};
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FlexStyle {
     pub display: Option<Display>,
-    pub flex: Option<f64>,
+    pub flex: Option<f64>, // Grow factor, set by `flex` or `flex-grow`
     pub flex_shrink: Option<f64>,
+    pub flex_basis: Option<CssLength>, // None means `auto`
     pub flex_direction: Option<FlexDirection>,
+    pub flex_wrap: Option<FlexWrap>,
     pub justify_content: Option<JustifyContent>,
     pub align_items: Option<AlignItems>,
     pub gap: Option<f64>,        // Gap between flex/grid items (in PDF points)
@@ -38,7 +41,9 @@ impl FlexStyle {
             display: None,
             flex: None,
             flex_shrink: None,
+            flex_basis: None,
             flex_direction: None,
+            flex_wrap: None,
             justify_content: None,
             align_items: None,
             gap: None,
@@ -54,7 +59,9 @@ impl Default for FlexStyle {
             display: Some(Display::Block),
             flex: None,
             flex_shrink: None,
+            flex_basis: None,
             flex_direction: Some(FlexDirection::Row),
+            flex_wrap: None,
             justify_content: None,
             align_items: None,
             gap: None,
@@ -12,6 +12,8 @@ pub use text_style::TextStyle;
 
 use serde::{Deserialize, Serialize};
 
+use crate::primitives::{Color, CssLength};
+
 // ============================================================================
 // Font Types
 // ============================================================================
@@ -99,6 +101,41 @@ pub enum TextDecoration {
     UnderlineStrikethrough,
 }
 
+/// CSS list-style-type property
+///
+/// Selects the marker drawn before list items. Inherited, so it is usually
+/// set on the `<ul>`/`<ol>` and applies to every item.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ListStyleType {
+    /// Filled circle (default for unordered lists)
+    Disc,
+    /// Hollow circle
+    Circle,
+    /// Filled square
+    Square,
+    /// Item number followed by a period ("1.", "2.", ...)
+    Decimal,
+    /// No marker
+    None,
+}
+
+/// CSS text-shadow property (first shadow only)
+///
+/// Rendered as a hard shadow: the text is drawn once in `color`, offset by
+/// (`offset_x`, `offset_y`), underneath the main text. `blur` is kept for
+/// completeness but not rendered.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TextShadow {
+    /// Horizontal offset in PDF points (positive = right)
+    pub offset_x: f64,
+    /// Vertical offset in PDF points (positive = down)
+    pub offset_y: f64,
+    /// Blur radius in PDF points
+    pub blur: f64,
+    /// Shadow color
+    pub color: Color,
+}
+
 /// CSS vertical-align property
 ///
 /// Controls vertical alignment of inline or table-cell elements.
@@ -163,6 +200,20 @@ pub enum FlexDirection {
     ColumnReverse,
 }
 
+/// CSS flex-wrap property
+///
+/// Defines whether flex items are forced onto one line or can wrap onto multiple lines.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum FlexWrap {
+    /// All items on a single line (may overflow or shrink)
+    #[default]
+    NoWrap,
+    /// Items wrap onto additional lines along the cross axis
+    Wrap,
+    /// Items wrap onto additional lines in reverse cross-axis order
+    WrapReverse,
+}
+
 /// CSS justify-content property
 ///
 /// Defines how flex items are distributed along the main axis.
@@ -198,3 +249,64 @@ pub enum AlignItems {
     /// Items stretched to fill container
     Stretch,
 }
+
+/// CSS position property
+///
+/// `Relative` boxes stay in flow and only serve as the containing block of
+/// absolutely positioned descendants; their own offsets are not applied.
+/// `sticky` and `fixed` are not supported.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum Position {
+    /// In normal flow (the CSS initial value)
+    #[default]
+    Static,
+    /// In normal flow, and the containing block of absolute descendants
+    Relative,
+    /// Out of flow, placed by its insets within the nearest positioned
+    /// ancestor (or the page content area)
+    Absolute,
+}
+
+impl Position {
+    /// Whether the box is the containing block of its absolute descendants
+    pub fn is_positioned(self) -> bool {
+        !matches!(self, Self::Static)
+    }
+}
+
+/// Offsets of a positioned box (CSS `top`, `right`, `bottom`, `left`)
+///
+/// `None` sides are `auto`. Percentages resolve against the containing block.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct Inset {
+    pub top: Option<CssLength>,
+    pub right: Option<CssLength>,
+    pub bottom: Option<CssLength>,
+    pub left: Option<CssLength>,
+}
+
+impl Inset {
+    /// Combine with `overrides`, whose set sides replace this inset's
+    pub fn overridden_by(self, overrides: Inset) -> Inset {
+        Inset {
+            top: overrides.top.or(self.top),
+            right: overrides.right.or(self.right),
+            bottom: overrides.bottom.or(self.bottom),
+            left: overrides.left.or(self.left),
+        }
+    }
+}
+
+/// CSS box-sizing property
+///
+/// Whether `width`/`height` include padding and border. Unset boxes are laid
+/// out as `BorderBox`, matching Tailwind's preflight reset that templates
+/// are written against.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum BoxSizing {
+    /// Width and height are the content size; padding and border add to it
+    ContentBox,
+    /// Width and height include padding and border
+    #[default]
+    BorderBox,
+}
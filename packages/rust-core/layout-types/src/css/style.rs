@@ -28,6 +28,10 @@ pub struct StyleDeclaration {
     pub box_model: BoxStyle,
     /// Flexbox layout properties
     pub flex: FlexStyle,
+    /// Declarations marked `!important`, as `property: value` without the
+    /// flag; they are re-applied over later, non-important layers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub important: Vec<String>,
 }
 
 impl StyleDeclaration {
@@ -40,9 +44,19 @@ impl StyleDeclaration {
             text: TextStyle::new(),
             box_model: BoxStyle::new(),
             flex: FlexStyle::new(),
+            important: Vec::new(),
         }
     }
 
+    /// Whether `property` was declared `!important`
+    pub fn is_important(&self, property: &str) -> bool {
+        self.important.iter().any(|declaration| {
+            declaration
+                .split_once(':')
+                .is_some_and(|(name, _)| name.trim() == property)
+        })
+    }
+
     /// Set margin using a Spacing value
     ///
     /// # Example
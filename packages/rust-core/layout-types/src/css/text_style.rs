@@ -3,7 +3,8 @@
 use serde::{Deserialize, Serialize};
 
 use super::{
-    FontStyle, FontWeight, TextAlign, TextDecoration, TextTransform, VerticalAlign, WhiteSpace,
+    FontStyle, FontWeight, ListStyleType, TextAlign, TextDecoration, TextShadow, TextTransform,
+    VerticalAlign, WhiteSpace,
 };
 use crate::primitives::Color;
 
@@ -41,6 +42,24 @@ pub struct TextStyle {
     pub letter_spacing: Option<f64>,
     pub vertical_align: Option<VerticalAlign>,
     pub white_space: Option<WhiteSpace>,
+    /// Maximum number of wrapped lines; overflow is cut with a trailing "…"
+    /// (CSS `-webkit-line-clamp`, Tailwind `line-clamp-*`)
+    pub line_clamp: Option<usize>,
+    /// Hard drop shadow drawn beneath the text (CSS `text-shadow`)
+    pub text_shadow: Option<TextShadow>,
+    /// Minimum lines kept at the bottom of a page when a block is split (CSS `orphans`)
+    pub orphans: Option<usize>,
+    /// Minimum lines carried to the top of the next page when a block is split (CSS `widows`)
+    pub widows: Option<usize>,
+    /// Marker drawn before list items (CSS `list-style-type`); `None` draws a disc
+    pub list_style_type: Option<ListStyleType>,
+    /// Alignment of a block's last line (CSS `text-align-last`); `None`
+    /// follows `text_align`, except that justified text ends flush left
+    pub text_align_last: Option<TextAlign>,
+    /// Right-aligned tab stops in points from the box's left edge; the n-th
+    /// `\t` in a line aligns the text after it at the n-th stop, and tabs
+    /// without a stop align to the right edge
+    pub tab_stops: Option<Vec<f64>>,
 }
 
 impl TextStyle {
@@ -59,6 +78,13 @@ impl TextStyle {
             letter_spacing: None,
             vertical_align: None,
             white_space: None,
+            line_clamp: None,
+            text_shadow: None,
+            orphans: None,
+            widows: None,
+            list_style_type: None,
+            text_align_last: None,
+            tab_stops: None,
         }
     }
 }
@@ -78,6 +104,13 @@ impl Default for TextStyle {
             letter_spacing: Some(0.0),
             vertical_align: None,
             white_space: None,
+            line_clamp: None,
+            text_shadow: None,
+            orphans: None,
+            widows: None,
+            list_style_type: None,
+            text_align_last: None,
+            tab_stops: None,
         }
     }
 }
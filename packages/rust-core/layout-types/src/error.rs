@@ -11,4 +11,6 @@ pub enum ValidationError {
     InvalidFontSize(f64),
     #[error("Dimension must be non-negative, got {0}")]
     NegativeDimension(f64),
+    #[error("Invalid layout box on page {page}: {reason}")]
+    InvalidLayoutBox { page: usize, reason: String },
 }
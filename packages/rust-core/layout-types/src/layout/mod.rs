@@ -32,6 +32,9 @@ pub struct TextSegment {
     pub text_decoration: Option<TextDecoration>,
     /// Text color (None means inherit from parent)
     pub color: Option<Color>,
+    /// Link target from an enclosing `<a href>`, already sanitized
+    #[serde(default)]
+    pub link: Option<String>,
 }
 
 /// Layout information attached to an element after layout computation
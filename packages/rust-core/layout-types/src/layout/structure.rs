@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::css::StyleDeclaration;
+use crate::error::ValidationError;
 
 // ============================================================================
 // Element Type
@@ -49,6 +50,12 @@ pub enum ElementType {
     Footer,
     Main,
 
+    // Semantic elements
+    /// `<address>`: the candidate's contact details
+    ContactBlock,
+    /// `<time>`: a date or date range
+    DateTime,
+
     // Inline elements
     Strong,
     Em,
@@ -57,6 +64,10 @@ pub enum ElementType {
 
     // Other elements
     Image,
+    /// `<figure>`: an image grouped with its caption
+    Figure,
+    /// `<figcaption>`: the caption of a figure, rendered below the image
+    Caption,
     Break,
 }
 
@@ -98,6 +109,16 @@ impl ElementType {
     pub fn needs_lookahead_orphan_prevention(&self) -> bool {
         matches!(self, Self::Heading1 | Self::Heading2)
     }
+
+    /// Check if this element should stay on one page when it fits on one.
+    ///
+    /// `<article>` conventionally wraps a single CV entry (a role heading and
+    /// its bullets), so pagination moves the whole entry to the next page
+    /// instead of splitting it, as if it had `break-inside: avoid`. Entries
+    /// taller than a page are still split.
+    pub fn keeps_together(&self) -> bool {
+        matches!(self, Self::Article)
+    }
 }
 
 // ============================================================================
@@ -126,6 +147,9 @@ pub struct LayoutBox {
     pub style: StyleDeclaration,
     /// Optional HTML element type for semantic rendering decisions
     pub element_type: Option<ElementType>,
+    /// Position of a list item within its list, starting at 1, for decimal markers
+    #[serde(default)]
+    pub list_ordinal: Option<usize>,
 }
 
 /// Content within a layout box
@@ -163,6 +187,7 @@ impl TextLine {
                 font_size: None,
                 text_decoration: None,
                 color: None,
+                link: None,
             }],
         }
     }
@@ -246,3 +271,119 @@ pub struct LayoutStructure {
     /// Pages in the document (for multi-page support)
     pub pages: Vec<Page>,
 }
+
+impl LayoutStructure {
+    /// Check that every box has usable geometry before rendering
+    ///
+    /// Rejects non-finite coordinates or sizes, negative sizes, and boxes
+    /// lying entirely outside the page. Such boxes come from styles the
+    /// layout engine could not resolve and would corrupt the PDF.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        for page in &self.pages {
+            for layout_box in &page.boxes {
+                self.validate_box(page.page_number, layout_box)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_box(&self, page: usize, layout_box: &LayoutBox) -> Result<(), ValidationError> {
+        let invalid = |reason: String| ValidationError::InvalidLayoutBox { page, reason };
+        let LayoutBox {
+            x,
+            y,
+            width,
+            height,
+            ..
+        } = *layout_box;
+        let position = format!("box at ({}, {}) sized {}x{}", x, y, width, height);
+
+        for (name, value) in [("x", x), ("y", y), ("width", width), ("height", height)] {
+            if !value.is_finite() {
+                return Err(invalid(format!("{} is {} ({})", name, value, position)));
+            }
+        }
+        if width < 0.0 || height < 0.0 {
+            return Err(invalid(format!("negative dimension ({})", position)));
+        }
+        if x > self.page_width || y > self.page_height || x + width < 0.0 || y + height < 0.0 {
+            return Err(invalid(format!(
+                "outside the {}x{} page ({})",
+                self.page_width, self.page_height, position
+            )));
+        }
+
+        if let BoxContent::Container(children) = &layout_box.content {
+            for child in children {
+                self.validate_box(page, child)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout_with_box(x: f64, y: f64, width: f64, height: f64) -> LayoutStructure {
+        let layout_box = LayoutBox {
+            x,
+            y,
+            width,
+            height,
+            content: BoxContent::Empty,
+            style: StyleDeclaration::default(),
+            element_type: None,
+            list_ordinal: None,
+        };
+        LayoutStructure {
+            page_width: 612.0,
+            page_height: 792.0,
+            pages: vec![Page::new(1, vec![layout_box])],
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_box_on_page() {
+        assert_eq!(layout_with_box(72.0, 72.0, 468.0, 20.0).validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_rejects_nan_width() {
+        let error = layout_with_box(72.0, 72.0, f64::NAN, 20.0)
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid layout box on page 1: width is NaN (box at (72, 72) sized NaNx20)"
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_negative_and_off_page_boxes() {
+        let negative = layout_with_box(72.0, 72.0, -5.0, 20.0).validate();
+        assert!(negative
+            .unwrap_err()
+            .to_string()
+            .contains("negative dimension"));
+
+        let off_page = layout_with_box(72.0, 900.0, 100.0, 20.0).validate();
+        assert!(off_page
+            .unwrap_err()
+            .to_string()
+            .contains("outside the 612x792 page"));
+    }
+
+    #[test]
+    fn test_validate_checks_nested_boxes() {
+        let mut layout = layout_with_box(0.0, 0.0, 612.0, 792.0);
+        let child = layout_with_box(10.0, f64::INFINITY, 10.0, 10.0).pages[0].boxes[0].clone();
+        layout.pages[0].boxes[0].content = BoxContent::Container(vec![child]);
+        assert!(layout
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("y is inf"));
+    }
+}
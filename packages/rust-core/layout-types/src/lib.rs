@@ -31,16 +31,16 @@ pub mod text_measurement;
 
 // Re-export all public types for backwards compatibility
 pub use css::{
-    AlignItems, BoxStyle, Display, FlexDirection, FlexStyle, FontStyle, FontWeight, JustifyContent,
-    StyleDeclaration, TextAlign, TextDecoration, TextStyle, TextTransform, VerticalAlign,
-    WhiteSpace,
+    AlignItems, BoxSizing, BoxStyle, Display, FlexDirection, FlexStyle, FlexWrap, FontStyle,
+    FontWeight, Inset, JustifyContent, ListStyleType, Position, StyleDeclaration, TextAlign,
+    TextDecoration, TextShadow, TextStyle, TextTransform, VerticalAlign, WhiteSpace,
 };
 pub use error::ValidationError;
 pub use layout::{
     BoxContent, ElementType, LayoutBox, LayoutElement, LayoutInfo, LayoutStructure, Page, TextLine,
     TextSegment,
 };
-pub use primitives::{BorderLineStyle, BorderStyle, Color, Rect, Spacing};
+pub use primitives::{BorderLineStyle, BorderStyle, Color, CssLength, Rect, Spacing};
 pub use text_measurement::{EstimatedTextMeasurer, TextMeasurer};
 
 // Re-export constants from css module
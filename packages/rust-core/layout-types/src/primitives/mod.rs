@@ -1,4 +1,4 @@
-//! Primitive types for layout system (Color, Spacing, Rect)
+//! Primitive types for layout system (Color, Spacing, CssLength, Rect)
 
 mod border;
 
@@ -185,6 +185,80 @@ impl Spacing {
     pub const ZERO: Self = Self::uniform(0.0);
 }
 
+// ============================================================================
+// CssLength
+// ============================================================================
+
+/// CSS length that may depend on the containing block
+///
+/// Absolute units are converted to PDF points at parse time. Percentages
+/// can only be resolved once the containing box's width is known, and `em`
+/// lengths once the element's font size is, so both are kept as-is and
+/// resolved by the layout engine. So are `min()`, `max()` and `clamp()`
+/// mixing a percentage with absolute lengths.
+///
+/// # Example
+/// ```
+/// use layout_types::CssLength;
+///
+/// let half = CssLength::Percent(50.0);
+/// assert_eq!(half.resolve(400.0), 200.0);
+///
+/// let fixed = CssLength::Points(72.0);
+/// assert_eq!(fixed.resolve(400.0), 72.0);
+///
+/// let em = CssLength::Em(2.0).resolve_em(15.0);
+/// assert_eq!(em, CssLength::Points(30.0));
+///
+/// // min(450pt, 90%)
+/// let clamped = CssLength::Clamped { min: None, percent: 90.0, max: Some(450.0) };
+/// assert_eq!(clamped.resolve(400.0), 360.0);
+/// assert_eq!(clamped.resolve(600.0), 450.0);
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum CssLength {
+    /// Absolute length in PDF points
+    Points(f64),
+    /// Percentage of the containing block's width (50.0 = 50%)
+    Percent(f64),
+    /// Multiple of the element's own font size (1.5 = 1.5em)
+    Em(f64),
+    /// Percentage of the containing block's width kept between point bounds,
+    /// `max(min, min(percent%, max))` like CSS `clamp()`
+    Clamped {
+        min: Option<f64>,
+        percent: f64,
+        max: Option<f64>,
+    },
+}
+
+impl CssLength {
+    /// Resolve to PDF points against the containing block's width
+    ///
+    /// An `em` length not yet passed through [`resolve_em`](Self::resolve_em)
+    /// uses [`DEFAULT_FONT_SIZE`](crate::DEFAULT_FONT_SIZE).
+    pub fn resolve(&self, container_width: f64) -> f64 {
+        match self {
+            Self::Points(points) => *points,
+            Self::Percent(percent) => container_width * percent / 100.0,
+            Self::Em(em) => em * crate::DEFAULT_FONT_SIZE,
+            Self::Clamped { min, percent, max } => {
+                let length = container_width * percent / 100.0;
+                let length = max.map_or(length, |max| length.min(max));
+                min.map_or(length, |min| length.max(min))
+            }
+        }
+    }
+
+    /// Convert an `em` length to points against `font_size`; other lengths are unchanged
+    pub fn resolve_em(self, font_size: f64) -> Self {
+        match self {
+            Self::Em(em) => Self::Points(em * font_size),
+            other => other,
+        }
+    }
+}
+
 // ============================================================================
 // Rect
 // ============================================================================
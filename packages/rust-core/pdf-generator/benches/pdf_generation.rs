@@ -15,6 +15,7 @@ fn create_single_page_layout() -> LayoutStructure {
             content: BoxContent::Text(vec!["John Doe".to_string().into()]),
             style: StyleDeclaration::default(),
             element_type: Some(ElementType::Heading1),
+            list_ordinal: None,
         },
         // Contact info
         LayoutBox {
@@ -28,6 +29,7 @@ fn create_single_page_layout() -> LayoutStructure {
 
             style: StyleDeclaration::default(),
             element_type: Some(ElementType::Paragraph),
+            list_ordinal: None,
         },
         // Experience section
         LayoutBox {
@@ -38,6 +40,7 @@ fn create_single_page_layout() -> LayoutStructure {
             content: BoxContent::Text(vec!["Experience".to_string().into()]),
             style: StyleDeclaration::default(),
             element_type: Some(ElementType::Heading2),
+            list_ordinal: None,
         },
         // Job 1
         LayoutBox {
@@ -53,6 +56,7 @@ fn create_single_page_layout() -> LayoutStructure {
             ]),
             style: StyleDeclaration::default(),
             element_type: Some(ElementType::Div),
+            list_ordinal: None,
         },
         // Education section
         LayoutBox {
@@ -63,6 +67,7 @@ fn create_single_page_layout() -> LayoutStructure {
             content: BoxContent::Text(vec!["Education".to_string().into()]),
             style: StyleDeclaration::default(),
             element_type: Some(ElementType::Heading2),
+            list_ordinal: None,
         },
         // Degree
         LayoutBox {
@@ -77,6 +82,7 @@ fn create_single_page_layout() -> LayoutStructure {
             ]),
             style: StyleDeclaration::default(),
             element_type: Some(ElementType::Paragraph),
+            list_ordinal: None,
         },
     ];
 
@@ -105,6 +111,7 @@ fn create_multi_page_layout() -> LayoutStructure {
                 content: BoxContent::Text(vec![format!("Page {} Content", page_num).into()]),
                 style: StyleDeclaration::default(),
                 element_type: Some(ElementType::Heading1),
+                list_ordinal: None,
             },
             LayoutBox {
                 x: 72.0,
@@ -121,6 +128,7 @@ fn create_multi_page_layout() -> LayoutStructure {
                 ]),
                 style: StyleDeclaration::default(),
                 element_type: Some(ElementType::Paragraph),
+                list_ordinal: None,
             },
         ];
 
@@ -183,6 +191,7 @@ fn bench_varying_page_counts(c: &mut Criterion) {
                         content: BoxContent::Text(vec![format!("Page {}", page_num).into()]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Heading1),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 72.0,
@@ -192,6 +201,7 @@ fn bench_varying_page_counts(c: &mut Criterion) {
                         content: BoxContent::Text(vec!["Sample content.".repeat(20).into()]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                 ],
             });
@@ -245,7 +255,9 @@ fn bench_content_stream_generation(c: &mut Criterion) {
     let page = &layout.pages[0];
 
     c.bench_function("content_stream_single_page", |b| {
-        b.iter(|| render_page_to_content(black_box(page), black_box(792.0)).unwrap());
+        b.iter(|| {
+            render_page_to_content(black_box(page), black_box(612.0), black_box(792.0)).unwrap()
+        });
     });
 }
 
@@ -260,12 +272,22 @@ fn bench_ats_validation(c: &mut Criterion) {
         title: Some("Software Engineer".to_string()),
         email: Some("john@example.com".to_string()),
         phone: Some("+1-555-1234".to_string()),
+        phone_e164: None,
         location: None,
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: LayoutType::SingleColumn,
         estimated_pages: 1,
         component_count: 6,
         has_contact_info: true,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         has_clear_sections: true,
         font_complexity: FontComplexity::Simple,
     };
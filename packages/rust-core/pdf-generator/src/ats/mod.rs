@@ -5,7 +5,7 @@
 //! after generation, providing privacy-first validation.
 //!
 //! # Module Organization
-//! - `report` - Report types (ATSValidationReport, FieldsPlaced, ATSWeights)
+//! - `report` - Report types (ATSValidationReport, ATSRuleResult, FieldsPlaced, ATSWeights)
 //! - `scorer` - Scoring logic for ATS compatibility
 //! - `rules` - Validation rules and field detection
 
@@ -14,5 +14,5 @@ pub mod rules;
 pub mod scorer;
 
 // Re-export public API
-pub use report::{ATSValidationReport, ATSWeights, FieldsPlaced};
+pub use report::{ATSRuleResult, ATSValidationReport, ATSWeights, FieldsPlaced};
 pub use rules::validate_ats_compatibility;
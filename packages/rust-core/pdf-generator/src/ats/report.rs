@@ -29,6 +29,49 @@ pub struct ATSValidationReport {
 
     /// Critical errors that prevent ATS parsing
     pub errors: Vec<String>,
+
+    /// Individual rule outcomes behind the score, in display order
+    #[serde(default)]
+    pub rules: Vec<ATSRuleResult>,
+
+    /// Decorative characters in the rendered text that ATS may drop or garble
+    /// (emoji, symbol bullets), in order of first appearance
+    #[serde(default)]
+    pub special_characters: Vec<char>,
+}
+
+/// Outcome of a single ATS rule
+///
+/// Rule identifiers are grouped by a dotted category prefix
+/// (`contact.*`, `sections.*`, `fonts.*`, `layout.*`, `text.*`) so callers can render
+/// a grouped checklist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(target_arch = "wasm32", derive(tsify::Tsify))]
+#[cfg_attr(target_arch = "wasm32", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct ATSRuleResult {
+    /// Stable rule identifier (e.g., "contact.email")
+    pub rule: String,
+
+    /// Whether the CV satisfies the rule
+    pub passed: bool,
+
+    /// Contribution to the overall score when passed (0.0 = informational)
+    pub weight: f64,
+
+    /// Human-readable outcome
+    pub message: String,
+}
+
+impl ATSRuleResult {
+    /// Create a rule result, picking the message that matches the outcome
+    pub fn new(rule: &str, passed: bool, weight: f64, pass_msg: &str, fail_msg: &str) -> Self {
+        Self {
+            rule: rule.to_string(),
+            passed,
+            weight,
+            message: if passed { pass_msg } else { fail_msg }.to_string(),
+        }
+    }
 }
 
 /// Fields that were placed in the PDF during generation
@@ -61,18 +104,21 @@ pub struct FieldsPlaced {
 /// Configurable weights for ATS scoring components
 ///
 /// Allows customization of how different resume elements contribute to the overall
-/// ATS compatibility score. Weights should sum to 1.0 for intuitive percentage-based scoring.
+/// ATS compatibility score. Weights are relative: they are normalized to sum to 1.0
+/// before scoring (see [`ATSWeights::normalized`]), so any scale works, e.g. 0-10
+/// importance ratings. A weight of 0 disables the rule entirely.
 ///
 /// # Default Weights (Industry Standard)
 /// - Name: 10% - Critical for ATS identification
 /// - Email: 10% - Critical for contact
 /// - Phone: 5% - Recommended contact info
-/// - Experience: 30% - Primary content (6% per entry, max 5)
+/// - Experience: 25% - Primary content
 /// - Education: 20% - Important background (10% per entry, max 2)
 /// - Skills: 10% - Keyword matching
 /// - Text Embedded: 5% - Technical requirement
 /// - Fonts Embedded: 5% - Technical requirement
 /// - Structure: 5% - Accessibility and parsing
+/// - Special Characters: 5% - No emoji or decorative symbols
 ///
 /// # Examples
 ///
@@ -88,6 +134,12 @@ pub struct FieldsPlaced {
 ///     education: 0.10,   // 10%
 ///     ..Default::default()
 /// };
+///
+/// // Don't require a phone number
+/// let no_phone = ATSWeights {
+///     phone: 0.0,
+///     ..Default::default()
+/// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(target_arch = "wasm32", derive(tsify::Tsify))]
@@ -102,8 +154,7 @@ pub struct ATSWeights {
     /// Weight for phone presence (default: 0.05 = 5%)
     pub phone: f64,
 
-    /// Weight for experience entries (default: 0.30 = 30%)
-    /// Applied as: (experience_count * 0.06).min(0.30)
+    /// Weight for experience entries (default: 0.25 = 25%)
     pub experience: f64,
 
     /// Weight for education entries (default: 0.20 = 20%)
@@ -121,6 +172,16 @@ pub struct ATSWeights {
 
     /// Weight for proper document structure (default: 0.05 = 5%)
     pub structure: f64,
+
+    /// Weight for text free of emoji and decorative symbols (default: 0.05 = 5%)
+    #[serde(default = "default_special_characters_weight")]
+    pub special_characters: f64,
+}
+
+/// Default weight for the special characters rule, also used when a
+/// serialized `ATSWeights` predates it
+fn default_special_characters_weight() -> f64 {
+    0.05
 }
 
 impl ATSValidationReport {
@@ -134,6 +195,8 @@ impl ATSValidationReport {
             fields_placed: FieldsPlaced::new(),
             warnings: Vec::new(),
             errors: Vec::new(),
+            rules: Vec::new(),
+            special_characters: Vec::new(),
         }
     }
 
@@ -141,6 +204,11 @@ impl ATSValidationReport {
     pub fn passes(&self) -> bool {
         self.score >= 0.9
     }
+
+    /// Look up a rule outcome by identifier
+    pub fn rule(&self, rule: &str) -> Option<&ATSRuleResult> {
+        self.rules.iter().find(|r| r.rule == rule)
+    }
 }
 
 impl Default for ATSValidationReport {
@@ -176,12 +244,13 @@ impl Default for ATSWeights {
             name: 0.10,
             email: 0.10,
             phone: 0.05,
-            experience: 0.30,
+            experience: 0.25,
             education: 0.20,
             skills: 0.10,
             text_embedded: 0.05,
             fonts_embedded: 0.05,
             structure: 0.05,
+            special_characters: default_special_characters_weight(),
         }
     }
 }
@@ -216,10 +285,82 @@ impl ATSWeights {
             + self.skills
             + self.text_embedded
             + self.fonts_embedded
-            + self.structure;
+            + self.structure
+            + self.special_characters;
 
         (sum - 1.0).abs() < 0.01
     }
+
+    /// Scale weights so they sum to 1.0
+    ///
+    /// Negative weights are treated as 0. Falls back to the default weights
+    /// when every weight is 0, since no rule could then contribute to the score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pdf_generator::ATSWeights;
+    ///
+    /// // Importance ratings on a 0-10 scale
+    /// let weights = ATSWeights {
+    ///     name: 10.0,
+    ///     email: 10.0,
+    ///     phone: 0.0,
+    ///     experience: 10.0,
+    ///     education: 5.0,
+    ///     skills: 5.0,
+    ///     text_embedded: 5.0,
+    ///     fonts_embedded: 2.5,
+    ///     structure: 2.5,
+    ///     special_characters: 0.0,
+    /// }
+    /// .normalized();
+    ///
+    /// assert!(weights.is_valid());
+    /// assert_eq!(weights.name, 0.2);
+    /// assert_eq!(weights.phone, 0.0);
+    /// ```
+    pub fn normalized(&self) -> Self {
+        let clamped = Self {
+            name: self.name.max(0.0),
+            email: self.email.max(0.0),
+            phone: self.phone.max(0.0),
+            experience: self.experience.max(0.0),
+            education: self.education.max(0.0),
+            skills: self.skills.max(0.0),
+            text_embedded: self.text_embedded.max(0.0),
+            fonts_embedded: self.fonts_embedded.max(0.0),
+            structure: self.structure.max(0.0),
+            special_characters: self.special_characters.max(0.0),
+        };
+        let sum = clamped.name
+            + clamped.email
+            + clamped.phone
+            + clamped.experience
+            + clamped.education
+            + clamped.skills
+            + clamped.text_embedded
+            + clamped.fonts_embedded
+            + clamped.structure
+            + clamped.special_characters;
+
+        if sum <= 0.0 {
+            return Self::default();
+        }
+
+        Self {
+            name: clamped.name / sum,
+            email: clamped.email / sum,
+            phone: clamped.phone / sum,
+            experience: clamped.experience / sum,
+            education: clamped.education / sum,
+            skills: clamped.skills / sum,
+            text_embedded: clamped.text_embedded / sum,
+            fonts_embedded: clamped.fonts_embedded / sum,
+            structure: clamped.structure / sum,
+            special_characters: clamped.special_characters / sum,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -244,6 +385,7 @@ mod tests {
             text_embedded: 0.05,
             fonts_embedded: 0.03,
             structure: 0.02,
+            special_characters: 0.0,
         };
         assert!(weights.is_valid());
     }
@@ -260,7 +402,41 @@ mod tests {
             text_embedded: 0.05,
             fonts_embedded: 0.05,
             structure: 0.05,
+            special_characters: 0.0,
         };
         assert!(!weights.is_valid());
     }
+
+    #[test]
+    fn test_ats_weights_normalized() {
+        let weights = ATSWeights {
+            name: 0.50,
+            email: 0.50,
+            phone: -1.0, // Negative treated as disabled
+            experience: 0.0,
+            education: 0.0,
+            skills: 0.0,
+            text_embedded: 0.0,
+            fonts_embedded: 0.0,
+            structure: 0.0,
+            special_characters: 0.0,
+        }
+        .normalized();
+        assert!(weights.is_valid());
+        assert_eq!(weights.phone, 0.0);
+
+        let all_zero = ATSWeights {
+            name: 0.0,
+            email: 0.0,
+            phone: 0.0,
+            experience: 0.0,
+            education: 0.0,
+            skills: 0.0,
+            text_embedded: 0.0,
+            fonts_embedded: 0.0,
+            structure: 0.0,
+            special_characters: 0.0,
+        };
+        assert_eq!(all_zero.normalized().name, ATSWeights::default().name);
+    }
 }
@@ -2,9 +2,10 @@
 //!
 //! Field detection and validation rules for ATS compatibility checking.
 
-use super::report::{ATSValidationReport, FieldsPlaced};
+use super::report::{ATSRuleResult, ATSValidationReport, FieldsPlaced};
+use crate::layout_analyzer::extract_rendered_text;
 use crate::layout_renderer::{BoxContent, ElementType, LayoutBox, LayoutStructure};
-use cv_domain::CVMetadata;
+use cv_domain::{CVMetadata, LayoutType};
 
 /// Validate ATS compatibility during PDF generation
 ///
@@ -43,21 +44,72 @@ pub fn validate_ats_compatibility(
     // Check document structure
     report.proper_structure = check_document_structure(layout);
 
-    // Calculate overall ATS score with configurable weights
-    report.score = super::scorer::calculate_ats_score(
+    // Check the rendered text for decorative characters ATS may drop or garble
+    report.special_characters = find_special_characters(&extract_rendered_text(layout));
+
+    // Evaluate each rule with configurable weights; the score is their sum
+    report.rules = super::scorer::evaluate_ats_rules(
         &report.fields_placed,
         report.text_embedded,
         report.fonts_embedded,
         report.proper_structure,
+        &report.special_characters,
         scoring_weights,
     );
+    report.score = super::scorer::score_from_rules(&report.rules);
+
+    // Informational (unweighted) layout check
+    report.rules.push(check_column_layout(metadata));
 
     // Generate warnings and errors
     generate_warnings_and_errors(&mut report, metadata);
 
+    // Fields present in the layout but hidden by styling
+    report.warnings.extend(check_rendered_fields(
+        layout,
+        metadata,
+        &report.fields_placed,
+    ));
+
     report
 }
 
+/// Warn about expected fields that are in the layout but not visible in the PDF
+///
+/// Compares the rendered text (see [`extract_rendered_text`]) against the
+/// metadata, catching fields hidden by styling such as white-on-white text
+/// or content clipped off the page. Fields missing from the layout entirely
+/// are already reported as errors.
+pub fn check_rendered_fields(
+    layout: &LayoutStructure,
+    metadata: &CVMetadata,
+    fields: &FieldsPlaced,
+) -> Vec<String> {
+    let rendered_text = extract_rendered_text(layout);
+    let rendered_lower = rendered_text.to_lowercase();
+    let mut warnings = Vec::new();
+
+    if let Some(ref name) = metadata.name {
+        if fields.name && !rendered_lower.contains(&name.to_lowercase()) {
+            warnings.push(
+                "Name is in the layout but not visible in the rendered PDF (hidden or clipped)"
+                    .to_string(),
+            );
+        }
+    }
+
+    if let Some(ref email) = metadata.email {
+        if fields.email && !rendered_text.contains(email.as_str()) {
+            warnings.push(
+                "Email is in the layout but not visible in the rendered PDF (hidden or clipped)"
+                    .to_string(),
+            );
+        }
+    }
+
+    warnings
+}
+
 /// Analyze which fields from CVMetadata are actually placed in the layout
 pub fn analyze_fields_placed(layout: &LayoutStructure, metadata: &CVMetadata) -> FieldsPlaced {
     let mut fields = FieldsPlaced::new();
@@ -95,6 +147,14 @@ pub fn analyze_fields_placed(layout: &LayoutStructure, metadata: &CVMetadata) ->
         }
     }
 
+    // <address> marks contact details explicitly; accept them even when the
+    // text escapes the email/phone patterns used for metadata extraction
+    let contact_text = collect_element_text(layout, ElementType::ContactBlock);
+    if !contact_text.is_empty() {
+        fields.email |= looks_like_email(&contact_text);
+        fields.phone |= looks_like_phone(&contact_text);
+    }
+
     // Check for section headings (h2, h3 elements indicate experience, education, etc.)
     fields.has_section_headings = check_has_headings(layout);
 
@@ -144,6 +204,43 @@ fn collect_text_from_box(layout_box: &LayoutBox, all_text: &mut String) {
     }
 }
 
+/// Collect text from all boxes of one element type, including their children
+fn collect_element_text(layout: &LayoutStructure, element_type: ElementType) -> String {
+    fn visit(layout_box: &LayoutBox, element_type: ElementType, text: &mut String) {
+        if layout_box.element_type == Some(element_type) {
+            collect_text_from_box(layout_box, text);
+        } else if let BoxContent::Container(children) = &layout_box.content {
+            for child in children {
+                visit(child, element_type, text);
+            }
+        }
+    }
+
+    let mut text = String::new();
+    for page in &layout.pages {
+        for layout_box in &page.boxes {
+            visit(layout_box, element_type, &mut text);
+        }
+    }
+    text
+}
+
+/// Minimum digits in a run of phone-number characters
+const MIN_PHONE_DIGITS: usize = 7;
+
+/// Loose email check for contact blocks: an `@` or an obfuscated "[at]"/"(at)"
+fn looks_like_email(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    lower.contains('@') || lower.contains("[at]") || lower.contains("(at)")
+}
+
+/// Loose phone check for contact blocks: enough digits in a run of digits,
+/// spaces and phone punctuation
+fn looks_like_phone(text: &str) -> bool {
+    text.split(|c: char| !(c.is_ascii_digit() || " +-./()".contains(c)))
+        .any(|run| run.chars().filter(char::is_ascii_digit).count() >= MIN_PHONE_DIGITS)
+}
+
 /// Check if layout uses text (not images)
 ///
 /// For our PDF generator, we always use text, so this always returns true.
@@ -153,6 +250,63 @@ fn check_text_embedded(_layout: &LayoutStructure) -> bool {
     true
 }
 
+/// Check whether the layout reads in a single column
+///
+/// Multi-column and visual layouts are a known parsing risk for older ATS,
+/// which may interleave text from side-by-side columns. Reported for the
+/// checklist only; it does not contribute to the score.
+fn check_column_layout(metadata: &CVMetadata) -> ATSRuleResult {
+    let single_column = matches!(
+        metadata.layout_type,
+        LayoutType::SingleColumn | LayoutType::Academic
+    );
+    ATSRuleResult::new(
+        "layout.columns",
+        single_column,
+        0.0,
+        "Single-column layout",
+        "Multi-column layout may be read out of order by some ATS",
+    )
+}
+
+/// Characters outside the ATS-safe set, in order of first appearance
+///
+/// Letters, digits and marks in any script, whitespace, ASCII, Latin-1
+/// punctuation and common typographic punctuation (dashes, curly quotes,
+/// `•` bullets, ellipsis, `€`, `™`) are safe. Emoji, symbol bullets such as
+/// `★` or `▪`, and other pictographs are reported. Emoji variation selectors
+/// and joiners are skipped, since the emoji they modify is reported already.
+pub fn find_special_characters(text: &str) -> Vec<char> {
+    let mut found = Vec::new();
+    for c in text.chars() {
+        if is_ats_safe_char(c) || matches!(c, '\u{FE0E}' | '\u{FE0F}' | '\u{200D}') {
+            continue;
+        }
+        if !found.contains(&c) {
+            found.push(c);
+        }
+    }
+    found
+}
+
+fn is_ats_safe_char(c: char) -> bool {
+    c.is_alphanumeric()
+        || c.is_whitespace()
+        || matches!(c,
+            ' '..='~'
+            | '\u{00A1}'..='\u{00FF}'
+            | '\u{0300}'..='\u{036F}'
+            | '\u{2010}'..='\u{2015}'
+            | '\u{2018}'..='\u{201F}'
+            | '\u{2020}'..='\u{2022}'
+            | '\u{2026}'
+            | '\u{2030}'
+            | '\u{2032}'..='\u{2033}'
+            | '\u{2039}'..='\u{203A}'
+            | '\u{20AC}'
+            | '\u{2122}')
+}
+
 /// Check if document has proper structure (headings for sections)
 fn check_document_structure(layout: &LayoutStructure) -> bool {
     // A properly structured document should have at least 2 heading elements (h2/h3)
@@ -335,38 +489,54 @@ pub fn generate_warnings_and_errors(report: &mut ATSValidationReport, metadata:
     }
 
     // Warnings (reduce ATS compatibility but not critical)
-    if !report.fonts_embedded {
+    // Rules disabled through a zero weight don't produce warnings
+    let enabled = |report: &ATSValidationReport, rule: &str| report.rule(rule).is_some();
+
+    if !report.fonts_embedded && enabled(report, "fonts.embedded") {
         report
             .warnings
             .push("Fonts not fully embedded - may cause parsing issues in some ATS".to_string());
     }
 
-    if !report.proper_structure {
+    if !report.proper_structure && enabled(report, "sections.headings") {
         report.warnings.push(
             "No clear section headings detected - ATS may struggle to categorize information"
                 .to_string(),
         );
     }
 
-    if !report.fields_placed.phone {
+    if !report.fields_placed.phone && enabled(report, "contact.phone") {
         report.warnings.push(
             "Phone number not found in layout - consider including contact phone".to_string(),
         );
     }
 
-    if report.fields_placed.experience_count == 0 {
+    if report.fields_placed.experience_count == 0 && enabled(report, "sections.experience") {
         report
             .warnings
             .push("No experience entries detected - ATS may not find work history".to_string());
     }
 
-    if report.fields_placed.education_count == 0 {
+    if report.fields_placed.education_count == 0 && enabled(report, "sections.education") {
         report.warnings.push(
             "No education entries detected - ATS may not find educational background".to_string(),
         );
     }
 
-    if report.fields_placed.skills_count == 0 {
+    if !report.special_characters.is_empty() && enabled(report, "text.special_characters") {
+        let listed = report
+            .special_characters
+            .iter()
+            .map(char::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+        report.warnings.push(format!(
+            "Replace decorative symbols ({}) with plain text labels such as \"Email:\" or \"-\"",
+            listed
+        ));
+    }
+
+    if report.fields_placed.skills_count == 0 && enabled(report, "sections.skills") {
         report
             .warnings
             .push("No skills detected - ATS keyword matching may be limited".to_string());
@@ -396,6 +566,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Jane Smith")]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Heading1),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -405,6 +576,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("jane@example.com")]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -414,6 +586,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("+1-555-123-4567")]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -423,6 +596,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Experience")]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Heading2),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -434,6 +608,7 @@ mod tests {
                         )]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -445,6 +620,7 @@ mod tests {
                         )]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -456,6 +632,7 @@ mod tests {
                         )]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -467,6 +644,7 @@ mod tests {
                         )]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -478,6 +656,7 @@ mod tests {
                         )]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -487,6 +666,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Education")]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Heading2),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -498,6 +678,7 @@ mod tests {
                         )]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -507,6 +688,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Skills")]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Heading2),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -518,6 +700,7 @@ mod tests {
                         )]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                 ],
             }],
@@ -528,12 +711,22 @@ mod tests {
             title: Some("Software Engineer".to_string()),
             email: Some("jane@example.com".to_string()),
             phone: Some("+1-555-123-4567".to_string()),
+            phone_e164: None,
             location: None,
             website: None,
+            skills: Vec::new(),
+            experience: Vec::new(),
+            certifications: Vec::new(),
             layout_type: LayoutType::SingleColumn,
             estimated_pages: 1,
             component_count: 10,
             has_contact_info: true,
+            contact_in_sidebar: false,
+            chronological_order: None,
+            duplicate_sections: Vec::new(),
+            word_count: 0,
+            char_count: 0,
+            reading_time_seconds: 0,
             has_clear_sections: true,
             font_complexity: FontComplexity::Simple,
         };
@@ -564,6 +757,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("John Doe")]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Heading1),
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -573,6 +767,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("john@example.com")]),
                         style: StyleDeclaration::default(),
                         element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
                     },
                 ],
             }],
@@ -583,12 +778,22 @@ mod tests {
             title: Some("Software Engineer".to_string()),
             email: Some("john@example.com".to_string()),
             phone: None,
+            phone_e164: None,
             location: None,
             website: None,
+            skills: Vec::new(),
+            experience: Vec::new(),
+            certifications: Vec::new(),
             layout_type: LayoutType::SingleColumn,
             estimated_pages: 1,
             component_count: 2,
             has_contact_info: true,
+            contact_in_sidebar: false,
+            chronological_order: None,
+            duplicate_sections: Vec::new(),
+            word_count: 0,
+            char_count: 0,
+            reading_time_seconds: 0,
             has_clear_sections: false,
             font_complexity: FontComplexity::Simple,
         };
@@ -605,6 +810,7 @@ mod tests {
             text_embedded: 0.05,
             fonts_embedded: 0.03,
             structure: 0.02,
+            special_characters: 0.0,
         };
 
         let report = validate_ats_compatibility(&layout, &metadata, true, Some(&custom_weights));
@@ -617,4 +823,308 @@ mod tests {
         // Score should reflect custom weights: name (0.25) + email (0.25) + text (0.05) + fonts (0.03) = 0.58
         assert!((report.score - 0.58).abs() < 0.01);
     }
+
+    #[test]
+    fn test_validate_ats_reports_per_rule_results() {
+        let layout = LayoutStructure {
+            page_width: 612.0,
+            page_height: 792.0,
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![
+                    LayoutBox {
+                        x: 0.0,
+                        y: 0.0,
+                        width: 100.0,
+                        height: 20.0,
+                        content: BoxContent::Text(vec![TextLine::from("John Doe")]),
+                        style: StyleDeclaration::default(),
+                        element_type: Some(ElementType::Heading1),
+                        list_ordinal: None,
+                    },
+                    LayoutBox {
+                        x: 0.0,
+                        y: 20.0,
+                        width: 100.0,
+                        height: 15.0,
+                        content: BoxContent::Text(vec![TextLine::from("john@example.com")]),
+                        style: StyleDeclaration::default(),
+                        element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
+                    },
+                ],
+            }],
+        };
+
+        let metadata = CVMetadata {
+            name: Some("John Doe".to_string()),
+            title: None,
+            email: Some("john@example.com".to_string()),
+            phone: None,
+            phone_e164: None,
+            location: None,
+            website: None,
+            skills: Vec::new(),
+            experience: Vec::new(),
+            certifications: Vec::new(),
+            layout_type: LayoutType::TwoColumn,
+            estimated_pages: 1,
+            component_count: 2,
+            has_contact_info: true,
+            contact_in_sidebar: false,
+            chronological_order: None,
+            duplicate_sections: Vec::new(),
+            word_count: 0,
+            char_count: 0,
+            reading_time_seconds: 0,
+            has_clear_sections: false,
+            font_complexity: FontComplexity::Simple,
+        };
+
+        let report = validate_ats_compatibility(&layout, &metadata, true, None);
+
+        // Contact, sections, fonts, and layout rules are reported separately
+        assert!(report.rule("contact.email").unwrap().passed);
+        assert!(!report.rule("contact.phone").unwrap().passed);
+        assert!(!report.rule("sections.headings").unwrap().passed);
+        assert!(report.rule("fonts.embedded").unwrap().passed);
+        let columns = report.rule("layout.columns").unwrap();
+        assert!(!columns.passed);
+        assert_eq!(columns.weight, 0.0);
+        assert!(columns.message.contains("Multi-column"));
+
+        let mut ids: Vec<&str> = report.rules.iter().map(|r| r.rule.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), report.rules.len(), "Rule ids should be distinct");
+
+        // Score is the sum of the passed rule weights
+        let passed_weight: f64 = report
+            .rules
+            .iter()
+            .filter(|r| r.passed)
+            .map(|r| r.weight)
+            .sum();
+        assert!((report.score - passed_weight).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_validate_ats_warns_when_field_is_not_rendered() {
+        let mut white_text = StyleDeclaration::default();
+        white_text.text.color = Some(layout_types::Color::WHITE);
+
+        let layout = LayoutStructure {
+            page_width: 612.0,
+            page_height: 792.0,
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![
+                    LayoutBox {
+                        x: 0.0,
+                        y: 0.0,
+                        width: 100.0,
+                        height: 20.0,
+                        content: BoxContent::Text(vec![TextLine::from("John Doe")]),
+                        style: StyleDeclaration::default(),
+                        element_type: Some(ElementType::Heading1),
+                        list_ordinal: None,
+                    },
+                    LayoutBox {
+                        x: 0.0,
+                        y: 20.0,
+                        width: 100.0,
+                        height: 15.0,
+                        content: BoxContent::Text(vec![TextLine::from("john@example.com")]),
+                        style: white_text,
+                        element_type: Some(ElementType::Paragraph),
+                        list_ordinal: None,
+                    },
+                ],
+            }],
+        };
+
+        let metadata = CVMetadata {
+            name: Some("John Doe".to_string()),
+            title: None,
+            email: Some("john@example.com".to_string()),
+            phone: None,
+            phone_e164: None,
+            location: None,
+            website: None,
+            skills: Vec::new(),
+            experience: Vec::new(),
+            certifications: Vec::new(),
+            layout_type: LayoutType::SingleColumn,
+            estimated_pages: 1,
+            component_count: 2,
+            has_contact_info: true,
+            contact_in_sidebar: false,
+            chronological_order: None,
+            duplicate_sections: Vec::new(),
+            word_count: 0,
+            char_count: 0,
+            reading_time_seconds: 0,
+            has_clear_sections: false,
+            font_complexity: FontComplexity::Simple,
+        };
+
+        let report = validate_ats_compatibility(&layout, &metadata, true, None);
+
+        // Placed in the layout, so no error, but hidden from the rendered PDF
+        assert!(report.fields_placed.email);
+        assert!(report
+            .warnings
+            .iter()
+            .any(|w| w.starts_with("Email is in the layout but not visible")));
+        assert!(!report.warnings.iter().any(|w| w.starts_with("Name is")));
+    }
+
+    #[test]
+    fn test_address_block_counts_as_contact_info() {
+        let contact_box = |element_type: ElementType| LayoutBox {
+            x: 0.0,
+            y: 20.0,
+            width: 300.0,
+            height: 30.0,
+            content: BoxContent::Text(vec![
+                TextLine::from("jane [at] example [dot] com"),
+                TextLine::from("Tel. 030 / 123 456 78"),
+            ]),
+            style: StyleDeclaration::default(),
+            element_type: Some(element_type),
+            list_ordinal: None,
+        };
+        let layout_with = |element_type: ElementType| LayoutStructure {
+            page_width: 612.0,
+            page_height: 792.0,
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![contact_box(element_type)],
+            }],
+        };
+
+        // Neither value matches the metadata extractor's email/phone patterns
+        let metadata = CVMetadata {
+            name: None,
+            title: None,
+            email: None,
+            phone: None,
+            phone_e164: None,
+            location: None,
+            website: None,
+            skills: Vec::new(),
+            experience: Vec::new(),
+            certifications: Vec::new(),
+            layout_type: LayoutType::SingleColumn,
+            estimated_pages: 1,
+            component_count: 1,
+            has_contact_info: false,
+            contact_in_sidebar: false,
+            chronological_order: None,
+            duplicate_sections: Vec::new(),
+            word_count: 0,
+            char_count: 0,
+            reading_time_seconds: 0,
+            has_clear_sections: false,
+            font_complexity: FontComplexity::Simple,
+        };
+
+        let fields = analyze_fields_placed(&layout_with(ElementType::ContactBlock), &metadata);
+        assert!(fields.email);
+        assert!(fields.phone);
+
+        // The same text outside an <address> is not trusted
+        let fields = analyze_fields_placed(&layout_with(ElementType::Paragraph), &metadata);
+        assert!(!fields.email);
+        assert!(!fields.phone);
+    }
+
+    #[test]
+    fn test_validate_ats_zero_phone_weight_scores_phoneless_cv_full() {
+        let text_box = |y: f64, text: &str, element_type: ElementType| LayoutBox {
+            x: 0.0,
+            y,
+            width: 300.0,
+            height: 15.0,
+            content: BoxContent::Text(vec![TextLine::from(text)]),
+            style: StyleDeclaration::default(),
+            element_type: Some(element_type),
+            list_ordinal: None,
+        };
+        let layout = LayoutStructure {
+            page_width: 612.0,
+            page_height: 792.0,
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![
+                    text_box(0.0, "Jane Smith", ElementType::Heading1),
+                    text_box(20.0, "jane@example.com", ElementType::Paragraph),
+                    text_box(40.0, "Experience", ElementType::Heading2),
+                    text_box(
+                        60.0,
+                        "Software Engineer - January 2018 - April 2020",
+                        ElementType::Paragraph,
+                    ),
+                    text_box(80.0, "Education", ElementType::Heading2),
+                    text_box(100.0, "Bachelor of Science", ElementType::Paragraph),
+                    text_box(120.0, "Skills", ElementType::Heading2),
+                    text_box(140.0, "Rust, TypeScript", ElementType::Paragraph),
+                ],
+            }],
+        };
+
+        let metadata = CVMetadata {
+            name: Some("Jane Smith".to_string()),
+            title: None,
+            email: Some("jane@example.com".to_string()),
+            phone: None,
+            phone_e164: None,
+            location: None,
+            website: None,
+            skills: Vec::new(),
+            experience: Vec::new(),
+            certifications: Vec::new(),
+            layout_type: LayoutType::SingleColumn,
+            estimated_pages: 1,
+            component_count: 8,
+            has_contact_info: true,
+            contact_in_sidebar: false,
+            chronological_order: None,
+            duplicate_sections: Vec::new(),
+            word_count: 0,
+            char_count: 0,
+            reading_time_seconds: 0,
+            has_clear_sections: true,
+            font_complexity: FontComplexity::Simple,
+        };
+
+        // Default weights penalize the missing phone
+        let default_report = validate_ats_compatibility(&layout, &metadata, true, None);
+        assert!(default_report.score < 1.0);
+
+        // Zeroing the phone weight disables the rule entirely
+        use crate::ats::report::ATSWeights;
+        let weights = ATSWeights {
+            phone: 0.0,
+            ..Default::default()
+        };
+        let report = validate_ats_compatibility(&layout, &metadata, true, Some(&weights));
+
+        assert!(
+            (report.score - 1.0).abs() < 1e-9,
+            "Expected full marks, got {}",
+            report.score
+        );
+        assert!(report.rule("contact.phone").is_none());
+        assert!(!report.warnings.iter().any(|w| w.contains("Phone")));
+    }
+
+    #[test]
+    fn test_find_special_characters_safe_set() {
+        assert!(find_special_characters(
+            "José Müller – “Lead” engineer… • Zürich € 5™ Straße Ωmega Привет"
+        )
+        .is_empty());
+        assert_eq!(find_special_characters("★ Skills ▪ Rust ★"), vec!['★', '▪']);
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! Calculate ATS compatibility scores from validation results.
 
-use super::report::{ATSWeights, FieldsPlaced};
+use super::report::{ATSRuleResult, ATSWeights, FieldsPlaced};
 
 /// Calculate ATS compatibility score using configurable weights
 ///
@@ -10,7 +10,8 @@ use super::report::{ATSWeights, FieldsPlaced};
 /// to specific job descriptions. We can only validate that fields are extractable.
 ///
 /// # Scoring Method
-/// - Each component is weighted according to the provided `ATSWeights`
+/// - Each component is weighted according to the provided `ATSWeights`,
+///   normalized to sum to 1.0; components with a weight of 0 are skipped
 /// - Experience and education are scaled: (count * per_entry_weight).min(max_weight)
 /// - All other fields contribute their full weight if present
 /// - Final score is sum of all weighted components (0.0 to 1.0)
@@ -20,6 +21,7 @@ use super::report::{ATSWeights, FieldsPlaced};
 /// * `text_embedded` - Whether text is embedded (not images)
 /// * `fonts_embedded` - Whether fonts are properly embedded
 /// * `proper_structure` - Whether document has proper structure
+/// * `special_characters` - Decorative characters found in the rendered text
 /// * `weights` - Configurable weights for each scoring component
 ///
 /// # Returns
@@ -29,60 +31,138 @@ pub fn calculate_ats_score(
     text_embedded: bool,
     fonts_embedded: bool,
     proper_structure: bool,
+    special_characters: &[char],
     weights: &ATSWeights,
 ) -> f64 {
-    let mut score = 0.0;
-
-    // Name: weighted (critical for ATS)
-    if fields.name {
-        score += weights.name;
-    }
-
-    // Email: weighted (critical for contact)
-    if fields.email {
-        score += weights.email;
-    }
-
-    // Phone: weighted (recommended)
-    if fields.phone {
-        score += weights.phone;
-    }
-
-    // Experience: binary check (ATS compatibility = can extract ANY experience)
-    // ATS compatibility means "can the system parse experience entries", not "how many entries"
-    // A CV with 1 experience entry that's parseable should score 100% on experience extraction
-    if fields.experience_count > 0 {
-        score += weights.experience;
-    }
-
-    // Education: binary check (ATS compatibility = can extract ANY education)
-    // ATS compatibility means "can the system parse education entries", not "how many degrees"
-    // A CV with 1 degree that's parseable should score 100% on education extraction
-    if fields.education_count > 0 {
-        score += weights.education;
-    }
-
-    // Skills: weighted (presence check)
-    if fields.skills_count > 0 {
-        score += weights.skills;
-    }
+    score_from_rules(&evaluate_ats_rules(
+        fields,
+        text_embedded,
+        fonts_embedded,
+        proper_structure,
+        special_characters,
+        weights,
+    ))
+}
 
-    // Text embedded: weighted (not flattened images)
-    if text_embedded {
-        score += weights.text_embedded;
-    }
+/// Sum the weights of all passed rules
+pub fn score_from_rules(rules: &[ATSRuleResult]) -> f64 {
+    rules
+        .iter()
+        .filter(|rule| rule.passed)
+        .fold(0.0, |score, rule| score + rule.weight)
+}
 
-    // Fonts embedded: weighted (properly embedded)
-    if fonts_embedded {
-        score += weights.fonts_embedded;
-    }
+/// Evaluate each weighted scoring component as an individual rule
+///
+/// Takes the same inputs as [`calculate_ats_score`]; summing the weights of
+/// the passed rules yields the score. Weights are normalized first, and rules
+/// with a weight of 0 are disabled and omitted.
+pub fn evaluate_ats_rules(
+    fields: &FieldsPlaced,
+    text_embedded: bool,
+    fonts_embedded: bool,
+    proper_structure: bool,
+    special_characters: &[char],
+    weights: &ATSWeights,
+) -> Vec<ATSRuleResult> {
+    let weights = weights.normalized();
+    let listed_special_characters = special_characters
+        .iter()
+        .map(char::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
 
-    // Proper structure: weighted (headings for sections)
-    if proper_structure {
-        score += weights.structure;
-    }
+    let rules = vec![
+        // Name: weighted (critical for ATS)
+        ATSRuleResult::new(
+            "contact.name",
+            fields.name,
+            weights.name,
+            "Name found",
+            "Name not found",
+        ),
+        // Email: weighted (critical for contact)
+        ATSRuleResult::new(
+            "contact.email",
+            fields.email,
+            weights.email,
+            "Email found",
+            "Email not found",
+        ),
+        // Phone: weighted (recommended)
+        ATSRuleResult::new(
+            "contact.phone",
+            fields.phone,
+            weights.phone,
+            "Phone number found",
+            "Phone number not found",
+        ),
+        // Experience: binary check (ATS compatibility = can extract ANY experience)
+        // ATS compatibility means "can the system parse experience entries", not "how many entries"
+        // A CV with 1 experience entry that's parseable should score 100% on experience extraction
+        ATSRuleResult::new(
+            "sections.experience",
+            fields.experience_count > 0,
+            weights.experience,
+            "Experience entries detected",
+            "No experience entries detected",
+        ),
+        // Education: binary check (ATS compatibility = can extract ANY education)
+        // ATS compatibility means "can the system parse education entries", not "how many degrees"
+        // A CV with 1 degree that's parseable should score 100% on education extraction
+        ATSRuleResult::new(
+            "sections.education",
+            fields.education_count > 0,
+            weights.education,
+            "Education entries detected",
+            "No education entries detected",
+        ),
+        // Skills: weighted (presence check)
+        ATSRuleResult::new(
+            "sections.skills",
+            fields.skills_count > 0,
+            weights.skills,
+            "Skills section detected",
+            "No skills section detected",
+        ),
+        // Proper structure: weighted (headings for sections)
+        ATSRuleResult::new(
+            "sections.headings",
+            proper_structure,
+            weights.structure,
+            "Clear section headings",
+            "No clear section headings",
+        ),
+        // Fonts embedded: weighted (properly embedded)
+        ATSRuleResult::new(
+            "fonts.embedded",
+            fonts_embedded,
+            weights.fonts_embedded,
+            "Fonts embedded",
+            "Fonts not fully embedded",
+        ),
+        // Text embedded: weighted (not flattened images)
+        ATSRuleResult::new(
+            "layout.text_embedded",
+            text_embedded,
+            weights.text_embedded,
+            "Text is selectable",
+            "Text is flattened into images",
+        ),
+        // Special characters: weighted (emoji and symbols ATS may drop or garble)
+        ATSRuleResult::new(
+            "text.special_characters",
+            special_characters.is_empty(),
+            weights.special_characters,
+            "No decorative symbols or emoji",
+            &format!(
+                "Decorative characters may be dropped or garbled by ATS: {}",
+                listed_special_characters
+            ),
+        ),
+    ];
 
-    score
+    rules.into_iter().filter(|rule| rule.weight > 0.0).collect()
 }
 
 #[cfg(test)]
@@ -101,7 +181,7 @@ mod tests {
             has_section_headings: true,
         };
 
-        let score = calculate_ats_score(&fields, true, true, true, &ATSWeights::default());
+        let score = calculate_ats_score(&fields, true, true, true, &[], &ATSWeights::default());
 
         // Should be 100/100 = 1.0
         assert_eq!(score, 1.0);
@@ -119,10 +199,10 @@ mod tests {
             has_section_headings: false,
         };
 
-        let score = calculate_ats_score(&fields, true, true, false, &ATSWeights::default());
+        let score = calculate_ats_score(&fields, true, true, false, &[], &ATSWeights::default());
 
-        // Name (10) + Email (10) + Text (5) + Fonts (5) = 30/100 = 0.30
-        assert_eq!(score, 0.30);
+        // Name (10) + Email (10) + Text (5) + Fonts (5) + Special chars (5) = 35/100 = 0.35
+        assert!((score - 0.35).abs() < 1e-9, "Expected 0.35, got {}", score);
     }
 
     #[test]
@@ -137,9 +217,10 @@ mod tests {
             has_section_headings: true,
         };
 
-        let score = calculate_ats_score(&fields, true, true, true, &ATSWeights::default());
+        let score = calculate_ats_score(&fields, true, true, true, &[], &ATSWeights::default());
 
-        // Exp (30) + Edu (20) + Skills (10) + Text (5) + Fonts (5) + Structure (5) = 75/100 = 0.75
+        // Exp (25) + Edu (20) + Skills (10) + Text (5) + Fonts (5) + Structure (5)
+        // + Special chars (5) = 75/100 = 0.75
         assert!(
             (score - 0.75).abs() < 0.0001,
             "Expected ~0.75, got {}",
@@ -170,9 +251,10 @@ mod tests {
             text_embedded: 0.05,
             fonts_embedded: 0.05,
             structure: 0.05,
+            special_characters: 0.0,
         };
 
-        let score = calculate_ats_score(&fields, true, true, true, &custom_weights);
+        let score = calculate_ats_score(&fields, true, true, true, &[], &custom_weights);
 
         // Should still be 1.0 (all fields present)
         assert_eq!(score, 1.0);
@@ -201,13 +283,37 @@ mod tests {
             text_embedded: 0.05,
             fonts_embedded: 0.05,
             structure: 0.05,
+            special_characters: 0.0,
         };
 
-        let score = calculate_ats_score(&fields, true, true, true, &custom_weights);
+        let score = calculate_ats_score(&fields, true, true, true, &[], &custom_weights);
 
         // Experience scoring is binary: if experience_count > 0, full weight applies
         // Name (0.10) + Email (0.10) + Experience (0.40) [binary: 3 > 0]
         // + Text (0.05) + Fonts (0.05) + Structure (0.05) = 0.75
         assert!((score - 0.75).abs() < 0.01);
     }
+
+    #[test]
+    fn test_zero_weight_disables_rule() {
+        let fields = FieldsPlaced {
+            name: true,
+            email: true,
+            phone: false,
+            experience_count: 1,
+            education_count: 1,
+            skills_count: 5,
+            has_section_headings: true,
+        };
+        let weights = ATSWeights {
+            phone: 0.0,
+            ..Default::default()
+        };
+
+        let rules = evaluate_ats_rules(&fields, true, true, true, &[], &weights);
+        assert!(rules.iter().all(|r| r.rule != "contact.phone"));
+
+        let score = calculate_ats_score(&fields, true, true, true, &[], &weights);
+        assert!((score - 1.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,166 @@
+//! Embedded file attachments
+//!
+//! Stores files inside the PDF as `/EmbeddedFile` streams, listed in the
+//! catalog's `/Names /EmbeddedFiles` name tree so viewers show them in their
+//! attachments panel. Used to keep the source TSX with the generated CV.
+
+use crate::error::PDFError;
+use lopdf::{dictionary, Document, Object, ObjectId, Stream, StringFormat};
+
+/// File name of the source TSX attachment
+pub const SOURCE_ATTACHMENT_NAME: &str = "resume.tsx";
+
+/// Attach a file to the document
+///
+/// Creates a compressed `/EmbeddedFile` stream and its file specification,
+/// then appends it to the catalog's `/EmbeddedFiles` name tree.
+///
+/// # Arguments
+/// * `doc` - Document whose trailer `/Root` points at the catalog
+/// * `file_name` - Name shown in the viewer's attachments panel
+/// * `mime_type` - MIME type, written as the stream `/Subtype` (e.g. `text/plain`)
+/// * `description` - Human-readable `/Desc` of the attachment
+/// * `bytes` - File contents
+///
+/// # Returns
+/// The object ID of the file specification dictionary
+pub fn embed_file(
+    doc: &mut Document,
+    file_name: &str,
+    mime_type: &str,
+    description: &str,
+    bytes: &[u8],
+) -> Result<ObjectId, PDFError> {
+    let catalog_id = doc
+        .trailer
+        .get(b"Root")
+        .and_then(|obj| obj.as_reference())
+        .map_err(|e| PDFError::RenderError(format!("Missing document catalog: {}", e)))?;
+
+    let mut file_stream = Stream::new(
+        dictionary! {
+            "Type" => "EmbeddedFile",
+            "Subtype" => Object::Name(mime_type.as_bytes().to_vec()),
+            "Params" => dictionary! { "Size" => bytes.len() as i64 },
+        },
+        bytes.to_vec(),
+    );
+    file_stream
+        .compress()
+        .map_err(|e| PDFError::RenderError(format!("Failed to compress attachment: {}", e)))?;
+    let file_stream_id = doc.add_object(file_stream);
+
+    let name = || Object::String(file_name.as_bytes().to_vec(), StringFormat::Literal);
+    let filespec_id = doc.add_object(dictionary! {
+        "Type" => "Filespec",
+        "F" => name(),
+        "UF" => name(),
+        "Desc" => Object::String(description.as_bytes().to_vec(), StringFormat::Literal),
+        "EF" => dictionary! { "F" => file_stream_id },
+        "AFRelationship" => "Source",
+    });
+
+    let catalog = doc
+        .get_object_mut(catalog_id)
+        .and_then(|obj| obj.as_dict_mut())
+        .map_err(|e| PDFError::RenderError(format!("Invalid document catalog: {}", e)))?;
+
+    // Name tree leaf: [(name1) ref1 (name2) ref2 ...]
+    let mut names = catalog
+        .get(b"Names")
+        .and_then(|obj| obj.as_dict())
+        .and_then(|names| names.get(b"EmbeddedFiles"))
+        .and_then(|obj| obj.as_dict())
+        .and_then(|tree| tree.get(b"Names"))
+        .and_then(|obj| obj.as_array())
+        .cloned()
+        .unwrap_or_default();
+    names.push(name());
+    names.push(Object::Reference(filespec_id));
+
+    let mut names_dict = catalog
+        .get(b"Names")
+        .and_then(|obj| obj.as_dict())
+        .cloned()
+        .unwrap_or_default();
+    names_dict.set("EmbeddedFiles", dictionary! { "Names" => names });
+    catalog.set("Names", names_dict);
+
+    // Associated files (PDF/A-3 / PDF 2.0) reference the attachment from the catalog
+    let mut associated = catalog
+        .get(b"AF")
+        .and_then(|obj| obj.as_array())
+        .cloned()
+        .unwrap_or_default();
+    associated.push(Object::Reference(filespec_id));
+    catalog.set("AF", associated);
+
+    Ok(filespec_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document_with_catalog() -> Document {
+        let mut doc = Document::with_version("1.7");
+        let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog" });
+        doc.trailer.set("Root", catalog_id);
+        doc
+    }
+
+    fn embedded_files(doc: &Document) -> Vec<Object> {
+        doc.catalog()
+            .unwrap()
+            .get(b"Names")
+            .and_then(|obj| obj.as_dict())
+            .and_then(|names| names.get(b"EmbeddedFiles"))
+            .and_then(|obj| obj.as_dict())
+            .and_then(|tree| tree.get(b"Names"))
+            .and_then(|obj| obj.as_array())
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_embed_file_adds_name_tree_entry() {
+        let mut doc = document_with_catalog();
+        let source = b"export default () => <div>Jane Doe</div>;";
+
+        let filespec_id =
+            embed_file(&mut doc, "resume.tsx", "text/plain", "Source", source).unwrap();
+
+        let names = embedded_files(&doc);
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].as_str().unwrap(), b"resume.tsx");
+        assert_eq!(names[1].as_reference().unwrap(), filespec_id);
+
+        let filespec = doc.get_dictionary(filespec_id).unwrap();
+        let stream_id = filespec
+            .get(b"EF")
+            .and_then(|obj| obj.as_dict())
+            .and_then(|ef| ef.get(b"F"))
+            .and_then(|obj| obj.as_reference())
+            .unwrap();
+        let stream = doc.get_object(stream_id).unwrap().as_stream().unwrap();
+        assert_eq!(
+            stream.dict.get(b"Subtype").unwrap().as_name().unwrap(),
+            b"text/plain"
+        );
+        assert_eq!(stream.get_plain_content().unwrap(), source);
+    }
+
+    #[test]
+    fn test_embed_file_appends_to_existing_tree() {
+        let mut doc = document_with_catalog();
+        embed_file(&mut doc, "a.txt", "text/plain", "First", b"a").unwrap();
+        embed_file(&mut doc, "b.txt", "text/plain", "Second", b"b").unwrap();
+
+        let names = embedded_files(&doc);
+        assert_eq!(names.len(), 4);
+        assert_eq!(names[2].as_str().unwrap(), b"b.txt");
+
+        let associated = doc.catalog().unwrap().get(b"AF").unwrap();
+        assert_eq!(associated.as_array().unwrap().len(), 2);
+    }
+}
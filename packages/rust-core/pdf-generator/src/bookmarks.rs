@@ -236,6 +236,7 @@ mod tests {
                 "h6" => ElementType::Heading6,
                 _ => ElementType::Div,
             }),
+            list_ordinal: None,
         }
     }
 
@@ -252,6 +253,7 @@ mod tests {
                 "h2" => ElementType::Heading2,
                 _ => ElementType::Div,
             }),
+            list_ordinal: None,
         }
     }
 
@@ -283,6 +285,7 @@ mod tests {
             content: BoxContent::Empty,
             style: StyleDeclaration::new(),
             element_type: None,
+            list_ordinal: None,
         };
         assert_eq!(extract_text_from_box(&empty_box), None);
     }
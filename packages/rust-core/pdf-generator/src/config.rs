@@ -1,11 +1,33 @@
 use crate::ats::ATSWeights;
-use serde::{Deserialize, Serialize};
+use crate::error::PDFError;
+use layout_types::Color;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::str::FromStr;
+
+/// Default `/Producer` value: the software that wrote the PDF bytes
+pub const DEFAULT_PRODUCER: &str =
+    concat!("ResumeWright pdf-generator ", env!("CARGO_PKG_VERSION"));
 
 /// Default value for generate_bookmarks field (enabled by default)
 fn default_generate_bookmarks() -> bool {
     true
 }
 
+/// Default value for structured_reading_order field (enabled by default)
+fn default_structured_reading_order() -> bool {
+    true
+}
+
+/// Default value for keywords_from_skills field (enabled by default)
+fn default_keywords_from_skills() -> bool {
+    true
+}
+
+/// Default value for binary_comment field (enabled by default)
+fn default_binary_comment() -> bool {
+    true
+}
+
 /// PDF standard conformance levels.
 ///
 /// Different PDF standards provide varying levels of compatibility,
@@ -45,6 +67,240 @@ pub enum PDFStandard {
     PDFA1b,
 }
 
+impl PDFStandard {
+    /// Whether the standard permits embedded file attachments
+    ///
+    /// PDF/A-1 forbids embedded files (ISO 19005-1, clause 6.1.11); plain
+    /// PDF 1.7 allows them, as does PDF/A-3.
+    pub fn allows_embedded_files(&self) -> bool {
+        match self {
+            PDFStandard::PDF17 => true,
+            PDFStandard::PDFA1b => false,
+        }
+    }
+
+    /// Whether the standard requires a high-byte comment after the header
+    ///
+    /// PDF/A-1 requires the header to be followed by a comment of at least
+    /// four bytes above 127 (ISO 19005-1, clause 6.1.2).
+    pub fn requires_binary_comment(&self) -> bool {
+        match self {
+            PDFStandard::PDF17 => false,
+            PDFStandard::PDFA1b => true,
+        }
+    }
+}
+
+/// Document-level viewer preferences (`/ViewerPreferences` in the catalog).
+///
+/// Only flags set to `true` are written; viewers treat absent entries as
+/// `false`. `display_doc_title` is required by PDF/UA (ISO 14289-1, 7.1) so
+/// the window shows the document title rather than the file name.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::{PDFConfig, ViewerPreferences};
+///
+/// let config = PDFConfig {
+///     viewer_preferences: Some(ViewerPreferences {
+///         display_doc_title: true,
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct ViewerPreferences {
+    /// Resize the window to fit the first page (`/FitWindow`)
+    pub fit_window: bool,
+    /// Show the document title in the window title bar (`/DisplayDocTitle`)
+    pub display_doc_title: bool,
+    /// Hide the viewer's toolbars (`/HideToolbar`)
+    pub hide_toolbar: bool,
+}
+
+/// Styling applied to autolinked text (emails and web addresses).
+///
+/// Text covered by a link annotation is recolored and, optionally,
+/// underlined so readers can tell it is clickable. The default is the
+/// classic browser link blue with an underline.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::{LinkStyle, PDFConfig};
+///
+/// let config = PDFConfig {
+///     autolink: true,
+///     link_style: Some(LinkStyle::default()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct LinkStyle {
+    /// Text color of the link
+    pub color: Color,
+    /// Whether the link is underlined
+    pub underline: bool,
+}
+
+impl Default for LinkStyle {
+    fn default() -> Self {
+        Self {
+            color: Color {
+                r: 0,
+                g: 0,
+                b: 0xEE,
+                a: 1.0,
+            },
+            underline: true,
+        }
+    }
+}
+
+/// Text drawn diagonally across every page, beneath the content.
+///
+/// The watermark is written once as a shared Form XObject that each page
+/// paints, so it adds the same few bytes however many pages there are.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::{PDFConfig, Watermark};
+///
+/// let config = PDFConfig {
+///     watermark: Some(Watermark::new("DRAFT")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Watermark {
+    /// Text to draw (e.g. `DRAFT`)
+    pub text: String,
+    /// Font size in points
+    pub font_size: f64,
+    /// Text color; light colors keep the content readable
+    pub color: Color,
+}
+
+impl Watermark {
+    /// Watermark with the given text at the default size and color
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for Watermark {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            font_size: 72.0,
+            color: Color {
+                r: 0xDD,
+                g: 0xDD,
+                b: 0xDD,
+                a: 1.0,
+            },
+        }
+    }
+}
+
+/// What to do when a requested font's data is missing from the collection.
+///
+/// Google and custom fonts are rendered from font data supplied by the
+/// caller; a variant that failed to load is missing. Every substitution is
+/// reported (see [`crate::FontSubstitution`]).
+///
+/// Serialized externally tagged: `"SubstituteStandard14"`, `"Error"` or
+/// `{ "SubstituteNamedFallback": "Georgia" }`.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::{MissingFontPolicy, PDFConfig};
+///
+/// let config = PDFConfig {
+///     missing_font_policy: MissingFontPolicy::SubstituteNamedFallback("Georgia".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum MissingFontPolicy {
+    /// Render with the Standard 14 font matching the weight and style
+    #[default]
+    SubstituteStandard14,
+    /// Render with the named family instead (a supplied or web-safe font)
+    SubstituteNamedFallback(String),
+    /// Fail the conversion with a font error
+    Error,
+}
+
+/// Order of each page's `/Annots` array, which viewers use as tab order.
+///
+/// Geometric order reads top to bottom, then left to right, which interleaves
+/// the columns of a multi-column layout. DOM order keeps the layout's box
+/// order, so the links of a two-column CV are visited one column and then the
+/// other. No structure tree is written, so this is the only reading order the
+/// PDF carries.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::{PDFConfig, ReadingOrder};
+///
+/// let config = PDFConfig {
+///     reading_order: ReadingOrder::DomOrder,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ReadingOrder {
+    /// Top to bottom, then left to right, by position on the page
+    #[default]
+    Geometric,
+    /// Order the annotations' boxes are listed in the layout
+    ///
+    /// For in-flow boxes this is the TSX source order, including children of
+    /// `flex-direction: *-reverse` containers. Absolutely positioned boxes are
+    /// the exception: pagination lists them among the flow boxes at their
+    /// vertical position, so their links are visited where they sit on the
+    /// page rather than where they appear in the source.
+    DomOrder,
+}
+
+/// Speed versus size tradeoff for embedding supplied fonts.
+///
+/// Subsetting is the slowest font step, so a live preview may prefer
+/// [`FontQuality::Fast`] while a final export uses [`FontQuality::Small`].
+/// Bundled base fonts (PDF/A, `embed_base_fonts`) are always subset.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::{FontQuality, PDFConfig};
+///
+/// let preview = PDFConfig {
+///     font_quality: FontQuality::Fast,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum FontQuality {
+    /// Embed full fonts as supplied, skipping subsetting
+    Fast,
+    /// Subset fonts to the document text and strip hinting tables
+    #[default]
+    Balanced,
+    /// Subset fonts, strip every table PDF rendering does not read, and
+    /// compress content streams
+    Small,
+}
+
 /// Page size dimensions for PDF documents.
 ///
 /// All dimensions are measured in points (1 point = 1/72 inch).
@@ -60,7 +316,10 @@ pub enum PDFStandard {
 /// assert_eq!(width, 612.0);  // 8.5 inches
 /// assert_eq!(height, 792.0); // 11 inches
 /// ```
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+///
+/// Deserializes from a case-insensitive name via [`PageSize::from_name`],
+/// so `"a4"` and `"A4"` are equivalent and unknown names are rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PageSize {
     /// US Letter size (8.5 x 11 inches = 612 x 792 points)
     ///
@@ -76,6 +335,15 @@ pub enum PageSize {
     ///
     /// Longer format for legal documents and extended resumes.
     Legal,
+
+    /// ISO A3 size (297 x 420 mm = 841.890 x 1190.551 points)
+    A3,
+
+    /// ISO A5 size (148 x 210 mm = 419.528 x 595.276 points)
+    A5,
+
+    /// US Tabloid size (11 x 17 inches = 792 x 1224 points)
+    Tabloid,
 }
 
 impl PageSize {
@@ -100,10 +368,63 @@ impl PageSize {
             PageSize::Letter => (612.0, 792.0),
             PageSize::A4 => (595.276, 841.890), // Exact A4 in points
             PageSize::Legal => (612.0, 1008.0),
+            PageSize::A3 => (841.890, 1190.551),
+            PageSize::A5 => (419.528, 595.276),
+            PageSize::Tabloid => (792.0, 1224.0),
+        }
+    }
+
+    /// Look up a page size by name (case-insensitive).
+    ///
+    /// Recognizes "A3", "A4", "A5", "Letter", "Legal", and "Tabloid".
+    /// Shared by config deserialization and the WASM bridge so every entry
+    /// point accepts the same names.
+    ///
+    /// # Returns
+    ///
+    /// `None` for unrecognized names rather than falling back to a default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pdf_generator::PageSize;
+    ///
+    /// assert_eq!(PageSize::from_name("a4"), Some(PageSize::A4));
+    /// assert_eq!(PageSize::from_name("Foolscap"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "letter" => Some(PageSize::Letter),
+            "a4" => Some(PageSize::A4),
+            "legal" => Some(PageSize::Legal),
+            "a3" => Some(PageSize::A3),
+            "a5" => Some(PageSize::A5),
+            "tabloid" => Some(PageSize::Tabloid),
+            _ => None,
         }
     }
 }
 
+impl FromStr for PageSize {
+    type Err = PDFError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| {
+            PDFError::ConfigError(format!(
+                "Unknown page size '{}' (expected A3, A4, A5, Letter, Legal, or Tabloid)",
+                name
+            ))
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PageSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// Page margin configuration for PDF documents.
 ///
 /// Margins define the whitespace around the content area of a page.
@@ -234,6 +555,7 @@ pub struct PDFConfig {
     ///
     /// If not provided, uses industry-standard default weights.
     /// Allows fine-tuning of ATS compatibility scoring to match specific requirements.
+    /// Weights are relative (normalized before scoring); a weight of 0 disables a rule.
     #[serde(default)]
     pub ats_weights: Option<ATSWeights>,
 
@@ -284,6 +606,314 @@ pub struct PDFConfig {
     /// ```
     #[serde(default = "default_generate_bookmarks")]
     pub generate_bookmarks: bool,
+
+    /// Declare structure-based tab order on every page (default: true)
+    ///
+    /// When enabled, each page dictionary gets `/Tabs /S` and its `/Annots` are
+    /// ordered as chosen by `reading_order`. The document has no structure
+    /// tree, so viewers visit annotations in `/Annots` order.
+    #[serde(default = "default_structured_reading_order")]
+    pub structured_reading_order: bool,
+
+    /// How `/Annots` are ordered under structured reading order (default: geometric)
+    ///
+    /// See [`ReadingOrder`]. Has no effect when `structured_reading_order` is off.
+    #[serde(default)]
+    pub reading_order: ReadingOrder,
+
+    /// Embed fonts whose license restricts embedding (default: false)
+    ///
+    /// Fonts with a restricted OS/2 `fsType` are normally skipped and the
+    /// Standard 14 fallback is used. Enable only when you own a license that
+    /// permits embedding.
+    #[serde(default)]
+    pub allow_restricted_fonts: bool,
+
+    /// Producer name override (appears as `/Producer` in PDF metadata)
+    ///
+    /// Defaults to [`DEFAULT_PRODUCER`], which names this crate and its
+    /// version. PDF tooling reads this field to attribute generation.
+    #[serde(default)]
+    pub producer: Option<String>,
+
+    /// Populate `keywords` from the CV's extracted skills (default: true)
+    ///
+    /// Only applies when `keywords` is not set explicitly. Recruiters search
+    /// ATS databases by keyword, so skills in `/Keywords` and XMP
+    /// `pdf:Keywords` make the document easier to find.
+    #[serde(default = "default_keywords_from_skills")]
+    pub keywords_from_skills: bool,
+
+    /// Inset of `/TrimBox` from `/MediaBox` in points (default: None)
+    ///
+    /// The trim box is the finished page after cutting. When only
+    /// `bleed_box` is set, the trim inset defaults to the bleed so the
+    /// bleed area fits on the media. Neither box is emitted when both are unset.
+    #[serde(default)]
+    pub trim_box: Option<f64>,
+
+    /// Bleed extending beyond `/TrimBox` in points (default: None)
+    ///
+    /// Emitted as `/BleedBox`, clamped to `/MediaBox`. Print shops typically
+    /// ask for 9pt (1/8 inch) of bleed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pdf_generator::PDFConfig;
+    ///
+    /// let config = PDFConfig {
+    ///     bleed_box: Some(9.0),
+    ///     ..Default::default()
+    /// };
+    /// ```
+    #[serde(default)]
+    pub bleed_box: Option<f64>,
+
+    /// Attach the source TSX to the PDF as an embedded file (default: false)
+    ///
+    /// The source is stored as `resume.tsx` in the catalog's `/EmbeddedFiles`
+    /// name tree for auditability. Rejected with [`PDFError::ConfigError`]
+    /// when `standard` forbids embedded files (PDF/A-1b).
+    #[serde(default)]
+    pub embed_source: bool,
+
+    /// Write a high-byte comment after the `%PDF-` header (default: true)
+    ///
+    /// The comment tells transfer tools to treat the file as binary. Disable it
+    /// for pure-ASCII debugging output that diffs cleanly; an empty `%` line
+    /// is written in its place. Always written for standards that require it
+    /// (PDF/A-1b), regardless of this setting.
+    #[serde(default = "default_binary_comment")]
+    pub binary_comment: bool,
+
+    /// Viewer preferences written to the document catalog (default: None)
+    ///
+    /// Controls how PDF viewers open the document, e.g. showing the title
+    /// instead of the file name. No `/ViewerPreferences` entry when unset.
+    #[serde(default)]
+    pub viewer_preferences: Option<ViewerPreferences>,
+
+    /// Turn emails and web addresses in text into clickable links (default: false)
+    ///
+    /// Each email and `http(s)://`/`www.` address gets a `/Link` annotation
+    /// over its text. Off by default so output matches the author's markup
+    /// exactly.
+    #[serde(default)]
+    pub autolink: bool,
+
+    /// Styling for autolinked emails and URLs (default: None)
+    ///
+    /// Only applies with `autolink` on; when set, link text is also recolored
+    /// and underlined. Unset leaves the author's styling untouched.
+    #[serde(default)]
+    pub link_style: Option<LinkStyle>,
+
+    /// Best-effort maximum output size in bytes (default: None)
+    ///
+    /// When the PDF comes out larger, size reductions are enabled one by one
+    /// and it is regenerated (see [`crate::generate_within_target`]).
+    /// Missing the target is reported, not an error.
+    #[serde(default)]
+    pub target_max_bytes: Option<usize>,
+
+    /// Strip font tables PDF rendering never reads (default: false)
+    ///
+    /// Removes naming, OpenType layout and device-metric tables from embedded
+    /// fonts on top of the hinting tables that are always stripped.
+    #[serde(default)]
+    pub strip_optional_font_tables: bool,
+
+    /// Embed the bundled base fonts in non-PDF/A output too (default: false)
+    ///
+    /// PDF/A always embeds subsets of the bundled Helvetica substitutes; this
+    /// does the same for PDF 1.7 so the document never relies on the reader's
+    /// own Helvetica.
+    #[serde(default)]
+    pub embed_base_fonts: bool,
+
+    /// Baseline grid step in points (default: None)
+    ///
+    /// When set, line heights are rounded to multiples of the step and text
+    /// boxes are shifted so every baseline sits on a grid line counted from
+    /// the top margin, giving columns and sections a shared vertical rhythm.
+    #[serde(default)]
+    pub baseline_grid: Option<f64>,
+
+    /// Handling of requested fonts missing from the font collection
+    /// (default: substitute Standard 14)
+    ///
+    /// Applies to Google and custom families whose data was not supplied for
+    /// the requested weight and style.
+    #[serde(default)]
+    pub missing_font_policy: MissingFontPolicy,
+
+    /// Speed versus size tradeoff for supplied fonts (default: balanced)
+    #[serde(default)]
+    pub font_quality: FontQuality,
+
+    /// Largest number of pages the content should fill (default: no limit)
+    ///
+    /// When the natural layout runs longer, font sizes and spacing are shrunk
+    /// uniformly until it fits, but never below a 6pt font floor; past the
+    /// floor the layout keeps its natural size and extra pages.
+    #[serde(default)]
+    pub fit_to_pages: Option<usize>,
+
+    /// Extra key/value pairs written to the Info dictionary (default: none)
+    ///
+    /// Keys are sanitized to PDF name tokens (see
+    /// [`PDFConfig::custom_info_entries`]). Under PDF/A the entries are
+    /// mirrored into the XMP metadata as custom properties.
+    #[serde(default)]
+    pub custom_info: Vec<(String, String)>,
+
+    /// Height in points reserved below the top margin for a repeating header
+    /// band (default: 0); body content starts below it
+    #[serde(default)]
+    pub header_band_height: f64,
+
+    /// Height in points reserved above the bottom margin for a footer band
+    /// such as page numbers (default: 0); body content never enters it
+    #[serde(default)]
+    pub footer_band_height: f64,
+
+    /// Text drawn diagonally beneath the content of every page (default: none)
+    #[serde(default)]
+    pub watermark: Option<Watermark>,
+}
+
+/// Info dictionary keys written from dedicated config fields or by the generator
+const STANDARD_INFO_KEYS: &[&str] = &[
+    "Title",
+    "Author",
+    "Subject",
+    "Keywords",
+    "Creator",
+    "Producer",
+    "CreationDate",
+    "ModDate",
+    "Trapped",
+    "PageCount",
+];
+
+impl PDFConfig {
+    /// Producer name written to the Info dictionary and XMP metadata
+    pub fn producer_name(&self) -> &str {
+        self.producer.as_deref().unwrap_or(DEFAULT_PRODUCER)
+    }
+
+    /// Start a [`PDFConfigBuilder`] from the default configuration
+    pub fn builder() -> PDFConfigBuilder {
+        PDFConfigBuilder::default()
+    }
+
+    /// Check the configuration for settings generation would reject or misrender
+    ///
+    /// # Errors
+    ///
+    /// Returns `PDFError::ConfigError` when:
+    /// - a margin or header/footer band is negative or not finite
+    /// - the margins and bands leave no content area on the page
+    /// - a feature is forbidden by the standard (`embed_source` under PDF/A-1b)
+    /// - a metadata string contains a control character
+    pub fn validate(&self) -> Result<(), PDFError> {
+        let lengths = [
+            ("top margin", self.margin.top),
+            ("right margin", self.margin.right),
+            ("bottom margin", self.margin.bottom),
+            ("left margin", self.margin.left),
+            ("header band height", self.header_band_height),
+            ("footer band height", self.footer_band_height),
+        ];
+        for (name, value) in lengths {
+            if !value.is_finite() || value < 0.0 {
+                return Err(PDFError::ConfigError(format!(
+                    "The {} must be a non-negative number of points, got {}",
+                    name, value
+                )));
+            }
+        }
+
+        let (width, height) = self.page_size.dimensions();
+        let horizontal = self.margin.left + self.margin.right;
+        let vertical = self.margin.top
+            + self.margin.bottom
+            + self.header_band_height
+            + self.footer_band_height;
+        if horizontal >= width || vertical >= height {
+            return Err(PDFError::ConfigError(format!(
+                "Margins ({}pt horizontal, {}pt vertical with bands) leave no content area on a {}x{}pt page",
+                horizontal, vertical, width, height
+            )));
+        }
+
+        if self.embed_source && !self.standard.allows_embedded_files() {
+            return Err(PDFError::ConfigError(format!(
+                "embed_source is not allowed with {:?}: the standard forbids embedded files",
+                self.standard
+            )));
+        }
+
+        let metadata = [
+            ("title", self.title.as_deref()),
+            ("author", self.author.as_deref()),
+            ("subject", self.subject.as_deref()),
+            ("keywords", self.keywords.as_deref()),
+            ("creator", self.creator.as_deref()),
+            ("producer", self.producer.as_deref()),
+        ];
+        let custom = self.custom_info.iter().flat_map(|(key, value)| {
+            [
+                ("custom info key", Some(key.as_str())),
+                ("custom info value", Some(value.as_str())),
+            ]
+        });
+        for (name, value) in metadata.into_iter().chain(custom) {
+            if value.is_some_and(|v| v.chars().any(char::is_control)) {
+                return Err(PDFError::ConfigError(format!(
+                    "The {} contains a control character",
+                    name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `custom_info` entries as written to the Info dictionary and XMP
+    ///
+    /// Characters other than ASCII letters, digits, `-`, `_` and `.` become
+    /// `_`, and a key starting with a digit, `-` or `.` gets a leading `_`, so
+    /// every key is a valid PDF name and XML name. Empty keys, keys naming a
+    /// standard Info entry, and repeats of an earlier key are dropped.
+    pub fn custom_info_entries(&self) -> Vec<(String, &str)> {
+        let mut entries: Vec<(String, &str)> = Vec::new();
+        for (key, value) in &self.custom_info {
+            let mut name: String = key
+                .trim()
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            if name.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '.')) {
+                name.insert(0, '_');
+            }
+            if name.is_empty()
+                || STANDARD_INFO_KEYS.contains(&name.as_str())
+                || entries.iter().any(|(existing, _)| *existing == name)
+            {
+                continue;
+            }
+            entries.push((name, value.as_str()));
+        }
+        entries
+    }
 }
 
 impl Default for PDFConfig {
@@ -297,13 +927,180 @@ impl Default for PDFConfig {
             subject: Some("Curriculum Vitae".to_string()),
             keywords: None,
             creator: Some("ResumeWright Browser Extension".to_string()),
-            ats_weights: None,               // Use default weights
-            compress_content_streams: false, // Disabled by default for compatibility
-            generate_bookmarks: true,        // Enable bookmarks by default for better UX
+            ats_weights: None,                      // Use default weights
+            compress_content_streams: false,        // Disabled by default for compatibility
+            generate_bookmarks: true,               // Enable bookmarks by default for better UX
+            structured_reading_order: true,         // Accessible tab order by default
+            reading_order: ReadingOrder::Geometric, // Top-to-bottom annotations by default
+            allow_restricted_fonts: false,          // Respect font licenses by default
+            producer: None,                         // Crate-versioned default producer
+            keywords_from_skills: true,             // Skills become /Keywords by default
+            trim_box: None,                         // No print boxes by default
+            bleed_box: None,                        // No print boxes by default
+            embed_source: false,                    // No attachments by default
+            binary_comment: true,                   // Mark output as binary by default
+            viewer_preferences: None,               // Viewer defaults by default
+            autolink: false,                        // Text is not scanned for links by default
+            link_style: None,                       // Author styling for links by default
+            target_max_bytes: None,                 // No size target by default
+            strip_optional_font_tables: false,      // Keep all non-hinting tables by default
+            embed_base_fonts: false,                // Reader's Helvetica outside PDF/A by default
+            baseline_grid: None,                    // Lines keep their own line-height by default
+            missing_font_policy: MissingFontPolicy::SubstituteStandard14, // Never fail on a missing font by default
+            font_quality: FontQuality::Balanced,                          // Subset fonts by default
+            fit_to_pages: None,      // Natural size, however many pages by default
+            custom_info: Vec::new(), // Standard Info entries only by default
+            header_band_height: 0.0, // No header band by default
+            footer_band_height: 0.0, // No footer band by default
+            watermark: None,         // No watermark by default
         }
     }
 }
 
+/// Chainable, validated construction of a [`PDFConfig`].
+///
+/// Starts from [`PDFConfig::default`]; [`build`](Self::build) runs
+/// [`PDFConfig::validate`] so invalid settings fail before generation starts.
+/// Settings without a setter can be given by starting from a config literal
+/// with `PDFConfigBuilder::from(config)`.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::{Margin, PDFConfig, PDFStandard, PageSize};
+///
+/// let config = PDFConfig::builder()
+///     .page_size(PageSize::A4)
+///     .margin(Margin::from_inches(0.5))
+///     .standard(PDFStandard::PDFA1b)
+///     .title("Jane Doe - Resume")
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.margin.top, 36.0);
+///
+/// assert!(PDFConfig::builder().margin(Margin::from_inches(-1.0)).build().is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PDFConfigBuilder {
+    config: PDFConfig,
+}
+
+impl From<PDFConfig> for PDFConfigBuilder {
+    fn from(config: PDFConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl PDFConfigBuilder {
+    /// Page dimensions
+    pub fn page_size(mut self, page_size: PageSize) -> Self {
+        self.config.page_size = page_size;
+        self
+    }
+
+    /// Page margins in points
+    pub fn margin(mut self, margin: Margin) -> Self {
+        self.config.margin = margin;
+        self
+    }
+
+    /// PDF standard to conform to
+    pub fn standard(mut self, standard: PDFStandard) -> Self {
+        self.config.standard = standard;
+        self
+    }
+
+    /// Document title
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.config.title = Some(title.into());
+        self
+    }
+
+    /// Document author
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.config.author = Some(author.into());
+        self
+    }
+
+    /// Document subject
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.config.subject = Some(subject.into());
+        self
+    }
+
+    /// Document keywords
+    pub fn keywords(mut self, keywords: impl Into<String>) -> Self {
+        self.config.keywords = Some(keywords.into());
+        self
+    }
+
+    /// Application that created the content
+    pub fn creator(mut self, creator: impl Into<String>) -> Self {
+        self.config.creator = Some(creator.into());
+        self
+    }
+
+    /// Producer written instead of the default
+    pub fn producer(mut self, producer: impl Into<String>) -> Self {
+        self.config.producer = Some(producer.into());
+        self
+    }
+
+    /// Add an extra Info dictionary entry
+    pub fn custom_info(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config.custom_info.push((key.into(), value.into()));
+        self
+    }
+
+    /// Whether content streams are compressed
+    pub fn compress_content_streams(mut self, compress: bool) -> Self {
+        self.config.compress_content_streams = compress;
+        self
+    }
+
+    /// Whether the source TSX is attached to the PDF
+    pub fn embed_source(mut self, embed: bool) -> Self {
+        self.config.embed_source = embed;
+        self
+    }
+
+    /// Heights in points reserved for the header and footer bands
+    pub fn bands(mut self, header_height: f64, footer_height: f64) -> Self {
+        self.config.header_band_height = header_height;
+        self.config.footer_band_height = footer_height;
+        self
+    }
+
+    /// Order of annotations under structured reading order
+    pub fn reading_order(mut self, reading_order: ReadingOrder) -> Self {
+        self.config.reading_order = reading_order;
+        self
+    }
+
+    /// Largest number of pages the content should fill
+    pub fn fit_to_pages(mut self, pages: usize) -> Self {
+        self.config.fit_to_pages = Some(pages);
+        self
+    }
+
+    /// Text drawn beneath the content of every page
+    pub fn watermark(mut self, watermark: Watermark) -> Self {
+        self.config.watermark = Some(watermark);
+        self
+    }
+
+    /// Validate and return the configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns `PDFError::ConfigError` for the settings listed in
+    /// [`PDFConfig::validate`].
+    pub fn build(self) -> Result<PDFConfig, PDFError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -331,6 +1128,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_page_size_from_name() {
+        let cases = [
+            ("A3", PageSize::A3),
+            ("A4", PageSize::A4),
+            ("A5", PageSize::A5),
+            ("Letter", PageSize::Letter),
+            ("Legal", PageSize::Legal),
+            ("Tabloid", PageSize::Tabloid),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(PageSize::from_name(name), Some(expected), "{}", name);
+            assert_eq!(
+                PageSize::from_name(&name.to_lowercase()),
+                Some(expected),
+                "{} (lowercase)",
+                name
+            );
+            assert_eq!(
+                PageSize::from_name(&name.to_uppercase()),
+                Some(expected),
+                "{} (uppercase)",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_page_size_rejects_unknown_name() {
+        assert_eq!(PageSize::from_name("Foolscap"), None);
+
+        let err = "Foolscap".parse::<PageSize>().unwrap_err();
+        assert!(matches!(err, PDFError::ConfigError(ref msg) if msg.contains("Foolscap")));
+
+        let json = r#""Foolscap""#;
+        assert!(serde_json::from_str::<PageSize>(json).is_err());
+    }
+
+    #[test]
+    fn test_page_size_deserializes_case_insensitively() {
+        let size: PageSize = serde_json::from_str(r#""tabloid""#).unwrap();
+        assert_eq!(size, PageSize::Tabloid);
+
+        // Serialization keeps the canonical variant name
+        assert_eq!(serde_json::to_string(&PageSize::A5).unwrap(), r#""A5""#);
+    }
+
     #[test]
     fn test_margin_from_inches() {
         let margin = Margin::from_inches(0.5);
@@ -370,5 +1214,125 @@ mod tests {
             "Default title should be 'Resume'"
         );
         assert_eq!(config.subject, Some("Curriculum Vitae".to_string()));
+        assert!(config.structured_reading_order);
+        assert_eq!(config.reading_order, ReadingOrder::Geometric);
+        assert!(!config.allow_restricted_fonts);
+        assert_eq!(config.producer_name(), DEFAULT_PRODUCER);
+    }
+
+    #[test]
+    fn test_structured_reading_order_defaults_when_missing_from_json() {
+        let json = r#"{
+            "page_size": "Letter",
+            "margin": {"top": 0.0, "right": 0.0, "bottom": 0.0, "left": 0.0},
+            "standard": "PDF17",
+            "title": null,
+            "author": null,
+            "subject": null,
+            "keywords": null,
+            "creator": null
+        }"#;
+        let config: PDFConfig = serde_json::from_str(json).unwrap();
+        assert!(config.structured_reading_order);
+        assert!(!config.allow_restricted_fonts);
+        assert!(config.binary_comment);
+    }
+
+    #[test]
+    fn test_custom_info_keys_are_sanitized() {
+        let config = PDFConfig {
+            custom_info: vec![
+                ("Candidate ID".to_string(), "42".to_string()),
+                ("2024/Source".to_string(), "LinkedIn".to_string()),
+                ("Producer".to_string(), "Spoofed".to_string()),
+                ("Candidate/ID".to_string(), "duplicate".to_string()),
+                ("  ".to_string(), "empty".to_string()),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.custom_info_entries(),
+            vec![
+                ("Candidate_ID".to_string(), "42"),
+                ("_2024_Source".to_string(), "LinkedIn"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_builder_builds_valid_config() {
+        let config = PDFConfig::builder()
+            .page_size(PageSize::A4)
+            .margin(Margin::from_inches(0.75))
+            .standard(PDFStandard::PDFA1b)
+            .title("Jane Doe - Resume")
+            .author("Jane Doe")
+            .custom_info("Candidate ID", "42")
+            .bands(24.0, 18.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.page_size, PageSize::A4);
+        assert_eq!(config.margin.left, 54.0);
+        assert_eq!(config.standard, PDFStandard::PDFA1b);
+        assert_eq!(config.author.as_deref(), Some("Jane Doe"));
+        assert_eq!(config.custom_info.len(), 1);
+        assert_eq!(config.footer_band_height, 18.0);
+        assert!(PDFConfig::default().validate().is_ok());
+    }
+
+    fn config_error(builder: PDFConfigBuilder) -> String {
+        match builder.build() {
+            Err(PDFError::ConfigError(msg)) => msg,
+            other => panic!("expected ConfigError, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_builder_rejects_negative_margins() {
+        let margin = Margin {
+            left: -1.0,
+            ..Margin::from_inches(0.5)
+        };
+        assert!(config_error(PDFConfig::builder().margin(margin)).contains("left margin"));
+        assert!(config_error(PDFConfig::builder().bands(f64::NAN, 0.0)).contains("header band"));
+    }
+
+    #[test]
+    fn test_builder_rejects_margins_wider_than_the_page() {
+        let builder = PDFConfig::builder()
+            .page_size(PageSize::A5)
+            .margin(Margin::from_inches(3.0));
+        assert!(config_error(builder).contains("no content area"));
+
+        let builder = PDFConfig::builder()
+            .margin(Margin::from_inches(1.0))
+            .bands(400.0, 300.0);
+        assert!(config_error(builder).contains("no content area"));
+    }
+
+    #[test]
+    fn test_builder_rejects_features_forbidden_by_the_standard() {
+        let builder = PDFConfig::builder()
+            .standard(PDFStandard::PDFA1b)
+            .embed_source(true);
+        assert!(config_error(builder).contains("embed_source"));
+
+        assert!(PDFConfig::builder()
+            .standard(PDFStandard::PDF17)
+            .embed_source(true)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_builder_rejects_control_characters_in_metadata() {
+        assert!(config_error(PDFConfig::builder().title("Jane\u{0}Doe")).contains("title"));
+        assert!(config_error(PDFConfig::builder().keywords("Rust\nWASM")).contains("keywords"));
+        assert!(
+            config_error(PDFConfig::builder().custom_info("Source", "Linked\u{7}In"))
+                .contains("custom info value")
+        );
     }
 }
@@ -34,6 +34,16 @@ pub trait ContentBuilder {
     /// * `fmt_args` - Formatted string arguments
     fn push_formatted(&mut self, fmt_args: std::fmt::Arguments<'_>);
 
+    /// Save graphics state (q operator)
+    fn save_graphics_state(&mut self) {
+        self.push_operator("q\n");
+    }
+
+    /// Restore graphics state (Q operator)
+    fn restore_graphics_state(&mut self) {
+        self.push_operator("Q\n");
+    }
+
     /// Begin a text block (BT operator)
     fn begin_text(&mut self) {
         self.push_operator("BT\n");
@@ -64,6 +74,36 @@ pub trait ContentBuilder {
         self.push_formatted(format_args!("{} {} Td\n", x, y));
     }
 
+    /// Set text rise (Ts operator)
+    ///
+    /// Text rise is part of the text state and persists across text objects,
+    /// so callers that set a non-zero rise must reset it to 0 afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `rise` - Baseline shift in points (positive moves text up)
+    fn set_text_rise(&mut self, rise: f64) {
+        self.push_formatted(format_args!("{} Ts\n", rise));
+    }
+
+    /// Set text rendering mode (Tr operator)
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Rendering mode (0 = fill, 1 = stroke, 2 = fill then stroke)
+    fn set_text_rendering_mode(&mut self, mode: u8) {
+        self.push_formatted(format_args!("{} Tr\n", mode));
+    }
+
+    /// Set character spacing (Tc operator)
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - Extra advance after each glyph, in unscaled text space units
+    fn set_character_spacing(&mut self, spacing: f64) {
+        self.push_formatted(format_args!("{} Tc\n", spacing));
+    }
+
     /// Show text (Tj operator with hex-encoded string)
     ///
     /// # Arguments
@@ -73,6 +113,26 @@ pub trait ContentBuilder {
         self.push_formatted(format_args!("<{}> Tj\n", hex_text));
     }
 
+    /// Show several strings with positioning adjustments (TJ operator)
+    ///
+    /// Each part is a hex-encoded string followed by the adjustment applied
+    /// after it, in thousandths of text space units (positive moves left).
+    /// Zero adjustments are omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` - Hex-encoded strings paired with their trailing adjustment
+    fn show_text_hex_array(&mut self, parts: &[(String, f64)]) {
+        self.push_operator("[");
+        for (hex_text, adjustment) in parts {
+            self.push_formatted(format_args!("<{}>", hex_text));
+            if *adjustment != 0.0 {
+                self.push_formatted(format_args!(" {} ", adjustment));
+            }
+        }
+        self.push_operator("] TJ\n");
+    }
+
     /// Set fill color RGB (rg operator)
     ///
     /// # Arguments
@@ -169,6 +229,20 @@ pub trait ContentBuilder {
             x1, y1, x2, y2, x3, y3
         ));
     }
+
+    /// Concatenate a matrix onto the current transformation matrix (cm operator)
+    fn concat_matrix(&mut self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) {
+        self.push_formatted(format_args!("{} {} {} {} {} {} cm\n", a, b, c, d, e, f));
+    }
+
+    /// Paint an XObject from the page resources (Do operator)
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - XObject resource name (e.g., "Im1")
+    fn draw_xobject(&mut self, name: &str) {
+        self.push_formatted(format_args!("/{} Do\n", name));
+    }
 }
 
 /// Implementation of ContentBuilder for String
@@ -185,6 +259,236 @@ impl ContentBuilder for String {
     }
 }
 
+/// Text-showing operators (`Tj`, `TJ`, `'`, `"`)
+const TEXT_SHOW_OPERATORS: &[&str] = &["Tj", "TJ", "'", "\""];
+
+/// Path, painting, clipping, graphics state, color and XObject operators
+const GRAPHICS_OPERATORS: &[&str] = &[
+    "m", "l", "c", "v", "y", "h", "re", "S", "s", "f", "F", "f*", "B", "B*", "b", "b*", "n", "W",
+    "W*", "q", "Q", "cm", "w", "J", "j", "M", "d", "ri", "i", "gs", "CS", "cs", "SC", "SCN", "sc",
+    "scn", "G", "g", "RG", "rg", "K", "k", "Do", "sh",
+];
+
+/// Operator counts and size of a single content stream
+///
+/// Used to diagnose bloated output, e.g. a page showing thousands of
+/// individual `Tj` strings that could be batched.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::content_builder::{ContentBuilder, ContentStats};
+///
+/// let mut content = String::new();
+/// content.begin_text();
+/// content.show_text_hex("48656C6C6F");
+/// content.end_text();
+/// content.rectangle(0.0, 0.0, 10.0, 10.0);
+/// content.fill();
+///
+/// let stats = ContentStats::from_content(&content);
+/// assert_eq!(stats.text_show_ops, 1);
+/// assert_eq!(stats.graphics_ops, 2);
+/// assert_eq!(stats.stream_bytes, content.len());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContentStats {
+    /// Number of text-showing operators
+    pub text_show_ops: usize,
+    /// Number of path, painting, state, color and XObject operators
+    pub graphics_ops: usize,
+    /// Uncompressed length of the content stream in bytes
+    pub stream_bytes: usize,
+    /// `q`/`Q` nesting errors corrected before the stream was written (see
+    /// [`balance_graphics_state`])
+    pub corrected_imbalance: GraphicsStateImbalance,
+}
+
+impl ContentStats {
+    /// Count the operators in a content stream
+    ///
+    /// Operands (numbers, names, strings, arrays and dictionaries) and
+    /// comments are skipped; every remaining token is an operator.
+    pub fn from_content(content: &str) -> Self {
+        let mut stats = Self {
+            stream_bytes: content.len(),
+            ..Self::default()
+        };
+
+        for op in content_operators(content) {
+            if TEXT_SHOW_OPERATORS.contains(&op) {
+                stats.text_show_ops += 1;
+            } else if GRAPHICS_OPERATORS.contains(&op) {
+                stats.graphics_ops += 1;
+            }
+        }
+
+        stats
+    }
+}
+
+/// Split a content stream into its operator tokens
+fn content_operators(content: &str) -> Vec<&str> {
+    let bytes = content.as_bytes();
+    let is_delimiter = |b: u8| b"()<>[]{}/%".contains(&b) || b.is_ascii_whitespace();
+    let mut operators = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b if b.is_ascii_whitespace() => i += 1,
+            b'%' => {
+                while i < bytes.len() && bytes[i] != b'\n' && bytes[i] != b'\r' {
+                    i += 1;
+                }
+            }
+            b'(' => {
+                // Literal string: balanced parentheses, backslash escapes
+                let mut depth = 0;
+                while i < bytes.len() {
+                    match bytes[i] {
+                        b'\\' => i += 1,
+                        b'(' => depth += 1,
+                        b')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                i += 1;
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                    i += 1;
+                }
+            }
+            b'<' if bytes.get(i + 1) == Some(&b'<') => i += 2,
+            b'>' if bytes.get(i + 1) == Some(&b'>') => i += 2,
+            b'<' => {
+                // Hex string
+                while i < bytes.len() && bytes[i] != b'>' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'/' => {
+                // Name operand
+                i += 1;
+                while i < bytes.len() && !is_delimiter(bytes[i]) {
+                    i += 1;
+                }
+            }
+            b'[' | b']' | b'{' | b'}' | b'>' | b')' => i += 1,
+            _ => {
+                let start = i;
+                while i < bytes.len() && !is_delimiter(bytes[i]) {
+                    i += 1;
+                }
+                let token = &content[start..i];
+                let is_operand =
+                    token.parse::<f64>().is_ok() || matches!(token, "true" | "false" | "null");
+                if !is_operand {
+                    operators.push(token);
+                }
+            }
+        }
+    }
+
+    operators
+}
+
+/// Graphics state nesting errors found in a content stream
+///
+/// Every `q` must be closed by a `Q` within the same stream; readers may
+/// reject a page whose graphics state stack does not balance.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GraphicsStateImbalance {
+    /// `q` operators never closed by a `Q`
+    pub unclosed_saves: usize,
+    /// `Q` operators with no open `q` to restore
+    pub unmatched_restores: usize,
+}
+
+impl GraphicsStateImbalance {
+    /// Check the `q`/`Q` nesting of a content stream
+    pub fn of(content: &str) -> Self {
+        let mut imbalance = Self::default();
+        for op in content_operators(content) {
+            match op {
+                "q" => imbalance.unclosed_saves += 1,
+                "Q" if imbalance.unclosed_saves > 0 => imbalance.unclosed_saves -= 1,
+                "Q" => imbalance.unmatched_restores += 1,
+                _ => {}
+            }
+        }
+        imbalance
+    }
+
+    /// Whether every `q` is matched by a later `Q`
+    pub fn is_balanced(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Balance the graphics state nesting of a finished content stream
+///
+/// Each unmatched `Q` gets a `q` at the start of the stream and each unclosed
+/// `q` a trailing `Q`, so the stream always leaves the stack as it found it.
+/// A balanced stream is left untouched. Returns what was corrected.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::content_builder::{balance_graphics_state, ContentBuilder};
+///
+/// let mut content = String::new();
+/// content.save_graphics_state();
+/// content.rectangle(0.0, 0.0, 10.0, 10.0);
+/// content.fill();
+///
+/// let imbalance = balance_graphics_state(&mut content);
+/// assert_eq!(imbalance.unclosed_saves, 1);
+/// assert!(content.ends_with("Q\n"));
+/// ```
+pub fn balance_graphics_state(content: &mut String) -> GraphicsStateImbalance {
+    let imbalance = GraphicsStateImbalance::of(content);
+    if imbalance.unmatched_restores > 0 {
+        content.insert_str(0, &"q\n".repeat(imbalance.unmatched_restores));
+    }
+    for _ in 0..imbalance.unclosed_saves {
+        content.restore_graphics_state();
+    }
+    imbalance
+}
+
+/// Content stream statistics for every rendered page
+///
+/// Collected by [`PDFGenerator`](crate::PDFGenerator) when enabled with
+/// `set_collect_render_stats`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Statistics per page, in page order
+    pub pages: Vec<ContentStats>,
+}
+
+impl RenderStats {
+    /// Sum of the statistics over all pages
+    pub fn total(&self) -> ContentStats {
+        self.pages
+            .iter()
+            .fold(ContentStats::default(), |total, page| ContentStats {
+                text_show_ops: total.text_show_ops + page.text_show_ops,
+                graphics_ops: total.graphics_ops + page.graphics_ops,
+                stream_bytes: total.stream_bytes + page.stream_bytes,
+                corrected_imbalance: GraphicsStateImbalance {
+                    unclosed_saves: total.corrected_imbalance.unclosed_saves
+                        + page.corrected_imbalance.unclosed_saves,
+                    unmatched_restores: total.corrected_imbalance.unmatched_restores
+                        + page.corrected_imbalance.unmatched_restores,
+                },
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,6 +523,35 @@ mod tests {
         assert_eq!(content, "100 200 Td\n");
     }
 
+    #[test]
+    fn test_string_set_text_rise() {
+        let mut content = String::new();
+        content.set_text_rise(-14.0);
+        assert_eq!(content, "-14 Ts\n");
+    }
+
+    #[test]
+    fn test_string_set_text_rendering_mode() {
+        let mut content = String::new();
+        content.set_text_rendering_mode(2);
+        assert_eq!(content, "2 Tr\n");
+    }
+
+    #[test]
+    fn test_string_set_character_spacing() {
+        let mut content = String::new();
+        content.set_character_spacing(0.2);
+        assert_eq!(content, "0.2 Tc\n");
+    }
+
+    #[test]
+    fn test_string_save_restore_graphics_state() {
+        let mut content = String::new();
+        content.save_graphics_state();
+        content.restore_graphics_state();
+        assert_eq!(content, "q\nQ\n");
+    }
+
     #[test]
     fn test_string_show_text_hex() {
         let mut content = String::new();
@@ -226,6 +559,17 @@ mod tests {
         assert_eq!(content, "<0048656C6C6F> Tj\n");
     }
 
+    #[test]
+    fn test_string_show_text_hex_array() {
+        let mut content = String::new();
+        content.show_text_hex_array(&[
+            ("0041".to_string(), 0.0),
+            ("0042".to_string(), -20.0),
+            ("0043".to_string(), 0.0),
+        ]);
+        assert_eq!(content, "[<0041><0042> -20 <0043>] TJ\n");
+    }
+
     #[test]
     fn test_string_set_fill_color() {
         let mut content = String::new();
@@ -296,6 +640,14 @@ mod tests {
         assert_eq!(content, "1 2 3 4 5 6 c\n");
     }
 
+    #[test]
+    fn test_string_concat_matrix_and_draw_xobject() {
+        let mut content = String::new();
+        content.concat_matrix(100.0, 0.0, 0.0, 50.0, 10.0, 20.0);
+        content.draw_xobject("Im1");
+        assert_eq!(content, "100 0 0 50 10 20 cm\n/Im1 Do\n");
+    }
+
     #[test]
     fn test_complete_text_rendering_workflow() {
         let mut content = String::new();
@@ -313,4 +665,50 @@ mod tests {
         assert!(content.contains("<48656C6C6F> Tj"));
         assert!(content.contains("ET"));
     }
+
+    #[test]
+    fn test_balance_graphics_state_corrects_imbalance() {
+        let mut content = String::new();
+        content.restore_graphics_state();
+        content.save_graphics_state();
+        content.save_graphics_state();
+        content.concat_matrix(0.0, 1.0, -1.0, 0.0, 0.0, 0.0);
+        content.restore_graphics_state();
+
+        let imbalance = balance_graphics_state(&mut content);
+        assert_eq!(
+            imbalance,
+            GraphicsStateImbalance {
+                unclosed_saves: 1,
+                unmatched_restores: 1,
+            }
+        );
+        assert_eq!(content, "q\nQ\nq\nq\n0 1 -1 0 0 0 cm\nQ\nQ\n");
+        assert!(GraphicsStateImbalance::of(&content).is_balanced());
+    }
+
+    #[test]
+    fn test_balance_graphics_state_keeps_balanced_stream() {
+        let mut content = String::new();
+        content.save_graphics_state();
+        content.begin_text();
+        // A literal string operand is not an operator
+        content.push_operator("(q Q Q) Tj\n");
+        content.end_text();
+        content.restore_graphics_state();
+        let before = content.clone();
+
+        assert!(balance_graphics_state(&mut content).is_balanced());
+        assert_eq!(content, before);
+    }
+
+    #[test]
+    fn test_content_stats_skip_operands() {
+        let content = "% comment Tj\nBT\n/F1 12 Tf\n[(a) -20 (b\\) Tj)] TJ\n(x) '\nET\n\
+                       q /GS1 gs 0.5 g 0 0 5 5 re f Q\n";
+        let stats = ContentStats::from_content(content);
+        assert_eq!(stats.text_show_ops, 2);
+        assert_eq!(stats.graphics_ops, 6);
+        assert_eq!(stats.stream_bytes, content.len());
+    }
 }
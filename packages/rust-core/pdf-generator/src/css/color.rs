@@ -4,7 +4,9 @@
 //! - Hex colors: #RGB, #RRGGBB, #RRGGBBAA
 //! - RGB/RGBA: rgb(r, g, b), rgba(r, g, b, a)
 //! - Named colors: black, white, red, etc.
+//! - `var(--name, fallback)`: resolved to the fallback
 
+use super::converter::resolve_var_fallback;
 use crate::css_parser::CSSParseError;
 use layout_types::Color;
 
@@ -15,8 +17,13 @@ use layout_types::Color;
 /// - RGB: rgb(r, g, b)
 /// - RGBA: rgba(r, g, b, a)
 /// - Named colors: black, white, red, green, blue, gray, etc.
+/// - Custom properties: `var(--name, fallback)` parses the fallback
+///
+/// `currentColor` depends on the element's text color and is not handled
+/// here; see [`is_current_color`].
 pub fn parse_color(color_str: &str) -> Result<Color, CSSParseError> {
-    let trimmed = color_str.trim();
+    let trimmed = resolve_var_fallback(color_str)
+        .ok_or_else(|| CSSParseError::InvalidColor(color_str.to_string()))?;
 
     // Handle hex colors: #RGB, #RRGGBB, #RRGGBBAA
     if trimmed.starts_with('#') {
@@ -32,6 +39,11 @@ pub fn parse_color(color_str: &str) -> Result<Color, CSSParseError> {
     parse_named_color(trimmed)
 }
 
+/// Check for the `currentColor` keyword (case-insensitive)
+pub fn is_current_color(value: &str) -> bool {
+    value.trim().eq_ignore_ascii_case("currentcolor")
+}
+
 /// Parse hex color
 fn parse_hex_color(hex_str: &str) -> Result<Color, CSSParseError> {
     let hex = hex_str.trim_start_matches('#');
@@ -254,6 +266,23 @@ fn parse_named_color(name: &str) -> Result<Color, CSSParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_color_var_fallback() {
+        assert_eq!(parse_color("var(--x, red)").unwrap(), Color::rgb(255, 0, 0));
+        assert_eq!(
+            parse_color("var(--muted, #333)").unwrap(),
+            Color::rgb(51, 51, 51)
+        );
+        assert!(parse_color("var(--x)").is_err());
+    }
+
+    #[test]
+    fn test_is_current_color() {
+        assert!(is_current_color("currentColor"));
+        assert!(is_current_color(" currentcolor "));
+        assert!(!is_current_color("red"));
+    }
+
     #[test]
     fn test_parse_hex_color_3_digit() {
         let color = parse_color("#f00").unwrap();
@@ -1,10 +1,136 @@
 //! CSS unit conversion module
 //!
 //! Converts CSS length units to PDF points (72 points = 1 inch).
-//! Supports px, pt, em, rem, %, and unitless values.
+//! Supports px, pt, em, rem, %, vw, vh, unitless values, and the
+//! `min()`, `max()` and `clamp()` comparison functions.
 
 use crate::css_parser::CSSParseError;
-use layout_types::DEFAULT_FONT_SIZE;
+use layout_types::{CssLength, DEFAULT_FONT_SIZE};
+
+/// US Letter page size in points that `vw`/`vh` resolve against
+const VIEWPORT_WIDTH: f64 = 612.0;
+const VIEWPORT_HEIGHT: f64 = 792.0;
+
+/// Evaluate `min()`, `max()` and `clamp(lo, val, hi)` (= `max(lo, min(val, hi))`)
+///
+/// Arguments may nest further calls. Absolute arguments are compared right
+/// away; a percentage cannot be, as the containing block is not known yet, so
+/// at most one percentage argument is kept as a [`CssLength::Clamped`]
+/// between the absolute ones for the layout engine to resolve.
+fn evaluate_math_function(name: &str, args: &str, value: &str) -> Result<CssLength, CSSParseError> {
+    let invalid = || CSSParseError::InvalidValue(value.to_string());
+    let args = split_top_level_args(args)
+        .into_iter()
+        .map(|arg| {
+            let arg = arg.trim();
+            if let Some((name, args)) = split_math_function(arg) {
+                evaluate_math_function(name, args, value)
+            } else if arg.ends_with('%') {
+                css_to_length(arg)
+            } else {
+                css_to_points(arg).map(CssLength::Points)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let points = |length: &CssLength| match length {
+        CssLength::Points(points) => Some(*points),
+        _ => None,
+    };
+    let absolute = args.iter().filter_map(points);
+    let relative = match args
+        .iter()
+        .filter(|a| points(a).is_none())
+        .collect::<Vec<_>>()[..]
+    {
+        [] => None,
+        [length] => Some(*length),
+        _ => return Err(invalid()),
+    };
+
+    match (name, args.as_slice()) {
+        ("min", [_, ..]) => {
+            let bound = absolute.reduce(f64::min);
+            Ok(match (relative, bound) {
+                (Some(length), Some(max)) => clamp_length(length, None, Some(max)),
+                (Some(length), None) => length,
+                (None, bound) => CssLength::Points(bound.ok_or_else(invalid)?),
+            })
+        }
+        ("max", [_, ..]) => {
+            let bound = absolute.reduce(f64::max);
+            Ok(match (relative, bound) {
+                (Some(length), Some(min)) => clamp_length(length, Some(min), None),
+                (Some(length), None) => length,
+                (None, bound) => CssLength::Points(bound.ok_or_else(invalid)?),
+            })
+        }
+        ("clamp", [lo, val, hi]) => match (points(lo), points(hi)) {
+            (Some(lo), Some(hi)) => Ok(clamp_length(*val, Some(lo), Some(hi))),
+            _ => Err(invalid()),
+        },
+        _ => Err(invalid()),
+    }
+}
+
+/// `max(min, min(length, max))`, folding the bounds into a clamped percentage
+fn clamp_length(length: CssLength, min: Option<f64>, max: Option<f64>) -> CssLength {
+    let (lower, upper) = (
+        min.unwrap_or(f64::NEG_INFINITY),
+        max.unwrap_or(f64::INFINITY),
+    );
+    match length {
+        CssLength::Percent(percent) => CssLength::Clamped { min, percent, max },
+        CssLength::Clamped {
+            min: inner_min,
+            percent,
+            max: inner_max,
+        } => {
+            // min() distributes over max(), so the outer bounds fold into the inner ones
+            let max = match (inner_max, max) {
+                (Some(inner), Some(outer)) => Some(inner.min(outer)),
+                (inner, outer) => inner.or(outer),
+            };
+            let min = match (inner_min.map(|m| m.min(upper)), min) {
+                (Some(inner), Some(outer)) => Some(inner.max(outer)),
+                (inner, outer) => inner.or(outer),
+            };
+            CssLength::Clamped { min, percent, max }
+        }
+        other => CssLength::Points(other.resolve(0.0).min(upper).max(lower)),
+    }
+}
+
+/// Split `min(...)`, `max(...)` or `clamp(...)` into its name and argument list
+fn split_math_function(value: &str) -> Option<(&str, &str)> {
+    ["min", "max", "clamp"].into_iter().find_map(|name| {
+        let args = value
+            .strip_prefix(name)?
+            .strip_prefix('(')?
+            .strip_suffix(')')?;
+        Some((name, args))
+    })
+}
+
+/// Split function arguments on commas outside nested parentheses
+fn split_top_level_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, ch) in args.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&args[start..]);
+    parts
+}
 
 /// Convert CSS length value to PDF points (72 points = 1 inch)
 ///
@@ -14,11 +140,33 @@ use layout_types::DEFAULT_FONT_SIZE;
 /// - em: relative to font size (1em = 12pt default per W3C)
 /// - rem: relative to root font size (1rem = 12pt default per W3C)
 /// - %: percentage (converted based on context, default 12pt base)
+/// - vw/vh: percentage of a US Letter page
 /// - unitless: treated as pixels
+///
+/// `min()`, `max()` and `clamp()` are evaluated when all their arguments are
+/// absolute or viewport lengths; use [`css_to_length`] when they compare
+/// against a percentage.
 pub fn css_to_points(value: &str) -> Result<f64, CSSParseError> {
     let trimmed = value.trim();
 
-    if trimmed.ends_with("px") {
+    if let Some((name, args)) = split_math_function(trimmed) {
+        match evaluate_math_function(name, args, value)? {
+            CssLength::Points(points) => Ok(points),
+            _ => Err(CSSParseError::InvalidValue(value.to_string())),
+        }
+    } else if trimmed.ends_with("vw") {
+        let num = trimmed
+            .trim_end_matches("vw")
+            .parse::<f64>()
+            .map_err(|_| CSSParseError::InvalidValue(value.to_string()))?;
+        Ok(num * VIEWPORT_WIDTH / 100.0)
+    } else if trimmed.ends_with("vh") {
+        let num = trimmed
+            .trim_end_matches("vh")
+            .parse::<f64>()
+            .map_err(|_| CSSParseError::InvalidValue(value.to_string()))?;
+        Ok(num * VIEWPORT_HEIGHT / 100.0)
+    } else if trimmed.ends_with("px") {
         let num = trimmed
             .trim_end_matches("px")
             .parse::<f64>()
@@ -58,10 +206,79 @@ pub fn css_to_points(value: &str) -> Result<f64, CSSParseError> {
     }
 }
 
+/// Convert CSS length value to a [`CssLength`]
+///
+/// Used for properties whose percentages refer to the containing block
+/// (width, max-width, margin). Percentages are preserved so the layout
+/// engine can resolve them against the parent's content width, and `em`
+/// lengths so it can resolve them against the element's own font size.
+/// `min()`/`max()`/`clamp()` comparing a percentage with absolute lengths
+/// become a [`CssLength::Clamped`]; all other units are converted with
+/// [`css_to_points`].
+pub fn css_to_length(value: &str) -> Result<CssLength, CSSParseError> {
+    let trimmed = value.trim();
+
+    if let Some((name, args)) = split_math_function(trimmed) {
+        evaluate_math_function(name, args, value)
+    } else if let Some(num) = trimmed.strip_suffix('%') {
+        let percent = num
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| CSSParseError::InvalidValue(value.to_string()))?;
+        Ok(CssLength::Percent(percent))
+    } else if let Some(num) = trimmed.strip_suffix("em").filter(|n| !n.ends_with('r')) {
+        let em = num
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| CSSParseError::InvalidValue(value.to_string()))?;
+        Ok(CssLength::Em(em))
+    } else {
+        css_to_points(trimmed).map(CssLength::Points)
+    }
+}
+
+/// Resolve a CSS custom property reference to its fallback value
+///
+/// Custom properties are not tracked, so `var(--name, fallback)` always
+/// resolves to `fallback` (itself resolved, for nested `var()`). Returns
+/// `None` for a `var()` without fallback; other values pass through unchanged.
+pub fn resolve_var_fallback(value: &str) -> Option<&str> {
+    let trimmed = value.trim();
+    let Some(args) = trimmed
+        .strip_prefix("var(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    else {
+        return Some(trimmed);
+    };
+
+    // The fallback starts after the first top-level comma and may itself contain commas
+    let mut depth = 0;
+    for (i, ch) in args.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => return resolve_var_fallback(&args[i + 1..]),
+            _ => {}
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_resolve_var_fallback() {
+        assert_eq!(resolve_var_fallback("var(--accent, #333)"), Some("#333"));
+        assert_eq!(
+            resolve_var_fallback("var(--a, var(--b, rgb(1, 2, 3)))"),
+            Some("rgb(1, 2, 3)")
+        );
+        assert_eq!(resolve_var_fallback("var(--accent)"), None);
+        assert_eq!(resolve_var_fallback(" 12pt "), Some("12pt"));
+    }
+
     #[test]
     fn test_css_to_points_px() {
         assert_eq!(css_to_points("16px").unwrap(), 12.0);
@@ -87,6 +304,86 @@ mod tests {
         assert_eq!(css_to_points("50%").unwrap(), 0.5 * DEFAULT_FONT_SIZE);
     }
 
+    #[test]
+    fn test_css_to_length_percentage() {
+        assert_eq!(css_to_length("50%").unwrap(), CssLength::Percent(50.0));
+        assert_eq!(css_to_length(" 100% ").unwrap(), CssLength::Percent(100.0));
+    }
+
+    #[test]
+    fn test_css_to_length_absolute_units() {
+        assert_eq!(css_to_length("16px").unwrap(), CssLength::Points(12.0));
+        assert_eq!(css_to_length("2rem").unwrap(), CssLength::Points(24.0));
+        assert!(css_to_length("abc%").is_err());
+    }
+
+    #[test]
+    fn test_css_to_points_evaluates_functions() {
+        // 600px = 450pt
+        assert_eq!(css_to_points("min(600px, 400pt)").unwrap(), 400.0);
+        assert_eq!(css_to_points("max(600px, 400pt)").unwrap(), 450.0);
+        assert_eq!(
+            css_to_length("min(600px, 400pt)").unwrap(),
+            CssLength::Points(400.0)
+        );
+    }
+
+    #[test]
+    fn test_css_to_points_clamp_with_viewport_units() {
+        // 2vw of a 612pt page = 12.24pt, between 9pt and 13.5pt
+        let size = css_to_points("clamp(12px, 2vw, 18px)").unwrap();
+        assert!((size - 12.24).abs() < 1e-9);
+
+        // Below the minimum and above the maximum
+        assert_eq!(css_to_points("clamp(12px, 1vw, 18px)").unwrap(), 9.0);
+        assert_eq!(css_to_points("clamp(12px, 5vw, 18px)").unwrap(), 13.5);
+        assert_eq!(css_to_points("min(max(10pt, 1vh), 20pt)").unwrap(), 10.0);
+    }
+
+    #[test]
+    fn test_css_to_points_invalid_function() {
+        assert!(css_to_points("clamp(1px, 2px)").is_err());
+        assert!(css_to_points("min()").is_err());
+        assert!(css_to_points("min(1px, abc)").is_err());
+        // Points need a known containing block to compare percentages against
+        assert!(css_to_points("min(600px, 90%)").is_err());
+        // Only one percentage can be kept between the absolute bounds
+        assert!(css_to_length("min(50%, 90%)").is_err());
+        assert!(css_to_length("clamp(10%, 300px, 90%)").is_err());
+    }
+
+    #[test]
+    fn test_css_to_length_defers_percentage_functions() {
+        // 600px = 450pt, 90% of a 400pt container = 360pt
+        let width = css_to_length("min(600px, 90%)").unwrap();
+        assert_eq!(
+            width,
+            CssLength::Clamped {
+                min: None,
+                percent: 90.0,
+                max: Some(450.0)
+            }
+        );
+        assert_eq!(width.resolve(400.0), 360.0);
+        assert_eq!(width.resolve(600.0), 450.0);
+
+        let width = css_to_length("clamp(100pt, 50%, 300pt)").unwrap();
+        assert_eq!(width.resolve(100.0), 100.0);
+        assert_eq!(width.resolve(400.0), 200.0);
+        assert_eq!(width.resolve(800.0), 300.0);
+
+        // Nested bounds fold into one clamp: max(100pt, min(50%, 300pt))
+        let nested = css_to_length("max(100pt, min(50%, 300pt))").unwrap();
+        assert_eq!(nested, width);
+    }
+
+    #[test]
+    fn test_css_to_length_keeps_em() {
+        assert_eq!(css_to_length("1.5em").unwrap(), CssLength::Em(1.5));
+        assert_eq!(css_to_length("2rem").unwrap(), CssLength::Points(24.0));
+        assert!(css_to_length("abcem").is_err());
+    }
+
     #[test]
     fn test_css_to_points_unitless() {
         assert_eq!(css_to_points("16").unwrap(), 12.0); // Treated as px
@@ -17,5 +17,5 @@ pub mod parser;
 
 // Re-export main parsing functions for backward compatibility
 pub use color::parse_color;
-pub use converter::css_to_points;
-pub use parser::parse_inline_styles;
+pub use converter::{css_to_length, css_to_points};
+pub use parser::{parse_declared_styles, parse_inline_styles};
@@ -4,12 +4,13 @@
 //! Handles individual CSS properties and their values, delegating to specialized
 //! parsers for colors and unit conversions.
 
-use super::color::parse_color;
-use super::converter::css_to_points;
+use super::color::{is_current_color, parse_color};
+use super::converter::{css_to_length, css_to_points, resolve_var_fallback};
 use crate::css_parser::CSSParseError;
 use layout_types::{
-    BorderLineStyle, BorderStyle, Display, FlexDirection, FontStyle, FontWeight, JustifyContent,
-    Spacing, StyleDeclaration, TextAlign, TextTransform, DEFAULT_FONT_SIZE,
+    BorderLineStyle, BorderStyle, BoxSizing, Color, CssLength, Display, FlexDirection, FlexWrap,
+    FontStyle, FontWeight, Inset, JustifyContent, ListStyleType, Position, Spacing,
+    StyleDeclaration, TextAlign, TextShadow, TextTransform, DEFAULT_FONT_SIZE,
 };
 
 /// Parse inline style attribute to StyleDeclaration
@@ -23,12 +24,50 @@ use layout_types::{
 /// # Returns
 /// A StyleDeclaration with parsed values, or an error if parsing fails
 pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSParseError> {
-    let mut decl = StyleDeclaration::default();
+    parse_styles_onto(StyleDeclaration::default(), style_attr)
+}
+
+/// Parse only the declared properties, leaving every other property `None`
+///
+/// Unlike [`parse_inline_styles`], undeclared text properties are not filled
+/// with defaults, so the result can be layered over another style without
+/// resetting it.
+pub fn parse_declared_styles(style_attr: &str) -> Result<StyleDeclaration, CSSParseError> {
+    parse_styles_onto(StyleDeclaration::new(), style_attr)
+}
 
+fn parse_styles_onto(
+    mut decl: StyleDeclaration,
+    style_attr: &str,
+) -> Result<StyleDeclaration, CSSParseError> {
     if style_attr.trim().is_empty() {
         return Ok(decl);
     }
 
+    // An `!important` declaration beats later normal ones for the same property
+    let important: Vec<String> = style_attr
+        .split(';')
+        .filter_map(|declaration| declaration.split_once(':'))
+        .filter_map(|(property, value)| match strip_important(value) {
+            (value, true) => Some(format!("{}: {}", property.trim().to_lowercase(), value)),
+            (_, false) => None,
+        })
+        .collect();
+    let is_important = |property: &str| {
+        important
+            .iter()
+            .any(|declaration| declaration.split_once(':').map(|(name, _)| name) == Some(property))
+    };
+
+    // `currentColor` in other properties refers to this element's own `color`
+    let current_color = style_attr
+        .rsplit(';')
+        .filter_map(|declaration| declaration.split_once(':'))
+        .filter(|(property, _)| property.trim().eq_ignore_ascii_case("color"))
+        .filter(|(_, value)| strip_important(value).1 || !is_important("color"))
+        .filter_map(|(_, value)| resolve_var_fallback(strip_important(value).0))
+        .find(|value| parse_color(value).is_ok());
+
     // Split by semicolons and parse each declaration
     for declaration in style_attr.split(';') {
         let declaration = declaration.trim();
@@ -43,7 +82,29 @@ pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSPars
         }
 
         let property = parts[0].trim().to_lowercase();
-        let value = parts[1].trim();
+        let (value, important) = strip_important(parts[1]);
+        if !important && is_important(&property) {
+            continue;
+        }
+        // Custom properties aren't tracked; a var() without fallback is dropped
+        let Some(value) = resolve_var_fallback(value) else {
+            continue;
+        };
+        let substituted;
+        let value = if property != "color" && value.split_whitespace().any(is_current_color) {
+            // Without an own color the keyword is dropped, leaving the default
+            substituted = value
+                .split_whitespace()
+                .filter_map(|token| match is_current_color(token) {
+                    true => current_color.map(str::trim),
+                    false => Some(token),
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            substituted.as_str()
+        } else {
+            value
+        };
 
         // Parse based on property name with error recovery
         // Skip invalid property values instead of failing the entire style string
@@ -64,7 +125,10 @@ pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSPars
                 decl.text.font_style = Some(parse_font_style(value));
             }
             "color" => {
-                if let Ok(color) = parse_color(value) {
+                if is_current_color(value) {
+                    // `color: currentColor` is the inherited color
+                    decl.text.color = None;
+                } else if let Ok(color) = parse_color(value) {
                     decl.text.color = Some(color);
                 }
                 // Skip invalid colors
@@ -78,6 +142,10 @@ pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSPars
             "text-align" => {
                 decl.text.text_align = Some(parse_text_align(value));
             }
+            // `auto` leaves the last line to follow text-align
+            "text-align-last" if !value.trim().eq_ignore_ascii_case("auto") => {
+                decl.text.text_align_last = Some(parse_text_align(value));
+            }
             "line-height" => {
                 if let Ok(height) = parse_line_height(value, decl.text.font_size) {
                     decl.text.line_height = Some(height);
@@ -91,19 +159,15 @@ pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSPars
                 // Skip invalid values
             }
             "margin" => {
-                if let Ok(margin) = parse_spacing(value) {
-                    decl.box_model.margin = Some(margin);
-                }
                 // Skip invalid values
+                let _ = apply_margin(&mut decl, value);
             }
             "padding" => {
-                if let Ok(padding) = parse_spacing(value) {
-                    decl.box_model.padding = Some(padding);
-                }
                 // Skip invalid values
+                let _ = apply_padding(&mut decl, value);
             }
             "width" => {
-                if let Ok(width) = css_to_points(value) {
+                if let Ok(width) = css_to_length(value) {
                     decl.box_model.width = Some(width);
                 }
                 // Skip invalid values
@@ -115,7 +179,7 @@ pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSPars
                 // Skip invalid values
             }
             "max-width" => {
-                if let Ok(max_width) = css_to_points(value) {
+                if let Ok(max_width) = css_to_unclamped_length(value) {
                     decl.box_model.max_width = Some(max_width);
                 }
                 // Skip invalid values
@@ -130,16 +194,41 @@ pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSPars
                 decl.flex.display = Some(parse_display(value));
             }
             "flex" => {
-                decl.flex.flex = Some(parse_flex(value)?);
+                let (grow, shrink, basis) = parse_flex(value)?;
+                decl.flex.flex = Some(grow);
+                decl.flex.flex_shrink = Some(shrink);
+                decl.flex.flex_basis = basis;
+            }
+            "flex-grow" => {
+                if let Ok(v) = value.trim().parse::<f64>() {
+                    decl.flex.flex = Some(v);
+                }
             }
             "flex-shrink" => {
                 if let Ok(v) = value.trim().parse::<f64>() {
                     decl.flex.flex_shrink = Some(v);
                 }
             }
+            "flex-basis" => {
+                if let Ok(basis) = css_to_unclamped_length(value) {
+                    decl.flex.flex_basis = Some(basis);
+                }
+                // `auto`, `content` and invalid values leave the basis unset (auto)
+            }
             "flex-direction" => {
                 decl.flex.flex_direction = Some(parse_flex_direction(value));
             }
+            "flex-wrap" => {
+                decl.flex.flex_wrap = Some(parse_flex_wrap(value));
+            }
+            // Shorthand for all four sides; later per-side declarations override it
+            "border" => {
+                let border = parse_border_bottom(value)?;
+                decl.box_model.border_top = Some(border.clone());
+                decl.box_model.border_right = Some(border.clone());
+                decl.box_model.border_bottom = Some(border.clone());
+                decl.box_model.border_left = Some(border);
+            }
             "border-bottom" => {
                 decl.box_model.border_bottom = Some(parse_border_bottom(value)?);
             }
@@ -265,45 +354,31 @@ pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSPars
             }
             // Specific margin properties
             "margin-top" => {
-                let mut margin = decl.box_model.margin.unwrap_or_default();
-                margin.top = css_to_points(value)?;
-                decl.box_model.margin = Some(margin);
+                apply_margin_side(&mut decl, css_to_unclamped_length(value)?, |s| &mut s.top);
             }
             "margin-right" => {
-                let mut margin = decl.box_model.margin.unwrap_or_default();
-                margin.right = css_to_points(value)?;
-                decl.box_model.margin = Some(margin);
+                apply_margin_side(&mut decl, css_to_unclamped_length(value)?, |s| &mut s.right);
             }
             "margin-bottom" => {
-                let mut margin = decl.box_model.margin.unwrap_or_default();
-                margin.bottom = css_to_points(value)?;
-                decl.box_model.margin = Some(margin);
+                apply_margin_side(&mut decl, css_to_unclamped_length(value)?, |s| {
+                    &mut s.bottom
+                });
             }
             "margin-left" => {
-                let mut margin = decl.box_model.margin.unwrap_or_default();
-                margin.left = css_to_points(value)?;
-                decl.box_model.margin = Some(margin);
+                apply_margin_side(&mut decl, css_to_unclamped_length(value)?, |s| &mut s.left);
             }
             // Specific padding properties
             "padding-top" => {
-                let mut padding = decl.box_model.padding.unwrap_or_default();
-                padding.top = css_to_points(value)?;
-                decl.box_model.padding = Some(padding);
+                apply_padding_side(&mut decl, value, |s| &mut s.top)?;
             }
             "padding-right" => {
-                let mut padding = decl.box_model.padding.unwrap_or_default();
-                padding.right = css_to_points(value)?;
-                decl.box_model.padding = Some(padding);
+                apply_padding_side(&mut decl, value, |s| &mut s.right)?;
             }
             "padding-bottom" => {
-                let mut padding = decl.box_model.padding.unwrap_or_default();
-                padding.bottom = css_to_points(value)?;
-                decl.box_model.padding = Some(padding);
+                apply_padding_side(&mut decl, value, |s| &mut s.bottom)?;
             }
             "padding-left" => {
-                let mut padding = decl.box_model.padding.unwrap_or_default();
-                padding.left = css_to_points(value)?;
-                decl.box_model.padding = Some(padding);
+                apply_padding_side(&mut decl, value, |s| &mut s.left)?;
             }
             "gap" => {
                 if let Ok(gap_value) = css_to_points(value) {
@@ -327,15 +402,112 @@ pub fn parse_inline_styles(style_attr: &str) -> Result<StyleDeclaration, CSSPars
                     decl.flex.column_gap = Some(gap_value);
                 }
             }
+            "column-count" => {
+                // Multi-column text flow; "auto" and invalid counts are ignored
+                if let Ok(count) = value.trim().parse::<u32>() {
+                    if count >= 1 {
+                        decl.box_model.column_count = Some(count);
+                    }
+                }
+            }
+            "box-sizing" => {
+                decl.box_model.box_sizing = match value.trim() {
+                    "border-box" => Some(BoxSizing::BorderBox),
+                    "content-box" => Some(BoxSizing::ContentBox),
+                    _ => decl.box_model.box_sizing,
+                };
+            }
+            "position" => {
+                // "sticky" and "fixed" are not supported and leave the box in flow
+                decl.box_model.position = match value.trim() {
+                    "static" => Some(Position::Static),
+                    "relative" => Some(Position::Relative),
+                    "absolute" => Some(Position::Absolute),
+                    _ => decl.box_model.position,
+                };
+            }
+            "top" | "right" | "bottom" | "left" => {
+                if let Some(length) = parse_inset_side(value) {
+                    let inset = decl.box_model.inset.get_or_insert_with(Inset::default);
+                    let side = match property.as_str() {
+                        "top" => &mut inset.top,
+                        "right" => &mut inset.right,
+                        "bottom" => &mut inset.bottom,
+                        _ => &mut inset.left,
+                    };
+                    *side = length;
+                }
+            }
+            "inset" => {
+                if let Ok(sides) = expand_box_shorthand(value) {
+                    if let [Some(top), Some(right), Some(bottom), Some(left)] =
+                        sides.map(parse_inset_side)
+                    {
+                        decl.box_model.inset = Some(Inset {
+                            top,
+                            right,
+                            bottom,
+                            left,
+                        });
+                    }
+                }
+            }
+            "text-shadow" => {
+                if value.trim().eq_ignore_ascii_case("none") {
+                    decl.text.text_shadow = None;
+                } else if let Some(shadow) = parse_text_shadow(value) {
+                    decl.text.text_shadow = Some(shadow);
+                }
+            }
+            "list-style-type" | "list-style" => {
+                // The shorthand may also carry a position or image; only the type is used
+                if let Some(list_style_type) =
+                    value.split_whitespace().find_map(parse_list_style_type)
+                {
+                    decl.text.list_style_type = Some(list_style_type);
+                }
+            }
+            "orphans" | "widows" => {
+                // Per-block pagination thresholds; zero and non-integers are ignored
+                if let Ok(lines) = value.trim().parse::<usize>() {
+                    if lines >= 1 {
+                        if property == "orphans" {
+                            decl.text.orphans = Some(lines);
+                        } else {
+                            decl.text.widows = Some(lines);
+                        }
+                    }
+                }
+            }
+            "-webkit-line-clamp" | "line-clamp" => {
+                // Multi-line truncation; "none" and invalid counts are ignored
+                if let Ok(lines) = value.trim().parse::<usize>() {
+                    if lines >= 1 {
+                        decl.text.line_clamp = Some(lines);
+                    }
+                }
+            }
             _ => {
                 // Ignore unknown properties
             }
         }
     }
 
+    decl.important = important;
     Ok(decl)
 }
 
+/// Split a trailing `!important` flag off a declaration value
+fn strip_important(value: &str) -> (&str, bool) {
+    let value = value.trim();
+    match value.rsplit_once('!') {
+        Some((rest, flag)) if flag.trim().eq_ignore_ascii_case("important") => {
+            (rest.trim_end(), true)
+        }
+        _ => (value, false),
+    }
+}
+
 /// Parse font-family CSS property
 fn parse_font_family(value: &str) -> String {
     // Remove quotes and take first font family
@@ -418,12 +590,45 @@ fn parse_display(value: &str) -> Display {
     }
 }
 
-/// Parse flex property (flex-grow value)
-fn parse_flex(value: &str) -> Result<f64, CSSParseError> {
-    value
-        .trim()
-        .parse::<f64>()
-        .map_err(|_| CSSParseError::InvalidValue(value.to_string()))
+/// Parse the flex shorthand into (grow, shrink, basis)
+///
+/// Follows the CSS one-, two- and three-value forms: a lone number sets grow
+/// with a `0%` basis (`flex: 1` → `1 1 0%`), a lone length sets the basis with
+/// grow 1, and `none`/`auto`/`initial` map to their keyword values. A `None`
+/// basis means `auto`.
+fn parse_flex(value: &str) -> Result<(f64, f64, Option<CssLength>), CSSParseError> {
+    let invalid = || CSSParseError::InvalidValue(value.to_string());
+    let number = |part: &str| part.parse::<f64>().ok().filter(|n| *n >= 0.0);
+    let basis = |part: &str| match part {
+        "auto" | "content" => Ok(None),
+        _ => css_to_unclamped_length(part)
+            .map(Some)
+            .map_err(|_| invalid()),
+    };
+
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    match parts.as_slice() {
+        ["none"] => Ok((0.0, 0.0, None)),
+        ["auto"] => Ok((1.0, 1.0, None)),
+        ["initial"] => Ok((0.0, 1.0, None)),
+        [single] => match number(single) {
+            Some(grow) => Ok((grow, 1.0, Some(CssLength::Percent(0.0)))),
+            None => Ok((1.0, 1.0, basis(single)?)),
+        },
+        [grow, second] => {
+            let grow = number(grow).ok_or_else(invalid)?;
+            match number(second) {
+                Some(shrink) => Ok((grow, shrink, Some(CssLength::Percent(0.0)))),
+                None => Ok((grow, 1.0, basis(second)?)),
+            }
+        }
+        [grow, shrink, flex_basis] => Ok((
+            number(grow).ok_or_else(invalid)?,
+            number(shrink).ok_or_else(invalid)?,
+            basis(flex_basis)?,
+        )),
+        _ => Err(invalid()),
+    }
 }
 
 /// Parse flex-direction CSS property
@@ -436,6 +641,15 @@ fn parse_flex_direction(value: &str) -> FlexDirection {
     }
 }
 
+/// Parse flex-wrap CSS property
+fn parse_flex_wrap(value: &str) -> FlexWrap {
+    match value.trim().to_lowercase().as_str() {
+        "wrap" => FlexWrap::Wrap,
+        "wrap-reverse" => FlexWrap::WrapReverse,
+        _ => FlexWrap::NoWrap,
+    }
+}
+
 /// Parse border-bottom CSS property
 /// Supports formats: "1px solid #000" or "2px dashed red"
 fn parse_border_bottom(value: &str) -> Result<BorderStyle, CSSParseError> {
@@ -488,6 +702,65 @@ fn parse_border_bottom(value: &str) -> Result<BorderStyle, CSSParseError> {
     })
 }
 
+/// Parse a list-style-type keyword; unsupported types yield `None`
+fn parse_list_style_type(value: &str) -> Option<ListStyleType> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "disc" => Some(ListStyleType::Disc),
+        "circle" => Some(ListStyleType::Circle),
+        "square" => Some(ListStyleType::Square),
+        "decimal" => Some(ListStyleType::Decimal),
+        "none" => Some(ListStyleType::None),
+        _ => None,
+    }
+}
+
+/// Parse the first shadow of a text-shadow value: `<x> <y> [<blur>] [<color>]`
+///
+/// The color may come first or last and defaults to black.
+fn parse_text_shadow(value: &str) -> Option<TextShadow> {
+    // Only the first of a comma-separated shadow list is used; commas inside
+    // rgb() belong to the color
+    let mut depth = 0;
+    let first = value
+        .split(|ch| {
+            match ch {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            ch == ',' && depth == 0
+        })
+        .next()?;
+
+    let mut lengths = Vec::new();
+    let mut color_tokens = Vec::new();
+    let mut nesting = 0;
+    for token in first.split_whitespace() {
+        let in_color = nesting > 0 || token.contains('(');
+        nesting += token.matches('(').count() as i32 - token.matches(')').count() as i32;
+        match css_to_points(token) {
+            Ok(points) if !in_color => lengths.push(points),
+            _ => color_tokens.push(token),
+        }
+    }
+
+    let [offset_x, offset_y, rest @ ..] = lengths.as_slice() else {
+        return None;
+    };
+    let color = if color_tokens.is_empty() {
+        Color::BLACK
+    } else {
+        parse_color(&color_tokens.join(" ")).ok()?
+    };
+
+    Some(TextShadow {
+        offset_x: *offset_x,
+        offset_y: *offset_y,
+        blur: rest.first().copied().unwrap_or(0.0),
+        color,
+    })
+}
+
 /// Parse text-transform CSS property
 fn parse_text_transform(value: &str) -> TextTransform {
     match value.trim().to_lowercase().as_str() {
@@ -510,51 +783,129 @@ fn parse_justify_content(value: &str) -> JustifyContent {
     }
 }
 
-/// Parse spacing shorthand (margin, padding)
+/// Expand a 1-4 value box shorthand into `[top, right, bottom, left]`
 /// Supports: value (all), value value (vertical horizontal),
 /// value value value (top horizontal bottom), value value value value (top right bottom left)
-fn parse_spacing(value: &str) -> Result<Spacing, CSSParseError> {
+fn expand_box_shorthand(value: &str) -> Result<[&str; 4], CSSParseError> {
     let values: Vec<&str> = value.split_whitespace().collect();
 
-    match values.len() {
-        1 => {
-            let all = css_to_points(values[0])?;
-            Ok(Spacing {
-                top: all,
-                right: all,
-                bottom: all,
-                left: all,
-            })
+    match values.as_slice() {
+        [all] => Ok([all, all, all, all]),
+        [vertical, horizontal] => Ok([vertical, horizontal, vertical, horizontal]),
+        [top, horizontal, bottom] => Ok([top, horizontal, bottom, horizontal]),
+        [top, right, bottom, left] => Ok([top, right, bottom, left]),
+        _ => Err(CSSParseError::InvalidValue(value.to_string())),
+    }
+}
+
+/// Apply the padding shorthand, leaving `decl` unchanged if any side is invalid
+fn apply_padding(decl: &mut StyleDeclaration, value: &str) -> Result<(), CSSParseError> {
+    let [top, right, bottom, left] = expand_box_shorthand(value)?;
+    for side in [top, right, bottom, left] {
+        css_to_points(side)?;
+    }
+
+    decl.box_model.padding = None;
+    decl.box_model.padding_em = None;
+    apply_padding_side(decl, top, |s| &mut s.top)?;
+    apply_padding_side(decl, right, |s| &mut s.right)?;
+    apply_padding_side(decl, bottom, |s| &mut s.bottom)?;
+    apply_padding_side(decl, left, |s| &mut s.left)
+}
+
+/// Set one padding side, keeping point and `em` padding exclusive
+fn apply_padding_side(
+    decl: &mut StyleDeclaration,
+    value: &str,
+    side: fn(&mut Spacing) -> &mut f64,
+) -> Result<(), CSSParseError> {
+    let mut padding = decl.box_model.padding.unwrap_or_default();
+    let mut em = decl.box_model.padding_em.unwrap_or_default();
+
+    match css_to_length(value) {
+        Ok(CssLength::Em(ems)) => {
+            *side(&mut padding) = 0.0;
+            *side(&mut em) = ems;
         }
-        2 => {
-            let vertical = css_to_points(values[0])?;
-            let horizontal = css_to_points(values[1])?;
-            Ok(Spacing {
-                top: vertical,
-                right: horizontal,
-                bottom: vertical,
-                left: horizontal,
-            })
+        _ => {
+            *side(&mut padding) = css_to_points(value)?;
+            *side(&mut em) = 0.0;
         }
-        3 => {
-            let top = css_to_points(values[0])?;
-            let horizontal = css_to_points(values[1])?;
-            let bottom = css_to_points(values[2])?;
-            Ok(Spacing {
-                top,
-                right: horizontal,
-                bottom,
-                left: horizontal,
-            })
+    }
+
+    decl.box_model.padding = Some(padding);
+    decl.box_model.padding_em = (em != Spacing::ZERO).then_some(em);
+    Ok(())
+}
+
+/// [`css_to_length`] for properties that cannot bound a percentage
+///
+/// Only `width` maps a [`CssLength::Clamped`] onto layout constraints (its
+/// min and max width), so elsewhere `min()`/`max()`/`clamp()` comparing a
+/// percentage are invalid.
+fn css_to_unclamped_length(value: &str) -> Result<CssLength, CSSParseError> {
+    match css_to_length(value)? {
+        CssLength::Clamped { .. } => Err(CSSParseError::InvalidValue(value.to_string())),
+        length => Ok(length),
+    }
+}
+
+/// Parse one inset side: `Some(None)` for `auto`, `None` if invalid
+fn parse_inset_side(value: &str) -> Option<Option<CssLength>> {
+    if value.trim() == "auto" {
+        Some(None)
+    } else {
+        css_to_unclamped_length(value).ok().map(Some)
+    }
+}
+
+/// Apply the margin shorthand, leaving `decl` unchanged if any side is invalid
+///
+/// Sets the point margins plus, when any side is a percentage or `em`, the
+/// percentage or `em` margins (resolved later by the layout engine).
+fn apply_margin(decl: &mut StyleDeclaration, value: &str) -> Result<(), CSSParseError> {
+    let [top, right, bottom, left] = expand_box_shorthand(value)?;
+    let (top, right, bottom, left) = (
+        css_to_unclamped_length(top)?,
+        css_to_unclamped_length(right)?,
+        css_to_unclamped_length(bottom)?,
+        css_to_unclamped_length(left)?,
+    );
+
+    decl.box_model.margin = None;
+    decl.box_model.margin_percent = None;
+    decl.box_model.margin_em = None;
+    apply_margin_side(decl, top, |s| &mut s.top);
+    apply_margin_side(decl, right, |s| &mut s.right);
+    apply_margin_side(decl, bottom, |s| &mut s.bottom);
+    apply_margin_side(decl, left, |s| &mut s.left);
+    Ok(())
+}
+
+/// Set one margin side, keeping point, percentage and `em` margins exclusive
+fn apply_margin_side(
+    decl: &mut StyleDeclaration,
+    length: CssLength,
+    side: fn(&mut Spacing) -> &mut f64,
+) {
+    let mut margin = decl.box_model.margin.unwrap_or_default();
+    let mut percent = decl.box_model.margin_percent.unwrap_or_default();
+    let mut em = decl.box_model.margin_em.unwrap_or_default();
+
+    *side(&mut margin) = 0.0;
+    *side(&mut percent) = 0.0;
+    *side(&mut em) = 0.0;
+    match length {
+        CssLength::Points(points) => *side(&mut margin) = points,
+        CssLength::Percent(pct) | CssLength::Clamped { percent: pct, .. } => {
+            *side(&mut percent) = pct
         }
-        4 => Ok(Spacing {
-            top: css_to_points(values[0])?,
-            right: css_to_points(values[1])?,
-            bottom: css_to_points(values[2])?,
-            left: css_to_points(values[3])?,
-        }),
-        _ => Err(CSSParseError::InvalidValue(value.to_string())),
+        CssLength::Em(ems) => *side(&mut em) = ems,
     }
+
+    decl.box_model.margin = Some(margin);
+    decl.box_model.margin_percent = (percent != Spacing::ZERO).then_some(percent);
+    decl.box_model.margin_em = (em != Spacing::ZERO).then_some(em);
 }
 
 #[cfg(test)]
@@ -615,6 +966,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_important_is_stripped_and_recorded() {
+        let result = parse_inline_styles("color: red !important; color: blue").unwrap();
+        assert_eq!(result.text.color, Some(layout_types::Color::rgb(255, 0, 0)));
+        assert_eq!(result.important, vec!["color: red".to_string()]);
+        assert!(result.is_important("color"));
+
+        let result = parse_inline_styles("font-size: 14pt ! IMPORTANT").unwrap();
+        assert_eq!(result.text.font_size, Some(14.0));
+        assert!(result.is_important("font-size"));
+        assert!(!result.is_important("color"));
+    }
+
     #[test]
     fn test_parse_margin_shorthand() {
         let result = parse_inline_styles("margin: 10px 20px").unwrap();
@@ -697,6 +1061,16 @@ mod tests {
         assert_eq!(result.text.text_align, Some(TextAlign::Justify));
     }
 
+    #[test]
+    fn test_parse_text_align_last() {
+        let result = parse_inline_styles("text-align: justify; text-align-last: center").unwrap();
+        assert_eq!(result.text.text_align, Some(TextAlign::Justify));
+        assert_eq!(result.text.text_align_last, Some(TextAlign::Center));
+
+        let result = parse_inline_styles("text-align-last: auto").unwrap();
+        assert_eq!(result.text.text_align_last, None);
+    }
+
     #[test]
     fn test_parse_display_block() {
         let result = parse_inline_styles("display: block").unwrap();
@@ -721,6 +1095,57 @@ mod tests {
         assert_eq!(result.flex.flex, Some(1.0));
     }
 
+    #[test]
+    fn test_parse_flex_three_value_form() {
+        let result = parse_inline_styles("flex: 1 0 200px").unwrap();
+        assert_eq!(result.flex.flex, Some(1.0));
+        assert_eq!(result.flex.flex_shrink, Some(0.0));
+        assert_eq!(result.flex.flex_basis, Some(CssLength::Points(150.0)));
+
+        let result = parse_inline_styles("flex: 0 0 30%").unwrap();
+        assert_eq!(result.flex.flex, Some(0.0));
+        assert_eq!(result.flex.flex_basis, Some(CssLength::Percent(30.0)));
+    }
+
+    #[test]
+    fn test_parse_flex_shorthand_forms() {
+        let flex = |css: &str| {
+            let style = parse_inline_styles(css).unwrap();
+            (
+                style.flex.flex,
+                style.flex.flex_shrink,
+                style.flex.flex_basis,
+            )
+        };
+        let zero = Some(CssLength::Percent(0.0));
+
+        assert_eq!(flex("flex: 1"), (Some(1.0), Some(1.0), zero));
+        assert_eq!(flex("flex: 2 3"), (Some(2.0), Some(3.0), zero));
+        assert_eq!(
+            flex("flex: 1 100pt"),
+            (Some(1.0), Some(1.0), Some(CssLength::Points(100.0)))
+        );
+        assert_eq!(
+            flex("flex: 50%"),
+            (Some(1.0), Some(1.0), Some(CssLength::Percent(50.0)))
+        );
+        assert_eq!(flex("flex: none"), (Some(0.0), Some(0.0), None));
+        assert_eq!(flex("flex: auto"), (Some(1.0), Some(1.0), None));
+        assert_eq!(flex("flex: 1 1 auto"), (Some(1.0), Some(1.0), None));
+    }
+
+    #[test]
+    fn test_parse_flex_longhands() {
+        let result = parse_inline_styles("flex-grow: 2; flex-basis: 120pt").unwrap();
+        assert_eq!(result.flex.flex, Some(2.0));
+        assert_eq!(result.flex.flex_shrink, None);
+        assert_eq!(result.flex.flex_basis, Some(CssLength::Points(120.0)));
+
+        // A later shorthand replaces the longhands
+        let result = parse_inline_styles("flex-basis: 120pt; flex: none").unwrap();
+        assert_eq!(result.flex.flex_basis, None);
+    }
+
     #[test]
     fn test_parse_flex_invalid() {
         let result = parse_inline_styles("flex: invalid");
@@ -739,6 +1164,193 @@ mod tests {
         assert_eq!(result.flex.flex_direction, Some(FlexDirection::Column));
     }
 
+    #[test]
+    fn test_parse_flex_wrap() {
+        let result = parse_inline_styles("display: flex; flex-wrap: wrap").unwrap();
+        assert_eq!(result.flex.flex_wrap, Some(FlexWrap::Wrap));
+
+        let result = parse_inline_styles("flex-wrap: wrap-reverse").unwrap();
+        assert_eq!(result.flex.flex_wrap, Some(FlexWrap::WrapReverse));
+
+        let result = parse_inline_styles("flex-wrap: nowrap").unwrap();
+        assert_eq!(result.flex.flex_wrap, Some(FlexWrap::NoWrap));
+    }
+
+    #[test]
+    fn test_parse_min_width_only_bounds_width() {
+        let result =
+            parse_inline_styles("width: min(600px, 90%); max-width: min(600px, 90%)").unwrap();
+        assert_eq!(
+            result.box_model.width,
+            Some(CssLength::Clamped {
+                min: None,
+                percent: 90.0,
+                max: Some(450.0)
+            })
+        );
+        assert_eq!(result.box_model.max_width, None);
+        assert!(parse_inline_styles("margin-left: max(10px, 5%)").is_err());
+    }
+
+    #[test]
+    fn test_parse_percent_width_and_margin() {
+        let result = parse_inline_styles("width: 50%; max-width: 100%").unwrap();
+        assert_eq!(result.box_model.width, Some(CssLength::Percent(50.0)));
+        assert_eq!(result.box_model.max_width, Some(CssLength::Percent(100.0)));
+
+        let result = parse_inline_styles("margin: 10pt 5%").unwrap();
+        assert_eq!(
+            result.box_model.margin,
+            Some(Spacing {
+                top: 10.0,
+                right: 0.0,
+                bottom: 10.0,
+                left: 0.0
+            })
+        );
+        assert_eq!(
+            result.box_model.margin_percent,
+            Some(Spacing {
+                top: 0.0,
+                right: 5.0,
+                bottom: 0.0,
+                left: 5.0
+            })
+        );
+
+        // A later point value replaces the percentage on that side
+        let result = parse_inline_styles("margin-left: 10%; margin-left: 6pt").unwrap();
+        assert_eq!(result.box_model.margin.map(|m| m.left), Some(6.0));
+        assert_eq!(result.box_model.margin_percent, None);
+    }
+
+    #[test]
+    fn test_parse_em_margin_and_padding_kept_for_layout() {
+        let result = parse_inline_styles("font-size: 20px; margin: 1em 6pt").unwrap();
+        assert_eq!(
+            result.box_model.margin,
+            Some(Spacing {
+                top: 0.0,
+                right: 6.0,
+                bottom: 0.0,
+                left: 6.0
+            })
+        );
+        assert_eq!(
+            result.box_model.margin_em,
+            Some(Spacing {
+                top: 1.0,
+                right: 0.0,
+                bottom: 1.0,
+                left: 0.0
+            })
+        );
+
+        let result = parse_inline_styles("padding: 4pt; padding-left: 0.5em").unwrap();
+        assert_eq!(
+            result.box_model.padding,
+            Some(Spacing {
+                left: 0.0,
+                ..Spacing::uniform(4.0)
+            })
+        );
+        assert_eq!(result.box_model.padding_em.map(|p| p.left), Some(0.5));
+
+        // A later point value replaces the em value on that side
+        let result = parse_inline_styles("padding: 1em; padding: 2pt").unwrap();
+        assert_eq!(result.box_model.padding, Some(Spacing::uniform(2.0)));
+        assert_eq!(result.box_model.padding_em, None);
+    }
+
+    #[test]
+    fn test_parse_column_count() {
+        let result = parse_inline_styles("column-count: 3; column-gap: 16px").unwrap();
+        assert_eq!(result.box_model.column_count, Some(3));
+        assert_eq!(result.flex.column_gap, Some(12.0)); // 16px * 0.75
+
+        let result = parse_inline_styles("column-count: auto").unwrap();
+        assert_eq!(result.box_model.column_count, None);
+
+        let result = parse_inline_styles("column-count: 0").unwrap();
+        assert_eq!(result.box_model.column_count, None);
+    }
+
+    #[test]
+    fn test_parse_position_and_insets() {
+        let result =
+            parse_inline_styles("position: absolute; top: 0; right: 10%; left: auto").unwrap();
+        assert_eq!(result.box_model.position, Some(Position::Absolute));
+        assert_eq!(
+            result.box_model.inset,
+            Some(Inset {
+                top: Some(CssLength::Points(0.0)),
+                right: Some(CssLength::Percent(10.0)),
+                bottom: None,
+                left: None,
+            })
+        );
+
+        let result = parse_inline_styles("position: relative; inset: 4px 8px").unwrap();
+        assert_eq!(result.box_model.position, Some(Position::Relative));
+        assert_eq!(
+            result.box_model.inset.unwrap().left,
+            Some(CssLength::Points(6.0))
+        );
+
+        let result = parse_inline_styles("position: sticky").unwrap();
+        assert_eq!(result.box_model.position, None);
+    }
+
+    #[test]
+    fn test_parse_box_sizing() {
+        let result = parse_inline_styles("box-sizing: border-box").unwrap();
+        assert_eq!(result.box_model.box_sizing, Some(BoxSizing::BorderBox));
+
+        let result = parse_inline_styles("box-sizing: content-box").unwrap();
+        assert_eq!(result.box_model.box_sizing, Some(BoxSizing::ContentBox));
+
+        let result = parse_inline_styles("box-sizing: inherit").unwrap();
+        assert_eq!(result.box_model.box_sizing, None);
+    }
+
+    #[test]
+    fn test_parse_list_style_type() {
+        let result = parse_inline_styles("list-style-type: square").unwrap();
+        assert_eq!(result.text.list_style_type, Some(ListStyleType::Square));
+
+        let result = parse_inline_styles("list-style: decimal inside").unwrap();
+        assert_eq!(result.text.list_style_type, Some(ListStyleType::Decimal));
+
+        let result = parse_inline_styles("list-style: none").unwrap();
+        assert_eq!(result.text.list_style_type, Some(ListStyleType::None));
+
+        let result = parse_inline_styles("list-style-type: lower-roman").unwrap();
+        assert_eq!(result.text.list_style_type, None);
+    }
+
+    #[test]
+    fn test_parse_orphans_widows() {
+        let result = parse_inline_styles("orphans: 3; widows: 4").unwrap();
+        assert_eq!(result.text.orphans, Some(3));
+        assert_eq!(result.text.widows, Some(4));
+
+        let result = parse_inline_styles("orphans: 0; widows: two").unwrap();
+        assert_eq!(result.text.orphans, None);
+        assert_eq!(result.text.widows, None);
+    }
+
+    #[test]
+    fn test_parse_line_clamp() {
+        let result = parse_inline_styles("-webkit-line-clamp: 3").unwrap();
+        assert_eq!(result.text.line_clamp, Some(3));
+
+        let result = parse_inline_styles("line-clamp: 2").unwrap();
+        assert_eq!(result.text.line_clamp, Some(2));
+
+        let result = parse_inline_styles("-webkit-line-clamp: none").unwrap();
+        assert_eq!(result.text.line_clamp, None);
+    }
+
     #[test]
     fn test_parse_border_bottom_simple() {
         let result = parse_inline_styles("border-bottom: 1px solid #000").unwrap();
@@ -757,6 +1369,44 @@ mod tests {
         assert_eq!(border.color.r, 255);
     }
 
+    #[test]
+    fn test_parse_border_shorthand_sets_all_sides() {
+        let result = parse_inline_styles("border: 2px solid red").unwrap();
+        let sides = [
+            &result.box_model.border_top,
+            &result.box_model.border_right,
+            &result.box_model.border_bottom,
+            &result.box_model.border_left,
+        ];
+        for side in sides {
+            let border = side.as_ref().unwrap();
+            assert_eq!(border.width, 1.5);
+            assert_eq!(border.style, BorderLineStyle::Solid);
+            assert_eq!(border.color.r, 255);
+        }
+    }
+
+    #[test]
+    fn test_parse_border_side_after_shorthand_wins() {
+        let result = parse_inline_styles("border: 2px solid red; border-bottom: none").unwrap();
+        let bottom = result.box_model.border_bottom.unwrap();
+        assert_eq!(bottom.style, BorderLineStyle::None);
+        for side in [
+            result.box_model.border_top,
+            result.box_model.border_right,
+            result.box_model.border_left,
+        ] {
+            assert_eq!(side.unwrap().style, BorderLineStyle::Solid);
+        }
+
+        // Order matters: a later shorthand replaces the earlier side
+        let result = parse_inline_styles("border-bottom: none; border: 1px dashed #333").unwrap();
+        assert_eq!(
+            result.box_model.border_bottom.unwrap().style,
+            BorderLineStyle::Dashed
+        );
+    }
+
     #[test]
     fn test_parse_border_bottom_invalid() {
         let result = parse_inline_styles("border-bottom:");
@@ -861,7 +1511,17 @@ mod tests {
     #[test]
     fn test_parse_width() {
         let result = parse_inline_styles("width: 100px").unwrap();
-        assert_eq!(result.box_model.width, Some(75.0)); // 100 * 0.75
+        assert_eq!(result.box_model.width, Some(CssLength::Points(75.0))); // 100 * 0.75
+    }
+
+    #[test]
+    fn test_parse_comparison_functions() {
+        let result =
+            parse_inline_styles("width: min(600px, 400pt); font-size: clamp(12px, 2vw, 18px)")
+                .unwrap();
+        assert_eq!(result.box_model.width, Some(CssLength::Points(400.0)));
+        let font_size = result.text.font_size.unwrap();
+        assert!((font_size - 12.24).abs() < 1e-9);
     }
 
     #[test]
@@ -933,4 +1593,67 @@ mod tests {
         assert_eq!(border.color.b, 175);
         assert_eq!(border.color.a, 1.0);
     }
+
+    #[test]
+    fn test_parse_var_with_fallback() {
+        let result =
+            parse_inline_styles("color: var(--x, red); margin-top: var(--gap, 8pt)").unwrap();
+        assert_eq!(result.text.color, Some(layout_types::Color::rgb(255, 0, 0)));
+        assert_eq!(result.box_model.margin.unwrap().top, 8.0);
+
+        // Unresolvable custom property is skipped, not treated as black
+        let result = parse_inline_styles("background-color: var(--surface)").unwrap();
+        assert_eq!(result.box_model.background_color, None);
+    }
+
+    #[test]
+    fn test_parse_current_color() {
+        let result =
+            parse_inline_styles("border-bottom: 1px solid currentColor; color: #2563eb").unwrap();
+        let border = result.box_model.border_bottom.unwrap();
+        assert_eq!(border.color, layout_types::Color::rgb(37, 99, 235));
+
+        // On `color` itself the keyword means "inherit"
+        let result = parse_inline_styles("color: currentColor").unwrap();
+        assert_eq!(result.text.color, None);
+    }
+
+    #[test]
+    fn test_parse_text_shadow() {
+        let result = parse_inline_styles("text-shadow: 1pt 2pt 3pt #ff0000").unwrap();
+        assert_eq!(
+            result.text.text_shadow,
+            Some(TextShadow {
+                offset_x: 1.0,
+                offset_y: 2.0,
+                blur: 3.0,
+                color: Color::rgb(255, 0, 0),
+            })
+        );
+
+        // Color first, no blur, second shadow ignored
+        let result =
+            parse_inline_styles("text-shadow: rgba(0, 0, 0, 0.5) 2px 2px, 0 0 4px red").unwrap();
+        let shadow = result.text.text_shadow.unwrap();
+        assert_eq!(
+            (shadow.offset_x, shadow.offset_y, shadow.blur),
+            (1.5, 1.5, 0.0)
+        );
+        assert_eq!(shadow.color.a, 0.5);
+
+        assert_eq!(
+            parse_inline_styles("text-shadow: none")
+                .unwrap()
+                .text
+                .text_shadow,
+            None
+        );
+        assert_eq!(
+            parse_inline_styles("text-shadow: red")
+                .unwrap()
+                .text
+                .text_shadow,
+            None
+        );
+    }
 }
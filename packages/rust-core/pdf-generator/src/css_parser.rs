@@ -20,14 +20,14 @@ use thiserror::Error;
 
 // Re-export style types from shared layout-types crate
 pub use layout_types::{
-    AlignItems, BorderLineStyle, BorderStyle, Color, Display, FlexDirection, FontStyle, FontWeight,
-    JustifyContent, Spacing, StyleDeclaration, TextAlign, TextTransform,
+    AlignItems, BorderLineStyle, BorderStyle, Color, Display, FlexDirection, FlexWrap, FontStyle,
+    FontWeight, JustifyContent, Spacing, StyleDeclaration, TextAlign, TextTransform,
 };
 
 // Re-export parsing functions from submodules
-pub use crate::css::css_to_points;
 pub use crate::css::parse_color;
-pub use crate::css::parse_inline_styles;
+pub use crate::css::{css_to_length, css_to_points};
+pub use crate::css::{parse_declared_styles, parse_inline_styles};
 
 /// CSS parsing error type
 #[derive(Debug, Error)]
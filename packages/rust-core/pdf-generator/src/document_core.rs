@@ -2,8 +2,9 @@
 //!
 //! This module handles PDF document initialization, metadata, and finalization.
 
+use crate::attachments::{embed_file, SOURCE_ATTACHMENT_NAME};
 use crate::bookmarks::{create_bookmark_tree, extract_bookmarks};
-use crate::config::{PDFConfig, PDFStandard};
+use crate::config::{FontQuality, PDFConfig, PDFStandard, ViewerPreferences};
 use crate::error::PDFError;
 use crate::layout_renderer::LayoutStructure;
 use crate::pdfa;
@@ -12,6 +13,7 @@ use lopdf::{dictionary, Document, Object};
 use std::collections::HashMap;
 
 /// Core PDF document wrapper with metadata management
+#[derive(Clone)]
 pub struct PDFDocumentCore {
     /// The lopdf Document
     pub doc: Document,
@@ -21,13 +23,27 @@ pub struct PDFDocumentCore {
     layout: Option<LayoutStructure>,
     /// Text content for font subsetting
     text_content: String,
+    /// Source TSX attached when `embed_source` is enabled
+    source: Option<String>,
 }
 
 impl PDFDocumentCore {
     /// Create a new PDF document with configuration
     pub fn new(config: PDFConfig) -> Result<Self, PDFError> {
+        if config.embed_source && !config.standard.allows_embedded_files() {
+            return Err(PDFError::ConfigError(format!(
+                "embed_source is not allowed with {:?}: the standard forbids embedded files",
+                config.standard
+            )));
+        }
+
         let mut doc = Document::with_version("1.7");
 
+        // lopdf writes `%` followed by the mark; an empty mark leaves a plain ASCII comment
+        if !config.binary_comment && !config.standard.requires_binary_comment() {
+            doc.binary_mark = Vec::new();
+        }
+
         if config.standard == PDFStandard::PDFA1b {
             pdfa::use_traditional_xref_table(&mut doc);
         }
@@ -37,6 +53,7 @@ impl PDFDocumentCore {
             config,
             layout: None,
             text_content: String::new(),
+            source: None,
         })
     }
 
@@ -45,14 +62,22 @@ impl PDFDocumentCore {
         self.text_content = text;
     }
 
+    /// Set the source TSX attached on finalize when `embed_source` is enabled
+    pub fn set_source(&mut self, source: String) {
+        self.source = Some(source);
+    }
+
     /// Initialize document catalog and metadata
     pub fn initialize(&mut self, pages_id: (u32, u16)) -> Result<(u32, u16), PDFError> {
         let catalog_id = self.doc.new_object_id();
 
-        let catalog = dictionary! {
+        let mut catalog = dictionary! {
             "Type" => "Catalog",
             "Pages" => pages_id,
         };
+        if let Some(ref preferences) = self.config.viewer_preferences {
+            catalog.set("ViewerPreferences", viewer_preferences_dict(preferences));
+        }
         self.doc
             .objects
             .insert(catalog_id, Object::Dictionary(catalog));
@@ -115,7 +140,7 @@ impl PDFDocumentCore {
         info_dict.set(
             "Producer",
             Object::String(
-                b"lopdf + ResumeWright".to_vec(),
+                self.config.producer_name().as_bytes().to_vec(),
                 lopdf::StringFormat::Literal,
             ),
         );
@@ -126,6 +151,13 @@ impl PDFDocumentCore {
             Object::String(date_str.as_bytes().to_vec(), lopdf::StringFormat::Literal),
         );
 
+        for (key, value) in self.config.custom_info_entries() {
+            info_dict.set(
+                key,
+                Object::String(value.as_bytes().to_vec(), lopdf::StringFormat::Literal),
+            );
+        }
+
         self.doc
             .objects
             .insert(info_id, Object::Dictionary(info_dict));
@@ -189,17 +221,32 @@ impl PDFDocumentCore {
             }
         }
 
+        // Attach the source TSX (standards forbidding attachments are rejected in new())
+        if self.config.embed_source {
+            if let Some(ref source) = self.source {
+                embed_file(
+                    &mut self.doc,
+                    SOURCE_ATTACHMENT_NAME,
+                    "text/plain",
+                    "CV source (TSX)",
+                    source.as_bytes(),
+                )?;
+            }
+        }
+
         // Apply PDF/A compliance
         if self.config.standard == PDFStandard::PDFA1b {
             pdfa::set_pdfa1_version(&mut self.doc);
             pdfa::apply_pdfa1b_compliance(&mut self.doc, &self.config)?;
             pdfa::add_document_id(&mut self.doc)?;
+        }
 
-            // Embed Karla fonts for all pages (PDF/A requires all fonts embedded)
-            // This replaces Type1 fonts with embedded TrueType fonts that support Unicode
-            // Note: This must be done AFTER font_registry.register_fonts() in generator.rs
-            // because we're replacing the Type1 font references with embedded CIDFont Type 2
-
+        // Embed Karla fonts for all pages (PDF/A requires all fonts embedded;
+        // other standards opt in with embed_base_fonts)
+        // This replaces Type1 fonts with embedded TrueType fonts that support Unicode
+        // Note: This must be done AFTER font_registry.register_fonts() in generator.rs
+        // because we're replacing the Type1 font references with embedded CIDFont Type 2
+        if self.config.standard == PDFStandard::PDFA1b || self.config.embed_base_fonts {
             // Get all page IDs (we don't have direct access to page_manager here)
             // So we'll need to collect page IDs from the document
             let page_ids: Vec<(u32, u16)> = self
@@ -215,13 +262,17 @@ impl PDFDocumentCore {
                     None
                 })
                 .collect();
-            // Embed standard fonts for PDF/A compliance (with subsetting)
+            // Embed standard fonts (with subsetting)
             pdfa::embed_standard_fonts_for_pages(&mut self.doc, &page_ids, &self.text_content)?;
         }
 
         let mut buffer = Vec::new();
 
-        if self.config.compress_content_streams {
+        if self.config.strip_optional_font_tables {
+            crate::size_target::strip_embedded_font_tables(&mut self.doc);
+        }
+
+        if self.config.compress_content_streams || self.config.font_quality == FontQuality::Small {
             self.doc.compress();
         }
 
@@ -238,6 +289,21 @@ impl PDFDocumentCore {
     }
 }
 
+/// Build the catalog `/ViewerPreferences` dictionary, listing only enabled flags
+fn viewer_preferences_dict(preferences: &ViewerPreferences) -> lopdf::Dictionary {
+    let mut dict = dictionary! {};
+    for (key, enabled) in [
+        ("FitWindow", preferences.fit_window),
+        ("DisplayDocTitle", preferences.display_doc_title),
+        ("HideToolbar", preferences.hide_toolbar),
+    ] {
+        if enabled {
+            dict.set(key, true);
+        }
+    }
+    dict
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -294,6 +360,120 @@ mod tests {
         assert!(info_obj.is_ok());
     }
 
+    fn info_producer(doc_core: &PDFDocumentCore) -> String {
+        let info_id = doc_core
+            .doc
+            .trailer
+            .get(b"Info")
+            .and_then(Object::as_reference)
+            .unwrap();
+        let producer = doc_core
+            .doc
+            .get_dictionary(info_id)
+            .and_then(|info| info.get(b"Producer"))
+            .and_then(Object::as_str)
+            .unwrap();
+        String::from_utf8_lossy(producer).into_owned()
+    }
+
+    #[test]
+    fn test_set_metadata_producer_defaults_to_crate_version() {
+        let mut doc_core = PDFDocumentCore::new(PDFConfig::default()).unwrap();
+        let pages_id = doc_core.doc.new_object_id();
+        doc_core.initialize(pages_id).unwrap();
+
+        let producer = info_producer(&doc_core);
+        assert!(producer.contains("ResumeWright"));
+        assert!(producer.contains(env!("CARGO_PKG_VERSION")));
+    }
+
+    #[test]
+    fn test_set_metadata_producer_override() {
+        let config = PDFConfig {
+            producer: Some("Acme CV Builder 2.0".to_string()),
+            ..Default::default()
+        };
+        let mut doc_core = PDFDocumentCore::new(config).unwrap();
+        let pages_id = doc_core.doc.new_object_id();
+        doc_core.initialize(pages_id).unwrap();
+
+        assert_eq!(info_producer(&doc_core), "Acme CV Builder 2.0");
+    }
+
+    #[test]
+    fn test_custom_info_entries_written_to_info_dictionary() {
+        let config = PDFConfig {
+            custom_info: vec![
+                ("CandidateID".to_string(), "C-1042".to_string()),
+                ("Job Source".to_string(), "Referral".to_string()),
+                ("Title".to_string(), "Overridden".to_string()),
+            ],
+            ..Default::default()
+        };
+        let mut doc_core = PDFDocumentCore::new(config).unwrap();
+        let pages_id = doc_core.doc.new_object_id();
+        doc_core.initialize(pages_id).unwrap();
+
+        let bytes = doc_core.finalize(1).unwrap();
+        let doc = Document::load_mem(&bytes).unwrap();
+        let info = doc
+            .trailer
+            .get(b"Info")
+            .and_then(Object::as_reference)
+            .and_then(|id| doc.get_dictionary(id))
+            .unwrap();
+        assert_eq!(
+            info.get(b"CandidateID").and_then(Object::as_str).unwrap(),
+            b"C-1042"
+        );
+        assert_eq!(
+            info.get(b"Job_Source").and_then(Object::as_str).unwrap(),
+            b"Referral"
+        );
+        // Standard entries cannot be overwritten
+        assert_eq!(
+            info.get(b"Title").and_then(Object::as_str).unwrap(),
+            b"Resume"
+        );
+    }
+
+    #[test]
+    fn test_initialize_writes_viewer_preferences() {
+        let config = PDFConfig {
+            viewer_preferences: Some(ViewerPreferences {
+                display_doc_title: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut doc_core = PDFDocumentCore::new(config).unwrap();
+        let pages_id = doc_core.doc.new_object_id();
+        doc_core.initialize(pages_id).unwrap();
+
+        let bytes = doc_core.finalize(1).unwrap();
+        let doc = Document::load_mem(&bytes).unwrap();
+        let preferences = doc
+            .catalog()
+            .and_then(|catalog| catalog.get(b"ViewerPreferences"))
+            .and_then(Object::as_dict)
+            .unwrap();
+        assert_eq!(preferences.len(), 1);
+        assert_eq!(
+            preferences.get(b"DisplayDocTitle").unwrap(),
+            &Object::Boolean(true)
+        );
+        assert!(
+            String::from_utf8_lossy(&bytes).contains("/ViewerPreferences<</DisplayDocTitle true>>")
+        );
+
+        // No entry unless configured
+        let mut doc_core = PDFDocumentCore::new(PDFConfig::default()).unwrap();
+        let pages_id = doc_core.doc.new_object_id();
+        doc_core.initialize(pages_id).unwrap();
+        let bytes = doc_core.finalize(1).unwrap();
+        assert!(!String::from_utf8_lossy(&bytes).contains("/ViewerPreferences"));
+    }
+
     #[test]
     fn test_set_layout() {
         use crate::layout_renderer::LayoutStructure;
@@ -326,6 +506,42 @@ mod tests {
         assert!(pdf_bytes.starts_with(b"%PDF"));
     }
 
+    fn second_line(pdf_bytes: &[u8]) -> &[u8] {
+        pdf_bytes.split(|&b| b == b'\n').nth(1).unwrap()
+    }
+
+    #[test]
+    fn test_finalize_without_binary_comment() {
+        let finalize = |config: PDFConfig| {
+            let mut doc_core = PDFDocumentCore::new(config).unwrap();
+            let pages_id = doc_core.doc.new_object_id();
+            doc_core.initialize(pages_id).unwrap();
+            doc_core.finalize(1).unwrap()
+        };
+
+        let default_bytes = finalize(PDFConfig::default());
+        assert!(second_line(&default_bytes).iter().skip(1).all(|&b| b > 127));
+
+        let ascii_bytes = finalize(PDFConfig {
+            binary_comment: false,
+            ..Default::default()
+        });
+        assert!(ascii_bytes.is_ascii(), "Output should be pure ASCII");
+        assert!(!second_line(&ascii_bytes).iter().any(|&b| b > 127));
+        assert!(lopdf::Document::load_mem(&ascii_bytes).is_ok());
+    }
+
+    #[test]
+    fn test_binary_comment_forced_for_pdfa() {
+        let config = PDFConfig {
+            standard: PDFStandard::PDFA1b,
+            binary_comment: false,
+            ..Default::default()
+        };
+        let doc_core = PDFDocumentCore::new(config).unwrap();
+        assert!(!doc_core.doc.binary_mark.is_empty());
+    }
+
     #[test]
     fn test_finalize_with_compression() {
         let config = PDFConfig {
@@ -341,6 +557,38 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_embed_base_fonts_outside_pdfa() {
+        let has_font_file = |embed_base_fonts: bool| {
+            let config = PDFConfig {
+                standard: PDFStandard::PDF17,
+                embed_base_fonts,
+                ..Default::default()
+            };
+            let mut doc_core = PDFDocumentCore::new(config).unwrap();
+            let pages_id = doc_core.doc.new_object_id();
+            doc_core.initialize(pages_id).unwrap();
+            doc_core.doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+            });
+            doc_core.set_text_content("Jane Doe".to_string());
+
+            let bytes = doc_core.finalize(1).unwrap();
+            let doc = lopdf::Document::load_mem(&bytes).unwrap();
+            doc.objects
+                .values()
+                .filter_map(|obj| obj.as_dict().ok())
+                .any(|dict| dict.has(b"FontFile2"))
+        };
+
+        assert!(has_font_file(true), "base font embedded on request");
+        assert!(
+            !has_font_file(false),
+            "PDF 1.7 relies on the reader by default"
+        );
+    }
+
     #[test]
     fn test_add_bookmarks_disabled() {
         let config = PDFConfig {
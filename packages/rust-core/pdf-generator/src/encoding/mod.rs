@@ -1,9 +1,12 @@
 //! Text encoding utilities for PDF content streams
 //!
 //! This module provides encoding functions for converting text to PDF-compatible formats:
-//! - Legacy PDF literal strings (Standard 14 fonts)
+//! - Legacy PDF literal strings (Standard 14 fonts, WinAnsiEncoding)
+//! - WinAnsiEncoding hex strings (Standard 14 fonts in the layout renderer)
 //! - CIDFont Type 2 hex encoding (embedded fonts with Identity-H encoding)
 
 mod text_encoding;
 
-pub use text_encoding::{encode_as_cidfont_hex, escape_pdf_string};
+pub use text_encoding::{
+    encode_as_cidfont_hex, encode_text_hex, encode_win_ansi, encode_win_ansi_hex, escape_pdf_string,
+};
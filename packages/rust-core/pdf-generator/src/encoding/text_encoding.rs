@@ -3,9 +3,65 @@
 //! Provides encoding functions for both legacy PDF literal strings and
 //! modern CIDFont Type 2 fonts with Identity-H encoding.
 
+use std::collections::HashSet;
+
+/// Map a character to its WinAnsiEncoding byte (PDF Reference 1.7, Appendix D)
+///
+/// ASCII and Latin-1 (U+00A0..U+00FF) map to themselves; the 0x80..0x9F range
+/// carries typographic punctuation such as curly quotes, dashes and the ellipsis.
+/// Returns `None` for characters WinAnsiEncoding cannot represent.
+///
+/// # Examples
+/// ```
+/// use pdf_generator::encoding::encode_win_ansi;
+///
+/// assert_eq!(encode_win_ansi('A'), Some(0x41));
+/// assert_eq!(encode_win_ansi('\u{2019}'), Some(0x92)); // right single quote
+/// assert_eq!(encode_win_ansi('\u{4E2D}'), None);
+/// ```
+pub fn encode_win_ansi(c: char) -> Option<u8> {
+    let byte = match c {
+        '\u{0000}'..='\u{007F}' | '\u{00A0}'..='\u{00FF}' => c as u8,
+        '\u{20AC}' => 0x80, // €
+        '\u{201A}' => 0x82, // ‚
+        '\u{0192}' => 0x83, // ƒ
+        '\u{201E}' => 0x84, // „
+        '\u{2026}' => 0x85, // …
+        '\u{2020}' => 0x86, // †
+        '\u{2021}' => 0x87, // ‡
+        '\u{02C6}' => 0x88, // ˆ
+        '\u{2030}' => 0x89, // ‰
+        '\u{0160}' => 0x8A, // Š
+        '\u{2039}' => 0x8B, // ‹
+        '\u{0152}' => 0x8C, // Œ
+        '\u{017D}' => 0x8E, // Ž
+        '\u{2018}' => 0x91, // ‘
+        '\u{2019}' => 0x92, // ’
+        '\u{201C}' => 0x93, // “
+        '\u{201D}' => 0x94, // ”
+        '\u{2022}' => 0x95, // •
+        '\u{2013}' => 0x96, // –
+        '\u{2014}' => 0x97, // —
+        '\u{02DC}' => 0x98, // ˜
+        '\u{2122}' => 0x99, // ™
+        '\u{0161}' => 0x9A, // š
+        '\u{203A}' => 0x9B, // ›
+        '\u{0153}' => 0x9C, // œ
+        '\u{017E}' => 0x9E, // ž
+        '\u{0178}' => 0x9F, // Ÿ
+        _ => return None,
+    };
+    Some(byte)
+}
+
 /// Escape special characters in PDF strings per PDF specification
 ///
-/// Escapes: backslash, parentheses, newline, carriage return, tab, and control characters
+/// Escapes: backslash, parentheses, newline, carriage return, tab, and control characters.
+/// Non-ASCII characters are encoded to their WinAnsiEncoding byte and written as
+/// octal escapes (e.g. `’` becomes `\222`), so the output stays 7-bit clean.
+/// Characters outside WinAnsiEncoding are replaced with `?`. Text is
+/// normalized to NFC first, so a decomposed "e\u{301}" maps to the single
+/// WinAnsi byte for "é".
 ///
 /// # Arguments
 /// * `s` - The string to escape
@@ -24,9 +80,11 @@
 /// assert_eq!(escape_pdf_string("Hello"), "Hello");
 /// assert_eq!(escape_pdf_string("Hello (world)"), "Hello \\(world\\)");
 /// assert_eq!(escape_pdf_string("C:\\path"), "C:\\\\path");
+/// assert_eq!(escape_pdf_string("it\u{2019}s"), "it\\222s");
 /// ```
 pub fn escape_pdf_string(s: &str) -> String {
-    s.chars()
+    font_toolkit::to_nfc(s)
+        .chars()
         .flat_map(|c| match c {
             '\\' => vec!['\\', '\\'],
             '(' => vec!['\\', '('],
@@ -34,11 +92,15 @@ pub fn escape_pdf_string(s: &str) -> String {
             '\n' => vec!['\\', 'n'],
             '\r' => vec!['\\', 'r'],
             '\t' => vec!['\\', 't'],
-            c if c.is_control() => {
+            c if c.is_ascii_control() => {
                 // Escape control characters as octal (\ddd)
                 format!("\\{:03o}", c as u8).chars().collect()
             }
-            c => vec![c],
+            c if c.is_ascii() => vec![c],
+            c => match encode_win_ansi(c) {
+                Some(byte) => format!("\\{:03o}", byte).chars().collect(),
+                None => vec!['?'],
+            },
         })
         .collect()
 }
@@ -69,17 +131,63 @@ pub fn escape_pdf_string(s: &str) -> String {
 /// to be encoded as a 2-byte (4-hex-digit) CID value. This prevents multi-byte
 /// UTF-8 characters from being misinterpreted as invalid high GID values.
 ///
+/// Text is normalized to NFC before encoding, matching the normalization the
+/// subsetter applies when it builds the CID-to-GID map. Combining marks whose
+/// precomposed form is missing from the font are not decomposed back.
+///
 /// See PDF Reference 1.7, Section 9.7.4 "CIDFonts" for details.
 pub fn encode_as_cidfont_hex(text: &str) -> String {
-    text.chars()
+    font_toolkit::to_nfc(text)
+        .chars()
         .map(|c| format!("{:04X}", c as u32))
         .collect::<String>()
 }
 
+/// Encode text as hex-encoded WinAnsiEncoding bytes for Standard 14 Type1 fonts
+///
+/// One byte (two hex digits) per character, for `<hex> Tj` operators on
+/// fonts that declare `/WinAnsiEncoding`. Text is normalized to NFC first;
+/// characters outside WinAnsiEncoding are replaced with `?`, as in
+/// [`escape_pdf_string`].
+///
+/// # Examples
+/// ```
+/// use pdf_generator::encoding::encode_win_ansi_hex;
+///
+/// assert_eq!(encode_win_ansi_hex("John"), "4A6F686E");
+/// assert_eq!(encode_win_ansi_hex("it\u{2019}s"), "69749273");
+/// ```
+pub fn encode_win_ansi_hex(text: &str) -> String {
+    font_toolkit::to_nfc(text)
+        .chars()
+        .map(|c| format!("{:02X}", encode_win_ansi(c).unwrap_or(b'?')))
+        .collect::<String>()
+}
+
+/// Hex-encode text for the font resource it is shown in
+///
+/// Fonts in `embedded_fonts` are CIDFonts with Identity-H encoding (see
+/// [`encode_as_cidfont_hex`]); all others are Standard 14 Type1 fonts
+/// declaring `/WinAnsiEncoding` (see [`encode_win_ansi_hex`]).
+pub fn encode_text_hex(text: &str, font_name: &str, embedded_fonts: &HashSet<String>) -> String {
+    if embedded_fonts.contains(font_name) {
+        encode_as_cidfont_hex(text)
+    } else {
+        encode_win_ansi_hex(text)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_encoding_normalizes_to_nfc() {
+        // "José" typed with a combining acute accent
+        assert_eq!(encode_as_cidfont_hex("Jose\u{301}"), "004A006F007300E9");
+        assert_eq!(escape_pdf_string("e\u{301}"), "\\351");
+    }
+
     #[test]
     fn test_escape_pdf_string() {
         assert_eq!(escape_pdf_string("Hello"), "Hello");
@@ -106,6 +214,54 @@ mod tests {
         assert!(escaped.contains("\\007"));
     }
 
+    #[test]
+    fn test_escape_pdf_string_smart_punctuation() {
+        // ‘single’ “double” — en – ellipsis …
+        let text = "\u{2018}a\u{2019} \u{201C}b\u{201D} \u{2014} \u{2013} \u{2026}";
+        assert_eq!(
+            escape_pdf_string(text),
+            "\\221a\\222 \\223b\\224 \\227 \\226 \\205"
+        );
+    }
+
+    #[test]
+    fn test_escape_pdf_string_latin1_and_unmappable() {
+        // é is Latin-1 (0xE9 = octal 351); CJK has no WinAnsi byte
+        assert_eq!(escape_pdf_string("caf\u{00E9}"), "caf\\351");
+        assert_eq!(escape_pdf_string("\u{4E2D}"), "?");
+    }
+
+    #[test]
+    fn test_encode_win_ansi_bytes() {
+        let bytes: Vec<Option<u8>> = "\u{2018}\u{2019}\u{201C}\u{201D}\u{2013}\u{2014}\u{2026}"
+            .chars()
+            .map(encode_win_ansi)
+            .collect();
+        assert_eq!(
+            bytes,
+            [0x91, 0x92, 0x93, 0x94, 0x96, 0x97, 0x85]
+                .map(Some)
+                .to_vec()
+        );
+        assert_eq!(encode_win_ansi('\u{0081}'), None);
+    }
+
+    #[test]
+    fn test_encode_win_ansi_hex() {
+        // ‘quoted’ — em dash, é and an unmappable CJK character
+        assert_eq!(
+            encode_win_ansi_hex("\u{2018}a\u{2019} \u{2014} e\u{301} \u{4E2D}"),
+            "916192209720E9203F"
+        );
+    }
+
+    #[test]
+    fn test_encode_text_hex_follows_font() {
+        let embedded: HashSet<String> = ["Roboto".to_string()].into_iter().collect();
+        assert_eq!(encode_text_hex("Hi", "Roboto", &embedded), "00480069");
+        assert_eq!(encode_text_hex("Hi", "Helvetica", &embedded), "4869");
+    }
+
     #[test]
     fn test_encode_as_cidfont_hex_basic() {
         assert_eq!(encode_as_cidfont_hex("A"), "0041");
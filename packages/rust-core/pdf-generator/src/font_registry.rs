@@ -3,16 +3,23 @@
 //! This module handles font collection, validation, and registration for PDF documents.
 //! It supports both Standard 14 Type1 fonts and embedded TrueType fonts (including Google Fonts).
 
+use crate::config::FontQuality;
 use crate::error::PDFError;
 use crate::layout_renderer::{BoxContent, LayoutBox, LayoutStructure};
-use font_toolkit::embedding::embed_truetype_font;
+use font_toolkit::embedding::{
+    embed_truetype_font_with_options, is_license_restricted, EmbedError, EmbedOptions,
+};
 use font_toolkit::mapper::{is_google_font, map_web_safe_font, select_font_variant};
-use font_toolkit::strip_hinting_tables;
 #[cfg(feature = "advanced-fonts")]
-use font_toolkit::subsetter::subset_font_core;
+use font_toolkit::subsetter::{subset_font_core, SubsetError};
+use font_toolkit::{
+    read_decoration_metrics, strip_hinting_tables, strip_optional_tables, DecorationMetrics,
+};
 use layout_types::{FontStyle, FontWeight};
-use lopdf::{dictionary, Document, Object};
+use lopdf::{dictionary, Dictionary, Document, Object, ObjectId};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 
 /// Internal type describing how a font should be registered
 struct FontRegistration {
@@ -39,6 +46,19 @@ pub struct PDFFontRegistry {
     font_bytes: HashMap<String, Vec<u8>>,
     /// Text content for font subsetting (all text that will be rendered)
     text_content: String,
+    /// Embed fonts whose license restricts embedding
+    allow_restricted_fonts: bool,
+    /// Whether supplied fonts are subset and how much is stripped
+    font_quality: FontQuality,
+    /// Fonts already embedded, keyed by a hash of the embedded bytes
+    /// Value: (resource name, Type0 font object ID)
+    embedded_fonts: HashMap<u64, (String, ObjectId)>,
+    /// Font bytes keys that failed to embed (not retried on later pages)
+    failed_fonts: HashSet<String>,
+    /// Font resource names registered as embedded CIDFonts
+    cid_fonts: HashSet<String>,
+    /// Human-readable notes about supplied fonts replaced by a fallback
+    warnings: Vec<String>,
 }
 
 impl PDFFontRegistry {
@@ -47,14 +67,43 @@ impl PDFFontRegistry {
         Self {
             font_bytes: HashMap::new(),
             text_content: String::new(),
+            allow_restricted_fonts: false,
+            font_quality: FontQuality::default(),
+            embedded_fonts: HashMap::new(),
+            failed_fonts: HashSet::new(),
+            cid_fonts: HashSet::new(),
+            warnings: Vec::new(),
         }
     }
 
+    /// Supplied fonts that could not be embedded and were replaced by Standard 14
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Whether font bytes were supplied for a variant
+    ///
+    /// Matches keys exactly, as embedding looks them up; see
+    /// [`crate::font_substitution::has_font_data`].
+    pub fn has_font(&self, family: &str, weight: u16, is_italic: bool) -> bool {
+        crate::font_substitution::has_font_data(&self.font_bytes, family, weight, is_italic)
+    }
+
     /// Sets cached Google Fonts font bytes for embedding
     pub fn set_font_bytes(&mut self, font_bytes: HashMap<String, Vec<u8>>) {
         self.font_bytes = font_bytes;
     }
 
+    /// Allows embedding fonts whose OS/2 `fsType` restricts embedding
+    pub fn set_allow_restricted_fonts(&mut self, allow: bool) {
+        self.allow_restricted_fonts = allow;
+    }
+
+    /// Sets the speed versus size tradeoff for embedding supplied fonts
+    pub fn set_font_quality(&mut self, font_quality: FontQuality) {
+        self.font_quality = font_quality;
+    }
+
     /// Sets the text content for font subsetting
     ///
     /// Call this before `register_fonts()` with all text that will be rendered.
@@ -118,7 +167,7 @@ impl PDFFontRegistry {
 
     /// Register fonts on a PDF document page
     pub fn register_fonts(
-        &self,
+        &mut self,
         doc: &mut Document,
         page_id: (u32, u16),
         fonts: &HashSet<String>,
@@ -135,19 +184,79 @@ impl PDFFontRegistry {
         Ok(())
     }
 
-    /// Prepare font registration data without mutating document
-    fn prepare_font_registration(&self, font_name: &str) -> Result<FontRegistration, PDFError> {
+    /// Fonts whose bold face must be synthesized from the regular face
+    ///
+    /// A font qualifies when it is a bold Google Font variant with no bold
+    /// bytes loaded but a regular face of the same style available. The
+    /// regular face is embedded in its place and the renderer fakes the
+    /// weight (see [`crate::font_substitution::synthesizes_bold`]).
+    pub fn synthetic_bold_fonts(&self, fonts: &HashSet<String>) -> HashSet<String> {
+        fonts
+            .iter()
+            .filter(|font_name| self.synthetic_bold_source(font_name).is_some())
+            .cloned()
+            .collect()
+    }
+
+    /// Weight of the regular face standing in for a missing bold face
+    fn synthetic_bold_source(&self, font_name: &str) -> Option<u16> {
+        crate::font_substitution::synthesizes_bold(&self.font_bytes, font_name).then_some(400)
+    }
+
+    /// Underline and strikeout metrics of the fonts that will be embedded
+    ///
+    /// Keyed by font resource name. Standard 14 fonts and Google Fonts
+    /// without loaded bytes are left out, so the renderer falls back to its
+    /// size-based placement for them.
+    pub fn decoration_metrics(
+        &self,
+        fonts: &HashSet<String>,
+    ) -> HashMap<String, DecorationMetrics> {
+        fonts
+            .iter()
+            .filter_map(|font_name| {
+                let (_, _, _, key) = self.google_font_key(font_name)?;
+                let metrics = read_decoration_metrics(self.font_bytes.get(&key)?)?;
+                Some((font_name.clone(), metrics))
+            })
+            .collect()
+    }
+
+    /// Fonts registered as embedded CIDFonts by [`register_fonts`](Self::register_fonts)
+    ///
+    /// Includes synthetic bolds backed by a regular face. Fonts whose
+    /// embedding failed (no glyphs for the text, corrupt data, a license
+    /// restricting embedding) were registered as Standard 14 Type1 fonts with
+    /// `/WinAnsiEncoding` instead, as was everything else, so the renderer
+    /// encodes their text as single bytes (see `encode_text_hex`).
+    pub fn embedded_fonts(&self) -> &HashSet<String> {
+        &self.cid_fonts
+    }
+
+    /// Family, weight, italic flag and `font_bytes` key of the face backing a Google Font
+    ///
+    /// A synthetic bold resolves to its regular face. Returns `None` for
+    /// fonts that are not Google Fonts.
+    fn google_font_key(&self, font_name: &str) -> Option<(String, u16, bool, String)> {
         let family = font_name.split('-').next().unwrap_or(font_name);
+        if !is_google_font(family) {
+            return None;
+        }
 
-        if is_google_font(family) {
-            let (weight, is_italic) = Self::parse_font_variant(font_name);
-            let key = format!("{}:{}:{}", family, weight, is_italic);
+        let (weight, is_italic) = Self::parse_font_variant(font_name);
+        let weight = self.synthetic_bold_source(font_name).unwrap_or(weight);
+        let key = format!("{}:{}:{}", family, weight, is_italic);
+        Some((family.to_string(), weight, is_italic, key))
+    }
 
+    /// Prepare font registration data without mutating document
+    fn prepare_font_registration(&self, font_name: &str) -> Result<FontRegistration, PDFError> {
+        if let Some((family, weight, is_italic, key)) = self.google_font_key(font_name) {
             if self.font_bytes.contains_key(&key) {
                 return Ok(FontRegistration {
                     name: font_name.to_string(),
                     registration_type: FontRegistrationType::GoogleFont {
-                        family: family.to_string(),
+                        family,
                         weight,
                         is_italic,
                     },
@@ -167,7 +276,7 @@ impl PDFFontRegistry {
 
     /// Apply a font registration to the document
     fn apply_font_registration(
-        &self,
+        &mut self,
         doc: &mut Document,
         page_id: (u32, u16),
         registration: FontRegistration,
@@ -198,6 +307,8 @@ impl PDFFontRegistry {
                             &registration.name,
                             &registration.name,
                         )?;
+                    } else {
+                        self.cid_fonts.insert(registration.name);
                     }
                 } else {
                     Self::register_type1_font(
@@ -232,21 +343,45 @@ impl PDFFontRegistry {
     }
 
     /// Try to embed a Google Font
+    ///
+    /// Identical font bytes (e.g. one file loaded under two aliases, or a
+    /// regular face standing in for a synthetic bold) are embedded once and
+    /// the existing Type0 font, with its descriptor and FontFile2 stream, is reused.
     fn try_embed_google_font(
-        &self,
+        &mut self,
         doc: &mut Document,
         family: &str,
         weight: u16,
         is_italic: bool,
     ) -> Option<font_toolkit::embedding::EmbeddedFont> {
         let key = format!("{}:{}:{}", family, weight, is_italic);
+        if self.failed_fonts.contains(&key) {
+            return None;
+        }
         let font_bytes = self.font_bytes.get(&key)?;
 
+        // The license is the supplied font's; a subset no longer carries its fsType
+        if !self.allow_restricted_fonts && is_license_restricted(font_bytes) {
+            let error = EmbedError::LicenseRestricted(family.to_string());
+            eprintln!("WARNING: Font embedding failed for {}: {}", family, error);
+            self.warnings.push(format!(
+                "Font '{}' ({}) could not be embedded: {}. Falling back to Standard 14.",
+                family, key, error
+            ));
+            self.failed_fonts.insert(key);
+            return None;
+        }
+
+        if self.font_quality == FontQuality::Fast {
+            eprintln!("[PDF] Embedding full font for {} (fast font quality)", key);
+            let full_bytes = font_bytes.clone();
+            return self.embed_font_bytes(doc, key, family, weight, is_italic, full_bytes, None);
+        }
+
         eprintln!(
             "[PDF] Found font bytes for {}, attempting to subset and embed...",
             key
         );
-
         #[cfg(feature = "advanced-fonts")]
         let (subsetted_bytes, cid_to_new_gid): (
             Vec<u8>,
@@ -269,6 +404,14 @@ impl PDFFontRegistry {
                 );
                 (bytes, std::collections::BTreeMap::new())
             }
+            Err(SubsetError::NoGlyphsRetained { .. }) => {
+                // Embedding a font that can draw none of the text only yields .notdef boxes
+                eprintln!(
+                    "WARNING: {} has no glyphs for the document text. Falling back to Standard 14.",
+                    family
+                );
+                return None;
+            }
             Err(e) => {
                 eprintln!(
                     "WARNING: Font subsetting failed for {}: {}. Using full font.",
@@ -287,32 +430,82 @@ impl PDFFontRegistry {
             font_bytes.clone()
         };
 
-        // Strip hinting tables (not needed for PDF, saves ~14% per font)
-        let optimized_bytes = strip_hinting_tables(&subsetted_bytes);
+        // Strip hinting tables (not needed for PDF, saves ~14% per font), and
+        // for the smallest output every other table PDF rendering ignores
+        let optimized_bytes = if self.font_quality == FontQuality::Small {
+            strip_optional_tables(&subsetted_bytes)
+        } else {
+            strip_hinting_tables(&subsetted_bytes)
+        };
         if optimized_bytes.len() < subsetted_bytes.len() {
             eprintln!(
-                "[PDF] Stripped hinting tables: {} -> {} bytes ({:.1}% reduction)",
+                "[PDF] Stripped font tables: {} -> {} bytes ({:.1}% reduction)",
                 subsetted_bytes.len(),
                 optimized_bytes.len(),
                 (1.0 - optimized_bytes.len() as f64 / subsetted_bytes.len() as f64) * 100.0
             );
         }
 
-        // Embed the font (with mapping if subsetted)
         #[cfg(feature = "advanced-fonts")]
-        let embed_result = embed_truetype_font(
+        let cid_mapping = Some(&cid_to_new_gid);
+        #[cfg(not(feature = "advanced-fonts"))]
+        let cid_mapping = None;
+
+        self.embed_font_bytes(
+            doc,
+            key,
+            family,
+            weight,
+            is_italic,
+            optimized_bytes,
+            cid_mapping,
+        )
+    }
+
+    /// Embed prepared font bytes, reusing an identical font embedded before
+    ///
+    /// `cid_mapping` is the subsetter's CID-to-GID map; `None` embeds a full
+    /// font mapped through its own cmap.
+    #[allow(clippy::too_many_arguments)]
+    fn embed_font_bytes(
+        &mut self,
+        doc: &mut Document,
+        key: String,
+        family: &str,
+        weight: u16,
+        is_italic: bool,
+        optimized_bytes: Vec<u8>,
+        cid_mapping: Option<&std::collections::BTreeMap<u32, u16>>,
+    ) -> Option<font_toolkit::embedding::EmbeddedFont> {
+        let bytes_hash = hash_font_bytes(&optimized_bytes);
+        if let Some((resource_name, font_id)) = self.embedded_fonts.get(&bytes_hash) {
+            eprintln!(
+                "[PDF] Reusing embedded font {} for identical {} bytes",
+                resource_name, key
+            );
+            return Some(font_toolkit::embedding::EmbeddedFont {
+                resource_name: resource_name.clone(),
+                font_id: *font_id,
+                family: family.to_string(),
+                weight,
+                is_italic,
+            });
+        }
+
+        // Embed the font (with mapping if subsetted)
+        let options = EmbedOptions {
+            allow_restricted: self.allow_restricted_fonts,
+        };
+        let embed_result = embed_truetype_font_with_options(
             doc,
             &optimized_bytes,
             family,
             weight,
             is_italic,
-            Some(&cid_to_new_gid),
+            cid_mapping,
+            options,
         );
 
-        #[cfg(not(feature = "advanced-fonts"))]
-        let embed_result =
-            embed_truetype_font(doc, &optimized_bytes, family, weight, is_italic, None);
-
         match embed_result {
             Ok(embedded) => {
                 eprintln!(
@@ -320,10 +513,19 @@ impl PDFFontRegistry {
                     family,
                     optimized_bytes.len()
                 );
+                self.embedded_fonts.insert(
+                    bytes_hash,
+                    (embedded.resource_name.clone(), embedded.font_id),
+                );
                 Some(embedded)
             }
             Err(e) => {
                 eprintln!("WARNING: Font embedding failed for {}: {}", family, e);
+                self.warnings.push(format!(
+                    "Font '{}' ({}) could not be embedded: {}. Falling back to Standard 14.",
+                    family, key, e
+                ));
+                self.failed_fonts.insert(key);
                 None
             }
         }
@@ -336,11 +538,7 @@ impl PDFFontRegistry {
         name: &str,
         base_font: &str,
     ) -> Result<(), PDFError> {
-        let font_id = doc.add_object(dictionary! {
-            "Type" => "Font",
-            "Subtype" => "Type1",
-            "BaseFont" => base_font,
-        });
+        let font_id = doc.add_object(type1_font_dict(base_font));
 
         Self::add_font_to_page_resources(doc, page_id, name, font_id)
     }
@@ -397,6 +595,31 @@ impl PDFFontRegistry {
     }
 }
 
+/// Font dictionary of a Standard 14 Type1 font
+///
+/// Text fonts declare `/WinAnsiEncoding` so the bytes written by
+/// `escape_pdf_string` (curly quotes, dashes, ellipsis) select the right
+/// glyphs; Symbol and ZapfDingbats keep their built-in encodings.
+pub(crate) fn type1_font_dict(base_font: &str) -> Dictionary {
+    let mut font_dict = dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => base_font,
+    };
+    if !matches!(base_font, "Symbol" | "ZapfDingbats") {
+        font_dict.set("Encoding", "WinAnsiEncoding");
+    }
+    font_dict
+}
+
+/// Hashes font bytes (with their length) to detect identical embedded streams
+fn hash_font_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.len().hash(&mut hasher);
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
 impl Default for PDFFontRegistry {
     fn default() -> Self {
         Self::new()
@@ -455,6 +678,7 @@ mod tests {
                     content: BoxContent::Text(vec![TextLine::from("Test")]),
                     style,
                     element_type: None,
+                    list_ordinal: None,
                 }],
             }],
             page_height: 792.0,
@@ -481,6 +705,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Test 1")]),
                         style: style.clone(),
                         element_type: None,
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -490,6 +715,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Test 2")]),
                         style: style.clone(),
                         element_type: None,
+                        list_ordinal: None,
                     },
                 ],
             }],
@@ -520,6 +746,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Test 1")]),
                         style: style_helvetica,
                         element_type: None,
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -529,6 +756,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Test 2")]),
                         style: style_courier,
                         element_type: None,
+                        list_ordinal: None,
                     },
                 ],
             }],
@@ -557,6 +785,7 @@ mod tests {
                     content: BoxContent::Text(vec![TextLine::from("Test")]),
                     style,
                     element_type: None,
+                    list_ordinal: None,
                 }],
             }],
             page_height: 792.0,
@@ -579,7 +808,7 @@ mod tests {
         };
         doc.objects.insert(page_id, Object::Dictionary(page_dict));
 
-        let registry = PDFFontRegistry::new();
+        let mut registry = PDFFontRegistry::new();
         let fonts = HashSet::new();
 
         let result = registry.register_fonts(&mut doc, page_id, &fonts);
@@ -714,6 +943,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_synthetic_bold_uses_regular_face() {
+        let mut registry = PDFFontRegistry::new();
+        let mut fonts = HashMap::new();
+        fonts.insert("Roboto:400:false".to_string(), vec![1, 2, 3]);
+        registry.set_font_bytes(fonts);
+
+        let requested: HashSet<String> = ["Roboto-Bold", "Roboto-BoldItalic", "Helvetica-Bold"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let synthetic = registry.synthetic_bold_fonts(&requested);
+        assert_eq!(synthetic.len(), 1);
+        assert!(synthetic.contains("Roboto-Bold"));
+
+        let registration = registry.prepare_font_registration("Roboto-Bold").unwrap();
+        assert!(matches!(
+            registration.registration_type,
+            FontRegistrationType::GoogleFont { weight: 400, .. }
+        ));
+    }
+
+    #[test]
+    fn test_decoration_metrics_only_for_supplied_google_fonts() {
+        let roboto = include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf");
+        let mut registry = PDFFontRegistry::new();
+        registry.set_font_bytes(HashMap::from([(
+            "Roboto:400:false".to_string(),
+            roboto.to_vec(),
+        )]));
+
+        let requested: HashSet<String> = ["Roboto", "Roboto-Bold", "Lato", "Helvetica"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let metrics = registry.decoration_metrics(&requested);
+
+        // Synthetic bold draws the regular face, so it shares its metrics
+        let expected = read_decoration_metrics(roboto).unwrap();
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(metrics["Roboto"], expected);
+        assert_eq!(metrics["Roboto-Bold"], expected);
+    }
+
     // register_type1_font tests
     #[test]
     fn test_register_type1_font() {
@@ -732,6 +1005,14 @@ mod tests {
         let resources = page_obj.get(b"Resources").unwrap().as_dict().unwrap();
         let fonts = resources.get(b"Font").unwrap().as_dict().unwrap();
         assert!(fonts.get(b"Helvetica").is_ok());
+
+        // Text fonts use WinAnsiEncoding to match escape_pdf_string
+        let font_id = fonts.get(b"Helvetica").unwrap().as_reference().unwrap();
+        let font = doc.get_dictionary(font_id).unwrap();
+        assert_eq!(
+            font.get(b"Encoding").unwrap().as_name().unwrap(),
+            b"WinAnsiEncoding"
+        );
     }
 
     // add_font_to_page_resources tests
@@ -804,7 +1085,7 @@ mod tests {
         };
         let page_id = doc.add_object(page_dict);
 
-        let registry = PDFFontRegistry::new();
+        let mut registry = PDFFontRegistry::new();
         let mut fonts = HashSet::new();
         fonts.insert("Helvetica".to_string());
         fonts.insert("Times-Roman".to_string());
@@ -822,6 +1103,227 @@ mod tests {
         assert!(font_dict.get(b"Courier").is_ok());
     }
 
+    #[test]
+    fn test_register_fonts_falls_back_for_corrupt_font() {
+        let roboto = include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf").to_vec();
+        let mut doc = Document::with_version("1.7");
+        let page_ids = [
+            doc.add_object(dictionary! { "Type" => "Page" }),
+            doc.add_object(dictionary! { "Type" => "Page" }),
+        ];
+
+        // Valid TrueType magic followed by garbage tables
+        let mut corrupt = vec![0x00, 0x01, 0x00, 0x00];
+        corrupt.extend([0xAB; 64]);
+
+        let mut registry = PDFFontRegistry::new();
+        let mut font_bytes = HashMap::new();
+        font_bytes.insert("Roboto:400:false".to_string(), roboto);
+        font_bytes.insert("Roboto:700:false".to_string(), corrupt);
+        registry.set_font_bytes(font_bytes);
+        registry.set_text_content("Hello".to_string());
+
+        let fonts: HashSet<String> = ["Roboto", "Roboto-Bold"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        for page_id in page_ids {
+            registry.register_fonts(&mut doc, page_id, &fonts).unwrap();
+        }
+
+        // The valid face is embedded; the corrupt one degrades to Type1
+        let page_fonts = |name: &[u8]| {
+            let font_dict = doc
+                .get_dictionary(page_ids[1])
+                .and_then(|page| page.get(b"Resources"))
+                .and_then(Object::as_dict)
+                .and_then(|resources| resources.get(b"Font"))
+                .and_then(Object::as_dict)
+                .unwrap();
+            let font_id = font_dict
+                .iter()
+                .find(|(key, _)| key.starts_with(name))
+                .and_then(|(_, obj)| obj.as_reference().ok())
+                .unwrap();
+            doc.get_dictionary(font_id)
+                .and_then(|font| font.get(b"Subtype"))
+                .and_then(Object::as_name)
+                .unwrap()
+                .to_vec()
+        };
+        assert_eq!(page_fonts(b"Roboto-Bold"), b"Type1");
+        assert!(registry.embedded_fonts().contains("Roboto"));
+        assert!(!registry.embedded_fonts().contains("Roboto-Bold"));
+
+        // Reported once, even though registration ran for two pages
+        assert_eq!(registry.warnings().len(), 1);
+        assert!(registry.warnings()[0].contains("Roboto:700:false"));
+        assert!(doc.objects.values().any(|obj| obj
+            .as_dict()
+            .is_ok_and(|dict| dict.get(b"FontFile2").is_ok())));
+    }
+
+    #[test]
+    fn test_register_fonts_falls_back_for_restricted_font() {
+        let mut roboto =
+            include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf").to_vec();
+        // OS/2 fsType 0x0002: restricted license embedding
+        let num_tables = u16::from_be_bytes([roboto[4], roboto[5]]) as usize;
+        let os2_offset = (0..num_tables)
+            .map(|i| 12 + i * 16)
+            .find(|&record| &roboto[record..record + 4] == b"OS/2")
+            .map(|record| {
+                u32::from_be_bytes(roboto[record + 8..record + 12].try_into().unwrap()) as usize
+            })
+            .unwrap();
+        roboto[os2_offset + 8..os2_offset + 10].copy_from_slice(&0x0002u16.to_be_bytes());
+
+        let mut doc = Document::with_version("1.7");
+        let page_id = doc.add_object(dictionary! { "Type" => "Page" });
+        let mut registry = PDFFontRegistry::new();
+        registry.set_font_bytes(HashMap::from([("Roboto:400:false".to_string(), roboto)]));
+        registry.set_allow_restricted_fonts(false);
+        registry.set_text_content("Jane Doe".to_string());
+
+        let fonts: HashSet<String> = ["Roboto".to_string()].into_iter().collect();
+        registry.register_fonts(&mut doc, page_id, &fonts).unwrap();
+
+        // Registered as Type1, so the renderer must write WinAnsi bytes
+        assert!(registry.embedded_fonts().is_empty());
+        assert!(!doc.objects.values().any(|obj| obj
+            .as_dict()
+            .is_ok_and(|dict| dict.get(b"FontFile2").is_ok())));
+        assert_eq!(registry.warnings().len(), 1);
+    }
+
+    #[test]
+    fn test_register_fonts_deduplicates_identical_font_bytes() {
+        let roboto = include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf").to_vec();
+        let mut doc = Document::with_version("1.7");
+        let page_id = doc.add_object(dictionary! { "Type" => "Page" });
+
+        // Same file loaded under two aliases
+        let mut registry = PDFFontRegistry::new();
+        let mut font_bytes = HashMap::new();
+        font_bytes.insert("Roboto:400:false".to_string(), roboto.clone());
+        font_bytes.insert("Roboto:500:false".to_string(), roboto);
+        registry.set_font_bytes(font_bytes);
+        registry.set_text_content("Hello".to_string());
+
+        let fonts: HashSet<String> = ["Roboto", "Roboto-Medium"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        registry.register_fonts(&mut doc, page_id, &fonts).unwrap();
+
+        let font_file_streams: HashSet<ObjectId> = doc
+            .objects
+            .values()
+            .filter_map(|obj| obj.as_dict().ok())
+            .filter_map(|dict| dict.get(b"FontFile2").and_then(|o| o.as_reference()).ok())
+            .collect();
+        assert_eq!(font_file_streams.len(), 1);
+
+        let type0_fonts = doc
+            .objects
+            .values()
+            .filter_map(|obj| obj.as_dict().ok())
+            .filter(|dict| {
+                dict.get(b"Subtype")
+                    .and_then(|o| o.as_name())
+                    .is_ok_and(|name| name == b"Type0")
+            })
+            .count();
+        assert_eq!(type0_fonts, 1);
+    }
+
+    #[cfg(feature = "advanced-fonts")]
+    #[test]
+    fn test_register_fonts_falls_back_when_font_lacks_text_glyphs() {
+        let roboto = include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf").to_vec();
+        let mut doc = Document::with_version("1.7");
+        let page_id = doc.add_object(dictionary! { "Type" => "Page" });
+
+        let mut registry = PDFFontRegistry::new();
+        let mut font_bytes = HashMap::new();
+        font_bytes.insert("Roboto:400:false".to_string(), roboto);
+        registry.set_font_bytes(font_bytes);
+        registry.set_text_content("山田 太郎".to_string());
+
+        let fonts: HashSet<String> = ["Roboto".to_string()].into_iter().collect();
+        registry.register_fonts(&mut doc, page_id, &fonts).unwrap();
+
+        let has_font_file = doc
+            .objects
+            .values()
+            .filter_map(|obj| obj.as_dict().ok())
+            .any(|dict| dict.has(b"FontFile2"));
+        assert!(
+            !has_font_file,
+            "Latin font should not be embedded for CJK text"
+        );
+
+        let page_obj = doc.get_dictionary(page_id).unwrap();
+        let resources = page_obj.get(b"Resources").unwrap().as_dict().unwrap();
+        let font_dict = resources.get(b"Font").unwrap().as_dict().unwrap();
+        assert!(font_dict.get(b"Roboto").is_ok());
+    }
+
+    #[cfg(feature = "advanced-fonts")]
+    #[test]
+    fn test_font_quality_controls_subsetting() {
+        let roboto = include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf").to_vec();
+
+        // (BaseFont, embedded FontFile2 length) for one embedded Roboto
+        let embed = |font_quality: FontQuality| {
+            let mut doc = Document::with_version("1.7");
+            let page_id = doc.add_object(dictionary! { "Type" => "Page" });
+            let mut registry = PDFFontRegistry::new();
+            registry.set_font_bytes(HashMap::from([(
+                "Roboto:400:false".to_string(),
+                roboto.clone(),
+            )]));
+            registry.set_text_content("Jane Doe, Staff Engineer".to_string());
+            registry.set_font_quality(font_quality);
+            let fonts: HashSet<String> = ["Roboto".to_string()].into_iter().collect();
+            registry.register_fonts(&mut doc, page_id, &fonts).unwrap();
+
+            let dicts = || doc.objects.values().filter_map(|obj| obj.as_dict().ok());
+            let base_font = dicts()
+                .find(|dict| dict.get(b"Subtype").and_then(Object::as_name).ok() == Some(b"Type0"))
+                .and_then(|dict| dict.get(b"BaseFont").and_then(Object::as_name).ok())
+                .map(|name| String::from_utf8_lossy(name).into_owned())
+                .unwrap();
+            let font_file_id = dicts()
+                .find_map(|dict| dict.get(b"FontFile2").and_then(Object::as_reference).ok())
+                .unwrap();
+            let font_file_len = doc
+                .get_object(font_file_id)
+                .and_then(Object::as_stream)
+                .unwrap()
+                .decompressed_content()
+                .unwrap()
+                .len();
+            (base_font, font_file_len)
+        };
+
+        let (fast_name, fast_len) = embed(FontQuality::Fast);
+        let (balanced_name, balanced_len) = embed(FontQuality::Balanced);
+        let (small_name, small_len) = embed(FontQuality::Small);
+
+        assert!(!fast_name.contains('+'), "full font: {}", fast_name);
+        assert_eq!(fast_len, roboto.len());
+        assert_eq!(
+            balanced_name.find('+'),
+            Some(6),
+            "subset: {}",
+            balanced_name
+        );
+        assert_eq!(small_name.find('+'), Some(6), "subset: {}", small_name);
+        assert!(fast_len > balanced_len, "{} > {}", fast_len, balanced_len);
+        assert!(balanced_len > small_len, "{} > {}", balanced_len, small_len);
+    }
+
     #[test]
     fn test_collect_fonts_with_nested_containers() {
         let mut style = StyleDeclaration::default();
@@ -842,9 +1344,11 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Nested text")]),
                         style,
                         element_type: None,
+                        list_ordinal: None,
                     }]),
                     style: StyleDeclaration::default(),
                     element_type: None,
+                    list_ordinal: None,
                 }],
             }],
             page_height: 792.0,
@@ -877,6 +1381,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Bold text")]),
                         style: style_bold,
                         element_type: None,
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 0.0,
@@ -886,6 +1391,7 @@ mod tests {
                         content: BoxContent::Text(vec![TextLine::from("Italic text")]),
                         style: style_italic,
                         element_type: None,
+                        list_ordinal: None,
                     },
                 ],
             }],
@@ -0,0 +1,276 @@
+//! Handling of requested fonts missing from the supplied font collection
+//!
+//! A family that is neither web-safe nor generic (a Google Font or a custom
+//! font) can only be rendered from font data supplied by the caller. When the
+//! data for a requested variant is missing, [`MissingFontPolicy`] decides
+//! whether the text falls back to Standard 14, to a named family, or whether
+//! the conversion fails. Every substitution made is reported.
+//!
+//! The layout engine resolves the policy on each element's style before
+//! measuring text, so wrapping and alignment use the substitute's metrics.
+
+use crate::config::MissingFontPolicy;
+use crate::error::PDFError;
+use crate::font_resolver::get_font_name;
+use crate::layout_renderer::{BoxContent, LayoutBox, LayoutStructure};
+use font_toolkit::mapper::is_google_font;
+use layout_types::{FontStyle, FontWeight, StyleDeclaration};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Families rendered with a Standard 14 font and never supplied as font data
+const BUILTIN_FAMILIES: &[&str] = &[
+    "arial",
+    "helvetica",
+    "times new roman",
+    "times",
+    "times-roman",
+    "georgia",
+    "courier new",
+    "courier",
+    "verdana",
+    "sans-serif",
+    "serif",
+    "monospace",
+];
+
+/// A requested font variant replaced because its data was not supplied
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FontSubstitution {
+    /// Requested font family (e.g. `Roboto`)
+    pub family: String,
+    /// Requested weight (400 regular, 700 bold, 300 light)
+    pub weight: u16,
+    /// Whether the italic face was requested
+    pub italic: bool,
+    /// Font registered in the PDF instead (a Standard 14 font name)
+    pub substitute: String,
+}
+
+/// Whether a family needs supplied font data to be rendered as itself
+pub fn requires_font_data(family: &str) -> bool {
+    let family = family.trim().to_ascii_lowercase();
+    !family.is_empty() && !BUILTIN_FAMILIES.contains(&family.as_str())
+}
+
+/// Whether `font_bytes` holds data for a variant
+///
+/// Keys are `family:weight:italic` (e.g. `Roboto:700:false`) and are matched
+/// exactly, as the font registry looks them up when embedding. A bold
+/// variant counts as supplied when its regular face is, since the bold is
+/// then synthesized.
+pub fn has_font_data(
+    font_bytes: &HashMap<String, Vec<u8>>,
+    family: &str,
+    weight: u16,
+    is_italic: bool,
+) -> bool {
+    let supplied = |weight: u16| {
+        font_bytes.contains_key(&format!("{}:{}:{}", family.trim(), weight, is_italic))
+    };
+    supplied(weight) || (weight >= 700 && supplied(400))
+}
+
+/// Whether a bold font resource is drawn from its regular face
+///
+/// `font_name` is a font resource name such as `Roboto-Bold`. True for a
+/// bold Google Font variant without data in `font_bytes` (see
+/// [`has_font_data`]) whose regular face of the same style is supplied; the
+/// regular face is embedded in its place and the weight is faked.
+pub fn synthesizes_bold(font_bytes: &HashMap<String, Vec<u8>>, font_name: &str) -> bool {
+    let family = font_name.split('-').next().unwrap_or(font_name);
+    let variant = &font_name[family.len()..];
+    let is_italic = variant.contains("Italic");
+    is_google_font(family)
+        && variant.contains("Bold")
+        && !font_bytes.contains_key(&format!("{}:700:{}", family, is_italic))
+        && font_bytes.contains_key(&format!("{}:400:{}", family, is_italic))
+}
+
+/// Apply `policy` to every text box whose font variant is not supplied
+///
+/// For layouts built without font information; the layout engine applies
+/// the policy itself (see [`resolve_missing_font`]), leaving nothing for
+/// this pass to change but the reports. `is_supplied(family, weight,
+/// italic)` reports whether font data exists for a variant. Returns one
+/// [`FontSubstitution`] per distinct missing variant, in layout order.
+///
+/// # Errors
+///
+/// Returns `PDFError::FontError` naming the first missing variant under
+/// [`MissingFontPolicy::Error`].
+pub fn apply_missing_font_policy(
+    layout: &mut LayoutStructure,
+    policy: &MissingFontPolicy,
+    is_supplied: &dyn Fn(&str, u16, bool) -> bool,
+) -> Result<Vec<FontSubstitution>, PDFError> {
+    let mut substitutions = Vec::new();
+    for page in &mut layout.pages {
+        substitute_in_boxes(&mut page.boxes, policy, is_supplied, &mut substitutions)?;
+    }
+    Ok(substitutions)
+}
+
+fn substitute_in_boxes(
+    boxes: &mut [LayoutBox],
+    policy: &MissingFontPolicy,
+    is_supplied: &dyn Fn(&str, u16, bool) -> bool,
+    substitutions: &mut Vec<FontSubstitution>,
+) -> Result<(), PDFError> {
+    for layout_box in boxes {
+        match &mut layout_box.content {
+            BoxContent::Text(_) => {
+                if let Some(substitution) =
+                    resolve_missing_font(&mut layout_box.style, policy, is_supplied)?
+                {
+                    if !substitutions.contains(&substitution) {
+                        substitutions.push(substitution);
+                    }
+                }
+            }
+            BoxContent::Container(children) => {
+                substitute_in_boxes(children, policy, is_supplied, substitutions)?
+            }
+            BoxContent::Empty => {}
+        }
+    }
+    Ok(())
+}
+
+/// Apply `policy` to one style whose font variant may not be supplied
+///
+/// A named fallback rewrites the style's `font_family`; Standard 14
+/// substitution leaves it as is. Returns the substitution made, naming the
+/// font the renderer registers for the style, or `None` when the variant is
+/// supplied or needs no data.
+///
+/// # Errors
+///
+/// Returns `PDFError::FontError` naming the variant under
+/// [`MissingFontPolicy::Error`].
+pub fn resolve_missing_font(
+    style: &mut StyleDeclaration,
+    policy: &MissingFontPolicy,
+    is_supplied: &dyn Fn(&str, u16, bool) -> bool,
+) -> Result<Option<FontSubstitution>, PDFError> {
+    let Some(family) = style.text.font_family.clone() else {
+        return Ok(None);
+    };
+    let weight = variant_weight(style.text.font_weight);
+    let italic = matches!(
+        style.text.font_style,
+        Some(FontStyle::Italic | FontStyle::Oblique)
+    );
+    if !requires_font_data(&family) || is_supplied(&family, weight, italic) {
+        return Ok(None);
+    }
+
+    match policy {
+        MissingFontPolicy::Error => {
+            return Err(PDFError::FontError(format!(
+                "Font {}:{}:{} was requested but not supplied",
+                family, weight, italic
+            )));
+        }
+        MissingFontPolicy::SubstituteStandard14 => {}
+        MissingFontPolicy::SubstituteNamedFallback(fallback) => {
+            style.text.font_family = Some(fallback.clone());
+        }
+    }
+
+    Ok(Some(FontSubstitution {
+        family,
+        weight,
+        italic,
+        substitute: get_font_name(style).to_string(),
+    }))
+}
+
+/// Weight of the font file that renders `font_weight`
+fn variant_weight(font_weight: Option<FontWeight>) -> u16 {
+    match font_weight {
+        Some(FontWeight::Bold | FontWeight::Bolder) => 700,
+        Some(FontWeight::Lighter) => 300,
+        Some(FontWeight::Normal) | None => 400,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout_renderer::Page;
+    use layout_types::TextLine;
+
+    fn layout(family: &str, weight: FontWeight) -> LayoutStructure {
+        let mut style = StyleDeclaration::default();
+        style.text.font_family = Some(family.to_string());
+        style.text.font_weight = Some(weight);
+        LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![LayoutBox {
+                    x: 72.0,
+                    y: 72.0,
+                    width: 300.0,
+                    height: 20.0,
+                    content: BoxContent::Container(vec![LayoutBox {
+                        x: 72.0,
+                        y: 72.0,
+                        width: 300.0,
+                        height: 20.0,
+                        content: BoxContent::Text(vec![TextLine::from("Jane Doe")]),
+                        style,
+                        element_type: None,
+                        list_ordinal: None,
+                    }]),
+                    style: StyleDeclaration::default(),
+                    element_type: None,
+                    list_ordinal: None,
+                }],
+            }],
+            page_width: 612.0,
+            page_height: 792.0,
+        }
+    }
+
+    fn text_family(layout: &LayoutStructure) -> Option<&str> {
+        match &layout.pages[0].boxes[0].content {
+            BoxContent::Container(children) => children[0].style.text.font_family.as_deref(),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_supplied_and_builtin_fonts_are_not_substituted() {
+        let supplied = |family: &str, weight: u16, _: bool| family == "Roboto" && weight == 700;
+        for family in ["Roboto", "Georgia", "sans-serif"] {
+            let mut layout = layout(family, FontWeight::Bold);
+            let substitutions =
+                apply_missing_font_policy(&mut layout, &MissingFontPolicy::Error, &supplied)
+                    .unwrap();
+            assert!(substitutions.is_empty(), "{}", family);
+        }
+    }
+
+    #[test]
+    fn test_named_fallback_rewrites_font_family() {
+        let mut layout = layout("Roboto", FontWeight::Normal);
+        let policy = MissingFontPolicy::SubstituteNamedFallback("Georgia".to_string());
+        let substitutions =
+            apply_missing_font_policy(&mut layout, &policy, &|_, _, _| false).unwrap();
+
+        assert_eq!(text_family(&layout), Some("Georgia"));
+        assert_eq!(substitutions[0].substitute, "Times-Roman");
+        assert_eq!(substitutions[0].weight, 400);
+    }
+
+    #[test]
+    fn test_requires_font_data() {
+        assert!(requires_font_data("Roboto"));
+        assert!(requires_font_data("FooBrand"));
+        assert!(!requires_font_data("Times New Roman"));
+        assert!(!requires_font_data(" Arial "));
+        assert!(!requires_font_data("monospace"));
+        assert!(!requires_font_data(""));
+    }
+}
@@ -0,0 +1,187 @@
+//! Fonts referenced by the finished PDF
+//!
+//! Reports the fonts that page content streams actually select with `Tf`,
+//! rather than everything registered in page resources, so callers can check
+//! which faces made it into the output and whether they are embedded.
+
+use crate::error::PDFError;
+use lopdf::content::Content;
+use lopdf::{Dictionary, Document, Object};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A font selected by page content in the final PDF
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FontUsage {
+    /// Resource name used in content streams (e.g. `Roboto-Bold`)
+    pub resource_name: String,
+    /// `BaseFont` from the font dictionary (e.g. `Helvetica-Bold`)
+    pub base_font: String,
+    /// Whether the font program is embedded in the PDF
+    pub embedded: bool,
+    /// 1-based numbers of the pages that use the font
+    pub pages: Vec<usize>,
+}
+
+/// List the fonts referenced by page content in a finished PDF
+///
+/// Fonts registered in page resources but never selected with `Tf` are left
+/// out. Results are sorted by resource name.
+///
+/// # Errors
+///
+/// Returns `PDFError::RenderError` if the PDF or a content stream cannot be parsed.
+pub fn referenced_fonts(pdf_bytes: &[u8]) -> Result<Vec<FontUsage>, PDFError> {
+    let doc = Document::load_mem(pdf_bytes)
+        .map_err(|e| PDFError::RenderError(format!("Failed to load PDF: {}", e)))?;
+
+    let mut usages: BTreeMap<String, FontUsage> = BTreeMap::new();
+    for (page_number, page_id) in doc.get_pages() {
+        let fonts = doc.get_page_fonts(page_id).map_err(|e| {
+            PDFError::RenderError(format!(
+                "Failed to read fonts of page {}: {}",
+                page_number, e
+            ))
+        })?;
+        let used = content_font_names(&doc, page_id, page_number)?;
+
+        for name in used {
+            let Some(font) = fonts.get(name.as_bytes()) else {
+                continue;
+            };
+            let usage = usages.entry(name.clone()).or_insert_with(|| FontUsage {
+                resource_name: name,
+                base_font: font_name(font),
+                embedded: is_embedded(&doc, font),
+                pages: Vec::new(),
+            });
+            usage.pages.push(page_number as usize);
+        }
+    }
+
+    Ok(usages.into_values().collect())
+}
+
+/// Font resource names selected with `Tf` in a page's content
+fn content_font_names(
+    doc: &Document,
+    page_id: lopdf::ObjectId,
+    page_number: u32,
+) -> Result<BTreeSet<String>, PDFError> {
+    let data = doc.get_page_content(page_id).map_err(|e| {
+        PDFError::RenderError(format!(
+            "Failed to read content of page {}: {}",
+            page_number, e
+        ))
+    })?;
+    let content = Content::decode(&data).map_err(|e| {
+        PDFError::RenderError(format!(
+            "Failed to decode content of page {}: {}",
+            page_number, e
+        ))
+    })?;
+
+    Ok(content
+        .operations
+        .iter()
+        .filter(|op| op.operator == "Tf")
+        .filter_map(|op| op.operands.first()?.as_name().ok())
+        .map(|name| String::from_utf8_lossy(name).into_owned())
+        .collect())
+}
+
+fn font_name(font: &Dictionary) -> String {
+    font.get(b"BaseFont")
+        .and_then(Object::as_name)
+        .map(|name| String::from_utf8_lossy(name).into_owned())
+        .unwrap_or_default()
+}
+
+/// Whether the font's descriptor, or its first descendant's, carries a font program
+fn is_embedded(doc: &Document, font: &Dictionary) -> bool {
+    let descriptor = font
+        .get(b"FontDescriptor")
+        .ok()
+        .and_then(|obj| resolve_dict(doc, obj))
+        .or_else(|| {
+            let descendants = font.get(b"DescendantFonts").ok()?;
+            let descendants = match descendants {
+                Object::Reference(id) => doc.get_object(*id).ok()?,
+                other => other,
+            };
+            let descendant = resolve_dict(doc, descendants.as_array().ok()?.first()?)?;
+            resolve_dict(doc, descendant.get(b"FontDescriptor").ok()?)
+        });
+
+    descriptor.is_some_and(|descriptor| {
+        [b"FontFile".as_slice(), b"FontFile2", b"FontFile3"]
+            .iter()
+            .any(|key| descriptor.has(key))
+    })
+}
+
+fn resolve_dict<'a>(doc: &'a Document, obj: &'a Object) -> Option<&'a Dictionary> {
+    match obj {
+        Object::Reference(id) => doc.get_dictionary(*id).ok(),
+        other => other.as_dict().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::css_parser::{FontWeight, StyleDeclaration};
+    use crate::layout_renderer::{BoxContent, LayoutBox, LayoutStructure, Page};
+    use crate::{PDFConfig, PDFGenerator};
+    use layout_types::TextLine;
+
+    fn text_box(y: f64, text: &str, font_weight: Option<FontWeight>) -> LayoutBox {
+        let mut style = StyleDeclaration::default();
+        style.text.font_weight = font_weight;
+        LayoutBox {
+            x: 72.0,
+            y,
+            width: 300.0,
+            height: 20.0,
+            content: BoxContent::Text(vec![TextLine::from(text)]),
+            style,
+            element_type: None,
+            list_ordinal: None,
+        }
+    }
+
+    #[test]
+    fn test_regular_and_bold_fonts_are_referenced() {
+        let layout = LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![
+                    text_box(72.0, "Jane Doe", Some(FontWeight::Bold)),
+                    text_box(100.0, "Software Engineer", None),
+                ],
+            }],
+            page_width: 612.0,
+            page_height: 792.0,
+        };
+        let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+        generator.render_layout(&layout).unwrap();
+
+        let fonts = generator.referenced_fonts().unwrap();
+        let names: Vec<&str> = fonts.iter().map(|f| f.base_font.as_str()).collect();
+        assert!(names.contains(&"Helvetica"), "fonts: {:?}", fonts);
+        assert!(names.contains(&"Helvetica-Bold"), "fonts: {:?}", fonts);
+        assert!(fonts.iter().all(|f| f.pages == vec![1] && !f.embedded));
+        assert_eq!(
+            referenced_fonts(&generator.finalize().unwrap()).unwrap(),
+            fonts
+        );
+    }
+
+    #[test]
+    fn test_invalid_pdf_is_an_error() {
+        assert!(matches!(
+            referenced_fonts(b"not a pdf"),
+            Err(PDFError::RenderError(_))
+        ));
+    }
+}
@@ -32,6 +32,19 @@ pub fn estimate_text_width(text: &str, font_size: f64, font_name: &str) -> f64 {
         .sum()
 }
 
+/// Stroke width for synthesized bold, as a fraction of the font size
+///
+/// Fill+stroke rendering thickens each glyph outline by this amount, and the
+/// renderer widens each glyph's advance by the same amount (`Tc`).
+pub const SYNTHETIC_BOLD_STROKE_RATIO: f64 = 0.02;
+
+/// Extra width of text drawn in synthesized bold
+///
+/// Every glyph advances by the stroke width more than in the regular face.
+pub fn synthetic_bold_widening(text: &str, font_size: f64) -> f64 {
+    font_size * SYNTHETIC_BOLD_STROKE_RATIO * text.chars().count() as f64
+}
+
 /// Text measurer using accurate PDF font metrics
 ///
 /// This implementation uses actual character width tables from PDF Standard 14 fonts,
@@ -56,6 +69,45 @@ impl TextMeasurer for PDFTextMeasurer {
     }
 }
 
+/// Text measurer that accounts for synthesized bold
+///
+/// Measures like [`PDFTextMeasurer`], widening text in the fonts for which
+/// `is_synthetic_bold` holds by [`synthetic_bold_widening`], so lines are
+/// wrapped and aligned with the advances the renderer gives faked bold.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::fonts::{estimate_text_width, SyntheticBoldMeasurer};
+/// use layout_types::TextMeasurer;
+///
+/// let measurer = SyntheticBoldMeasurer::new(|font_name: &str| font_name == "Helvetica-Bold");
+/// let regular = estimate_text_width("Lead", 10.0, "Helvetica-Bold");
+/// assert!((measurer.measure_text("Lead", 10.0, "Helvetica-Bold") - regular - 0.8).abs() < 1e-9);
+/// assert_eq!(measurer.measure_text("Lead", 10.0, "Helvetica"), estimate_text_width("Lead", 10.0, "Helvetica"));
+/// ```
+pub struct SyntheticBoldMeasurer<F> {
+    is_synthetic_bold: F,
+}
+
+impl<F: Fn(&str) -> bool + Send + Sync> SyntheticBoldMeasurer<F> {
+    /// Create a measurer faking bold for fonts matching `is_synthetic_bold`
+    pub fn new(is_synthetic_bold: F) -> Self {
+        Self { is_synthetic_bold }
+    }
+}
+
+impl<F: Fn(&str) -> bool + Send + Sync> TextMeasurer for SyntheticBoldMeasurer<F> {
+    fn measure_text(&self, text: &str, font_size: f64, font_name: &str) -> f64 {
+        let width = estimate_text_width(text, font_size, font_name);
+        if (self.is_synthetic_bold)(font_name) {
+            width + synthetic_bold_widening(text, font_size)
+        } else {
+            width
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,4 +7,7 @@
 mod estimator;
 mod width_tables;
 
-pub use estimator::{estimate_text_width, PDFTextMeasurer};
+pub use estimator::{
+    estimate_text_width, synthetic_bold_widening, PDFTextMeasurer, SyntheticBoldMeasurer,
+    SYNTHETIC_BOLD_STROKE_RATIO,
+};
@@ -3,9 +3,11 @@
 //! This module provides the main PDFGenerator struct which coordinates PDF document
 //! generation through specialized sub-components.
 
-use crate::config::PDFConfig;
+use crate::config::{PDFConfig, PDFStandard};
+use crate::content_builder::{balance_graphics_state, ContentStats, RenderStats};
 use crate::encoding::escape_pdf_string;
 use crate::error::PDFError;
+use crate::font_usage::FontUsage;
 use crate::layout_analyzer::extract_all_text_from_layout;
 use crate::layout_renderer::LayoutStructure;
 use lopdf::{dictionary, Object};
@@ -14,6 +16,8 @@ use std::collections::HashSet;
 // Import modular components
 use crate::document_core::PDFDocumentCore;
 use crate::font_registry::PDFFontRegistry;
+use crate::font_substitution::{apply_missing_font_policy, FontSubstitution};
+use crate::page_furniture::add_page_furniture;
 use crate::page_manager::PDFPageManager;
 
 /// PDF document generator with support for multi-page layouts and custom fonts.
@@ -93,6 +97,12 @@ pub struct PDFGenerator {
     page_manager: PDFPageManager,
     font_registry: PDFFontRegistry,
     config: PDFConfig,
+    /// Baseline below the lowest `add_text` call on the current page (PDF coordinates)
+    cursor_y: f64,
+    /// Per-page content statistics, collected only when enabled
+    render_stats: Option<RenderStats>,
+    /// Requested fonts replaced by `missing_font_policy` in the last render
+    font_substitutions: Vec<FontSubstitution>,
 }
 
 impl PDFGenerator {
@@ -146,16 +156,27 @@ impl PDFGenerator {
 
         // Create page manager with first page
         let (width, height) = config.page_size.dimensions();
-        let page_manager = PDFPageManager::new(&mut document_core.doc, pages_id, width, height)?;
+        let mut page_manager =
+            PDFPageManager::new(&mut document_core.doc, pages_id, width, height)?;
+        page_manager.set_structured_reading_order(config.structured_reading_order);
+        page_manager.set_reading_order(config.reading_order);
+        page_manager.set_print_boxes(config.trim_box, config.bleed_box);
 
         // Create font registry
-        let font_registry = PDFFontRegistry::new();
+        let mut font_registry = PDFFontRegistry::new();
+        font_registry.set_allow_restricted_fonts(config.allow_restricted_fonts);
+        font_registry.set_font_quality(config.font_quality);
+
+        let cursor_y = height - config.margin.top;
 
         Ok(Self {
             document_core,
             page_manager,
             font_registry,
             config,
+            cursor_y,
+            render_stats: None,
+            font_substitutions: Vec::new(),
         })
     }
 
@@ -186,6 +207,43 @@ impl PDFGenerator {
         self.font_registry.set_font_bytes(font_bytes);
     }
 
+    /// Supplied fonts that failed to embed during rendering.
+    ///
+    /// Each failing font is replaced by its Standard 14 fallback and reported
+    /// once, so a corrupt font never aborts the conversion.
+    pub fn font_warnings(&self) -> &[String] {
+        self.font_registry.warnings()
+    }
+
+    /// Requested fonts that were not supplied, with what replaced them.
+    ///
+    /// Filled by `render_layout` according to `PDFConfig::missing_font_policy`;
+    /// one entry per missing family, weight and style.
+    pub fn font_substitutions(&self) -> &[FontSubstitution] {
+        &self.font_substitutions
+    }
+
+    /// Enables or disables collecting content stream statistics while rendering.
+    ///
+    /// Intended for debugging output size: once enabled, every page rendered by
+    /// `render_layout` records its operator counts and stream length.
+    pub fn set_collect_render_stats(&mut self, enabled: bool) {
+        self.render_stats = enabled.then(RenderStats::default);
+    }
+
+    /// Content stream statistics collected so far, if collection is enabled.
+    pub fn render_stats(&self) -> Option<&RenderStats> {
+        self.render_stats.as_ref()
+    }
+
+    /// Sets the source TSX to attach when `PDFConfig::embed_source` is enabled.
+    ///
+    /// The source is embedded as `resume.tsx` on finalize; without
+    /// `embed_source` it is ignored.
+    pub fn set_source(&mut self, tsx: &str) {
+        self.document_core.set_source(tsx.to_string());
+    }
+
     /// Adds a new blank page to the document.
     ///
     /// The new page will have the same dimensions as specified in the configuration.
@@ -213,7 +271,38 @@ impl PDFGenerator {
     pub fn add_page(&mut self) -> Result<(), PDFError> {
         let (width, height) = self.config.page_size.dimensions();
         self.page_manager
-            .add_page(&mut self.document_core.doc, width, height)
+            .add_page(&mut self.document_core.doc, width, height)?;
+        self.cursor_y = height - self.config.margin.top;
+        Ok(())
+    }
+
+    /// Forces a page break in programmatic mode.
+    ///
+    /// Closes the current page and starts a fresh one: subsequent `add_text`
+    /// calls draw on the new page, and [`cursor_y`](Self::cursor_y) is reset to
+    /// the top margin.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pdf_generator::{PDFGenerator, PDFConfig};
+    ///
+    /// let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+    /// generator.add_text("Experience", 72.0, generator.cursor_y(), 14.0).unwrap();
+    ///
+    /// generator.insert_page_break().unwrap();
+    /// generator.add_text("Education", 72.0, generator.cursor_y(), 14.0).unwrap();
+    /// ```
+    pub fn insert_page_break(&mut self) -> Result<(), PDFError> {
+        self.add_page()
+    }
+
+    /// Returns the next free baseline on the current page, in PDF coordinates.
+    ///
+    /// Starts at the top margin on each page and moves below every line
+    /// placed with `add_text` (one line height, 1.2 × font size).
+    pub fn cursor_y(&self) -> f64 {
+        self.cursor_y
     }
 
     /// Renders a complete layout structure to the PDF.
@@ -295,11 +384,35 @@ impl PDFGenerator {
     where
         F: Fn(f32),
     {
+        // Non-finite or off-page boxes would serialize into a broken PDF
+        layout
+            .validate()
+            .map_err(|e| PDFError::RenderError(e.to_string()))?;
+
+        // Give every email/URL its own segment so it can carry a link
+        let mut layout = if self.config.autolink {
+            crate::links::autolink_layout(layout, self.config.link_style.as_ref())
+        } else {
+            layout.clone()
+        };
+
+        // Requested fonts without supplied data are substituted or rejected
+        let font_registry = &self.font_registry;
+        self.font_substitutions = apply_missing_font_policy(
+            &mut layout,
+            &self.config.missing_font_policy,
+            &|family, weight, is_italic| font_registry.has_font(family, weight, is_italic),
+        )?;
+        let layout = &layout;
+
         // Store layout for bookmark extraction
         self.document_core.set_layout(layout.clone());
 
-        // Extract all text for font subsetting
-        let text_content = extract_all_text_from_layout(layout);
+        // Extract all text for font subsetting, including the watermark's
+        let mut text_content = extract_all_text_from_layout(layout);
+        if let Some(watermark) = &self.config.watermark {
+            text_content.push_str(&watermark.text);
+        }
         self.font_registry.set_text_content(text_content.clone());
         self.document_core.set_text_content(text_content);
 
@@ -314,6 +427,34 @@ impl PDFGenerator {
             }
         }
 
+        // Bold variants backed only by a regular face get a faked weight
+        let synthetic_bold = self.font_registry.synthetic_bold_fonts(&fonts);
+        // The Helvetica faces are replaced by Karla on finalize under PDF/A or
+        // with embed_base_fonts (see PDFDocumentCore::finalize)
+        let embeds_base_fonts =
+            self.config.standard == PDFStandard::PDFA1b || self.config.embed_base_fonts;
+        // Embedded fonts place underlines and strikethroughs from their own
+        // metrics, including the Karla faces embedded for Helvetica
+        let mut decoration_metrics = self.font_registry.decoration_metrics(&fonts);
+        if embeds_base_fonts {
+            decoration_metrics.extend(crate::standard_fonts::embedded_decoration_metrics());
+        }
+        // Text in embedded CIDFonts is written as CIDs, in Type1 fonts as
+        // WinAnsi bytes
+        let mut embedded_fonts = self.font_registry.embedded_fonts().clone();
+        if embeds_base_fonts {
+            embedded_fonts.extend(
+                crate::standard_fonts::EMBEDDED_BASE_FONTS
+                    .iter()
+                    .map(|name| name.to_string()),
+            );
+        }
+        let font_context = crate::layout_renderer::FontContext {
+            synthetic_bold,
+            decoration_metrics,
+            embedded_fonts,
+        };
+
         // Render each page
         let total_pages = layout.pages.len();
         for (page_idx, page) in layout.pages.iter().enumerate() {
@@ -324,7 +465,20 @@ impl PDFGenerator {
 
             // Render page content
             let page_id = self.page_manager.current_page_id();
-            let content = crate::layout_renderer::render_page_to_content(page, layout.page_height)?;
+            let mut content = crate::layout_renderer::render_page_to_content_with_fonts(
+                page,
+                layout.page_width,
+                layout.page_height,
+                &font_context,
+            )?;
+            let corrected_imbalance = balance_graphics_state(&mut content);
+
+            if let Some(stats) = self.render_stats.as_mut() {
+                stats.pages.push(ContentStats {
+                    corrected_imbalance,
+                    ..ContentStats::from_content(&content)
+                });
+            }
 
             // Update page content stream
             let content_id = {
@@ -354,6 +508,15 @@ impl PDFGenerator {
                 }
             }
 
+            // Make anchors (and, with autolink, emails and URLs) clickable
+            let links = crate::layout_renderer::collect_page_links(
+                page,
+                layout.page_height,
+                &font_context.synthetic_bold,
+                self.config.autolink,
+            );
+            crate::links::add_link_annotations(&mut self.document_core.doc, page_id, &links)?;
+
             // Report progress
             if let Some(callback) = progress_callback {
                 let progress = ((page_idx + 1) as f32 / total_pages as f32) * 100.0;
@@ -454,7 +617,7 @@ impl PDFGenerator {
                 new_content.push('\n');
             }
             new_content.push_str(&format!(
-                "BT\n/F1 {} Tf\n{} {} Td\n({}) Tj\nET",
+                "BT\n/Helvetica {} Tf\n{} {} Td\n({}) Tj\nET",
                 font_size, x, y, escaped
             ));
 
@@ -469,6 +632,10 @@ impl PDFGenerator {
             }
         }
 
+        self.cursor_y = self
+            .cursor_y
+            .min(y - font_size * layout_types::DEFAULT_LINE_HEIGHT_RATIO);
+
         Ok(())
     }
 
@@ -503,11 +670,90 @@ impl PDFGenerator {
     pub fn finalize(mut self) -> Result<Vec<u8>, PDFError> {
         // Update page count in pages tree
         self.page_manager.finalize(&mut self.document_core.doc)?;
+        add_page_furniture(
+            &mut self.document_core.doc,
+            &self.config,
+            self.page_manager.page_ids(),
+        )?;
 
         // Finalize document (adds PDF/A compliance, etc.)
         let page_count = self.page_manager.page_count();
         self.document_core.finalize(page_count)
     }
+
+    /// Finalizes the document and returns the collected render statistics.
+    ///
+    /// The statistics are `None` unless collection was enabled with
+    /// [`set_collect_render_stats`](Self::set_collect_render_stats).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pdf_generator::{PDFGenerator, PDFConfig};
+    /// # use pdf_generator::LayoutStructure;
+    /// # let layout = LayoutStructure { page_width: 612.0, page_height: 792.0, pages: vec![] };
+    ///
+    /// let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+    /// generator.set_collect_render_stats(true);
+    /// generator.render_layout(&layout).unwrap();
+    ///
+    /// let (pdf_bytes, stats) = generator.finalize_with_stats().unwrap();
+    /// if let Some(stats) = stats {
+    ///     println!("{} Tj/TJ operators", stats.total().text_show_ops);
+    /// }
+    /// ```
+    pub fn finalize_with_stats(mut self) -> Result<(Vec<u8>, Option<RenderStats>), PDFError> {
+        let stats = self.render_stats.take();
+        Ok((self.finalize()?, stats))
+    }
+
+    /// Finalize a snapshot of the PDF document without consuming the generator
+    ///
+    /// Serializes a copy of the current document, leaving the generator untouched
+    /// so more content can be added and the document finalized again. Useful for
+    /// incremental previews, or for retrying after a serialization failure.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - PDF document as bytes
+    /// * `Err(PDFError)` - If finalization or serialization fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pdf_generator::{PDFGenerator, PDFConfig};
+    ///
+    /// let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+    /// generator.add_text("Draft", 100.0, 700.0, 12.0).unwrap();
+    /// let preview = generator.finalize_bytes().unwrap();
+    ///
+    /// generator.add_text("More content", 100.0, 680.0, 12.0).unwrap();
+    /// let pdf_bytes = generator.finalize_bytes().unwrap();
+    /// ```
+    pub fn finalize_bytes(&self) -> Result<Vec<u8>, PDFError> {
+        let mut document_core = self.document_core.clone();
+        self.page_manager.finalize(&mut document_core.doc)?;
+        add_page_furniture(
+            &mut document_core.doc,
+            &self.config,
+            self.page_manager.page_ids(),
+        )?;
+
+        let page_count = self.page_manager.page_count();
+        document_core.finalize(page_count)
+    }
+
+    /// List the fonts referenced by page content in the current document
+    ///
+    /// Finalizes a snapshot (see [`finalize_bytes`](Self::finalize_bytes)) and
+    /// reports the fonts its content streams select, with the pages using each.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if finalization fails or the snapshot cannot be parsed.
+    pub fn referenced_fonts(&self) -> Result<Vec<FontUsage>, PDFError> {
+        crate::font_usage::referenced_fonts(&self.finalize_bytes()?)
+    }
 }
 
 #[cfg(test)]
@@ -599,6 +845,7 @@ mod tests {
                     content: BoxContent::Text(vec![TextLine::from("Test")]),
                     style: StyleDeclaration::default(),
                     element_type: None,
+                    list_ordinal: None,
                 }],
             }],
             page_width: 612.0,
@@ -608,6 +855,290 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_render_stats_count_operators_per_page() {
+        let layout = LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![LayoutBox {
+                    x: 72.0,
+                    y: 72.0,
+                    width: 200.0,
+                    height: 40.0,
+                    content: BoxContent::Text(vec![TextLine::from("One"), TextLine::from("Two")]),
+                    style: StyleDeclaration::default(),
+                    element_type: None,
+                    list_ordinal: None,
+                }],
+            }],
+            page_width: 612.0,
+            page_height: 792.0,
+        };
+
+        let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+        assert!(generator.render_stats().is_none());
+        generator.set_collect_render_stats(true);
+        generator.render_layout(&layout).unwrap();
+
+        let content =
+            crate::layout_renderer::render_page_to_content(&layout.pages[0], 612.0, 792.0).unwrap();
+        let (_, stats) = generator.finalize_with_stats().unwrap();
+        let stats = stats.expect("stats were enabled");
+        assert_eq!(stats.pages.len(), 1);
+        let page = stats.pages[0];
+        // One fill color and one Tj per line
+        assert_eq!(page.text_show_ops, 2);
+        assert_eq!(page.graphics_ops, 2);
+        assert_eq!(page.stream_bytes, content.len());
+        assert!(page.corrected_imbalance.is_balanced());
+        assert_eq!(stats.total(), page);
+    }
+
+    #[test]
+    fn test_render_layout_rejects_non_finite_geometry() {
+        let layout = LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![LayoutBox {
+                    x: 72.0,
+                    y: 72.0,
+                    width: f64::NAN,
+                    height: 20.0,
+                    content: BoxContent::Text(vec![TextLine::from("Jane Doe")]),
+                    style: StyleDeclaration::default(),
+                    element_type: None,
+                    list_ordinal: None,
+                }],
+            }],
+            page_width: 612.0,
+            page_height: 792.0,
+        };
+        let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+
+        let error = generator.render_layout(&layout).unwrap_err();
+        assert!(matches!(error, PDFError::RenderError(_)));
+        assert!(error.to_string().contains("width is NaN"));
+    }
+
+    fn render_linked_text(link_style: Option<crate::LinkStyle>) -> (String, Vec<[f32; 4]>) {
+        let layout = LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![LayoutBox {
+                    x: 72.0,
+                    y: 72.0,
+                    width: 300.0,
+                    height: 20.0,
+                    content: BoxContent::Text(vec![TextLine::from("Contact: jane@example.com")]),
+                    style: StyleDeclaration::default(),
+                    element_type: None,
+                    list_ordinal: None,
+                }],
+            }],
+            page_width: 612.0,
+            page_height: 792.0,
+        };
+        let config = PDFConfig {
+            autolink: true,
+            link_style,
+            ..Default::default()
+        };
+        let mut generator = PDFGenerator::new(config).unwrap();
+        generator.render_layout(&layout).unwrap();
+        let pdf_bytes = generator.finalize().unwrap();
+
+        let doc = lopdf::Document::load_mem(&pdf_bytes).unwrap();
+        let page_id = doc.get_pages()[&1];
+        let content = String::from_utf8_lossy(&doc.get_page_content(page_id).unwrap()).to_string();
+        let annots = doc
+            .get_dictionary(page_id)
+            .unwrap()
+            .get(b"Annots")
+            .and_then(|obj| obj.as_array())
+            .cloned()
+            .unwrap_or_default();
+        let rects = annots
+            .iter()
+            .map(|annot| {
+                let dict = doc.get_dictionary(annot.as_reference().unwrap()).unwrap();
+                assert_eq!(dict.get(b"Subtype").unwrap().as_name().unwrap(), b"Link");
+                let action = dict.get(b"A").unwrap().as_dict().unwrap();
+                assert_eq!(
+                    action.get(b"URI").unwrap().as_str().unwrap(),
+                    b"mailto:jane@example.com"
+                );
+                let rect = dict.get(b"Rect").unwrap().as_array().unwrap();
+                let values: Vec<f32> = rect.iter().map(|v| v.as_float().unwrap()).collect();
+                [values[0], values[1], values[2], values[3]]
+            })
+            .collect();
+        (content, rects)
+    }
+
+    #[test]
+    fn test_link_style_colors_and_underlines_covered_text() {
+        let style = crate::LinkStyle::default();
+        let (content, rects) = render_linked_text(Some(style));
+
+        let link_rgb = format!("0 0 {} rg", style.color.b as f64 / 255.0);
+        assert!(content.contains(&link_rgb), "link color missing: {content}");
+
+        // The underline is the only path on the page
+        let point = |op: &str| -> (f32, f32) {
+            let line = content
+                .lines()
+                .find(|line| line.ends_with(op))
+                .unwrap_or_else(|| panic!("no `{op}` in {content}"));
+            let nums: Vec<f32> = line
+                .split(' ')
+                .take(2)
+                .map(|n| n.parse().unwrap())
+                .collect();
+            (nums[0], nums[1])
+        };
+        let (start_x, underline_y) = point(" m");
+        let (end_x, _) = point(" l");
+
+        assert_eq!(rects.len(), 1);
+        let [llx, lly, urx, ury] = rects[0];
+        assert!(llx <= start_x + 0.01 && end_x - 0.01 <= urx);
+        assert!(lly <= underline_y && underline_y <= ury);
+        assert!(llx > 72.0, "link must not cover the 'Contact: ' label");
+    }
+
+    #[test]
+    fn test_links_keep_author_styling_without_link_style() {
+        let (content, rects) = render_linked_text(None);
+
+        assert_eq!(rects.len(), 1, "links stay clickable");
+        assert!(!content.contains(" m\n"), "no underline drawn");
+        assert!(content.contains("0 0 0 rg"));
+        assert!(!content.contains(" 0.9333"));
+    }
+
+    #[test]
+    fn test_links_are_not_annotated_by_default() {
+        let layout = LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![LayoutBox {
+                    x: 72.0,
+                    y: 72.0,
+                    width: 300.0,
+                    height: 20.0,
+                    content: BoxContent::Text(vec![TextLine::from("Contact: jane@example.com")]),
+                    style: StyleDeclaration::default(),
+                    element_type: None,
+                    list_ordinal: None,
+                }],
+            }],
+            page_width: 612.0,
+            page_height: 792.0,
+        };
+        let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+        generator.render_layout(&layout).unwrap();
+        let pdf_bytes = generator.finalize().unwrap();
+
+        let doc = lopdf::Document::load_mem(&pdf_bytes).unwrap();
+        let page_id = doc.get_pages()[&1];
+        let page = doc.get_dictionary(page_id).unwrap();
+        assert!(page.get(b"Annots").is_err(), "no link annotations");
+    }
+
+    /// URIs of page 1's annotations for a two-column layout, sidebar first in source
+    fn two_column_link_order(reading_order: crate::ReadingOrder) -> Vec<String> {
+        let link = |x: f64, y: f64, email: &str| LayoutBox {
+            x,
+            y,
+            width: 200.0,
+            height: 20.0,
+            content: BoxContent::Text(vec![TextLine::from(email)]),
+            style: StyleDeclaration::default(),
+            element_type: None,
+            list_ordinal: None,
+        };
+        let column = |x: f64, links: Vec<LayoutBox>| LayoutBox {
+            x,
+            y: 72.0,
+            width: 200.0,
+            height: 300.0,
+            content: BoxContent::Container(links),
+            style: StyleDeclaration::default(),
+            element_type: None,
+            list_ordinal: None,
+        };
+        let layout = LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![
+                    column(
+                        350.0,
+                        vec![
+                            link(350.0, 72.0, "side1@example.com"),
+                            link(350.0, 200.0, "side2@example.com"),
+                        ],
+                    ),
+                    column(
+                        72.0,
+                        vec![
+                            link(72.0, 100.0, "main1@example.com"),
+                            link(72.0, 300.0, "main2@example.com"),
+                        ],
+                    ),
+                ],
+            }],
+            page_width: 612.0,
+            page_height: 792.0,
+        };
+        let config = PDFConfig {
+            autolink: true,
+            reading_order,
+            ..Default::default()
+        };
+        let mut generator = PDFGenerator::new(config).unwrap();
+        generator.render_layout(&layout).unwrap();
+        let doc = lopdf::Document::load_mem(&generator.finalize().unwrap()).unwrap();
+
+        let page_id = doc.get_pages()[&1];
+        let page = doc.get_dictionary(page_id).unwrap();
+        page.get(b"Annots")
+            .and_then(|obj| obj.as_array())
+            .unwrap()
+            .iter()
+            .map(|annot| {
+                let dict = doc.get_dictionary(annot.as_reference().unwrap()).unwrap();
+                let action = dict.get(b"A").unwrap().as_dict().unwrap();
+                let uri = action.get(b"URI").unwrap().as_str().unwrap();
+                String::from_utf8_lossy(uri)
+                    .trim_start_matches("mailto:")
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dom_reading_order_follows_source_columns() {
+        assert_eq!(
+            two_column_link_order(crate::ReadingOrder::DomOrder),
+            [
+                "side1@example.com",
+                "side2@example.com",
+                "main1@example.com",
+                "main2@example.com",
+            ]
+        );
+        // Geometric order interleaves the columns by Y
+        assert_eq!(
+            two_column_link_order(crate::ReadingOrder::Geometric),
+            [
+                "side1@example.com",
+                "main1@example.com",
+                "side2@example.com",
+                "main2@example.com",
+            ]
+        );
+    }
+
     #[test]
     fn test_render_layout_with_progress() {
         let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
@@ -640,6 +1171,54 @@ mod tests {
         assert!(pdf_bytes.len() > 100);
     }
 
+    #[test]
+    fn test_insert_page_break() {
+        let config = PDFConfig {
+            margin: Margin::from_inches(1.0),
+            ..Default::default()
+        };
+        let mut generator = PDFGenerator::new(config).unwrap();
+        assert_eq!(generator.cursor_y(), 720.0);
+
+        generator
+            .add_text("First page", 72.0, generator.cursor_y(), 10.0)
+            .unwrap();
+        assert_eq!(generator.cursor_y(), 708.0);
+
+        generator.insert_page_break().unwrap();
+        assert_eq!(generator.cursor_y(), 720.0, "Cursor resets to top margin");
+        generator
+            .add_text("Second page", 72.0, generator.cursor_y(), 10.0)
+            .unwrap();
+
+        let pdf_bytes = generator.finalize().unwrap();
+        let doc = lopdf::Document::load_mem(&pdf_bytes).unwrap();
+        let pages = doc.get_pages();
+        assert_eq!(pages.len(), 2);
+
+        let page_text = |number: u32| {
+            String::from_utf8_lossy(&doc.get_page_content(pages[&number]).unwrap()).to_string()
+        };
+        assert!(page_text(1).contains("(First page) Tj"));
+        assert!(!page_text(1).contains("Second page"));
+        assert!(page_text(2).contains("(Second page) Tj"));
+        assert!(!page_text(2).contains("First page"));
+    }
+
+    #[test]
+    fn test_add_text_selects_registered_font() {
+        let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+        generator.add_text("Jane Doe", 72.0, 700.0, 12.0).unwrap();
+        generator.insert_page_break().unwrap();
+        generator.add_text("Experience", 72.0, 700.0, 12.0).unwrap();
+
+        // The font selected with Tf must be in each page's font resources
+        let fonts = generator.referenced_fonts().unwrap();
+        assert_eq!(fonts.len(), 1);
+        assert_eq!(fonts[0].base_font, "Helvetica");
+        assert_eq!(fonts[0].pages, vec![1, 2]);
+    }
+
     #[test]
     fn test_metadata_in_output() {
         let config = PDFConfig {
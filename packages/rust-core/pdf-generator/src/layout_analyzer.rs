@@ -3,7 +3,10 @@
 //! This module provides utilities for analyzing layout structures,
 //! including text extraction and size estimation.
 
-use layout_types::{BoxContent, LayoutBox, LayoutStructure, Page};
+use layout_types::{
+    BoxContent, Color, LayoutBox, LayoutStructure, Page, DEFAULT_FONT_SIZE,
+    DEFAULT_LINE_HEIGHT_RATIO,
+};
 
 /// Extract all text content from a layout structure for font subsetting.
 ///
@@ -36,6 +39,7 @@ use layout_types::{BoxContent, LayoutBox, LayoutStructure, Page};
 ///             content: BoxContent::Text(vec![TextLine::from("Hello")]),
 ///             style: StyleDeclaration::default(),
 ///             element_type: None,
+///             list_ordinal: None,
 ///         }],
 ///     }],
 ///     page_height: 792.0,
@@ -72,6 +76,120 @@ pub fn extract_all_text_from_layout(layout: &LayoutStructure) -> String {
     all_text
 }
 
+/// Extract the text that will actually be visible in the PDF, in reading order.
+///
+/// Unlike [`extract_all_text_from_layout`], this skips text a reader (or an
+/// ATS parsing the PDF) would never see:
+/// - lines lying entirely outside the page, which the viewer clips away
+/// - boxes with zero opacity and text with a zero font size or transparent color
+/// - text whose color matches the background it is painted on (e.g. white on white)
+///
+/// Lines are joined with spaces, pages and boxes in document order.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::layout_analyzer::extract_rendered_text;
+/// use layout_types::{LayoutStructure, Page, LayoutBox, BoxContent, StyleDeclaration, TextLine};
+///
+/// let layout = LayoutStructure {
+///     pages: vec![Page {
+///         page_number: 1,
+///         boxes: vec![LayoutBox {
+///             x: 0.0,
+///             y: 0.0,
+///             width: 100.0,
+///             height: 20.0,
+///             content: BoxContent::Text(vec![TextLine::from("Hello")]),
+///             style: StyleDeclaration::default(),
+///             element_type: None,
+///             list_ordinal: None,
+///         }],
+///     }],
+///     page_height: 792.0,
+///     page_width: 612.0,
+/// };
+///
+/// assert_eq!(extract_rendered_text(&layout), "Hello");
+/// ```
+pub fn extract_rendered_text(layout: &LayoutStructure) -> String {
+    let mut parts = Vec::new();
+
+    for page in &layout.pages {
+        for layout_box in &page.boxes {
+            collect_rendered_text(layout, layout_box, Color::WHITE, &mut parts);
+        }
+    }
+
+    parts.join(" ")
+}
+
+/// Collects visible text from a box, tracking the background it paints on
+fn collect_rendered_text(
+    layout: &LayoutStructure,
+    layout_box: &LayoutBox,
+    background: Color,
+    parts: &mut Vec<String>,
+) {
+    if layout_box.style.box_model.opacity.is_some_and(|o| o <= 0.0) {
+        return;
+    }
+
+    let background = layout_box
+        .style
+        .box_model
+        .background_color
+        .filter(|bg| bg.a > 0.0)
+        .unwrap_or(background);
+
+    match &layout_box.content {
+        BoxContent::Text(lines) => {
+            let text = &layout_box.style.text;
+            let font_size = text.font_size.unwrap_or(DEFAULT_FONT_SIZE);
+            let line_height = text
+                .line_height
+                .unwrap_or(font_size * DEFAULT_LINE_HEIGHT_RATIO);
+            let base_color = text.color.unwrap_or(Color::BLACK);
+
+            let off_page_horizontally =
+                layout_box.x >= layout.page_width || layout_box.x + layout_box.width <= 0.0;
+
+            for (line_index, line) in lines.iter().enumerate() {
+                let line_top = layout_box.y + line_index as f64 * line_height;
+                if off_page_horizontally
+                    || line_top >= layout.page_height
+                    || line_top + line_height <= 0.0
+                {
+                    continue;
+                }
+
+                let visible: String = line
+                    .segments
+                    .iter()
+                    .filter(|seg| seg.font_size.unwrap_or(font_size) > 0.0)
+                    .filter(|seg| is_visible_on(seg.color.unwrap_or(base_color), background))
+                    .map(|seg| seg.text.as_str())
+                    .collect();
+                let visible = visible.trim();
+                if !visible.is_empty() {
+                    parts.push(visible.to_string());
+                }
+            }
+        }
+        BoxContent::Container(children) => {
+            for child in children {
+                collect_rendered_text(layout, child, background, parts);
+            }
+        }
+        BoxContent::Empty => {}
+    }
+}
+
+/// Whether text in `color` can be seen on `background`
+fn is_visible_on(color: Color, background: Color) -> bool {
+    color.a > 0.0 && (color.r, color.g, color.b) != (background.r, background.g, background.b)
+}
+
 /// Estimates the size in bytes of the PDF content stream for a page.
 ///
 /// This provides a conservative estimate to pre-allocate string buffers,
@@ -147,6 +265,7 @@ mod tests {
                     content: BoxContent::Text(vec![TextLine::from("Hello World")]),
                     style: StyleDeclaration::default(),
                     element_type: None,
+                    list_ordinal: None,
                 }],
             }],
             page_height: 792.0,
@@ -176,6 +295,7 @@ mod tests {
                             content: BoxContent::Text(vec![TextLine::from("First")]),
                             style: StyleDeclaration::default(),
                             element_type: None,
+                            list_ordinal: None,
                         },
                         LayoutBox {
                             x: 10.0,
@@ -185,10 +305,12 @@ mod tests {
                             content: BoxContent::Text(vec![TextLine::from("Second")]),
                             style: StyleDeclaration::default(),
                             element_type: None,
+                            list_ordinal: None,
                         },
                     ]),
                     style: StyleDeclaration::default(),
                     element_type: None,
+                    list_ordinal: None,
                 }],
             }],
             page_height: 792.0,
@@ -223,6 +345,7 @@ mod tests {
                 content: BoxContent::Empty,
                 style: StyleDeclaration::default(),
                 element_type: None,
+                list_ordinal: None,
             }],
         };
 
@@ -242,6 +365,7 @@ mod tests {
                 content: BoxContent::Empty,
                 style: StyleDeclaration::default(),
                 element_type: None,
+                list_ordinal: None,
             }],
         };
 
@@ -267,6 +391,7 @@ mod tests {
                         content: BoxContent::Empty,
                         style: StyleDeclaration::default(),
                         element_type: None,
+                        list_ordinal: None,
                     },
                     LayoutBox {
                         x: 10.0,
@@ -276,14 +401,99 @@ mod tests {
                         content: BoxContent::Empty,
                         style: StyleDeclaration::default(),
                         element_type: None,
+                        list_ordinal: None,
                     },
                 ]),
                 style: StyleDeclaration::default(),
                 element_type: None,
+                list_ordinal: None,
             }],
         };
 
         let size = estimate_content_size(&page);
         assert_eq!(size, 300); // 3 boxes (1 parent + 2 children) * 100 bytes
     }
+
+    fn text_box(x: f64, y: f64, lines: &[&str], style: StyleDeclaration) -> LayoutBox {
+        LayoutBox {
+            x,
+            y,
+            width: 200.0,
+            height: lines.len() as f64 * 14.4,
+            content: BoxContent::Text(lines.iter().map(|l| TextLine::from(*l)).collect()),
+            style,
+            element_type: None,
+            list_ordinal: None,
+        }
+    }
+
+    fn single_page(boxes: Vec<LayoutBox>) -> LayoutStructure {
+        LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes,
+            }],
+            page_height: 792.0,
+            page_width: 612.0,
+        }
+    }
+
+    #[test]
+    fn test_extract_rendered_text_excludes_overflowed_text() {
+        let layout = single_page(vec![
+            text_box(0.0, 0.0, &["Visible"], StyleDeclaration::default()),
+            // Last line overflows past the bottom edge of the page
+            text_box(
+                0.0,
+                780.0,
+                &["Bottom line", "Clipped line"],
+                StyleDeclaration::default(),
+            ),
+            // Pushed off the right edge of the page
+            text_box(650.0, 100.0, &["Off page"], StyleDeclaration::default()),
+        ]);
+
+        let rendered = extract_rendered_text(&layout);
+        assert_eq!(rendered, "Visible Bottom line");
+
+        // The unfiltered extraction still sees everything
+        let all_text = extract_all_text_from_layout(&layout);
+        assert!(all_text.contains("Clipped line"));
+        assert!(all_text.contains("Off page"));
+    }
+
+    #[test]
+    fn test_extract_rendered_text_excludes_hidden_text() {
+        let mut white_text = StyleDeclaration::default();
+        white_text.text.color = Some(Color::WHITE);
+
+        let mut transparent = StyleDeclaration::default();
+        transparent.box_model.opacity = Some(0.0);
+
+        let mut dark_panel = StyleDeclaration::default();
+        dark_panel.box_model.background_color = Some(Color::rgb(30, 41, 59));
+        let panel = LayoutBox {
+            x: 0.0,
+            y: 100.0,
+            width: 200.0,
+            height: 50.0,
+            content: BoxContent::Container(vec![text_box(
+                10.0,
+                110.0,
+                &["On dark"],
+                white_text.clone(),
+            )]),
+            style: dark_panel,
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        let layout = single_page(vec![
+            text_box(0.0, 0.0, &["White on white"], white_text),
+            text_box(0.0, 20.0, &["Faded out"], transparent),
+            panel,
+        ]);
+
+        assert_eq!(extract_rendered_text(&layout), "On dark");
+    }
 }
@@ -3,22 +3,26 @@
 //! This module provides functions to render positioned layout boxes into PDF
 //! content streams with proper text, colors, and styling.
 
-use crate::content_builder::ContentBuilder;
+use crate::content_builder::{ContentBuilder, GraphicsStateImbalance};
 use crate::css_parser::{Color, StyleDeclaration};
-use crate::encoding::encode_as_cidfont_hex;
+use crate::encoding::encode_text_hex;
 use crate::error::PDFError;
 use crate::font_resolver::get_font_name;
-use crate::fonts::estimate_text_width;
+use crate::fonts::{estimate_text_width, SYNTHETIC_BOLD_STROKE_RATIO};
 use crate::layout_analyzer::estimate_content_size;
+use crate::links::{link_uri, LinkAnnotation};
 use crate::pdf_operators::{
-    render_background, render_border_bottom, render_list_bullet, render_text_decoration,
+    render_background, render_border_side, render_full_bleed_background, render_list_bullet,
+    render_text_decoration, BorderSide,
 };
-use crate::text_utils::{apply_text_transform, calculate_text_alignment_offset};
+use crate::text_utils::{apply_text_transform, calculate_text_alignment_offset, line_text_align};
 
 // Import shared layout types from layout-types crate
+use font_toolkit::DecorationMetrics;
 pub use layout_types::{BoxContent, ElementType, LayoutBox, LayoutStructure, Page};
-use layout_types::{FontStyle, FontWeight, TextDecoration, TextLine};
+use layout_types::{FontStyle, FontWeight, TextAlign, TextDecoration, TextLine};
 use layout_types::{DEFAULT_FONT_SIZE, DEFAULT_LINE_HEIGHT_RATIO};
+use std::collections::{HashMap, HashSet};
 
 // Re-export extracted functions for backward compatibility
 pub use crate::layout_analyzer::extract_all_text_from_layout;
@@ -33,6 +37,9 @@ const DEFAULT_PAGE_MARGIN: f64 = 36.0;
 /// Values > 1.3 indicate significant line-height that needs adjustment
 const LINE_HEIGHT_ADJUSTMENT_THRESHOLD: f64 = 1.3;
 
+/// Separates the columns of a tabbed line (`Engineer\t2020`)
+const TAB: char = '\t';
+
 /// Leading correction factor to improve vertical text positioning accuracy
 /// Applied as 95% of calculated leading to account for font metrics variations
 const LEADING_CORRECTION_FACTOR: f64 = 0.95;
@@ -56,48 +63,148 @@ pub fn render_layout_to_content(layout: &LayoutStructure) -> Result<String, PDFE
         .pages
         .first()
         .ok_or_else(|| PDFError::RenderError("Layout has no pages".to_string()))
-        .and_then(|page| render_page_to_content(page, layout.page_height))
+        .and_then(|page| render_page_to_content(page, layout.page_width, layout.page_height))
 }
 
 /// Debug flag to render page content boundaries
 /// Set to true to visualize margins and content area
 const DEBUG_PAGE_BORDERS: bool = false;
 
+/// Fonts registered with the document that change how text is drawn
+///
+/// Built by [`PDFGenerator`](crate::PDFGenerator) from its font registry. The
+/// default describes a document using only the Standard 14 fonts.
+#[derive(Debug, Clone, Default)]
+pub struct FontContext {
+    /// Font resource names whose bold face is backed by a regular face (see
+    /// `PDFFontRegistry::synthetic_bold_fonts`); text in these fonts is drawn
+    /// with fill+stroke (`2 Tr`) and widened character spacing (`Tc`)
+    pub synthetic_bold: HashSet<String>,
+    /// Underline and strikeout metrics by font resource name (see
+    /// `PDFFontRegistry::decoration_metrics`); decorations of fonts without an
+    /// entry are placed by font-size ratios
+    pub decoration_metrics: HashMap<String, DecorationMetrics>,
+    /// Font resource names backed by embedded CIDFonts (see
+    /// `PDFFontRegistry::embedded_fonts`); their text is written as 2-byte
+    /// CIDs, text in all other fonts as WinAnsi bytes
+    pub embedded_fonts: HashSet<String>,
+}
+
 /// Render a single page to PDF content stream
 ///
 /// # Arguments
 /// * `page` - The page with positioned boxes
+/// * `page_width` - Width of the page in points
 /// * `page_height` - Height of the page in points
 ///
 /// # Returns
 /// A string containing PDF content stream commands for this page
-pub fn render_page_to_content(page: &Page, page_height: f64) -> Result<String, PDFError> {
+pub fn render_page_to_content(
+    page: &Page,
+    page_width: f64,
+    page_height: f64,
+) -> Result<String, PDFError> {
+    render_page_to_content_with_fonts(page, page_width, page_height, &FontContext::default())
+}
+
+/// Render a single page using the document's registered fonts
+///
+/// # Arguments
+/// * `page` - The page with positioned boxes
+/// * `page_width` - Width of the page in points
+/// * `page_height` - Height of the page in points
+/// * `fonts` - Synthetic bold, decoration metrics and encoding of the
+///   document's fonts
+///
+/// # Returns
+/// A string containing PDF content stream commands for this page
+///
+/// # Panics
+/// In debug builds, if the stream leaves the graphics state unbalanced, so
+/// renderer bugs fail tests. Release builds rely on
+/// [`PDFGenerator`](crate::PDFGenerator) correcting the imbalance with
+/// [`balance_graphics_state`](crate::content_builder::balance_graphics_state).
+pub fn render_page_to_content_with_fonts(
+    page: &Page,
+    page_width: f64,
+    page_height: f64,
+    fonts: &FontContext,
+) -> Result<String, PDFError> {
     // Pre-allocate string buffer based on estimated content size
     let estimated_size = estimate_content_size(page);
     let mut content = String::with_capacity(estimated_size);
 
     // Debug: render page content boundaries
     if DEBUG_PAGE_BORDERS {
-        render_debug_page_borders(page, page_height, &mut content)?;
+        render_debug_page_borders(page, page_height, &fonts.embedded_fonts, &mut content)?;
     }
 
-    // Render all boxes on this page
+    // Paint full-bleed backgrounds first so they sit beneath all other content
+    render_full_bleed_backgrounds(&page.boxes, page_width, page_height, &mut content)?;
+
+    // Render all boxes on this page in CSS paint order: every background and
+    // border first, then text, so a later box's background never covers
+    // earlier text
     for layout_box in &page.boxes {
-        render_box_to_content(layout_box, page_height, &mut content)?;
+        render_box_decorations(layout_box, page_height, &mut content)?;
+    }
+    for layout_box in &page.boxes {
+        render_box_foreground(layout_box, page_height, fonts, &mut content)?;
     }
 
     // Add page number on pages 2+ (AC6)
     // Position: bottom-center, 0.5" from bottom
     if page.page_number > 1 {
-        // Note: page_width should come from LayoutStructure but Page doesn't store it
-        // Using standard letter width (612pt) as fallback until Page struct is updated
-        let page_width = 612.0;
-        render_page_number(page.page_number, page_height, page_width, &mut content)?;
+        render_page_number(
+            page.page_number,
+            page_height,
+            page_width,
+            &fonts.embedded_fonts,
+            &mut content,
+        )?;
     }
 
+    debug_assert!(
+        GraphicsStateImbalance::of(&content).is_balanced(),
+        "page {} content leaves the graphics state unbalanced: {:?}",
+        page.page_number,
+        GraphicsStateImbalance::of(&content)
+    );
+
     Ok(content)
 }
 
+/// Render backgrounds of full-bleed boxes (edge-to-edge sidebars)
+///
+/// Walks the page's boxes recursively and paints each full-bleed background
+/// out to the page edge. `render_box_decorations` skips these backgrounds so
+/// they are painted exactly once, underneath everything else.
+fn render_full_bleed_backgrounds<C: ContentBuilder>(
+    boxes: &[LayoutBox],
+    page_width: f64,
+    page_height: f64,
+    content: &mut C,
+) -> Result<(), PDFError> {
+    for layout_box in boxes {
+        if layout_box.style.box_model.full_bleed == Some(true) {
+            if let Some(bg_color) = layout_box.style.box_model.background_color {
+                render_full_bleed_background(
+                    layout_box,
+                    bg_color,
+                    page_width,
+                    page_height,
+                    content,
+                )?;
+            }
+        }
+        if let BoxContent::Container(children) = &layout_box.content {
+            render_full_bleed_backgrounds(children, page_width, page_height, content)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Render debug borders showing actual content area boundaries
 ///
 /// Infers content boundaries from the boxes on the page to show where
@@ -105,6 +212,7 @@ pub fn render_page_to_content(page: &Page, page_height: f64) -> Result<String, P
 fn render_debug_page_borders<C: ContentBuilder>(
     page: &Page,
     page_height: f64,
+    embedded_fonts: &HashSet<String>,
     content: &mut C,
 ) -> Result<(), PDFError> {
     // Infer actual content boundaries from boxes on the page
@@ -157,7 +265,7 @@ fn render_debug_page_borders<C: ContentBuilder>(
         "Page {} | Content: {:.0}x{:.0}pt | Top margin: {:.0}pt",
         page.page_number, content_width, content_height, content_y_top
     );
-    content.show_text_hex(&encode_as_cidfont_hex(&info));
+    content.show_text_hex(&encode_text_hex(&info, "Helvetica", embedded_fonts));
     content.end_text();
 
     Ok(())
@@ -168,6 +276,7 @@ fn render_page_number<C: ContentBuilder>(
     page_number: usize,
     _page_height: f64,
     page_width: f64,
+    embedded_fonts: &HashSet<String>,
     content: &mut C,
 ) -> Result<(), PDFError> {
     let font_size = 10.0;
@@ -190,58 +299,82 @@ fn render_page_number<C: ContentBuilder>(
     content.set_font(font_name, font_size);
     content.set_text_position(x, y);
 
-    // For PDF/A-1b, Helvetica/Times/Courier are embedded as Karla CIDFont Type 2
-    // Use hex encoding for all text (CIDFont Type 2 with Identity-H encoding)
-    content.show_text_hex(&encode_as_cidfont_hex(&page_text));
+    // Helvetica is a Karla CIDFont under PDF/A and a Type1 font otherwise
+    content.show_text_hex(&encode_text_hex(&page_text, font_name, embedded_fonts));
 
     content.end_text();
 
     Ok(())
 }
 
-/// Render a single layout box to PDF content
-fn render_box_to_content<C: ContentBuilder>(
+/// Paint backgrounds and borders of a box and its descendants in tree order
+///
+/// First pass of the CSS paint order: a parent's background is painted before
+/// its children's, and all of them before any text (see `render_box_foreground`).
+fn render_box_decorations<C: ContentBuilder>(
     layout_box: &LayoutBox,
     page_height: f64,
     content: &mut C,
 ) -> Result<(), PDFError> {
-    match &layout_box.content {
-        BoxContent::Text(text) => {
-            render_text_box(layout_box, text, page_height, content)?;
-
-            // Render border bottom if set (text boxes can have borders too,
-            // especially when flattened from containers during pagination)
-            if let Some(ref border) = layout_box.style.box_model.border_bottom {
-                render_border_bottom(layout_box, border, page_height, content)?;
-            }
-        }
-        BoxContent::Container(children) => {
-            // Render background color if set
+    if let BoxContent::Container(children) = &layout_box.content {
+        // Render background color if set (full-bleed backgrounds are painted per page)
+        if layout_box.style.box_model.full_bleed != Some(true) {
             if let Some(bg_color) = layout_box.style.box_model.background_color {
                 render_background(layout_box, bg_color, page_height, content)?;
             }
+        }
 
-            // Render bullet for list items
-            if matches!(layout_box.element_type, Some(ElementType::ListItem)) {
-                render_list_bullet(layout_box, page_height, content)?;
-            }
+        for child in children {
+            render_box_decorations(child, page_height, content)?;
+        }
+    }
 
-            // Render children
-            for child in children {
-                render_box_to_content(child, page_height, content)?;
+    // Render borders if set (text and empty boxes can have borders too,
+    // especially when flattened from containers during pagination); zero-height
+    // synthetic border boxes only ever stand for a bottom border
+    let box_model = &layout_box.style.box_model;
+    for (border, side) in [
+        (&box_model.border_bottom, BorderSide::Bottom),
+        (&box_model.border_top, BorderSide::Top),
+        (&box_model.border_right, BorderSide::Right),
+        (&box_model.border_left, BorderSide::Left),
+    ] {
+        if let Some(border) = border {
+            if side == BorderSide::Bottom || layout_box.height > 0.0 {
+                render_border_side(layout_box, border, side, page_height, content)?;
             }
+        }
+    }
 
-            // Render border bottom if set
-            if let Some(ref border) = layout_box.style.box_model.border_bottom {
-                render_border_bottom(layout_box, border, page_height, content)?;
-            }
+    Ok(())
+}
+
+/// Paint list markers and text of a box and its descendants in tree order
+///
+/// Second pass of the CSS paint order, after `render_box_decorations`.
+fn render_box_foreground<C: ContentBuilder>(
+    layout_box: &LayoutBox,
+    page_height: f64,
+    fonts: &FontContext,
+    content: &mut C,
+) -> Result<(), PDFError> {
+    // Render marker for list items (before text)
+    if matches!(layout_box.element_type, Some(ElementType::ListItem))
+        && !matches!(layout_box.content, BoxContent::Empty)
+    {
+        render_list_bullet(layout_box, page_height, &fonts.embedded_fonts, content)?;
+    }
+
+    match &layout_box.content {
+        BoxContent::Text(text) => {
+            render_text_box(layout_box, text, page_height, fonts, content)?;
         }
-        BoxContent::Empty => {
-            // Empty boxes may still have borders to render (from flattened containers)
-            if let Some(ref border) = layout_box.style.box_model.border_bottom {
-                render_border_bottom(layout_box, border, page_height, content)?;
+        BoxContent::Container(children) => {
+            for child in children {
+                render_box_foreground(child, page_height, fonts, content)?;
             }
         }
+        BoxContent::Empty => {}
     }
 
     Ok(())
@@ -317,11 +450,189 @@ fn render_text_box<C: ContentBuilder>(
     layout_box: &LayoutBox,
     lines: &[TextLine],
     page_height: f64,
+    fonts: &FontContext,
     content: &mut C,
 ) -> Result<(), PDFError> {
     let style = &layout_box.style;
 
-    // Extract base style parameters from box style
+    // Render each line separately
+    for (line_index, line) in lines.iter().enumerate() {
+        let PositionedLine {
+            line_top,
+            line_ascent,
+            runs,
+        } = position_line(
+            layout_box,
+            line,
+            line_index,
+            line_index + 1 == lines.len(),
+            &fonts.synthetic_bold,
+            &fonts.embedded_fonts,
+            page_height,
+        );
+        let pdf_y = line_top - line_ascent;
+
+        // Consecutive runs sharing font, size and color are shown by one operator
+        for batch in runs.chunk_by(|a, b| a.shares_text_state(b)) {
+            render_text_batch(
+                batch,
+                style,
+                line_top,
+                line_ascent,
+                &fonts.synthetic_bold,
+                content,
+            );
+
+            // Render text decoration for each segment
+            for run in batch {
+                if let Some(decoration) = run.decoration {
+                    if decoration != TextDecoration::None {
+                        render_text_decoration(
+                            decoration,
+                            run.x,
+                            pdf_y,
+                            run.width,
+                            run.font_size,
+                            fonts.decoration_metrics.get(run.font_name),
+                            &run.color,
+                            content,
+                        )?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Collect link annotation areas for linked text on a page
+///
+/// Segments with an `<a href>` target are always linked. With `autolink`,
+/// segments whose text is an email or URL are linked too; this expects a
+/// layout prepared by `links::autolink_layout`, where every link is a segment
+/// of its own. A segment's `<a href>` target wins over its text. Each link
+/// covers its run horizontally and the full line box vertically.
+pub(crate) fn collect_page_links(
+    page: &Page,
+    page_height: f64,
+    synthetic_bold: &HashSet<String>,
+    autolink: bool,
+) -> Vec<LinkAnnotation> {
+    let mut links = Vec::new();
+    for layout_box in &page.boxes {
+        collect_box_links(
+            layout_box,
+            page_height,
+            synthetic_bold,
+            autolink,
+            &mut links,
+        );
+    }
+    links
+}
+
+fn collect_box_links(
+    layout_box: &LayoutBox,
+    page_height: f64,
+    synthetic_bold: &HashSet<String>,
+    autolink: bool,
+    links: &mut Vec<LinkAnnotation>,
+) {
+    match &layout_box.content {
+        BoxContent::Text(lines) => {
+            let TextStyleParams {
+                line_height,
+                leading,
+                ..
+            } = calculate_text_style_params(&layout_box.style);
+            for (line_index, line) in lines.iter().enumerate() {
+                let positioned = position_line(
+                    layout_box,
+                    line,
+                    line_index,
+                    line_index + 1 == lines.len(),
+                    synthetic_bold,
+                    // Only positions are needed, which do not depend on encoding
+                    &HashSet::new(),
+                    page_height,
+                );
+                let top = positioned.line_top + leading;
+                for run in &positioned.runs {
+                    let segment = &line.segments[run.segment_index];
+                    let uri = segment
+                        .link
+                        .clone()
+                        .or_else(|| autolink.then(|| link_uri(&segment.text)).flatten());
+                    if let Some(uri) = uri {
+                        links.push(LinkAnnotation {
+                            rect: [run.x, top - line_height, run.x + run.width, top],
+                            uri,
+                        });
+                    }
+                }
+            }
+        }
+        BoxContent::Container(children) => {
+            for child in children {
+                collect_box_links(child, page_height, synthetic_bold, autolink, links);
+            }
+        }
+        BoxContent::Empty => {}
+    }
+}
+
+/// Shared baseline: sits below the tallest run's ascent so mixed-size runs
+/// (e.g. a large name next to small text) line up on one baseline
+fn line_ascent(line: &TextLine, base_font_size: f64) -> f64 {
+    line.segments
+        .iter()
+        .filter_map(|seg| seg.font_size)
+        .fold(base_font_size, f64::max)
+}
+
+/// Distance from a text box's top edge down to a line's baseline
+///
+/// Matches where the renderer draws the line, so layout can align baselines
+/// (e.g. to a baseline grid) without duplicating the leading rules.
+pub fn line_baseline_offset(style: &StyleDeclaration, line: &TextLine, line_index: usize) -> f64 {
+    let TextStyleParams {
+        font_size,
+        line_height,
+        leading,
+        ..
+    } = calculate_text_style_params(style);
+    leading + line_index as f64 * line_height + line_ascent(line, font_size)
+}
+
+/// A text line resolved to positioned runs
+struct PositionedLine {
+    /// Top of the line box in PDF coordinates
+    line_top: f64,
+    /// Ascent of the tallest run; the shared baseline sits this far below
+    /// `line_top`
+    line_ascent: f64,
+    runs: Vec<SegmentRun>,
+}
+
+/// Resolve a line's segments to fonts, colors and horizontal positions
+///
+/// A justified line is stretched to the box width by widening every space
+/// between words; see [`line_text_align`] for how the last line is aligned.
+/// A tabbed line spans the box instead: each column after a tab becomes its
+/// own run, right-aligned at the box's right edge (the layout engine's tab
+/// stop when none is configured) or just after the previous column if that
+/// would overlap it.
+fn position_line(
+    layout_box: &LayoutBox,
+    line: &TextLine,
+    line_index: usize,
+    is_last_line: bool,
+    synthetic_bold: &HashSet<String>,
+    embedded_fonts: &HashSet<String>,
+    page_height: f64,
+) -> PositionedLine {
+    let style = &layout_box.style;
     let TextStyleParams {
         font_size: base_font_size,
         line_height,
@@ -330,96 +641,414 @@ fn render_text_box<C: ContentBuilder>(
         font_name: _base_font_name,
     } = calculate_text_style_params(style);
 
-    // Render bullet for list items (before text)
-    if matches!(layout_box.element_type, Some(ElementType::ListItem)) {
-        render_list_bullet(layout_box, page_height, content)?;
+    // Convert coordinates (PDF origin is bottom-left, we use top-left)
+    let line_top = page_height - layout_box.y - leading - (line_index as f64 * line_height);
+
+    let line_ascent = line_ascent(line, base_font_size);
+
+    // Calculate total line width for alignment
+    let total_line_width: f64 = line
+        .segments
+        .iter()
+        .map(|seg| {
+            let seg_font_size = seg.font_size.unwrap_or(base_font_size);
+            let seg_font_name = get_segment_font_name(
+                style,
+                seg.font_weight.or(style.text.font_weight),
+                seg.font_style.or(style.text.font_style),
+            );
+            estimate_text_width(&seg.text, seg_font_size, seg_font_name)
+                + synthetic_bold_widening(&seg.text, seg_font_size, seg_font_name, synthetic_bold)
+        })
+        .sum();
+
+    let column_widths = tab_column_widths(line, style, base_font_size, synthetic_bold);
+    let text_align = if column_widths.len() > 1 {
+        None
+    } else {
+        line_text_align(style, is_last_line)
+    };
+    let x_offset = calculate_text_alignment_offset(text_align, layout_box.width, total_line_width);
+    let mut current_x = layout_box.x + x_offset;
+    let mut available_width = layout_box.width;
+    let is_list_item = layout_box.element_type == Some(ElementType::ListItem);
+    if is_list_item && line_index == 0 && x_offset == 0.0 {
+        let hang = hanging_marker_width(line, style, base_font_size, synthetic_bold);
+        current_x -= hang;
+        available_width += hang;
     }
 
-    // Render each line separately
-    for (line_index, line) in lines.iter().enumerate() {
-        // Convert coordinates (PDF origin is bottom-left, we use top-left)
-        let pdf_y = page_height
-            - layout_box.y
-            - leading
-            - base_font_size
-            - (line_index as f64 * line_height);
-
-        // Calculate total line width for alignment
-        let total_line_width: f64 = line
+    // Spaces between words, ignoring those that end the line
+    let last_segment = line.segments.len().saturating_sub(1);
+    let justified_spaces = |index: usize, text: &str| {
+        let text = if index == last_segment {
+            text.trim_end()
+        } else {
+            text
+        };
+        text.matches(' ').count()
+    };
+    let word_gap = if text_align == Some(TextAlign::Justify) {
+        let gaps: usize = line
             .segments
             .iter()
-            .map(|seg| {
-                let seg_font_size = seg.font_size.unwrap_or(base_font_size);
-                let seg_font_name = get_segment_font_name(
-                    style,
-                    seg.font_weight.or(style.text.font_weight),
-                    seg.font_style.or(style.text.font_style),
-                );
-                estimate_text_width(&seg.text, seg_font_size, seg_font_name)
+            .enumerate()
+            .map(|(index, segment)| {
+                justified_spaces(index, &apply_text_transform(&segment.text, style))
             })
             .sum();
+        let slack = available_width - total_line_width;
+        if gaps > 0 && slack > 0.0 {
+            slack / gaps as f64
+        } else {
+            0.0
+        }
+    } else {
+        0.0
+    };
 
-        let x_offset = calculate_text_alignment_offset(style, layout_box.width, total_line_width);
-        let mut current_x = layout_box.x + x_offset;
-
-        // Render each segment with its own style
-        for segment in &line.segments {
-            let seg_font_size = segment.font_size.unwrap_or(base_font_size);
-            let seg_font_weight = segment.font_weight.or(style.text.font_weight);
-            let seg_font_style = segment.font_style.or(style.text.font_style);
-            let seg_font_name = get_segment_font_name(style, seg_font_weight, seg_font_style);
-
-            // Use segment color or fall back to base color
-            let seg_color = segment.color.as_ref().map_or_else(
-                || base_color,
-                |c| Color {
-                    r: c.r,
-                    g: c.g,
-                    b: c.b,
-                    a: c.a,
-                },
-            );
-
-            // Apply text transform
-            let transformed_text = apply_text_transform(&segment.text, style);
-            let seg_width = estimate_text_width(&transformed_text, seg_font_size, seg_font_name);
+    // Resolve each segment's font, color and position
+    let mut runs = Vec::with_capacity(line.segments.len());
+    let mut column = 0;
+    for (index, segment) in line.segments.iter().enumerate() {
+        let font_size = segment.font_size.unwrap_or(base_font_size);
+        let font_name = get_segment_font_name(
+            style,
+            segment.font_weight.or(style.text.font_weight),
+            segment.font_style.or(style.text.font_style),
+        );
 
-            // Set color for this segment
-            content.set_fill_color_rgb(
-                seg_color.r as f64 / 255.0,
-                seg_color.g as f64 / 255.0,
-                seg_color.b as f64 / 255.0,
-            );
+        // Use segment color or fall back to base color
+        let color = segment.color.as_ref().map_or_else(
+            || base_color,
+            |c| Color {
+                r: c.r,
+                g: c.g,
+                b: c.b,
+                a: c.a,
+            },
+        );
 
-            // Render text segment
-            content.begin_text();
-            content.set_font(seg_font_name, seg_font_size);
-            content.set_text_position(current_x, pdf_y);
-            content.show_text_hex(&encode_as_cidfont_hex(&transformed_text));
-            content.end_text();
-
-            // Render text decoration for this segment
-            let seg_decoration = segment.text_decoration.or(style.text.text_decoration);
-            if let Some(decoration) = seg_decoration {
-                if decoration != TextDecoration::None {
-                    render_text_decoration(
-                        decoration,
-                        current_x,
-                        pdf_y,
-                        seg_width,
-                        seg_font_size,
-                        &seg_color,
-                        content,
-                    )?;
+        // Apply text transform
+        let text = apply_text_transform(&segment.text, style);
+        for (piece_index, text) in text.split(TAB).enumerate() {
+            if piece_index > 0 {
+                column += 1;
+                // Columns end at their configured tab stop, the rest at the edge
+                let stop = style
+                    .text
+                    .tab_stops
+                    .as_ref()
+                    .and_then(|stops| stops.get(column - 1))
+                    .map_or(layout_box.x + layout_box.width, |stop| layout_box.x + stop);
+                current_x = (stop - column_widths[column]).max(current_x);
+                if text.is_empty() {
+                    continue;
                 }
             }
 
-            // Advance x position for next segment
-            current_x += seg_width;
+            // Synthetic bold's character spacing moves the pen as well
+            let mut glyph_width = estimate_text_width(text, font_size, font_name)
+                + synthetic_bold_widening(text, font_size, font_name, synthetic_bold);
+            let mut width = glyph_width;
+            if word_gap > 0.0 {
+                // Gaps after spaces inside the run are part of the shown text; a
+                // trailing space's gap is left to the next run's position
+                let inner_spaces = text.matches(' ').count() - usize::from(text.ends_with(' '));
+                glyph_width += word_gap * inner_spaces as f64;
+                width += word_gap * justified_spaces(index, text) as f64;
+            }
+
+            runs.push(SegmentRun {
+                segment_index: index,
+                x: current_x,
+                width,
+                glyph_width,
+                word_gap,
+                text_hex: encode_text_hex(text, font_name, embedded_fonts),
+                space_hex: if embedded_fonts.contains(font_name) {
+                    "0020"
+                } else {
+                    "20"
+                },
+                font_name,
+                font_size,
+                color,
+                decoration: segment.text_decoration.or(style.text.text_decoration),
+            });
+
+            // Advance x position for next run
+            current_x += width;
         }
     }
 
-    Ok(())
+    PositionedLine {
+        line_top,
+        line_ascent,
+        runs,
+    }
+}
+
+/// Widths of a line's tab-separated columns; a line without tabs has one
+fn tab_column_widths(
+    line: &TextLine,
+    style: &StyleDeclaration,
+    base_font_size: f64,
+    synthetic_bold: &HashSet<String>,
+) -> Vec<f64> {
+    let mut widths = vec![0.0];
+    for segment in &line.segments {
+        let font_size = segment.font_size.unwrap_or(base_font_size);
+        let font_name = get_segment_font_name(
+            style,
+            segment.font_weight.or(style.text.font_weight),
+            segment.font_style.or(style.text.font_style),
+        );
+        let text = apply_text_transform(&segment.text, style);
+        for (piece_index, text) in text.split(TAB).enumerate() {
+            if piece_index > 0 {
+                widths.push(0.0);
+            }
+            if let Some(width) = widths.last_mut() {
+                *width += estimate_text_width(text, font_size, font_name)
+                    + synthetic_bold_widening(text, font_size, font_name, synthetic_bold);
+            }
+        }
+    }
+    widths
+}
+
+/// Characters that open a manually bulleted line (`• Led the team`)
+const HANGING_MARKERS: &[char] = &['•', '◦', '▪', '‣', '·', '–', '-', '*'];
+
+/// Width of a leading bullet marker and the spaces after it
+///
+/// A list item's left-aligned first line starting with a marker is moved
+/// left by this much, so the marker hangs in the margin and the wrapped lines, which
+/// start at the box edge, line up with the item text rather than the marker.
+fn hanging_marker_width(
+    line: &TextLine,
+    style: &StyleDeclaration,
+    base_font_size: f64,
+    synthetic_bold: &HashSet<String>,
+) -> f64 {
+    let Some(segment) = line.segments.first() else {
+        return 0.0;
+    };
+    let mut chars = segment.text.chars();
+    if !chars.next().is_some_and(|c| HANGING_MARKERS.contains(&c)) {
+        return 0.0;
+    }
+    let text_start = chars.as_str().trim_start();
+    if text_start.len() == chars.as_str().len() || text_start.is_empty() {
+        return 0.0;
+    }
+
+    let marker = &segment.text[..segment.text.len() - text_start.len()];
+    let font_size = segment.font_size.unwrap_or(base_font_size);
+    let font_name = get_segment_font_name(
+        style,
+        segment.font_weight.or(style.text.font_weight),
+        segment.font_style.or(style.text.font_style),
+    );
+    estimate_text_width(marker, font_size, font_name)
+        + synthetic_bold_widening(marker, font_size, font_name, synthetic_bold)
+}
+
+/// A line segment resolved to its font, color and horizontal position
+struct SegmentRun {
+    /// Index of the line segment the run shows (part of it, on a tabbed line)
+    segment_index: usize,
+    x: f64,
+    /// Advance including the gap left after a justified line's trailing space
+    width: f64,
+    /// Advance of the shown glyphs, as a PDF viewer will move the pen
+    glyph_width: f64,
+    /// Extra advance after each space of a justified line
+    word_gap: f64,
+    text_hex: String,
+    /// Encoded space in `text_hex`: one byte in WinAnsi, two as a CID
+    space_hex: &'static str,
+    font_name: &'static str,
+    font_size: f64,
+    color: Color,
+    decoration: Option<TextDecoration>,
+}
+
+impl SegmentRun {
+    /// Whether two runs can be shown in the same text object
+    fn shares_text_state(&self, other: &SegmentRun) -> bool {
+        self.font_name == other.font_name
+            && self.font_size == other.font_size
+            && self.color == other.color
+    }
+}
+
+/// Render consecutive runs that share font, size and color
+///
+/// A single run is shown with `Tj`; several runs are coalesced into one `TJ`
+/// array whose adjustments move the pen to each run's computed position (see
+/// [`text_array_parts`]).
+fn render_text_batch<C: ContentBuilder>(
+    batch: &[SegmentRun],
+    style: &StyleDeclaration,
+    line_top: f64,
+    line_ascent: f64,
+    synthetic_bold: &HashSet<String>,
+    content: &mut C,
+) {
+    let Some(first) = batch.first() else {
+        return;
+    };
+    let (font_name, font_size, color) = (first.font_name, first.font_size, first.color);
+    let bold_stroke = synthetic_bold_stroke(font_size, font_name, synthetic_bold);
+
+    // Text origin at the run's own ascent, lowered onto the shared line
+    // baseline with a text rise
+    let baseline_rise = font_size - line_ascent;
+
+    let show_batch = |content: &mut C| match batch {
+        [run] if run.word_gap == 0.0 => content.show_text_hex(&run.text_hex),
+        _ => content.show_text_hex_array(&text_array_parts(batch)),
+    };
+
+    if let Some(stroke_width) = bold_stroke {
+        // Fake bold widens every glyph's advance by the stroke width, for the
+        // shadow copy as well; restored with the stroke state below
+        content.save_graphics_state();
+        content.set_character_spacing(stroke_width);
+    }
+
+    // Approximate text-shadow with a hard (unblurred) copy underneath;
+    // CSS offsets point down, PDF y points up
+    if let Some(shadow) = style.text.text_shadow {
+        content.set_fill_color_rgb(
+            shadow.color.r as f64 / 255.0,
+            shadow.color.g as f64 / 255.0,
+            shadow.color.b as f64 / 255.0,
+        );
+        content.begin_text();
+        content.set_font(font_name, font_size);
+        content.set_text_position(
+            first.x + shadow.offset_x,
+            line_top - font_size - shadow.offset_y,
+        );
+        if baseline_rise != 0.0 {
+            content.set_text_rise(baseline_rise);
+        }
+        show_batch(content);
+        if baseline_rise != 0.0 {
+            content.set_text_rise(0.0);
+        }
+        content.end_text();
+    }
+
+    // Set color for this batch
+    content.set_fill_color_rgb(
+        color.r as f64 / 255.0,
+        color.g as f64 / 255.0,
+        color.b as f64 / 255.0,
+    );
+
+    if let Some(stroke_width) = bold_stroke {
+        // Fake bold: fill+stroke the glyph outlines in the text color
+        content.set_stroke_color_rgb(
+            color.r as f64 / 255.0,
+            color.g as f64 / 255.0,
+            color.b as f64 / 255.0,
+        );
+        content.set_line_width(stroke_width);
+    }
+    content.begin_text();
+    content.set_font(font_name, font_size);
+    if bold_stroke.is_some() {
+        content.set_text_rendering_mode(2);
+    }
+    content.set_text_position(first.x, line_top - font_size);
+    if baseline_rise != 0.0 {
+        content.set_text_rise(baseline_rise);
+    }
+    show_batch(content);
+    if baseline_rise != 0.0 {
+        content.set_text_rise(0.0);
+    }
+    content.end_text();
+    if bold_stroke.is_some() {
+        // Restores stroke color, line width, character spacing and text
+        // rendering mode
+        content.restore_graphics_state();
+    }
+}
+
+/// `TJ` elements for a batch of runs sharing one font and size
+///
+/// Each adjustment is computed from the measured widths: the distance from
+/// where a part's glyphs leave the pen (`x + glyph_width`) to where the next
+/// part starts, so every run lands on its own computed `x` rather than
+/// wherever the previous run's glyph advances happen to end.
+fn text_array_parts(batch: &[SegmentRun]) -> Vec<(String, f64)> {
+    let mut parts: Vec<(String, f64)> = Vec::new();
+    for (index, run) in batch.iter().enumerate() {
+        // Rounded so runs that already abut get no adjustment at all
+        let to_thousandths = |gap: f64| (-gap * 1000.0 / run.font_size * 1000.0).round() / 1000.0;
+        let words = split_hex_after_spaces(&run.text_hex, run.space_hex);
+        let last_word = words.len() - 1;
+        for (word_index, word) in words.into_iter().enumerate() {
+            let gap = if word_index < last_word {
+                run.word_gap
+            } else if let Some(next) = batch.get(index + 1) {
+                next.x - (run.x + run.glyph_width)
+            } else {
+                0.0
+            };
+            parts.push((word.to_string(), to_thousandths(gap)));
+        }
+    }
+    parts
+}
+
+/// Split hex-encoded text after each encoded space (`space_hex`)
+///
+/// `space_hex` is `0020` for CID-encoded text and `20` for WinAnsi bytes; it
+/// also sets the code length, so the split never lands mid-code. Returns the
+/// whole string as one part when it has no inner space.
+fn split_hex_after_spaces<'a>(text_hex: &'a str, space_hex: &str) -> Vec<&'a str> {
+    let code_len = space_hex.len();
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for end in (code_len..text_hex.len()).step_by(code_len) {
+        if &text_hex[end - code_len..end] == space_hex {
+            parts.push(&text_hex[start..end]);
+            start = end;
+        }
+    }
+    parts.push(&text_hex[start..]);
+    parts
+}
+
+/// Stroke width for a synthesized-bold run, if its font needs faking
+fn synthetic_bold_stroke(
+    font_size: f64,
+    font_name: &str,
+    synthetic_bold: &HashSet<String>,
+) -> Option<f64> {
+    synthetic_bold
+        .contains(font_name)
+        .then_some(font_size * SYNTHETIC_BOLD_STROKE_RATIO)
+}
+
+/// Extra advance for synthesized bold (see [`crate::fonts::synthetic_bold_widening`])
+fn synthetic_bold_widening(
+    text: &str,
+    font_size: f64,
+    font_name: &str,
+    synthetic_bold: &HashSet<String>,
+) -> f64 {
+    if synthetic_bold.contains(font_name) {
+        crate::fonts::synthetic_bold_widening(text, font_size)
+    } else {
+        0.0
+    }
 }
 
 /// Get font name for a segment based on its weight and style
@@ -528,6 +1157,7 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("Hello World")]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let page = Page::new(1, vec![layout_box]);
@@ -539,9 +1169,9 @@ mod tests {
         };
 
         let content = render_layout_to_content(&layout).unwrap();
-        // Text should be hex-encoded for CIDFont Type 2
-        // "Hello World" -> <00480065006C006C006F00200057006F0072006C0064>
-        assert!(content.contains("<00480065006C006C006F00200057006F0072006C0064>"));
+        // Text should be hex-encoded WinAnsi bytes for the Type1 Helvetica
+        // "Hello World" -> <48656C6C6F20576F726C64>
+        assert!(content.contains("<48656C6C6F20576F726C64>"));
         assert!(content.contains("BT"));
         assert!(content.contains("ET"));
     }
@@ -550,8 +1180,24 @@ mod tests {
 #[cfg(test)]
 mod integration_tests {
     use super::*;
+    use crate::encoding::{encode_as_cidfont_hex, encode_win_ansi_hex};
     use layout_types::TextLine;
 
+    /// Render one box tree with both paint passes, as the page renderer does
+    fn render_box_to_content<C: ContentBuilder>(
+        layout_box: &LayoutBox,
+        page_height: f64,
+        synthetic_bold: &HashSet<String>,
+        content: &mut C,
+    ) -> Result<(), PDFError> {
+        render_box_decorations(layout_box, page_height, content)?;
+        let fonts = FontContext {
+            synthetic_bold: synthetic_bold.clone(),
+            ..FontContext::default()
+        };
+        render_box_foreground(layout_box, page_height, &fonts, content)
+    }
+
     /// Integration test: Verify text box generates correct PDF operators
     #[test]
     fn test_render_text_box_generates_correct_operators() {
@@ -571,11 +1217,19 @@ mod integration_tests {
             content: BoxContent::Text(vec![TextLine::from("Test Text")]),
             style,
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
         let lines = vec![TextLine::from("Test Text")];
-        render_text_box(&layout_box, &lines, 792.0, &mut content).unwrap();
+        render_text_box(
+            &layout_box,
+            &lines,
+            792.0,
+            &FontContext::default(),
+            &mut content,
+        )
+        .unwrap();
 
         // Verify essential PDF operators are present
         assert!(content.contains("rg"), "Should set fill color");
@@ -604,10 +1258,11 @@ mod integration_tests {
             content: BoxContent::Container(vec![]),
             style,
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
-        render_box_to_content(&layout_box, 792.0, &mut content).unwrap();
+        render_box_to_content(&layout_box, 792.0, &HashSet::new(), &mut content).unwrap();
 
         // Verify background rendering operators
         assert!(content.contains("rg"), "Should set fill color");
@@ -615,6 +1270,174 @@ mod integration_tests {
         assert!(content.contains("f"), "Should fill rectangle");
     }
 
+    /// Integration test: Verify backgrounds are painted before any text
+    #[test]
+    fn test_backgrounds_paint_before_child_text() {
+        let container_with_text = |y: f64, bg: Color, text: &str| {
+            let mut style = StyleDeclaration::default();
+            style.box_model.background_color = Some(bg);
+            let text_box = LayoutBox {
+                x: 20.0,
+                y: y + 5.0,
+                width: 160.0,
+                height: 15.0,
+                content: BoxContent::Text(vec![TextLine::from(text)]),
+                style: StyleDeclaration::default(),
+                element_type: None,
+                list_ordinal: None,
+            };
+            LayoutBox {
+                x: 10.0,
+                y,
+                width: 180.0,
+                height: 30.0,
+                content: BoxContent::Container(vec![text_box]),
+                style,
+                element_type: None,
+                list_ordinal: None,
+            }
+        };
+        // The second container overlaps the bottom of the first one's text
+        let page = Page::new(
+            1,
+            vec![
+                container_with_text(100.0, Color::rgb(255, 255, 0), "First"),
+                container_with_text(115.0, Color::rgb(0, 255, 255), "Second"),
+            ],
+        );
+
+        let content = render_page_to_content(&page, 612.0, 792.0).unwrap();
+
+        let first_text = content.find("BT\n").expect("text rendered");
+        let yellow = content.find("1 1 0 rg\n").expect("first background");
+        let cyan = content.find("0 1 1 rg\n").expect("second background");
+        assert!(yellow < cyan, "Backgrounds paint in tree order");
+        assert!(
+            cyan < first_text,
+            "Sibling background must not cover earlier text"
+        );
+        assert_eq!(content[first_text..].matches(" re\n").count(), 0);
+    }
+
+    /// Integration test: Verify full-bleed sidebar background reaches the page edge
+    #[test]
+    fn test_render_full_bleed_sidebar_background() {
+        let mut style = StyleDeclaration::default();
+        style.box_model.full_bleed = Some(true);
+        style.box_model.background_color = Some(Color {
+            r: 31,
+            g: 41,
+            b: 55,
+            a: 1.0,
+        });
+        // Left sidebar sitting inside the 36pt content margin
+        let sidebar = LayoutBox {
+            x: 36.0,
+            y: 36.0,
+            width: 180.0,
+            height: 300.0,
+            content: BoxContent::Container(vec![LayoutBox {
+                x: 48.0,
+                y: 48.0,
+                width: 156.0,
+                height: 20.0,
+                content: BoxContent::Text(vec![TextLine::from("Skills")]),
+                style: StyleDeclaration::default(),
+                element_type: None,
+                list_ordinal: None,
+            }]),
+            style,
+            element_type: Some(ElementType::Div),
+            list_ordinal: None,
+        };
+
+        let page = Page::new(1, vec![sidebar]);
+        let content = render_page_to_content(&page, 612.0, 792.0).unwrap();
+
+        // Fill starts at x=0, ends at the sidebar's right edge (36 + 180),
+        // and covers the full page height
+        assert!(
+            content.contains("0 0 216 792 re\n"),
+            "Sidebar fill should span x=0..216 and full page height, got: {}",
+            content
+        );
+        assert_eq!(
+            content.matches(" re\n").count(),
+            1,
+            "Full-bleed background should be painted exactly once"
+        );
+        assert!(
+            content.find(" re\n") < content.find("BT"),
+            "Background should be painted before the sidebar text"
+        );
+    }
+
+    /// A list of text items with the given marker type, as laid out for `<ul>`
+    fn list_with_style(list_style_type: layout_types::ListStyleType) -> LayoutBox {
+        let mut style = StyleDeclaration::default();
+        style.text.list_style_type = Some(list_style_type);
+        let item = |ordinal: usize, text: &str| LayoutBox {
+            x: 70.0,
+            y: 86.0 + 14.0 * ordinal as f64,
+            width: 200.0,
+            height: 14.0,
+            content: BoxContent::Text(vec![TextLine::from(text)]),
+            style: style.clone(),
+            element_type: Some(ElementType::ListItem),
+            list_ordinal: Some(ordinal),
+        };
+        LayoutBox {
+            x: 50.0,
+            y: 100.0,
+            width: 220.0,
+            height: 28.0,
+            content: BoxContent::Container(vec![item(1, "Rust"), item(2, "TypeScript")]),
+            style: style.clone(),
+            element_type: Some(ElementType::UnorderedList),
+            list_ordinal: None,
+        }
+    }
+
+    /// `list-none` items get no marker shape or glyph
+    #[test]
+    fn test_render_list_none_draws_no_marker() {
+        use layout_types::ListStyleType;
+
+        let mut content = String::new();
+        render_box_to_content(
+            &list_with_style(ListStyleType::None),
+            792.0,
+            &HashSet::new(),
+            &mut content,
+        )
+        .unwrap();
+
+        assert_eq!(content.matches(" Tj\n").count(), 2, "Only the item text");
+        for path_op in [" m\n", " c\n", " re\n", "\nf\n", "\nS\n"] {
+            assert!(!content.contains(path_op), "Unexpected {path_op:?}");
+        }
+    }
+
+    /// Decimal markers number the items of a list in order
+    #[test]
+    fn test_render_list_decimal_numbers_items() {
+        use layout_types::ListStyleType;
+
+        let mut content = String::new();
+        render_box_to_content(
+            &list_with_style(ListStyleType::Decimal),
+            792.0,
+            &HashSet::new(),
+            &mut content,
+        )
+        .unwrap();
+
+        let first = content.find(&encode_win_ansi_hex("1.")).unwrap();
+        let second = content.find(&encode_win_ansi_hex("2.")).unwrap();
+        assert!(first < second);
+        assert!(!content.contains(" c\n"), "No disc bullets");
+    }
+
     /// Integration test: Verify list item bullet rendering
     #[test]
     fn test_render_list_item_with_bullet() {
@@ -634,10 +1457,11 @@ mod integration_tests {
             content: BoxContent::Container(vec![]),
             style,
             element_type: Some(ElementType::ListItem),
+            list_ordinal: None,
         };
 
         let mut content = String::new();
-        render_box_to_content(&layout_box, 792.0, &mut content).unwrap();
+        render_box_to_content(&layout_box, 792.0, &HashSet::new(), &mut content).unwrap();
 
         // Verify bullet rendering (uses Bézier curves)
         assert!(
@@ -678,11 +1502,19 @@ mod integration_tests {
             content: BoxContent::Text(vec![TextLine::from("Underlined")]),
             style,
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
         let lines = vec![TextLine::from("Underlined")];
-        render_text_box(&layout_box, &lines, 792.0, &mut content).unwrap();
+        render_text_box(
+            &layout_box,
+            &lines,
+            792.0,
+            &FontContext::default(),
+            &mut content,
+        )
+        .unwrap();
 
         // Verify underline operators
         assert!(content.contains("RG"), "Should set stroke color");
@@ -691,6 +1523,61 @@ mod integration_tests {
         assert!(content.contains("S"), "Should stroke line");
     }
 
+    #[test]
+    fn test_render_text_shadow_draws_offset_copy_first() {
+        use layout_types::TextShadow;
+        let mut style = StyleDeclaration::default();
+        style.text.font_size = Some(20.0);
+        style.text.color = Some(Color::rgb(0, 0, 255));
+        style.text.text_shadow = Some(TextShadow {
+            offset_x: 2.0,
+            offset_y: 3.0,
+            blur: 4.0,
+            color: Color::rgb(255, 0, 0),
+        });
+        let lines = vec![TextLine::from("Jane Doe")];
+        let layout_box = LayoutBox {
+            x: 50.0,
+            y: 100.0,
+            width: 300.0,
+            height: 24.0,
+            content: BoxContent::Text(lines.clone()),
+            style,
+            element_type: Some(ElementType::Heading1),
+            list_ordinal: None,
+        };
+
+        let mut content = String::new();
+        render_text_box(
+            &layout_box,
+            &lines,
+            792.0,
+            &FontContext::default(),
+            &mut content,
+        )
+        .unwrap();
+
+        assert_eq!(content.matches("Tj\n").count(), 2, "Text drawn twice");
+        // Text origin is at line_top - font_size = 792 - 100 - leading - 20
+        let shadow = content.find("1 0 0 rg\n").expect("shadow color");
+        let main = content.find("0 0 1 rg\n").expect("main color");
+        assert!(shadow < main, "Shadow must be drawn underneath");
+        let shadow_pos = content[shadow..main].find(" Td\n").map(|i| shadow + i);
+        let main_pos = content[main..].find(" Td\n").map(|i| main + i);
+        let td_line = |end: usize| content[..end].rsplit('\n').next().unwrap().to_string();
+        let shadow_td = td_line(shadow_pos.unwrap());
+        let main_td = td_line(main_pos.unwrap());
+        let coords = |td: &str| -> (f64, f64) {
+            let mut parts = td.split_whitespace().map(|p| p.parse::<f64>().unwrap());
+            (parts.next().unwrap(), parts.next().unwrap())
+        };
+        let (sx, sy) = coords(&shadow_td);
+        let (mx, my) = coords(&main_td);
+        assert_eq!(mx, 50.0);
+        assert!((sx - mx - 2.0).abs() < 1e-6, "Shadow shifted right by 2pt");
+        assert!((my - sy - 3.0).abs() < 1e-6, "Shadow shifted down by 3pt");
+    }
+
     /// Integration test: Verify border rendering
     #[test]
     fn test_render_container_with_border() {
@@ -714,10 +1601,11 @@ mod integration_tests {
             content: BoxContent::Container(vec![]),
             style,
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
-        render_box_to_content(&layout_box, 792.0, &mut content).unwrap();
+        render_box_to_content(&layout_box, 792.0, &HashSet::new(), &mut content).unwrap();
 
         // Verify border operators
         assert!(content.contains("RG"), "Should set stroke color");
@@ -728,6 +1616,28 @@ mod integration_tests {
         assert!(content.contains("S"), "Should stroke line");
     }
 
+    #[test]
+    fn test_render_full_box_border_draws_four_edges() {
+        let style = crate::css_parser::parse_inline_styles("border: 2px solid red").unwrap();
+        let layout_box = LayoutBox {
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 50.0,
+            content: BoxContent::Container(vec![]),
+            style,
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        let mut content = String::new();
+        render_box_to_content(&layout_box, 792.0, &HashSet::new(), &mut content).unwrap();
+
+        assert_eq!(content.matches(" l\n").count(), 4, "{content}");
+        // Left edge runs the full box height, inset by half the border width
+        assert!(content.contains("10.75 772 m\n10.75 722 l\n"), "{content}");
+    }
+
     /// Integration test: Verify multi-line text rendering
     #[test]
     fn test_render_multi_line_text() {
@@ -747,10 +1657,18 @@ mod integration_tests {
             content: BoxContent::Text(lines.clone()),
             style,
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
-        render_text_box(&layout_box, &lines, 792.0, &mut content).unwrap();
+        render_text_box(
+            &layout_box,
+            &lines,
+            792.0,
+            &FontContext::default(),
+            &mut content,
+        )
+        .unwrap();
 
         // Verify multiple text blocks (one per line)
         assert_eq!(
@@ -766,6 +1684,429 @@ mod integration_tests {
         );
     }
 
+    /// Mixed font sizes on one line share a single baseline
+    #[test]
+    fn test_render_mixed_font_sizes_share_baseline() {
+        use layout_types::TextSegment;
+
+        let segment = |text: &str, font_size: f64| TextSegment {
+            text: text.to_string(),
+            font_weight: None,
+            font_style: None,
+            font_size: Some(font_size),
+            text_decoration: None,
+            color: None,
+            link: None,
+        };
+        let lines = vec![TextLine {
+            segments: vec![segment("Jane", 24.0), segment("PhD", 10.0)],
+        }];
+        let mut style = StyleDeclaration::default();
+        style.text.font_size = Some(10.0);
+        style.text.line_height = Some(12.0);
+        let layout_box = LayoutBox {
+            x: 50.0,
+            y: 100.0,
+            width: 300.0,
+            height: 30.0,
+            content: BoxContent::Text(lines.clone()),
+            style,
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        let mut content = String::new();
+        render_text_box(
+            &layout_box,
+            &lines,
+            792.0,
+            &FontContext::default(),
+            &mut content,
+        )
+        .unwrap();
+
+        // Each run: "x y Td" followed by an optional "rise Ts"
+        let runs: Vec<(f64, f64)> = content
+            .split("BT\n")
+            .skip(1)
+            .map(|run| {
+                let td_y = run
+                    .lines()
+                    .find(|l| l.ends_with(" Td"))
+                    .and_then(|l| l.split_whitespace().nth(1))
+                    .and_then(|y| y.parse::<f64>().ok())
+                    .expect("run should have a Td operator");
+                let rise = run
+                    .lines()
+                    .find(|l| l.ends_with(" Ts"))
+                    .and_then(|l| l.split_whitespace().next())
+                    .and_then(|r| r.parse::<f64>().ok())
+                    .unwrap_or(0.0);
+                (td_y, rise)
+            })
+            .collect();
+
+        assert_eq!(runs.len(), 2);
+        let (large_y, large_rise) = runs[0];
+        let (small_y, small_rise) = runs[1];
+
+        // Text origins differ by the ascent difference (24pt - 10pt)
+        assert!((small_y - large_y - 14.0).abs() < 1e-9);
+        // ...and the text rise brings both onto the same baseline
+        assert!((large_y + large_rise - (small_y + small_rise)).abs() < 1e-9);
+        assert!(content.contains("0 Ts"), "Text rise should be reset");
+    }
+
+    /// Same-style segments on a line are shown by one TJ array
+    #[test]
+    fn test_render_batches_same_style_segments() {
+        use crate::content_builder::ContentStats;
+        use layout_types::TextSegment;
+
+        let segment = |text: &str, color: Option<Color>| TextSegment {
+            text: text.to_string(),
+            font_weight: None,
+            font_style: None,
+            font_size: None,
+            text_decoration: None,
+            color,
+            link: None,
+        };
+        let render = |segments: Vec<TextSegment>| {
+            let lines = vec![TextLine { segments }];
+            let layout_box = LayoutBox {
+                x: 50.0,
+                y: 100.0,
+                width: 400.0,
+                height: 14.0,
+                content: BoxContent::Text(lines.clone()),
+                style: StyleDeclaration::default(),
+                element_type: None,
+                list_ordinal: None,
+            };
+            let mut content = String::new();
+            render_text_box(
+                &layout_box,
+                &lines,
+                792.0,
+                &FontContext::default(),
+                &mut content,
+            )
+            .unwrap();
+            content
+        };
+
+        let words = ["Rust, ", "TypeScript, ", "WebAssembly"];
+        let batched = render(words.iter().map(|w| segment(w, None)).collect());
+        let stats = ContentStats::from_content(&batched);
+        assert_eq!(stats.text_show_ops, 1, "Expected one TJ for {batched}");
+        assert!(stats.text_show_ops < words.len());
+        assert!(
+            batched.contains("50 680 Td\n"),
+            "Batch starts at the box edge"
+        );
+        let hex: Vec<_> = words.iter().map(|w| encode_win_ansi_hex(w)).collect();
+        assert!(batched.contains(&format!("[<{}><{}><{}>] TJ\n", hex[0], hex[1], hex[2])));
+
+        // A color change breaks the batch; the red run keeps its own position
+        let red = Color::rgb(255, 0, 0);
+        let split = render(vec![
+            segment(words[0], None),
+            segment(words[1], Some(red)),
+            segment(words[2], None),
+        ]);
+        assert_eq!(ContentStats::from_content(&split).text_show_ops, 3);
+        let red_x = 50.0 + estimate_text_width(words[0], 12.0, "Helvetica");
+        assert!(split.contains(&format!("{} 680 Td\n", red_x)));
+    }
+
+    /// TJ adjustments place each run at its computed x, not where the pen ends
+    #[test]
+    fn test_text_array_adjustments_follow_run_positions() {
+        let run = |text: &str, x: f64| {
+            let glyph_width = estimate_text_width(text, 10.0, "Helvetica");
+            SegmentRun {
+                segment_index: 0,
+                x,
+                width: glyph_width,
+                glyph_width,
+                word_gap: 0.0,
+                text_hex: encode_as_cidfont_hex(text),
+                space_hex: "0020",
+                font_name: "Helvetica",
+                font_size: 10.0,
+                color: Color::rgb(0, 0, 0),
+                decoration: None,
+            }
+        };
+        let end = 50.0 + estimate_text_width("Engineer", 10.0, "Helvetica");
+
+        let parts = text_array_parts(&[run("Engineer", 50.0), run("ing", end)]);
+        assert_eq!(parts[0].1, 0.0);
+
+        // 12.5pt at 10pt is 1250 thousandths of text space
+        let parts = text_array_parts(&[run("Engineer", 50.0), run("2020", end + 12.5)]);
+        assert_eq!(parts[0].1, -1250.0);
+        assert_eq!(parts[1].1, 0.0);
+    }
+
+    /// Bold requested with only a regular face embedded is faked via fill+stroke
+    #[test]
+    fn test_render_synthetic_bold_run() {
+        use crate::css_parser::FontWeight;
+
+        let lines = vec![TextLine::from("Senior Engineer")];
+        let mut style = StyleDeclaration::default();
+        style.text.font_size = Some(10.0);
+        style.text.font_weight = Some(FontWeight::Bold);
+        let layout_box = LayoutBox {
+            x: 50.0,
+            y: 100.0,
+            width: 300.0,
+            height: 12.0,
+            content: BoxContent::Text(lines.clone()),
+            style,
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        let mut regular = String::new();
+        render_text_box(
+            &layout_box,
+            &lines,
+            792.0,
+            &FontContext::default(),
+            &mut regular,
+        )
+        .unwrap();
+        assert!(
+            !regular.contains(" Tr"),
+            "Real bold needs no rendering mode"
+        );
+
+        let fonts = FontContext {
+            synthetic_bold: ["Helvetica-Bold".to_string()].into_iter().collect(),
+            ..FontContext::default()
+        };
+        let mut content = String::new();
+        render_text_box(&layout_box, &lines, 792.0, &fonts, &mut content).unwrap();
+
+        assert!(content.contains("2 Tr\n"), "Should fill+stroke glyphs");
+        assert!(
+            content.contains("0.2 w\n"),
+            "Stroke width should be 2% of 10pt"
+        );
+        assert!(
+            content.contains("0.2 Tc\n"),
+            "Glyph advances should widen by the stroke width"
+        );
+        assert!(
+            content.find("q\n") < content.find("BT\n"),
+            "Stroke state should be saved before the text block"
+        );
+        assert!(
+            content.contains("ET\nQ\n"),
+            "Stroke state should be restored"
+        );
+    }
+
+    #[test]
+    fn test_wrapped_bullet_item_hangs_marker() {
+        let lines = vec![
+            TextLine::from("• Led migration of the billing platform to event sourcing,"),
+            TextLine::from("cutting reconciliation time from days to minutes"),
+        ];
+        let layout_box = LayoutBox {
+            x: 72.0,
+            y: 100.0,
+            width: 300.0,
+            height: 30.0,
+            content: BoxContent::Text(lines.clone()),
+            style: StyleDeclaration::default(),
+            element_type: Some(ElementType::ListItem),
+            list_ordinal: None,
+        };
+
+        let mut content = String::new();
+        render_text_box(
+            &layout_box,
+            &lines,
+            792.0,
+            &FontContext::default(),
+            &mut content,
+        )
+        .unwrap();
+
+        let line_x: Vec<f64> = content
+            .lines()
+            .filter(|l| l.ends_with(" Td"))
+            .filter_map(|l| l.split_whitespace().next()?.parse().ok())
+            .collect();
+        assert_eq!(line_x.len(), 2);
+        let (bullet_x, second_x) = (line_x[0], line_x[1]);
+        let marker_width = estimate_text_width("• ", 12.0, "Helvetica");
+        let first_text_x = bullet_x + marker_width;
+
+        assert!(
+            (second_x - first_text_x).abs() < 0.01,
+            "second line at {} should align with first line text at {}",
+            second_x,
+            first_text_x
+        );
+        assert!(bullet_x < layout_box.x, "bullet should hang in the margin");
+        assert!((second_x - layout_box.x).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_paragraph_marker_does_not_hang() {
+        let lines = vec![TextLine::from("- 2019 Moved to Berlin")];
+        let layout_box = LayoutBox {
+            x: 72.0,
+            y: 100.0,
+            width: 300.0,
+            height: 15.0,
+            content: BoxContent::Text(lines.clone()),
+            style: StyleDeclaration::default(),
+            element_type: Some(ElementType::Paragraph),
+            list_ordinal: None,
+        };
+
+        let line = position_line(
+            &layout_box,
+            &lines[0],
+            0,
+            true,
+            &HashSet::new(),
+            &HashSet::new(),
+            792.0,
+        );
+        assert!((line.runs[0].x - layout_box.x).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_justified_block_centers_last_line() {
+        let lines = vec![
+            TextLine::from("Built a streaming analytics platform used by"),
+            TextLine::from("forty product teams across three continents"),
+            TextLine::from("since 2019"),
+        ];
+        let mut style = StyleDeclaration::default();
+        style.text.text_align = Some(TextAlign::Justify);
+        style.text.text_align_last = Some(TextAlign::Center);
+        let layout_box = LayoutBox {
+            x: 72.0,
+            y: 100.0,
+            width: 300.0,
+            height: 45.0,
+            content: BoxContent::Text(lines.clone()),
+            style,
+            element_type: None,
+            list_ordinal: None,
+        };
+        let position = |index: usize| {
+            position_line(
+                &layout_box,
+                &lines[index],
+                index,
+                index + 1 == lines.len(),
+                &HashSet::new(),
+                &HashSet::new(),
+                792.0,
+            )
+        };
+
+        // Wrapped lines are stretched across the full width
+        for index in 0..2 {
+            let line = position(index);
+            let run = &line.runs[0];
+            assert!((run.x - 72.0).abs() < 0.01);
+            assert!((run.x + run.width - 372.0).abs() < 0.01, "line {}", index);
+            assert!(run.word_gap > 0.0);
+        }
+
+        // The last line keeps its natural width and is centered
+        let last = position(2);
+        let natural = estimate_text_width("since 2019", 12.0, "Helvetica");
+        assert_eq!(last.runs[0].word_gap, 0.0);
+        assert!((last.runs[0].x - (72.0 + (300.0 - natural) / 2.0)).abs() < 0.01);
+
+        // Stretched lines widen their spaces with TJ adjustments
+        let mut content = String::new();
+        render_text_box(
+            &layout_box,
+            &lines,
+            792.0,
+            &FontContext::default(),
+            &mut content,
+        )
+        .unwrap();
+        assert_eq!(content.matches("] TJ").count(), 2, "{}", content);
+        assert_eq!(content.matches(" Tj").count(), 1, "{}", content);
+    }
+
+    #[test]
+    fn test_tabbed_columns_align_at_tab_stops() {
+        let line = TextLine::from("Role\tAcme\t2020");
+        let mut style = StyleDeclaration::default();
+        style.text.tab_stops = Some(vec![150.0]);
+        let layout_box = LayoutBox {
+            x: 72.0,
+            y: 100.0,
+            width: 300.0,
+            height: 15.0,
+            content: BoxContent::Text(vec![line.clone()]),
+            style,
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        let positioned = position_line(
+            &layout_box,
+            &line,
+            0,
+            true,
+            &HashSet::new(),
+            &HashSet::new(),
+            792.0,
+        );
+        let end = |index: usize| positioned.runs[index].x + positioned.runs[index].width;
+        assert_eq!(positioned.runs.len(), 3);
+        assert!((positioned.runs[0].x - 72.0).abs() < 0.01);
+        // The first tab has a configured stop, the second ends at the box edge
+        assert!((end(1) - (72.0 + 150.0)).abs() < 0.01);
+        assert!((end(2) - (72.0 + 300.0)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_split_hex_after_spaces() {
+        let hex = encode_as_cidfont_hex("led a team ");
+        assert_eq!(
+            split_hex_after_spaces(&hex, "0020"),
+            vec!["006C006500640020", "00610020", "007400650061006D0020"]
+        );
+        assert_eq!(split_hex_after_spaces("004A", "0020"), vec!["004A"]);
+
+        // WinAnsi codes are one byte; "20" inside "0200" is not a space
+        let hex = encode_win_ansi_hex("led a team ");
+        assert_eq!(
+            split_hex_after_spaces(&hex, "20"),
+            vec!["6C656420", "6120", "7465616D20"]
+        );
+        assert_eq!(split_hex_after_spaces("0200", "20"), vec!["0200"]);
+    }
+
+    #[test]
+    fn test_hanging_marker_requires_following_space() {
+        let style = StyleDeclaration::default();
+        let bold = HashSet::new();
+        let width = |text: &str| hanging_marker_width(&TextLine::from(text), &style, 12.0, &bold);
+
+        assert!(width("– Speaker at RustConf") > 0.0);
+        assert_eq!(width("-5% churn"), 0.0);
+        assert_eq!(width("*"), 0.0);
+        assert_eq!(width("Led the team"), 0.0);
+    }
+
     /// Integration test: Verify page number rendering
     #[test]
     fn test_render_page_with_page_number() {
@@ -777,11 +2118,12 @@ mod integration_tests {
             content: BoxContent::Text(vec![TextLine::from("Content")]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let page = Page::new(2, vec![layout_box]); // Page 2 should have page number
 
-        let content = render_page_to_content(&page, 792.0).unwrap();
+        let content = render_page_to_content(&page, 612.0, 792.0).unwrap();
 
         // Verify page number operators
         assert!(content.contains("rg"), "Should set page number color");
@@ -806,10 +2148,11 @@ mod integration_tests {
             content: BoxContent::Empty,
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
-        let result = render_box_to_content(&layout_box, 792.0, &mut content);
+        let result = render_box_to_content(&layout_box, 792.0, &HashSet::new(), &mut content);
 
         assert!(result.is_ok(), "Should handle empty content without error");
         assert!(content.is_empty(), "Empty box should produce no output");
@@ -840,10 +2183,11 @@ mod integration_tests {
             content: BoxContent::Empty,
             style,
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
-        let result = render_box_to_content(&layout_box, 792.0, &mut content);
+        let result = render_box_to_content(&layout_box, 792.0, &HashSet::new(), &mut content);
 
         assert!(
             result.is_ok(),
@@ -96,12 +96,22 @@
 //! #     title: None,
 //! #     email: Some("john@example.com".to_string()),
 //! #     phone: None,
+//! #     phone_e164: None,
 //! #     location: None,
 //! #     website: None,
+//! #     skills: Vec::new(),
+//! #     experience: Vec::new(),
+//! #     certifications: Vec::new(),
 //! #     layout_type: cv_domain::LayoutType::SingleColumn,
 //! #     estimated_pages: 1,
 //! #     component_count: 10,
 //! #     has_contact_info: true,
+//! #     contact_in_sidebar: false,
+//! #     chronological_order: None,
+//! #     duplicate_sections: Vec::new(),
+//! #     word_count: 0,
+//! #     char_count: 0,
+//! #     reading_time_seconds: 0,
 //! #     has_clear_sections: true,
 //! #     font_complexity: cv_domain::FontComplexity::Simple,
 //! # };
@@ -178,6 +188,7 @@
 //! included by default.
 
 pub mod ats; // ATS validation module (SRP refactor - organized submodules)
+pub mod attachments; // Embedded file attachments (source TSX)
 pub mod bookmarks;
 pub mod color_utils; // RGB to PDF color conversion utilities
 pub mod config;
@@ -189,25 +200,41 @@ pub mod encoding; // Text encoding utilities for PDF content streams
 pub mod error;
 mod font_registry; // Font collection and registration (SRP refactor)
 pub mod font_resolver; // Font name resolution based on style
+pub mod font_substitution; // Missing requested fonts and their substitutes
+pub mod font_usage; // Fonts referenced by page content in the final PDF
 pub mod fonts; // Font metrics and text width estimation
 pub mod generator;
 pub mod layout_analyzer; // Text extraction and size estimation from layouts
 pub mod layout_renderer;
+pub mod links; // Autolinked emails/URLs and their link annotations
+mod page_furniture; // Furniture repeated on every page, shared as one Form XObject
 mod page_manager; // Page creation and tracking (SRP refactor)
 mod pdf_operators; // PDF content stream operators for rendering
 pub mod pdfa; // PDF/A compliance support
+pub mod size_target; // Regenerating with size reductions to fit a byte budget
 pub mod standard_fonts; // Embedded Standard 14 fonts for PDF/A
 pub mod text_utils; // Text transformation and alignment utilities
 mod timestamp; // PDF timestamp generation without chrono
 
-pub use ats::{validate_ats_compatibility, ATSValidationReport, ATSWeights, FieldsPlaced};
-pub use config::{Margin, PDFConfig, PDFStandard, PageSize};
+pub use ats::{
+    validate_ats_compatibility, ATSRuleResult, ATSValidationReport, ATSWeights, FieldsPlaced,
+};
+pub use config::{
+    FontQuality, LinkStyle, Margin, MissingFontPolicy, PDFConfig, PDFConfigBuilder, PDFStandard,
+    PageSize, ReadingOrder, ViewerPreferences, Watermark,
+};
+pub use content_builder::{ContentStats, RenderStats};
 pub use css_parser::{
     css_to_points, parse_color, parse_inline_styles, CSSParseError, Color, FontStyle, FontWeight,
     Spacing, StyleDeclaration, TextAlign,
 };
 pub use error::PDFError;
+pub use font_substitution::FontSubstitution;
+pub use font_usage::{referenced_fonts, FontUsage};
 pub use generator::PDFGenerator;
 pub use layout_renderer::{
-    render_layout_to_content, render_page_to_content, BoxContent, LayoutBox, LayoutStructure, Page,
+    line_baseline_offset, render_layout_to_content, render_page_to_content,
+    render_page_to_content_with_fonts, BoxContent, FontContext, LayoutBox, LayoutStructure, Page,
 };
+pub use links::sanitize_link_uri;
+pub use size_target::{generate_within_target, SizeReduction, SizeReport};
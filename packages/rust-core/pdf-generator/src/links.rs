@@ -0,0 +1,315 @@
+//! Autolinking of email addresses and web URLs
+//!
+//! Emails and `http(s)://`/`www.` addresses found in text are split into
+//! their own layout segments so the renderer can place a `/Link` annotation
+//! exactly over them. Segments that already carry an `<a href>` target keep
+//! it. An optional [`LinkStyle`] recolors and underlines the linked text.
+//!
+//! Every URI passes [`sanitize_link_uri`] before it becomes an annotation, so
+//! `javascript:`, `data:` and similar schemes never reach the PDF.
+
+use crate::config::LinkStyle;
+use crate::error::PDFError;
+use layout_types::{BoxContent, LayoutBox, LayoutStructure, TextDecoration, TextSegment};
+use lopdf::{dictionary, Document, Object, ObjectId, StringFormat};
+use regex_utils::{EMAIL, URL};
+use std::ops::Range;
+
+/// Characters that end a sentence rather than belong to a trailing URL
+const URL_TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', ')', ']', '}', '\'', '"'];
+
+/// URI schemes allowed as link annotation targets
+const SAFE_LINK_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// A clickable area on a page
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkAnnotation {
+    /// Annotation rectangle `[llx, lly, urx, ury]` in PDF coordinates
+    pub rect: [f64; 4],
+    /// Target URI (`mailto:` for emails)
+    pub uri: String,
+}
+
+/// Find links in text, returning byte ranges and their target URIs
+///
+/// Emails become `mailto:` links; `www.` addresses get an `https://` scheme.
+/// Bare domains (`example.com`) are not linked to avoid false positives.
+pub fn find_links(text: &str) -> Vec<(Range<usize>, String)> {
+    let mut links: Vec<(Range<usize>, String)> = EMAIL
+        .find_iter(text)
+        .map(|m| (m.range(), format!("mailto:{}", m.as_str())))
+        .collect();
+
+    for m in URL.find_iter(text) {
+        let matched = m.as_str().trim_end_matches(URL_TRAILING_PUNCTUATION);
+        let uri = if matched.starts_with("http://") || matched.starts_with("https://") {
+            matched.to_string()
+        } else if matched.starts_with("www.") {
+            format!("https://{}", matched)
+        } else {
+            continue;
+        };
+        let range = m.start()..m.start() + matched.len();
+        let overlaps_email = links
+            .iter()
+            .any(|(existing, _)| existing.start < range.end && range.start < existing.end);
+        if !range.is_empty() && !overlaps_email {
+            links.push((range, uri));
+        }
+    }
+
+    links.sort_by_key(|(range, _)| range.start);
+    links
+}
+
+/// Return `uri` if it is safe to use as a link annotation target
+///
+/// Only absolute `http`, `https`, `mailto` and `tel` URIs are allowed.
+/// Script-capable schemes (`javascript:`, `vbscript:`, `data:`), relative
+/// references and URIs containing control characters (which viewers may strip
+/// to reveal a hidden scheme, e.g. `java\tscript:`) are rejected.
+pub fn sanitize_link_uri(uri: &str) -> Option<String> {
+    let uri = uri.trim();
+    if uri.chars().any(char::is_control) {
+        return None;
+    }
+    let (scheme, rest) = uri.split_once(':')?;
+    let allowed = SAFE_LINK_SCHEMES
+        .iter()
+        .any(|safe| scheme.eq_ignore_ascii_case(safe));
+    (allowed && !rest.is_empty()).then(|| uri.to_string())
+}
+
+/// Target URI of a segment whose whole (trimmed) text is a single link
+pub fn link_uri(text: &str) -> Option<String> {
+    let trimmed = text.trim();
+    match find_links(trimmed).as_slice() {
+        [(range, uri)] if range.start == 0 && range.end == trimmed.len() => Some(uri.clone()),
+        _ => None,
+    }
+}
+
+/// Split text segments so every link is a segment of its own
+///
+/// When `style` is set, link segments also take its color and underline.
+pub fn autolink_layout(layout: &LayoutStructure, style: Option<&LinkStyle>) -> LayoutStructure {
+    let mut linked = layout.clone();
+    for page in &mut linked.pages {
+        for layout_box in &mut page.boxes {
+            autolink_box(layout_box, style);
+        }
+    }
+    linked
+}
+
+fn autolink_box(layout_box: &mut LayoutBox, style: Option<&LinkStyle>) {
+    match &mut layout_box.content {
+        BoxContent::Text(lines) => {
+            for line in lines {
+                line.segments = line
+                    .segments
+                    .iter()
+                    .flat_map(|segment| split_segment(segment, style))
+                    .collect();
+            }
+        }
+        BoxContent::Container(children) => {
+            for child in children {
+                autolink_box(child, style);
+            }
+        }
+        BoxContent::Empty => {}
+    }
+}
+
+fn split_segment(segment: &TextSegment, style: Option<&LinkStyle>) -> Vec<TextSegment> {
+    if segment.link.is_some() {
+        let mut link = segment.clone();
+        apply_link_style(&mut link, style);
+        return vec![link];
+    }
+
+    let links = find_links(&segment.text);
+    if links.is_empty() {
+        return vec![segment.clone()];
+    }
+
+    let piece = |range: Range<usize>| TextSegment {
+        text: segment.text[range].to_string(),
+        ..segment.clone()
+    };
+
+    let mut pieces = Vec::with_capacity(links.len() * 2 + 1);
+    let mut cursor = 0;
+    for (range, _) in links {
+        if range.start > cursor {
+            pieces.push(piece(cursor..range.start));
+        }
+        let mut link = piece(range.clone());
+        apply_link_style(&mut link, style);
+        pieces.push(link);
+        cursor = range.end;
+    }
+    if cursor < segment.text.len() {
+        pieces.push(piece(cursor..segment.text.len()));
+    }
+    pieces
+}
+
+fn apply_link_style(segment: &mut TextSegment, style: Option<&LinkStyle>) {
+    if let Some(style) = style {
+        segment.color = Some(style.color);
+        if style.underline {
+            segment.text_decoration = Some(TextDecoration::Underline);
+        }
+    }
+}
+
+/// Add `/Link` annotations with URI actions to a page's `/Annots`
+///
+/// Links whose URI fails [`sanitize_link_uri`] are dropped.
+pub fn add_link_annotations(
+    doc: &mut Document,
+    page_id: ObjectId,
+    links: &[LinkAnnotation],
+) -> Result<(), PDFError> {
+    let links: Vec<&LinkAnnotation> = links
+        .iter()
+        .filter(|link| sanitize_link_uri(&link.uri).is_some())
+        .collect();
+    if links.is_empty() {
+        return Ok(());
+    }
+
+    let annotation_ids: Vec<Object> = links
+        .iter()
+        .map(|link| {
+            let annotation = dictionary! {
+                "Type" => "Annot",
+                "Subtype" => "Link",
+                "Rect" => link.rect.iter().map(|&v| Object::Real(v as f32)).collect::<Vec<_>>(),
+                "Border" => vec![0.into(), 0.into(), 0.into()],
+                // Print flag, required for PDF/A
+                "F" => 4,
+                "A" => dictionary! {
+                    "S" => "URI",
+                    "URI" => Object::String(link.uri.as_bytes().to_vec(), StringFormat::Literal),
+                },
+            };
+            Object::Reference(doc.add_object(annotation))
+        })
+        .collect();
+
+    let page = doc
+        .get_object_mut(page_id)
+        .and_then(Object::as_dict_mut)
+        .map_err(|e| PDFError::RenderError(format!("Failed to get page: {}", e)))?;
+    match page.get_mut(b"Annots") {
+        Ok(Object::Array(annots)) => annots.extend(annotation_ids),
+        _ => page.set("Annots", annotation_ids),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_links_email_and_urls() {
+        let text = "jane@example.com, https://jane.dev. See www.example.org!";
+        let links = find_links(text);
+        let found: Vec<(&str, &str)> = links
+            .iter()
+            .map(|(range, uri)| (&text[range.clone()], uri.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("jane@example.com", "mailto:jane@example.com"),
+                ("https://jane.dev", "https://jane.dev"),
+                ("www.example.org", "https://www.example.org"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_links_ignores_bare_domains() {
+        assert!(find_links("Worked at example.com for years").is_empty());
+    }
+
+    #[test]
+    fn test_link_uri_requires_whole_text() {
+        assert_eq!(
+            link_uri(" jane@example.com "),
+            Some("mailto:jane@example.com".to_string())
+        );
+        assert_eq!(link_uri("Email: jane@example.com"), None);
+    }
+
+    #[test]
+    fn test_sanitize_link_uri_allows_only_safe_schemes() {
+        assert_eq!(
+            sanitize_link_uri(" https://jane.dev "),
+            Some("https://jane.dev".to_string())
+        );
+        assert_eq!(
+            sanitize_link_uri("MAILTO:jane@example.com"),
+            Some("MAILTO:jane@example.com".to_string())
+        );
+        for unsafe_uri in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            "java\tscript:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "data:image/png;base64,AAAA",
+            "vbscript:msgbox",
+            "/relative/path",
+            "#section",
+            "https:",
+        ] {
+            assert_eq!(sanitize_link_uri(unsafe_uri), None, "{}", unsafe_uri);
+        }
+    }
+
+    #[test]
+    fn test_split_segment_keeps_href_segment_whole() {
+        let segment = TextSegment {
+            text: "Portfolio (jane@example.com)".to_string(),
+            font_weight: None,
+            font_style: None,
+            font_size: None,
+            text_decoration: None,
+            color: None,
+            link: Some("https://jane.dev".to_string()),
+        };
+        let pieces = split_segment(&segment, Some(&LinkStyle::default()));
+
+        assert_eq!(pieces.len(), 1);
+        assert_eq!(pieces[0].link, segment.link);
+        assert_eq!(pieces[0].text_decoration, Some(TextDecoration::Underline));
+    }
+
+    #[test]
+    fn test_split_segment_styles_only_the_link() {
+        let segment = TextSegment {
+            text: "Email: jane@example.com today".to_string(),
+            font_weight: None,
+            font_style: None,
+            font_size: None,
+            text_decoration: None,
+            color: None,
+            link: None,
+        };
+        let style = LinkStyle::default();
+        let pieces = split_segment(&segment, Some(&style));
+
+        let texts: Vec<&str> = pieces.iter().map(|p| p.text.as_str()).collect();
+        assert_eq!(texts, vec!["Email: ", "jane@example.com", " today"]);
+        assert_eq!(pieces[1].color, Some(style.color));
+        assert_eq!(pieces[1].text_decoration, Some(TextDecoration::Underline));
+        assert_eq!(pieces[0].color, None);
+        assert_eq!(pieces[2].text_decoration, None);
+    }
+}
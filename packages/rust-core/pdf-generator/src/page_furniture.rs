@@ -0,0 +1,188 @@
+//! Page furniture repeated on every page
+//!
+//! Content drawn identically on each page, such as a watermark, is written
+//! once as a Form XObject. Every page lists the form in its resources and
+//! paints it with `Do`, so the bytes are not copied into each content stream.
+
+use crate::color_utils::rgb_to_pdf_color;
+use crate::config::{PDFConfig, PDFStandard, Watermark};
+use crate::content_builder::ContentBuilder;
+use crate::encoding::{encode_as_cidfont_hex, encode_win_ansi_hex};
+use crate::error::PDFError;
+use crate::font_registry::type1_font_dict;
+use crate::fonts::estimate_text_width;
+use lopdf::{dictionary, Dictionary, Document, Object, ObjectId, Stream};
+use std::collections::HashMap;
+
+/// Resource name of the shared furniture form in page resources
+pub const FURNITURE_XOBJECT_NAME: &str = "Fm1";
+
+/// Font the furniture form selects
+const FURNITURE_FONT: &str = "Helvetica";
+
+/// Paint the configured furniture beneath the content of every page
+///
+/// Does nothing when the configuration has no furniture (no watermark).
+pub fn add_page_furniture(
+    doc: &mut Document,
+    config: &PDFConfig,
+    page_ids: &HashMap<usize, ObjectId>,
+) -> Result<(), PDFError> {
+    let Some(watermark) = config
+        .watermark
+        .as_ref()
+        .filter(|w| !w.text.trim().is_empty())
+    else {
+        return Ok(());
+    };
+
+    let (width, height) = config.page_size.dimensions();
+    // The form's Helvetica is replaced by an embedded Karla CIDFont on finalize
+    let cid_font = config.standard == PDFStandard::PDFA1b || config.embed_base_fonts;
+    let content = watermark_content(watermark, width, height, cid_font);
+
+    let mut pages: Vec<(usize, ObjectId)> = page_ids.iter().map(|(n, id)| (*n, *id)).collect();
+    pages.sort_unstable();
+    let page_ids: Vec<ObjectId> = pages.into_iter().map(|(_, id)| id).collect();
+
+    add_shared_form(doc, &page_ids, &content, width, height)?;
+    Ok(())
+}
+
+/// Content of a watermark drawn along the page diagonal, centered
+///
+/// The text is written as CIDs when `cid_font` is set and as WinAnsi bytes for
+/// the Type1 font otherwise.
+fn watermark_content(
+    watermark: &Watermark,
+    page_width: f64,
+    page_height: f64,
+    cid_font: bool,
+) -> String {
+    let (r, g, b) = rgb_to_pdf_color(&watermark.color);
+    let text_width = estimate_text_width(&watermark.text, watermark.font_size, FURNITURE_FONT);
+    let (sin, cos) = page_height.atan2(page_width).sin_cos();
+
+    let mut content = String::new();
+    content.save_graphics_state();
+    content.set_fill_color_rgb(r, g, b);
+    content.concat_matrix(cos, sin, -sin, cos, page_width / 2.0, page_height / 2.0);
+    content.begin_text();
+    content.set_font(FURNITURE_FONT, watermark.font_size);
+    // Center on the origin; capitals stand about 0.7 em above the baseline
+    content.set_text_position(-text_width / 2.0, -watermark.font_size * 0.35);
+    content.show_text_hex(&if cid_font {
+        encode_as_cidfont_hex(&watermark.text)
+    } else {
+        encode_win_ansi_hex(&watermark.text)
+    });
+    content.end_text();
+    content.restore_graphics_state();
+    content
+}
+
+/// Write `content` once as a page-sized Form XObject painted first on each page
+///
+/// The form carries its own font resources; each page gets it under
+/// [`FURNITURE_XOBJECT_NAME`] and a `q /Fm1 Do Q` prefix on its content
+/// stream, so the furniture sits beneath the page content.
+fn add_shared_form(
+    doc: &mut Document,
+    page_ids: &[ObjectId],
+    content: &str,
+    width: f64,
+    height: f64,
+) -> Result<ObjectId, PDFError> {
+    let font_id = doc.add_object(type1_font_dict(FURNITURE_FONT));
+    let form = Stream::new(
+        dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Form",
+            "BBox" => vec![0.into(), 0.into(), width.into(), height.into()],
+            "Resources" => dictionary! {
+                "Font" => dictionary! { FURNITURE_FONT => font_id },
+            },
+        },
+        content.as_bytes().to_vec(),
+    );
+    let form_id = doc.add_object(form);
+
+    let mut prefix = String::new();
+    prefix.save_graphics_state();
+    prefix.draw_xobject(FURNITURE_XOBJECT_NAME);
+    prefix.restore_graphics_state();
+
+    for &page_id in page_ids {
+        let page = doc
+            .get_object_mut(page_id)
+            .and_then(Object::as_dict_mut)
+            .map_err(|e| PDFError::RenderError(format!("Failed to get page: {}", e)))?;
+        xobject_resources(page).set(FURNITURE_XOBJECT_NAME, form_id);
+
+        let content_id = page
+            .get(b"Contents")
+            .and_then(Object::as_reference)
+            .map_err(|e| PDFError::RenderError(format!("Failed to get content: {}", e)))?;
+        let stream = doc
+            .get_object_mut(content_id)
+            .and_then(Object::as_stream_mut)
+            .map_err(|e| PDFError::RenderError(format!("Failed to get content: {}", e)))?;
+        let mut page_content = prefix.as_bytes().to_vec();
+        page_content.extend_from_slice(&stream.content);
+        stream.set_plain_content(page_content);
+    }
+
+    Ok(form_id)
+}
+
+/// The page's `/Resources /XObject` dictionary, created if missing
+fn xobject_resources(page: &mut Dictionary) -> &mut Dictionary {
+    if !matches!(page.get(b"Resources"), Ok(Object::Dictionary(_))) {
+        page.set("Resources", dictionary! {});
+    }
+    let resources = page
+        .get_mut(b"Resources")
+        .and_then(Object::as_dict_mut)
+        .unwrap();
+    if !matches!(resources.get(b"XObject"), Ok(Object::Dictionary(_))) {
+        resources.set("XObject", dictionary! {});
+    }
+    resources
+        .get_mut(b"XObject")
+        .and_then(Object::as_dict_mut)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watermark_content_is_centered_on_the_diagonal() {
+        let content = watermark_content(&Watermark::new("DRAFT"), 612.0, 792.0, false);
+
+        assert!(
+            content.starts_with("q\n") && content.ends_with("Q\n"),
+            "{}",
+            content
+        );
+        assert!(content.contains("306 396 cm"), "{}", content);
+        assert!(content.contains("<4452414654> Tj"), "{}", content);
+        assert!(content.contains("/Helvetica 72 Tf"), "{}", content);
+
+        let content = watermark_content(&Watermark::new("DRAFT"), 612.0, 792.0, true);
+        assert!(content.contains(&encode_as_cidfont_hex("DRAFT")));
+    }
+
+    #[test]
+    fn test_no_furniture_without_watermark() {
+        let mut doc = Document::with_version("1.7");
+        let blank = PDFConfig {
+            watermark: Some(Watermark::new("  ")),
+            ..Default::default()
+        };
+        add_page_furniture(&mut doc, &PDFConfig::default(), &HashMap::new()).unwrap();
+        add_page_furniture(&mut doc, &blank, &HashMap::new()).unwrap();
+        assert!(doc.objects.is_empty());
+    }
+}
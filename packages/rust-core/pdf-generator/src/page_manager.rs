@@ -2,6 +2,7 @@
 //!
 //! This module handles page creation, navigation, and tracking for PDF documents.
 
+use crate::config::ReadingOrder;
 use crate::error::PDFError;
 use lopdf::{dictionary, Document, Object, Stream};
 use std::collections::HashMap;
@@ -16,6 +17,12 @@ pub struct PDFPageManager {
     page_ids: HashMap<usize, (u32, u16)>,
     /// Pages tree object ID
     pages_id: (u32, u16),
+    /// Whether pages declare structure-based tab order (`/Tabs /S`)
+    structured_reading_order: bool,
+    /// Whether `/Annots` are sorted by position or keep layout box order
+    reading_order: ReadingOrder,
+    /// Trim inset and bleed in points for `/TrimBox` and `/BleedBox`
+    print_boxes: Option<(f64, f64)>,
 }
 
 impl PDFPageManager {
@@ -36,9 +43,37 @@ impl PDFPageManager {
             page_count: 1,
             page_ids,
             pages_id,
+            structured_reading_order: true,
+            reading_order: ReadingOrder::Geometric,
+            print_boxes: None,
         })
     }
 
+    /// Enable or disable structure-based tab order (`/Tabs /S`) on finalize
+    pub fn set_structured_reading_order(&mut self, enabled: bool) {
+        self.structured_reading_order = enabled;
+    }
+
+    /// Choose how `/Annots` are ordered under structured reading order
+    pub fn set_reading_order(&mut self, reading_order: ReadingOrder) {
+        self.reading_order = reading_order;
+    }
+
+    /// Configure `/TrimBox` and `/BleedBox` emitted on finalize
+    ///
+    /// `trim_inset` is measured inward from `/MediaBox` and `bleed` outward
+    /// from the trim. An unset trim inset defaults to the bleed; when both are
+    /// unset, no print boxes are emitted.
+    pub fn set_print_boxes(&mut self, trim_inset: Option<f64>, bleed: Option<f64>) {
+        self.print_boxes = match (trim_inset, bleed) {
+            (None, None) => None,
+            (trim, bleed) => {
+                let bleed = bleed.unwrap_or(0.0).max(0.0);
+                Some((trim.unwrap_or(bleed).max(0.0), bleed))
+            }
+        };
+    }
+
     /// Add a new page to the document
     pub fn add_page(
         &mut self,
@@ -74,10 +109,36 @@ impl PDFPageManager {
     }
 
     /// Update page count in the document
+    ///
+    /// When structured reading order is enabled, also sets `/Tabs /S` on every
+    /// page and, for [`ReadingOrder::Geometric`], sorts each page's annotations
+    /// into reading order; [`ReadingOrder::DomOrder`] keeps the order they
+    /// were added in, which is layout box order. When print boxes are
+    /// configured, sets `/TrimBox` and `/BleedBox` on every page.
     pub fn finalize(&self, doc: &mut Document) -> Result<(), PDFError> {
         if let Ok(Object::Dictionary(ref mut pages_dict)) = doc.get_object_mut(self.pages_id) {
             pages_dict.set("Count", self.page_count as i64);
         }
+
+        if let Some((trim_inset, bleed)) = self.print_boxes {
+            for page_id in self.page_ids.values() {
+                if let Ok(Object::Dictionary(ref mut page_dict)) = doc.get_object_mut(*page_id) {
+                    set_print_boxes(page_dict, trim_inset, bleed);
+                }
+            }
+        }
+
+        if self.structured_reading_order {
+            for page_id in self.page_ids.values() {
+                if self.reading_order == ReadingOrder::Geometric {
+                    sort_annotations_by_reading_order(doc, *page_id);
+                }
+                if let Ok(Object::Dictionary(ref mut page_dict)) = doc.get_object_mut(*page_id) {
+                    page_dict.set("Tabs", Object::Name(b"S".to_vec()));
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -90,6 +151,100 @@ fn ensure_color_space(resources: &mut lopdf::Dictionary) {
     resources.set("ColorSpace", colorspace_dict);
 }
 
+/// Sort a page's `/Annots` array into reading order
+///
+/// Annotations are ordered top-to-bottom (PDF Y descending), then left-to-right
+/// (X ascending), using the top-left corner of each annotation's `/Rect`.
+/// Annotations without a readable `/Rect` keep their relative order at the end.
+fn sort_annotations_by_reading_order(doc: &mut Document, page_id: (u32, u16)) {
+    let annots = match doc.get_object(page_id) {
+        Ok(Object::Dictionary(page_dict)) => page_dict
+            .get(b"Annots")
+            .and_then(|obj| obj.as_array())
+            .cloned()
+            .ok(),
+        _ => None,
+    };
+    let Some(annots) = annots else {
+        return;
+    };
+
+    let mut keyed: Vec<(Option<(f32, f32)>, Object)> = annots
+        .into_iter()
+        .map(|annot| (annotation_top_left(doc, &annot), annot))
+        .collect();
+    keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+        (Some((ax, ay)), Some((bx, by))) => by.total_cmp(ay).then(ax.total_cmp(bx)),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    let sorted: Vec<Object> = keyed.into_iter().map(|(_, annot)| annot).collect();
+    if let Ok(Object::Dictionary(ref mut page_dict)) = doc.get_object_mut(page_id) {
+        page_dict.set("Annots", sorted);
+    }
+}
+
+/// Top-left corner `(x, y)` of an annotation's `/Rect`, in PDF coordinates
+fn annotation_top_left(doc: &Document, annot: &Object) -> Option<(f32, f32)> {
+    let dict = match annot {
+        Object::Reference(id) => doc.get_dictionary(*id).ok()?,
+        Object::Dictionary(dict) => dict,
+        _ => return None,
+    };
+    let rect = dict.get(b"Rect").and_then(|obj| obj.as_array()).ok()?;
+    let coords: Vec<f32> = rect.iter().filter_map(|v| v.as_float().ok()).collect();
+    if coords.len() != 4 {
+        return None;
+    }
+
+    Some((coords[0].min(coords[2]), coords[1].max(coords[3])))
+}
+
+/// Sets `/TrimBox` and `/BleedBox` on a page, derived from its `/MediaBox`
+fn set_print_boxes(page_dict: &mut lopdf::Dictionary, trim_inset: f64, bleed: f64) {
+    let Some(media) = page_dict
+        .get(b"MediaBox")
+        .and_then(|obj| obj.as_array())
+        .ok()
+        .and_then(|arr| rect_coords(arr))
+    else {
+        return;
+    };
+
+    let [llx, lly, urx, ury] = media;
+    let trim_inset = trim_inset.min((urx - llx) / 2.0).min((ury - lly) / 2.0);
+    let trim = [
+        llx + trim_inset,
+        lly + trim_inset,
+        urx - trim_inset,
+        ury - trim_inset,
+    ];
+    let bleed = [
+        (trim[0] - bleed).max(llx),
+        (trim[1] - bleed).max(lly),
+        (trim[2] + bleed).min(urx),
+        (trim[3] + bleed).min(ury),
+    ];
+
+    let to_array = |rect: [f64; 4]| rect.iter().map(|v| (*v).into()).collect::<Vec<Object>>();
+    page_dict.set("TrimBox", to_array(trim));
+    page_dict.set("BleedBox", to_array(bleed));
+}
+
+/// Reads a four-number rectangle array
+fn rect_coords(arr: &[Object]) -> Option<[f64; 4]> {
+    if arr.len() != 4 {
+        return None;
+    }
+    let mut coords = [0.0; 4];
+    for (coord, obj) in coords.iter_mut().zip(arr) {
+        *coord = obj.as_float().ok()? as f64;
+    }
+    Some(coords)
+}
+
 /// Add a page to the document
 fn add_page(
     doc: &mut Document,
@@ -278,6 +433,130 @@ mod tests {
         }
     }
 
+    fn page_rect(doc: &Document, page_id: (u32, u16), key: &[u8]) -> Option<[f64; 4]> {
+        match doc.get_object(page_id) {
+            Ok(Object::Dictionary(page_dict)) => page_dict
+                .get(key)
+                .and_then(|obj| obj.as_array())
+                .ok()
+                .and_then(|arr| rect_coords(arr)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_finalize_sets_print_boxes_on_every_page() {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! { "Type" => "Pages", "Kids" => vec![], "Count" => 0 }),
+        );
+
+        let mut manager = PDFPageManager::new(&mut doc, pages_id, 612.0, 792.0).unwrap();
+        manager.add_page(&mut doc, 612.0, 792.0).unwrap();
+        manager.set_print_boxes(Some(18.0), Some(9.0));
+        manager.finalize(&mut doc).unwrap();
+
+        for page_id in manager.page_ids().values() {
+            let trim = page_rect(&doc, *page_id, b"TrimBox").expect("TrimBox");
+            let bleed = page_rect(&doc, *page_id, b"BleedBox").expect("BleedBox");
+            assert_eq!(trim, [18.0, 18.0, 594.0, 774.0]);
+            assert_eq!(bleed, [9.0, 9.0, 603.0, 783.0]);
+        }
+    }
+
+    #[test]
+    fn test_finalize_without_print_boxes() {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! { "Type" => "Pages", "Kids" => vec![], "Count" => 0 }),
+        );
+
+        let manager = PDFPageManager::new(&mut doc, pages_id, 612.0, 792.0).unwrap();
+        manager.finalize(&mut doc).unwrap();
+
+        let page_id = manager.current_page_id();
+        assert!(page_rect(&doc, page_id, b"TrimBox").is_none());
+        assert!(page_rect(&doc, page_id, b"BleedBox").is_none());
+    }
+
+    #[test]
+    fn test_finalize_sets_structured_tab_order_and_sorts_annotations() {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let pages_dict = dictionary! {
+            "Type" => "Pages",
+            "Kids" => vec![],
+            "Count" => 0,
+        };
+        doc.objects.insert(pages_id, Object::Dictionary(pages_dict));
+
+        let mut manager = PDFPageManager::new(&mut doc, pages_id, 612.0, 792.0).unwrap();
+        manager.add_page(&mut doc, 612.0, 792.0).unwrap();
+
+        // Link annotations added out of reading order: [x1, y1, x2, y2]
+        let rects = [
+            [300.0, 600.0, 400.0, 612.0], // second row, right
+            [50.0, 700.0, 150.0, 712.0],  // first row
+            [50.0, 600.0, 150.0, 612.0],  // second row, left
+        ];
+        let annot_ids: Vec<Object> = rects
+            .iter()
+            .map(|r| {
+                let rect: Vec<Object> = r.iter().map(|v| Object::Real(*v)).collect();
+                Object::Reference(doc.add_object(dictionary! {
+                    "Type" => "Annot",
+                    "Subtype" => "Link",
+                    "Rect" => rect,
+                }))
+            })
+            .collect();
+        let first_page = manager.get_page_id(1).unwrap();
+        if let Ok(Object::Dictionary(ref mut page_dict)) = doc.get_object_mut(first_page) {
+            page_dict.set("Annots", annot_ids);
+        }
+
+        manager.finalize(&mut doc).unwrap();
+
+        for page_id in manager.page_ids().values() {
+            let page_dict = doc.get_dictionary(*page_id).unwrap();
+            assert_eq!(page_dict.get(b"Tabs").unwrap().as_name().unwrap(), b"S");
+        }
+
+        // Y descending, then X ascending
+        let page_dict = doc.get_dictionary(first_page).unwrap();
+        let corners: Vec<(f32, f32)> = page_dict
+            .get(b"Annots")
+            .and_then(|obj| obj.as_array())
+            .unwrap()
+            .iter()
+            .map(|annot| annotation_top_left(&doc, annot).unwrap())
+            .collect();
+        assert_eq!(corners, vec![(50.0, 712.0), (50.0, 612.0), (300.0, 612.0)]);
+    }
+
+    #[test]
+    fn test_finalize_without_structured_reading_order() {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let pages_dict = dictionary! {
+            "Type" => "Pages",
+            "Kids" => vec![],
+            "Count" => 0,
+        };
+        doc.objects.insert(pages_id, Object::Dictionary(pages_dict));
+
+        let mut manager = PDFPageManager::new(&mut doc, pages_id, 612.0, 792.0).unwrap();
+        manager.set_structured_reading_order(false);
+        manager.finalize(&mut doc).unwrap();
+
+        let page_dict = doc.get_dictionary(manager.current_page_id()).unwrap();
+        assert!(!page_dict.has(b"Tabs"));
+    }
+
     #[test]
     fn test_ensure_color_space_adds_default_rgb() {
         let mut resources = dictionary! {};
@@ -6,8 +6,15 @@
 
 use crate::content_builder::ContentBuilder;
 use crate::css_parser::{BorderLineStyle, BorderStyle, Color};
+use crate::encoding::encode_text_hex;
 use crate::error::PDFError;
-use layout_types::{LayoutBox, TextDecoration, DEFAULT_FONT_SIZE, DEFAULT_LINE_HEIGHT_RATIO};
+use crate::font_resolver::get_font_name;
+use crate::fonts::estimate_text_width;
+use font_toolkit::DecorationMetrics;
+use layout_types::{
+    LayoutBox, ListStyleType, TextDecoration, DEFAULT_FONT_SIZE, DEFAULT_LINE_HEIGHT_RATIO,
+};
+use std::collections::HashSet;
 
 // PDF Rendering Constants
 
@@ -30,6 +37,12 @@ const BEZIER_CIRCLE_KAPPA: f64 = 0.5522847498;
 /// Horizontal offset for list bullets in points (distance to the left of list item)
 const BULLET_OFFSET_POINTS: f64 = 8.0;
 
+/// Gap between a decimal list marker ("1.") and the list item text in points
+const DECIMAL_MARKER_GAP_POINTS: f64 = 4.0;
+
+/// Outline width of a hollow circle marker, as a fraction of its radius
+const HOLLOW_BULLET_STROKE_RATIO: f64 = 0.4;
+
 /// Line height ratio threshold for applying leading corrections
 /// Values > 1.3 indicate significant line-height that needs adjustment
 const LINE_HEIGHT_ADJUSTMENT_THRESHOLD: f64 = 1.3;
@@ -71,40 +84,118 @@ pub fn render_background<C: ContentBuilder>(
     Ok(())
 }
 
-/// Render border bottom line
+/// Render full-bleed background rectangle
 ///
-/// Appends PDF operators to draw a border line at the bottom of a box.
-/// Supports solid, dashed, and dotted line styles.
+/// Paints a box's background out to the nearest physical page edge and over the
+/// full page height, ignoring the content margin. Used for edge-to-edge sidebars:
+/// a box in the left half of the page bleeds from x=0 to its right edge, a box in
+/// the right half bleeds from its left edge to the page width.
+///
+/// # Arguments
+///
+/// * `layout_box` - The box defining horizontal position and width
+/// * `color` - Fill color for the background
+/// * `page_width` - Width of the page in points
+/// * `page_height` - Height of the page in points
+/// * `content` - Mutable string buffer to append PDF operators to
+pub fn render_full_bleed_background<C: ContentBuilder>(
+    layout_box: &LayoutBox,
+    color: Color,
+    page_width: f64,
+    page_height: f64,
+    content: &mut C,
+) -> Result<(), PDFError> {
+    let box_center = layout_box.x + layout_box.width / 2.0;
+    let (x, width) = if box_center <= page_width / 2.0 {
+        (0.0, layout_box.x + layout_box.width)
+    } else {
+        (layout_box.x, page_width - layout_box.x)
+    };
+
+    content.set_fill_color_rgb(
+        color.r as f64 / 255.0,
+        color.g as f64 / 255.0,
+        color.b as f64 / 255.0,
+    );
+
+    content.rectangle(x, 0.0, width, page_height);
+    content.fill();
+
+    Ok(())
+}
+
+/// A box edge drawn by [`render_border_side`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorderSide {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+/// Render one border line of a box
+///
+/// Supports solid, dashed, and dotted line styles. Top, right and left lines
+/// are centered within the border area Taffy reserved inside the box; the
+/// bottom line sits on the box's content edge. Each line spans the box's
+/// full width or height.
 ///
 /// # Arguments
 ///
 /// * `layout_box` - The box defining position and dimensions
 /// * `border` - Border style specification (width, style, color)
+/// * `side` - Which edge to draw
 /// * `page_height` - Height of the page (for coordinate conversion)
 /// * `content` - Mutable string buffer to append PDF operators to
-pub fn render_border_bottom<C: ContentBuilder>(
+pub fn render_border_side<C: ContentBuilder>(
     layout_box: &LayoutBox,
     border: &BorderStyle,
+    side: BorderSide,
     page_height: f64,
     content: &mut C,
 ) -> Result<(), PDFError> {
-    // Skip if border style is None
     if matches!(border.style, BorderLineStyle::None) {
         return Ok(());
     }
 
-    // Convert coordinates for border positioning
-    // For Taffy-computed boxes (height > 0): border is included in height, adjust to content edge
-    // For synthetic border boxes (height = 0): border_box.y is already the correct position
-    let border_adjustment = if layout_box.height > 0.0 {
-        border.width // Adjust for Taffy including border in height
-    } else {
-        0.0 // Synthetic border box - y is already correct
+    let top = page_height - layout_box.y;
+    let bottom = top - layout_box.height;
+    let inset = border.width / 2.0;
+    let (start, end) = match side {
+        BorderSide::Top => (
+            (layout_box.x, top - inset),
+            (layout_box.x + layout_box.width, top - inset),
+        ),
+        BorderSide::Right => {
+            let x = layout_box.x + layout_box.width - inset;
+            ((x, top), (x, bottom))
+        }
+        BorderSide::Bottom => {
+            // Taffy-computed boxes include the border in their height; a
+            // synthetic zero-height border box is already at the line
+            let y = if layout_box.height > 0.0 {
+                bottom + border.width
+            } else {
+                bottom
+            };
+            ((layout_box.x, y), (layout_box.x + layout_box.width, y))
+        }
+        BorderSide::Left => {
+            let x = layout_box.x + inset;
+            ((x, top), (x, bottom))
+        }
     };
-    let pdf_y = page_height - layout_box.y - layout_box.height + border_adjustment;
-    let x_start = layout_box.x;
-    let x_end = layout_box.x + layout_box.width;
 
+    set_border_stroke(border, content);
+    content.move_to(start.0, start.1);
+    content.line_to(end.0, end.1);
+    content.stroke();
+
+    Ok(())
+}
+
+/// Set stroke color, width and dash pattern for a border line
+fn set_border_stroke<C: ContentBuilder>(border: &BorderStyle, content: &mut C) {
     // Set stroke color using ContentBuilder
     content.set_stroke_color_rgb(
         border.color.r as f64 / 255.0,
@@ -129,13 +220,6 @@ pub fn render_border_bottom<C: ContentBuilder>(
         BorderLineStyle::Dotted => content.set_dash_pattern("[1 1]", 0), // 1 on, 1 off
         BorderLineStyle::Solid | BorderLineStyle::None => content.set_dash_pattern("[]", 0), // Solid line
     }
-
-    // Draw line using ContentBuilder
-    content.move_to(x_start, pdf_y);
-    content.line_to(x_end, pdf_y);
-    content.stroke();
-
-    Ok(())
 }
 
 /// Render text decoration (underline, strikethrough)
@@ -150,19 +234,21 @@ pub fn render_border_bottom<C: ContentBuilder>(
 /// * `y` - Baseline y coordinate
 /// * `width` - Width of the text to decorate
 /// * `font_size` - Font size (used for positioning calculations)
+/// * `metrics` - Underline/strikeout metrics of an embedded font; without
+///   them (Standard 14 fonts) lines are placed by fixed font-size ratios
 /// * `color` - Color for the decoration lines
 /// * `content` - Mutable string buffer to append PDF operators to
+#[allow(clippy::too_many_arguments)]
 pub fn render_text_decoration<C: ContentBuilder>(
     decoration: TextDecoration,
     x: f64,
     y: f64,
     width: f64,
     font_size: f64,
+    metrics: Option<&DecorationMetrics>,
     color: &Color,
     content: &mut C,
 ) -> Result<(), PDFError> {
-    let line_width = DEFAULT_LINE_WIDTH;
-
     // Set stroke color using ContentBuilder
     content.set_stroke_color_rgb(
         color.r as f64 / 255.0,
@@ -170,15 +256,21 @@ pub fn render_text_decoration<C: ContentBuilder>(
         color.b as f64 / 255.0,
     );
 
-    // Set line width
-    content.set_line_width(line_width);
-
     // Render underline
     if matches!(
         decoration,
         TextDecoration::Underline | TextDecoration::UnderlineStrikethrough
     ) {
-        let underline_y = y - font_size * UNDERLINE_OFFSET_RATIO;
+        let (underline_y, line_width) = match metrics {
+            // underlinePosition is the top of the underline; the stroke is
+            // centered on the path, so move down by half the thickness
+            Some(m) => (
+                y + (m.underline_position - m.underline_thickness / 2.0) * font_size,
+                m.underline_thickness * font_size,
+            ),
+            None => (y - font_size * UNDERLINE_OFFSET_RATIO, DEFAULT_LINE_WIDTH),
+        };
+        content.set_line_width(line_width);
         content.move_to(x, underline_y);
         content.line_to(x + width, underline_y);
         content.stroke();
@@ -189,7 +281,15 @@ pub fn render_text_decoration<C: ContentBuilder>(
         decoration,
         TextDecoration::Strikethrough | TextDecoration::UnderlineStrikethrough
     ) {
-        let strike_y = y + font_size * STRIKETHROUGH_OFFSET_RATIO;
+        let strikeout = metrics.and_then(|m| m.strikeout_position.zip(m.strikeout_thickness));
+        let (strike_y, line_width) = match strikeout {
+            Some((position, thickness)) => (y + position * font_size, thickness * font_size),
+            None => (
+                y + font_size * STRIKETHROUGH_OFFSET_RATIO,
+                DEFAULT_LINE_WIDTH,
+            ),
+        };
+        content.set_line_width(line_width);
         content.move_to(x, strike_y);
         content.line_to(x + width, strike_y);
         content.stroke();
@@ -198,22 +298,30 @@ pub fn render_text_decoration<C: ContentBuilder>(
     Ok(())
 }
 
-/// Render bullet point for list items
+/// Render the marker for a list item
 ///
-/// Appends PDF operators to draw a filled circle bullet to the left of a list item.
-/// The bullet position and size are calculated based on font size and line height.
+/// Appends PDF operators to draw the item's `list-style-type` marker to the
+/// left of the list item: a filled circle (disc, the default), a hollow
+/// circle, a filled square, or the right-aligned item number ("3.") for
+/// decimal, numbered by the box's `list_ordinal`. `none` draws nothing.
+/// The marker position and size are calculated based on font size and line
+/// height.
 ///
 /// # Arguments
 ///
 /// * `layout_box` - The list item box defining position and style
 /// * `page_height` - Height of the page (for coordinate conversion)
+/// * `embedded_fonts` - Font resource names backed by embedded CIDFonts, which
+///   decide how a decimal label is encoded (see `encode_text_hex`)
 /// * `content` - Mutable string buffer to append PDF operators to
 pub fn render_list_bullet<C: ContentBuilder>(
     layout_box: &LayoutBox,
     page_height: f64,
+    embedded_fonts: &HashSet<String>,
     content: &mut C,
 ) -> Result<(), PDFError> {
     let style = &layout_box.style;
+    let list_style_type = style.text.list_style_type.unwrap_or(ListStyleType::Disc);
 
     // Get font size, line-height and color
     let font_size = style.text.font_size.unwrap_or(DEFAULT_FONT_SIZE);
@@ -240,63 +348,111 @@ pub fn render_list_bullet<C: ContentBuilder>(
     let bullet_x = layout_box.x - BULLET_OFFSET_POINTS;
     let bullet_y = page_height - layout_box.y - leading - font_size;
 
-    // Set fill color using ContentBuilder
-    content.set_fill_color_rgb(
+    let (r, g, b) = (
         color.r as f64 / 255.0,
         color.g as f64 / 255.0,
         color.b as f64 / 255.0,
     );
 
-    // Render bullet as a filled circle
     let bullet_radius = font_size * 0.15;
     let bullet_center_y = bullet_y + font_size * 0.4;
 
-    // Draw filled circle using Bézier curves and ContentBuilder
-    // PDF doesn't have a direct circle command, so we approximate with 4 Bézier curves
-    let k_r = BEZIER_CIRCLE_KAPPA * bullet_radius;
+    match list_style_type {
+        ListStyleType::None => {}
+        ListStyleType::Disc => {
+            content.set_fill_color_rgb(r, g, b);
+            circle_path(content, bullet_x, bullet_center_y, bullet_radius);
+            content.fill();
+        }
+        ListStyleType::Circle => {
+            // Stroke inside the nominal radius so the outer size matches a disc
+            let stroke_width = bullet_radius * HOLLOW_BULLET_STROKE_RATIO;
+            content.set_stroke_color_rgb(r, g, b);
+            content.set_line_width(stroke_width);
+            circle_path(
+                content,
+                bullet_x,
+                bullet_center_y,
+                bullet_radius - stroke_width / 2.0,
+            );
+            content.stroke();
+        }
+        ListStyleType::Square => {
+            content.set_fill_color_rgb(r, g, b);
+            content.rectangle(
+                bullet_x - bullet_radius,
+                bullet_center_y - bullet_radius,
+                bullet_radius * 2.0,
+                bullet_radius * 2.0,
+            );
+            content.fill();
+        }
+        ListStyleType::Decimal => {
+            // Number right-aligned against the item, on the text baseline
+            let label = format!("{}.", layout_box.list_ordinal.unwrap_or(1));
+            let font_name = get_font_name(style);
+            let label_width = estimate_text_width(&label, font_size, font_name);
+            content.set_fill_color_rgb(r, g, b);
+            content.begin_text();
+            content.set_font(font_name, font_size);
+            content.set_text_position(
+                layout_box.x - DECIMAL_MARKER_GAP_POINTS - label_width,
+                bullet_y,
+            );
+            content.show_text_hex(&encode_text_hex(&label, font_name, embedded_fonts));
+            content.end_text();
+        }
+    }
 
-    content.move_to(bullet_x + bullet_radius, bullet_center_y);
+    Ok(())
+}
+
+/// Append a closed circle path approximated with 4 Bézier curves
+///
+/// PDF doesn't have a direct circle command.
+fn circle_path<C: ContentBuilder>(content: &mut C, cx: f64, cy: f64, radius: f64) {
+    let k_r = BEZIER_CIRCLE_KAPPA * radius;
+
+    content.move_to(cx + radius, cy);
     content.curve_to(
-        bullet_x + bullet_radius,
-        bullet_center_y + k_r,
-        bullet_x + k_r,
-        bullet_center_y + bullet_radius,
-        bullet_x,
-        bullet_center_y + bullet_radius,
+        cx + radius,
+        cy + k_r,
+        cx + k_r,
+        cy + radius,
+        cx,
+        cy + radius,
     );
     content.curve_to(
-        bullet_x - k_r,
-        bullet_center_y + bullet_radius,
-        bullet_x - bullet_radius,
-        bullet_center_y + k_r,
-        bullet_x - bullet_radius,
-        bullet_center_y,
+        cx - k_r,
+        cy + radius,
+        cx - radius,
+        cy + k_r,
+        cx - radius,
+        cy,
     );
     content.curve_to(
-        bullet_x - bullet_radius,
-        bullet_center_y - k_r,
-        bullet_x - k_r,
-        bullet_center_y - bullet_radius,
-        bullet_x,
-        bullet_center_y - bullet_radius,
+        cx - radius,
+        cy - k_r,
+        cx - k_r,
+        cy - radius,
+        cx,
+        cy - radius,
     );
     content.curve_to(
-        bullet_x + k_r,
-        bullet_center_y - bullet_radius,
-        bullet_x + bullet_radius,
-        bullet_center_y - k_r,
-        bullet_x + bullet_radius,
-        bullet_center_y,
+        cx + k_r,
+        cy - radius,
+        cx + radius,
+        cy - k_r,
+        cx + radius,
+        cy,
     );
-    content.fill();
-
-    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::css_parser::StyleDeclaration;
+    use crate::encoding::encode_win_ansi_hex;
     use layout_types::{BoxContent, TextLine};
 
     #[test]
@@ -309,6 +465,7 @@ mod tests {
             content: BoxContent::Container(vec![]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
@@ -344,6 +501,7 @@ mod tests {
             content: BoxContent::Container(vec![]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let mut content = String::new();
@@ -365,7 +523,34 @@ mod tests {
     }
 
     #[test]
-    fn test_render_border_bottom_solid() {
+    fn test_render_full_bleed_background_right_sidebar() {
+        let layout_box = LayoutBox {
+            x: 432.0,
+            y: 36.0,
+            width: 144.0,
+            height: 300.0,
+            content: BoxContent::Container(vec![]),
+            style: StyleDeclaration::default(),
+            element_type: None,
+            list_ordinal: None,
+        };
+
+        let mut content = String::new();
+        let color = Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 1.0,
+        };
+
+        render_full_bleed_background(&layout_box, color, 612.0, 792.0, &mut content).unwrap();
+
+        // Right-half box bleeds from its left edge to the page width
+        assert!(content.contains("432 0 180 792 re\n"));
+    }
+
+    #[test]
+    fn test_render_border_side_bottom_solid() {
         let layout_box = LayoutBox {
             x: 100.0,
             y: 200.0,
@@ -374,6 +559,7 @@ mod tests {
             content: BoxContent::Container(vec![]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let border = BorderStyle {
@@ -390,7 +576,13 @@ mod tests {
         let mut content = String::new();
         let page_height = 792.0;
 
-        let result = render_border_bottom(&layout_box, &border, page_height, &mut content);
+        let result = render_border_side(
+            &layout_box,
+            &border,
+            BorderSide::Bottom,
+            page_height,
+            &mut content,
+        );
 
         assert!(result.is_ok());
         // Should contain stroke color
@@ -408,7 +600,7 @@ mod tests {
     }
 
     #[test]
-    fn test_render_border_bottom_dashed() {
+    fn test_render_border_side_bottom_dashed() {
         let layout_box = LayoutBox {
             x: 50.0,
             y: 100.0,
@@ -417,6 +609,7 @@ mod tests {
             content: BoxContent::Container(vec![]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let border = BorderStyle {
@@ -431,7 +624,13 @@ mod tests {
         };
 
         let mut content = String::new();
-        let result = render_border_bottom(&layout_box, &border, 792.0, &mut content);
+        let result = render_border_side(
+            &layout_box,
+            &border,
+            BorderSide::Bottom,
+            792.0,
+            &mut content,
+        );
 
         assert!(result.is_ok());
         // Should contain dashed pattern [3 2] 0 d
@@ -441,7 +640,7 @@ mod tests {
     }
 
     #[test]
-    fn test_render_border_bottom_dotted() {
+    fn test_render_border_side_bottom_dotted() {
         let layout_box = LayoutBox {
             x: 25.0,
             y: 75.0,
@@ -450,6 +649,7 @@ mod tests {
             content: BoxContent::Container(vec![]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let border = BorderStyle {
@@ -464,7 +664,13 @@ mod tests {
         };
 
         let mut content = String::new();
-        let result = render_border_bottom(&layout_box, &border, 792.0, &mut content);
+        let result = render_border_side(
+            &layout_box,
+            &border,
+            BorderSide::Bottom,
+            792.0,
+            &mut content,
+        );
 
         assert!(result.is_ok());
         // Should contain dotted pattern [1 1] 0 d
@@ -474,7 +680,7 @@ mod tests {
     }
 
     #[test]
-    fn test_render_border_bottom_none() {
+    fn test_render_border_side_bottom_none() {
         let layout_box = LayoutBox {
             x: 0.0,
             y: 0.0,
@@ -483,6 +689,7 @@ mod tests {
             content: BoxContent::Container(vec![]),
             style: StyleDeclaration::default(),
             element_type: None,
+            list_ordinal: None,
         };
 
         let border = BorderStyle {
@@ -497,7 +704,13 @@ mod tests {
         };
 
         let mut content = String::new();
-        let result = render_border_bottom(&layout_box, &border, 792.0, &mut content);
+        let result = render_border_side(
+            &layout_box,
+            &border,
+            BorderSide::Bottom,
+            792.0,
+            &mut content,
+        );
 
         assert!(result.is_ok());
         // Should not add any content for BorderLineStyle::None
@@ -520,6 +733,7 @@ mod tests {
             100.0, // y
             50.0,  // width
             12.0,  // font_size
+            None,
             &color,
             &mut content,
         );
@@ -554,6 +768,7 @@ mod tests {
             200.0,
             80.0,
             14.0,
+            None,
             &color,
             &mut content,
         );
@@ -583,6 +798,7 @@ mod tests {
             150.0,
             60.0,
             10.0,
+            None,
             &color,
             &mut content,
         );
@@ -597,6 +813,46 @@ mod tests {
         assert_eq!(content.matches("S\n").count(), 2);
     }
 
+    #[test]
+    fn test_render_text_decoration_from_font_metrics() {
+        let metrics = DecorationMetrics {
+            underline_position: -0.1,
+            underline_thickness: 0.05,
+            strikeout_position: Some(0.25),
+            strikeout_thickness: Some(0.04),
+        };
+        let black = Color {
+            r: 0,
+            g: 0,
+            b: 0,
+            a: 1.0,
+        };
+
+        let mut content = String::new();
+        render_text_decoration(
+            TextDecoration::UnderlineStrikethrough,
+            10.0,
+            100.0,
+            50.0,
+            20.0,
+            Some(&metrics),
+            &black,
+            &mut content,
+        )
+        .unwrap();
+
+        assert!(
+            content.contains("1 w\n10 97.5 m\n60 97.5 l\nS\n"),
+            "{}",
+            content
+        );
+        assert!(
+            content.contains("0.8 w\n10 105 m\n60 105 l\nS\n"),
+            "{}",
+            content
+        );
+    }
+
     #[test]
     fn test_render_list_bullet() {
         let mut style = StyleDeclaration::default();
@@ -617,13 +873,14 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("List item")]),
             style,
             element_type: None,
+            list_ordinal: None,
         };
         layout_box.element_type = Some(layout_types::ElementType::ListItem);
 
         let mut content = String::new();
         let page_height = 792.0;
 
-        let result = render_list_bullet(&layout_box, page_height, &mut content);
+        let result = render_list_bullet(&layout_box, page_height, &HashSet::new(), &mut content);
 
         assert!(result.is_ok());
         // Should contain fill color
@@ -636,6 +893,39 @@ mod tests {
         assert!(content.contains(" m\n"));
     }
 
+    #[test]
+    fn test_render_list_bullet_marker_shapes() {
+        let render = |list_style_type: ListStyleType| {
+            let mut style = StyleDeclaration::default();
+            style.text.list_style_type = Some(list_style_type);
+            let layout_box = LayoutBox {
+                x: 50.0,
+                y: 100.0,
+                width: 200.0,
+                height: 14.0,
+                content: BoxContent::Text(vec![TextLine::from("Item")]),
+                style,
+                element_type: Some(layout_types::ElementType::ListItem),
+                list_ordinal: Some(3),
+            };
+            let mut content = String::new();
+            render_list_bullet(&layout_box, 792.0, &HashSet::new(), &mut content).unwrap();
+            content
+        };
+
+        let circle = render(ListStyleType::Circle);
+        assert_eq!(circle.matches(" c\n").count(), 4);
+        assert!(circle.ends_with("S\n"), "Hollow circle is stroked");
+
+        let square = render(ListStyleType::Square);
+        assert!(square.contains(" re\nf\n"));
+
+        let decimal = render(ListStyleType::Decimal);
+        assert!(decimal.contains(&format!("<{}> Tj\n", encode_win_ansi_hex("3."))));
+
+        assert!(render(ListStyleType::None).is_empty());
+    }
+
     #[test]
     fn test_render_list_bullet_with_large_line_height() {
         let mut style = StyleDeclaration::default();
@@ -655,11 +945,12 @@ mod tests {
             content: BoxContent::Text(vec![TextLine::from("Item")]),
             style,
             element_type: None,
+            list_ordinal: None,
         };
         layout_box.element_type = Some(layout_types::ElementType::ListItem);
 
         let mut content = String::new();
-        let result = render_list_bullet(&layout_box, 792.0, &mut content);
+        let result = render_list_bullet(&layout_box, 792.0, &HashSet::new(), &mut content);
 
         assert!(result.is_ok());
         // Should apply leading correction for large line-height
@@ -31,7 +31,9 @@ pub const SRGB_ICC_PROFILE: &[u8] = include_bytes!("../srgb.icc");
 /// - `{DC_SUBJECT}` - Dublin Core subject keywords
 /// - `{TIMESTAMP}` - ISO 8601 timestamp (CreateDate and ModifyDate)
 /// - `{CREATOR_TOOL}` - XMP CreatorTool value
+/// - `{PRODUCER}` - PDF Producer value (must match the Info dictionary)
 /// - `{PDF_KEYWORDS}` - PDF keywords element
+/// - `{CUSTOM_PROPERTIES}` - Custom Info entries with their extension schema
 ///
 /// # Performance
 /// Using a template with placeholders is ~5-10% faster than building the
@@ -68,9 +70,10 @@ pub const XMP_TEMPLATE: &str = r#"<?xpacket begin="﻿" id="W5M0MpCehiHzreSzNTcz
     <!-- PDF Schema -->
     <rdf:Description rdf:about=""
       xmlns:pdf="http://ns.adobe.com/pdf/1.3/">
-      <pdf:Producer>lopdf + ResumeWright</pdf:Producer>
+      <pdf:Producer>{PRODUCER}</pdf:Producer>
       {PDF_KEYWORDS}
     </rdf:Description>
+{CUSTOM_PROPERTIES}
 
   </rdf:RDF>
 </x:xmpmeta>
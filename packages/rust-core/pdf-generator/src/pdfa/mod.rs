@@ -166,7 +166,8 @@ pub fn apply_pdfa1b_compliance(
 /// present in PDF readers.
 ///
 /// This function embeds Karla fonts
-/// for all pages in the document.
+/// for all pages in the document, and for the Form XObjects (shared page
+/// furniture) the pages paint.
 ///
 /// # Arguments
 /// * `doc` - Mutable reference to the PDF document
@@ -191,6 +192,14 @@ pub fn embed_standard_fonts_for_pages(
     let bold_italic_id =
         embed_standard_font(doc, FontWeight::Bold, FontStyle::Italic, text_content)?;
 
+    let embedded_fonts = [
+        ("Helvetica", regular_id),
+        ("Helvetica-Bold", bold_id),
+        ("Helvetica-Oblique", italic_id),
+        ("Helvetica-BoldOblique", bold_italic_id),
+    ];
+    let mut form_ids = Vec::new();
+
     // Then, add fonts to each page's resources
     for &page_id in page_ids {
         // Get page object
@@ -234,10 +243,40 @@ pub fn embed_standard_fonts_for_pages(
             };
 
             // Add all font references to this page
-            fonts_dict.set("Helvetica", Object::Reference(regular_id));
-            fonts_dict.set("Helvetica-Bold", Object::Reference(bold_id));
-            fonts_dict.set("Helvetica-Oblique", Object::Reference(italic_id));
-            fonts_dict.set("Helvetica-BoldOblique", Object::Reference(bold_italic_id));
+            for (name, font_id) in embedded_fonts {
+                fonts_dict.set(name, Object::Reference(font_id));
+            }
+
+            if let Ok(xobjects) = resources.get(b"XObject").and_then(Object::as_dict) {
+                form_ids.extend(
+                    xobjects
+                        .iter()
+                        .filter_map(|(_, obj)| obj.as_reference().ok()),
+                );
+            }
+        }
+    }
+
+    // Forms select fonts from their own resources, which need the same fonts
+    form_ids.sort_unstable();
+    form_ids.dedup();
+    for form_id in form_ids {
+        let Ok(Object::Stream(form)) = doc.get_object_mut(form_id) else {
+            continue;
+        };
+        if form.dict.get(b"Subtype").and_then(Object::as_name).ok() != Some(b"Form") {
+            continue;
+        }
+        let form_fonts = form
+            .dict
+            .get_mut(b"Resources")
+            .and_then(Object::as_dict_mut)
+            .and_then(|resources| resources.get_mut(b"Font"))
+            .and_then(Object::as_dict_mut);
+        if let Ok(form_fonts) = form_fonts {
+            for (name, font_id) in embedded_fonts {
+                form_fonts.set(name, Object::Reference(font_id));
+            }
         }
     }
 
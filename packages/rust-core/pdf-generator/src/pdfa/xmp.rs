@@ -94,12 +94,107 @@ fn xmp_element(
     }
 }
 
+/// Generate the `dc:subject` bag from a keyword string
+///
+/// `dc:subject` is an unordered list, so each comma- or semicolon-separated
+/// keyword becomes its own `rdf:li`. The unsplit string goes to `pdf:Keywords`,
+/// which must match the Info dictionary `/Keywords` entry verbatim.
+///
+/// # Returns
+/// Formatted XML string, or empty string if there are no keywords
+fn xmp_keyword_bag(keywords: Option<&str>) -> String {
+    let items = keywords
+        .into_iter()
+        .flat_map(|k| k.split([',', ';']))
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .map(|k| format!("\n          <rdf:li>{}</rdf:li>", escape_xml(k)))
+        .collect::<String>();
+
+    if items.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        r#"<dc:subject>
+        <rdf:Bag>{items}
+        </rdf:Bag>
+      </dc:subject>"#
+    )
+}
+
+/// Generate the custom Info entries as `pdfx:` properties
+///
+/// PDF/A-1 only accepts properties from predefined schemas or from schemas
+/// described in the packet, so the `pdfx` namespace is declared with a
+/// PDF/A extension schema listing every property.
+///
+/// # Returns
+/// Formatted XML string, or empty string if there are no custom entries
+fn xmp_custom_properties(entries: &[(String, &str)]) -> String {
+    if entries.is_empty() {
+        return String::new();
+    }
+
+    let definitions = entries
+        .iter()
+        .map(|(name, _)| {
+            format!(
+                r#"
+                <rdf:li rdf:parseType="Resource">
+                  <pdfaProperty:name>{name}</pdfaProperty:name>
+                  <pdfaProperty:valueType>Text</pdfaProperty:valueType>
+                  <pdfaProperty:category>external</pdfaProperty:category>
+                  <pdfaProperty:description>Custom document property</pdfaProperty:description>
+                </rdf:li>"#
+            )
+        })
+        .collect::<String>();
+    let values = entries
+        .iter()
+        .map(|(name, value)| format!("\n      <pdfx:{name}>{}</pdfx:{name}>", escape_xml(value)))
+        .collect::<String>();
+
+    format!(
+        r#"
+    <!-- PDF/A Extension Schema for custom properties -->
+    <rdf:Description rdf:about=""
+      xmlns:pdfaExtension="http://www.aiim.org/pdfa/ns/extension/"
+      xmlns:pdfaSchema="http://www.aiim.org/pdfa/ns/schema#"
+      xmlns:pdfaProperty="http://www.aiim.org/pdfa/ns/property#">
+      <pdfaExtension:schemas>
+        <rdf:Bag>
+          <rdf:li rdf:parseType="Resource">
+            <pdfaSchema:schema>Custom document properties</pdfaSchema:schema>
+            <pdfaSchema:namespaceURI>http://ns.adobe.com/pdfx/1.3/</pdfaSchema:namespaceURI>
+            <pdfaSchema:prefix>pdfx</pdfaSchema:prefix>
+            <pdfaSchema:property>
+              <rdf:Seq>{definitions}
+              </rdf:Seq>
+            </pdfaSchema:property>
+          </rdf:li>
+        </rdf:Bag>
+      </pdfaExtension:schemas>
+    </rdf:Description>
+
+    <!-- Custom Document Properties -->
+    <rdf:Description rdf:about=""
+      xmlns:pdfx="http://ns.adobe.com/pdfx/1.3/">{values}
+    </rdf:Description>
+"#
+    )
+}
+
 /// Generates XMP metadata packet for PDF/A-1b compliance.
 ///
 /// XMP (Extensible Metadata Platform) is required by PDF/A to store document
 /// metadata in a standardized XML format. This function creates the XMP packet
 /// with the necessary PDF/A identification schema.
 ///
+/// Subject and keywords mirror the Info dictionary: `dc:description` carries
+/// the subject, `pdf:Keywords` the keyword string as-is, and `dc:subject`
+/// one entry per keyword. Custom Info entries become `pdfx:` properties.
+///
 /// # Arguments
 /// * `config` - PDF configuration containing document metadata
 ///
@@ -156,12 +251,15 @@ pub fn generate_xmp_metadata(config: &PDFConfig) -> Vec<u8> {
         &[("xml:lang", "x-default")],
     );
 
-    // Subject keywords: only include if present
-    let dc_subject = xmp_element("dc:subject", keywords, Some("Bag"), &[]);
+    // Subject keywords: one bag entry per keyword, only include if present
+    let dc_subject = xmp_keyword_bag(keywords);
 
     // PDF Keywords: only include if present (direct text, no wrapper)
     let pdf_keywords = xmp_element("pdf:Keywords", keywords, None, &[]);
 
+    // Custom Info entries: only include if present
+    let custom_properties = xmp_custom_properties(&config.custom_info_entries());
+
     // Get current timestamp in ISO 8601 format for XMP
     let timestamp = get_xmp_timestamp();
 
@@ -174,7 +272,9 @@ pub fn generate_xmp_metadata(config: &PDFConfig) -> Vec<u8> {
         .replace("{DC_SUBJECT}", &dc_subject)
         .replace("{TIMESTAMP}", &timestamp)
         .replace("{CREATOR_TOOL}", &escape_xml(creator))
-        .replace("{PDF_KEYWORDS}", &pdf_keywords);
+        .replace("{PRODUCER}", &escape_xml(config.producer_name()))
+        .replace("{PDF_KEYWORDS}", &pdf_keywords)
+        .replace("{CUSTOM_PROPERTIES}", &custom_properties);
 
     xmp.into_bytes()
 }
@@ -364,6 +464,18 @@ mod tests {
         assert!(!result.contains("<script>"));
     }
 
+    #[test]
+    fn test_xmp_keyword_bag_splits_keywords() {
+        let result = xmp_keyword_bag(Some("Rust, TypeScript;  R&D ,"));
+        assert!(result.contains("<rdf:li>Rust</rdf:li>"));
+        assert!(result.contains("<rdf:li>TypeScript</rdf:li>"));
+        assert!(result.contains("<rdf:li>R&amp;D</rdf:li>"));
+        assert_eq!(result.matches("<rdf:li>").count(), 3);
+
+        assert_eq!(xmp_keyword_bag(None), "");
+        assert_eq!(xmp_keyword_bag(Some(" , ")), "");
+    }
+
     #[test]
     fn test_generate_xmp_metadata() {
         let config = PDFConfig {
@@ -388,6 +500,10 @@ mod tests {
         assert!(xmp_str.contains("Software Engineer"));
         assert!(xmp_str.contains("software,engineering"));
         assert!(xmp_str.contains("ResumeWright"));
+
+        // Producer matches the Info dictionary value
+        let producer = format!("<pdf:Producer>{}</pdf:Producer>", config.producer_name());
+        assert!(xmp_str.contains(&producer));
     }
 
     #[test]
@@ -409,6 +525,24 @@ mod tests {
         assert!(xmp_str.contains("xmlns:pdf="));
     }
 
+    #[test]
+    fn test_xmp_mirrors_custom_info_entries() {
+        let config = PDFConfig {
+            custom_info: vec![("CandidateID".to_string(), "C-1042 & co".to_string())],
+            ..Default::default()
+        };
+        let xmp = generate_xmp_metadata(&config);
+        let xmp_str = String::from_utf8_lossy(&xmp);
+
+        assert!(xmp_str.contains("<pdfx:CandidateID>C-1042 &amp; co</pdfx:CandidateID>"));
+        assert!(xmp_str.contains("<pdfaProperty:name>CandidateID</pdfaProperty:name>"));
+        assert!(xmp_str.contains("<pdfaSchema:prefix>pdfx</pdfaSchema:prefix>"));
+
+        // No extension schema without custom entries
+        let xmp = generate_xmp_metadata(&PDFConfig::default());
+        assert!(!String::from_utf8_lossy(&xmp).contains("pdfaExtension"));
+    }
+
     #[test]
     fn test_xmp_metadata_with_minimal_config() {
         let config = PDFConfig {
@@ -0,0 +1,247 @@
+//! Best-effort output size targeting
+//!
+//! When [`PDFConfig::target_max_bytes`] is set and the generated PDF is larger,
+//! size reductions are switched on one at a time, cheapest first, and the
+//! document is regenerated until it fits or no reduction is left. Images are
+//! not embedded by the generator, so there is no image downsampling step.
+
+use crate::config::PDFConfig;
+use font_toolkit::strip_optional_tables;
+use lopdf::{Document, Object, ObjectId};
+use serde::{Deserialize, Serialize};
+
+/// A size reduction applied to meet the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SizeReduction {
+    /// DEFLATE-compress content streams (`compress_content_streams`)
+    CompressStreams,
+    /// Strip font tables PDF rendering never reads (`strip_optional_font_tables`)
+    StripFontTables,
+}
+
+impl SizeReduction {
+    /// All reductions, in the order they are tried
+    pub const ALL: [SizeReduction; 2] = [Self::CompressStreams, Self::StripFontTables];
+
+    /// Enable this reduction in `config`
+    ///
+    /// Returns `false` if it was already enabled, so regenerating would not
+    /// change the output.
+    pub fn apply(self, config: &mut PDFConfig) -> bool {
+        let flag = match self {
+            Self::CompressStreams => &mut config.compress_content_streams,
+            Self::StripFontTables => &mut config.strip_optional_font_tables,
+        };
+        !std::mem::replace(flag, true)
+    }
+}
+
+/// Outcome of generating against a size target
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeReport {
+    /// Requested maximum size in bytes
+    pub target_bytes: usize,
+    /// Size of the returned PDF in bytes
+    pub achieved_bytes: usize,
+    /// Reductions switched on to get there, in the order applied
+    pub reductions: Vec<SizeReduction>,
+    /// Whether the PDF fits the target
+    pub met: bool,
+}
+
+/// Generate a PDF, applying size reductions until it fits `target_max_bytes`
+///
+/// `generate` builds the complete PDF for a config and is called once, plus
+/// once per applied reduction. Missing the target is not an error: the
+/// smallest PDF is returned with `met: false` in its report. Without a
+/// target the report is `None`.
+///
+/// # Examples
+///
+/// ```
+/// use pdf_generator::{generate_within_target, PDFConfig, PDFGenerator};
+///
+/// let config = PDFConfig {
+///     target_max_bytes: Some(1_000_000),
+///     ..Default::default()
+/// };
+/// let (pdf, report) = generate_within_target(&config, |config| {
+///     PDFGenerator::new(config.clone())?.finalize()
+/// })
+/// .unwrap();
+/// assert!(report.unwrap().met);
+/// assert!(pdf.starts_with(b"%PDF"));
+/// ```
+pub fn generate_within_target<E>(
+    config: &PDFConfig,
+    mut generate: impl FnMut(&PDFConfig) -> Result<Vec<u8>, E>,
+) -> Result<(Vec<u8>, Option<SizeReport>), E> {
+    let mut bytes = generate(config)?;
+    let Some(target_bytes) = config.target_max_bytes else {
+        return Ok((bytes, None));
+    };
+
+    let mut config = config.clone();
+    let mut reductions = Vec::new();
+    for reduction in SizeReduction::ALL {
+        if bytes.len() <= target_bytes {
+            break;
+        }
+        if reduction.apply(&mut config) {
+            bytes = generate(&config)?;
+            reductions.push(reduction);
+        }
+    }
+
+    let report = SizeReport {
+        target_bytes,
+        achieved_bytes: bytes.len(),
+        reductions,
+        met: bytes.len() <= target_bytes,
+    };
+    Ok((bytes, Some(report)))
+}
+
+/// Rewrite every embedded TrueType font without its optional tables
+pub(crate) fn strip_embedded_font_tables(doc: &mut Document) {
+    let font_file_ids: Vec<ObjectId> = doc
+        .objects
+        .values()
+        .filter_map(|obj| obj.as_dict().ok())
+        .filter(|dict| dict.get(b"Type").and_then(Object::as_name).ok() == Some(b"FontDescriptor"))
+        .filter_map(|dict| dict.get(b"FontFile2").and_then(Object::as_reference).ok())
+        .collect();
+
+    for id in font_file_ids {
+        let Ok(Object::Stream(stream)) = doc.get_object_mut(id) else {
+            continue;
+        };
+        let Ok(font_bytes) = stream.decompressed_content() else {
+            continue;
+        };
+        let stripped = strip_optional_tables(&font_bytes);
+        if stripped.len() < font_bytes.len() {
+            stream.dict.set("Length1", stripped.len() as i64);
+            stream.dict.remove(b"Filter");
+            stream.set_content(stripped);
+            // Flate-compress the rewritten font whether or not the original was;
+            // lopdf keeps it raw when compression would not make it smaller
+            let _ = stream.compress();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reductions_apply_once() {
+        let mut config = PDFConfig::default();
+        assert!(SizeReduction::CompressStreams.apply(&mut config));
+        assert!(config.compress_content_streams);
+        assert!(!SizeReduction::CompressStreams.apply(&mut config));
+    }
+
+    #[test]
+    fn test_generate_within_target_stops_once_met() {
+        let config = PDFConfig {
+            target_max_bytes: Some(100),
+            ..Default::default()
+        };
+        let mut calls = 0;
+        let (bytes, report) = generate_within_target(&config, |config| {
+            calls += 1;
+            let size = if config.compress_content_streams {
+                80
+            } else {
+                150
+            };
+            Ok::<_, ()>(vec![0; size])
+        })
+        .unwrap();
+
+        assert_eq!(calls, 2);
+        assert_eq!(bytes.len(), 80);
+        assert_eq!(
+            report,
+            Some(SizeReport {
+                target_bytes: 100,
+                achieved_bytes: 80,
+                reductions: vec![SizeReduction::CompressStreams],
+                met: true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_unreachable_target_applies_compression_and_reports_miss() {
+        use crate::PDFGenerator;
+
+        let config = PDFConfig {
+            target_max_bytes: Some(1),
+            ..Default::default()
+        };
+        let (bytes, report) = generate_within_target(&config, |config| {
+            let mut generator = PDFGenerator::new(config.clone())?;
+            generator.add_text(&"Senior engineer. ".repeat(40), 72.0, 700.0, 10.0)?;
+            generator.finalize()
+        })
+        .unwrap();
+        let report = report.expect("a target was set");
+
+        assert_eq!(report.reductions, SizeReduction::ALL.to_vec());
+        assert_eq!(report.achieved_bytes, bytes.len());
+        assert_eq!(report.target_bytes, 1);
+        assert!(
+            !report.met,
+            "still succeeds when the target is out of reach"
+        );
+
+        let doc = Document::load_mem(&bytes).unwrap();
+        let page_id = doc.get_pages()[&1];
+        let content_id = doc
+            .get_dictionary(page_id)
+            .unwrap()
+            .get(b"Contents")
+            .and_then(Object::as_reference)
+            .unwrap();
+        let content = doc.get_object(content_id).unwrap().as_stream().unwrap();
+        assert!(content.dict.has(b"Filter"), "content stream is compressed");
+    }
+
+    #[test]
+    fn test_strip_font_tables_shrinks_embedded_fonts() {
+        use crate::{PDFGenerator, PDFStandard};
+
+        let font_lengths = |strip: bool| -> Vec<i64> {
+            let config = PDFConfig {
+                standard: PDFStandard::PDFA1b,
+                strip_optional_font_tables: strip,
+                ..Default::default()
+            };
+            let mut generator = PDFGenerator::new(config).unwrap();
+            generator.add_text("Jane Doe", 72.0, 700.0, 10.0).unwrap();
+            let doc = Document::load_mem(&generator.finalize().unwrap()).unwrap();
+            doc.objects
+                .values()
+                .filter_map(|obj| obj.as_stream().ok())
+                .filter_map(|stream| stream.dict.get(b"Length1").and_then(Object::as_i64).ok())
+                .collect()
+        };
+
+        let full = font_lengths(false);
+        let stripped = font_lengths(true);
+        assert!(!full.is_empty(), "PDF/A embeds its fonts");
+        assert_eq!(full.len(), stripped.len());
+        assert!(stripped.iter().sum::<i64>() < full.iter().sum::<i64>());
+    }
+
+    #[test]
+    fn test_generate_without_target_has_no_report() {
+        let (_, report) =
+            generate_within_target(&PDFConfig::default(), |_| Ok::<_, ()>(vec![0; 10])).unwrap();
+        assert_eq!(report, None);
+    }
+}
@@ -20,10 +20,11 @@
 
 use crate::error::PDFError;
 use font_toolkit::embedding::embed_truetype_font;
-use font_toolkit::strip_hinting_tables;
 #[cfg(feature = "advanced-fonts")]
 use font_toolkit::subsetter::subset_font_core;
+use font_toolkit::{read_decoration_metrics, strip_hinting_tables, DecorationMetrics};
 use layout_types::{FontStyle, FontWeight};
+use std::collections::HashMap;
 
 /// Karla Regular
 const KARLA_REGULAR: &[u8] = include_bytes!("../fonts/Karla-Regular.ttf");
@@ -37,6 +38,24 @@ const KARLA_ITALIC: &[u8] = include_bytes!("../fonts/Karla-Italic.ttf");
 /// Karla Bold Italic
 const KARLA_BOLD_ITALIC: &[u8] = include_bytes!("../fonts/Karla-BoldItalic.ttf");
 
+/// Helvetica variants replaced by embedded Karla CIDFonts
+pub const EMBEDDED_BASE_FONTS: [&str; 4] = [
+    "Helvetica",
+    "Helvetica-Bold",
+    "Helvetica-Oblique",
+    "Helvetica-BoldOblique",
+];
+
+/// Underline and strikeout metrics of the Karla faces, keyed by the
+/// Helvetica variant each one replaces
+pub fn embedded_decoration_metrics() -> HashMap<String, DecorationMetrics> {
+    EMBEDDED_BASE_FONTS
+        .into_iter()
+        .zip([KARLA_REGULAR, KARLA_BOLD, KARLA_ITALIC, KARLA_BOLD_ITALIC])
+        .filter_map(|(name, bytes)| Some((name.to_string(), read_decoration_metrics(bytes)?)))
+        .collect()
+}
+
 /// Embeds a Standard 14 font replacement for PDF/A compliance.
 ///
 /// For PDF/A-1b, even the Standard 14 fonts must be embedded. This function
@@ -161,6 +180,16 @@ mod tests {
 
     const TEST_TEXT: &str = "Hello World";
 
+    #[test]
+    fn test_embedded_decoration_metrics_cover_helvetica_variants() {
+        let metrics = embedded_decoration_metrics();
+        assert_eq!(metrics.len(), 4);
+        assert_eq!(
+            metrics["Helvetica-Bold"],
+            read_decoration_metrics(KARLA_BOLD).unwrap()
+        );
+    }
+
     #[test]
     fn test_embed_standard_font_regular() {
         let mut doc = Document::with_version("1.4");
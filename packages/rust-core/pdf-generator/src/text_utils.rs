@@ -41,11 +41,27 @@ pub fn apply_text_transform<'a>(text: &'a str, style: &StyleDeclaration) -> Cow<
     }
 }
 
+/// Alignment of one line of a text block
+///
+/// The last line follows `text-align-last` when set; otherwise it follows
+/// `text-align`, except that the last line of justified text is flush left.
+pub fn line_text_align(style: &StyleDeclaration, is_last_line: bool) -> Option<TextAlign> {
+    match (
+        is_last_line,
+        style.text.text_align_last,
+        style.text.text_align,
+    ) {
+        (true, Some(align_last), _) => Some(align_last),
+        (true, None, Some(TextAlign::Justify)) => Some(TextAlign::Left),
+        (_, _, text_align) => text_align,
+    }
+}
+
 /// Calculate x offset for text alignment
 ///
 /// # Arguments
 ///
-/// * `style` - The style declaration containing text alignment
+/// * `text_align` - The alignment of the line
 /// * `available_width` - The available width for the text
 /// * `text_width` - The actual width of the text
 ///
@@ -53,15 +69,16 @@ pub fn apply_text_transform<'a>(text: &'a str, style: &StyleDeclaration) -> Cow<
 ///
 /// The x-offset to apply for proper alignment
 pub fn calculate_text_alignment_offset(
-    style: &StyleDeclaration,
+    text_align: Option<TextAlign>,
     available_width: f64,
     text_width: f64,
 ) -> f64 {
-    match style.text.text_align {
+    match text_align {
         Some(TextAlign::Center) => (available_width - text_width) / 2.0,
         Some(TextAlign::Right) => available_width - text_width,
         Some(TextAlign::Left) | None => 0.0,
-        Some(TextAlign::Justify) => 0.0, // Not implemented for MVP
+        // Justified lines start at the left edge and are stretched to the right one
+        Some(TextAlign::Justify) => 0.0,
     }
 }
 
@@ -127,7 +144,7 @@ mod tests {
         let mut style = StyleDeclaration::default();
         style.text.text_align = Some(TextAlign::Left);
 
-        let offset = calculate_text_alignment_offset(&style, 200.0, 100.0);
+        let offset = calculate_text_alignment_offset(style.text.text_align, 200.0, 100.0);
         assert_eq!(offset, 0.0);
     }
 
@@ -136,7 +153,7 @@ mod tests {
         let mut style = StyleDeclaration::default();
         style.text.text_align = Some(TextAlign::Center);
 
-        let offset = calculate_text_alignment_offset(&style, 200.0, 100.0);
+        let offset = calculate_text_alignment_offset(style.text.text_align, 200.0, 100.0);
         assert_eq!(offset, 50.0); // (200 - 100) / 2
     }
 
@@ -145,7 +162,7 @@ mod tests {
         let mut style = StyleDeclaration::default();
         style.text.text_align = Some(TextAlign::Right);
 
-        let offset = calculate_text_alignment_offset(&style, 300.0, 150.0);
+        let offset = calculate_text_alignment_offset(style.text.text_align, 300.0, 150.0);
         assert_eq!(offset, 150.0); // 300 - 150
     }
 
@@ -153,7 +170,7 @@ mod tests {
     fn test_calculate_text_alignment_offset_none() {
         let style = StyleDeclaration::default();
 
-        let offset = calculate_text_alignment_offset(&style, 200.0, 100.0);
+        let offset = calculate_text_alignment_offset(style.text.text_align, 200.0, 100.0);
         assert_eq!(offset, 0.0); // Defaults to left
     }
 
@@ -162,7 +179,23 @@ mod tests {
         let mut style = StyleDeclaration::default();
         style.text.text_align = Some(TextAlign::Justify);
 
-        let offset = calculate_text_alignment_offset(&style, 200.0, 100.0);
-        assert_eq!(offset, 0.0); // Justify not implemented, defaults to left
+        let offset = calculate_text_alignment_offset(style.text.text_align, 200.0, 100.0);
+        assert_eq!(offset, 0.0); // Justified lines start at the left edge
+    }
+
+    #[test]
+    fn test_line_text_align_last_line() {
+        let mut style = StyleDeclaration::default();
+        style.text.text_align = Some(TextAlign::Justify);
+        assert_eq!(line_text_align(&style, false), Some(TextAlign::Justify));
+        assert_eq!(line_text_align(&style, true), Some(TextAlign::Left));
+
+        style.text.text_align_last = Some(TextAlign::Center);
+        assert_eq!(line_text_align(&style, false), Some(TextAlign::Justify));
+        assert_eq!(line_text_align(&style, true), Some(TextAlign::Center));
+
+        style.text.text_align = Some(TextAlign::Right);
+        style.text.text_align_last = None;
+        assert_eq!(line_text_align(&style, true), Some(TextAlign::Right));
     }
 }
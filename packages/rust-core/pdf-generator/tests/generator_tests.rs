@@ -2,7 +2,8 @@
 //!
 //! Comprehensive tests for PDFGenerator.
 
-use pdf_generator::{Margin, PDFConfig, PDFGenerator, PDFStandard, PageSize};
+use pdf_generator::encoding::encode_win_ansi_hex;
+use pdf_generator::{FontQuality, Margin, PDFConfig, PDFGenerator, PDFStandard, PageSize};
 
 /// Helper function to encode text as hex for CIDFont Type 2 validation
 /// Matches the encoding used in layout_renderer.rs:encode_as_cidfont_hex()
@@ -173,6 +174,91 @@ fn test_set_font_bytes() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_missing_font_policy_outcomes() {
+    use layout_types::TextLine;
+    use pdf_generator::css_parser::{FontWeight, StyleDeclaration};
+    use pdf_generator::{
+        referenced_fonts, BoxContent, FontSubstitution, LayoutBox, LayoutStructure,
+        MissingFontPolicy, PDFError, Page,
+    };
+    use std::collections::HashMap;
+
+    // Roboto Bold is requested, but only the Lato regular face was supplied
+    let render = |policy: MissingFontPolicy| {
+        let mut style = StyleDeclaration::default();
+        style.text.font_family = Some("Roboto".to_string());
+        style.text.font_weight = Some(FontWeight::Bold);
+        let layout = LayoutStructure {
+            pages: vec![Page {
+                page_number: 1,
+                boxes: vec![LayoutBox {
+                    x: 72.0,
+                    y: 72.0,
+                    width: 300.0,
+                    height: 20.0,
+                    content: BoxContent::Text(vec![TextLine::from("Jane Doe")]),
+                    style,
+                    element_type: None,
+                    list_ordinal: None,
+                }],
+            }],
+            page_width: 612.0,
+            page_height: 792.0,
+        };
+
+        let mut generator = PDFGenerator::new(PDFConfig {
+            missing_font_policy: policy,
+            ..Default::default()
+        })
+        .unwrap();
+        generator.set_font_bytes(HashMap::from([(
+            "Lato:400:false".to_string(),
+            vec![0u8; 4],
+        )]));
+        generator.render_layout(&layout)?;
+        let substitutions = generator.font_substitutions().to_vec();
+        let fonts: Vec<String> = referenced_fonts(&generator.finalize()?)?
+            .into_iter()
+            .map(|font| font.base_font)
+            .collect();
+        Ok::<_, PDFError>((substitutions, fonts))
+    };
+    let substitution = |substitute: &str| FontSubstitution {
+        family: "Roboto".to_string(),
+        weight: 700,
+        italic: false,
+        substitute: substitute.to_string(),
+    };
+
+    // Standard 14: rendered in the matching Helvetica face
+    let (substitutions, fonts) = render(MissingFontPolicy::SubstituteStandard14).unwrap();
+    assert_eq!(substitutions, vec![substitution("Helvetica-Bold")]);
+    assert_eq!(fonts, vec!["Helvetica-Bold".to_string()]);
+
+    // Named web-safe fallback: rendered in its Standard 14 face
+    let (substitutions, fonts) = render(MissingFontPolicy::SubstituteNamedFallback(
+        "Georgia".to_string(),
+    ))
+    .unwrap();
+    assert_eq!(substitutions, vec![substitution("Times-Bold")]);
+    assert_eq!(fonts, vec!["Times-Bold".to_string()]);
+
+    // Named supplied fallback: reported as the font actually registered
+    let (substitutions, fonts) = render(MissingFontPolicy::SubstituteNamedFallback(
+        "Lato".to_string(),
+    ))
+    .unwrap();
+    assert_eq!(substitutions, vec![substitution("Helvetica-Bold")]);
+    assert_eq!(fonts, vec!["Helvetica-Bold".to_string()]);
+
+    // Error: the conversion fails naming the missing variant
+    match render(MissingFontPolicy::Error) {
+        Err(PDFError::FontError(message)) => assert!(message.contains("Roboto:700:false")),
+        other => panic!("expected a font error, got {:?}", other),
+    }
+}
+
 // ============================================================================
 // PDF Output Validation Tests
 // ============================================================================
@@ -197,6 +283,33 @@ fn test_finalize() {
     );
 }
 
+#[test]
+fn test_small_font_quality_compresses_content_streams() {
+    let generate = |font_quality: FontQuality| {
+        let config = PDFConfig {
+            font_quality,
+            ..Default::default()
+        };
+        let mut generator = PDFGenerator::new(config).unwrap();
+        for line in 0..40 {
+            let y = 740.0 - line as f64 * 16.0;
+            generator
+                .add_text("Led the platform team through a migration", 72.0, y, 12.0)
+                .unwrap();
+        }
+        generator.finalize().unwrap()
+    };
+
+    let balanced = generate(FontQuality::Balanced);
+    let small = generate(FontQuality::Small);
+    assert!(
+        small.len() < balanced.len(),
+        "{} < {}",
+        small.len(),
+        balanced.len()
+    );
+}
+
 #[test]
 fn test_finalize_returns_pdf_bytes() {
     let config = PDFConfig::default();
@@ -225,6 +338,43 @@ fn test_pdf_has_valid_header() {
     );
 }
 
+#[test]
+fn test_finalize_bytes_does_not_consume_generator() {
+    use lopdf::Document;
+
+    let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+    generator
+        .add_text("First Draft", 100.0, 700.0, 12.0)
+        .unwrap();
+
+    let first = generator.finalize_bytes().unwrap();
+
+    generator.add_page().unwrap();
+    generator
+        .add_text("Second Draft", 100.0, 700.0, 12.0)
+        .unwrap();
+
+    let second = generator.finalize_bytes().unwrap();
+
+    let first_doc = Document::load_mem(&first).expect("First snapshot should be a valid PDF");
+    let second_doc = Document::load_mem(&second).expect("Second snapshot should be a valid PDF");
+
+    assert_eq!(first_doc.get_pages().len(), 1);
+    assert_eq!(second_doc.get_pages().len(), 2);
+
+    let first_text = String::from_utf8_lossy(&first);
+    let second_text = String::from_utf8_lossy(&second);
+    assert!(!first_text.contains("Second Draft"));
+    assert!(
+        second_text.contains("Second Draft"),
+        "Content added between calls should appear in the second snapshot"
+    );
+
+    // The consuming finalize still works after snapshots
+    let final_bytes = generator.finalize().unwrap();
+    assert!(final_bytes.starts_with(b"%PDF"));
+}
+
 // ============================================================================
 // Metadata Tests
 // ============================================================================
@@ -262,7 +412,7 @@ fn test_pdf_metadata_included() {
         "PDF should contain creator"
     );
     assert!(
-        pdf_str.contains("lopdf + ResumeWright"),
+        pdf_str.contains(pdf_generator::config::DEFAULT_PRODUCER),
         "PDF should contain producer"
     );
     assert!(
@@ -311,6 +461,137 @@ fn test_pdf_includes_color_space() {
     );
 }
 
+#[test]
+fn test_add_text_encodes_smart_quotes_as_win_ansi() {
+    let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+    generator
+        .add_text(
+            "\u{201C}It\u{2019}s\u{201D} \u{2014} done",
+            100.0,
+            700.0,
+            12.0,
+        )
+        .unwrap();
+
+    let pdf_bytes = generator.finalize().unwrap();
+    let pdf_str = String::from_utf8_lossy(&pdf_bytes);
+
+    // “ = 0x93, ’ = 0x92, ” = 0x94, — = 0x97 as octal escapes
+    assert!(
+        pdf_str.contains("(\\223It\\222s\\224 \\227 done) Tj"),
+        "Smart punctuation should be written as WinAnsi bytes"
+    );
+}
+
+#[test]
+fn test_embed_source_attaches_tsx() {
+    use lopdf::Document;
+
+    let tsx = "const CV = () => <div><h1>Jane Doe</h1><p>Engineer</p></div>;\n".repeat(4);
+    let config = PDFConfig {
+        embed_source: true,
+        ..Default::default()
+    };
+    let mut generator = PDFGenerator::new(config).unwrap();
+    generator.set_source(&tsx);
+    generator.add_text("Jane Doe", 100.0, 700.0, 12.0).unwrap();
+    let pdf_bytes = generator.finalize().unwrap();
+
+    let doc = Document::load_mem(&pdf_bytes).expect("Should be a valid PDF");
+    let names = doc
+        .catalog()
+        .unwrap()
+        .get(b"Names")
+        .and_then(|obj| obj.as_dict())
+        .and_then(|names| names.get(b"EmbeddedFiles"))
+        .and_then(|obj| obj.as_dict())
+        .and_then(|tree| tree.get(b"Names"))
+        .and_then(|obj| obj.as_array())
+        .expect("catalog should have an EmbeddedFiles name tree");
+    assert_eq!(names[0].as_str().unwrap(), b"resume.tsx");
+
+    let filespec = doc
+        .get_dictionary(names[1].as_reference().unwrap())
+        .unwrap();
+    let stream_id = filespec
+        .get(b"EF")
+        .and_then(|obj| obj.as_dict())
+        .and_then(|ef| ef.get(b"F"))
+        .and_then(|obj| obj.as_reference())
+        .unwrap();
+    let stream = doc.get_object(stream_id).unwrap().as_stream().unwrap();
+
+    assert_eq!(
+        stream.dict.get(b"Type").unwrap().as_name().unwrap(),
+        b"EmbeddedFile"
+    );
+    assert_eq!(
+        stream.dict.get(b"Subtype").unwrap().as_name().unwrap(),
+        b"text/plain"
+    );
+    assert_eq!(stream.decompressed_content().unwrap(), tsx.as_bytes());
+}
+
+#[test]
+fn test_embed_source_rejected_for_pdfa1b() {
+    let config = PDFConfig {
+        embed_source: true,
+        standard: PDFStandard::PDFA1b,
+        ..Default::default()
+    };
+
+    let result = PDFGenerator::new(config);
+    assert!(matches!(
+        result,
+        Err(pdf_generator::PDFError::ConfigError(_))
+    ));
+}
+
+#[test]
+fn test_bleed_box_extends_beyond_trim_box() {
+    use lopdf::Document;
+
+    let config = PDFConfig {
+        bleed_box: Some(9.0),
+        ..Default::default()
+    };
+    let mut generator = PDFGenerator::new(config).unwrap();
+    generator.add_text("Test", 100.0, 700.0, 12.0).unwrap();
+    let pdf_bytes = generator.finalize().unwrap();
+
+    let doc = Document::load_mem(&pdf_bytes).expect("Should be a valid PDF");
+    let rect = |page: &lopdf::Dictionary, key: &[u8]| -> Vec<f32> {
+        page.get(key)
+            .and_then(|obj| obj.as_array())
+            .expect("page should have the box")
+            .iter()
+            .map(|v| v.as_float().unwrap())
+            .collect()
+    };
+
+    for page_id in doc.get_pages().values() {
+        let page = doc.get_dictionary(*page_id).unwrap();
+        let trim = rect(page, b"TrimBox");
+        let bleed = rect(page, b"BleedBox");
+
+        assert_eq!(trim[0] - bleed[0], 9.0, "left bleed");
+        assert_eq!(trim[1] - bleed[1], 9.0, "bottom bleed");
+        assert_eq!(bleed[2] - trim[2], 9.0, "right bleed");
+        assert_eq!(bleed[3] - trim[3], 9.0, "top bleed");
+    }
+}
+
+#[test]
+fn test_no_print_boxes_by_default() {
+    let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+    generator.add_text("Test", 100.0, 700.0, 12.0).unwrap();
+    let pdf_bytes = generator.finalize().unwrap();
+    let pdf_str = String::from_utf8_lossy(&pdf_bytes);
+
+    assert!(!pdf_str.contains("/TrimBox"));
+    assert!(!pdf_str.contains("/BleedBox"));
+}
+
 // ============================================================================
 // Comprehensive Integration Tests
 // ============================================================================
@@ -347,6 +628,7 @@ fn test_comprehensive_multi_page_layout() {
                 s
             },
             element_type: Some(layout_types::ElementType::Heading1),
+            list_ordinal: None,
         },
         LayoutBox {
             x: 72.0,
@@ -361,6 +643,7 @@ fn test_comprehensive_multi_page_layout() {
                 s
             },
             element_type: Some(layout_types::ElementType::Paragraph),
+            list_ordinal: None,
         },
     ];
     pages.push(Page {
@@ -382,6 +665,7 @@ fn test_comprehensive_multi_page_layout() {
             s
         },
         element_type: Some(layout_types::ElementType::Paragraph),
+        list_ordinal: None,
     }];
     pages.push(Page {
         page_number: 2,
@@ -401,6 +685,7 @@ fn test_comprehensive_multi_page_layout() {
             s
         },
         element_type: Some(layout_types::ElementType::Paragraph),
+        list_ordinal: None,
     }];
     pages.push(Page {
         page_number: 3,
@@ -445,10 +730,10 @@ fn test_comprehensive_multi_page_layout() {
         "PDF should include Courier font"
     );
 
-    // Verify content is present (text is hex-encoded in PDF as CIDFont Type 2)
-    let john_doe_hex = encode_text_as_hex("John Doe");
-    let continued_hex = encode_text_as_hex("Continued from page 1");
-    let final_hex = encode_text_as_hex("Final page content");
+    // Verify content is present (text is hex-encoded WinAnsi for the Type1 fonts)
+    let john_doe_hex = encode_win_ansi_hex("John Doe");
+    let continued_hex = encode_win_ansi_hex("Continued from page 1");
+    let final_hex = encode_win_ansi_hex("Final page content");
 
     assert!(
         pdf_str.contains(&john_doe_hex),
@@ -475,13 +760,23 @@ fn test_comprehensive_multi_page_layout() {
         name: Some("John Doe".to_string()),
         email: Some("john@example.com".to_string()),
         phone: Some("+1-555-0100".to_string()),
+        phone_e164: None,
         location: Some("San Francisco, CA".to_string()),
         title: Some("Software Engineer".to_string()),
         website: None,
+        skills: Vec::new(),
+        experience: Vec::new(),
+        certifications: Vec::new(),
         layout_type: cv_domain::LayoutType::SingleColumn,
         estimated_pages: 3,
         component_count: 3,
         has_contact_info: true,
+        contact_in_sidebar: false,
+        chronological_order: None,
+        duplicate_sections: Vec::new(),
+        word_count: 0,
+        char_count: 0,
+        reading_time_seconds: 0,
         font_complexity: cv_domain::FontComplexity::Simple,
         has_clear_sections: true,
     };
@@ -527,6 +822,7 @@ fn test_inline_bold_text_renders_to_pdf() {
             s
         },
         element_type: Some(ElementType::Paragraph),
+        list_ordinal: None,
     };
 
     let page = Page {
@@ -548,9 +844,9 @@ fn test_inline_bold_text_renders_to_pdf() {
     let pdf_string = String::from_utf8_lossy(&pdf_bytes);
 
     // The text should appear in the PDF content stream
-    // Look for hex-encoded version (CIDFont Type 2 encoding)
-    let native_hex = encode_text_as_hex("Native:");
-    let russian_hex = encode_text_as_hex("Russian");
+    // Look for hex-encoded version (WinAnsi bytes for the Type1 fonts)
+    let native_hex = encode_win_ansi_hex("Native:");
+    let russian_hex = encode_win_ansi_hex("Russian");
 
     assert!(
         pdf_string.contains(&native_hex) || pdf_string.contains("Native:"),
@@ -564,3 +860,238 @@ fn test_inline_bold_text_renders_to_pdf() {
         russian_hex
     );
 }
+
+#[test]
+fn test_layout_text_encoding_follows_font_type() {
+    use layout_types::{BoxContent, LayoutBox, LayoutStructure, Page, StyleDeclaration, TextLine};
+    use lopdf::Document;
+
+    let render = |config: PDFConfig| {
+        let text_box = LayoutBox {
+            x: 72.0,
+            y: 100.0,
+            width: 400.0,
+            height: 20.0,
+            content: BoxContent::Text(vec![TextLine::from("It\u{2019}s done")]),
+            style: StyleDeclaration::default(),
+            element_type: None,
+            list_ordinal: None,
+        };
+        let layout = LayoutStructure {
+            page_width: 612.0,
+            page_height: 792.0,
+            pages: vec![Page::new(1, vec![text_box])],
+        };
+        let mut generator = PDFGenerator::new(config).unwrap();
+        generator.render_layout(&layout).unwrap();
+        let doc = Document::load_mem(&generator.finalize().unwrap()).unwrap();
+        let page_id = *doc.get_pages().get(&1).unwrap();
+        String::from_utf8_lossy(&doc.get_page_content(page_id).unwrap()).into_owned()
+    };
+
+    // Type1 Helvetica declares WinAnsiEncoding: one byte per character
+    let content = render(PDFConfig::default());
+    assert!(content.contains("<4974927320646F6E65>"), "{}", content);
+
+    // Embedded Karla is a CIDFont: two bytes per character
+    let content = render(PDFConfig {
+        embed_base_fonts: true,
+        ..Default::default()
+    });
+    assert!(
+        content.contains(&format!("<{}>", encode_text_as_hex("It\u{2019}s done"))),
+        "{}",
+        content
+    );
+}
+
+/// Render a three-page layout with one line of text per page
+fn render_three_pages(config: PDFConfig) -> lopdf::Document {
+    use layout_types::TextLine;
+    use pdf_generator::css_parser::StyleDeclaration;
+    use pdf_generator::{BoxContent, LayoutBox, LayoutStructure, Page};
+
+    let pages = (1..=3)
+        .map(|page_number| Page {
+            page_number,
+            boxes: vec![LayoutBox {
+                x: 72.0,
+                y: 72.0,
+                width: 468.0,
+                height: 14.0,
+                content: BoxContent::Text(vec![TextLine::from(format!("Page {}", page_number))]),
+                style: StyleDeclaration::default(),
+                element_type: None,
+                list_ordinal: None,
+            }],
+        })
+        .collect();
+    let layout = LayoutStructure {
+        pages,
+        page_width: 612.0,
+        page_height: 792.0,
+    };
+
+    let mut generator = PDFGenerator::new(config).unwrap();
+    generator.render_layout(&layout).unwrap();
+    let mut doc = lopdf::Document::load_mem(&generator.finalize().unwrap()).unwrap();
+    doc.decompress();
+    doc
+}
+
+#[test]
+fn test_watermark_is_one_form_xobject_shared_by_all_pages() {
+    use lopdf::Object;
+    use pdf_generator::Watermark;
+
+    let doc = render_three_pages(PDFConfig {
+        watermark: Some(Watermark::new("DRAFT")),
+        ..Default::default()
+    });
+    // Default config: the form's Type1 Helvetica takes WinAnsi bytes
+    let watermark_hex = encode_win_ansi_hex("DRAFT");
+
+    let forms: Vec<_> = doc
+        .objects
+        .iter()
+        .filter_map(|(id, obj)| Some((*id, obj.as_stream().ok()?)))
+        .filter(|(_, stream)| {
+            stream.dict.get(b"Subtype").and_then(Object::as_name).ok() == Some(b"Form")
+        })
+        .collect();
+    assert_eq!(forms.len(), 1, "watermark written once");
+    let (form_id, form) = forms[0];
+    assert!(String::from_utf8_lossy(&form.content).contains(&watermark_hex));
+
+    let pages = doc.get_pages();
+    assert_eq!(pages.len(), 3);
+    for page_id in pages.values() {
+        let xobjects = doc
+            .get_dictionary(*page_id)
+            .and_then(|page| page.get(b"Resources"))
+            .and_then(Object::as_dict)
+            .and_then(|resources| resources.get(b"XObject"))
+            .and_then(Object::as_dict)
+            .expect("page lists the form");
+        assert_eq!(
+            xobjects.get(b"Fm1").unwrap().as_reference().unwrap(),
+            form_id
+        );
+
+        let content = String::from_utf8_lossy(&doc.get_page_content(*page_id).unwrap()).to_string();
+        assert!(content.starts_with("q\n/Fm1 Do\nQ\n"), "{}", content);
+        assert!(
+            !content.contains(&watermark_hex),
+            "not inlined: {}",
+            content
+        );
+    }
+}
+
+#[test]
+fn test_watermark_form_fonts_embedded_for_pdfa() {
+    use lopdf::Object;
+    use pdf_generator::Watermark;
+
+    let doc = render_three_pages(PDFConfig {
+        standard: PDFStandard::PDFA1b,
+        watermark: Some(Watermark::new("DRAFT")),
+        ..Default::default()
+    });
+
+    let form = doc
+        .objects
+        .values()
+        .filter_map(|obj| obj.as_stream().ok())
+        .find(|stream| stream.dict.get(b"Subtype").and_then(Object::as_name).ok() == Some(b"Form"))
+        .expect("watermark form");
+    let font_id = form
+        .dict
+        .get(b"Resources")
+        .and_then(Object::as_dict)
+        .and_then(|resources| resources.get(b"Font"))
+        .and_then(Object::as_dict)
+        .and_then(|fonts| fonts.get(b"Helvetica"))
+        .and_then(Object::as_reference)
+        .unwrap();
+    let font = doc.get_dictionary(font_id).unwrap();
+    assert_eq!(
+        font.get(b"Subtype").and_then(Object::as_name).unwrap(),
+        b"Type0",
+        "form uses the embedded base font"
+    );
+    assert!(String::from_utf8_lossy(&form.content).contains(&encode_text_as_hex("DRAFT")));
+}
+
+#[test]
+fn test_underline_placed_from_embedded_font_metrics() {
+    use layout_types::{TextDecoration, TextLine};
+    use lopdf::content::Content;
+    use pdf_generator::css_parser::StyleDeclaration;
+    use pdf_generator::{BoxContent, LayoutBox, LayoutStructure, Page};
+
+    // PDF/A and embed_base_fonts embed Karla Regular for Helvetica
+    let karla = include_bytes!("../fonts/Karla-Regular.ttf");
+    let metrics = font_toolkit::read_decoration_metrics(karla).unwrap();
+
+    let mut style = StyleDeclaration::default();
+    style.text.font_size = Some(20.0);
+    style.text.text_decoration = Some(TextDecoration::Underline);
+    let layout = LayoutStructure {
+        pages: vec![Page {
+            page_number: 1,
+            boxes: vec![LayoutBox {
+                x: 72.0,
+                y: 72.0,
+                width: 468.0,
+                height: 24.0,
+                content: BoxContent::Text(vec![TextLine::from("Jane Doe")]),
+                style,
+                element_type: None,
+                list_ordinal: None,
+            }],
+        }],
+        page_width: 612.0,
+        page_height: 792.0,
+    };
+
+    assert!(metrics.underline_position < 0.0);
+    for config in [
+        PDFConfig {
+            standard: PDFStandard::PDFA1b,
+            ..Default::default()
+        },
+        PDFConfig {
+            embed_base_fonts: true,
+            ..Default::default()
+        },
+    ] {
+        let mut generator = PDFGenerator::new(config).unwrap();
+        generator.render_layout(&layout).unwrap();
+        let mut doc = lopdf::Document::load_mem(&generator.finalize().unwrap()).unwrap();
+        doc.decompress();
+        let page_id = doc.page_iter().next().unwrap();
+        let content = Content::decode(&doc.get_page_content(page_id).unwrap()).unwrap();
+
+        let operand_y = |operator: &str| {
+            let op = content
+                .operations
+                .iter()
+                .find(|op| op.operator == operator)
+                .unwrap_or_else(|| panic!("no {} operator", operator));
+            op.operands[1].as_float().unwrap() as f64
+        };
+        let baseline_y = operand_y("Td");
+        let underline_y = operand_y("m");
+
+        // The stroke is centered half its thickness below underlinePosition
+        let expected_offset =
+            (metrics.underline_position - metrics.underline_thickness / 2.0) * 20.0;
+        assert!(
+            (underline_y - baseline_y - expected_offset).abs() < 1e-3,
+            "underline at {}, baseline at {}",
+            underline_y,
+            baseline_y
+        );
+    }
+}
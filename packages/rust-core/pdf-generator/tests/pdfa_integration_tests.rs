@@ -299,3 +299,53 @@ fn test_pdfa1b_metadata_sync() {
     assert!(pdf_str.contains("<dc:description>"));
     assert!(pdf_str.contains("<dc:subject>"));
 }
+
+/// Test that subject and keywords in XMP agree with the Info dictionary
+///
+/// Strict PDF/A validators compare Info `/Subject` with `dc:description` and
+/// Info `/Keywords` with `pdf:Keywords`.
+#[test]
+fn test_pdfa1b_subject_keywords_match_info() {
+    let config = PDFConfig {
+        standard: PDFStandard::PDFA1b,
+        title: Some("Keyword Test".to_string()),
+        subject: Some("Senior Backend Engineer".to_string()),
+        keywords: Some("Rust, PostgreSQL, Kubernetes".to_string()),
+        ..Default::default()
+    };
+
+    let mut gen = PDFGenerator::new(config).unwrap();
+    gen.add_text("Test", 100.0, 700.0, 12.0).unwrap();
+    let pdf_bytes = gen.finalize().unwrap();
+
+    let doc = lopdf::Document::load_mem(&pdf_bytes).unwrap();
+    let info_id = doc.trailer.get(b"Info").unwrap().as_reference().unwrap();
+    let info = doc.get_dictionary(info_id).unwrap();
+    let info_subject =
+        String::from_utf8(info.get(b"Subject").unwrap().as_str().unwrap().to_vec()).unwrap();
+    let info_keywords =
+        String::from_utf8(info.get(b"Keywords").unwrap().as_str().unwrap().to_vec()).unwrap();
+
+    let metadata_id = doc
+        .catalog()
+        .unwrap()
+        .get(b"Metadata")
+        .unwrap()
+        .as_reference()
+        .unwrap();
+    let stream = doc.get_object(metadata_id).unwrap().as_stream().unwrap();
+    let xmp = String::from_utf8(stream.content.clone()).unwrap();
+
+    assert!(xmp.contains(&format!(
+        "<rdf:li xml:lang=\"x-default\">{}</rdf:li>",
+        info_subject
+    )));
+    assert!(xmp.contains(&format!("<pdf:Keywords>{}</pdf:Keywords>", info_keywords)));
+    for keyword in info_keywords.split(',') {
+        assert!(
+            xmp.contains(&format!("<rdf:li>{}</rdf:li>", keyword.trim())),
+            "dc:subject should list '{}'",
+            keyword.trim()
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Contact sheet rendering: all pages of a PDF tiled into a single PNG.
+//!
+//! Each page is rendered with the usual [`RenderConfig`], so `scale` and
+//! `max_width`/`max_height` control the thumbnail size. Thumbnails are laid
+//! out left to right in a fixed number of columns, each with its page number
+//! drawn underneath.
+
+use crate::pdf_renderer::{render_all_pages, PDFRenderError, RenderConfig};
+use image::{imageops, ImageBuffer, Rgba, RgbaImage};
+use std::path::Path;
+
+/// Space between thumbnails and around the sheet edge, in pixels
+const GUTTER: u32 = 16;
+
+/// Sheet background (light gray so white pages stand out)
+const BACKGROUND: Rgba<u8> = Rgba([224, 224, 224, 255]);
+
+/// Page label color
+const LABEL_COLOR: Rgba<u8> = Rgba([64, 64, 64, 255]);
+
+/// Digit glyphs as 3×5 bitmaps, one row per entry (bit 2 = left column)
+const DIGITS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b010, 0b010, 0b010],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+/// Digit glyph width and height in bitmap cells
+const DIGIT_WIDTH: u32 = 3;
+const DIGIT_HEIGHT: u32 = 5;
+
+/// Result of rendering a contact sheet
+#[derive(Debug, Clone)]
+pub struct ContactSheet {
+    /// Path of the written PNG
+    pub path: String,
+    /// Number of PDF pages on the sheet
+    pub page_count: usize,
+}
+
+/// Renders every page of a PDF and tiles them into one PNG grid.
+///
+/// # Arguments
+///
+/// * `pdf_path` - Path to the PDF file to render
+/// * `output_path` - Path of the PNG to write (parent directories are created)
+/// * `columns` - Number of thumbnails per row (at least 1)
+/// * `config` - Rendering configuration applied to each thumbnail
+///
+/// # Errors
+///
+/// Returns `PDFRenderError` if the PDF cannot be rendered, has no pages, or
+/// the sheet cannot be written
+///
+/// # Example
+///
+/// ```no_run
+/// use pdf_test_utils::{pdf_to_contact_sheet, RenderConfig};
+///
+/// let config = RenderConfig { scale: 0.5, ..RenderConfig::default() };
+/// let sheet = pdf_to_contact_sheet("cv.pdf", "./output/cv-contact-sheet.png", 2, config)?;
+/// println!("{} pages -> {}", sheet.page_count, sheet.path);
+/// # Ok::<(), pdf_test_utils::PDFRenderError>(())
+/// ```
+pub fn pdf_to_contact_sheet<P: AsRef<Path>, Q: AsRef<Path>>(
+    pdf_path: P,
+    output_path: Q,
+    columns: u32,
+    config: RenderConfig,
+) -> Result<ContactSheet, PDFRenderError> {
+    let pages = render_all_pages(pdf_path, &config)?;
+    if pages.is_empty() {
+        return Err(PDFRenderError::EmptyDocument);
+    }
+
+    let sheet = compose_contact_sheet(&pages, columns);
+
+    let output_path = output_path.as_ref();
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| PDFRenderError::CreateDirError(e.to_string()))?;
+    }
+    sheet
+        .save(output_path)
+        .map_err(|e| PDFRenderError::ContactSheetSaveError(e.to_string()))?;
+
+    Ok(ContactSheet {
+        path: output_path
+            .to_str()
+            .ok_or(PDFRenderError::InvalidPath)?
+            .to_string(),
+        page_count: pages.len(),
+    })
+}
+
+/// Tiles page images into a grid with a page-number label under each cell.
+///
+/// Cells are sized to the largest page so mixed page sizes stay aligned.
+fn compose_contact_sheet(pages: &[RgbaImage], columns: u32) -> RgbaImage {
+    let columns = columns.clamp(1, pages.len().max(1) as u32);
+    let rows = (pages.len() as u32).div_ceil(columns);
+
+    let cell_width = pages.iter().map(|p| p.width()).max().unwrap_or(0);
+    let cell_height = pages.iter().map(|p| p.height()).max().unwrap_or(0);
+
+    // Scale labels with thumbnail size so they stay legible on large renders
+    let label_scale = (cell_width / 150).max(1);
+    let label_height = DIGIT_HEIGHT * label_scale + GUTTER / 2;
+
+    let row_height = cell_height + label_height;
+    let sheet_width = columns * cell_width + (columns + 1) * GUTTER;
+    let sheet_height = rows * row_height + (rows + 1) * GUTTER;
+
+    let mut sheet = ImageBuffer::from_pixel(sheet_width, sheet_height, BACKGROUND);
+
+    for (index, page) in pages.iter().enumerate() {
+        let column = index as u32 % columns;
+        let row = index as u32 / columns;
+        let cell_x = GUTTER + column * (cell_width + GUTTER);
+        let cell_y = GUTTER + row * (row_height + GUTTER);
+
+        let x = cell_x + (cell_width - page.width()) / 2;
+        imageops::overlay(&mut sheet, page, x as i64, cell_y as i64);
+
+        let label = (index + 1).to_string();
+        let label_width = label_pixel_width(&label, label_scale);
+        let label_x = cell_x + cell_width.saturating_sub(label_width) / 2;
+        let label_y = cell_y + cell_height + GUTTER / 4;
+        draw_label(&mut sheet, &label, label_x, label_y, label_scale);
+    }
+
+    sheet
+}
+
+/// Width in pixels of a digit string drawn with [`draw_label`]
+fn label_pixel_width(label: &str, scale: u32) -> u32 {
+    let digits = label.len() as u32;
+    (digits * (DIGIT_WIDTH + 1)).saturating_sub(1) * scale
+}
+
+/// Draws a digit string with its top-left corner at (`x`, `y`).
+///
+/// Non-digit characters are skipped. Pixels outside the image are clipped.
+fn draw_label(image: &mut RgbaImage, label: &str, x: u32, y: u32, scale: u32) {
+    for (position, digit) in label.chars().filter_map(|c| c.to_digit(10)).enumerate() {
+        let glyph_x = x + position as u32 * (DIGIT_WIDTH + 1) * scale;
+
+        for (row, bits) in DIGITS[digit as usize].iter().enumerate() {
+            for column in 0..DIGIT_WIDTH {
+                if bits & (1 << (DIGIT_WIDTH - 1 - column)) == 0 {
+                    continue;
+                }
+                let px = glyph_x + column * scale;
+                let py = y + row as u32 * scale;
+                for dy in 0..scale {
+                    for dx in 0..scale {
+                        if px + dx < image.width() && py + dy < image.height() {
+                            image.put_pixel(px + dx, py + dy, LABEL_COLOR);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(width: u32, height: u32) -> RgbaImage {
+        ImageBuffer::from_pixel(width, height, Rgba([255, 255, 255, 255]))
+    }
+
+    #[test]
+    fn test_compose_contact_sheet_two_column_grid() {
+        let pages = vec![page(100, 140), page(100, 140), page(100, 140)];
+        let sheet = compose_contact_sheet(&pages, 2);
+
+        let label_height = DIGIT_HEIGHT + GUTTER / 2;
+        assert_eq!(sheet.width(), 2 * 100 + 3 * GUTTER);
+        assert_eq!(sheet.height(), 2 * (140 + label_height) + 3 * GUTTER);
+
+        // Third page starts the second row in the first column
+        let second_row_y = GUTTER + 140 + label_height + GUTTER;
+        assert_eq!(
+            *sheet.get_pixel(GUTTER, second_row_y),
+            Rgba([255, 255, 255, 255])
+        );
+        // Second row, second column is empty background
+        assert_eq!(*sheet.get_pixel(2 * GUTTER + 100, second_row_y), BACKGROUND);
+    }
+
+    #[test]
+    fn test_draw_label_marks_pixels() {
+        let mut image = ImageBuffer::from_pixel(20, 10, BACKGROUND);
+        draw_label(&mut image, "1", 0, 0, 1);
+
+        // "1" has its stem in the middle column
+        assert_eq!(*image.get_pixel(1, 0), LABEL_COLOR);
+        assert_eq!(*image.get_pixel(0, 0), BACKGROUND);
+        assert_eq!(label_pixel_width("12", 2), 14);
+    }
+}
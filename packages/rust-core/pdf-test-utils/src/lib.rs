@@ -19,6 +19,7 @@
 //! - **Configurable output** - Control scale, dimensions, and quality
 //! - **Batch processing** - Render all pages or individual pages
 //! - **In-memory processing** - Get raw image buffers without saving to disk
+//! - **Contact sheets** - Tile all pages of a PDF into one PNG for quick review
 //!
 //! # System Requirements
 //!
@@ -95,6 +96,8 @@
 //! - `RenderError` - Failed to render page to bitmap
 //! - `SaveError` - Failed to save PNG file
 //! - `CreateDirError` - Failed to create output directory
+//! - `EmptyDocument` - PDF has no pages (contact sheets)
+//! - `ContactSheetSaveError` - Failed to save the contact sheet PNG
 //!
 //! # Performance Characteristics
 //!
@@ -105,6 +108,8 @@
 //! Rendering is significantly faster than JavaScript-based PDF rendering,
 //! making it ideal for CI/CD pipelines and automated testing.
 
+mod contact_sheet;
 mod pdf_renderer;
 
+pub use contact_sheet::{pdf_to_contact_sheet, ContactSheet};
 pub use pdf_renderer::{pdf_page_to_image, pdf_to_pngs, PDFRenderError, RenderConfig};
@@ -7,7 +7,7 @@
 //! **Note:** This module is intended for testing environments only and requires
 //! the pdfium shared library (`libpdfium.so` on Linux) to be available at runtime.
 
-use image::{ImageBuffer, Rgba};
+use image::{ImageBuffer, Rgba, RgbaImage};
 use pdfium_render::prelude::*;
 use std::path::Path;
 use thiserror::Error;
@@ -80,6 +80,47 @@ fn create_render_config(page: &PdfPage, config: &RenderConfig) -> PdfRenderConfi
     render_config
 }
 
+/// Renders a single page to an RGBA image buffer.
+///
+/// `page_num` is only used for error reporting.
+fn render_page(
+    page: &PdfPage,
+    config: &RenderConfig,
+    page_num: usize,
+) -> Result<ImageBuffer<Rgba<u8>, Vec<u8>>, PDFRenderError> {
+    let render_config = create_render_config(page, config);
+
+    let bitmap =
+        page.render_with_config(&render_config)
+            .map_err(|e| PDFRenderError::RenderError {
+                page: page_num,
+                error: format!("{:?}", e),
+            })?;
+
+    Ok(bitmap.as_image().to_rgba8())
+}
+
+/// Renders every page of a PDF to in-memory RGBA image buffers, in page order.
+///
+/// # Errors
+///
+/// Returns `PDFRenderError` if Pdfium is unavailable or the PDF cannot be
+/// loaded or rendered
+pub(crate) fn render_all_pages<P: AsRef<Path>>(
+    pdf_path: P,
+    config: &RenderConfig,
+) -> Result<Vec<RgbaImage>, PDFRenderError> {
+    let pdfium = initialize_pdfium()?;
+    let document = load_pdf_document(&pdfium, pdf_path.as_ref())?;
+
+    document
+        .pages()
+        .iter()
+        .enumerate()
+        .map(|(index, page)| render_page(&page, config, index + 1))
+        .collect()
+}
+
 /// Errors that can occur during PDF rendering
 #[derive(Debug, Error)]
 pub enum PDFRenderError {
@@ -106,6 +147,12 @@ pub enum PDFRenderError {
 
     #[error("Failed to get page {0}")]
     PageAccessError(usize),
+
+    #[error("PDF has no pages to render")]
+    EmptyDocument,
+
+    #[error("Failed to save contact sheet: {0}")]
+    ContactSheetSaveError(String),
 }
 
 /// Configuration for PDF rendering
@@ -186,20 +233,8 @@ pub fn pdf_to_pngs<P: AsRef<Path>>(
     for (index, page) in document.pages().iter().enumerate() {
         let page_num = index + 1;
 
-        // Configure rendering options
-        let render_config = create_render_config(&page, &config);
-
-        // Render page to bitmap
-        let bitmap =
-            page.render_with_config(&render_config)
-                .map_err(|e| PDFRenderError::RenderError {
-                    page: page_num,
-                    error: format!("{:?}", e),
-                })?;
-
-        // Convert to image::DynamicImage and save as PNG
-        let dynamic_image = bitmap.as_image();
-        let rgb_image = dynamic_image.to_rgba8();
+        // Render page and save as PNG
+        let rgb_image = render_page(&page, &config, page_num)?;
 
         let png_path = output_dir
             .as_ref()
@@ -252,19 +287,7 @@ pub fn pdf_page_to_image<P: AsRef<Path>>(
         .get(page_index as u16)
         .map_err(|_| PDFRenderError::PageAccessError(page_index))?;
 
-    // Configure rendering
-    let render_config = create_render_config(&page, &config);
-
-    // Render and convert to image buffer
-    let bitmap =
-        page.render_with_config(&render_config)
-            .map_err(|e| PDFRenderError::RenderError {
-                page: page_index,
-                error: format!("{:?}", e),
-            })?;
-
-    let dynamic_image = bitmap.as_image();
-    Ok(dynamic_image.to_rgba8())
+    render_page(&page, &config, page_index)
 }
 
 #[cfg(test)]
@@ -22,6 +22,11 @@
 //! pdf-to-png --input input.pdf --output-dir ./output --basename page --scale 2.0
 //! ```
 //!
+//! With `--contact-sheet`, all pages are tiled into a single two-column PNG
+//! (`<basename>-contact-sheet.png`) labelled with page numbers. `--scale` and
+//! `--max-width`/`--max-height` then set the thumbnail size, and `pngPaths`
+//! holds just that one path.
+//!
 //! # Output
 //!
 //! Outputs JSON to stdout with the paths to generated PNG files:
@@ -42,12 +47,15 @@
 //! ```
 
 use clap::Parser;
-use pdf_test_utils::{pdf_to_pngs, RenderConfig};
+use pdf_test_utils::{pdf_to_contact_sheet, pdf_to_pngs, RenderConfig};
 use serde_json::json;
 use std::error::Error;
 use std::path::PathBuf;
 use std::process;
 
+/// Thumbnails per row on a contact sheet
+const CONTACT_SHEET_COLUMNS: u32 = 2;
+
 #[derive(Parser, Debug)]
 #[command(name = "pdf-to-png")]
 #[command(version)]
@@ -79,6 +87,10 @@ struct Args {
     /// Valid range: 100 to 10000
     #[arg(long)]
     max_height: Option<u32>,
+
+    /// Tile all pages into one PNG grid instead of one PNG per page
+    #[arg(long)]
+    contact_sheet: bool,
 }
 
 fn main() {
@@ -137,14 +149,25 @@ fn run(args: Args) -> Result<(), Box<dyn Error>> {
     };
 
     // Convert PDF to PNGs
-    let png_paths = pdf_to_pngs(&args.input, &args.output_dir, &args.basename, config)
-        .map_err(|e| format!("PDF conversion failed: {}", e))?;
+    let (png_paths, page_count) = if args.contact_sheet {
+        let sheet_path = args
+            .output_dir
+            .join(format!("{}-contact-sheet.png", args.basename));
+        let sheet = pdf_to_contact_sheet(&args.input, &sheet_path, CONTACT_SHEET_COLUMNS, config)
+            .map_err(|e| format!("PDF conversion failed: {}", e))?;
+        (vec![sheet.path], sheet.page_count)
+    } else {
+        let png_paths = pdf_to_pngs(&args.input, &args.output_dir, &args.basename, config)
+            .map_err(|e| format!("PDF conversion failed: {}", e))?;
+        let page_count = png_paths.len();
+        (png_paths, page_count)
+    };
 
     // Output success JSON
     let output = json!({
         "success": true,
         "pngPaths": png_paths,
-        "pageCount": png_paths.len(),
+        "pageCount": page_count,
     });
     println!("{}", output);
 
@@ -176,6 +199,7 @@ mod tests {
             scale: 0.0, // Invalid - too small
             max_width: None,
             max_height: None,
+            contact_sheet: false,
         };
 
         let result = run(args);
@@ -195,6 +219,7 @@ mod tests {
             scale: 15.0, // Invalid - too large
             max_width: None,
             max_height: None,
+            contact_sheet: false,
         };
 
         let result = run(args);
@@ -214,6 +239,7 @@ mod tests {
             scale: 2.0,
             max_width: None,
             max_height: None,
+            contact_sheet: false,
         };
 
         let result = run(args);
@@ -233,6 +259,7 @@ mod tests {
             scale: 2.0,
             max_width: Some(50), // Too small
             max_height: None,
+            contact_sheet: false,
         };
 
         let result = run(args);
@@ -252,6 +279,7 @@ mod tests {
             scale: 2.0,
             max_width: Some(20000), // Too large
             max_height: None,
+            contact_sheet: false,
         };
 
         let result = run(args);
@@ -271,6 +299,7 @@ mod tests {
             scale: 2.0,
             max_width: None,
             max_height: Some(50), // Too small
+            contact_sheet: false,
         };
 
         let result = run(args);
@@ -290,6 +319,7 @@ mod tests {
             scale: 2.0,
             max_width: None,
             max_height: Some(20000), // Too large
+            contact_sheet: false,
         };
 
         let result = run(args);
@@ -309,6 +339,7 @@ mod tests {
             scale: 2.0,
             max_width: Some(1000),
             max_height: Some(1000),
+            contact_sheet: false,
         };
 
         // This will fail at PDF conversion (Cargo.toml is not a PDF),
@@ -0,0 +1,78 @@
+//! Contact sheet CLI tests
+//!
+//! NOTE: Tests render PDFs and require the pdfium shared library.
+//! Run with: cargo test --package pdf-to-png --features pdfium
+
+#![cfg(feature = "pdfium")]
+
+use pdf_generator::{PDFConfig, PDFGenerator};
+use serde_json::Value;
+
+/// US Letter page size in points
+const PAGE_WIDTH_PT: f64 = 612.0;
+const PAGE_HEIGHT_PT: f64 = 792.0;
+
+/// Render scale passed to the CLI
+const SCALE: f64 = 0.5;
+
+fn three_page_pdf() -> Vec<u8> {
+    let mut generator = PDFGenerator::new(PDFConfig::default()).unwrap();
+    for page in 1..=3 {
+        if page > 1 {
+            generator.insert_page_break().unwrap();
+        }
+        let y = generator.cursor_y();
+        generator
+            .add_text(&format!("Page {}", page), 72.0, y, 24.0)
+            .unwrap();
+    }
+    generator.finalize().unwrap()
+}
+
+#[test]
+fn test_contact_sheet_outputs_single_two_column_png() {
+    let dir = tempfile::tempdir().unwrap();
+    let pdf_path = dir.path().join("cv.pdf");
+    std::fs::write(&pdf_path, three_page_pdf()).unwrap();
+    let output_dir = dir.path().join("out");
+
+    let output = assert_cmd::cargo::cargo_bin_cmd!("pdf-to-png")
+        .arg("--input")
+        .arg(&pdf_path)
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .arg("--scale")
+        .arg(SCALE.to_string())
+        .arg("--contact-sheet")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["success"], true);
+    assert_eq!(json["pageCount"], 3);
+    let paths = json["pngPaths"].as_array().unwrap();
+    assert_eq!(paths.len(), 1);
+
+    let pngs: Vec<_> = std::fs::read_dir(&output_dir).unwrap().collect();
+    assert_eq!(pngs.len(), 1, "Contact sheet should be the only PNG");
+
+    // Two columns and two rows of thumbnails, plus gutters and labels
+    let (width, height) = image::image_dimensions(paths[0].as_str().unwrap()).unwrap();
+    let thumb_width = (PAGE_WIDTH_PT * SCALE) as u32;
+    let thumb_height = (PAGE_HEIGHT_PT * SCALE) as u32;
+    assert!(
+        (2 * thumb_width..3 * thumb_width).contains(&width),
+        "width {} should fit two thumbnails of {}",
+        width,
+        thumb_width
+    );
+    assert!(
+        (2 * thumb_height..3 * thumb_height).contains(&height),
+        "height {} should fit two rows of {}",
+        height,
+        thumb_height
+    );
+}
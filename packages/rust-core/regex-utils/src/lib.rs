@@ -10,10 +10,21 @@ use regex::Regex;
 // Phone Number Patterns
 // =============================================================================
 
+// Area code: parenthesized as a balanced pair, or bare at the start of a word
+const US_AREA_CODE: &str = r"(?:\([0-9]{3}\)|[0-9]{3})";
+
 /// US/Canada phone: (555) 123-4567, +1-555-123-4567, 555.123.4567
+///
+/// The number is captured as `number` and an optional extension
+/// ("ext. 42", "x42", "extension 42") as `ext`. Without a country code the
+/// number must not continue a longer word or digit run, so a leading
+/// unrelated group such as "(Office)" is never part of the match.
 pub static PHONE_US_CANADA: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"(\+1[-. \t]?)?\(?[0-9]{3}\)?[-. \t]?[0-9]{3}[-. \t]?[0-9]{4}")
-        .expect("US/Canada phone regex should be valid")
+    let pattern = format!(
+        r"(?P<number>(?:\+1[-. \t]?{AREA}|\([0-9]{{3}}\)|(?-u:\b)[0-9]{{3}})[-. \t]?[0-9]{{3}}[-. \t]?[0-9]{{4}})(?:[ \t]*,?[ \t]*(?:[Ee][Xx][Tt](?:[Ee][Nn][Ss][Ii][Oo][Nn])?\.?|[Xx])[ \t]*(?P<ext>[0-9]{{1,6}}))?",
+        AREA = US_AREA_CODE
+    );
+    Regex::new(&pattern).expect("US/Canada phone regex should be valid")
 });
 
 /// UK phone: +44 20 7123 4567, +44 7700 900123
@@ -170,6 +181,13 @@ pub static CERTIFICATE: Lazy<Regex> = Lazy::new(|| {
         .expect("Certificate regex should be valid")
 });
 
+/// A single certification line: `name` with an optional trailing issue `year`
+/// ("AWS Certified Developer (2023)", "PMP, 2019", "CKA – 2022")
+pub static CERTIFICATION_ENTRY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^[ \t]*(?P<name>.+?)(?:[ \t]*(?:\(|,|-|–|—|\|)[ \t]*(?P<year>(?:19|20)[0-9]{2})\)?)?[ \t]*$")
+        .expect("Certification entry regex should be valid")
+});
+
 /// Vocational/trade qualifications
 pub static VOCATIONAL: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"(?-u:\b)([Tt][Rr][Aa][Dd][Ee][ \t]+[Ss][Cc][Hh][Oo][Oo][Ll]|[Vv][Oo][Cc][Aa][Tt][Ii][Oo][Nn][Aa][Ll][ \t]+(?:[Tt][Rr][Aa][Ii][Nn][Ii][Nn][Gg]|[Ee][Dd][Uu][Cc][Aa][Tt][Ii][Oo][Nn])|[Aa][Pp][Pp][Rr][Ee][Nn][Tt][Ii][Cc][Ee][Ss][Hh][Ii][Pp])")
@@ -203,6 +221,41 @@ mod tests {
         assert!(PHONE_US_CANADA.is_match("(555) 123-4567"));
         assert!(PHONE_US_CANADA.is_match("+1-555-123-4567"));
         assert!(PHONE_US_CANADA.is_match("555.123.4567"));
+        assert!(PHONE_US_CANADA.is_match("+1 (555) 123-4567"));
+        assert!(PHONE_US_CANADA.is_match("+15551234567"));
+        // An unbalanced parenthesis stays outside the match
+        assert_eq!(
+            PHONE_US_CANADA.find("(555 123-4567").unwrap().as_str(),
+            "555 123-4567"
+        );
+    }
+
+    #[test]
+    fn test_phone_us_canada_extension() {
+        let caps = PHONE_US_CANADA
+            .captures("Call 555-123-4567 ext. 42 after 5pm")
+            .unwrap();
+        assert_eq!(&caps["number"], "555-123-4567");
+        assert_eq!(&caps["ext"], "42");
+
+        let caps = PHONE_US_CANADA.captures("(555) 123-4567 x9").unwrap();
+        assert_eq!(&caps["number"], "(555) 123-4567");
+        assert_eq!(&caps["ext"], "9");
+
+        let caps = PHONE_US_CANADA.captures("555.123.4567").unwrap();
+        assert!(caps.name("ext").is_none());
+    }
+
+    #[test]
+    fn test_phone_us_canada_skips_leading_parenthetical() {
+        let caps = PHONE_US_CANADA.captures("(Office) (555) 123-4567").unwrap();
+        assert_eq!(&caps[0], "(555) 123-4567");
+
+        let caps = PHONE_US_CANADA
+            .captures("(2019) 555-123-4567 ext 7")
+            .unwrap();
+        assert_eq!(&caps["number"], "555-123-4567");
+        assert_eq!(&caps["ext"], "7");
     }
 
     #[test]
@@ -233,4 +286,26 @@ mod tests {
         assert!(DEGREE.is_match("Master's Degree"));
         assert!(DEGREE.is_match("PhD in Computer Science"));
     }
+
+    #[test]
+    fn test_certification_entry() {
+        let caps = CERTIFICATION_ENTRY
+            .captures("Certified Kubernetes Administrator (CKA) (2022)")
+            .unwrap();
+        assert_eq!(&caps["name"], "Certified Kubernetes Administrator (CKA)");
+        assert_eq!(&caps["year"], "2022");
+
+        let caps = CERTIFICATION_ENTRY.captures("PMP, 2019").unwrap();
+        assert_eq!(&caps["name"], "PMP");
+        assert_eq!(&caps["year"], "2019");
+
+        let caps = CERTIFICATION_ENTRY
+            .captures("AWS Certified Solutions Architect - Professional")
+            .unwrap();
+        assert_eq!(
+            &caps["name"],
+            "AWS Certified Solutions Architect - Professional"
+        );
+        assert!(caps.name("year").is_none());
+    }
 }
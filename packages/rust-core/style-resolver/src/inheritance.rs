@@ -62,8 +62,15 @@ pub fn inherit_text_styles(parent: &StyleDeclaration) -> StyleDeclaration {
             letter_spacing: parent.text.letter_spacing,
             text_transform: parent.text.text_transform,
             text_decoration: parent.text.text_decoration,
+            text_shadow: parent.text.text_shadow,
+            orphans: parent.text.orphans,
+            widows: parent.text.widows,
+            list_style_type: parent.text.list_style_type,
+            text_align_last: parent.text.text_align_last,
+            tab_stops: parent.text.tab_stops.clone(),
             vertical_align: None,
             white_space: None,
+            line_clamp: None,
         },
         box_model: BoxStyle::default(),
         // Non-inherited properties default to None/0
@@ -71,13 +78,17 @@ pub fn inherit_text_styles(parent: &StyleDeclaration) -> StyleDeclaration {
             display: None,
             flex: None,
             flex_shrink: None,
+            flex_basis: None,
             flex_direction: None,
+            flex_wrap: None,
             justify_content: None,
             align_items: None,
             gap: None,
             row_gap: None,
             column_gap: None,
         },
+        // Importance applies to the declaring element only
+        important: Vec::new(),
     }
 }
 
@@ -140,6 +151,19 @@ pub fn apply_inherited_properties(
                 .or(inherited.text.text_decoration),
             vertical_align: child_style.text.vertical_align,
             white_space: child_style.text.white_space,
+            line_clamp: child_style.text.line_clamp,
+            text_shadow: child_style.text.text_shadow.or(inherited.text.text_shadow),
+            orphans: child_style.text.orphans.or(inherited.text.orphans),
+            widows: child_style.text.widows.or(inherited.text.widows),
+            list_style_type: child_style
+                .text
+                .list_style_type
+                .or(inherited.text.list_style_type),
+            text_align_last: child_style
+                .text
+                .text_align_last
+                .or(inherited.text.text_align_last),
+            tab_stops: child_style.text.tab_stops.or(inherited.text.tab_stops),
         },
         // Non-inherited properties come only from child
         box_model: BoxStyle {
@@ -156,18 +180,29 @@ pub fn apply_inherited_properties(
             max_height: child_style.box_model.max_height,
             border_radius: child_style.box_model.border_radius,
             opacity: child_style.box_model.opacity,
+            full_bleed: child_style.box_model.full_bleed,
+            column_count: child_style.box_model.column_count,
+            margin_percent: child_style.box_model.margin_percent,
+            margin_em: child_style.box_model.margin_em,
+            padding_em: child_style.box_model.padding_em,
+            box_sizing: child_style.box_model.box_sizing,
+            position: child_style.box_model.position,
+            inset: child_style.box_model.inset,
         },
         flex: FlexStyle {
             display: child_style.flex.display,
             flex: child_style.flex.flex,
             flex_shrink: child_style.flex.flex_shrink,
+            flex_basis: child_style.flex.flex_basis,
             flex_direction: child_style.flex.flex_direction,
+            flex_wrap: child_style.flex.flex_wrap,
             justify_content: child_style.flex.justify_content,
             align_items: child_style.flex.align_items,
             gap: child_style.flex.gap,
             row_gap: child_style.flex.row_gap,
             column_gap: child_style.flex.column_gap,
         },
+        important: child_style.important,
     }
 }
 
@@ -217,6 +252,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_current_color_inherits_parent_color() {
+        let mut parent = StyleDeclaration::default();
+        parent.text.color = Some(Color::rgb(37, 99, 235));
+
+        let child = crate::resolve_element_styles(None, Some("color: currentColor"), Some(&parent));
+        assert_eq!(child.text.color, Some(Color::rgb(37, 99, 235)));
+
+        let child =
+            crate::resolve_element_styles(None, Some("color: var(--x, red)"), Some(&parent));
+        assert_eq!(child.text.color, Some(Color::rgb(255, 0, 0)));
+    }
+
+    #[test]
+    fn test_current_color_uses_cascaded_color() {
+        let mut parent = StyleDeclaration::default();
+        parent.text.color = Some(Color::rgb(37, 99, 235));
+        let sheet = crate::Stylesheet::parse(
+            "hr { border-bottom: 1px solid currentColor }",
+            (612.0, 792.0),
+        );
+
+        // Inherited from the parent
+        let child =
+            crate::resolve_element_styles_with_stylesheet("hr", None, None, Some(&parent), &sheet);
+        let border = child.box_model.border_bottom.unwrap();
+        assert_eq!(border.color, Color::rgb(37, 99, 235));
+
+        // Set by a Tailwind class on the element
+        let child = crate::resolve_element_styles_with_stylesheet(
+            "hr",
+            Some("text-red-600"),
+            None,
+            Some(&parent),
+            &sheet,
+        );
+        let border = child.box_model.border_bottom.unwrap();
+        assert_eq!(Some(border.color), child.text.color);
+        assert_ne!(border.color, Color::rgb(37, 99, 235));
+    }
+
     #[test]
     fn test_apply_inherited_properties_explicit_wins() {
         let mut parent = StyleDeclaration::default();
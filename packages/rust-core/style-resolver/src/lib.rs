@@ -44,13 +44,16 @@
 
 mod inheritance;
 mod merge;
+mod stylesheet;
 mod tailwind;
 
 pub use inheritance::{apply_inherited_properties, inherit_text_styles};
 pub use merge::{merge_inherited_styles, merge_style_overrides};
+pub use stylesheet::Stylesheet;
 pub use tailwind::resolve_tailwind_classes;
 
-use pdf_generator::css_parser::StyleDeclaration;
+use pdf_generator::css::color::is_current_color;
+use pdf_generator::css_parser::{Color, StyleDeclaration};
 
 /// Resolve complete styles for an element
 ///
@@ -70,6 +73,8 @@ use pdf_generator::css_parser::StyleDeclaration;
 /// 2. **Tailwind classes** from className attribute
 /// 3. **Inline styles** from style attribute (highest specificity)
 ///
+/// `currentColor` in any layer resolves to the element's final `color`.
+///
 /// # Example
 /// ```rust
 /// use style_resolver::resolve_element_styles;
@@ -87,6 +92,37 @@ pub fn resolve_element_styles(
     class_name: Option<&str>,
     inline_style: Option<&str>,
     parent_style: Option<&StyleDeclaration>,
+) -> StyleDeclaration {
+    resolve_element_styles_with_stylesheet(
+        "",
+        class_name,
+        inline_style,
+        parent_style,
+        &Stylesheet::default(),
+    )
+}
+
+/// Resolve complete styles for an element, including `<style>` rules
+///
+/// Same as [`resolve_element_styles`], with the document's [`Stylesheet`]
+/// rules matching `tag` or `class_name` applied after Tailwind classes and
+/// before inline styles.
+///
+/// # Example
+/// ```rust
+/// use style_resolver::{resolve_element_styles_with_stylesheet, Stylesheet};
+///
+/// let sheet = Stylesheet::parse("@media print { .title { font-size: 20pt } }", (612.0, 792.0));
+/// let style =
+///     resolve_element_styles_with_stylesheet("h1", Some("title text-lg"), None, None, &sheet);
+/// assert_eq!(style.text.font_size, Some(20.0));
+/// ```
+pub fn resolve_element_styles_with_stylesheet(
+    tag: &str,
+    class_name: Option<&str>,
+    inline_style: Option<&str>,
+    parent_style: Option<&StyleDeclaration>,
+    stylesheet: &Stylesheet,
 ) -> StyleDeclaration {
     // Step 1: Start with inherited properties from parent (if any)
     let mut resolved = if let Some(parent) = parent_style {
@@ -101,14 +137,9 @@ pub fn resolve_element_styles(
         resolved = merge_inherited_styles(resolved, tw_style);
     }
 
-    // Step 3: Apply inline styles (highest specificity, override everything)
-    if let Some(inline) = inline_style {
-        if let Ok(inline_parsed) = pdf_generator::css_parser::parse_inline_styles(inline) {
-            resolved = merge_style_overrides(resolved, inline_parsed);
-        }
-    }
-
-    resolved
+    // Steps 3-4: Apply matching stylesheet rules, then inline styles
+    let declarations = stylesheet.matching_declarations(tag, class_name);
+    apply_style_overrides(resolved, declarations.as_deref(), inline_style)
 }
 
 /// Resolve styles for a text node (inherits from parent)
@@ -140,13 +171,76 @@ pub fn resolve_text_node_styles(
     parent_style: &StyleDeclaration,
     inline_style: Option<&str>,
 ) -> StyleDeclaration {
-    let mut resolved = inherit_text_styles(parent_style);
+    apply_style_overrides(inherit_text_styles(parent_style), None, inline_style)
+}
+
+/// Apply stylesheet declarations and then inline styles over `base`
+///
+/// `currentColor` refers to the element's computed `color`, which may come
+/// from the parent, a Tailwind class or another layer than the declaration
+/// using it. Layers mentioning the keyword are therefore applied a second
+/// time with the cascaded color substituted in.
+fn apply_style_overrides(
+    base: StyleDeclaration,
+    declarations: Option<&str>,
+    inline_style: Option<&str>,
+) -> StyleDeclaration {
+    let apply = |mut resolved: StyleDeclaration,
+                 declarations: Option<&str>,
+                 inline: Option<&str>| {
+        // Stylesheet rules (only declared properties)
+        if let Some(declarations) = declarations {
+            if let Ok(sheet_style) = pdf_generator::css_parser::parse_declared_styles(declarations)
+            {
+                resolved = merge_style_overrides(resolved, sheet_style);
+            }
+        }
 
-    if let Some(inline) = inline_style {
-        if let Ok(inline_parsed) = pdf_generator::css_parser::parse_inline_styles(inline) {
-            resolved = merge_style_overrides(resolved, inline_parsed);
+        // Inline styles (highest specificity, override everything)
+        if let Some(inline) = inline {
+            if let Ok(inline_parsed) = pdf_generator::css_parser::parse_inline_styles(inline) {
+                resolved = merge_style_overrides(resolved, inline_parsed);
+            }
         }
-    }
 
-    resolved
+        resolved
+    };
+
+    let mentions_current_color = [declarations, inline_style]
+        .into_iter()
+        .flatten()
+        .any(|style| style.to_ascii_lowercase().contains("currentcolor"));
+    let cascaded = apply(base.clone(), declarations, inline_style);
+    let Some(color) = cascaded.text.color.filter(|_| mentions_current_color) else {
+        return cascaded;
+    };
+
+    let declarations = declarations.map(|style| substitute_current_color(style, color));
+    let inline_style = inline_style.map(|style| substitute_current_color(style, color));
+    apply(base, declarations.as_deref(), inline_style.as_deref())
+}
+
+/// Replace `currentColor` outside the `color` property with `color`
+///
+/// `color: currentColor` itself is left alone; it means the inherited color.
+fn substitute_current_color(style: &str, color: Color) -> String {
+    let color = format!("rgba({},{},{},{})", color.r, color.g, color.b, color.a);
+    style
+        .split(';')
+        .map(|declaration| match declaration.split_once(':') {
+            Some((property, value)) if !property.trim().eq_ignore_ascii_case("color") => {
+                let value = value
+                    .split_whitespace()
+                    .map(|token| match is_current_color(token) {
+                        true => color.as_str(),
+                        false => token,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!("{property}: {value}")
+            }
+            _ => declaration.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
 }
@@ -3,7 +3,7 @@
 //! Implements CSS cascade rules for merging style declarations.
 
 use layout_types::{BoxStyle, FlexStyle, TextStyle};
-use pdf_generator::css_parser::StyleDeclaration;
+use pdf_generator::css_parser::{parse_declared_styles, StyleDeclaration};
 
 /// Merge two style declarations with explicit styles taking precedence
 ///
@@ -64,6 +64,19 @@ pub fn merge_inherited_styles(
                 .or(inherited.text.text_decoration),
             vertical_align: explicit.text.vertical_align,
             white_space: explicit.text.white_space,
+            line_clamp: explicit.text.line_clamp,
+            text_shadow: explicit.text.text_shadow.or(inherited.text.text_shadow),
+            orphans: explicit.text.orphans.or(inherited.text.orphans),
+            widows: explicit.text.widows.or(inherited.text.widows),
+            list_style_type: explicit
+                .text
+                .list_style_type
+                .or(inherited.text.list_style_type),
+            text_align_last: explicit
+                .text
+                .text_align_last
+                .or(inherited.text.text_align_last),
+            tab_stops: explicit.text.tab_stops.or(inherited.text.tab_stops),
         },
         // Layout properties are not inherited
         box_model: BoxStyle {
@@ -80,18 +93,30 @@ pub fn merge_inherited_styles(
             max_height: explicit.box_model.max_height,
             border_radius: explicit.box_model.border_radius,
             opacity: explicit.box_model.opacity,
+            full_bleed: explicit.box_model.full_bleed,
+            column_count: explicit.box_model.column_count,
+            margin_percent: explicit.box_model.margin_percent,
+            margin_em: explicit.box_model.margin_em,
+            padding_em: explicit.box_model.padding_em,
+            box_sizing: explicit.box_model.box_sizing,
+            position: explicit.box_model.position,
+            inset: explicit.box_model.inset,
         },
         flex: FlexStyle {
             display: explicit.flex.display,
             flex: explicit.flex.flex,
             flex_shrink: explicit.flex.flex_shrink,
+            flex_basis: explicit.flex.flex_basis,
             flex_direction: explicit.flex.flex_direction,
+            flex_wrap: explicit.flex.flex_wrap,
             justify_content: explicit.flex.justify_content,
             align_items: explicit.flex.align_items,
             gap: explicit.flex.gap,
             row_gap: explicit.flex.row_gap,
             column_gap: explicit.flex.column_gap,
         },
+        // Importance is not inherited
+        important: explicit.important,
     }
 }
 
@@ -101,7 +126,8 @@ pub fn merge_inherited_styles(
 /// Any property set in `overrides` will replace the corresponding property in `base`.
 ///
 /// This implements the highest specificity level in the CSS cascade - inline styles
-/// override everything else.
+/// override everything else, except `!important` declarations in `base` that
+/// `overrides` does not also mark `!important`.
 ///
 /// # Arguments
 /// * `base` - Base styles (e.g., from Tailwind classes or inheritance)
@@ -121,9 +147,37 @@ pub fn merge_inherited_styles(
 /// # let _merged = merge_style_overrides(base, overrides);
 /// ```
 pub fn merge_style_overrides(
-    mut base: StyleDeclaration,
+    base: StyleDeclaration,
     overrides: StyleDeclaration,
 ) -> StyleDeclaration {
+    // Important base declarations survive unless the override is important too
+    let kept_important: Vec<String> = base
+        .important
+        .iter()
+        .filter(|declaration| {
+            declaration
+                .split_once(':')
+                .is_some_and(|(property, _)| !overrides.is_important(property))
+        })
+        .cloned()
+        .collect();
+    let overrides_important = overrides.important.clone();
+
+    let mut merged = apply_overrides(base, overrides);
+    if !kept_important.is_empty() {
+        if let Ok(important) = parse_declared_styles(&kept_important.join("; ")) {
+            merged = apply_overrides(merged, important);
+        }
+    }
+    merged.important = kept_important
+        .into_iter()
+        .chain(overrides_important)
+        .collect();
+    merged
+}
+
+/// Replace every property of `base` that `overrides` sets
+fn apply_overrides(mut base: StyleDeclaration, overrides: StyleDeclaration) -> StyleDeclaration {
     // Override each property if present in overrides
     if overrides.text.color.is_some() {
         base.text.color = overrides.text.color;
@@ -157,9 +211,12 @@ pub fn merge_style_overrides(
     }
     if overrides.box_model.margin.is_some() {
         base.box_model.margin = overrides.box_model.margin;
+        base.box_model.margin_percent = overrides.box_model.margin_percent;
+        base.box_model.margin_em = overrides.box_model.margin_em;
     }
     if overrides.box_model.padding.is_some() {
         base.box_model.padding = overrides.box_model.padding;
+        base.box_model.padding_em = overrides.box_model.padding_em;
     }
     if overrides.box_model.border_top.is_some() {
         base.box_model.border_top = overrides.box_model.border_top;
@@ -194,12 +251,42 @@ pub fn merge_style_overrides(
     if overrides.text.white_space.is_some() {
         base.text.white_space = overrides.text.white_space;
     }
+    if overrides.text.line_clamp.is_some() {
+        base.text.line_clamp = overrides.text.line_clamp;
+    }
+    if overrides.text.text_shadow.is_some() {
+        base.text.text_shadow = overrides.text.text_shadow;
+    }
+    if overrides.text.orphans.is_some() {
+        base.text.orphans = overrides.text.orphans;
+    }
+    if overrides.text.widows.is_some() {
+        base.text.widows = overrides.text.widows;
+    }
+    if overrides.text.list_style_type.is_some() {
+        base.text.list_style_type = overrides.text.list_style_type;
+    }
+    if overrides.text.text_align_last.is_some() {
+        base.text.text_align_last = overrides.text.text_align_last;
+    }
+    if overrides.text.tab_stops.is_some() {
+        base.text.tab_stops = overrides.text.tab_stops;
+    }
     if overrides.flex.flex.is_some() {
         base.flex.flex = overrides.flex.flex;
     }
+    if overrides.flex.flex_shrink.is_some() {
+        base.flex.flex_shrink = overrides.flex.flex_shrink;
+    }
+    if overrides.flex.flex_basis.is_some() {
+        base.flex.flex_basis = overrides.flex.flex_basis;
+    }
     if overrides.flex.flex_direction.is_some() {
         base.flex.flex_direction = overrides.flex.flex_direction;
     }
+    if overrides.flex.flex_wrap.is_some() {
+        base.flex.flex_wrap = overrides.flex.flex_wrap;
+    }
     if overrides.flex.justify_content.is_some() {
         base.flex.justify_content = overrides.flex.justify_content;
     }
@@ -212,6 +299,26 @@ pub fn merge_style_overrides(
     if overrides.box_model.border_radius.is_some() {
         base.box_model.border_radius = overrides.box_model.border_radius;
     }
+    if overrides.box_model.full_bleed.is_some() {
+        base.box_model.full_bleed = overrides.box_model.full_bleed;
+    }
+    if overrides.box_model.column_count.is_some() {
+        base.box_model.column_count = overrides.box_model.column_count;
+    }
+    if overrides.box_model.box_sizing.is_some() {
+        base.box_model.box_sizing = overrides.box_model.box_sizing;
+    }
+    if overrides.box_model.position.is_some() {
+        base.box_model.position = overrides.box_model.position;
+    }
+    if let Some(inset) = overrides.box_model.inset {
+        base.box_model.inset = Some(
+            base.box_model
+                .inset
+                .unwrap_or_default()
+                .overridden_by(inset),
+        );
+    }
     if overrides.text.vertical_align.is_some() {
         base.text.vertical_align = overrides.text.vertical_align;
     }
@@ -292,6 +399,13 @@ mod tests {
                 letter_spacing: None,
                 vertical_align: None,
                 white_space: None,
+                line_clamp: None,
+                text_shadow: None,
+                orphans: None,
+                widows: None,
+                list_style_type: None,
+                text_align_last: None,
+                tab_stops: None,
             },
             box_model: BoxStyle {
                 margin: None,
@@ -307,18 +421,29 @@ mod tests {
                 opacity: None,
                 border_radius: None,
                 background_color: None,
+                full_bleed: None,
+                column_count: None,
+                margin_percent: None,
+                margin_em: None,
+                padding_em: None,
+                box_sizing: None,
+                position: None,
+                inset: None,
             },
             flex: FlexStyle {
                 display: None,
                 flex: None,
                 flex_shrink: None,
+                flex_basis: None,
                 flex_direction: None,
+                flex_wrap: None,
                 justify_content: None,
                 align_items: None,
                 gap: None,
                 row_gap: None,
                 column_gap: None,
             },
+            important: Vec::new(),
         };
 
         let merged = merge_style_overrides(base, overrides);
@@ -335,6 +460,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_important_base_declaration_survives_overrides() {
+        use pdf_generator::css_parser::parse_inline_styles;
+
+        let base = parse_inline_styles("color: red !important; font-size: 10pt").unwrap();
+        let overrides = parse_inline_styles("color: blue; font-size: 14pt").unwrap();
+
+        let merged = merge_style_overrides(base.clone(), overrides);
+        assert_eq!(merged.text.color, Some(Color::rgb(255, 0, 0)));
+        assert_eq!(merged.text.font_size, Some(14.0));
+        assert!(merged.is_important("color"));
+
+        // A later important declaration still wins
+        let important_override = parse_inline_styles("color: blue !important").unwrap();
+        let merged = merge_style_overrides(base, important_override);
+        assert_eq!(merged.text.color, Some(Color::rgb(0, 0, 255)));
+    }
+
     #[test]
     fn test_merge_all_properties_from_overrides() {
         let mut base = StyleDeclaration::default();
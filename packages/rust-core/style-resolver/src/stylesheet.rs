@@ -0,0 +1,347 @@
+//! Stylesheet rules from inline `<style>` elements
+//!
+//! Collects the rules that apply to printed output: unconditional rules and
+//! rules inside `@media print`, `@media all` or a query without a media type
+//! (`@media (…)`, which means `all`) whose features hold for the page. Width,
+//! height (with `min-`/`max-`) and orientation are evaluated against the page
+//! size; any other feature never matches. Other media queries and at-rules
+//! are skipped. Only simple selectors are matched: a tag (`h1`) or a single
+//! class (`.title`); rules with any other selector are ignored.
+
+use pdf_generator::css_parser::css_to_points;
+
+/// Selector of a stylesheet rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    Tag(String),
+    Class(String),
+}
+
+impl Selector {
+    fn parse(selector: &str) -> Option<Self> {
+        let is_ident = |s: &str| {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        };
+
+        match selector.strip_prefix('.') {
+            Some(class) if is_ident(class) => Some(Self::Class(class.to_string())),
+            None if is_ident(selector) => Some(Self::Tag(selector.to_ascii_lowercase())),
+            _ => None,
+        }
+    }
+
+    /// Specificity rank: class selectors beat tag selectors
+    fn specificity(&self) -> u8 {
+        match self {
+            Self::Tag(_) => 0,
+            Self::Class(_) => 1,
+        }
+    }
+
+    fn matches(&self, tag: &str, class_name: Option<&str>) -> bool {
+        match self {
+            Self::Tag(name) => name.eq_ignore_ascii_case(tag),
+            Self::Class(class) => {
+                class_name.is_some_and(|classes| classes.split_whitespace().any(|c| c == class))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct StyleRule {
+    selector: Selector,
+    declarations: String,
+}
+
+/// Print-applicable rules parsed from `<style>` content
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stylesheet {
+    rules: Vec<StyleRule>,
+}
+
+impl Stylesheet {
+    /// Parse stylesheet text, keeping the rules that apply to print
+    ///
+    /// `page_size` is the (width, height) of the page in points, which media
+    /// features are evaluated against.
+    ///
+    /// # Example
+    /// ```rust
+    /// use style_resolver::Stylesheet;
+    ///
+    /// let css = "@media screen { .title { color: red } }
+    ///            @media print { .title { color: black } }
+    ///            @media (max-width: 600px) { .title { color: gray } }";
+    ///
+    /// // A US Letter page is 816px wide
+    /// let sheet = Stylesheet::parse(css, (612.0, 792.0));
+    /// assert_eq!(
+    ///     sheet.matching_declarations("h1", Some("title")).as_deref(),
+    ///     Some("color: black"),
+    /// );
+    /// ```
+    pub fn parse(css: &str, page_size: (f64, f64)) -> Self {
+        let mut sheet = Self::default();
+        sheet.parse_block(&strip_css_comments(css), page_size);
+        sheet
+    }
+
+    /// Whether no rule was kept
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Declarations of every rule matching an element, in cascade order
+    ///
+    /// Tag rules come before class rules, each group in source order, so the
+    /// result can be parsed as one declaration list where later entries win.
+    /// Returns `None` if no rule matches.
+    pub fn matching_declarations(&self, tag: &str, class_name: Option<&str>) -> Option<String> {
+        let mut matching: Vec<&StyleRule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.selector.matches(tag, class_name))
+            .collect();
+        if matching.is_empty() {
+            return None;
+        }
+
+        // Stable sort keeps source order within the same specificity
+        matching.sort_by_key(|rule| rule.selector.specificity());
+        Some(
+            matching
+                .iter()
+                .map(|rule| rule.declarations.as_str())
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+
+    fn parse_block(&mut self, mut css: &str, page_size: (f64, f64)) {
+        while let Some(open) = css.find('{') {
+            // Statement at-rules (`@import ...;`) end before the next block's prelude
+            let prelude = css[..open].rsplit(';').next().unwrap_or_default().trim();
+            let Some(close) = matching_brace(css, open) else {
+                break;
+            };
+            let body = &css[open + 1..close];
+            css = &css[close + 1..];
+
+            if let Some(media) = prelude.strip_prefix("@media") {
+                if media_applies_to_print(media, page_size) {
+                    self.parse_block(body, page_size);
+                }
+            } else if !prelude.starts_with('@') {
+                let declarations = body.trim().trim_end_matches(';').trim();
+                if declarations.is_empty() {
+                    continue;
+                }
+                for selector in prelude.split(',').filter_map(|s| Selector::parse(s.trim())) {
+                    self.rules.push(StyleRule {
+                        selector,
+                        declarations: declarations.to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Whether a media query list matches print output on a page of `page_size`
+///
+/// A query starting with a condition has the implicit media type `all`.
+fn media_applies_to_print(media: &str, page_size: (f64, f64)) -> bool {
+    media.split(',').any(|query| {
+        let query = query.trim().to_ascii_lowercase();
+        let query = query.strip_prefix("only ").unwrap_or(&query);
+        let (negated, query) = match query.strip_prefix("not ") {
+            Some(query) => (true, query),
+            None => (false, query),
+        };
+
+        let mut parts = query.split(" and ").map(str::trim);
+        let matches = match parts.next().unwrap_or_default() {
+            feature if feature.starts_with('(') => media_feature_matches(feature, page_size),
+            media_type => matches!(media_type, "print" | "all"),
+        } && parts.all(|feature| media_feature_matches(feature, page_size));
+        matches != negated
+    })
+}
+
+/// Whether a `(name: value)` media feature holds for a page of `page_size`
+fn media_feature_matches(feature: &str, (width, height): (f64, f64)) -> bool {
+    let Some((name, value)) = feature
+        .strip_prefix('(')
+        .and_then(|f| f.strip_suffix(')'))
+        .and_then(|f| f.split_once(':'))
+    else {
+        return false;
+    };
+    let (name, value) = (name.trim(), value.trim());
+
+    let length = || css_to_points(value).ok();
+    match name {
+        "orientation" => match value {
+            "portrait" => height >= width,
+            "landscape" => width > height,
+            _ => false,
+        },
+        "width" => length().is_some_and(|v| width == v),
+        "min-width" => length().is_some_and(|v| width >= v),
+        "max-width" => length().is_some_and(|v| width <= v),
+        "height" => length().is_some_and(|v| height == v),
+        "min-height" => length().is_some_and(|v| height >= v),
+        "max-height" => length().is_some_and(|v| height <= v),
+        _ => false,
+    }
+}
+
+/// Index of the `}` closing the `{` at `open`
+fn matching_brace(css: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in css[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Remove `/* ... */` comments from CSS
+fn strip_css_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print_rules_kept_and_screen_rules_ignored() {
+        let sheet = Stylesheet::parse(
+            "@media screen { .title { color: red } }
+             @media print { .title { color: black } }",
+            LETTER,
+        );
+
+        assert_eq!(
+            sheet.matching_declarations("h1", Some("title")),
+            Some("color: black".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unconditional_rules_kept() {
+        let sheet = Stylesheet::parse("h2 { font-size: 14pt; } .muted, p { color: gray }", LETTER);
+
+        assert_eq!(
+            sheet.matching_declarations("h2", None),
+            Some("font-size: 14pt".to_string())
+        );
+        assert_eq!(
+            sheet.matching_declarations("p", None),
+            Some("color: gray".to_string())
+        );
+        assert_eq!(
+            sheet.matching_declarations("span", Some("note muted")),
+            Some("color: gray".to_string())
+        );
+        assert_eq!(sheet.matching_declarations("div", Some("title")), None);
+    }
+
+    #[test]
+    fn test_class_rules_follow_tag_rules() {
+        let sheet = Stylesheet::parse(".title { color: black } h1 { color: red }", LETTER);
+
+        assert_eq!(
+            sheet.matching_declarations("h1", Some("title")),
+            Some("color: red; color: black".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unsupported_selectors_and_at_rules_skipped() {
+        let sheet = Stylesheet::parse(
+            "/* theme */ @import url(theme.css);
+             @font-face { font-family: Inter; src: url(inter.woff2) }
+             @media screen and (min-width: 768px) { p { color: red } }
+             div > p, #id, p.lead { color: blue }",
+            LETTER,
+        );
+
+        assert!(sheet.is_empty());
+    }
+
+    /// US Letter in points (816 × 1056 CSS px)
+    const LETTER: (f64, f64) = (612.0, 792.0);
+
+    #[test]
+    fn test_media_query_lists() {
+        assert!(media_applies_to_print(" print", LETTER));
+        assert!(media_applies_to_print(
+            " screen, print and (orientation: portrait)",
+            LETTER
+        ));
+        assert!(media_applies_to_print(" only print", LETTER));
+        assert!(media_applies_to_print(" all", LETTER));
+        assert!(!media_applies_to_print(" (max-width: 600px)", LETTER));
+        assert!(media_applies_to_print("(orientation: portrait)", LETTER));
+        assert!(!media_applies_to_print(" screen", LETTER));
+        assert!(!media_applies_to_print(
+            " screen and (max-width: 600px)",
+            LETTER
+        ));
+        assert!(!media_applies_to_print(" not print", LETTER));
+    }
+
+    #[test]
+    fn test_media_features_use_page_size() {
+        assert!(media_applies_to_print(" (min-width: 800px)", LETTER));
+        assert!(media_applies_to_print(
+            " print and (max-width: 612pt)",
+            LETTER
+        ));
+        assert!(!media_applies_to_print(" (min-height: 1100px)", LETTER));
+        assert!(!media_applies_to_print(" (orientation: landscape)", LETTER));
+        assert!(media_applies_to_print(
+            " (orientation: landscape)",
+            (LETTER.1, LETTER.0)
+        ));
+        // Features that cannot be evaluated never match
+        assert!(!media_applies_to_print(" (hover: hover)", LETTER));
+        assert!(!media_applies_to_print(" (min-width)", LETTER));
+    }
+
+    #[test]
+    fn test_media_without_type_applies() {
+        let sheet = Stylesheet::parse(
+            "@media (min-width: 1px) { .title { color: black } }",
+            LETTER,
+        );
+
+        assert_eq!(
+            sheet.matching_declarations("h1", Some("title")),
+            Some("color: black".to_string())
+        );
+    }
+}
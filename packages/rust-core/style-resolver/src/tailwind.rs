@@ -18,7 +18,8 @@ use tailwind_css::TailwindBuilder;
 /// StyleDeclaration with properties derived from Tailwind classes
 ///
 /// # Supported Classes
-/// - **Typography**: `text-{size}`, `font-{weight}`, `font-{style}`, `text-{color}`
+/// - **Typography**: `text-{size}`, `font-{weight}`, `font-{style}`, `text-{color}`,
+///   `tracking-{size}`, `uppercase`, `lowercase`, `capitalize`, `normal-case`
 /// - **Spacing**: `p-{size}`, `m-{size}`, `px-{size}`, `py-{size}`, etc.
 /// - **Layout**: `flex`, `block`, `inline`, `grid`
 /// - **Flexbox**: `flex-{direction}`, `justify-{content}`, `items-{align}`
@@ -42,12 +43,14 @@ pub fn resolve_tailwind_classes(class_name: &str) -> StyleDeclaration {
         pdf_generator::css_parser::parse_inline_styles(&tailwind_css).unwrap_or_default()
     } else {
         // If full parsing fails, parse classes individually and merge
-        // This handles cases where some classes (like border-b) fail but others work
+        // This handles cases where some classes (like border-b) fail but others work.
+        // Only each class's declared properties are parsed, so an explicit
+        // value is merged even when it equals the default
         let mut merged_style = StyleDeclaration::default();
         for class in class_name.split_whitespace() {
             let mut tw = TailwindBuilder::default();
             if let Ok((_rest, css)) = tw.inline(class) {
-                if let Ok(parsed) = pdf_generator::css_parser::parse_inline_styles(&css) {
+                if let Ok(parsed) = pdf_generator::css_parser::parse_declared_styles(&css) {
                     // Merge parsed properties into merged_style
                     merge_style_properties(&mut merged_style, &parsed);
                 }
@@ -86,6 +89,28 @@ fn merge_style_properties(target: &mut StyleDeclaration, source: &StyleDeclarati
     if source.text.text_align.is_some() {
         target.text.text_align = source.text.text_align;
     }
+    if source.text.text_transform.is_some() {
+        target.text.text_transform = source.text.text_transform;
+    }
+    if source.text.line_clamp.is_some() {
+        target.text.line_clamp = source.text.line_clamp;
+    }
+    if source.text.text_shadow.is_some() {
+        target.text.text_shadow = source.text.text_shadow;
+    }
+    if source.text.orphans.is_some() {
+        target.text.orphans = source.text.orphans;
+    }
+    if source.text.widows.is_some() {
+        target.text.widows = source.text.widows;
+    }
+    if source.text.list_style_type.is_some() {
+        target.text.list_style_type = source.text.list_style_type;
+    }
+    // letter_spacing: an explicit 0 (tracking-normal) overrides earlier tracking
+    if source.text.letter_spacing.is_some() {
+        target.text.letter_spacing = source.text.letter_spacing;
+    }
 
     // Flex properties
     if source.flex.display.is_some() {
@@ -94,6 +119,9 @@ fn merge_style_properties(target: &mut StyleDeclaration, source: &StyleDeclarati
     if source.flex.flex_direction.is_some() {
         target.flex.flex_direction = source.flex.flex_direction;
     }
+    if source.flex.flex_wrap.is_some() {
+        target.flex.flex_wrap = source.flex.flex_wrap;
+    }
     if source.flex.justify_content.is_some() {
         target.flex.justify_content = source.flex.justify_content;
     }
@@ -103,9 +131,15 @@ fn merge_style_properties(target: &mut StyleDeclaration, source: &StyleDeclarati
     if source.flex.gap.is_some() {
         target.flex.gap = source.flex.gap;
     }
+    if source.flex.flex.is_some() {
+        target.flex.flex = source.flex.flex;
+    }
     if source.flex.flex_shrink.is_some() {
         target.flex.flex_shrink = source.flex.flex_shrink;
     }
+    if source.flex.flex_basis.is_some() {
+        target.flex.flex_basis = source.flex.flex_basis;
+    }
 
     // Box model properties
     if source.box_model.padding.is_some() {
@@ -120,6 +154,21 @@ fn merge_style_properties(target: &mut StyleDeclaration, source: &StyleDeclarati
     if source.box_model.background_color.is_some() {
         target.box_model.background_color = source.box_model.background_color;
     }
+    if source.box_model.box_sizing.is_some() {
+        target.box_model.box_sizing = source.box_model.box_sizing;
+    }
+    if source.box_model.position.is_some() {
+        target.box_model.position = source.box_model.position;
+    }
+    if let Some(inset) = source.box_model.inset {
+        target.box_model.inset = Some(
+            target
+                .box_model
+                .inset
+                .unwrap_or_default()
+                .overridden_by(inset),
+        );
+    }
 }
 
 #[cfg(test)]
@@ -296,6 +345,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_full_bleed_marker() {
+        let style = resolve_tailwind_classes("full-bleed w-1/3 bg-gray-800");
+        assert_eq!(style.box_model.full_bleed, Some(true));
+        assert!(
+            style.box_model.background_color.is_some(),
+            "Other classes should still resolve alongside full-bleed"
+        );
+
+        let style = resolve_tailwind_classes("bg-gray-800");
+        assert_eq!(style.box_model.full_bleed, None);
+    }
+
     #[test]
     fn test_h2_full_classname() {
         // Full className from h2 element in CV
@@ -324,7 +386,7 @@ mod tests {
 }
 #[cfg(test)]
 mod test {
-    use super::resolve_tailwind_classes;
+    use super::{merge_style_properties, resolve_tailwind_classes, StyleDeclaration};
 
     #[test]
     fn test_italic_class() {
@@ -333,6 +395,125 @@ mod test {
         assert_eq!(style.text.font_style, Some(FontStyle::Italic));
     }
 
+    #[test]
+    fn test_text_transform_classes() {
+        use layout_types::TextTransform;
+        let style = resolve_tailwind_classes("uppercase");
+        assert_eq!(style.text.text_transform, Some(TextTransform::Uppercase));
+
+        let style = resolve_tailwind_classes("lowercase");
+        assert_eq!(style.text.text_transform, Some(TextTransform::Lowercase));
+
+        let style = resolve_tailwind_classes("capitalize");
+        assert_eq!(style.text.text_transform, Some(TextTransform::Capitalize));
+    }
+
+    #[test]
+    fn test_line_clamp_classes() {
+        let style = resolve_tailwind_classes("text-sm line-clamp-3");
+        assert_eq!(style.text.line_clamp, Some(3));
+
+        let style = resolve_tailwind_classes("line-clamp-none");
+        assert_eq!(style.text.line_clamp, None);
+    }
+
+    #[test]
+    fn test_list_style_classes() {
+        use layout_types::ListStyleType;
+
+        let style = resolve_tailwind_classes("list-decimal pl-4");
+        assert_eq!(style.text.list_style_type, Some(ListStyleType::Decimal));
+
+        let style = resolve_tailwind_classes("list-none");
+        assert_eq!(style.text.list_style_type, Some(ListStyleType::None));
+
+        let style = resolve_tailwind_classes("list-disc");
+        assert_eq!(style.text.list_style_type, Some(ListStyleType::Disc));
+    }
+
+    #[test]
+    fn test_box_sizing_classes() {
+        use layout_types::BoxSizing;
+
+        let style = resolve_tailwind_classes("box-content p-4");
+        assert_eq!(style.box_model.box_sizing, Some(BoxSizing::ContentBox));
+
+        let style = resolve_tailwind_classes("box-border");
+        assert_eq!(style.box_model.box_sizing, Some(BoxSizing::BorderBox));
+    }
+
+    #[test]
+    fn test_flex_wrap_classes() {
+        use layout_types::FlexWrap;
+
+        let style = resolve_tailwind_classes("flex flex-wrap gap-2");
+        assert_eq!(style.flex.flex_wrap, Some(FlexWrap::Wrap));
+
+        let style = resolve_tailwind_classes("flex flex-wrap-reverse");
+        assert_eq!(style.flex.flex_wrap, Some(FlexWrap::WrapReverse));
+
+        let style = resolve_tailwind_classes("flex flex-nowrap");
+        assert_eq!(style.flex.flex_wrap, Some(FlexWrap::NoWrap));
+    }
+
+    #[test]
+    fn test_tracking_classes() {
+        let wide = resolve_tailwind_classes("tracking-wide");
+        assert!(wide.text.letter_spacing.unwrap() > 0.0);
+
+        let tight = resolve_tailwind_classes("tracking-tight");
+        assert!(tight.text.letter_spacing.unwrap() < 0.0);
+
+        // Scaled by the element's font size: 0.1em * 13.5pt (text-lg)
+        let widest = resolve_tailwind_classes("text-lg tracking-widest");
+        assert!((widest.text.letter_spacing.unwrap() - 1.35).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_merge_style_properties_keeps_explicit_zero_letter_spacing() {
+        let mut target = StyleDeclaration::default();
+        target.text.letter_spacing = Some(0.3);
+        let tracking_normal =
+            pdf_generator::css_parser::parse_declared_styles("letter-spacing: 0").unwrap();
+
+        merge_style_properties(&mut target, &tracking_normal);
+        assert_eq!(target.text.letter_spacing, Some(0.0));
+
+        let unrelated = pdf_generator::css_parser::parse_declared_styles("color: red").unwrap();
+        target.text.letter_spacing = Some(0.3);
+        merge_style_properties(&mut target, &unrelated);
+        assert_eq!(target.text.letter_spacing, Some(0.3));
+    }
+
+    #[test]
+    fn test_tracking_and_transform_with_inline_override() {
+        use layout_types::TextTransform;
+        let style = crate::resolve_element_styles(
+            Some("uppercase tracking-wide"),
+            Some("letter-spacing: 2pt"),
+            None,
+        );
+        assert_eq!(style.text.letter_spacing, Some(2.0));
+        assert_eq!(style.text.text_transform, Some(TextTransform::Uppercase));
+
+        let style = crate::resolve_element_styles(
+            Some("uppercase tracking-wide"),
+            Some("text-transform: none"),
+            None,
+        );
+        assert_eq!(style.text.text_transform, Some(TextTransform::None));
+    }
+
+    #[test]
+    fn test_inline_important_color_beats_tailwind_class() {
+        let style = crate::resolve_element_styles(
+            Some("text-blue-500"),
+            Some("color: red !important"),
+            None,
+        );
+        assert_eq!(style.text.color, Some(layout_types::Color::rgb(255, 0, 0)));
+    }
+
     #[test]
     fn test_tailwind_inline_output() {
         use tailwind_css::TailwindBuilder;
@@ -421,7 +602,10 @@ mod test {
 /// Some Tailwind classes like space-y/space-x need special handling because
 /// they use CSS selectors that can't be represented in inline styles.
 fn handle_special_tailwind_classes(class_name: &str, style: &mut StyleDeclaration) {
-    use layout_types::{BorderLineStyle, BorderStyle, Color};
+    use layout_types::{
+        BorderLineStyle, BorderStyle, BoxSizing, Color, ListStyleType, TextTransform,
+        DEFAULT_FONT_SIZE,
+    };
     use pdf_generator::css_parser::{Display, FlexDirection};
 
     // Track pending border properties across classes
@@ -431,6 +615,70 @@ fn handle_special_tailwind_classes(class_name: &str, style: &mut StyleDeclaratio
 
     // Parse individual classes from the class string
     for class in class_name.split_whitespace() {
+        // Handle full-bleed marker (background extends to the physical page edge)
+        // Not a Tailwind utility - used by templates with edge-to-edge sidebars
+        if class == "full-bleed" {
+            style.box_model.full_bleed = Some(true);
+        }
+
+        // Handle text-transform utilities explicitly so they survive the
+        // per-class fallback path when the combined class string fails to parse
+        match class {
+            "uppercase" => style.text.text_transform = Some(TextTransform::Uppercase),
+            "lowercase" => style.text.text_transform = Some(TextTransform::Lowercase),
+            "capitalize" => style.text.text_transform = Some(TextTransform::Capitalize),
+            "normal-case" => style.text.text_transform = Some(TextTransform::None),
+            _ => {}
+        }
+
+        // Handle tracking-* (letter-spacing in em)
+        // The parser resolves em against the 12pt default; scale by the element's
+        // own font size instead so `text-lg tracking-wide` matches the browser
+        if let Some(size_str) = class.strip_prefix("tracking-") {
+            let tracking_em = match size_str {
+                "tighter" => Some(-0.05),
+                "tight" => Some(-0.025),
+                "normal" => Some(0.0),
+                "wide" => Some(0.025),
+                "wider" => Some(0.05),
+                "widest" => Some(0.1),
+                _ => None,
+            };
+            if let Some(em) = tracking_em {
+                let font_size = style.text.font_size.unwrap_or(DEFAULT_FONT_SIZE);
+                style.text.letter_spacing = Some(em * font_size);
+            }
+        }
+
+        // Handle box-{sizing}
+        match class {
+            "box-border" => style.box_model.box_sizing = Some(BoxSizing::BorderBox),
+            "box-content" => style.box_model.box_sizing = Some(BoxSizing::ContentBox),
+            _ => {}
+        }
+
+        // Handle list-{type} (list item markers)
+        match class {
+            "list-disc" => style.text.list_style_type = Some(ListStyleType::Disc),
+            "list-decimal" => style.text.list_style_type = Some(ListStyleType::Decimal),
+            "list-none" => style.text.list_style_type = Some(ListStyleType::None),
+            _ => {}
+        }
+
+        // Handle line-clamp-{n} (multi-line truncation with ellipsis)
+        if let Some(lines_str) = class.strip_prefix("line-clamp-") {
+            match lines_str {
+                "none" => style.text.line_clamp = None,
+                n => {
+                    if let Ok(lines) = n.parse::<usize>() {
+                        if lines >= 1 {
+                            style.text.line_clamp = Some(lines);
+                        }
+                    }
+                }
+            }
+        }
+
         // Handle border-b (border-bottom with default 1px width)
         // tailwind-css library incorrectly parses this as a color
         if class == "border-b" {
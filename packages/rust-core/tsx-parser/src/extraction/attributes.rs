@@ -117,21 +117,11 @@ fn extract_text_content_recursive(element: &JSXElement) -> Vec<String> {
                 texts.extend(extract_text_content_recursive(nested_element));
             }
             JSXChild::ExpressionContainer(expr_container) => {
-                // In oxc, Expression variants are inherited directly into JSXExpression
-                match &expr_container.expression {
-                    JSXExpression::StringLiteral(str_lit) => {
-                        texts.push(str_lit.value.to_string());
+                // Interpolated expressions like {name} are skipped
+                if let Some(content) = extract_expression_text(&expr_container.expression) {
+                    if !content.trim().is_empty() {
+                        texts.push(content);
                     }
-                    JSXExpression::TemplateLiteral(tpl) => {
-                        // Handle template literals
-                        for quasi in &tpl.quasis {
-                            let content = quasi.value.raw.to_string();
-                            if !content.trim().is_empty() {
-                                texts.push(content);
-                            }
-                        }
-                    }
-                    _ => {}
                 }
             }
             _ => {}
@@ -141,6 +131,25 @@ fn extract_text_content_recursive(element: &JSXElement) -> Vec<String> {
     texts
 }
 
+/// Evaluate a JSX expression that is a constant string
+///
+/// Handles string literals (`{"John Doe"}`) and template literals without
+/// interpolation (`{`Software Engineer`}`). Anything else, including
+/// templates with `${...}` substitutions, returns `None`.
+pub fn extract_expression_text(expression: &JSXExpression) -> Option<String> {
+    // In oxc, Expression variants are inherited directly into JSXExpression
+    match expression {
+        JSXExpression::StringLiteral(str_lit) => Some(str_lit.value.to_string()),
+        JSXExpression::TemplateLiteral(tpl) if tpl.expressions.is_empty() => {
+            let quasi = tpl.quasis.first()?;
+            // Cooked value has escape sequences resolved; fall back to raw if invalid
+            let value = quasi.value.cooked.as_ref().unwrap_or(&quasi.value.raw);
+            Some(value.to_string())
+        }
+        _ => None,
+    }
+}
+
 /// Get all attribute names from a JSX element
 ///
 /// # Arguments
@@ -190,6 +199,50 @@ pub fn get_attribute_value(element: &JSXElement, attr_name: &str) -> Option<Stri
     None
 }
 
+/// Extract the ARIA `role` of a JSX element
+///
+/// Returns the first role token, lowercased (`role="Heading note"` → `heading`).
+pub fn extract_aria_role(element: &JSXElement) -> Option<String> {
+    get_attribute_value(element, "role")?
+        .split_whitespace()
+        .next()
+        .map(str::to_ascii_lowercase)
+}
+
+/// Extract the `aria-level` of a JSX element
+///
+/// Accepts string (`aria-level="2"`) and numeric (`aria-level={2}`) values.
+/// Returns `None` if the attribute is missing or not a positive integer.
+pub fn extract_aria_level(element: &JSXElement) -> Option<u8> {
+    let value = element
+        .opening_element
+        .attributes
+        .iter()
+        .find_map(|attr| match attr {
+            JSXAttributeItem::Attribute(jsx_attr) => match &jsx_attr.name {
+                JSXAttributeName::Identifier(ident) if ident.name.as_str() == "aria-level" => {
+                    jsx_attr.value.as_ref()
+                }
+                _ => None,
+            },
+            _ => None,
+        })?;
+
+    let level = match value {
+        JSXAttributeValue::StringLiteral(str_lit) => str_lit.value.trim().parse().ok()?,
+        JSXAttributeValue::ExpressionContainer(expr_container) => {
+            match &expr_container.expression {
+                JSXExpression::NumericLiteral(num) if num.value.fract() == 0.0 => {
+                    u8::try_from(num.value as i64).ok()?
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    (level > 0).then_some(level)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,6 +269,39 @@ mod tests {
         assert!(style_str.contains("font-size") || style_str.contains("fontSize"));
     }
 
+    #[test]
+    fn test_extract_aria_role_and_level() {
+        let tsx = r#"
+            const CV = () => (
+                <div>
+                    <div role="Heading note" aria-level="2">Experience</div>
+                    <div role="heading" aria-level={3}>Education</div>
+                    <div aria-level="zero">Skills</div>
+                </div>
+            );
+        "#;
+
+        let document = parse_tsx(tsx).unwrap();
+        let elements = crate::extract_jsx_elements(&document);
+        let children: Vec<&JSXElement> = elements[0]
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                JSXChild::Element(element) => Some(&**element),
+                _ => None,
+            })
+            .collect();
+        let [first, second, third] = children[..] else {
+            panic!("expected three child elements");
+        };
+
+        assert_eq!(extract_aria_role(first), Some("heading".to_string()));
+        assert_eq!(extract_aria_level(first), Some(2));
+        assert_eq!(extract_aria_level(second), Some(3));
+        assert_eq!(extract_aria_role(third), None);
+        assert_eq!(extract_aria_level(third), None);
+    }
+
     #[test]
     fn test_extract_class_name_with_value() {
         let tsx = r#"
@@ -258,6 +344,30 @@ mod tests {
         assert_eq!(name, "circle");
     }
 
+    fn texts_of_first(tsx: &str) -> Vec<String> {
+        let document = parse_tsx(tsx).unwrap();
+        let elements = crate::extract_jsx_elements(&document);
+        extract_text_content(elements.first().expect("Should have element"))
+    }
+
+    #[test]
+    fn test_extract_text_content_string_literal_expression() {
+        let texts = texts_of_first(r#"const CV = () => <h1>{"John Doe"}</h1>;"#);
+        assert_eq!(texts, vec!["John Doe"]);
+    }
+
+    #[test]
+    fn test_extract_text_content_template_literal_expression() {
+        let texts = texts_of_first("const CV = () => <p>{`Software Engineer`}</p>;");
+        assert_eq!(texts, vec!["Software Engineer"]);
+    }
+
+    #[test]
+    fn test_extract_text_content_skips_interpolated_expressions() {
+        let texts = texts_of_first("const CV = () => <p>Hi {name} {`Dear ${name}`}</p>;");
+        assert_eq!(texts, vec!["Hi "]);
+    }
+
     #[test]
     fn test_extract_text_content_with_jsx_expressions() {
         let tsx = r#"
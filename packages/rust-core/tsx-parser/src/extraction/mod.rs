@@ -10,7 +10,8 @@ pub mod traversal;
 
 // Re-export public API for backward compatibility
 pub use attributes::{
-    extract_class_name, extract_element_name, extract_inline_style, extract_text_content,
-    get_attribute_names, get_attribute_value,
+    extract_aria_level, extract_aria_role, extract_class_name, extract_element_name,
+    extract_expression_text, extract_inline_style, extract_text_content, get_attribute_names,
+    get_attribute_value,
 };
 pub use traversal::extract_jsx_elements;
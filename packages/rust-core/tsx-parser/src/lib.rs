@@ -19,8 +19,9 @@ mod validation;
 // Re-export public API
 pub use error::{ParseError, ParseResult};
 pub use extraction::{
-    extract_class_name, extract_element_name, extract_inline_style, extract_jsx_elements,
-    extract_text_content, get_attribute_names, get_attribute_value,
+    extract_aria_level, extract_aria_role, extract_class_name, extract_element_name,
+    extract_expression_text, extract_inline_style, extract_jsx_elements, extract_text_content,
+    get_attribute_names, get_attribute_value,
 };
 pub use parser::{parse_tsx, parse_tsx_with_recovery};
 
@@ -57,6 +58,20 @@ impl TsxDocument {
     pub(crate) fn from_source(
         source: String,
     ) -> Result<Self, (Vec<crate::error::ParseError>, Option<Self>)> {
+        let (doc, errors) = Self::parse_source(source);
+
+        if errors.is_empty() {
+            Ok(doc)
+        } else {
+            Err((errors, Some(doc)))
+        }
+    }
+
+    /// Parse TSX source, always producing a document
+    ///
+    /// The parser recovers from syntax errors, so the returned document is
+    /// usable even when the error list is non-empty.
+    fn parse_source(source: String) -> (Self, Vec<crate::error::ParseError>) {
         use oxc_parser::Parser;
         use oxc_span::SourceType;
 
@@ -91,11 +106,7 @@ impl TsxDocument {
             program_ptr,
         };
 
-        if errors.is_empty() {
-            Ok(doc)
-        } else {
-            Err((errors, Some(doc)))
-        }
+        (doc, errors)
     }
 
     /// Get a reference to the source code
@@ -115,8 +126,9 @@ impl TsxDocument {
 
 impl Clone for TsxDocument {
     fn clone(&self) -> Self {
-        // Re-parse to create a new document with its own allocator
-        parse_tsx(&self.source).expect("Re-parsing should succeed for valid document")
+        // Re-parse to create a new document with its own allocator. Parse errors
+        // are ignored so documents recovered from invalid source clone as well.
+        Self::parse_source(self.source.clone()).0
     }
 }
 
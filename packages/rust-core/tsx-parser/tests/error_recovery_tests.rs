@@ -235,3 +235,27 @@ fn test_parse_with_recovery_unsupported_fragment() {
         }
     }
 }
+
+#[test]
+fn test_clone_recovered_document_does_not_panic() {
+    let tsx = r#"
+        const CV = () => (
+            <div>
+                <h1>John Doe
+                <p>Software Engineer</p>
+            </div>
+        );
+    "#;
+
+    let result = parse_tsx_with_recovery(tsx);
+    let document = result
+        .document()
+        .expect("Parser should recover a partial document");
+
+    let cloned = document.clone();
+    assert_eq!(cloned.source, document.source);
+    assert_eq!(
+        extract_jsx_elements(&cloned).len(),
+        extract_jsx_elements(document).len()
+    );
+}
@@ -9,13 +9,18 @@
 //! 5. Generate PDF (80%)
 //! 6. Complete (100%)
 
+use std::cell::RefCell;
+
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
+use crate::layout_cache::LayoutCache;
+use crate::timing::StageTimings;
 use crate::{debug_log, from_js, to_js};
 
 use cv_domain::{extract_metadata, extract_tsx_layout_config_from_document};
 use layout_engine::calculate_layout_direct;
-use pdf_generator::PDFConfig;
+use pdf_generator::{FontSubstitution, PDFConfig, SizeReport};
 use tsx_parser::parse_tsx;
 
 use crate::error::create_error;
@@ -165,8 +170,18 @@ impl Default for FontCollection {
 /// // Or single-step (uses web-safe fonts only)
 /// const pdfBytes = await converter.convert_tsx_to_pdf(tsxCode, config);
 /// ```
+///
+/// The converter keeps the last few computed layouts, so converting the same
+/// TSX with the same config again (live preview) only regenerates the PDF.
 #[wasm_bindgen]
-pub struct TsxToPdfConverter;
+pub struct TsxToPdfConverter {
+    layout_cache: RefCell<LayoutCache>,
+    warnings: RefCell<Vec<String>>,
+    size_report: RefCell<Option<SizeReport>>,
+    font_substitutions: RefCell<Vec<FontSubstitution>>,
+    content_scale: RefCell<f64>,
+    timings: RefCell<StageTimings>,
+}
 
 impl Default for TsxToPdfConverter {
     fn default() -> Self {
@@ -178,7 +193,109 @@ impl Default for TsxToPdfConverter {
 impl TsxToPdfConverter {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self
+        Self {
+            layout_cache: RefCell::new(LayoutCache::default()),
+            warnings: RefCell::new(Vec::new()),
+            size_report: RefCell::new(None),
+            font_substitutions: RefCell::new(Vec::new()),
+            content_scale: RefCell::new(1.0),
+            timings: RefCell::new(StageTimings::new()),
+        }
+    }
+
+    /// Warnings from the most recent [`convert_tsx_to_pdf`] call
+    ///
+    /// Lists supplied fonts that were corrupt or failed to embed; each was
+    /// replaced by a Standard 14 fallback and the conversion continued.
+    ///
+    /// # Example
+    /// ```typescript
+    /// const pdfBytes = await converter.convert_tsx_to_pdf(tsxCode, config, fontCollection);
+    /// for (const w of converter.warnings()) console.warn(w);
+    /// ```
+    #[wasm_bindgen]
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.borrow().clone()
+    }
+
+    /// Size outcome of the most recent [`convert_tsx_to_pdf`] call
+    ///
+    /// `undefined` unless the config set `target_max_bytes`. Otherwise
+    /// `{ target_bytes, achieved_bytes, reductions, met }`, where `reductions`
+    /// lists what was switched on (`"compress-streams"`, `"strip-font-tables"`).
+    /// A missed target still produces a PDF.
+    ///
+    /// # Example
+    /// ```typescript
+    /// const pdfBytes = await converter.convert_tsx_to_pdf(tsxCode, { target_max_bytes: 100_000 });
+    /// const report = converter.size_report();
+    /// if (report && !report.met) console.warn(`PDF is ${report.achieved_bytes} bytes`);
+    /// ```
+    #[wasm_bindgen]
+    pub fn size_report(&self) -> Result<JsValue, JsValue> {
+        Ok(to_js!(*self.size_report.borrow()))
+    }
+
+    /// Font substitutions made by the most recent [`convert_tsx_to_pdf`] call
+    ///
+    /// One `{ family, weight, italic, substitute }` entry per requested font
+    /// variant missing from the collection, per the config's
+    /// `missing_font_policy`. `substitute` is the Standard 14 font the PDF
+    /// uses instead, such as `"Helvetica-Bold"`.
+    ///
+    /// # Example
+    /// ```typescript
+    /// const pdfBytes = await converter.convert_tsx_to_pdf(tsxCode, config, fontCollection);
+    /// for (const s of converter.font_substitutions()) {
+    ///   console.warn(`${s.family} ${s.weight} replaced by ${s.substitute}`);
+    /// }
+    /// ```
+    #[wasm_bindgen]
+    pub fn font_substitutions(&self) -> Result<JsValue, JsValue> {
+        Ok(to_js!(*self.font_substitutions.borrow()))
+    }
+
+    /// Scale applied to the content by the most recent [`convert_tsx_to_pdf`] call
+    ///
+    /// Below 1.0 when the config's `fit_to_pages` shrank font sizes and
+    /// spacing so the CV fits the requested page count; 1.0 when no scaling
+    /// was needed or fitting would have gone below the 6pt font floor.
+    ///
+    /// # Example
+    /// ```typescript
+    /// const pdfBytes = await converter.convert_tsx_to_pdf(tsxCode, { fit_to_pages: 1 });
+    /// if (converter.content_scale() < 1) console.info("Shrunk to fit one page");
+    /// ```
+    #[wasm_bindgen]
+    pub fn content_scale(&self) -> f64 {
+        *self.content_scale.borrow()
+    }
+
+    /// Per-stage timings of the most recent [`convert_tsx_to_pdf`] call
+    ///
+    /// Elapsed milliseconds keyed by stage name (`parsing`,
+    /// `extracting-metadata`, `extracting-layout`, `laying-out`,
+    /// `generating-pdf`). Stages skipped by a layout cache hit are absent.
+    /// Failed conversions carry the same object in the error's `metadata.timings`.
+    ///
+    /// # Example
+    /// ```typescript
+    /// const pdfBytes = await converter.convert_tsx_to_pdf(tsxCode, config);
+    /// console.table(converter.timings());
+    /// ```
+    #[wasm_bindgen]
+    pub fn timings(&self) -> Result<JsValue, JsValue> {
+        // A plain object like the error metadata, rather than a JS Map
+        self.timings
+            .borrow()
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| JsValue::from_str(&format!("Serialization error: {}", e)))
+    }
+
+    /// Drop all cached layouts, forcing the next conversion to run the full pipeline
+    #[wasm_bindgen]
+    pub fn clear_layout_cache(&self) {
+        self.layout_cache.borrow_mut().clear();
     }
 
     /// Detect font requirements from TSX code (Step 1 of two-step font loading)
@@ -233,7 +350,8 @@ impl TsxToPdfConverter {
     ///
     /// # Progress Stages
     /// `parsing` (10%) → `extracting-metadata` (20%) → `extracting-layout` (40%)
-    /// → `laying-out` (60%) → `generating-pdf` (80%) → `completed` (100%)
+    /// → `laying-out` (50%) → `paginating` (60%, reported per page) → `generating-pdf` (80%)
+    /// → `completed` (100%)
     #[wasm_bindgen]
     pub fn convert_tsx_to_pdf(
         &self,
@@ -245,20 +363,73 @@ impl TsxToPdfConverter {
         // Parse config from JsValue
         let pdf_config: PDFConfig = from_js!(config, "INVALID_CONFIG", "parsing");
 
-        // Build font bytes map from FontCollection using FontProcessor
-        let font_bytes_map = if let Some(font_collection) = fonts {
+        // Build font bytes map from FontCollection; invalid fonts become warnings
+        let (font_bytes_map, font_warnings) = if let Some(font_collection) = fonts {
             let font_processor = crate::font_processor::FontProcessor::new();
-            font_processor
-                .build_font_collection(&font_collection)
-                .map_err(|e| self.create_error("INVALID_FONT_DATA", &e, "font-validation", true))?
+            font_processor.build_font_collection(&font_collection)
         } else {
-            std::collections::HashMap::new()
+            (std::collections::HashMap::new(), Vec::new())
         };
 
         // Execute pipeline using PipelineOrchestrator
         let orchestrator =
             crate::pipeline_orchestrator::PipelineOrchestrator::new(progress_callback);
-        orchestrator.execute_pipeline(tsx, pdf_config, font_bytes_map)
+        orchestrator.add_warnings(font_warnings);
+        let result = orchestrator.execute_pipeline_cached(
+            tsx,
+            pdf_config,
+            font_bytes_map,
+            Some(&self.layout_cache),
+        );
+        *self.warnings.borrow_mut() = orchestrator.warnings();
+        *self.size_report.borrow_mut() = orchestrator.size_report();
+        *self.font_substitutions.borrow_mut() = orchestrator.font_substitutions();
+        *self.content_scale.borrow_mut() = orchestrator.content_scale();
+        *self.timings.borrow_mut() = orchestrator.timings();
+        result
+    }
+
+    /// Convert TSX code to one PDF per page
+    ///
+    /// Paginates like [`convert_tsx_to_pdf`] but returns each page as a
+    /// complete single-page PDF with the fonts it uses embedded. Takes the
+    /// same arguments; `target_max_bytes` applies to each page's PDF.
+    ///
+    /// # Example
+    /// ```typescript
+    /// const pages = await converter.convert_tsx_to_pdf_pages(tsxCode, config, fontCollection);
+    /// pages.forEach((bytes, i) => zip.file(`resume-page-${i + 1}.pdf`, bytes));
+    /// ```
+    #[wasm_bindgen]
+    pub fn convert_tsx_to_pdf_pages(
+        &self,
+        tsx: &str,
+        config: JsValue,
+        fonts: Option<FontCollection>,
+        progress_callback: Option<js_sys::Function>,
+    ) -> Result<Vec<js_sys::Uint8Array>, JsValue> {
+        let pdf_config: PDFConfig = from_js!(config, "INVALID_CONFIG", "parsing");
+
+        let (font_bytes_map, font_warnings) = if let Some(font_collection) = fonts {
+            let font_processor = crate::font_processor::FontProcessor::new();
+            font_processor.build_font_collection(&font_collection)
+        } else {
+            (std::collections::HashMap::new(), Vec::new())
+        };
+
+        let orchestrator =
+            crate::pipeline_orchestrator::PipelineOrchestrator::new(progress_callback);
+        orchestrator.add_warnings(font_warnings);
+        let result = orchestrator.execute_pipeline_pages(tsx, pdf_config, font_bytes_map);
+        *self.warnings.borrow_mut() = orchestrator.warnings();
+        *self.size_report.borrow_mut() = orchestrator.size_report();
+        *self.font_substitutions.borrow_mut() = orchestrator.font_substitutions();
+        *self.content_scale.borrow_mut() = orchestrator.content_scale();
+        *self.timings.borrow_mut() = orchestrator.timings();
+        Ok(result?
+            .iter()
+            .map(|pdf_bytes| js_sys::Uint8Array::from(pdf_bytes.as_slice()))
+            .collect())
     }
 
     /// Create structured error object (delegates to error module)
@@ -270,12 +441,14 @@ impl TsxToPdfConverter {
     ///
     /// Returns a score (0.0-1.0) based on layout simplicity, font embedding,
     /// text extractability, and section structure. Passing score: ≥0.9 (90%).
-    /// Faster than full conversion since it skips PDF rendering.
+    /// The `rules` array lists each check as `{ rule, passed, weight, message }`
+    /// for checklist display. Faster than full conversion since it skips PDF rendering.
     ///
     /// # Example
     /// ```typescript
     /// const report = await converter.validate_ats_compatibility(tsxCode, config);
     /// console.log(`Score: ${(report.score * 100).toFixed(1)}%`);
+    /// for (const r of report.rules) console.log(`${r.passed ? '✓' : '✗'} ${r.message}`);
     /// if (report.passes()) {
     ///   const pdfBytes = await converter.convert_tsx_to_pdf(tsxCode, config);
     /// }
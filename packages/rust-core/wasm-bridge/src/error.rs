@@ -4,7 +4,11 @@
 //! conversion process. All errors are designed to be serialized to JavaScript as structured
 //! objects with actionable suggestions.
 
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::timing::StageTimings;
 
 /// Error object structure for WASM boundary
 ///
@@ -82,6 +86,45 @@ pub fn create_error_with_metadata(
         .unwrap_or_else(|_| JsValue::from_str("SERIALIZATION_ERROR"))
 }
 
+/// Attach pipeline stage timings to a serialized error's metadata
+///
+/// Adds a `timings` entry to the error's `metadata` (creating it when absent)
+/// so failures report how long each completed stage took, including the
+/// stage that failed.
+///
+/// # Arguments
+/// * `error` - JsValue produced by [`create_error`] or [`create_error_with_metadata`]
+/// * `timings` - Stage durations recorded so far
+///
+/// # Returns
+/// The same error value with timings attached (unchanged if it is not an object)
+pub fn attach_timings(error: JsValue, timings: &StageTimings) -> JsValue {
+    if !error.is_object() {
+        return error;
+    }
+
+    let Ok(timings) = timings.serialize(&serde_wasm_bindgen::Serializer::json_compatible()) else {
+        return error;
+    };
+
+    let metadata_key = JsValue::from_str("metadata");
+    let timings_key = JsValue::from_str("timings");
+    let metadata = js_sys::Reflect::get(&error, &metadata_key)
+        .ok()
+        .filter(|metadata| metadata.is_object())
+        .unwrap_or_else(|| js_sys::Object::new().into());
+
+    // serde_wasm_bindgen serializes serde_json maps as JS Map by default
+    if let Some(map) = metadata.dyn_ref::<js_sys::Map>() {
+        map.set(&timings_key, &timings);
+    } else {
+        let _ = js_sys::Reflect::set(&metadata, &timings_key, &timings);
+    }
+    let _ = js_sys::Reflect::set(&error, &metadata_key, &metadata);
+
+    error
+}
+
 /// Map error code to category
 ///
 /// Categories:
@@ -101,9 +101,56 @@ pub fn decompress_woff2_font(woff2_bytes: &[u8]) -> Result<Vec<u8>, JsValue> {
     })
 }
 
+/// Font file format identified from its leading magic number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontFormat {
+    /// TrueType outlines: 0x00010000 or 'true' (macOS)
+    TrueType,
+    /// OpenType with CFF outlines: 'OTTO'
+    OpenTypeCff,
+    /// WOFF 1.0 wrapper: 'wOFF'
+    Woff,
+    /// WOFF 2.0 wrapper: 'wOF2'
+    Woff2,
+}
+
+impl FontFormat {
+    /// Detect the format from the first 4 bytes of a font file
+    ///
+    /// Returns `None` for truncated input or unrecognized magic numbers
+    /// (including TrueType Collections, which are not supported).
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 4 {
+            return None;
+        }
+
+        let magic = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+        match magic {
+            0x774F4646 => Some(Self::Woff),        // "wOFF"
+            0x774F4632 => Some(Self::Woff2),       // "wOF2"
+            0x00010000 => Some(Self::TrueType),    // TrueType 1.0
+            0x74727565 => Some(Self::TrueType),    // 'true' (macOS)
+            0x4F54544F => Some(Self::OpenTypeCff), // 'OTTO' (OpenType CFF)
+            _ => None,
+        }
+    }
+
+    /// Short format name exposed to JavaScript: "ttf", "otf", "woff", or "woff2"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TrueType => "ttf",
+            Self::OpenTypeCff => "otf",
+            Self::Woff => "woff",
+            Self::Woff2 => "woff2",
+        }
+    }
+}
+
 /// Validate font format without decompression
 ///
-/// Returns the detected format: "ttf", "woff", "woff2", or null if invalid
+/// Returns the detected format: "ttf", "otf", "woff", "woff2", or null if invalid.
+/// Rust callers should use [`FontFormat::from_bytes`] to get a typed result.
 ///
 /// # JavaScript Example
 /// ```javascript
@@ -115,20 +162,7 @@ pub fn decompress_woff2_font(woff2_bytes: &[u8]) -> Result<Vec<u8>, JsValue> {
 /// ```
 #[wasm_bindgen]
 pub fn detect_font_format(bytes: &[u8]) -> Option<String> {
-    if bytes.len() < 4 {
-        return None;
-    }
-
-    let magic = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
-
-    match magic {
-        0x774F4646 => Some("woff".to_string()),  // "wOFF"
-        0x774F4632 => Some("woff2".to_string()), // "wOF2"
-        0x00010000 => Some("ttf".to_string()),   // TrueType 1.0
-        0x74727565 => Some("ttf".to_string()),   // 'true' (macOS)
-        0x4F54544F => Some("otf".to_string()),   // 'OTTO' (OpenType CFF)
-        _ => None,
-    }
+    FontFormat::from_bytes(bytes).map(|format| format.as_str().to_string())
 }
 
 #[cfg(test)]
@@ -154,6 +188,31 @@ mod tests {
         assert_eq!(detect_font_format(&invalid_bytes), None);
     }
 
+    #[test]
+    fn test_font_format_from_bytes_distinct_variants() {
+        assert_eq!(
+            FontFormat::from_bytes(&[0x00, 0x01, 0x00, 0x00]),
+            Some(FontFormat::TrueType)
+        );
+        assert_eq!(FontFormat::from_bytes(b"true"), Some(FontFormat::TrueType));
+        assert_eq!(
+            FontFormat::from_bytes(b"OTTO"),
+            Some(FontFormat::OpenTypeCff)
+        );
+        assert_eq!(FontFormat::from_bytes(b"wOFF"), Some(FontFormat::Woff));
+        assert_eq!(FontFormat::from_bytes(b"wOF2"), Some(FontFormat::Woff2));
+        assert_eq!(FontFormat::from_bytes(b"ttcf"), None);
+        assert_eq!(FontFormat::from_bytes(b"OT"), None);
+    }
+
+    #[test]
+    fn test_font_format_as_str() {
+        assert_eq!(FontFormat::TrueType.as_str(), "ttf");
+        assert_eq!(FontFormat::OpenTypeCff.as_str(), "otf");
+        assert_eq!(FontFormat::Woff.as_str(), "woff");
+        assert_eq!(FontFormat::Woff2.as_str(), "woff2");
+    }
+
     /// Edge case tests for font format detection
 
     #[test]
@@ -1,8 +1,8 @@
 //! Font detection and classification utilities
 //!
 //! This module provides font requirement extraction from TSX documents.
-//! It scans JSX elements for font-family declarations and classifies fonts into
-//! Google Fonts, web-safe fonts, or custom fonts.
+//! It scans inline styles and `<style>` blocks for font-family declarations and
+//! classifies fonts into Google Fonts, web-safe fonts, or custom fonts.
 
 use std::collections::HashSet;
 
@@ -63,7 +63,9 @@ const GOOGLE_FONTS: &[&str] = &[
 
 /// Extract font requirements from parsed TSX document
 ///
-/// Scans the document for CSS font-family declarations and determines:
+/// Scans inline styles and the text of `<style>` elements (rules and
+/// `@font-face` blocks, see [`extract_fonts_from_stylesheet`]) for CSS
+/// font-family declarations and determines:
 /// - Font family name
 /// - Font weight (from font-weight or element semantics)
 /// - Font style (normal or italic)
@@ -87,25 +89,46 @@ pub fn extract_font_requirements(
     let jsx_elements = tsx_parser::extract_jsx_elements(document);
 
     // Scan all elements for font-family declarations
+    let mut fonts = Vec::new();
+    let mut font_faces = Vec::new();
     for element in jsx_elements {
         // Extract inline styles: style="font-family: Roboto" or style={{ fontFamily: 'Roboto' }}
         if let Some(style_str) = tsx_parser::extract_inline_style(element) {
-            // Parse CSS properties from style string
-            let fonts = extract_fonts_from_css(&style_str);
-            for (family, weight, style) in fonts {
-                // Classify font source
-                let source = classify_font_source(&family);
-
-                let key = format!("{}:{}:{}", family, weight, style);
-                if seen.insert(key) {
-                    requirements.push(FontRequirement {
-                        family,
-                        weight,
-                        style,
-                        source,
-                    });
-                }
-            }
+            fonts.extend(extract_fonts_from_css(&style_str));
+        }
+
+        // Stylesheets: <style>{`h1 { font-family: Merriweather }`}</style>
+        if tsx_parser::extract_element_name(element) == "style" {
+            let css = tsx_parser::extract_text_content(element).join("\n");
+            let stylesheet = extract_fonts_from_stylesheet(&css);
+            fonts.extend(stylesheet.used);
+            font_faces.extend(stylesheet.font_faces);
+        }
+    }
+
+    // @font-face variants only matter for families something actually uses
+    let used_families: HashSet<String> = fonts
+        .iter()
+        .map(|(family, _, _)| family.to_lowercase())
+        .collect();
+    fonts.extend(
+        font_faces
+            .into_iter()
+            .filter(|(family, _, _)| used_families.contains(&family.to_lowercase())),
+    );
+
+    for (family, weight, style) in fonts {
+        // Classify font source
+        let source = classify_font_source(&family);
+
+        let key = format!("{}:{}:{}", family, weight, style);
+        if seen.insert(key) {
+            requirements.push(FontRequirement {
+                family,
+                weight,
+                style,
+                source,
+            });
         }
     }
 
@@ -150,6 +173,89 @@ fn classify_font_source(family: &str) -> FontSource {
     }
 }
 
+/// Fonts found in a `<style>` block
+#[derive(Debug, Default, PartialEq)]
+pub struct StylesheetFonts {
+    /// Fonts referenced by `font-family` in ordinary rules
+    pub used: Vec<(String, u16, String)>,
+    /// Fonts declared by `@font-face` rules
+    pub font_faces: Vec<(String, u16, String)>,
+}
+
+/// Extract font-family declarations from a stylesheet
+///
+/// Splits the CSS into `selector { declarations }` blocks and reads each
+/// block with [`extract_fonts_from_css`]. Blocks under an `@font-face`
+/// prelude are reported separately so declared-but-unused faces can be pruned.
+/// Only the first family of a fallback list is kept
+/// (`Merriweather, serif` → `Merriweather`).
+///
+/// # Arguments
+/// * `css` - Stylesheet text (e.g., the content of a `<style>` element)
+///
+/// # Returns
+/// Used and `@font-face`-declared fonts as (family, weight, style) tuples
+pub fn extract_fonts_from_stylesheet(css: &str) -> StylesheetFonts {
+    let mut result = StylesheetFonts::default();
+    let css = strip_css_comments(css);
+
+    let mut rest = css.as_str();
+    while let Some(open) = rest.find('{') {
+        let prelude = rest[..open].trim();
+        let after_open = &rest[open + 1..];
+
+        // Group rules (@media) nest blocks: descend into their body
+        if prelude.starts_with("@media") || prelude.starts_with("@supports") {
+            rest = after_open;
+            continue;
+        }
+
+        let Some(close) = after_open.find('}') else {
+            break;
+        };
+        let declarations = &after_open[..close];
+        rest = after_open[close + 1..].trim_start_matches(|c: char| c == '}' || c.is_whitespace());
+
+        let fonts = extract_fonts_from_css(declarations)
+            .into_iter()
+            .map(|(family, weight, style)| (primary_family(&family), weight, style))
+            .filter(|(family, _, _)| !family.is_empty());
+        if prelude.eq_ignore_ascii_case("@font-face") {
+            result.font_faces.extend(fonts);
+        } else {
+            result.used.extend(fonts);
+        }
+    }
+
+    result
+}
+
+/// Remove `/* ... */` comments from CSS
+fn strip_css_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = match rest[start + 2..].find("*/") {
+            Some(end) => &rest[start + 2 + end + 2..],
+            None => "",
+        };
+    }
+    out.push_str(rest);
+    out
+}
+
+/// First family of a `font-family` fallback list, unquoted
+fn primary_family(family: &str) -> String {
+    family
+        .split(',')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string()
+}
+
 /// Extract font-family declarations from CSS string
 ///
 /// Parses CSS properties and extracts font-family, font-weight, and font-style.
@@ -393,6 +499,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_fonts_from_stylesheet_rules_and_font_face() {
+        let css = r#"
+            /* Headings */
+            @font-face {
+                font-family: "Merriweather";
+                font-weight: 700;
+                src: url(merriweather-bold.woff2);
+            }
+            h1 { font-family: 'Merriweather', serif; font-weight: 700 }
+            @media print {
+                p { font-family: Lato }
+            }
+        "#;
+        let fonts = extract_fonts_from_stylesheet(css);
+        assert_eq!(
+            fonts.used,
+            vec![
+                ("Merriweather".to_string(), 700, "normal".to_string()),
+                ("Lato".to_string(), 400, "normal".to_string()),
+            ]
+        );
+        assert_eq!(
+            fonts.font_faces,
+            vec![("Merriweather".to_string(), 700, "normal".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_extract_font_requirements_from_style_block() {
+        let tsx = r#"
+            const CV = () => (
+                <div>
+                    <style>{`
+                        @font-face { font-family: "Merriweather"; font-style: italic; }
+                        @font-face { font-family: "Unused Display"; }
+                        .name { font-family: Merriweather, Georgia, serif; }
+                    `}</style>
+                    <h1 className="name">Jane Doe</h1>
+                </div>
+            );
+        "#;
+        let document = tsx_parser::parse_tsx(tsx).unwrap();
+        let requirements = extract_font_requirements(&document).unwrap();
+
+        let merriweather: Vec<_> = requirements
+            .iter()
+            .filter(|r| r.family == "Merriweather")
+            .collect();
+        assert_eq!(merriweather.len(), 2, "regular use plus italic face");
+        assert!(merriweather
+            .iter()
+            .all(|r| matches!(r.source, FontSource::Google)));
+        assert!(merriweather.iter().any(|r| r.style == "italic"));
+
+        // Declared but never used
+        assert!(!requirements.iter().any(|r| r.family == "Unused Display"));
+    }
+
     #[test]
     fn test_classify_font_source_case_insensitive() {
         // Test case insensitivity for web-safe fonts
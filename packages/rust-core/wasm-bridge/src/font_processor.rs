@@ -11,6 +11,7 @@
 use std::collections::HashMap;
 
 use crate::converter::FontCollection;
+use crate::debug_log;
 use crate::font_detection::extract_font_requirements;
 use crate::validation::is_valid_font_format;
 use tsx_parser::TsxDocument;
@@ -60,26 +61,35 @@ impl FontProcessor {
     /// Validates each font and creates a HashMap with keys in format:
     /// "family:weight:is_italic" -> font_bytes
     ///
+    /// Fonts failing validation are left out (their family falls back to
+    /// Standard 14) and reported as warnings instead of failing the conversion.
+    ///
     /// # Arguments
     /// * `font_collection` - FontCollection containing font data
     ///
     /// # Returns
-    /// HashMap of font keys to font bytes, or error if validation fails
+    /// HashMap of font keys to valid font bytes, and one warning per rejected font
     pub fn build_font_collection(
         &self,
         font_collection: &FontCollection,
-    ) -> Result<HashMap<String, Vec<u8>>, String> {
+    ) -> (HashMap<String, Vec<u8>>, Vec<String>) {
         let mut font_bytes_map = HashMap::new();
+        let mut warnings = Vec::new();
 
         for font in font_collection.fonts_internal() {
+            let key = format!("{}:{}:{}", font.family(), font.weight(), font.is_italic());
+
             // Validate font bytes before using
-            self.validate_font_data(font.bytes_internal(), &font.family())?;
+            if let Err(e) = self.validate_font_data(font.bytes_internal(), &font.family()) {
+                debug_log!("[FontProcessor] Skipping {}: {}", key, e);
+                warnings.push(format!("{} ({}). Falling back to Standard 14.", e, key));
+                continue;
+            }
 
-            let key = format!("{}:{}:{}", font.family(), font.weight(), font.is_italic());
             font_bytes_map.insert(key, font.bytes_internal().to_vec());
         }
 
-        Ok(font_bytes_map)
+        (font_bytes_map, warnings)
     }
 }
 
@@ -243,9 +253,34 @@ mod tests {
         let processor = FontProcessor::new();
         let empty_collection = FontCollection::new();
 
-        let result = processor.build_font_collection(&empty_collection);
-        assert!(result.is_ok());
-        let map = result.unwrap();
+        let (map, warnings) = processor.build_font_collection(&empty_collection);
         assert_eq!(map.len(), 0);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_build_font_collection_skips_invalid_font() {
+        use crate::converter::{FontCollection, FontData};
+
+        let processor = FontProcessor::new();
+        let mut collection = FontCollection::new();
+        collection.add(FontData::new(
+            "Roboto".to_string(),
+            400,
+            false,
+            vec![0x00, 0x01, 0x00, 0x00, 0xFF],
+        ));
+        collection.add(FontData::new(
+            "Roboto".to_string(),
+            700,
+            false,
+            vec![0xDE, 0xAD, 0xBE, 0xEF],
+        ));
+
+        let (map, warnings) = processor.build_font_collection(&collection);
+        assert!(map.contains_key("Roboto:400:false"));
+        assert!(!map.contains_key("Roboto:700:false"));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Roboto:700:false"));
     }
 }
@@ -0,0 +1,184 @@
+//! Layout cache for repeated conversions
+//!
+//! Live preview converts the same TSX over and over. The cache keeps the most
+//! recently computed layouts keyed by a hash of the TSX source and the
+//! caller's PDF config, so an identical re-conversion skips parsing, metadata
+//! extraction and layout and goes straight to PDF generation.
+//!
+//! Only the names of the supplied fonts are part of the key: layout is
+//! measured with the built-in PDF font metrics, but fonts missing from the
+//! collection are substituted before layout.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+use layout_engine::LayoutStructure;
+use pdf_generator::{FontSubstitution, PDFConfig};
+
+/// Number of layouts kept before the least recently used one is evicted
+pub const LAYOUT_CACHE_CAPACITY: usize = 4;
+
+/// A computed layout together with the config enriched from CV metadata
+#[derive(Debug)]
+pub struct CachedLayout {
+    pub layout: LayoutStructure,
+    pub config: PDFConfig,
+    /// Scale applied to fit `fit_to_pages` (1.0 = natural size)
+    pub scale: f64,
+    /// Requested fonts substituted while computing the layout
+    pub font_substitutions: Vec<FontSubstitution>,
+}
+
+/// Small least-recently-used cache of computed layouts
+#[derive(Debug)]
+pub struct LayoutCache {
+    /// Entries ordered from least to most recently used
+    entries: VecDeque<(u64, Rc<CachedLayout>)>,
+    capacity: usize,
+    hits: usize,
+    misses: usize,
+}
+
+impl LayoutCache {
+    /// Create an empty cache holding up to `capacity` layouts
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Cache key for a TSX source, its config and the fonts supplied with it
+    ///
+    /// Returns `None` if the config cannot be serialized; such conversions
+    /// are simply not cached.
+    pub fn key(
+        tsx: &str,
+        config: &PDFConfig,
+        font_bytes: &HashMap<String, Vec<u8>>,
+    ) -> Option<u64> {
+        let config_json = serde_json::to_string(config).ok()?;
+        let mut font_keys: Vec<&String> = font_bytes.keys().collect();
+        font_keys.sort();
+        let mut hasher = DefaultHasher::new();
+        tsx.hash(&mut hasher);
+        config_json.hash(&mut hasher);
+        font_keys.hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
+    /// Look up a layout, marking it most recently used
+    pub fn get(&mut self, key: u64) -> Option<Rc<CachedLayout>> {
+        let Some(index) = self.entries.iter().position(|(k, _)| *k == key) else {
+            self.misses += 1;
+            return None;
+        };
+        self.hits += 1;
+        let entry = self.entries.remove(index)?;
+        let layout = Rc::clone(&entry.1);
+        self.entries.push_back(entry);
+        Some(layout)
+    }
+
+    /// Store a layout, evicting the least recently used entry when full
+    pub fn insert(&mut self, key: u64, layout: Rc<CachedLayout>) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|(k, _)| *k != key);
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((key, layout));
+    }
+
+    /// Number of lookups answered from the cache
+    #[cfg(test)]
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of lookups that required computing a layout
+    #[cfg(test)]
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Number of cached layouts
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Drop all cached layouts
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl Default for LayoutCache {
+    fn default() -> Self {
+        Self::new(LAYOUT_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use layout_engine::Page;
+
+    fn cached(page_count: usize) -> Rc<CachedLayout> {
+        let pages = (1..=page_count)
+            .map(|page_number| Page::new(page_number, Vec::new()))
+            .collect();
+        Rc::new(CachedLayout {
+            layout: LayoutStructure {
+                page_width: 612.0,
+                page_height: 792.0,
+                pages,
+            },
+            config: PDFConfig::default(),
+            scale: 1.0,
+            font_substitutions: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn test_key_depends_on_tsx_config_and_fonts() {
+        let config = PDFConfig::default();
+        let fonts = HashMap::new();
+        let key = LayoutCache::key("<div/>", &config, &fonts);
+        assert_eq!(key, LayoutCache::key("<div/>", &config, &fonts));
+        assert_ne!(key, LayoutCache::key("<p/>", &config, &fonts));
+
+        let compressed = PDFConfig {
+            compress_content_streams: !config.compress_content_streams,
+            ..PDFConfig::default()
+        };
+        assert_ne!(key, LayoutCache::key("<div/>", &compressed, &fonts));
+
+        let roboto = HashMap::from([("Roboto:400:false".to_string(), vec![0u8; 4])]);
+        assert_ne!(key, LayoutCache::key("<div/>", &config, &roboto));
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = LayoutCache::new(2);
+        cache.insert(1, cached(1));
+        cache.insert(2, cached(2));
+
+        // Touch 1 so that 2 becomes the eviction candidate
+        assert!(cache.get(1).is_some());
+        cache.insert(3, cached(3));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(2).is_none());
+        assert_eq!(cache.get(1).unwrap().layout.pages.len(), 1);
+        assert_eq!(cache.get(3).unwrap().layout.pages.len(), 3);
+        assert_eq!((cache.hits(), cache.misses()), (3, 1));
+    }
+}
@@ -176,15 +176,19 @@ mod metadata_bridge;
 mod validation;
 
 mod font_processor;
+mod layout_cache;
 mod pipeline_orchestrator;
 
 // Type conversion macros
 #[macro_use]
 mod macros;
 mod progress;
+mod timing;
 
 pub use converter::{FontCollection, FontData, TsxToPdfConverter};
-pub use font_bridge::{decompress_woff2_font, decompress_woff_font, detect_font_format};
+pub use font_bridge::{
+    decompress_woff2_font, decompress_woff_font, detect_font_format, FontFormat,
+};
 pub use metadata_bridge::{extract_cv_metadata, CVMetadata, FontComplexity, LayoutType};
 
 // WASM initialization
@@ -8,12 +8,13 @@ use wasm_bindgen::prelude::*;
 
 // Import from cv_domain
 use cv_domain::{
-    CVMetadata as DomainCVMetadata, FontComplexity as DomainFontComplexity,
-    LayoutType as DomainLayoutType,
+    CVMetadata as DomainCVMetadata, Certification, ExperienceEntry,
+    FontComplexity as DomainFontComplexity, LayoutType as DomainLayoutType,
 };
 use tsx_parser::parse_tsx;
 
 use crate::error::create_error;
+use crate::to_js;
 
 /// Layout type detected in CV
 #[wasm_bindgen]
@@ -85,6 +86,9 @@ pub struct CVMetadata {
     phone: Option<String>,
     location: Option<String>,
     website: Option<String>,
+    skills: Vec<String>,
+    experience: Vec<ExperienceEntry>,
+    certifications: Vec<Certification>,
 
     // Document Characteristics
     layout_type: LayoutType,
@@ -93,8 +97,16 @@ pub struct CVMetadata {
 
     // ATS Optimization Hints
     has_contact_info: bool,
+    contact_in_sidebar: bool,
+    chronological_order: Option<bool>,
+    duplicate_sections: Vec<String>,
     has_clear_sections: bool,
     font_complexity: FontComplexity,
+
+    // Length
+    word_count: usize,
+    char_count: usize,
+    reading_time_seconds: usize,
 }
 
 #[wasm_bindgen]
@@ -131,6 +143,23 @@ impl CVMetadata {
         self.website.clone()
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn skills(&self) -> Vec<String> {
+        self.skills.clone()
+    }
+
+    /// Experience entries as `{ title, organization, date_range }` objects
+    #[wasm_bindgen(getter)]
+    pub fn experience(&self) -> Result<JsValue, JsValue> {
+        Ok(to_js!(self.experience))
+    }
+
+    /// Certifications as `{ name, year }` objects (`year` is null when not stated)
+    #[wasm_bindgen(getter)]
+    pub fn certifications(&self) -> Result<JsValue, JsValue> {
+        Ok(to_js!(self.certifications))
+    }
+
     #[wasm_bindgen(getter)]
     pub fn layout_type(&self) -> LayoutType {
         self.layout_type
@@ -151,6 +180,21 @@ impl CVMetadata {
         self.has_contact_info
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn contact_in_sidebar(&self) -> bool {
+        self.contact_in_sidebar
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn chronological_order(&self) -> Option<bool> {
+        self.chronological_order
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn duplicate_sections(&self) -> Vec<String> {
+        self.duplicate_sections.clone()
+    }
+
     #[wasm_bindgen(getter)]
     pub fn has_clear_sections(&self) -> bool {
         self.has_clear_sections
@@ -160,6 +204,21 @@ impl CVMetadata {
     pub fn font_complexity(&self) -> FontComplexity {
         self.font_complexity
     }
+
+    #[wasm_bindgen(getter)]
+    pub fn word_count(&self) -> usize {
+        self.word_count
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn char_count(&self) -> usize {
+        self.char_count
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn reading_time_seconds(&self) -> usize {
+        self.reading_time_seconds
+    }
 }
 
 impl From<DomainCVMetadata> for CVMetadata {
@@ -171,12 +230,21 @@ impl From<DomainCVMetadata> for CVMetadata {
             phone: domain.phone,
             location: domain.location,
             website: domain.website,
+            skills: domain.skills,
+            experience: domain.experience,
+            certifications: domain.certifications,
             layout_type: domain.layout_type.into(),
             estimated_pages: domain.estimated_pages,
             component_count: domain.component_count,
             has_contact_info: domain.has_contact_info,
+            contact_in_sidebar: domain.contact_in_sidebar,
+            chronological_order: domain.chronological_order,
+            duplicate_sections: domain.duplicate_sections,
             has_clear_sections: domain.has_clear_sections,
             font_complexity: domain.font_complexity.into(),
+            word_count: domain.word_count,
+            char_count: domain.char_count,
+            reading_time_seconds: domain.reading_time_seconds,
         }
     }
 }
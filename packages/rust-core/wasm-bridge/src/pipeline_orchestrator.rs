@@ -7,24 +7,38 @@
 //! - Pipeline stage execution (Parse → Extract → Render → Layout → Generate)
 //! - Progress tracking integration
 //! - Error handling and metadata enrichment
-//! - Timing and performance logging
+//! - Timing and performance logging (per-stage timings attached to errors)
+
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use serde_json::json;
 use wasm_bindgen::prelude::*;
 
 use cv_domain::{extract_metadata, extract_tsx_layout_config_from_document, CVMetadata};
-use layout_engine::{calculate_layout_direct, LayoutStructure};
-use pdf_generator::{PDFConfig, PDFGenerator};
+use layout_engine::{fit_layout_direct_with_fonts, LayoutError, LayoutStructure, Page};
+use pdf_generator::font_substitution::{has_font_data, synthesizes_bold};
+use pdf_generator::fonts::SyntheticBoldMeasurer;
+use pdf_generator::{
+    generate_within_target, FontSubstitution, PDFConfig, PDFError, PDFGenerator, SizeReport,
+};
 use tsx_parser::{parse_tsx, ParseError, TsxDocument};
 
 use crate::debug_log;
-use crate::error::{create_error, create_error_with_metadata};
+use crate::error::{attach_timings, create_error, create_error_with_metadata};
+use crate::layout_cache::{CachedLayout, LayoutCache};
 use crate::progress::{ProgressTracker, Stage};
+use crate::timing::{now_ms, StageTimings};
 use crate::validation::enrich_pdf_config_with_metadata;
 
 /// Pipeline orchestrator for managing the conversion pipeline
 pub struct PipelineOrchestrator {
     progress: ProgressTracker,
+    timings: RefCell<StageTimings>,
+    warnings: RefCell<Vec<String>>,
+    size_report: RefCell<Option<SizeReport>>,
+    font_substitutions: RefCell<Vec<FontSubstitution>>,
+    content_scale: RefCell<f64>,
 }
 
 impl PipelineOrchestrator {
@@ -35,29 +49,192 @@ impl PipelineOrchestrator {
     pub fn new(progress_callback: Option<js_sys::Function>) -> Self {
         Self {
             progress: ProgressTracker::new(progress_callback),
+            timings: RefCell::new(StageTimings::new()),
+            warnings: RefCell::new(Vec::new()),
+            size_report: RefCell::new(None),
+            font_substitutions: RefCell::new(Vec::new()),
+            content_scale: RefCell::new(1.0),
+        }
+    }
+
+    /// Create a pipeline orchestrator reporting to an existing progress tracker
+    #[cfg(test)]
+    pub fn with_progress(progress: ProgressTracker) -> Self {
+        Self {
+            progress,
+            timings: RefCell::new(StageTimings::new()),
+            warnings: RefCell::new(Vec::new()),
+            size_report: RefCell::new(None),
+            font_substitutions: RefCell::new(Vec::new()),
+            content_scale: RefCell::new(1.0),
         }
     }
 
-    /// Execute the full conversion pipeline
+    /// Per-stage timings recorded by the most recent pipeline run
+    ///
+    /// Keys are the stage names reported to the progress callback
+    /// (`parsing`, `extracting-metadata`, `extracting-layout`, `laying-out`,
+    /// `generating-pdf`); values are elapsed milliseconds.
+    pub fn timings(&self) -> StageTimings {
+        self.timings.borrow().clone()
+    }
+
+    /// Non-fatal problems collected by this orchestrator
+    ///
+    /// Includes warnings passed in with [`Self::add_warnings`] (e.g. fonts
+    /// rejected during validation) and fonts that failed to embed during PDF
+    /// generation; each affected font fell back to Standard 14.
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.borrow().clone()
+    }
+
+    /// Size outcome of the most recent run with `target_max_bytes` set
+    ///
+    /// Lists the achieved size and the reductions applied to reach it.
+    pub fn size_report(&self) -> Option<SizeReport> {
+        self.size_report.borrow().clone()
+    }
+
+    /// Requested fonts missing from the collection, with what replaced them
+    ///
+    /// Filled during layout according to the config's `missing_font_policy`.
+    pub fn font_substitutions(&self) -> Vec<FontSubstitution> {
+        self.font_substitutions.borrow().clone()
+    }
+
+    /// Scale applied to font sizes and spacing by the most recent run
+    ///
+    /// Below 1.0 when `fit_to_pages` shrank the content to fit; 1.0 otherwise.
+    pub fn content_scale(&self) -> f64 {
+        *self.content_scale.borrow()
+    }
+
+    /// Record non-fatal problems found before the pipeline runs
+    pub fn add_warnings(&self, warnings: impl IntoIterator<Item = String>) {
+        self.warnings.borrow_mut().extend(warnings);
+    }
+
+    /// Execute the full conversion pipeline, reusing a cached layout when possible
     ///
     /// # Arguments
     /// * `tsx` - TSX source code
     /// * `config` - PDF configuration
     /// * `font_bytes_map` - HashMap of font keys to font bytes
+    /// * `cache` - Layouts of earlier runs; `None` always runs every stage
     ///
     /// # Returns
-    /// PDF bytes or error with stage information
-    pub fn execute_pipeline(
+    /// PDF bytes or error with stage information. Errors carry the stage
+    /// timings recorded so far in `metadata.timings`; on success they are
+    /// available from [`Self::timings`].
+    ///
+    /// On a cache hit the parse, metadata, layout config and layout stages are
+    /// skipped (and absent from [`Self::timings`]); only PDF generation runs.
+    /// On a miss the computed layout is stored for the next identical call.
+    pub fn execute_pipeline_cached(
         &self,
         tsx: &str,
         config: PDFConfig,
         font_bytes_map: std::collections::HashMap<String, Vec<u8>>,
+        cache: Option<&RefCell<LayoutCache>>,
     ) -> Result<Vec<u8>, JsValue> {
-        let start_time = js_sys::Date::now();
+        let start_time = now_ms();
+        *self.timings.borrow_mut() = StageTimings::new();
+        self.font_substitutions.borrow_mut().clear();
+        *self.content_scale.borrow_mut() = 1.0;
 
         // Check TSX size limit
-        self.check_tsx_size(tsx)?;
+        self.check_tsx_size(tsx)
+            .map_err(|e| attach_timings(e, &self.timings.borrow()))?;
+
+        let cache_key = cache.and_then(|_| LayoutCache::key(tsx, &config, &font_bytes_map));
+        let cached = cache
+            .zip(cache_key)
+            .and_then(|(cache, key)| cache.borrow_mut().get(key));
+        if let Some(cached) = cached {
+            debug_log!("♻️  Layout cache hit, skipping to PDF generation");
+            *self.content_scale.borrow_mut() = cached.scale;
+            self.record_font_substitutions(&cached.font_substitutions);
+            let (pdf_bytes, _pdf_time) = self.generate_pdf_stage(
+                tsx,
+                &cached.layout,
+                cached.config.clone(),
+                font_bytes_map,
+            )?;
+            self.progress.report_stage(Stage::Completed)?;
+            debug_log!("⏱️  TOTAL: {:.2}ms", now_ms() - start_time);
+            return Ok(pdf_bytes);
+        }
+
+        // Stages 1-4: Parse, metadata, layout config and layout
+        let (layout, config) = self.layout_stages(tsx, config, &font_bytes_map)?;
 
+        // Stage 5: Generate PDF (80%)
+        let (pdf_bytes, _pdf_time) =
+            self.generate_pdf_stage(tsx, &layout, config.clone(), font_bytes_map)?;
+
+        // Only successful conversions are cached
+        if let Some((cache, key)) = cache.zip(cache_key) {
+            cache.borrow_mut().insert(
+                key,
+                Rc::new(CachedLayout {
+                    layout,
+                    config,
+                    scale: self.content_scale(),
+                    font_substitutions: self.font_substitutions(),
+                }),
+            );
+        }
+
+        // Stage 6: Complete (100%)
+        self.progress.report_stage(Stage::Completed)?;
+
+        let _total_time = now_ms() - start_time;
+        debug_log!("⏱️  TOTAL: {:.2}ms", _total_time);
+        debug_log!(
+            "📊 Stage timings (ms): {}",
+            serde_json::to_string(&*self.timings.borrow()).unwrap_or_default()
+        );
+
+        Ok(pdf_bytes)
+    }
+
+    /// Execute the conversion pipeline, producing one PDF per page
+    ///
+    /// The layout is paginated once, then each page is rendered as its own
+    /// complete single-page document with the fonts that page uses embedded.
+    /// `target_max_bytes` applies to each document separately;
+    /// [`Self::size_report`] describes the largest one.
+    pub fn execute_pipeline_pages(
+        &self,
+        tsx: &str,
+        config: PDFConfig,
+        font_bytes_map: std::collections::HashMap<String, Vec<u8>>,
+    ) -> Result<Vec<Vec<u8>>, JsValue> {
+        *self.timings.borrow_mut() = StageTimings::new();
+        self.font_substitutions.borrow_mut().clear();
+        *self.content_scale.borrow_mut() = 1.0;
+
+        self.check_tsx_size(tsx)
+            .map_err(|e| attach_timings(e, &self.timings.borrow()))?;
+
+        let (layout, config) = self.layout_stages(tsx, config, &font_bytes_map)?;
+        let (documents, _pdf_time) =
+            self.generate_page_pdfs_stage(tsx, &layout, config, font_bytes_map)?;
+
+        self.progress.report_stage(Stage::Completed)?;
+        Ok(documents)
+    }
+
+    /// Stages 1-4: parse, extract metadata and layout config, calculate layout
+    ///
+    /// Returns the layout with the config enriched from CV metadata. Fonts
+    /// missing from `font_bytes_map` are resolved before text is measured.
+    fn layout_stages(
+        &self,
+        tsx: &str,
+        config: PDFConfig,
+        font_bytes_map: &std::collections::HashMap<String, Vec<u8>>,
+    ) -> Result<(LayoutStructure, PDFConfig), JsValue> {
         // Stage 1: Parse TSX (10%)
         let (document, _parse_time) = self.parse_tsx_stage(tsx)?;
 
@@ -71,26 +248,15 @@ impl PipelineOrchestrator {
         let (layout_config, _extract_layout_time) = self.extract_layout_config_stage(&document)?;
 
         // Stage 4: Calculate layout directly (60%)
-        let (layout, _layout_time) =
-            self.calculate_layout_direct_stage(&document, &metadata, &layout_config, &config)?;
-
-        // Stage 5: Generate PDF (80%)
-        let (pdf_bytes, _pdf_time) = self.generate_pdf_stage(&layout, config, font_bytes_map)?;
-
-        // Stage 6: Complete (100%)
-        self.progress.report_stage(Stage::Completed)?;
-
-        let _total_time = js_sys::Date::now() - start_time;
-        debug_log!("⏱️  TOTAL: {:.2}ms", _total_time);
-        debug_log!("📊 Performance Breakdown: Parse={:.0}% Metadata={:.0}% ExtractLayout={:.0}% Layout={:.0}% PDF={:.0}%",
-            (_parse_time / _total_time * 100.0),
-            (_extract_time / _total_time * 100.0),
-            (_extract_layout_time / _total_time * 100.0),
-            (_layout_time / _total_time * 100.0),
-            (_pdf_time / _total_time * 100.0)
-        );
-
-        Ok(pdf_bytes)
+        let (layout, _layout_time) = self.calculate_layout_direct_stage(
+            &document,
+            &metadata,
+            &layout_config,
+            &config,
+            font_bytes_map,
+        )?;
+
+        Ok((layout, config))
     }
 
     /// Execute a pipeline stage with automatic progress reporting and timing
@@ -99,7 +265,7 @@ impl PipelineOrchestrator {
     /// 1. Report stage to progress tracker
     /// 2. Start timing
     /// 3. Execute operation
-    /// 4. Log elapsed time
+    /// 4. Record and log elapsed time (attaching timings to any error)
     /// 5. Return result with timing
     ///
     /// # Type Parameters
@@ -123,14 +289,18 @@ impl PipelineOrchestrator {
         F: FnOnce() -> Result<T, JsValue>,
     {
         self.progress.report_stage(stage)?;
-        let stage_start = js_sys::Date::now();
+        let stage_start = now_ms();
 
-        let result = operation()?;
+        let result = operation();
 
-        let elapsed = js_sys::Date::now() - stage_start;
+        let elapsed = now_ms() - stage_start;
+        self.timings.borrow_mut().record(stage, elapsed);
         debug_log!("⏱️  {}: {:.2}ms", label, elapsed);
 
-        Ok((result, elapsed))
+        match result {
+            Ok(value) => Ok((value, elapsed)),
+            Err(e) => Err(attach_timings(e, &self.timings.borrow())),
+        }
     }
 
     /// Check TSX size limit (5MB)
@@ -197,72 +367,231 @@ impl PipelineOrchestrator {
         metadata: &CVMetadata,
         layout_config: &cv_domain::TSXLayoutConfig,
         config: &PDFConfig,
+        font_bytes_map: &std::collections::HashMap<String, Vec<u8>>,
     ) -> Result<(LayoutStructure, f64), JsValue> {
         self.with_stage(Stage::LayingOut, "Calculate Layout", || {
-            let measurer = pdf_generator::fonts::PDFTextMeasurer;
-            calculate_layout_direct(document, metadata, layout_config, config, &measurer).map_err(
-                |e| {
-                    create_error(
-                        "PDF_LAYOUT_ERROR",
-                        &format!("PDF layout calculation failed: {}", e),
-                        "laying-out",
-                        true,
-                    )
-                },
+            // Bold faces drawn from a regular face render wider; measure them so
+            let measurer = SyntheticBoldMeasurer::new(|font_name: &str| {
+                synthesizes_bold(font_bytes_map, font_name)
+            });
+            // Pagination is the longest phase for long CVs; report it per page
+            let report_pagination = |progress_percent: f32| {
+                let sub_progress = (progress_percent / 100.0) as f64;
+                let _ = self
+                    .progress
+                    .report_percentage(Stage::Paginating, sub_progress);
+            };
+            let is_supplied = |family: &str, weight: u16, is_italic: bool| {
+                has_font_data(font_bytes_map, family, weight, is_italic)
+            };
+            let fitted = fit_layout_direct_with_fonts(
+                document,
+                metadata,
+                layout_config,
+                config,
+                &measurer,
+                &is_supplied,
+                Some(&report_pagination),
             )
+            .map_err(|e| match e {
+                // A missing font under `MissingFontPolicy::Error`
+                LayoutError::FontError(message) => {
+                    create_error("FONT_LOAD_ERROR", &message, "laying-out", true)
+                }
+                e => create_error(
+                    "PDF_LAYOUT_ERROR",
+                    &format!("PDF layout calculation failed: {}", e),
+                    "laying-out",
+                    true,
+                ),
+            })?;
+            *self.content_scale.borrow_mut() = fitted.scale;
+            self.record_font_substitutions(&fitted.font_substitutions);
+            Ok(fitted.layout)
         })
     }
 
     /// Stage 5: Generate PDF
     fn generate_pdf_stage(
         &self,
+        tsx: &str,
         layout: &LayoutStructure,
         config: PDFConfig,
         font_bytes_map: std::collections::HashMap<String, Vec<u8>>,
     ) -> Result<(Vec<u8>, f64), JsValue> {
         self.with_stage(Stage::GeneratingPdf, "Generate PDF", || {
-            let mut generator = PDFGenerator::new(config.clone()).map_err(|e| {
-                create_error(
-                    "PDF_GENERATION_FAILED",
-                    &format!("Failed to initialize PDF generator: {}", e),
-                    "generating-pdf",
-                    true,
-                )
+            // Regenerates with size reductions while over target_max_bytes;
+            // only the returned PDF's font warnings are kept
+            let mut font_warnings = Vec::new();
+            let (pdf_bytes, size_report) = generate_within_target(&config, |config| {
+                let (pdf_bytes, warnings) =
+                    self.render_pdf(tsx, layout, config, font_bytes_map.clone(), (0, 1))?;
+                font_warnings = warnings;
+                Ok::<_, JsValue>(pdf_bytes)
             })?;
 
-            // Pass font bytes to generator
-            generator.set_font_bytes(font_bytes_map);
+            // Fonts that failed to embed were replaced, not fatal
+            self.add_warnings(font_warnings);
 
-            // Use progress tracker for per-page progress
-            generator
-                .render_layout_with_progress(
-                    layout,
-                    Some(&|progress_percent: f32| {
-                        // Calculate sub-progress within GeneratingPdf stage (60-80%)
-                        let sub_progress = (progress_percent / 100.0) as f64;
-                        let _ = self
-                            .progress
-                            .report_percentage(Stage::GeneratingPdf, sub_progress);
-                    }),
-                )
-                .map_err(|e| {
-                    create_error(
-                        "PDF_GENERATION_FAILED",
-                        &format!("Failed to render PDF content: {}", e),
-                        "generating-pdf",
-                        true,
-                    )
+            if let Some(report) = size_report.as_ref().filter(|report| !report.met) {
+                self.add_warnings([format!(
+                    "PDF is {} bytes, above the {}-byte target even after all size reductions",
+                    report.achieved_bytes, report.target_bytes
+                )]);
+            }
+            *self.size_report.borrow_mut() = size_report;
+
+            Ok(pdf_bytes)
+        })
+    }
+
+    /// Stage 5 for per-page output: generate one single-page PDF per page
+    fn generate_page_pdfs_stage(
+        &self,
+        tsx: &str,
+        layout: &LayoutStructure,
+        config: PDFConfig,
+        font_bytes_map: std::collections::HashMap<String, Vec<u8>>,
+    ) -> Result<(Vec<Vec<u8>>, f64), JsValue> {
+        self.with_stage(Stage::GeneratingPdf, "Generate page PDFs", || {
+            let page_count = layout.pages.len();
+            let mut documents = Vec::with_capacity(page_count);
+            let mut font_warnings: Vec<String> = Vec::new();
+            let mut largest: Option<SizeReport> = None;
+
+            for (index, page) in layout.pages.iter().enumerate() {
+                // Each document is a standalone page 1 (bookmarks, page labels)
+                let page_layout = LayoutStructure {
+                    page_width: layout.page_width,
+                    page_height: layout.page_height,
+                    pages: vec![Page {
+                        page_number: 1,
+                        ..page.clone()
+                    }],
+                };
+
+                let mut page_warnings = Vec::new();
+                let (pdf_bytes, size_report) = generate_within_target(&config, |config| {
+                    let (pdf_bytes, warnings) = self.render_pdf(
+                        tsx,
+                        &page_layout,
+                        config,
+                        font_bytes_map.clone(),
+                        (index, page_count),
+                    )?;
+                    page_warnings = warnings;
+                    Ok::<_, JsValue>(pdf_bytes)
                 })?;
 
-            generator.finalize().map_err(|e| {
-                create_error(
+                // Pages sharing a failed font would repeat the same warning
+                for warning in page_warnings {
+                    if !font_warnings.contains(&warning) {
+                        font_warnings.push(warning);
+                    }
+                }
+                if let Some(report) = size_report.filter(|report| {
+                    largest
+                        .as_ref()
+                        .is_none_or(|largest| report.achieved_bytes > largest.achieved_bytes)
+                }) {
+                    largest = Some(report);
+                }
+                documents.push(pdf_bytes);
+            }
+
+            self.add_warnings(font_warnings);
+
+            if let Some(report) = largest.as_ref().filter(|report| !report.met) {
+                self.add_warnings([format!(
+                    "Page PDF is {} bytes, above the {}-byte target even after all size reductions",
+                    report.achieved_bytes, report.target_bytes
+                )]);
+            }
+            *self.size_report.borrow_mut() = largest;
+
+            Ok(documents)
+        })
+    }
+
+    /// Render and finalize one PDF for `config`, with its font warnings
+    ///
+    /// `part` is the document's index and the number of documents rendered
+    /// in this stage, used to scale per-page progress.
+    fn render_pdf(
+        &self,
+        tsx: &str,
+        layout: &LayoutStructure,
+        config: &PDFConfig,
+        font_bytes_map: std::collections::HashMap<String, Vec<u8>>,
+        part: (usize, usize),
+    ) -> Result<(Vec<u8>, Vec<String>), JsValue> {
+        let mut generator = PDFGenerator::new(config.clone()).map_err(|e| {
+            create_error(
+                "PDF_GENERATION_FAILED",
+                &format!("Failed to initialize PDF generator: {}", e),
+                "generating-pdf",
+                true,
+            )
+        })?;
+
+        // Pass font bytes to generator
+        generator.set_font_bytes(font_bytes_map);
+
+        // Keep the source for the optional TSX attachment
+        if config.embed_source {
+            generator.set_source(tsx);
+        }
+
+        // Use progress tracker for per-page progress
+        generator
+            .render_layout_with_progress(
+                layout,
+                Some(&|progress_percent: f32| {
+                    // Calculate sub-progress within GeneratingPdf stage (60-80%)
+                    let (index, count) = part;
+                    let sub_progress =
+                        (index as f64 + (progress_percent / 100.0) as f64) / count as f64;
+                    let _ = self
+                        .progress
+                        .report_percentage(Stage::GeneratingPdf, sub_progress);
+                }),
+            )
+            .map_err(|e| match e {
+                // Font errors, e.g. a missing font under `MissingFontPolicy::Error`
+                PDFError::FontError(message) => {
+                    create_error("FONT_LOAD_ERROR", &message, "generating-pdf", true)
+                }
+                e => create_error(
                     "PDF_GENERATION_FAILED",
-                    &format!("Failed to finalize PDF: {}", e),
+                    &format!("Failed to render PDF content: {}", e),
                     "generating-pdf",
                     true,
-                )
-            })
-        })
+                ),
+            })?;
+
+        // Layouts resolve missing fonts already; this only repeats their reports
+        self.record_font_substitutions(generator.font_substitutions());
+
+        let font_warnings = generator.font_warnings().to_vec();
+        let pdf_bytes = generator.finalize().map_err(|e| {
+            create_error(
+                "PDF_GENERATION_FAILED",
+                &format!("Failed to finalize PDF: {}", e),
+                "generating-pdf",
+                true,
+            )
+        })?;
+        Ok((pdf_bytes, font_warnings))
+    }
+
+    /// Add substitutions not reported yet, keeping their order
+    fn record_font_substitutions(&self, found: &[FontSubstitution]) {
+        let mut substitutions = self.font_substitutions.borrow_mut();
+        for substitution in found {
+            if !substitutions.contains(substitution) {
+                substitutions.push(substitution.clone());
+            }
+        }
     }
 
     /// Handle parse errors with detailed metadata
@@ -305,6 +634,11 @@ impl PipelineOrchestrator {
 mod tests {
     use super::*;
 
+    /// Recorded duration of a stage in the last run, as reported to callers
+    fn stage_ms(orchestrator: &PipelineOrchestrator, stage: Stage) -> Option<f64> {
+        serde_json::to_value(orchestrator.timings()).ok()?[stage.as_str()].as_f64()
+    }
+
     #[test]
     fn test_pipeline_orchestrator_creation() {
         let orchestrator = PipelineOrchestrator::new(None);
@@ -319,6 +653,258 @@ mod tests {
         assert!(orchestrator.check_tsx_size(small_tsx).is_ok());
     }
 
+    #[test]
+    fn test_execute_pipeline_records_stage_timings() {
+        let orchestrator = PipelineOrchestrator::new(None);
+        let tsx = r#"
+            const CV = () => (
+                <div style="font-family: Helvetica; font-size: 12px; padding: 36px">
+                    <h1>Jane Doe</h1>
+                    <p>Software Engineer</p>
+                </div>
+            );
+        "#;
+
+        let pdf_bytes = orchestrator
+            .execute_pipeline_cached(tsx, PDFConfig::default(), Default::default(), None)
+            .unwrap_or_else(|_| panic!("pipeline should succeed"));
+        assert!(pdf_bytes.starts_with(b"%PDF"));
+
+        for stage in [
+            Stage::Parsing,
+            Stage::ExtractingMetadata,
+            Stage::ExtractingLayout,
+            Stage::LayingOut,
+            Stage::GeneratingPdf,
+        ] {
+            let elapsed = stage_ms(&orchestrator, stage)
+                .unwrap_or_else(|| panic!("missing timing for {}", stage.as_str()));
+            assert!(
+                elapsed >= 0.0,
+                "{} timing should be non-negative",
+                stage.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn test_execute_pipeline_reports_pagination_progress() {
+        use std::rc::Rc;
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&events);
+        let orchestrator = PipelineOrchestrator::with_progress(ProgressTracker::with_listener(
+            move |stage, pct| sink.borrow_mut().push((stage, pct)),
+        ));
+        let tsx = include_str!(
+            "../../../../test-fixtures/tsx-samples/multi-page/03-six-page-executive.tsx"
+        );
+
+        orchestrator
+            .execute_pipeline_cached(tsx, PDFConfig::default(), Default::default(), None)
+            .unwrap_or_else(|_| panic!("pipeline should succeed"));
+
+        let events = events.borrow();
+        let paginating: Vec<f64> = events
+            .iter()
+            .filter(|(stage, _)| *stage == Stage::Paginating)
+            .map(|(_, pct)| *pct)
+            .collect();
+        assert!(
+            paginating.len() > 1,
+            "expected per-page pagination events, got {:?}",
+            paginating
+        );
+        assert!(paginating.iter().all(|pct| (50.0..=60.0).contains(pct)));
+
+        // Pagination events sit between layout and PDF generation
+        let position = |stage| events.iter().position(|(s, _)| *s == stage).unwrap();
+        assert!(position(Stage::LayingOut) < position(Stage::Paginating));
+        assert!(position(Stage::Paginating) < position(Stage::GeneratingPdf));
+    }
+
+    #[test]
+    fn test_execute_pipeline_embeds_source_when_enabled() {
+        let orchestrator = PipelineOrchestrator::new(None);
+        let tsx = r#"const CV = () => <div><h1>Jane Doe</h1></div>;"#;
+        let config = PDFConfig {
+            embed_source: true,
+            ..Default::default()
+        };
+
+        let pdf_bytes = orchestrator
+            .execute_pipeline_cached(tsx, config, Default::default(), None)
+            .unwrap_or_else(|_| panic!("pipeline should succeed"));
+        let pdf_str = String::from_utf8_lossy(&pdf_bytes);
+        assert!(pdf_str.contains("/EmbeddedFiles"));
+        assert!(pdf_str.contains("(resume.tsx)"));
+
+        let pdf_bytes = orchestrator
+            .execute_pipeline_cached(tsx, PDFConfig::default(), Default::default(), None)
+            .unwrap_or_else(|_| panic!("pipeline should succeed"));
+        assert!(!String::from_utf8_lossy(&pdf_bytes).contains("/EmbeddedFiles"));
+    }
+
+    #[test]
+    fn test_execute_pipeline_reports_missed_size_target() {
+        let orchestrator = PipelineOrchestrator::new(None);
+        let tsx = r#"const CV = () => <div><h1>Jane Doe</h1></div>;"#;
+        let config = PDFConfig {
+            target_max_bytes: Some(1),
+            ..Default::default()
+        };
+
+        let pdf_bytes = orchestrator
+            .execute_pipeline_cached(tsx, config, Default::default(), None)
+            .unwrap_or_else(|_| panic!("a missed target still produces a PDF"));
+        let report = orchestrator.size_report().expect("target was set");
+        assert_eq!(report.achieved_bytes, pdf_bytes.len());
+        assert_eq!(
+            report.reductions[0],
+            pdf_generator::SizeReduction::CompressStreams
+        );
+        assert!(!report.met);
+        assert!(orchestrator
+            .warnings()
+            .iter()
+            .any(|w| w.contains("1-byte target")));
+    }
+
+    /// First text segment on a page, searching nested containers
+    fn first_text(boxes: &[layout_engine::LayoutBox]) -> Option<String> {
+        boxes.iter().find_map(|b| match &b.content {
+            layout_engine::BoxContent::Text(lines) => lines
+                .iter()
+                .flat_map(|line| &line.segments)
+                .map(|segment| segment.text.trim())
+                .find(|text| !text.is_empty())
+                .map(str::to_string),
+            layout_engine::BoxContent::Container(children) => first_text(children),
+            layout_engine::BoxContent::Empty => None,
+        })
+    }
+
+    #[test]
+    fn test_execute_pipeline_pages_produces_one_pdf_per_page() {
+        let orchestrator = PipelineOrchestrator::new(None);
+        let tsx = include_str!(
+            "../../../../test-fixtures/tsx-samples/multi-page/02-three-page-academic.tsx"
+        );
+
+        let (layout, _) = orchestrator
+            .layout_stages(tsx, PDFConfig::default(), &Default::default())
+            .unwrap_or_else(|_| panic!("layout should succeed"));
+        assert_eq!(layout.pages.len(), 3);
+
+        let documents = orchestrator
+            .execute_pipeline_pages(tsx, PDFConfig::default(), Default::default())
+            .unwrap_or_else(|_| panic!("pipeline should succeed"));
+        assert_eq!(documents.len(), 3);
+
+        for (pdf_bytes, page) in documents.iter().zip(&layout.pages) {
+            let doc = lopdf::Document::load_mem(pdf_bytes).expect("each page PDF parses");
+            assert_eq!(doc.get_pages().len(), 1);
+
+            let expected = first_text(&page.boxes).expect("page has text");
+            let text = doc.extract_text(&[1]).expect("page text extracts");
+            // Headings may be uppercased by text-transform
+            let normalize = |s: &str| s.split_whitespace().collect::<String>().to_lowercase();
+            assert!(
+                normalize(&text).contains(&normalize(&expected)),
+                "page {} PDF should contain {:?}: {}",
+                page.page_number,
+                expected,
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_execute_pipeline_cached_skips_layout_on_repeat() {
+        let cache = RefCell::new(LayoutCache::default());
+        let orchestrator = PipelineOrchestrator::new(None);
+        let tsx = r#"const CV = () => <div><h1>Jane Doe</h1><p>Engineer</p></div>;"#;
+
+        let first = orchestrator
+            .execute_pipeline_cached(tsx, PDFConfig::default(), Default::default(), Some(&cache))
+            .unwrap_or_else(|_| panic!("pipeline should succeed"));
+        assert_eq!(cache.borrow().misses(), 1);
+        assert!(stage_ms(&orchestrator, Stage::LayingOut).is_some());
+
+        let second = orchestrator
+            .execute_pipeline_cached(tsx, PDFConfig::default(), Default::default(), Some(&cache))
+            .unwrap_or_else(|_| panic!("pipeline should succeed"));
+        assert_eq!((cache.borrow().hits(), cache.borrow().misses()), (1, 1));
+        assert!(stage_ms(&orchestrator, Stage::LayingOut).is_none());
+        assert!(stage_ms(&orchestrator, Stage::GeneratingPdf).is_some());
+        assert_eq!(first.len(), second.len());
+
+        // A different config is a different layout
+        let config = PDFConfig {
+            title: Some("Jane Doe".to_string()),
+            ..Default::default()
+        };
+        orchestrator
+            .execute_pipeline_cached(tsx, config, Default::default(), Some(&cache))
+            .unwrap_or_else(|_| panic!("pipeline should succeed"));
+        assert_eq!(cache.borrow().misses(), 2);
+        assert_eq!(cache.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_execute_pipeline_degrades_corrupt_font_to_warning() {
+        use crate::converter::{FontCollection, FontData};
+        use crate::font_processor::FontProcessor;
+
+        let roboto = include_bytes!("../../../../test-fixtures/fonts/Roboto-Regular.ttf");
+        let mut collection = FontCollection::new();
+        collection.add(FontData::new("Roboto".into(), 400, false, roboto.to_vec()));
+        collection.add(FontData::new("Roboto".into(), 700, false, vec![0xFF; 8]));
+        let (font_bytes_map, warnings) = FontProcessor::new().build_font_collection(&collection);
+        assert!(font_bytes_map.contains_key("Roboto:400:false"));
+
+        let orchestrator = PipelineOrchestrator::new(None);
+        orchestrator.add_warnings(warnings);
+        let tsx = r#"
+            const CV = () => (
+                <div style="font-family: Roboto">
+                    <h1 style="font-weight: bold">Jane Doe</h1>
+                    <p>Software Engineer</p>
+                </div>
+            );
+        "#;
+
+        let pdf_bytes = orchestrator
+            .execute_pipeline_cached(tsx, PDFConfig::default(), font_bytes_map, None)
+            .unwrap_or_else(|_| panic!("pipeline should survive a corrupt font"));
+        assert!(pdf_bytes.starts_with(b"%PDF"));
+
+        let warnings = orchestrator.warnings();
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains("Roboto:700:false"));
+    }
+
+    #[test]
+    fn test_execute_pipeline_without_usable_fonts() {
+        use crate::converter::{FontCollection, FontData};
+        use crate::font_processor::FontProcessor;
+
+        let mut collection = FontCollection::new();
+        collection.add(FontData::new("Roboto".into(), 400, false, vec![0xFF; 8]));
+        let (font_bytes_map, warnings) = FontProcessor::new().build_font_collection(&collection);
+        assert!(font_bytes_map.is_empty());
+
+        let orchestrator = PipelineOrchestrator::new(None);
+        orchestrator.add_warnings(warnings);
+        let tsx = r#"const CV = () => <div style="font-family: Roboto"><h1>Jane Doe</h1></div>;"#;
+
+        let pdf_bytes = orchestrator
+            .execute_pipeline_cached(tsx, PDFConfig::default(), font_bytes_map, None)
+            .unwrap_or_else(|_| panic!("pipeline should fall back to Standard 14"));
+        assert!(pdf_bytes.starts_with(b"%PDF"));
+        assert_eq!(orchestrator.warnings().len(), 1);
+    }
+
     // This test is disabled for non-WASM targets since it uses js_sys::Date
     // The actual functionality is tested in integration tests
     #[cfg(target_arch = "wasm32")]
@@ -15,7 +15,8 @@
 //! - Parsing: 10% (0-10%)
 //! - Metadata extraction: 10% (10-20%)
 //! - Layout extraction: 20% (20-40%)
-//! - Layout calculation: 20% (40-60%)
+//! - Layout calculation: 10% (40-50%)
+//! - Pagination: 10% (50-60%)
 //! - PDF generation: 20% (60-80%)
 //! - Completion: 20% (80-100%)
 
@@ -28,6 +29,7 @@ pub enum Stage {
     ExtractingMetadata,
     ExtractingLayout,
     LayingOut,
+    Paginating,
     GeneratingPdf,
     Completed,
 }
@@ -40,6 +42,7 @@ impl Stage {
             Stage::ExtractingMetadata => "extracting-metadata",
             Stage::ExtractingLayout => "extracting-layout",
             Stage::LayingOut => "laying-out",
+            Stage::Paginating => "paginating",
             Stage::GeneratingPdf => "generating-pdf",
             Stage::Completed => "completed",
         }
@@ -52,6 +55,7 @@ impl Stage {
             Stage::ExtractingMetadata => 10.0,
             Stage::ExtractingLayout => 20.0,
             Stage::LayingOut => 40.0,
+            Stage::Paginating => 50.0,
             Stage::GeneratingPdf => 60.0,
             Stage::Completed => 100.0,
         }
@@ -63,7 +67,8 @@ impl Stage {
             Stage::Parsing => 10.0,
             Stage::ExtractingMetadata => 10.0,
             Stage::ExtractingLayout => 20.0,
-            Stage::LayingOut => 20.0,
+            Stage::LayingOut => 10.0,
+            Stage::Paginating => 10.0,
             Stage::GeneratingPdf => 20.0,
             Stage::Completed => 0.0, // No weight, it's the final state
         }
@@ -95,12 +100,27 @@ impl Stage {
 /// ```
 pub struct ProgressTracker {
     callback: Option<js_sys::Function>,
+    listener: Option<Box<dyn Fn(Stage, f64)>>,
 }
 
 impl ProgressTracker {
     /// Create a new progress tracker with optional callback
     pub fn new(callback: Option<js_sys::Function>) -> Self {
-        Self { callback }
+        Self {
+            callback,
+            listener: None,
+        }
+    }
+
+    /// Create a progress tracker reporting to a Rust closure
+    ///
+    /// Used by native tests, where no JavaScript function exists.
+    #[cfg(test)]
+    pub fn with_listener(listener: impl Fn(Stage, f64) + 'static) -> Self {
+        Self {
+            callback: None,
+            listener: Some(Box::new(listener)),
+        }
     }
 
     /// Report progress at the start of a stage
@@ -157,6 +177,9 @@ impl ProgressTracker {
 
     /// Emit progress to JavaScript callback
     fn emit(&self, stage: Stage, percentage: f64) -> Result<(), JsValue> {
+        if let Some(ref listener) = self.listener {
+            listener(stage, percentage);
+        }
         if let Some(ref cb) = self.callback {
             let stage_val = JsValue::from_str(stage.as_str());
             let percentage_val = JsValue::from_f64(percentage);
@@ -176,6 +199,7 @@ mod tests {
         assert_eq!(Stage::ExtractingMetadata.base_percentage(), 10.0);
         assert_eq!(Stage::ExtractingLayout.base_percentage(), 20.0);
         assert_eq!(Stage::LayingOut.base_percentage(), 40.0);
+        assert_eq!(Stage::Paginating.base_percentage(), 50.0);
         assert_eq!(Stage::GeneratingPdf.base_percentage(), 60.0);
         assert_eq!(Stage::Completed.base_percentage(), 100.0);
     }
@@ -185,7 +209,8 @@ mod tests {
         assert_eq!(Stage::Parsing.weight(), 10.0);
         assert_eq!(Stage::ExtractingMetadata.weight(), 10.0);
         assert_eq!(Stage::ExtractingLayout.weight(), 20.0);
-        assert_eq!(Stage::LayingOut.weight(), 20.0);
+        assert_eq!(Stage::LayingOut.weight(), 10.0);
+        assert_eq!(Stage::Paginating.weight(), 10.0);
         assert_eq!(Stage::GeneratingPdf.weight(), 20.0);
         assert_eq!(Stage::Completed.weight(), 0.0);
     }
@@ -196,6 +221,7 @@ mod tests {
         assert_eq!(Stage::ExtractingMetadata.as_str(), "extracting-metadata");
         assert_eq!(Stage::ExtractingLayout.as_str(), "extracting-layout");
         assert_eq!(Stage::LayingOut.as_str(), "laying-out");
+        assert_eq!(Stage::Paginating.as_str(), "paginating");
         assert_eq!(Stage::GeneratingPdf.as_str(), "generating-pdf");
         assert_eq!(Stage::Completed.as_str(), "completed");
     }
@@ -251,6 +277,11 @@ mod tests {
 
         assert_eq!(
             Stage::LayingOut.base_percentage() + Stage::LayingOut.weight(),
+            Stage::Paginating.base_percentage()
+        );
+
+        assert_eq!(
+            Stage::Paginating.base_percentage() + Stage::Paginating.weight(),
             Stage::GeneratingPdf.base_percentage()
         );
 
@@ -266,6 +297,7 @@ mod tests {
             + Stage::ExtractingMetadata.weight()
             + Stage::ExtractingLayout.weight()
             + Stage::LayingOut.weight()
+            + Stage::Paginating.weight()
             + Stage::GeneratingPdf.weight()
             + 20.0; // Completion phase (80-100%)
 
@@ -298,7 +330,23 @@ mod tests {
         assert!(tracker.report_stage(Stage::ExtractingMetadata).is_ok());
         assert!(tracker.report_stage(Stage::ExtractingLayout).is_ok());
         assert!(tracker.report_stage(Stage::LayingOut).is_ok());
+        assert!(tracker.report_stage(Stage::Paginating).is_ok());
         assert!(tracker.report_stage(Stage::GeneratingPdf).is_ok());
         assert!(tracker.report_stage(Stage::Completed).is_ok());
     }
+
+    #[test]
+    fn test_progress_tracker_listener_receives_events() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&events);
+        let tracker = ProgressTracker::with_listener(move |stage, percentage| {
+            sink.borrow_mut().push((stage, percentage))
+        });
+
+        tracker.report_percentage(Stage::Paginating, 0.5).unwrap();
+        assert_eq!(*events.borrow(), vec![(Stage::Paginating, 55.0)]);
+    }
 }
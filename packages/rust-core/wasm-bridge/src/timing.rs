@@ -0,0 +1,77 @@
+//! Stage timing for the conversion pipeline
+//!
+//! Provides a monotonic millisecond clock that works both in the browser and
+//! natively, plus a small collector for per-stage durations.
+//!
+//! - WASM: `performance.now()` from the global scope (window or worker),
+//!   falling back to `Date.now()` when the Performance API is unavailable
+//! - Native: `std::time::Instant` relative to the first clock read
+
+use std::collections::BTreeMap;
+
+use crate::progress::Stage;
+
+/// Current time in milliseconds from a monotonic clock
+#[cfg(target_arch = "wasm32")]
+pub fn now_ms() -> f64 {
+    use wasm_bindgen::JsCast;
+
+    js_sys::Reflect::get(&js_sys::global(), &"performance".into())
+        .ok()
+        .and_then(|performance| performance.dyn_into::<web_sys::Performance>().ok())
+        .map(|performance| performance.now())
+        .unwrap_or_else(js_sys::Date::now)
+}
+
+/// Current time in milliseconds from a monotonic clock
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now_ms() -> f64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    EPOCH.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+}
+
+/// Elapsed milliseconds per pipeline stage, keyed by [`Stage::as_str`]
+///
+/// Serializes as a flat JSON object, e.g. `{"parsing": 1.2, "laying-out": 8.4}`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+#[serde(transparent)]
+pub struct StageTimings(BTreeMap<&'static str, f64>);
+
+impl StageTimings {
+    /// Create an empty timings map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the elapsed time for a stage (replaces any previous value)
+    pub fn record(&mut self, stage: Stage, elapsed_ms: f64) {
+        self.0.insert(stage.as_str(), elapsed_ms.max(0.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_now_ms_is_monotonic() {
+        let first = now_ms();
+        let second = now_ms();
+        assert!(second >= first);
+    }
+
+    #[test]
+    fn test_stage_timings_serializes_by_stage_name() {
+        let mut timings = StageTimings::new();
+        timings.record(Stage::Parsing, 1.5);
+        timings.record(Stage::LayingOut, 2.5);
+
+        assert_eq!(
+            serde_json::to_value(&timings).unwrap(),
+            serde_json::json!({ "parsing": 1.5, "laying-out": 2.5 })
+        );
+    }
+}
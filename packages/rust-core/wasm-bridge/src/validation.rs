@@ -57,7 +57,8 @@ pub fn is_valid_font_format(bytes: &[u8]) -> bool {
 /// - Author: CV name
 /// - Title: "CV name - Resume" or just "Resume"
 /// - Subject: CV title or "Curriculum Vitae"
-/// - Keywords: Generated from sections and skills
+/// - Keywords: Generated from name, title, skills and layout, unless the
+///   config already carries explicit keywords
 ///
 /// # Arguments
 /// * `config` - Base PDF configuration to enrich
@@ -81,30 +82,37 @@ pub fn enrich_pdf_config_with_metadata(
         config.subject = Some(title.clone());
     }
 
-    // Generate keywords from metadata
-    let mut keywords = Vec::new();
+    // Generate keywords from metadata unless the caller supplied them
+    if config.keywords.is_none() {
+        let mut keywords = Vec::new();
 
-    if let Some(ref name) = metadata.name {
-        keywords.push(name.clone());
-    }
+        if let Some(ref name) = metadata.name {
+            keywords.push(name.clone());
+        }
 
-    if let Some(ref title) = metadata.title {
-        keywords.push(title.clone());
-    }
+        if let Some(ref title) = metadata.title {
+            keywords.push(title.clone());
+        }
+
+        // Extracted skills are what recruiters search ATS databases for
+        if config.keywords_from_skills {
+            keywords.extend(metadata.skills.iter().cloned());
+        }
 
-    // Add layout type as keyword for ATS
-    keywords.push(match metadata.layout_type {
-        cv_domain::LayoutType::SingleColumn => "single-column".to_string(),
-        cv_domain::LayoutType::TwoColumn => "two-column".to_string(),
-        cv_domain::LayoutType::Academic => "academic".to_string(),
-        cv_domain::LayoutType::Portfolio => "portfolio".to_string(),
-        cv_domain::LayoutType::Custom => "custom".to_string(),
-    });
+        // Add layout type as keyword for ATS
+        keywords.push(match metadata.layout_type {
+            cv_domain::LayoutType::SingleColumn => "single-column".to_string(),
+            cv_domain::LayoutType::TwoColumn => "two-column".to_string(),
+            cv_domain::LayoutType::Academic => "academic".to_string(),
+            cv_domain::LayoutType::Portfolio => "portfolio".to_string(),
+            cv_domain::LayoutType::Custom => "custom".to_string(),
+        });
 
-    keywords.push("resume".to_string());
-    keywords.push("cv".to_string());
+        keywords.push("resume".to_string());
+        keywords.push("cv".to_string());
 
-    config.keywords = Some(keywords.join(", "));
+        config.keywords = Some(keywords.join(", "));
+    }
 
     // Ensure creator is set
     if config.creator.is_none() {
@@ -263,6 +271,29 @@ mod tests {
             ats_weights: None,
             compress_content_streams: false,
             generate_bookmarks: true,
+            structured_reading_order: true,
+            reading_order: Default::default(),
+            allow_restricted_fonts: false,
+            producer: None,
+            keywords_from_skills: true,
+            trim_box: None,
+            bleed_box: None,
+            embed_source: false,
+            binary_comment: true,
+            viewer_preferences: None,
+            autolink: false,
+            link_style: None,
+            target_max_bytes: None,
+            strip_optional_font_tables: false,
+            embed_base_fonts: false,
+            baseline_grid: None,
+            missing_font_policy: Default::default(),
+            font_quality: Default::default(),
+            fit_to_pages: None,
+            custom_info: Vec::new(),
+            header_band_height: 0.0,
+            footer_band_height: 0.0,
+            watermark: None,
         };
 
         // Create metadata with name
@@ -307,6 +338,29 @@ mod tests {
             ats_weights: None,
             compress_content_streams: false,
             generate_bookmarks: true,
+            structured_reading_order: true,
+            reading_order: Default::default(),
+            allow_restricted_fonts: false,
+            producer: None,
+            keywords_from_skills: true,
+            trim_box: None,
+            bleed_box: None,
+            embed_source: false,
+            binary_comment: true,
+            viewer_preferences: None,
+            autolink: false,
+            link_style: None,
+            target_max_bytes: None,
+            strip_optional_font_tables: false,
+            embed_base_fonts: false,
+            baseline_grid: None,
+            missing_font_policy: Default::default(),
+            font_quality: Default::default(),
+            fit_to_pages: None,
+            custom_info: Vec::new(),
+            header_band_height: 0.0,
+            footer_band_height: 0.0,
+            watermark: None,
         };
 
         let tsx = "<div><h1>John Doe</h1></div>";
@@ -323,4 +377,63 @@ mod tests {
         // Creator should remain unchanged
         assert_eq!(enriched.creator, Some("Test".to_string()));
     }
+
+    const SKILLS_CV: &str = r#"
+        const CV = () => (
+            <div>
+                <h1>Jane Doe</h1>
+                <p>jane@example.com</p>
+                <h2>Skills</h2>
+                <ul>
+                    <li>Languages: Rust, TypeScript</li>
+                    <li>PostgreSQL</li>
+                </ul>
+                <h2>Experience</h2>
+                <p>Backend Engineer, Acme</p>
+            </div>
+        );
+    "#;
+
+    #[test]
+    fn test_enrich_pdf_config_keywords_include_skills() {
+        let document = tsx_parser::parse_tsx(SKILLS_CV).unwrap();
+        let metadata = cv_domain::extract_metadata(&document).unwrap();
+        assert_eq!(metadata.skills, vec!["Rust", "TypeScript", "PostgreSQL"]);
+
+        let config =
+            enrich_pdf_config_with_metadata(pdf_generator::PDFConfig::default(), &metadata);
+
+        let mut generator = pdf_generator::PDFGenerator::new(config).unwrap();
+        generator.add_text("Jane Doe", 72.0, 720.0, 12.0).unwrap();
+        let pdf_bytes = generator.finalize().unwrap();
+
+        // Info dictionary strings are uncompressed literals
+        let pdf = String::from_utf8_lossy(&pdf_bytes);
+        let start = pdf.find("/Keywords").expect("PDF should have /Keywords");
+        let end = start + pdf[start..].find(')').unwrap();
+        let keywords = &pdf[start..end];
+        for skill in ["Rust", "TypeScript", "PostgreSQL"] {
+            assert!(keywords.contains(skill), "missing {skill} in {keywords}");
+        }
+    }
+
+    #[test]
+    fn test_enrich_pdf_config_keeps_explicit_keywords_and_opt_out() {
+        let document = tsx_parser::parse_tsx(SKILLS_CV).unwrap();
+        let metadata = cv_domain::extract_metadata(&document).unwrap();
+
+        let explicit = pdf_generator::PDFConfig {
+            keywords: Some("backend, platform".to_string()),
+            ..Default::default()
+        };
+        let enriched = enrich_pdf_config_with_metadata(explicit, &metadata);
+        assert_eq!(enriched.keywords.as_deref(), Some("backend, platform"));
+
+        let opted_out = pdf_generator::PDFConfig {
+            keywords_from_skills: false,
+            ..Default::default()
+        };
+        let enriched = enrich_pdf_config_with_metadata(opted_out, &metadata);
+        assert!(!enriched.keywords.unwrap().contains("PostgreSQL"));
+    }
 }
@@ -138,18 +138,19 @@ fn test_invalid_font_data() {
         invalid_font_bytes,
     ));
 
+    // The invalid font is skipped (Standard 14 fallback) and reported as a warning
     let result = converter.convert_tsx_to_pdf(valid_tsx, config, Some(fonts), None);
-    assert!(result.is_err(), "Should fail with invalid font data");
+    assert!(
+        result.is_ok(),
+        "Invalid font data should not abort conversion"
+    );
 
-    let err = result.unwrap_err();
-    let err_json = js_sys::JSON::stringify(&err)
-        .ok()
-        .and_then(|s| s.as_string())
-        .unwrap_or_default();
+    let warnings = converter.warnings();
+    assert_eq!(warnings.len(), 1);
     assert!(
-        err_json.contains("INVALID_FONT_DATA") || err_json.contains("font"),
-        "Error should indicate invalid font: {}",
-        err_json
+        warnings[0].contains("Roboto"),
+        "Warning should name the invalid font: {:?}",
+        warnings
     );
 }
 
@@ -50,6 +50,29 @@ fn create_default_config() -> PDFConfig {
         ats_weights: None,
         compress_content_streams: false,
         generate_bookmarks: true,
+        structured_reading_order: true,
+        reading_order: Default::default(),
+        allow_restricted_fonts: false,
+        producer: None,
+        keywords_from_skills: true,
+        trim_box: None,
+        bleed_box: None,
+        embed_source: false,
+        binary_comment: true,
+        viewer_preferences: None,
+        autolink: false,
+        link_style: None,
+        target_max_bytes: None,
+        strip_optional_font_tables: false,
+        embed_base_fonts: false,
+        baseline_grid: None,
+        missing_font_policy: Default::default(),
+        font_quality: Default::default(),
+        fit_to_pages: None,
+        custom_info: Vec::new(),
+        header_band_height: 0.0,
+        footer_band_height: 0.0,
+        watermark: None,
     }
 }
 
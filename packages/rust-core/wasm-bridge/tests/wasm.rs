@@ -385,4 +385,16 @@ fn test_ats_validation() {
 
     let errors = Reflect::get(&report, &"errors".into()).unwrap();
     assert!(errors.is_array(), "Report should have 'errors' array");
+
+    // Per-rule checklist entries
+    let rules = Reflect::get(&report, &"rules".into()).unwrap();
+    assert!(rules.is_array(), "Report should have 'rules' array");
+    let first_rule = Array::from(&rules).get(0);
+    for field in ["rule", "passed", "weight", "message"] {
+        assert!(
+            Reflect::has(&first_rule, &field.into()).unwrap(),
+            "Rule entry should have '{}'",
+            field
+        );
+    }
 }
@@ -0,0 +1,122 @@
+/**
+ * Test Fixture: Unbalanced Two-Column Resume
+ *
+ * Description: Sidebar + main column layout where the main column is much taller
+ * Layout Type: two-column
+ * Estimated Pages: 3
+ * Font Complexity: Simple (1 font)
+ * Special Features: Tests two-column pagination with uneven column heights
+ * Test Objectives:
+ *   - Verify both columns keep their X positions and gap on continuation pages
+ *   - Ensure both columns restart at the top of each new page
+ *   - Validate the shorter column does not create a phantom page
+ */
+
+export default function UnbalancedTwoColumnResume() {
+  return (
+    <div style={{ fontFamily: 'Helvetica', fontSize: '11px', display: 'flex', gap: '24px' }}>
+        <div style={{ width: '160px' }}>
+          <h2 style={{ fontSize: '14px', fontWeight: 'bold', marginBottom: '8px' }}>Skills</h2>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 1 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 2 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 3 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 4 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 5 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 6 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 7 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 8 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 9 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 10 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 11 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 12 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 13 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 14 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 15 with a short note</p>
+          <p style={{ marginBottom: '6px' }}>Sidebar skill entry number 16 with a short note</p>
+        </div>
+        <div style={{ flex: 1 }}>
+          <h2 style={{ fontSize: '14px', fontWeight: 'bold', marginBottom: '8px' }}>Experience</h2>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 1 at Company 1</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 2 at Company 2</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 3 at Company 3</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 4 at Company 4</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 5 at Company 5</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 6 at Company 6</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 7 at Company 7</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 8 at Company 8</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 9 at Company 9</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 10 at Company 10</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 11 at Company 11</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 12 at Company 12</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 13 at Company 13</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 14 at Company 14</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 15 at Company 15</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+          <h3 style={{ fontSize: '13px', fontWeight: 'bold', marginBottom: '4px' }}>Role 16 at Company 16</h3>
+          <p style={{ marginBottom: '12px' }}>
+            Led delivery of platform features across several teams, improving reliability and reducing
+            deployment time while mentoring engineers and coordinating releases with product partners.
+          </p>
+        </div>
+    </div>
+  );
+}